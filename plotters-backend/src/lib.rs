@@ -67,8 +67,8 @@ pub mod rasterizer;
 mod style;
 mod text;
 
-pub use style::{BackendColor, BackendStyle};
-pub use text::{text_anchor, BackendTextStyle, FontFamily, FontStyle, FontTransform};
+pub use style::{BackendColor, BackendGradient, BackendStyle, GradientDirection};
+pub use text::{text_anchor, BackendTextStyle, FontFamily, FontStyle, FontTransform, TextMetrics};
 
 use text_anchor::{HPos, VPos};
 
@@ -139,6 +139,9 @@ pub trait DrawingBackend: Sized {
         to: BackendCoord,
         style: &S,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if !style.stroke_dash().is_empty() {
+            return self.draw_path([from, to].iter().copied(), style);
+        }
         rasterizer::draw_line(self, from, to, style)
     }
 
@@ -169,6 +172,19 @@ pub trait DrawingBackend: Sized {
             return Ok(());
         }
 
+        if !style.stroke_dash().is_empty() {
+            let p: Vec<_> = path.into_iter().collect();
+            for dash in rasterizer::dash_path(&p[..], style.stroke_dash()) {
+                if style.stroke_width() == 1 {
+                    self.draw_path(dash, &style.color())?;
+                } else {
+                    let v = rasterizer::polygonize(&dash[..], style.stroke_width());
+                    self.fill_polygon(v, &style.color())?;
+                }
+            }
+            return Ok(());
+        }
+
         if style.stroke_width() == 1 {
             let mut begin: Option<BackendCoord> = None;
             for end in path.into_iter() {
@@ -204,6 +220,49 @@ pub trait DrawingBackend: Sized {
         rasterizer::draw_circle(self, center, radius, style, fill)
     }
 
+    /// Draw an ellipse on the drawing backend. The backends without native ellipses draw a
+    /// polygon approximating it.
+    /// - `center`: The center coordinate of the ellipse
+    /// - `radii`: The radius along the rotated X axis, and the radius along the rotated Y axis
+    /// - `rotation`: The rotation of the ellipse in radians, clockwise
+    /// - `style`: The style of the shape
+    /// - `fill`: If the ellipse should be filled
+    fn draw_ellipse<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radii: (f64, f64),
+        rotation: f64,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let full_turn = (0.0, 2.0 * std::f64::consts::PI);
+        let points = rasterizer::arc_points(center, radii, rotation, full_turn);
+        if fill {
+            self.fill_polygon(points, style)
+        } else {
+            self.draw_path(points, style)
+        }
+    }
+
+    /// Draw an arc of a circle on the drawing backend. The backends without native arcs draw a
+    /// path approximating it.
+    /// - `center`: The center coordinate of the circle
+    /// - `radius`: The radius of the circle
+    /// - `angles`: The start and the end angles of the arc in radians, clockwise from the X axis,
+    ///   the arc going from the start to the end
+    /// - `style`: The style of the arc
+    fn draw_arc<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        angles: (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let radius = f64::from(radius);
+        let points = rasterizer::arc_points(center, (radius, radius), 0.0, angles);
+        self.draw_path(points, style)
+    }
+
     fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
         &mut self,
         vert: I,
@@ -214,6 +273,19 @@ pub trait DrawingBackend: Sized {
         rasterizer::fill_polygon(self, &vert_buf[..], style)
     }
 
+    /// Fill a polygon with a linear gradient spanning its bounding box
+    /// - `vert`: The vertices of the polygon
+    /// - `gradient`: The gradient to fill the polygon with
+    fn fill_polygon_gradient<I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        gradient: &BackendGradient,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert_buf: Vec<_> = vert.into_iter().collect();
+
+        rasterizer::fill_polygon_gradient(self, &vert_buf[..], gradient)
+    }
+
     /// Draw a text on the drawing backend
     /// - `text`: The text to draw
     /// - `style`: The text style
@@ -246,6 +318,10 @@ pub trait DrawingBackend: Sized {
             VPos::Bottom => -height,
         };
         let trans = style.transform();
+        if style.rotation() % 360.0 != 0.0 {
+            let angle = trans.angle() + style.rotation();
+            return rasterizer::draw_rotated_text(self, text, style, pos, angle);
+        }
         let (w, h) = self.get_size();
         let drawing_result = style.draw(text, (0, 0), |x, y, color| {
             let (x, y) = trans.transform(x + dx - min_x, y + dy - min_y);
@@ -284,6 +360,34 @@ pub trait DrawingBackend: Sized {
         ))
     }
 
+    /// Estimate the size of the horizontal text and the vertical metrics of its font if
+    /// rendered on this backend, see [`TextMetrics`].
+    ///
+    /// The default implementation takes the size from [`DrawingBackend::estimate_text_size`],
+    /// and the vertical metrics from the style. The backends rendering the text with their own
+    /// fonts should override it to report the metrics of these fonts.
+    ///
+    /// - `text`: The text to estimate
+    /// - `font`: The font to estimate
+    /// - *Returns* The estimated text metrics
+    fn estimate_text_metrics<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<TextMetrics, DrawingErrorKind<Self::ErrorType>> {
+        let (width, height) = self.estimate_text_size(text, style)?;
+        let (ascent, descent, line_gap) = style
+            .vertical_metrics()
+            .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+        Ok(TextMetrics {
+            width,
+            height,
+            ascent,
+            descent,
+            line_gap,
+        })
+    }
+
     /// Blit a bitmap on to the backend.
     ///
     /// - `text`: pos the left upper conner of the bitmap to blit
@@ -326,4 +430,76 @@ pub trait DrawingBackend: Sized {
 
         Ok(())
     }
+
+    /// Blit a bitmap with an alpha channel on to the backend, compositing it over the existing
+    /// content.
+    ///
+    /// The default implementation draws each pixel which isn't fully transparent with
+    /// [`DrawingBackend::draw_pixel`], the backends which can embed images should override it.
+    ///
+    /// - `pos`: The left upper corner of the bitmap
+    /// - `size`: The size of the bitmap
+    /// - `src`: The pixels of the bitmap, row by row, as 4 bytes of red, green, blue and alpha
+    fn blit_rgba_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (iw, ih): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (w, h) = self.get_size();
+
+        for dy in 0..ih {
+            let y = pos.1 + dy as i32;
+            if y < 0 || y >= h as i32 {
+                continue;
+            }
+            for dx in 0..iw {
+                let x = pos.0 + dx as i32;
+                if x < 0 || x >= w as i32 {
+                    continue;
+                }
+                let pixel = &src[(dx + dy * iw) as usize * 4..][..4];
+                if pixel[3] == 0 {
+                    continue;
+                }
+                let color = BackendColor {
+                    alpha: f64::from(pixel[3]) / 255.0,
+                    rgb: (pixel[0], pixel[1], pixel[2]),
+                };
+                self.draw_pixel((x, y), color)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Restrict the following drawing operations to a rectangle, so that the pixels outside of
+    /// it are left untouched. The backends which don't support clipping ignore this.
+    ///
+    /// - `clip`: The upper-left corner and the excluded bottom-right corner of the rectangle, or
+    ///   `None` to draw on the whole backend again
+    fn set_clip_rect(&mut self, _clip: Option<(BackendCoord, BackendCoord)>) {}
+
+    /// Tag the following shapes with a class name, until the matching
+    /// [pop_element_class](DrawingBackend::pop_element_class). The backends writing a document,
+    /// such as SVG, may attach the classes to the shapes so that they can be restyled later,
+    /// the other backends ignore this.
+    ///
+    /// - `class`: The class name, the classes pushed before it still apply
+    fn push_element_class(&mut self, _class: &str) {}
+
+    /// Stop tagging the following shapes with the last pushed class name
+    fn pop_element_class(&mut self) {}
+
+    /// Attach metadata to the following shapes, until the matching
+    /// [pop_element_metadata](DrawingBackend::pop_element_metadata). The backends writing an
+    /// interactive document, such as SVG, may render it as tooltips and data attributes, the
+    /// other backends ignore this.
+    ///
+    /// - `tooltip`: The text shown when hovering the shapes, if any
+    /// - `data`: The key/value pairs attached to the shapes
+    fn push_element_metadata(&mut self, _tooltip: Option<&str>, _data: &[(String, String)]) {}
+
+    /// Stop attaching the last pushed metadata to the following shapes
+    fn pop_element_metadata(&mut self) {}
 }
@@ -0,0 +1,62 @@
+use crate::BackendCoord;
+
+/// Approximate an arc of an ellipse with points, about two pixels apart
+/// - `center`: The center of the ellipse
+/// - `radii`: The radius along the rotated X axis, and the radius along the rotated Y axis
+/// - `rotation`: The rotation of the ellipse in radians, clockwise on the backend
+/// - `angles`: The start and the end angles of the arc in radians, clockwise from the rotated
+///   X axis, going from the start to the end
+/// - **returns** The points of the arc, including both of its ends
+pub fn arc_points(
+    center: BackendCoord,
+    radii: (f64, f64),
+    rotation: f64,
+    angles: (f64, f64),
+) -> Vec<BackendCoord> {
+    let span = angles.1 - angles.0;
+    let length = radii.0.abs().max(radii.1.abs()) * span.abs();
+    let segments = ((length / 2.0).ceil() as usize).clamp(8, 2000);
+    let (sin, cos) = rotation.sin_cos();
+    (0..=segments)
+        .map(|idx| {
+            let angle = angles.0 + span * idx as f64 / segments as f64;
+            let (x, y) = (radii.0 * angle.cos(), radii.1 * angle.sin());
+            (
+                (f64::from(center.0) + x * cos - y * sin).round() as i32,
+                (f64::from(center.1) + x * sin + y * cos).round() as i32,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arc_points() {
+        // A quarter of a circle, clockwise from the right to the bottom
+        let points = arc_points(
+            (100, 100),
+            (50.0, 50.0),
+            0.0,
+            (0.0, std::f64::consts::FRAC_PI_2),
+        );
+        assert_eq!(points.first(), Some(&(150, 100)));
+        assert_eq!(points.last(), Some(&(100, 150)));
+        assert_eq!(points.len(), 41);
+        assert!(points
+            .iter()
+            .all(|&(x, y)| ((x - 100).pow(2) + (y - 100).pow(2) - 2500).abs() < 100));
+
+        // The rotation turns the axes of the ellipse
+        let points = arc_points(
+            (0, 0),
+            (20.0, 10.0),
+            std::f64::consts::FRAC_PI_2,
+            (0.0, 0.0),
+        );
+        assert_eq!(points.len(), 9);
+        assert!(points.iter().all(|&p| p == (0, 20)));
+    }
+}
@@ -0,0 +1,151 @@
+use crate::BackendCoord;
+
+/// Clip a line segment to a rectangle with the Liang–Barsky algorithm.
+///
+/// The rectangle follows the convention of
+/// [`DrawingBackend::set_clip_rect`](crate::DrawingBackend::set_clip_rect): its bottom-right
+/// corner is excluded, thus the visible pixels go up to one pixel before it.
+///
+/// - `from`: The start point of the segment
+/// - `to`: The end point of the segment
+/// - `clip`: The upper-left corner and the excluded bottom-right corner of the rectangle
+/// - **returns** The part of the segment within the rectangle, or `None` when the segment
+///   doesn't cross it. The part of a segment lying within the rectangle keeps its exact ends.
+pub fn clip_line(
+    from: BackendCoord,
+    to: BackendCoord,
+    (upper_left, bottom_right): (BackendCoord, BackendCoord),
+) -> Option<(BackendCoord, BackendCoord)> {
+    let (x0, y0) = (
+        f64::from(upper_left.0.min(bottom_right.0)),
+        f64::from(upper_left.1.min(bottom_right.1)),
+    );
+    let (x1, y1) = (
+        f64::from(upper_left.0.max(bottom_right.0) - 1),
+        f64::from(upper_left.1.max(bottom_right.1) - 1),
+    );
+    if x0 > x1 || y0 > y1 {
+        return None;
+    }
+
+    let (fx, fy) = (f64::from(from.0), f64::from(from.1));
+    let (dx, dy) = (f64::from(to.0) - fx, f64::from(to.1) - fy);
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+
+    // Each edge of the rectangle moves the entering or the leaving parameter of the segment
+    for (p, q) in [(-dx, fx - x0), (dx, x1 - fx), (-dy, fy - y0), (dy, y1 - fy)] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+            if t0 > t1 {
+                return None;
+            }
+        }
+    }
+
+    let point = |t: f64, end: BackendCoord| {
+        if t == 0.0 {
+            from
+        } else if t == 1.0 {
+            end
+        } else {
+            ((fx + t * dx).round() as i32, (fy + t * dy).round() as i32)
+        }
+    };
+    Some((point(t0, from), point(t1, to)))
+}
+
+/// Clip a path to a rectangle, see [`clip_line`].
+///
+/// - `path`: The key points of the path
+/// - `clip`: The upper-left corner and the excluded bottom-right corner of the rectangle
+/// - **returns** The key points of each piece of the path within the rectangle. The path is
+///   split where it leaves the rectangle, and resumed where it enters it again.
+pub fn clip_path(
+    path: &[BackendCoord],
+    clip: (BackendCoord, BackendCoord),
+) -> Vec<Vec<BackendCoord>> {
+    let mut pieces = vec![];
+    let mut current: Vec<BackendCoord> = vec![];
+
+    for segment in path.windows(2) {
+        match clip_line(segment[0], segment[1], clip) {
+            Some((from, to)) => {
+                if current.last() != Some(&from) {
+                    if current.len() > 1 {
+                        pieces.push(std::mem::take(&mut current));
+                    }
+                    current = vec![from];
+                }
+                current.push(to);
+            }
+            None => {
+                if current.len() > 1 {
+                    pieces.push(std::mem::take(&mut current));
+                }
+                current.clear();
+            }
+        }
+    }
+    if current.len() > 1 {
+        pieces.push(current);
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CLIP: (BackendCoord, BackendCoord) = ((0, 0), (101, 101));
+
+    #[test]
+    fn test_clip_line() {
+        // Inside, the segment is untouched
+        assert_eq!(
+            clip_line((10, 10), (90, 50), CLIP),
+            Some(((10, 10), (90, 50)))
+        );
+        // Crossing the top edge
+        assert_eq!(
+            clip_line((50, 50), (50, -50), CLIP),
+            Some(((50, 50), (50, 0)))
+        );
+        // Crossing two edges
+        assert_eq!(
+            clip_line((-50, 50), (150, 50), CLIP),
+            Some(((0, 50), (100, 50)))
+        );
+        assert_eq!(
+            clip_line((0, -100), (200, 100), CLIP),
+            Some(((100, 0), (100, 0)))
+        );
+        assert_eq!(
+            clip_line((-10, 40), (40, -10), CLIP),
+            Some(((0, 30), (30, 0)))
+        );
+        // Outside
+        assert_eq!(clip_line((-10, -10), (-10, 200), CLIP), None);
+        assert_eq!(clip_line((0, 150), (150, 150), CLIP), None);
+        assert_eq!(clip_line((-10, 5), (5, -10), CLIP), None);
+    }
+
+    #[test]
+    fn test_clip_path() {
+        // The path goes out through the top edge and comes back
+        let path = [(0, 50), (20, -50), (40, 50), (60, 50)];
+        assert_eq!(
+            clip_path(&path, CLIP),
+            vec![vec![(0, 50), (10, 0)], vec![(30, 0), (40, 50), (60, 50)]]
+        );
+        assert!(clip_path(&[(-10, -10), (-20, -20)], CLIP).is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::BackendCoord;
+
+/// Split a path into the dashes of a dash pattern.
+///
+/// The pattern follows the SVG `stroke-dasharray` convention: it alternates the lengths of
+/// the dashes and of the gaps between them, in pixels, starting with a dash. A pattern with an
+/// odd number of lengths is repeated to get an even number of them, so `[5.0]` means 5 pixels
+/// on, 5 pixels off. The pattern runs continuously along the path, across its corners.
+///
+/// - `path`: The key points of the path
+/// - `pattern`: The on/off lengths of the dash pattern
+/// - **returns** The key points of each dash. An empty pattern, or a pattern without any
+///   positive length, gives back the whole path.
+pub fn dash_path(path: &[BackendCoord], pattern: &[f64]) -> Vec<Vec<BackendCoord>> {
+    if pattern.iter().any(|&l| l.is_nan() || l < 0.0) || pattern.iter().all(|&l| l == 0.0) {
+        return vec![path.to_vec()];
+    }
+    let pattern: Vec<f64> = if pattern.len() % 2 == 1 {
+        pattern.iter().chain(pattern.iter()).copied().collect()
+    } else {
+        pattern.to_vec()
+    };
+
+    let mut dashes = vec![];
+    let mut current: Vec<BackendCoord> = vec![];
+    let mut idx = 0;
+    let mut remaining = pattern[0];
+
+    for segment in path.windows(2) {
+        let (from, to) = (segment[0], segment[1]);
+        let (dx, dy) = (f64::from(to.0 - from.0), f64::from(to.1 - from.1));
+        let length = dx.hypot(dy);
+        let point_at = |pos: f64| {
+            let t = if length > 0.0 { pos / length } else { 1.0 };
+            (
+                (f64::from(from.0) + dx * t).round() as i32,
+                (f64::from(from.1) + dy * t).round() as i32,
+            )
+        };
+
+        let mut pos = 0.0;
+        loop {
+            let on = idx % 2 == 0;
+            if on && current.is_empty() {
+                current.push(point_at(pos));
+            }
+            let step = remaining.min(length - pos);
+            pos += step;
+            remaining -= step;
+            if on {
+                let point = point_at(pos);
+                if current.last() != Some(&point) {
+                    current.push(point);
+                }
+            }
+            if remaining > 0.0 {
+                break;
+            }
+            if on && current.len() > 1 {
+                dashes.push(std::mem::take(&mut current));
+            }
+            current.clear();
+            idx = (idx + 1) % pattern.len();
+            remaining = pattern[idx];
+        }
+    }
+
+    if current.len() > 1 {
+        dashes.push(current);
+    }
+
+    dashes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_solid_path() {
+        let path = vec![(0, 0), (10, 0), (10, 10)];
+        assert_eq!(dash_path(&path, &[]), vec![path.clone()]);
+        assert_eq!(dash_path(&path, &[0.0, 0.0]), vec![path]);
+    }
+
+    #[test]
+    fn test_dashes() {
+        let dashes = dash_path(&[(0, 0), (20, 0)], &[5.0, 3.0]);
+        assert_eq!(
+            dashes,
+            vec![
+                vec![(0, 0), (5, 0)],
+                vec![(8, 0), (13, 0)],
+                vec![(16, 0), (20, 0)]
+            ]
+        );
+
+        // An odd pattern is repeated, so that the dashes and the gaps alternate
+        let dashes = dash_path(&[(0, 0), (0, 20)], &[5.0]);
+        assert_eq!(dashes, vec![vec![(0, 0), (0, 5)], vec![(0, 10), (0, 15)]]);
+    }
+
+    #[test]
+    fn test_dash_across_corners() {
+        let dashes = dash_path(&[(0, 0), (4, 0), (4, 10)], &[6.0, 2.0]);
+        assert_eq!(
+            dashes,
+            vec![vec![(0, 0), (4, 0), (4, 2)], vec![(4, 4), (4, 10)]]
+        );
+    }
+}
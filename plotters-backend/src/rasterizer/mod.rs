@@ -34,8 +34,20 @@ pub use rect::draw_rect;
 mod circle;
 pub use circle::draw_circle;
 
+mod arc;
+pub use arc::arc_points;
+
 mod polygon;
-pub use polygon::fill_polygon;
+pub use polygon::{fill_polygon, fill_polygon_gradient};
 
 mod path;
 pub use path::polygonize;
+
+mod dash;
+pub use dash::dash_path;
+
+mod clip;
+pub use clip::{clip_line, clip_path};
+
+mod text;
+pub use text::draw_rotated_text;
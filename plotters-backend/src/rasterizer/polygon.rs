@@ -1,4 +1,7 @@
-use crate::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::{
+    BackendColor, BackendCoord, BackendGradient, BackendStyle, DrawingBackend, DrawingErrorKind,
+    GradientDirection,
+};
 
 use std::cmp::{Ord, Ordering, PartialOrd};
 
@@ -69,30 +72,111 @@ impl Ord for Edge {
     }
 }
 
+fn bounding_box(vertices: &[BackendCoord]) -> Option<((i32, i32), (i32, i32))> {
+    vertices
+        .iter()
+        .fold(None, |res: Option<((i32, i32), (i32, i32))>, (x, y)| {
+            Some(
+                res.map(|((min_x, max_x), (min_y, max_y))| {
+                    (
+                        (min_x.min(*x), max_x.max(*x)),
+                        (min_y.min(*y), max_y.max(*y)),
+                    )
+                })
+                .unwrap_or(((*x, *x), (*y, *y))),
+            )
+        })
+}
+
+// The paint of a polygon: either the plain color of the style, or a gradient spanning the
+// bounding box of the polygon
+struct Paint<'a, S> {
+    style: &'a S,
+    gradient: Option<(&'a BackendGradient, BackendCoord, BackendCoord)>,
+}
+
+impl<S: BackendStyle> Paint<'_, S> {
+    fn color_at(&self, point: BackendCoord) -> BackendColor {
+        match self.gradient {
+            Some((gradient, upper_left, bottom_right)) => {
+                gradient.color_at(gradient.position(point, upper_left, bottom_right))
+            }
+            None => self.style.color(),
+        }
+    }
+
+    fn draw_span<DB: DrawingBackend>(
+        &self,
+        back: &mut DB,
+        from: BackendCoord,
+        to: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let uniform = match self.gradient {
+            Some((gradient, _, _)) => match gradient.direction {
+                GradientDirection::Vertical => from.1 == to.1,
+                GradientDirection::Horizontal => from.0 == to.0,
+                GradientDirection::Diagonal => from == to,
+            },
+            None => true,
+        };
+        if uniform {
+            return back.draw_line(from, to, &self.color_at(from));
+        }
+        // The color changes along the span, so it's painted pixel by pixel
+        if from.0 == to.0 {
+            for y in from.1.min(to.1)..=from.1.max(to.1) {
+                check_result!(back.draw_pixel((from.0, y), self.color_at((from.0, y))));
+            }
+        } else {
+            for x in from.0.min(to.0)..=from.0.max(to.0) {
+                check_result!(back.draw_pixel((x, from.1), self.color_at((x, from.1))));
+            }
+        }
+        Ok(())
+    }
+}
+
 pub fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
     back: &mut DB,
     vertices: &[BackendCoord],
     style: &S,
 ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-    if let Some((x_span, y_span)) =
-        vertices
-            .iter()
-            .fold(None, |res: Option<((i32, i32), (i32, i32))>, (x, y)| {
-                Some(
-                    res.map(|((min_x, max_x), (min_y, max_y))| {
-                        (
-                            (min_x.min(*x), max_x.max(*x)),
-                            (min_y.min(*y), max_y.max(*y)),
-                        )
-                    })
-                    .unwrap_or(((*x, *x), (*y, *y))),
-                )
-            })
-    {
+    let paint = Paint {
+        style,
+        gradient: None,
+    };
+    fill_polygon_with(back, vertices, &paint)
+}
+
+/// Fill a polygon with a linear gradient spanning its bounding box
+pub fn fill_polygon_gradient<DB: DrawingBackend>(
+    back: &mut DB,
+    vertices: &[BackendCoord],
+    gradient: &BackendGradient,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    if let Some((x_span, y_span)) = bounding_box(vertices) {
+        let paint = Paint {
+            style: &gradient.color_at(0.0),
+            gradient: Some((gradient, (x_span.0, y_span.0), (x_span.1, y_span.1))),
+        };
+        return fill_polygon_with(back, vertices, &paint);
+    }
+    Ok(())
+}
+
+fn fill_polygon_with<DB: DrawingBackend, S: BackendStyle>(
+    back: &mut DB,
+    vertices: &[BackendCoord],
+    paint: &Paint<S>,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    if let Some((x_span, y_span)) = bounding_box(vertices) {
         // First of all, let's handle the case that all the points is in a same vertical or
         // horizontal line
         if x_span.0 == x_span.1 || y_span.0 == y_span.1 {
-            return back.draw_line((x_span.0, y_span.0), (x_span.1, y_span.1), style);
+            if paint.gradient.is_some() {
+                return paint.draw_span(back, (x_span.0, y_span.0), (x_span.1, y_span.1));
+            }
+            return back.draw_line((x_span.0, y_span.0), (x_span.1, y_span.1), paint.style);
         }
 
         let horizontal_sweep = x_span.1 - x_span.0 > y_span.1 - y_span.0;
@@ -201,33 +285,39 @@ pub fn fill_polygon<DB: DrawingBackend, S: BackendStyle>(
                         }
 
                         if horizontal_sweep {
-                            check_result!(back.draw_line(
+                            let (from_p, to_p) = (
+                                (sweep_line, from.floor() as i32),
+                                (sweep_line, to.ceil() as i32),
+                            );
+                            check_result!(paint.draw_span(
+                                back,
                                 (sweep_line, from.ceil() as i32),
                                 (sweep_line, to.floor() as i32),
-                                &style.color(),
                             ));
                             check_result!(back.draw_pixel(
-                                (sweep_line, from.floor() as i32),
-                                style.color().mix(from.ceil() - from),
-                            ));
-                            check_result!(back.draw_pixel(
-                                (sweep_line, to.ceil() as i32),
-                                style.color().mix(to - to.floor()),
+                                from_p,
+                                paint.color_at(from_p).mix(from.ceil() - from),
                             ));
+                            check_result!(
+                                back.draw_pixel(to_p, paint.color_at(to_p).mix(to - to.floor()))
+                            );
                         } else {
-                            check_result!(back.draw_line(
+                            let (from_p, to_p) = (
+                                (from.floor() as i32, sweep_line),
+                                (to.ceil() as i32, sweep_line),
+                            );
+                            check_result!(paint.draw_span(
+                                back,
                                 (from.ceil() as i32, sweep_line),
                                 (to.floor() as i32, sweep_line),
-                                &style.color(),
                             ));
                             check_result!(back.draw_pixel(
-                                (from.floor() as i32, sweep_line),
-                                style.color().mix(from.ceil() - from),
-                            ));
-                            check_result!(back.draw_pixel(
-                                (to.ceil() as i32, sweep_line),
-                                style.color().mix(to.floor() - to),
+                                from_p,
+                                paint.color_at(from_p).mix(from.ceil() - from),
                             ));
+                            check_result!(
+                                back.draw_pixel(to_p, paint.color_at(to_p).mix(to.floor() - to))
+                            );
                         }
 
                         first = None;
@@ -0,0 +1,117 @@
+use crate::text_anchor::{HPos, VPos};
+use crate::{BackendColor, BackendCoord, BackendTextStyle, DrawingBackend, DrawingErrorKind};
+
+/// Draw a text rotated by an arbitrary angle.
+///
+/// The text is first rasterized without any rotation, then each pixel of the rotated bounding
+/// box is sampled from the rasterized glyphs, so that the rotated text doesn't have any hole.
+///
+/// - `b`: The backend to draw on
+/// - `text`: The text to draw
+/// - `style`: The style of the text, its transformation and rotation are ignored
+/// - `pos`: The position of the anchor of the text
+/// - `angle`: The clockwise rotation of the text around its anchor, in degrees
+pub fn draw_rotated_text<DB: DrawingBackend, S: BackendTextStyle>(
+    b: &mut DB,
+    text: &str,
+    style: &S,
+    pos: BackendCoord,
+    angle: f64,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let ((min_x, min_y), (max_x, max_y)) = style
+        .layout_box(text)
+        .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+    let (width, height) = (max_x - min_x, max_y - min_y);
+    if width <= 0 || height <= 0 {
+        return Ok(());
+    }
+
+    // The glyph pixels, relative to the upper left corner of the layout box. Some glyphs
+    // slightly overflow the layout box, so the coverage grid is sized after the pixels.
+    let mut pixels = vec![];
+    let mut rgb = style.color().rgb;
+    let rasterized = style.draw(text, (0, 0), |x, y, color| {
+        if color.alpha > 0.0 {
+            pixels.push((x - min_x, y - min_y, color.alpha));
+            rgb = color.rgb;
+        }
+        Ok::<(), DrawingErrorKind<DB::ErrorType>>(())
+    });
+    match rasterized {
+        Ok(result) => result?,
+        Err(font_error) => return Err(DrawingErrorKind::FontError(Box::new(font_error))),
+    }
+    if pixels.is_empty() {
+        return Ok(());
+    }
+
+    let gx0 = pixels.iter().map(|p| p.0).min().unwrap_or(0);
+    let gy0 = pixels.iter().map(|p| p.1).min().unwrap_or(0);
+    let grid_w = pixels.iter().map(|p| p.0).max().unwrap_or(0) - gx0 + 1;
+    let grid_h = pixels.iter().map(|p| p.1).max().unwrap_or(0) - gy0 + 1;
+    let mut coverage = vec![0.0; (grid_w * grid_h) as usize];
+    for (x, y, alpha) in pixels {
+        // The canvas of a glyph may overlap the previous glyph
+        let idx = ((y - gy0) * grid_w + x - gx0) as usize;
+        coverage[idx] = f64::max(coverage[idx], alpha);
+    }
+
+    let ax = match style.anchor().h_pos {
+        HPos::Left => 0,
+        HPos::Right => -width,
+        HPos::Center => -width / 2,
+    } + gx0;
+    let ay = match style.anchor().v_pos {
+        VPos::Top => 0,
+        VPos::Center => -height / 2,
+        VPos::Bottom => -height,
+    } + gy0;
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let rotate = |x: f64, y: f64| (x * cos - y * sin, x * sin + y * cos);
+
+    let corners = [
+        rotate(ax as f64, ay as f64),
+        rotate((ax + grid_w) as f64, ay as f64),
+        rotate(ax as f64, (ay + grid_h) as f64),
+        rotate((ax + grid_w) as f64, (ay + grid_h) as f64),
+    ];
+    let left = corners.iter().map(|c| c.0).fold(f64::MAX, f64::min).floor() as i32;
+    let right = corners.iter().map(|c| c.0).fold(f64::MIN, f64::max).ceil() as i32;
+    let top = corners.iter().map(|c| c.1).fold(f64::MAX, f64::min).floor() as i32;
+    let bottom = corners.iter().map(|c| c.1).fold(f64::MIN, f64::max).ceil() as i32;
+
+    let sample = |x: i32, y: i32| {
+        if x < 0 || x >= grid_w || y < 0 || y >= grid_h {
+            0.0
+        } else {
+            coverage[(y * grid_w + x) as usize]
+        }
+    };
+
+    let (w, h) = b.get_size();
+    for y in top..=bottom {
+        for x in left..=right {
+            let (px, py) = (pos.0 + x, pos.1 + y);
+            if px < 0 || px >= w as i32 || py < 0 || py >= h as i32 {
+                continue;
+            }
+            // Rotate the pixel back into the text, and interpolate the coverage around it
+            let (tx, ty) = (
+                x as f64 * cos + y as f64 * sin - ax as f64,
+                -x as f64 * sin + y as f64 * cos - ay as f64,
+            );
+            let (x0, y0) = (tx.floor(), ty.floor());
+            let (fx, fy) = (tx - x0, ty - y0);
+            let (x0, y0) = (x0 as i32, y0 as i32);
+            let value = sample(x0, y0) * (1.0 - fx) * (1.0 - fy)
+                + sample(x0 + 1, y0) * fx * (1.0 - fy)
+                + sample(x0, y0 + 1) * (1.0 - fx) * fy
+                + sample(x0 + 1, y0 + 1) * fx * fy;
+            if value > 0.0 {
+                b.draw_pixel((px, py), BackendColor { alpha: value, rgb })?;
+            }
+        }
+    }
+
+    Ok(())
+}
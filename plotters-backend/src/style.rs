@@ -1,3 +1,5 @@
+use crate::BackendCoord;
+
 /// The color type that is used by all the backend
 #[derive(Clone, Copy)]
 pub struct BackendColor {
@@ -24,6 +26,12 @@ pub trait BackendStyle {
     fn stroke_width(&self) -> u32 {
         1
     }
+
+    /// Get the dash pattern of current style, as the alternating lengths of the dashes and the
+    /// gaps, following the SVG `stroke-dasharray` convention. Empty for a solid stroke.
+    fn stroke_dash(&self) -> &[f64] {
+        &[]
+    }
 }
 
 impl BackendStyle for BackendColor {
@@ -31,3 +39,86 @@ impl BackendStyle for BackendColor {
         *self
     }
 }
+
+/// The direction of a linear gradient, across the bounding box of the filled shape
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GradientDirection {
+    /// From the top to the bottom of the shape
+    Vertical,
+    /// From the left to the right of the shape
+    Horizontal,
+    /// From the upper left corner to the lower right corner of the shape
+    Diagonal,
+}
+
+/// The linear gradient data for the backend drawing API
+#[derive(Clone)]
+pub struct BackendGradient {
+    /// The direction of the gradient
+    pub direction: GradientDirection,
+    /// The color stops, as positions from 0 to 1 along the gradient, sorted by position
+    pub stops: Vec<(f64, BackendColor)>,
+}
+
+impl BackendGradient {
+    /// Get the color at a position along the gradient, interpolated between the surrounding
+    /// stops. The positions before the first stop or after the last one take its color.
+    ///
+    /// - `pos`: The position along the gradient, from 0 to 1
+    /// - **returns** The interpolated color
+    pub fn color_at(&self, pos: f64) -> BackendColor {
+        let idx = self.stops.iter().take_while(|(p, _)| *p <= pos).count();
+        match (
+            idx.checked_sub(1).map(|i| self.stops[i]),
+            self.stops.get(idx),
+        ) {
+            (Some((p0, c0)), Some(&(p1, c1))) => {
+                let t = (pos - p0) / (p1 - p0);
+                let mix =
+                    |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+                BackendColor {
+                    alpha: c0.alpha + (c1.alpha - c0.alpha) * t,
+                    rgb: (
+                        mix(c0.rgb.0, c1.rgb.0),
+                        mix(c0.rgb.1, c1.rgb.1),
+                        mix(c0.rgb.2, c1.rgb.2),
+                    ),
+                }
+            }
+            (Some((_, c)), None) | (None, Some(&(_, c))) => c,
+            (None, None) => BackendColor {
+                alpha: 0.0,
+                rgb: (0, 0, 0),
+            },
+        }
+    }
+
+    /// Get the position of a point along the gradient, when it spans a bounding box.
+    ///
+    /// - `point`: The point
+    /// - `upper_left`: The upper left corner of the bounding box
+    /// - `bottom_right`: The bottom right corner of the bounding box
+    /// - **returns** The position along the gradient, 0 for the side of the box the gradient
+    ///   starts from. It is 0 all along a direction in which the box is flat.
+    pub fn position(
+        &self,
+        point: BackendCoord,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> f64 {
+        let ratio = |v: i32, from: i32, to: i32| {
+            if to > from {
+                (f64::from(v - from) / f64::from(to - from)).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+        let x = ratio(point.0, upper_left.0, bottom_right.0);
+        let y = ratio(point.1, upper_left.1, bottom_right.1);
+        match self.direction {
+            GradientDirection::Vertical => y,
+            GradientDirection::Horizontal => x,
+            GradientDirection::Diagonal => (x + y) / 2.0,
+        }
+    }
+}
@@ -137,6 +137,44 @@ impl FontTransform {
             FontTransform::Rotate270 => (y, -x),
         }
     }
+
+    /// Get the angle of the rotation performed by the transformation
+    ///
+    /// - **returns**: The clockwise angle in degrees
+    pub fn angle(&self) -> f64 {
+        match self {
+            FontTransform::None => 0.0,
+            FontTransform::Rotate90 => 90.0,
+            FontTransform::Rotate180 => 180.0,
+            FontTransform::Rotate270 => 270.0,
+        }
+    }
+
+    /// Get the size of the bounding box of a text once transformed, then rotated further by an
+    /// arbitrary angle, see [`BackendTextStyle::rotation`]
+    ///
+    /// - `w`: The width of the text before transform
+    /// - `h`: The height of the text before transform
+    /// - `rotation`: The clockwise angle of the further rotation in degrees
+    /// - **returns**: The width and height of the bounding box after transform
+    pub fn transform_size(&self, w: u32, h: u32, rotation: f64) -> (u32, u32) {
+        if rotation % 90.0 == 0.0 {
+            let (w, h) = self.transform(w as i32, h as i32);
+            let (w, h) = (w.unsigned_abs(), h.unsigned_abs());
+            return if rotation % 180.0 == 0.0 {
+                (w, h)
+            } else {
+                (h, w)
+            };
+        }
+        let (sin, cos) = (self.angle() + rotation).to_radians().sin_cos();
+        let (sin, cos) = (sin.abs(), cos.abs());
+        let (w, h) = (f64::from(w), f64::from(h));
+        (
+            (w * cos + h * sin).round() as u32,
+            (w * sin + h * cos).round() as u32,
+        )
+    }
 }
 
 /// Describes the font style. Such as Italic, Oblique, etc.
@@ -176,6 +214,27 @@ impl<'a> From<&'a str> for FontStyle {
     }
 }
 
+/// The measures of a text once rendered, with the vertical metrics of its font.
+///
+/// The ascent, the descent and the line gap are the ones of the font rather than of the glyphs
+/// of the text, thus two texts of the same style share them: they are the distances to keep
+/// between the baselines of the lines of a text block, whatever the lines contain.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextMetrics {
+    /// The width of the horizontal text, in pixels
+    pub width: u32,
+    /// The height of the horizontal text, in pixels
+    pub height: u32,
+    /// The distance from the baseline to the top of the tallest glyphs of the font, in pixels
+    pub ascent: f64,
+    /// The distance from the baseline to the bottom of the lowest glyphs of the font, in pixels.
+    /// It is positive when the glyphs go below the baseline
+    pub descent: f64,
+    /// The extra space the font recommends between the bottom of a line and the top of the next
+    /// one, in pixels
+    pub line_gap: f64,
+}
+
 /// The trait that abstracts a style of a text.
 ///
 /// This is used because the the backend crate have no knowledge about how
@@ -209,6 +268,17 @@ pub trait BackendTextStyle {
         FontTransform::None
     }
 
+    /// Get the rotation of the text, applied on top of its [`FontTransform`].
+    ///
+    /// Unlike the transformation, the rotation may be any angle, e.g. to slant the labels of an
+    /// axis. The default is no rotation, and the backends which can only draw the text along the
+    /// quarter turns ignore it.
+    ///
+    /// - **returns**: The clockwise angle in degrees
+    fn rotation(&self) -> f64 {
+        0.0
+    }
+
     fn style(&self) -> FontStyle {
         FontStyle::Normal
     }
@@ -219,13 +289,68 @@ pub trait BackendTextStyle {
 
     fn family(&self) -> FontFamily;
 
+    /// Get the families of the fonts drawing the characters missing from the font of the text,
+    /// in order. The backends drawing the glyphs themselves ignore them, while the vector
+    /// backends list them after [`BackendTextStyle::family`].
+    fn fallback_families(&self) -> &[FontFamily<'_>] {
+        &[]
+    }
+
     #[allow(clippy::type_complexity)]
     fn layout_box(&self, text: &str) -> Result<((i32, i32), (i32, i32)), Self::FontError>;
 
+    /// Get the ascent, the descent and the line gap of the font in pixels, see [`TextMetrics`].
+    ///
+    /// The default implementation doesn't know the font, and splits the size between an ascent
+    /// of one em and a descent of the rest, with no line gap.
+    fn vertical_metrics(&self) -> Result<(f64, f64, f64), Self::FontError> {
+        let em = self.size() / 1.24;
+        Ok((em, self.size() - em, 0.0))
+    }
+
     fn draw<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(
         &self,
         text: &str,
         pos: BackendCoord,
         draw: DrawFunc,
     ) -> Result<Result<(), E>, Self::FontError>;
+
+    /// Get the layout box of the text once scaled by a factor, as rendered by
+    /// [`BackendTextStyle::draw_scaled`].
+    ///
+    /// The default implementation scales the layout box of the unscaled text.
+    #[allow(clippy::type_complexity)]
+    fn layout_box_scaled(
+        &self,
+        text: &str,
+        scale: f64,
+    ) -> Result<((i32, i32), (i32, i32)), Self::FontError> {
+        let ((x0, y0), (x1, y1)) = self.layout_box(text)?;
+        let s = |v: i32| (f64::from(v) * scale).round() as i32;
+        Ok(((s(x0), s(y0)), (s(x1), s(y1))))
+    }
+
+    /// Draw the text scaled by a factor, e.g. on a high-DPI backend whose pixels are smaller
+    /// than the ones of the layout.
+    ///
+    /// The default implementation draws each pixel of the unscaled text as a block of pixels.
+    /// The styles which can render their font at any size should override it, along with
+    /// [`BackendTextStyle::layout_box_scaled`], to keep the text crisp.
+    fn draw_scaled<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(
+        &self,
+        text: &str,
+        pos: BackendCoord,
+        scale: f64,
+        mut draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::FontError> {
+        let s = |v: i32| (f64::from(v) * scale).round() as i32;
+        self.draw(text, (0, 0), |x, y, color| {
+            for dy in s(y)..s(y + 1) {
+                for dx in s(x)..s(x + 1) {
+                    draw(pos.0 + dx, pos.1 + dy, color)?;
+                }
+            }
+            Ok(())
+        })
+    }
 }
@@ -0,0 +1,51 @@
+use criterion::{criterion_group, Criterion};
+use plotters::prelude::*;
+use plotters_bitmap::BitMapBackend;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const W: u32 = 1920;
+const H: u32 = 1080;
+
+fn draw_frame<DB: DrawingBackend>(root: &DrawingArea<DB, plotters::coord::Shift>) {
+    root.fill(&WHITE).unwrap();
+    for i in 0..10 {
+        root.draw(&Circle::new((i * 190, 540), 80, RED.filled()))
+            .unwrap();
+    }
+}
+
+fn encode_png(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encoding::png_1920x1080");
+
+    group.bench_function("present_to_file", |b| {
+        let path = std::env::temp_dir().join("plotters-bitmap-bench.png");
+        b.iter(|| {
+            let root = BitMapBackend::new(&path, (W, H)).into_drawing_area();
+            draw_frame(&root);
+            root.present().unwrap();
+        })
+    });
+
+    group.bench_function("write_png_reused_buffers", |b| {
+        let mut buffer = vec![0; (W * H * 3) as usize];
+        let mut png = vec![];
+        let backend = Rc::new(RefCell::new(BitMapBackend::with_buffer(
+            &mut buffer,
+            (W, H),
+        )));
+        let root: DrawingArea<_, _> = (&backend).into();
+        b.iter(|| {
+            draw_frame(&root);
+            root.present().unwrap();
+            png.clear();
+            backend.borrow().write_png(&mut png).unwrap();
+        })
+    });
+}
+
+criterion_group! {
+    name = encoding_group;
+    config = Criterion::default().sample_size(10);
+    targets = encode_png
+}
@@ -1,2 +1,3 @@
+pub mod encoding;
 pub mod parallel;
 pub mod rasterizer;
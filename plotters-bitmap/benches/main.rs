@@ -3,6 +3,7 @@ use criterion::criterion_main;
 mod benches;
 
 criterion_main! {
+    benches::encoding::encoding_group,
     benches::parallel::parallel_group,
     benches::rasterizer::rasterizer_group,
 }
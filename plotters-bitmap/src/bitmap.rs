@@ -1,5 +1,6 @@
 use plotters_backend::{
-    BackendColor, BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind,
+    rasterizer::clip_line, BackendColor, BackendCoord, BackendGradient, BackendStyle,
+    BackendTextStyle, DrawingBackend, DrawingErrorKind,
 };
 use std::marker::PhantomData;
 
@@ -8,7 +9,12 @@ use crate::error::BitMapBackendError;
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 mod image_encoding_support {
-    pub(super) use image::{ImageBuffer, Rgb};
+    pub(super) use image::codecs::jpeg::JpegEncoder;
+    pub(super) use image::codecs::png::PngEncoder;
+    #[cfg(feature = "webp")]
+    pub(super) use image::codecs::webp::WebPEncoder;
+    pub(super) use image::{ColorType, ImageBuffer, ImageEncoder, Rgb};
+    pub(super) use std::io::Write;
     pub(super) use std::path::Path;
     pub(super) type BorrowedImage<'a> = ImageBuffer<Rgb<u8>, &'a mut [u8]>;
 }
@@ -16,8 +22,11 @@ mod image_encoding_support {
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 use image_encoding_support::*;
 
+mod anti_alias;
+mod scale;
 mod target;
 
+use scale::{ScaledStyle, ScaledTextStyle};
 use target::{Buffer, Target};
 
 /// The backend that drawing a bitmap
@@ -34,12 +43,18 @@ pub struct BitMapBackend<'a, P: PixelFormat = RGBPixel> {
     /// The path to the image
     #[allow(dead_code)]
     target: Target<'a>,
-    /// The size of the image
+    /// The size of the image, in the pixels of the image rather than the logical ones
     size: (u32, u32),
+    /// The number of pixels of the image per logical pixel of the drawing
+    scale: f64,
     /// The data buffer of the image
     buffer: Buffer<'a>,
     /// Flag indicates if the bitmap has been saved
     saved: bool,
+    /// Flag indicates if the lines and circles should be anti-aliased
+    anti_aliasing: bool,
+    /// The rectangle the drawing is restricted to, the bottom-right corner excluded
+    clip: Option<(BackendCoord, BackendCoord)>,
     _phantomdata: PhantomData<P>,
 }
 
@@ -56,7 +71,10 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
             target: Target::File(path.as_ref()),
             size: (w, h),
             buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
+            scale: 1.0,
             saved: false,
+            anti_aliasing: false,
+            clip: None,
             _phantomdata: PhantomData,
         }
     }
@@ -84,7 +102,10 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
             )?)),
             size: (w, h),
             buffer: Buffer::Owned(vec![0; Self::PIXEL_SIZE * (w * h) as usize]),
+            scale: 1.0,
             saved: false,
+            anti_aliasing: false,
+            clip: None,
             _phantomdata: PhantomData,
         })
     }
@@ -98,12 +119,165 @@ impl<'a> BitMapBackend<'a, RGBPixel> {
     /// uses RGB pixel format and manipulates the in-memory framebuffer.
     /// For more pixel format option, use `with_buffer_and_format` instead.
     ///
+    /// The buffer is only borrowed, thus the backend draws straight into the pixels owned by
+    /// the caller, e.g. the framebuffer of a GUI window. See `with_buffer_and_format` for the
+    /// layout of the buffer. Since there's no file to write, `present` does nothing.
+    ///
+    /// This function panics if the buffer is too small for the image, use
+    /// `with_buffer_and_format` to get an error instead.
+    ///
     /// - `buf`: The buffer to operate
     /// - `dimension`: The size of the image in pixels
     /// - **returns**: The newly created bitmap backend
     pub fn with_buffer(buf: &'a mut [u8], (w, h): (u32, u32)) -> Self {
         Self::with_buffer_and_format(buf, (w, h)).expect("Wrong buffer size")
     }
+
+    /// Encode the current frame as a PNG image and write it to the given writer
+    ///
+    /// The pixels are streamed to the PNG encoder straight from the backend's buffer, without
+    /// building an intermediate image or going through a file. When rendering an animation in
+    /// a tight loop, the same writer (e.g. a `Vec<u8>` cleared between the frames) can be
+    /// reused for all the frames.
+    ///
+    /// To keep an access to the backend while drawing on it, wrap it into a `Rc<RefCell<_>>`
+    /// and build the drawing area from a reference to it:
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// let mut buffer = vec![0; 640 * 480 * 3];
+    /// let mut png = vec![];
+    /// let backend = Rc::new(RefCell::new(BitMapBackend::with_buffer(&mut buffer, (640, 480))));
+    /// let root: DrawingArea<_, _> = (&backend).into();
+    /// for frame in 0..3 {
+    ///     root.fill(&WHITE).unwrap();
+    ///     root.draw(&Circle::new((100 * frame, 240), 50, RED.filled())).unwrap();
+    ///     root.present().unwrap();
+    ///     png.clear();
+    ///     backend.borrow().write_png(&mut png).unwrap();
+    /// }
+    /// ```
+    ///
+    /// - `writer`: Where to write the encoded PNG image
+    /// - **returns**: The result of the encoding
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub fn write_png<W: Write>(&self, writer: W) -> Result<(), BitMapBackendError> {
+        let (w, h) = self.size;
+        PngEncoder::new(writer)
+            .write_image(self.pixel_buffer(), w, h, ColorType::Rgb8)
+            .map_err(BitMapBackendError::ImageError)
+    }
+
+    /// Encode the current frame as a JPEG image and write it to the given writer
+    ///
+    /// Like [`write_png`](Self::write_png), the pixels are streamed to the encoder straight
+    /// from the backend's buffer. The bitmap has no alpha channel, every pixel being already
+    /// blended over what was drawn below it, thus the frame is encoded as it is shown. To encode
+    /// an image with an alpha channel, see [`write_rgba_jpeg`](Self::write_rgba_jpeg). A
+    /// `BitMapBackend::new` with a `.jpg` path also writes a JPEG image on `present`, but with
+    /// the default quality of the encoder.
+    ///
+    /// - `writer`: Where to write the encoded JPEG image
+    /// - `quality`: The quality of the compression, from 1 (smallest) to 100 (best)
+    /// - **returns**: The result of the encoding
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub fn write_jpeg<W: Write>(&self, writer: W, quality: u8) -> Result<(), BitMapBackendError> {
+        let (w, h) = self.size;
+        JpegEncoder::new_with_quality(writer, quality.clamp(1, 100))
+            .write_image(self.pixel_buffer(), w, h, ColorType::Rgb8)
+            .map_err(BitMapBackendError::ImageError)
+    }
+
+    /// Encode the current frame as a JPEG image and save it to a file, see
+    /// [`write_jpeg`](Self::write_jpeg)
+    ///
+    /// - `path`: The path of the file to create
+    /// - `quality`: The quality of the compression, from 1 (smallest) to 100 (best)
+    /// - **returns**: The result of the encoding
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub fn save_jpeg<T: AsRef<Path>>(
+        &self,
+        path: T,
+        quality: u8,
+    ) -> Result<(), BitMapBackendError> {
+        let mut file = std::io::BufWriter::new(
+            std::fs::File::create(path).map_err(BitMapBackendError::IOError)?,
+        );
+        self.write_jpeg(&mut file, quality)?;
+        file.flush().map_err(BitMapBackendError::IOError)
+    }
+
+    /// Encode an RGBA image, such as one blitted onto the bitmap, as a JPEG image and write it
+    /// to the given writer
+    ///
+    /// JPEG can't store an alpha channel, thus the image is composited over the background
+    /// color first, as it would be shown on a page of this color.
+    ///
+    /// - `writer`: Where to write the encoded JPEG image
+    /// - `rgba`: The pixels of the image, row by row, with 4 bytes per pixel
+    /// - `size`: The size of the image in pixels
+    /// - `background`: The opaque color the image is composited over
+    /// - `quality`: The quality of the compression, from 1 (smallest) to 100 (best)
+    /// - **returns**: The result of the encoding, which fails with
+    ///   `BitMapBackendError::InvalidBuffer` if the pixels don't fit the size
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub fn write_rgba_jpeg<W: Write>(
+        writer: W,
+        rgba: &[u8],
+        (w, h): (u32, u32),
+        background: (u8, u8, u8),
+        quality: u8,
+    ) -> Result<(), BitMapBackendError> {
+        if rgba.len() != (w * h) as usize * 4 {
+            return Err(BitMapBackendError::InvalidBuffer);
+        }
+        let background = [background.0, background.1, background.2];
+        let rgb: Vec<u8> = rgba
+            .chunks_exact(4)
+            .flat_map(|pixel| {
+                let alpha = u32::from(pixel[3]);
+                (0..3).map(move |idx| {
+                    let (fg, bg) = (u32::from(pixel[idx]), u32::from(background[idx]));
+                    ((fg * alpha + bg * (255 - alpha) + 127) / 255) as u8
+                })
+            })
+            .collect();
+        JpegEncoder::new_with_quality(writer, quality.clamp(1, 100))
+            .write_image(&rgb, w, h, ColorType::Rgb8)
+            .map_err(BitMapBackendError::ImageError)
+    }
+
+    /// Encode the current frame as a lossless WebP image and write it to the given writer
+    ///
+    /// The encoder is the pure Rust one of the `image` crate, which only supports the lossless
+    /// compression. Like [`write_png`](Self::write_png), the pixels are streamed to the encoder
+    /// straight from the backend's buffer.
+    ///
+    /// - `writer`: Where to write the encoded WebP image
+    /// - **returns**: The result of the encoding
+    #[cfg(all(not(target_arch = "wasm32"), feature = "webp"))]
+    pub fn write_webp<W: Write>(&self, writer: W) -> Result<(), BitMapBackendError> {
+        let (w, h) = self.size;
+        WebPEncoder::new_lossless(writer)
+            .write_image(self.pixel_buffer(), w, h, ColorType::Rgb8)
+            .map_err(BitMapBackendError::ImageError)
+    }
+
+    /// Encode the current frame as a lossless WebP image and save it to a file, see
+    /// [`write_webp`](Self::write_webp)
+    ///
+    /// - `path`: The path of the file to create
+    /// - **returns**: The result of the encoding
+    #[cfg(all(not(target_arch = "wasm32"), feature = "webp"))]
+    pub fn save_webp<T: AsRef<Path>>(&self, path: T) -> Result<(), BitMapBackendError> {
+        let mut file = std::io::BufWriter::new(
+            std::fs::File::create(path).map_err(BitMapBackendError::IOError)?,
+        );
+        self.write_webp(&mut file)?;
+        file.flush().map_err(BitMapBackendError::IOError)
+    }
 }
 
 impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
@@ -112,14 +286,22 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
     /// Note: This can be used as a way to manipulate framebuffer, `mmap` can be used on the top of this
     /// as well.
     ///
+    /// The pixels are stored row by row from the top of the image, and the rows are tightly
+    /// packed: the stride of a row is `width * P::PIXEL_SIZE` bytes, without any padding. Each
+    /// pixel is stored in the byte order of the pixel format, e.g. red, green and blue for
+    /// [`RGBPixel`](crate::bitmap_pixel::RGBPixel). A buffer larger than the image is accepted,
+    /// and the bytes past the end of the image are left untouched. Since there's no file to
+    /// write, `present` does nothing.
+    ///
     /// - `buf`: The buffer to operate
     /// - `dimension`: The size of the image in pixels
-    /// - **returns**: The newly created bitmap backend
+    /// - **returns**: The newly created bitmap backend, or `BitMapBackendError::InvalidBuffer`
+    ///   if the buffer is too small for the image
     pub fn with_buffer_and_format(
         buf: &'a mut [u8],
         (w, h): (u32, u32),
     ) -> Result<Self, BitMapBackendError> {
-        if (w * h) as usize * Self::PIXEL_SIZE > buf.len() {
+        if w as usize * h as usize * Self::PIXEL_SIZE > buf.len() {
             return Err(BitMapBackendError::InvalidBuffer);
         }
 
@@ -127,28 +309,138 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
             target: Target::Buffer(PhantomData),
             size: (w, h),
             buffer: Buffer::Borrowed(buf),
+            scale: 1.0,
             saved: false,
+            anti_aliasing: false,
+            clip: None,
             _phantomdata: PhantomData,
         })
     }
 
+    /// Enable or disable the anti-aliasing of the lines, paths and circles, disabled by default
+    ///
+    /// When enabled, the lines of any slope and width are drawn with smooth edges, blending
+    /// the pixels on the border of the stroke according to how much of them the stroke covers.
+    /// The circles, such as the markers of a scatter plot, are smoothed the same way, filled or
+    /// stroked. This is slower than the default rasterizer, and the axis-aligned lines of 1
+    /// pixel, such as the mesh of a chart, are still drawn crisp.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 100 * 100 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (100, 100))
+    ///     .with_anti_aliasing(true)
+    ///     .into_drawing_area();
+    /// root.fill(&WHITE).unwrap();
+    /// root.draw(&PathElement::new(vec![(10, 10), (90, 40)], BLACK.stroke_width(2)))
+    ///     .unwrap();
+    /// ```
+    ///
+    /// - `enabled`: If the lines and circles should be anti-aliased
+    /// - **returns**: The backend with the new setting
+    pub fn with_anti_aliasing(mut self, enabled: bool) -> Self {
+        self.anti_aliasing = enabled;
+        self
+    }
+
+    /// Scale the drawing by a factor, e.g. 2 to render for a high-DPI display
+    ///
+    /// The size given to the constructor becomes the logical size of the drawing, which keeps
+    /// its layout, while the image has `scale` times more pixels in each direction. The
+    /// coordinates, the line widths, the font sizes and the marker sizes are multiplied by the
+    /// factor, and the text is rasterized at the scaled size, thus it stays crisp.
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let mut buffer = vec![0; 200 * 200 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (100, 100))
+    ///     .scaled(2.0)
+    ///     .into_drawing_area();
+    /// assert_eq!(root.dim_in_pixel(), (100, 100));
+    /// root.fill(&WHITE).unwrap();
+    /// root.draw(&Circle::new((50, 50), 20, RED.filled())).unwrap();
+    /// ```
+    ///
+    /// This function panics if the factor isn't a positive number, or when the backend draws into
+    /// a borrowed buffer which is too small for the scaled image. The size of a GIF animation
+    /// can't change once its first frame has been written.
+    ///
+    /// - `scale`: The number of pixels of the image per logical pixel
+    /// - **returns**: The backend with the new scale factor
+    pub fn scaled(mut self, scale: f64) -> Self {
+        assert!(
+            scale.is_finite() && scale > 0.0,
+            "The scale factor must be a positive number"
+        );
+        let (w, h) = self.get_size();
+        let size = (
+            (f64::from(w) * scale).round() as u32,
+            (f64::from(h) * scale).round() as u32,
+        );
+        let len = size.0 as usize * size.1 as usize * Self::PIXEL_SIZE;
+        match &mut self.buffer {
+            #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+            Buffer::Owned(buf) => *buf = vec![0; len],
+            Buffer::Borrowed(buf) => assert!(
+                buf.len() >= len,
+                "The buffer is too small for the scaled image"
+            ),
+        }
+        #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
+        if let Target::Gif(gif) = &mut self.target {
+            gif.set_size(size);
+        }
+        self.size = size;
+        self.scale = scale;
+        self.clip = None;
+        self
+    }
+
+    /// Get the number of pixels of the image per logical pixel, see [`scaled`](Self::scaled)
+    pub fn scale_factor(&self) -> f64 {
+        self.scale
+    }
+
+    /// Get the size of the image in its own pixels, which is the size of the drawing multiplied
+    /// by the scale factor
+    pub fn pixel_size(&self) -> (u32, u32) {
+        self.size
+    }
+
     #[inline(always)]
     pub(crate) fn get_raw_pixel_buffer(&mut self) -> &mut [u8] {
         self.buffer.borrow_buffer()
     }
 
+    /// Get the pixels drawn so far, row by row, in the pixel format of the backend
+    ///
+    /// This gives a read-only access to the frame without copying it, for example to hand it
+    /// to a custom encoder.
+    ///
+    /// - **returns**: The `width * height * P::PIXEL_SIZE` bytes of the frame
+    pub fn pixel_buffer(&self) -> &[u8] {
+        let (w, h) = self.size;
+        &self.buffer.as_slice()[..(w * h) as usize * Self::PIXEL_SIZE]
+    }
+
     /// Split a bitmap backend vertically into several sub drawing area which allows
     /// multi-threading rendering.
     ///
-    /// - `area_size`: The size of the area
+    /// The split backends keep the scale factor of this backend.
+    ///
+    /// - `area_size`: The size of the area, in logical pixels
     /// - **returns**: The split backends that can be rendered in parallel
     pub fn split(&mut self, area_size: &[u32]) -> Vec<BitMapBackend<P>> {
-        let (w, h) = self.get_size();
+        let (w, h) = self.size;
+        let scale = self.scale;
         let buf = self.get_raw_pixel_buffer();
 
         let base_addr = &mut buf[0] as *mut u8;
         let mut split_points = vec![0];
         for size in area_size {
+            let size = (f64::from(*size) * scale).round() as u32;
             let next = split_points.last().unwrap() + size;
             if next >= h {
                 break;
@@ -167,60 +459,143 @@ impl<'a, P: PixelFormat> BitMapBackend<'a, P> {
                         ((end - begin) * w) as usize * Self::PIXEL_SIZE,
                     )
                 };
-                Self::with_buffer_and_format(actual_buf, (w, end - begin)).unwrap()
+                let mut part = Self::with_buffer_and_format(actual_buf, (w, end - begin)).unwrap();
+                part.scale = scale;
+                part
             })
             .collect()
     }
-}
 
-impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
-    type ErrorType = BitMapBackendError;
+    // Map a logical coordinate to the pixels of the image
+    fn scale_coord(&self, (x, y): BackendCoord) -> BackendCoord {
+        if self.scale == 1.0 {
+            return (x, y);
+        }
+        (
+            (f64::from(x) * self.scale).round() as i32,
+            (f64::from(y) * self.scale).round() as i32,
+        )
+    }
 
-    fn get_size(&self) -> (u32, u32) {
-        self.size
+    // The clip rectangle, or the whole bitmap, the bottom-right corner excluded
+    fn visible_rect(&self) -> (BackendCoord, BackendCoord) {
+        let (w, h) = self.size;
+        self.clip.unwrap_or(((0, 0), (w as i32, h as i32)))
     }
 
-    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
-        self.saved = false;
-        Ok(())
+    // Intersect a rectangle, the bottom-right corner excluded, with the bitmap and the clip
+    // rectangle. Returns `None` when nothing is left to draw.
+    fn clip_rect(
+        &self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+    ) -> Option<(BackendCoord, BackendCoord)> {
+        let ((cx0, cy0), (cx1, cy1)) = self.visible_rect();
+        let (x0, y0) = (
+            upper_left.0.min(bottom_right.0).max(cx0),
+            upper_left.1.min(bottom_right.1).max(cy0),
+        );
+        let (x1, y1) = (
+            upper_left.0.max(bottom_right.0).min(cx1),
+            upper_left.1.max(bottom_right.1).min(cy1),
+        );
+        if x0 >= x1 || y0 >= y1 {
+            return None;
+        }
+        Some(((x0, y0), (x1, y1)))
     }
+}
 
-    #[cfg(any(target_arch = "wasm32", not(feature = "image")))]
-    fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
-        Ok(())
+/// The view of a [`BitMapBackend`] in the pixels of the image, which the scaled drawing
+/// operations, and the rasterizers they fall back to, draw into
+struct Physical<'b, 'a, P: PixelFormat>(&'b mut BitMapBackend<'a, P>);
+
+impl<P: PixelFormat> Physical<'_, '_, P> {
+    // Cut a line where it leaves the visible rectangle before it's rasterized, with a margin of
+    // the stroke width so that the cut itself is out of sight
+    fn clip_visible(
+        &self,
+        from: BackendCoord,
+        to: BackendCoord,
+        width: u32,
+    ) -> Option<(BackendCoord, BackendCoord)> {
+        let margin = width as i32 + 1;
+        let ((x0, y0), (x1, y1)) = self.0.visible_rect();
+        clip_line(
+            from,
+            to,
+            ((x0 - margin, y0 - margin), (x1 + margin, y1 + margin)),
+        )
     }
 
-    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
-    fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
-        if !P::can_be_saved() {
-            return Ok(());
+    // Draw an anti-aliased polyline ignoring the dash pattern of the style
+    fn draw_anti_aliased_path<S: BackendStyle>(
+        &mut self,
+        path: &[BackendCoord],
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        let segments: Vec<_> = path
+            .windows(2)
+            .filter_map(|segment| self.clip_visible(segment[0], segment[1], style.stroke_width()))
+            .collect();
+        anti_alias::draw_path(self.0, &segments, style.color(), style.stroke_width())
+    }
+
+    // Draw a line ignoring the dash pattern of the style
+    fn draw_solid_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        let alpha = style.color().alpha;
+        let (r, g, b) = style.color().rgb;
+
+        let (from, to) = match self.clip_visible(from, to, style.stroke_width()) {
+            Some(line) => line,
+            None => return Ok(()),
+        };
+
+        let axis_aligned = (from.0 == to.0 || from.1 == to.1) && style.stroke_width() == 1;
+        if self.0.anti_aliasing && !axis_aligned {
+            return anti_alias::draw_line(self.0, from, to, style.color(), style.stroke_width());
         }
-        let (w, h) = self.get_size();
-        match &mut self.target {
-            Target::File(path) => {
-                if let Some(img) = BorrowedImage::from_raw(w, h, self.buffer.borrow_buffer()) {
-                    img.save(&path).map_err(|x| {
-                        DrawingErrorKind::DrawingError(BitMapBackendError::ImageError(x))
-                    })?;
-                    self.saved = true;
-                    Ok(())
+
+        if axis_aligned {
+            if alpha >= 1.0 {
+                if from.1 == to.1 {
+                    if let Some((a, b_)) = self.0.clip_rect(from, (to.0 + 1, to.1 + 1)) {
+                        P::fill_rect_fast(self.0, a, b_, r, g, b);
+                    }
                 } else {
-                    Err(DrawingErrorKind::DrawingError(
-                        BitMapBackendError::InvalidBuffer,
-                    ))
+                    let ys = (from.1.min(to.1), from.1.max(to.1) + 1);
+                    if let Some((a, b_)) = self.0.clip_rect((from.0, ys.0), (from.0 + 1, ys.1)) {
+                        P::fill_vertical_line_fast(self.0, a.0, (a.1, b_.1 - 1), r, g, b);
+                    }
                 }
+            } else if let Some((a, b_)) = self.0.clip_rect(from, (to.0 + 1, to.1 + 1)) {
+                P::blend_rect_fast(self.0, a, b_, r, g, b, alpha);
             }
-            Target::Buffer(_) => Ok(()),
-
-            #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
-            Target::Gif(target) => {
-                target
-                    .flush_frame(self.buffer.borrow_buffer())
-                    .map_err(DrawingErrorKind::DrawingError)?;
-                self.saved = true;
-                Ok(())
-            }
+            return Ok(());
         }
+
+        plotters_backend::rasterizer::draw_line(self, from, to, style)
+    }
+}
+
+impl<P: PixelFormat> DrawingBackend for Physical<'_, '_, P> {
+    type ErrorType = BitMapBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.0.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        Ok(())
     }
 
     fn draw_pixel(
@@ -230,16 +605,21 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
     ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
         if point.0 < 0
             || point.1 < 0
-            || point.0 as u32 >= self.size.0
-            || point.1 as u32 >= self.size.1
+            || point.0 as u32 >= self.0.size.0
+            || point.1 as u32 >= self.0.size.1
         {
             return Ok(());
         }
+        if let Some(((x0, y0), (x1, y1))) = self.0.clip {
+            if point.0 < x0 || point.1 < y0 || point.0 >= x1 || point.1 >= y1 {
+                return Ok(());
+            }
+        }
 
         let alpha = color.alpha;
         let rgb = color.rgb;
 
-        P::draw_pixel(self, point, rgb, alpha);
+        P::draw_pixel(self.0, point, rgb, alpha);
 
         Ok(())
     }
@@ -250,23 +630,58 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         to: (i32, i32),
         style: &S,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        let alpha = style.color().alpha;
-        let (r, g, b) = style.color().rgb;
+        if !style.stroke_dash().is_empty() {
+            return self.draw_path([from, to].iter().copied(), style);
+        }
+        self.draw_solid_line(from, to, style)
+    }
 
-        if (from.0 == to.0 || from.1 == to.1) && style.stroke_width() == 1 {
-            if alpha >= 1.0 {
-                if from.1 == to.1 {
-                    P::fill_rect_fast(self, from, (to.0 + 1, to.1 + 1), r, g, b);
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+
+        if !style.stroke_dash().is_empty() {
+            let p: Vec<_> = path.into_iter().collect();
+            for dash in plotters_backend::rasterizer::dash_path(&p[..], style.stroke_dash()) {
+                if self.0.anti_aliasing {
+                    self.draw_anti_aliased_path(&dash[..], style)?;
+                } else if style.stroke_width() == 1 {
+                    for segment in dash.windows(2) {
+                        self.draw_solid_line(segment[0], segment[1], style)?;
+                    }
                 } else {
-                    P::fill_vertical_line_fast(self, from.0, (from.1, to.1), r, g, b);
+                    let v =
+                        plotters_backend::rasterizer::polygonize(&dash[..], style.stroke_width());
+                    self.fill_polygon(v, &style.color())?;
                 }
-            } else {
-                P::blend_rect_fast(self, from, (to.0 + 1, to.1 + 1), r, g, b, alpha);
             }
             return Ok(());
         }
 
-        plotters_backend::rasterizer::draw_line(self, from, to, style)
+        if self.0.anti_aliasing {
+            let p: Vec<_> = path.into_iter().collect();
+            return self.draw_anti_aliased_path(&p[..], style);
+        }
+
+        if style.stroke_width() == 1 {
+            let mut begin: Option<BackendCoord> = None;
+            for end in path.into_iter() {
+                if let Some(begin) = begin {
+                    self.draw_line(begin, end, style)?;
+                }
+                begin = Some(end);
+            }
+            Ok(())
+        } else {
+            let p: Vec<_> = path.into_iter().collect();
+            let v = plotters_backend::rasterizer::polygonize(&p[..], style.stroke_width());
+            self.fill_polygon(v, &style.color())
+        }
     }
 
     fn draw_rect<S: BackendStyle>(
@@ -279,44 +694,92 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
         let alpha = style.color().alpha;
         let (r, g, b) = style.color().rgb;
         if fill {
-            if alpha >= 1.0 {
-                P::fill_rect_fast(self, upper_left, bottom_right, r, g, b);
-            } else {
-                P::blend_rect_fast(self, upper_left, bottom_right, r, g, b, alpha);
+            if let Some((upper_left, bottom_right)) = self.0.clip_rect(upper_left, bottom_right) {
+                if alpha >= 1.0 {
+                    P::fill_rect_fast(self.0, upper_left, bottom_right, r, g, b);
+                } else {
+                    P::blend_rect_fast(self.0, upper_left, bottom_right, r, g, b, alpha);
+                }
             }
             return Ok(());
         }
+        if !style.stroke_dash().is_empty() {
+            // The border is a single closed path, so that the dashes run on around the corners
+            let (x0, y0) = upper_left;
+            let (x1, y1) = bottom_right;
+            return self.draw_path([(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)], style);
+        }
         plotters_backend::rasterizer::draw_rect(self, upper_left, bottom_right, style, fill)
     }
 
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        // The circles entirely out of the visible rectangle, e.g. the markers of the data out of
+        // a chart, are skipped rather than rasterized pixel by pixel
+        let ((x0, y0), (x1, y1)) = self.0.visible_rect();
+        let r = radius as i32 + style.stroke_width() as i32;
+        if center.0 + r < x0 || center.0 - r >= x1 || center.1 + r < y0 || center.1 - r >= y1 {
+            return Ok(());
+        }
+        if !fill && !style.stroke_dash().is_empty() {
+            // A dashed outline is drawn as a closed polygon close enough to the circle, with about
+            // one vertex per pixel of the perimeter
+            let count = ((2.0 * std::f64::consts::PI * f64::from(radius)).ceil() as usize).max(8);
+            let outline: Vec<_> = (0..=count)
+                .map(|i| {
+                    let angle = 2.0 * std::f64::consts::PI * i as f64 / count as f64;
+                    (
+                        center.0 + (f64::from(radius) * angle.cos()).round() as i32,
+                        center.1 + (f64::from(radius) * angle.sin()).round() as i32,
+                    )
+                })
+                .collect();
+            return self.draw_path(outline, style);
+        }
+        if self.0.anti_aliasing {
+            return anti_alias::draw_circle(
+                self.0,
+                center,
+                radius,
+                style.color(),
+                style.stroke_width(),
+                fill,
+            );
+        }
+        plotters_backend::rasterizer::draw_circle(self, center, radius, style, fill)
+    }
+
     fn blit_bitmap(
         &mut self,
         pos: BackendCoord,
         (sw, sh): (u32, u32),
         src: &[u8],
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        let (dw, dh) = self.get_size();
+        let (dw, _) = self.get_size();
 
-        let (x0, y0) = pos;
-        let (x1, y1) = (x0 + sw as i32, y0 + sh as i32);
-
-        let (x0, y0, x1, y1) = (x0.max(0), y0.max(0), x1.min(dw as i32), y1.min(dh as i32));
-
-        if x0 == x1 || y0 == y1 {
-            return Ok(());
-        }
+        let ((x0, y0), (x1, y1)) = match self
+            .0
+            .clip_rect(pos, (pos.0 + sw as i32, pos.1 + sh as i32))
+        {
+            Some(rect) => rect,
+            None => return Ok(()),
+        };
 
         let mut chunk_size = (x1 - x0) as usize;
         let mut num_chunks = (y1 - y0) as usize;
         let dst_gap = dw as usize - chunk_size;
         let src_gap = sw as usize - chunk_size;
 
-        let dst_start = Self::PIXEL_SIZE * (y0 as usize * dw as usize + x0 as usize);
+        let dst_start = P::PIXEL_SIZE * (y0 as usize * dw as usize + x0 as usize);
 
-        let mut dst = &mut self.get_raw_pixel_buffer()[dst_start..];
+        let mut dst = &mut self.0.get_raw_pixel_buffer()[dst_start..];
 
-        let src_start =
-            Self::PIXEL_SIZE * ((sh as i32 + y0 - y1) * sw as i32 + (sw as i32 + x0 - x1)) as usize;
+        let src_start = P::PIXEL_SIZE * ((y0 - pos.1) * sw as i32 + (x0 - pos.0)) as usize;
         let mut src = &src[src_start..];
 
         if src_gap == 0 && dst_gap == 0 {
@@ -324,11 +787,11 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
             num_chunks = 1;
         }
         for i in 0..num_chunks {
-            dst[0..(chunk_size * Self::PIXEL_SIZE)]
-                .copy_from_slice(&src[0..(chunk_size * Self::PIXEL_SIZE)]);
+            dst[0..(chunk_size * P::PIXEL_SIZE)]
+                .copy_from_slice(&src[0..(chunk_size * P::PIXEL_SIZE)]);
             if i != num_chunks - 1 {
-                dst = &mut dst[((chunk_size + dst_gap) * Self::PIXEL_SIZE)..];
-                src = &src[((chunk_size + src_gap) * Self::PIXEL_SIZE)..];
+                dst = &mut dst[((chunk_size + dst_gap) * P::PIXEL_SIZE)..];
+                src = &src[((chunk_size + src_gap) * P::PIXEL_SIZE)..];
             }
         }
 
@@ -336,6 +799,214 @@ impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
     }
 }
 
+impl<'a, P: PixelFormat> DrawingBackend for BitMapBackend<'a, P> {
+    type ErrorType = BitMapBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        (
+            (f64::from(self.size.0) / self.scale).round() as u32,
+            (f64::from(self.size.1) / self.scale).round() as u32,
+        )
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        self.saved = false;
+        Ok(())
+    }
+
+    #[cfg(any(target_arch = "wasm32", not(feature = "image")))]
+    fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        Ok(())
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    fn present(&mut self) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        if !P::can_be_saved() {
+            return Ok(());
+        }
+        let (w, h) = self.size;
+        match &mut self.target {
+            Target::File(path) => {
+                if let Some(img) = BorrowedImage::from_raw(w, h, self.buffer.borrow_buffer()) {
+                    img.save(&path).map_err(|x| {
+                        DrawingErrorKind::DrawingError(BitMapBackendError::ImageError(x))
+                    })?;
+                    self.saved = true;
+                    Ok(())
+                } else {
+                    Err(DrawingErrorKind::DrawingError(
+                        BitMapBackendError::InvalidBuffer,
+                    ))
+                }
+            }
+            Target::Buffer(_) => Ok(()),
+
+            #[cfg(all(feature = "gif", not(target_arch = "wasm32"), feature = "image"))]
+            Target::Gif(target) => {
+                target
+                    .flush_frame(self.buffer.borrow_buffer())
+                    .map_err(DrawingErrorKind::DrawingError)?;
+                self.saved = true;
+                Ok(())
+            }
+        }
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+        if self.scale == 1.0 {
+            return Physical(self).draw_pixel(point, color);
+        }
+        let (upper_left, bottom_right) = (
+            self.scale_coord(point),
+            self.scale_coord((point.0 + 1, point.1 + 1)),
+        );
+        Physical(self).draw_rect(upper_left, bottom_right, &color, true)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (from, to) = (self.scale_coord(from), self.scale_coord(to));
+        let style = ScaledStyle::new(style, self.scale);
+        Physical(self).draw_line(from, to, &style)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let path: Vec<_> = path.into_iter().map(|p| self.scale_coord(p)).collect();
+        let style = ScaledStyle::new(style, self.scale);
+        Physical(self).draw_path(path, &style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (upper_left, bottom_right) =
+            (self.scale_coord(upper_left), self.scale_coord(bottom_right));
+        let style = ScaledStyle::new(style, self.scale);
+        Physical(self).draw_rect(upper_left, bottom_right, &style, fill)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let center = self.scale_coord(center);
+        let radius = (f64::from(radius) * self.scale).round() as u32;
+        let style = ScaledStyle::new(style, self.scale);
+        Physical(self).draw_circle(center, radius, &style, fill)
+    }
+
+    // The ellipses and the arcs are tessellated in physical pixels, to stay smooth when scaled
+    fn draw_ellipse<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radii: (f64, f64),
+        rotation: f64,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let center = self.scale_coord(center);
+        let radii = (radii.0 * self.scale, radii.1 * self.scale);
+        let style = ScaledStyle::new(style, self.scale);
+        Physical(self).draw_ellipse(center, radii, rotation, &style, fill)
+    }
+
+    fn draw_arc<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        angles: (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let center = self.scale_coord(center);
+        let radius = (f64::from(radius) * self.scale).round() as u32;
+        let style = ScaledStyle::new(style, self.scale);
+        Physical(self).draw_arc(center, radius, angles, &style)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().map(|p| self.scale_coord(p)).collect();
+        Physical(self).fill_polygon(vert, style)
+    }
+
+    fn fill_polygon_gradient<I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        gradient: &BackendGradient,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().map(|p| self.scale_coord(p)).collect();
+        Physical(self).fill_polygon_gradient(vert, gradient)
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if self.scale == 1.0 {
+            return Physical(self).draw_text(text, style, pos);
+        }
+        let pos = self.scale_coord(pos);
+        let style = ScaledTextStyle::new(style, self.scale);
+        Physical(self).draw_text(text, &style, pos)
+    }
+
+    fn set_clip_rect(&mut self, clip: Option<(BackendCoord, BackendCoord)>) {
+        let clip = clip.map(|(a, b)| (self.scale_coord(a), self.scale_coord(b)));
+        self.clip = clip.map(|(a, b)| ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1))));
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (sw, sh): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if self.scale == 1.0 {
+            return Physical(self).blit_bitmap(pos, (sw, sh), src);
+        }
+        // Each pixel of the source is enlarged, picking the nearest one for each target pixel
+        let (dw, dh) = (
+            (f64::from(sw) * self.scale).round() as u32,
+            (f64::from(sh) * self.scale).round() as u32,
+        );
+        let mut scaled = Vec::with_capacity(dw as usize * dh as usize * Self::PIXEL_SIZE);
+        for y in 0..dh {
+            let sy = ((f64::from(y) / self.scale) as u32).min(sh - 1);
+            for x in 0..dw {
+                let sx = ((f64::from(x) / self.scale) as u32).min(sw - 1);
+                let start = (sy * sw + sx) as usize * Self::PIXEL_SIZE;
+                scaled.extend_from_slice(&src[start..start + Self::PIXEL_SIZE]);
+            }
+        }
+        let pos = self.scale_coord(pos);
+        Physical(self).blit_bitmap(pos, (dw, dh), &scaled)
+    }
+}
+
 impl<P: PixelFormat> Drop for BitMapBackend<'_, P> {
     fn drop(&mut self) {
         if !self.saved {
@@ -0,0 +1,226 @@
+use std::collections::BTreeMap;
+
+use plotters_backend::{BackendColor, BackendCoord, DrawingBackend, DrawingErrorKind};
+
+use super::{BitMapBackend, Physical};
+use crate::bitmap_pixel::PixelFormat;
+use crate::error::BitMapBackendError;
+
+// Blend a pixel of the image, the coordinates being in the pixels of the image even when the
+// backend is scaled
+fn blend_pixel<P: PixelFormat>(
+    back: &mut BitMapBackend<P>,
+    point: BackendCoord,
+    color: BackendColor,
+) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+    Physical(back).draw_pixel(point, color)
+}
+
+/// Draw an anti-aliased line with round caps.
+///
+/// Each pixel near the line is blended with the color weighted by the part of the pixel the
+/// stroke covers, estimated from the distance between the center of the pixel and the segment.
+/// Only the pixels within reach of the stroke are visited, walking along the major axis of the
+/// line, so the cost is proportional to the length of the line times its width.
+pub(super) fn draw_line<P: PixelFormat>(
+    back: &mut BitMapBackend<P>,
+    from: BackendCoord,
+    to: BackendCoord,
+    color: BackendColor,
+    width: u32,
+) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+    if color.alpha == 0.0 || width == 0 {
+        return Ok(());
+    }
+
+    let mut pixels = vec![];
+    line_coverage(back.pixel_size(), from, to, width, |x, y, c| {
+        pixels.push((x, y, c))
+    });
+    for (x, y, c) in pixels {
+        blend_pixel(back, (x, y), color.mix(c))?;
+    }
+
+    Ok(())
+}
+
+/// Draw the anti-aliased segments of a path with round joints.
+///
+/// The segments are rasterized as the lines of [`draw_line`], but the coverage of a pixel is the
+/// highest coverage it gets from any of the segments, so the pixels around a joint, which are
+/// reached by both of its segments, are blended only once. Thus the joints of a translucent path
+/// aren't darker than the rest of it.
+pub(super) fn draw_path<P: PixelFormat>(
+    back: &mut BitMapBackend<P>,
+    segments: &[(BackendCoord, BackendCoord)],
+    color: BackendColor,
+    width: u32,
+) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+    if color.alpha == 0.0 || width == 0 {
+        return Ok(());
+    }
+
+    let size = back.pixel_size();
+    let mut pixels: BTreeMap<(i32, i32), f64> = BTreeMap::new();
+    for &(from, to) in segments {
+        line_coverage(size, from, to, width, |x, y, c| {
+            let coverage = pixels.entry((y, x)).or_insert(0.0);
+            *coverage = coverage.max(c);
+        });
+    }
+    for ((y, x), c) in pixels {
+        blend_pixel(back, (x, y), color.mix(c))?;
+    }
+
+    Ok(())
+}
+
+// Visit the pixels of the image covered by a line with round caps, with their coverage
+fn line_coverage(
+    (w, h): (u32, u32),
+    from: BackendCoord,
+    to: BackendCoord,
+    width: u32,
+    mut visit: impl FnMut(i32, i32, f64),
+) {
+    let radius = f64::from(width) / 2.0;
+    let reach = radius + 0.5;
+
+    let (x0, y0) = (f64::from(from.0), f64::from(from.1));
+    let (dx, dy) = (f64::from(to.0 - from.0), f64::from(to.1 - from.1));
+    let len2 = dx * dx + dy * dy;
+
+    let coverage = |x: i32, y: i32| {
+        let (px, py) = (f64::from(x) - x0, f64::from(y) - y0);
+        let t = if len2 > 0.0 {
+            ((px * dx + py * dy) / len2).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let distance = (px - t * dx).hypot(py - t * dy);
+        (reach - distance).clamp(0.0, 1.0)
+    };
+
+    // Walk along the major axis, and across it only as far as the stroke can reach
+    let steep = dy.abs() > dx.abs();
+    let (major_from, major_to, minor_size, major_size) = if steep {
+        (from.1.min(to.1), from.1.max(to.1), w, h)
+    } else {
+        (from.0.min(to.0), from.0.max(to.0), h, w)
+    };
+    let (major_delta, minor_delta, minor_origin, major_origin) = if steep {
+        (dy, dx, x0, y0)
+    } else {
+        (dx, dy, y0, x0)
+    };
+    let slope = if major_delta != 0.0 {
+        minor_delta / major_delta
+    } else {
+        0.0
+    };
+    let half_span = reach * (1.0 + slope * slope).sqrt();
+    let margin = reach.ceil() as i32;
+
+    let major_range = (major_from - margin).max(0)..=(major_to + margin).min(major_size as i32 - 1);
+    for major in major_range {
+        let along = (f64::from(major) - major_origin).clamp(
+            f64::from(major_from) - major_origin,
+            f64::from(major_to) - major_origin,
+        );
+        let center = minor_origin + along * slope;
+        let minor_start = ((center - half_span).floor() as i32).max(0);
+        let minor_end = ((center + half_span).ceil() as i32).min(minor_size as i32 - 1);
+        for minor in minor_start..=minor_end {
+            let (x, y) = if steep {
+                (minor, major)
+            } else {
+                (major, minor)
+            };
+            let c = coverage(x, y);
+            if c > 0.0 {
+                visit(x, y, c);
+            }
+        }
+    }
+}
+
+/// Draw an anti-aliased circle, either filled or stroked.
+///
+/// The coverage of a pixel is estimated from the distance between its center and the center of
+/// the circle, the pixels on the border of the disc or of the ring of the stroke being blended
+/// with the color weighted by their coverage. Each pixel is blended once, thus the alpha of a
+/// translucent color is respected, and the fully covered runs of the filled circles are filled
+/// at once.
+pub(super) fn draw_circle<P: PixelFormat>(
+    back: &mut BitMapBackend<P>,
+    center: BackendCoord,
+    radius: u32,
+    color: BackendColor,
+    width: u32,
+    fill: bool,
+) -> Result<(), DrawingErrorKind<BitMapBackendError>> {
+    if color.alpha == 0.0 || (!fill && width == 0) {
+        return Ok(());
+    }
+
+    let (w, h) = back.pixel_size();
+    let radius = f64::from(radius);
+    // The stroke is centered on the circle
+    let (outer, inner) = if fill {
+        (radius, None)
+    } else {
+        let half_width = f64::from(width) / 2.0;
+        let inner = radius - half_width;
+        (
+            radius + half_width,
+            Some(inner).filter(|&inner| inner > 0.0),
+        )
+    };
+    let coverage = |distance: f64| {
+        let inside_outer = (outer + 0.5 - distance).clamp(0.0, 1.0);
+        let inside_inner = inner.map_or(0.0, |inner| (inner + 0.5 - distance).clamp(0.0, 1.0));
+        inside_outer - inside_inner
+    };
+
+    let reach = outer + 0.5;
+    let margin = reach.ceil() as i32;
+    let (cx, cy) = (f64::from(center.0), f64::from(center.1));
+    let rows = (center.1 - margin).max(0)..=(center.1 + margin).min(h as i32 - 1);
+    for y in rows {
+        let dy = f64::from(y) - cy;
+        if dy.abs() > reach {
+            continue;
+        }
+        let half_span = (reach * reach - dy * dy).sqrt();
+        let start = ((cx - half_span).floor() as i32).max(0);
+        let end = ((cx + half_span).ceil() as i32).min(w as i32 - 1);
+
+        // The run of the pixels entirely inside a filled circle
+        let full = match outer - 0.5 {
+            full_reach if fill && full_reach >= dy.abs() => {
+                let half_full = (full_reach * full_reach - dy * dy).sqrt();
+                Some((
+                    (cx - half_full).ceil() as i32,
+                    (cx + half_full).floor() as i32,
+                ))
+            }
+            _ => None,
+        };
+        if let Some((full_start, full_end)) = full {
+            Physical(back).draw_rect((full_start, y), (full_end + 1, y + 1), &color, true)?;
+        }
+
+        for x in start..=end {
+            if matches!(full, Some((full_start, full_end)) if (full_start..=full_end).contains(&x))
+            {
+                continue;
+            }
+            let c = coverage((f64::from(x) - cx).hypot(dy));
+            if c > 0.0 {
+                blend_pixel(back, (x, y), color.mix(c))?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use plotters_backend::{
+    text_anchor, BackendColor, BackendCoord, BackendStyle, BackendTextStyle, FontFamily, FontStyle,
+    FontTransform,
+};
+
+/// A style whose stroke is enlarged by the scale factor of the backend
+pub(super) struct ScaledStyle<'s, S: BackendStyle> {
+    style: &'s S,
+    width: u32,
+    dash: Vec<f64>,
+}
+
+impl<'s, S: BackendStyle> ScaledStyle<'s, S> {
+    pub(super) fn new(style: &'s S, scale: f64) -> Self {
+        Self {
+            style,
+            width: ((f64::from(style.stroke_width()) * scale).round() as u32).max(1),
+            dash: style.stroke_dash().iter().map(|len| len * scale).collect(),
+        }
+    }
+}
+
+impl<S: BackendStyle> BackendStyle for ScaledStyle<'_, S> {
+    fn color(&self) -> BackendColor {
+        self.style.color()
+    }
+
+    fn stroke_width(&self) -> u32 {
+        self.width
+    }
+
+    fn stroke_dash(&self) -> &[f64] {
+        &self.dash
+    }
+}
+
+/// A text style whose font is rasterized at the size multiplied by the scale factor of the
+/// backend
+pub(super) struct ScaledTextStyle<'s, S: BackendTextStyle> {
+    style: &'s S,
+    scale: f64,
+}
+
+impl<'s, S: BackendTextStyle> ScaledTextStyle<'s, S> {
+    pub(super) fn new(style: &'s S, scale: f64) -> Self {
+        Self { style, scale }
+    }
+}
+
+impl<S: BackendTextStyle> BackendTextStyle for ScaledTextStyle<'_, S> {
+    type FontError = S::FontError;
+
+    fn color(&self) -> BackendColor {
+        self.style.color()
+    }
+
+    fn size(&self) -> f64 {
+        self.style.size() * self.scale
+    }
+
+    fn transform(&self) -> FontTransform {
+        self.style.transform()
+    }
+
+    fn rotation(&self) -> f64 {
+        self.style.rotation()
+    }
+
+    fn style(&self) -> FontStyle {
+        self.style.style()
+    }
+
+    fn anchor(&self) -> text_anchor::Pos {
+        self.style.anchor()
+    }
+
+    fn family(&self) -> FontFamily<'_> {
+        self.style.family()
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn layout_box(&self, text: &str) -> Result<((i32, i32), (i32, i32)), Self::FontError> {
+        self.style.layout_box_scaled(text, self.scale)
+    }
+
+    fn vertical_metrics(&self) -> Result<(f64, f64, f64), Self::FontError> {
+        let (ascent, descent, line_gap) = self.style.vertical_metrics()?;
+        Ok((
+            ascent * self.scale,
+            descent * self.scale,
+            line_gap * self.scale,
+        ))
+    }
+
+    fn draw<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(
+        &self,
+        text: &str,
+        pos: BackendCoord,
+        draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::FontError> {
+        self.style.draw_scaled(text, pos, self.scale, draw)
+    }
+}
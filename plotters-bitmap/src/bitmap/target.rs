@@ -27,4 +27,13 @@ impl<'a> Buffer<'a> {
             Buffer::Borrowed(buf) => buf,
         }
     }
+
+    #[inline(always)]
+    pub(super) fn as_slice(&self) -> &[u8] {
+        match self {
+            #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+            Buffer::Owned(buf) => &buf[..],
+            Buffer::Borrowed(buf) => buf,
+        }
+    }
 }
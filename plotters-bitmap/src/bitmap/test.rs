@@ -28,6 +28,38 @@ fn test_bitmap_backend() {
     assert!(buffer.into_iter().all(|x| x == 255));
 }
 
+#[test]
+fn test_borrowed_buffer_slice() {
+    use crate::{bitmap_pixel::RGBPixel, BitMapBackend};
+    use plotters::prelude::{Color, BLACK, RED};
+
+    // The image lives in the middle of a buffer owned by the caller
+    let mut framebuffer = [7u8; 4 + 4 * 3 * 3 + 4];
+
+    {
+        let mut back = BitMapBackend::with_buffer(&mut framebuffer[4..], (4, 3));
+        back.draw_pixel((1, 2), BLACK.to_backend_color()).unwrap();
+        back.draw_pixel((3, 0), RED.to_backend_color()).unwrap();
+        // There's no file to write, so presenting the frame does nothing
+        back.present().unwrap();
+        back.present().unwrap();
+    }
+
+    // The rows are tightly packed RGB pixels
+    assert_eq!(&framebuffer[4 + (2 * 4 + 1) * 3..][..3], &[0, 0, 0]);
+    assert_eq!(&framebuffer[4 + 3 * 3..][..3], &[255, 0, 0]);
+    assert_eq!(&framebuffer[..4], &[7; 4]);
+    // The bytes past the end of the image are left untouched
+    assert_eq!(&framebuffer[4 + 36..], &[7; 4]);
+    assert_eq!(framebuffer.iter().filter(|b| **b != 7).count(), 6);
+
+    let mut small = vec![0u8; 4 * 3 * 3 - 1];
+    assert!(matches!(
+        BitMapBackend::<RGBPixel>::with_buffer_and_format(&mut small, (4, 3)),
+        Err(BitMapBackendError::InvalidBuffer)
+    ));
+}
+
 #[cfg(test)]
 #[test]
 fn test_bitmap_backend_fill_half() {
@@ -612,3 +644,754 @@ mod test {
         );
     }
 }
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+#[test]
+fn test_write_png() {
+    use plotters::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let mut buffer = vec![0; 10 * 10 * 3];
+    let mut png = vec![];
+
+    {
+        let back = Rc::new(RefCell::new(BitMapBackend::with_buffer(
+            &mut buffer,
+            (10, 10),
+        )));
+        let area: DrawingArea<_, _> = (&back).into();
+        area.fill(&WHITE).unwrap();
+        area.draw(&Rectangle::new([(0, 0), (5, 10)], RED.filled()))
+            .unwrap();
+        area.present().unwrap();
+
+        let back = back.borrow();
+        assert_eq!(back.pixel_buffer().len(), 10 * 10 * 3);
+        back.write_png(&mut png).unwrap();
+    }
+
+    let decoded = image::load_from_memory(&png).unwrap().to_rgb8();
+    assert_eq!(decoded.dimensions(), (10, 10));
+    assert_eq!(decoded.into_raw(), buffer);
+}
+
+#[cfg(test)]
+#[test]
+fn test_anti_aliased_line() {
+    use plotters::prelude::*;
+
+    let draw = |anti_aliasing: bool, from: (i32, i32), to: (i32, i32), color: RGBAColor| {
+        let mut buffer = vec![0; 20 * 20 * 3];
+        {
+            let back =
+                BitMapBackend::with_buffer(&mut buffer, (20, 20)).with_anti_aliasing(anti_aliasing);
+            let area = back.into_drawing_area();
+            area.fill(&WHITE).unwrap();
+            area.draw(&PathElement::new(vec![from, to], color)).unwrap();
+            area.present().unwrap();
+        }
+        buffer
+    };
+    let red_at = |buffer: &[u8], (x, y): (usize, usize)| buffer[(y * 20 + x) * 3];
+    let intermediate = |buffer: &[u8]| buffer.iter().filter(|&&v| v > 0 && v < 255).count();
+
+    let aliased = draw(false, (0, 0), (19, 19), BLACK.to_rgba());
+    assert_eq!(red_at(&aliased, (5, 5)), 0);
+    assert_eq!(red_at(&aliased, (5, 6)), 255);
+    assert_eq!(intermediate(&aliased), 0);
+
+    let smooth = draw(true, (0, 0), (19, 19), BLACK.to_rgba());
+    assert_eq!(red_at(&smooth, (5, 5)), 0);
+    let edge = red_at(&smooth, (5, 6));
+    assert!(edge > 0 && edge < 255);
+    assert_eq!(red_at(&smooth, (5, 6)), red_at(&smooth, (6, 5)));
+    assert_eq!(red_at(&smooth, (5, 8)), 255);
+
+    for (from, to) in [((2, 0), (7, 19)), ((0, 3), (19, 11)), ((19, 0), (0, 15))] {
+        assert!(intermediate(&draw(true, from, to, BLACK.to_rgba())) > 0);
+    }
+
+    // The coverage is applied on top of the alpha of the color
+    let translucent = draw(true, (0, 0), (19, 19), BLACK.mix(0.5));
+    assert!(translucent.iter().all(|&v| v >= 127));
+    assert!(red_at(&translucent, (5, 6)) > edge);
+}
+
+#[test]
+fn test_anti_aliased_path_joints() {
+    use plotters::prelude::*;
+
+    let mut buffer = vec![0; 20 * 20 * 3];
+    {
+        let back = BitMapBackend::with_buffer(&mut buffer, (20, 20)).with_anti_aliasing(true);
+        let area = back.into_drawing_area();
+        area.fill(&WHITE).unwrap();
+        let style = ShapeStyle::from(&BLACK.mix(0.5)).stroke_width(3);
+        area.draw(&PathElement::new(vec![(2, 10), (10, 10), (17, 3)], style))
+            .unwrap();
+        area.present().unwrap();
+    }
+    let red_at = |(x, y): (usize, usize)| buffer[(y * 20 + x) * 3];
+
+    // The pixels of a translucent path are blended once, even where its segments meet
+    let stroke = red_at((6, 10));
+    assert!(stroke > 120 && stroke < 135);
+    assert_eq!(red_at((10, 10)), stroke);
+    assert_eq!(red_at((10, 11)), stroke);
+    assert!(buffer.iter().all(|&v| v >= stroke));
+}
+
+#[test]
+fn test_anti_aliased_circle() {
+    use plotters::prelude::*;
+
+    let draw = |anti_aliasing: bool, style: ShapeStyle| {
+        let mut buffer = vec![0; 20 * 20 * 3];
+        {
+            let back =
+                BitMapBackend::with_buffer(&mut buffer, (20, 20)).with_anti_aliasing(anti_aliasing);
+            let area = back.into_drawing_area();
+            area.fill(&WHITE).unwrap();
+            area.draw(&Circle::new((10, 10), 6, style)).unwrap();
+            area.present().unwrap();
+        }
+        buffer
+    };
+    let red_at = |buffer: &[u8], (x, y): (usize, usize)| buffer[(y * 20 + x) * 3];
+    let partial = |buffer: &[u8], (x, y): (usize, usize)| {
+        let v = red_at(buffer, (x, y));
+        v > 0 && v < 255
+    };
+
+    let smooth = draw(true, BLACK.filled());
+    // Inside and far outside, the pixels are untouched by the anti-aliasing
+    assert_eq!(red_at(&smooth, (10, 10)), 0);
+    assert_eq!(red_at(&smooth, (13, 13)), 0);
+    assert_eq!(red_at(&smooth, (1, 1)), 255);
+    assert_eq!(red_at(&smooth, (10, 17)), 255);
+    // On the diagonals, the perimeter crosses the pixels, which are partly covered
+    for &(x, y) in &[(14, 14), (6, 14), (14, 6), (6, 6)] {
+        assert!(partial(&smooth, (x, y)), "({}, {}) isn't blended", x, y);
+    }
+    // The circle is symmetric
+    for &(x, y) in &[(14, 14), (15, 12), (12, 16)] {
+        let v = red_at(&smooth, (x, y));
+        assert_eq!(v, red_at(&smooth, (20 - x, y)));
+        assert_eq!(v, red_at(&smooth, (x, 20 - y)));
+        assert_eq!(v, red_at(&smooth, (y, x)));
+    }
+
+    // The stroked circle is blended on both sides of its ring, and its center is untouched
+    let ring = draw(true, ShapeStyle::from(&BLACK).stroke_width(2));
+    assert_eq!(red_at(&ring, (10, 10)), 255);
+    assert_eq!(red_at(&ring, (10, 4)), 0);
+    assert_eq!(red_at(&ring, (14, 14)), 0);
+    assert!(partial(&ring, (15, 15)));
+    assert!(partial(&ring, (13, 14)));
+    assert_eq!(red_at(&ring, (12, 12)), 255);
+
+    // The coverage is applied on top of the alpha of the fill, each pixel being blended once
+    let translucent = draw(true, BLACK.mix(0.5).filled());
+    let inside = red_at(&translucent, (10, 10));
+    assert!((126..=128).contains(&inside));
+    assert!((9..=11).all(|x| red_at(&translucent, (x, 10)) == inside));
+    assert!(red_at(&translucent, (14, 14)) > inside);
+    assert!(red_at(&translucent, (14, 14)) < 255);
+
+    // Without anti-aliasing, the diagonal pixels of the filled circle keep the plain rasterizer
+    let aliased = draw(false, BLACK.filled());
+    assert_eq!(red_at(&aliased, (10, 10)), 0);
+    assert_ne!(red_at(&aliased, (14, 14)), red_at(&smooth, (14, 14)));
+}
+
+#[test]
+fn test_anti_aliased_scaled_circle() {
+    use plotters::prelude::*;
+
+    // The blended pixels are the pixels of the image, around the scaled circle
+    let mut buffer = vec![0; 20 * 20 * 3];
+    {
+        let back = BitMapBackend::with_buffer(&mut buffer, (10, 10))
+            .scaled(2.0)
+            .with_anti_aliasing(true);
+        let area = back.into_drawing_area();
+        area.fill(&WHITE).unwrap();
+        area.draw(&Circle::new((5, 5), 3, BLACK.filled())).unwrap();
+        area.present().unwrap();
+    }
+    let red_at = |(x, y): (usize, usize)| buffer[(y * 20 + x) * 3];
+    assert_eq!(red_at((10, 10)), 0);
+    assert_eq!(red_at((15, 10)), 0);
+    assert_eq!(red_at((17, 10)), 255);
+    let edge = red_at((14, 14));
+    assert!(edge > 0 && edge < 255);
+}
+
+#[test]
+fn test_anti_aliased_scaled_line() {
+    use plotters::prelude::*;
+
+    // The line drawn at twice the scale is blended in the pixels of the image, as the line twice
+    // as large and as wide, rather than in blocks of two pixels
+    let render = |scale: f64| {
+        let mut buffer = vec![0; 20 * 20 * 3];
+        {
+            let logical = ((20.0 / scale) as u32, (20.0 / scale) as u32);
+            let k = (2.0 / scale) as i32;
+            let back = BitMapBackend::with_buffer(&mut buffer, logical)
+                .scaled(scale)
+                .with_anti_aliasing(true);
+            let area = back.into_drawing_area();
+            area.fill(&WHITE).unwrap();
+            let style = ShapeStyle::from(&BLACK).stroke_width(k as u32);
+            area.draw(&PathElement::new(vec![(k, k), (8 * k, 3 * k)], style))
+                .unwrap();
+            area.present().unwrap();
+        }
+        buffer
+    };
+    let scaled = render(2.0);
+    let large = render(1.0);
+    let partial = scaled.iter().filter(|&&v| v > 0 && v < 255).count();
+    assert!(partial > 0);
+    assert_eq!(scaled, large);
+}
+
+#[test]
+fn test_dashed_line() {
+    use plotters::prelude::*;
+
+    let draw = |style: ShapeStyle| {
+        let mut buffer = vec![0; 20 * 20 * 3];
+        {
+            let area = BitMapBackend::with_buffer(&mut buffer, (20, 20)).into_drawing_area();
+            area.fill(&WHITE).unwrap();
+            area.draw(&PathElement::new(vec![(0, 5), (19, 5), (19, 19)], style))
+                .unwrap();
+            area.present().unwrap();
+        }
+        buffer
+    };
+    let red_at = |buffer: &[u8], (x, y): (usize, usize)| buffer[(y * 20 + x) * 3];
+
+    let solid = draw(BLACK.into());
+    assert!((0..20).all(|x| red_at(&solid, (x, 5)) == 0));
+
+    let dashed = draw(ShapeStyle::from(&BLACK).dashed(&[4.0, 3.0]));
+    let row: Vec<_> = (0..20).map(|x| red_at(&dashed, (x, 5)) == 0).collect();
+    assert_eq!(
+        &row[..8],
+        &[true, true, true, true, true, false, false, true]
+    );
+    // The pattern goes on along the vertical segment
+    assert!((6..20).any(|y| red_at(&dashed, (19, y)) == 255));
+
+    let wide = draw(ShapeStyle::from(&BLACK).stroke_width(3).dashed(&[4.0, 3.0]));
+    assert_eq!(red_at(&wide, (2, 6)), 0);
+    assert_eq!(red_at(&wide, (6, 6)), 255);
+}
+
+#[test]
+fn test_dashed_outlines() {
+    use plotters::prelude::*;
+
+    let draw = |rect_style: ShapeStyle, circle_style: ShapeStyle| {
+        let mut buffer = vec![0; 40 * 20 * 3];
+        {
+            let area = BitMapBackend::with_buffer(&mut buffer, (40, 20)).into_drawing_area();
+            area.fill(&WHITE).unwrap();
+            area.draw(&Rectangle::new([(1, 1), (18, 18)], rect_style))
+                .unwrap();
+            area.draw(&Circle::new((30, 10), 7, circle_style)).unwrap();
+            area.present().unwrap();
+        }
+        buffer
+    };
+    let dark = |buffer: &[u8], xs: std::ops::Range<usize>| {
+        (0..20)
+            .flat_map(|y| xs.clone().map(move |x| (y * 40 + x) * 3))
+            .filter(|&idx| buffer[idx] < 128)
+            .count()
+    };
+
+    let solid = draw(BLACK.into(), BLACK.into());
+    let dashed = draw(
+        ShapeStyle::from(&BLACK).dashed(&[4.0, 3.0]),
+        ShapeStyle::from(&BLACK).dashed(&[4.0, 3.0]),
+    );
+    // The outlines of the rectangles and the circles are dashed too
+    for xs in [0..20, 20..40] {
+        let (solid, dashed) = (dark(&solid, xs.clone()), dark(&dashed, xs));
+        assert!(dashed > solid / 3 && dashed < solid * 5 / 6);
+    }
+    // The dashes of the rectangle go on around its corners
+    assert_eq!(dashed[(40 + 1) * 3], 0);
+}
+
+#[test]
+fn test_gradient_fill() {
+    use plotters::prelude::*;
+
+    let draw = |gradient: GradientStyle| {
+        let mut buffer = vec![0; 20 * 20 * 3];
+        {
+            let area = BitMapBackend::with_buffer(&mut buffer, (20, 20)).into_drawing_area();
+            area.fill(&WHITE).unwrap();
+            area.draw(&Rectangle::new([(0, 0), (19, 19)], BLACK.filled()).with_gradient(gradient))
+                .unwrap();
+            area.present().unwrap();
+        }
+        buffer
+    };
+    let pixel = |buffer: &[u8], (x, y): (usize, usize)| {
+        let idx = (y * 20 + x) * 3;
+        (buffer[idx], buffer[idx + 1], buffer[idx + 2])
+    };
+
+    let vertical = draw(GradientStyle::vertical(&RED, &BLUE));
+    assert_eq!(pixel(&vertical, (10, 0)), (255, 0, 0));
+    assert_eq!(pixel(&vertical, (10, 18)), (13, 0, 242));
+    let (r, _, b) = pixel(&vertical, (3, 10));
+    assert!(r > 100 && r < 155 && b > 100 && b < 155);
+    assert_eq!(pixel(&vertical, (3, 10)), pixel(&vertical, (16, 10)));
+
+    let horizontal = draw(GradientStyle::horizontal(&RED, &BLUE));
+    assert_eq!(pixel(&horizontal, (0, 10)), (255, 0, 0));
+    assert_eq!(pixel(&horizontal, (18, 10)), (13, 0, 242));
+    assert_eq!(pixel(&horizontal, (10, 3)), pixel(&horizontal, (10, 16)));
+}
+
+#[test]
+fn test_rotated_text() {
+    use plotters::prelude::*;
+    use plotters::style::text_anchor::{HPos, Pos, VPos};
+
+    let draw = |angle: f64| {
+        let mut buffer = vec![0; 100 * 100 * 3];
+        {
+            let area = BitMapBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            area.fill(&WHITE).unwrap();
+            let style = TextStyle::from(("sans-serif", 20).into_font())
+                .pos(Pos::new(HPos::Left, VPos::Center))
+                .rotation(angle);
+            area.draw_text("HHHHHH", &style, (10, 50)).unwrap();
+            area.present().unwrap();
+        }
+        buffer
+    };
+    let inked_rows = |buffer: &[u8]| {
+        let rows: Vec<_> = (0..100)
+            .filter(|y| (0..100).any(|x| buffer[(y * 100 + x) * 3] < 128))
+            .collect();
+        (rows[0], rows[rows.len() - 1])
+    };
+
+    // The text goes down along the diagonal, starting from its anchor
+    let (top, bottom) = inked_rows(&draw(45.0));
+    assert!(top > 35 && bottom > 80);
+
+    let (top, bottom) = inked_rows(&draw(-45.0));
+    assert!(top < 20 && bottom < 65);
+}
+
+#[test]
+fn test_blend_overlapping_fills() {
+    use plotters::prelude::*;
+    let mut buffer = vec![0; 40 * 40 * 3];
+
+    {
+        let area = BitMapBackend::with_buffer(&mut buffer, (40, 40)).into_drawing_area();
+        area.fill(&WHITE).unwrap();
+        area.draw(&Rectangle::new([(0, 0), (30, 30)], RED.mix(0.5).filled()))
+            .unwrap();
+        area.draw(&Rectangle::new(
+            [(10, 10), (40, 40)],
+            BLUE.mix(0.5).filled(),
+        ))
+        .unwrap();
+        area.present().unwrap();
+    }
+
+    // The blending truncates the channels, thus they may be one below the exact blend
+    for x in 0..40 {
+        for y in 0..40 {
+            let expected = match (x < 30 && y < 30, x >= 10 && y >= 10) {
+                (true, true) => [128, 64, 191],
+                (true, false) => [255, 128, 128],
+                (false, true) => [128, 128, 255],
+                (false, false) => [255, 255, 255],
+            };
+            let idx = (y * 40 + x) * 3;
+            for (actual, expected) in buffer[idx..idx + 3].iter().zip(expected) {
+                assert!(
+                    *actual <= expected && expected - *actual <= 1,
+                    "pixel ({}, {})",
+                    x,
+                    y
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_clipped_drawing_area() {
+    use plotters::prelude::*;
+
+    let mut buffer = vec![0; 20 * 20 * 3];
+    let pixel = |buffer: &[u8], x: usize, y: usize| {
+        let idx = (y * 20 + x) * 3;
+        (buffer[idx], buffer[idx + 1], buffer[idx + 2])
+    };
+
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (20, 20)).into_drawing_area();
+        root.fill(&WHITE).unwrap();
+
+        // Only redraw the rectangle from (5, 5) to (10, 10), in the coordinate of the root
+        let dirty = root.clip_rect((5, 5), (10, 10));
+        assert_eq!(dirty.dim_in_pixel(), (20, 20));
+        dirty.fill(&RED).unwrap();
+        dirty
+            .draw(&PathElement::new(vec![(0, 7), (19, 7)], BLUE))
+            .unwrap();
+        dirty
+            .draw(&PathElement::new(
+                vec![(0, 0), (19, 19)],
+                ShapeStyle::from(&BLUE).stroke_width(3),
+            ))
+            .unwrap();
+        dirty
+            .draw(&Rectangle::new(
+                [(0, 15), (19, 19)],
+                GREEN.mix(0.5).filled(),
+            ))
+            .unwrap();
+
+        // The areas split from a clipped area are clipped too
+        let (_, right) = dirty.split_horizontally(8);
+        right.fill(&GREEN).unwrap();
+
+        // Once the draw is done, the backend draws on the whole area again
+        root.draw_pixel((0, 0), &BLACK).unwrap();
+        root.present().unwrap();
+    }
+
+    for y in 0..20 {
+        for x in 0..20 {
+            let color = pixel(&buffer, x, y);
+            if (x, y) == (0, 0) {
+                assert_eq!(color, (0, 0, 0));
+            } else if !(5..10).contains(&x) || !(5..10).contains(&y) {
+                assert_eq!(color, (255, 255, 255), "pixel ({}, {}) was touched", x, y);
+            }
+        }
+    }
+    assert_eq!(pixel(&buffer, 5, 8), (255, 0, 0));
+    assert_eq!(pixel(&buffer, 6, 7), (0, 0, 255));
+    assert_eq!(pixel(&buffer, 9, 9), (0, 255, 0));
+    assert_eq!(pixel(&buffer, 5, 9), (255, 0, 0));
+}
+
+#[test]
+fn test_series_clipped_to_plotting_area() {
+    use plotters::prelude::*;
+
+    let mut buffer = vec![0; 100 * 100 * 3];
+    let pixel = |buffer: &[u8], x: usize, y: usize| {
+        let idx = (y * 100 + x) * 3;
+        (buffer[idx], buffer[idx + 1], buffer[idx + 2])
+    };
+
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        root.fill(&WHITE).unwrap();
+        // The plotting area starts 20 pixels below the top of the image
+        let mut chart = ChartBuilder::on(&root)
+            .margin_top(20)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+
+        // The middle point is beyond the y-max
+        let data = [(0.0, 5.0), (5.0, 30.0), (10.0, 5.0)];
+        chart
+            .draw_series(LineSeries::new(
+                data,
+                ShapeStyle::from(&RED).stroke_width(3),
+            ))
+            .unwrap();
+        chart
+            .draw_series(data.iter().map(|p| Circle::new(*p, 4, BLUE.filled())))
+            .unwrap();
+        root.present().unwrap();
+    }
+
+    for y in 0..20 {
+        for x in 0..100 {
+            assert_eq!(
+                pixel(&buffer, x, y),
+                (255, 255, 255),
+                "pixel ({}, {}) is above the plotting area",
+                x,
+                y
+            );
+        }
+    }
+    // The line is drawn up to the top of the plotting area, where it's cut
+    assert!((0..100).any(|x| pixel(&buffer, x, 20) == (255, 0, 0)));
+}
+
+#[test]
+fn test_series_clipping_disabled() {
+    use plotters::prelude::*;
+
+    let mut buffer = vec![0; 100 * 100 * 3];
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+        root.fill(&WHITE).unwrap();
+        let mut chart = ChartBuilder::on(&root)
+            .margin_top(20)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .unwrap();
+
+        // The annotation intentionally goes above the plotting area
+        chart.set_series_clipping(false);
+        chart
+            .draw_series(std::iter::once(Circle::new((5.0, 11.0), 4, BLUE.filled())))
+            .unwrap();
+        root.present().unwrap();
+    }
+
+    assert!((0..20 * 100).any(|idx| buffer[idx * 3..idx * 3 + 3] == [0, 0, 255]));
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+#[test]
+fn test_jpeg_round_trip() {
+    use plotters::prelude::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    let mut buffer = vec![0; 120 * 80 * 3];
+    let backend = Rc::new(RefCell::new(BitMapBackend::with_buffer(
+        &mut buffer,
+        (120, 80),
+    )));
+    {
+        let root: DrawingArea<_, _> = (&backend).into();
+        root.fill(&WHITE).unwrap();
+        root.draw(&Rectangle::new([(60, 0), (120, 80)], RED.filled()))
+            .unwrap();
+        root.present().unwrap();
+    }
+
+    let mut small = vec![];
+    let mut best = vec![];
+    backend.borrow().write_jpeg(&mut small, 10).unwrap();
+    backend.borrow().write_jpeg(&mut best, 100).unwrap();
+    assert!(small.len() < best.len());
+
+    let decoded = image::load_from_memory_with_format(&best, image::ImageFormat::Jpeg)
+        .unwrap()
+        .to_rgb8();
+    assert_eq!(decoded.dimensions(), (120, 80));
+    // The colors survive the lossy compression away from the edge between them
+    let close = |pixel: &image::Rgb<u8>, expected: [u8; 3]| {
+        pixel
+            .0
+            .iter()
+            .zip(expected.iter())
+            .all(|(a, b)| (i32::from(*a) - i32::from(*b)).abs() < 8)
+    };
+    assert!(close(decoded.get_pixel(20, 40), [255, 255, 255]));
+    assert!(close(decoded.get_pixel(100, 40), [255, 0, 0]));
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+#[test]
+fn test_rgba_jpeg_background() {
+    // An opaque red half and a transparent half, then a half transparent blue row
+    let (w, h) = (64, 32);
+    let mut rgba = vec![];
+    for y in 0..h {
+        for x in 0..w {
+            rgba.extend_from_slice(match (x < w / 2, y < h / 2) {
+                (true, _) => &[255, 0, 0, 255],
+                (false, true) => &[0, 0, 0, 0],
+                (false, false) => &[0, 0, 255, 128],
+            });
+        }
+    }
+
+    let mut jpeg = vec![];
+    BitMapBackend::write_rgba_jpeg(&mut jpeg, &rgba, (w, h), (255, 255, 255), 100).unwrap();
+    let decoded = image::load_from_memory_with_format(&jpeg, image::ImageFormat::Jpeg)
+        .unwrap()
+        .to_rgb8();
+    assert_eq!(decoded.dimensions(), (w, h));
+    let close = |pixel: &image::Rgb<u8>, expected: [u8; 3]| {
+        pixel
+            .0
+            .iter()
+            .zip(expected.iter())
+            .all(|(a, b)| (i32::from(*a) - i32::from(*b)).abs() < 8)
+    };
+    assert!(close(decoded.get_pixel(8, 8), [255, 0, 0]));
+    // The transparent pixels show the background, instead of their black color
+    assert!(close(decoded.get_pixel(56, 8), [255, 255, 255]));
+    assert!(close(decoded.get_pixel(56, 24), [127, 127, 255]));
+
+    assert!(matches!(
+        BitMapBackend::write_rgba_jpeg(vec![], &rgba[4..], (w, h), (0, 0, 0), 90),
+        Err(BitMapBackendError::InvalidBuffer)
+    ));
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "webp"))]
+#[test]
+fn test_webp_round_trip() {
+    use plotters::prelude::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    let mut buffer = vec![0; 120 * 80 * 3];
+    let backend = Rc::new(RefCell::new(BitMapBackend::with_buffer(
+        &mut buffer,
+        (120, 80),
+    )));
+    {
+        let root: DrawingArea<_, _> = (&backend).into();
+        root.fill(&WHITE).unwrap();
+        root.draw(&Circle::new((60, 40), 30, RED.filled())).unwrap();
+        root.present().unwrap();
+    }
+
+    let mut webp = vec![];
+    backend.borrow().write_webp(&mut webp).unwrap();
+    let decoded = image::load_from_memory_with_format(&webp, image::ImageFormat::WebP)
+        .unwrap()
+        .to_rgb8();
+    // The compression is lossless
+    assert_eq!(decoded.dimensions(), (120, 80));
+    assert_eq!(decoded.into_raw(), backend.borrow().pixel_buffer());
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+#[test]
+fn test_scaled_backend() {
+    use plotters::prelude::*;
+
+    let path = std::env::temp_dir().join("plotters_bitmap_scaled.png");
+    {
+        let backend = BitMapBackend::new(&path, (40, 30)).scaled(2.0);
+        // The drawing keeps its logical size, while the image has 4 times more pixels
+        assert_eq!(backend.get_size(), (40, 30));
+        assert_eq!(backend.pixel_size(), (80, 60));
+        assert_eq!(backend.pixel_buffer().len(), 4 * 40 * 30 * 3);
+
+        let root = backend.into_drawing_area();
+        root.fill(&WHITE).unwrap();
+        root.draw(&Rectangle::new([(10, 10), (20, 20)], RED.filled()))
+            .unwrap();
+        root.draw(&PathElement::new(vec![(0, 25), (40, 25)], BLUE))
+            .unwrap();
+        root.present().unwrap();
+    }
+
+    let image = image::open(&path).unwrap().to_rgb8();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(image.dimensions(), (80, 60));
+    // The rectangle covers the scaled coordinates
+    assert_eq!(image.get_pixel(19, 30).0, [255, 255, 255]);
+    assert_eq!(image.get_pixel(20, 20).0, [255, 0, 0]);
+    assert_eq!(image.get_pixel(39, 39).0, [255, 0, 0]);
+    // The line of 1 logical pixel is drawn with a stroke of 2 pixels
+    let blue = (0..60)
+        .filter(|y| image.get_pixel(40, *y).0 == [0, 0, 255])
+        .count();
+    assert!(blue >= 2);
+}
+
+#[test]
+fn test_scaled_text_is_rasterized_at_scale() {
+    use plotters::prelude::*;
+
+    // The text rendered at twice the scale is the same as the text with a font twice as large,
+    // rather than enlarged blocks of pixels
+    let render = |scale: f64, size: f64| {
+        let mut buffer = vec![255; 200 * 100 * 3];
+        {
+            let logical = ((200.0 / scale) as u32, (100.0 / scale) as u32);
+            let root = BitMapBackend::with_buffer(&mut buffer, logical)
+                .scaled(scale)
+                .into_drawing_area();
+            root.draw_text(
+                "Plotters",
+                &("sans-serif", size).into_font().color(&BLACK),
+                (0, 0),
+            )
+            .unwrap();
+        }
+        buffer
+    };
+    let scaled = render(2.0, 20.0);
+    let large = render(1.0, 40.0);
+    assert!(scaled.iter().any(|x| *x != 255));
+    assert_eq!(scaled, large);
+}
+
+#[test]
+fn test_scaled_ellipses_and_arcs_are_tessellated_at_scale() {
+    use plotters::prelude::*;
+
+    // The curves drawn at twice the scale are the same as the curves twice as large, rather than
+    // enlarged blocks of pixels
+    let render = |scale: f64| {
+        let mut buffer = vec![255; 200 * 100 * 3];
+        {
+            let logical = ((200.0 / scale) as u32, (100.0 / scale) as u32);
+            let mut backend = BitMapBackend::with_buffer(&mut buffer, logical).scaled(scale);
+            let k = 2.0 / scale;
+            let center = ((25.0 * k) as i32, (25.0 * k) as i32);
+            backend
+                .draw_ellipse(center, (20.0 * k, 10.0 * k), 0.5, &RED, true)
+                .unwrap();
+            let style = Color::stroke_width(&BLUE, (2.0 * k) as u32);
+            backend
+                .draw_arc(
+                    (center.0 * 3, center.1),
+                    (20.0 * k) as u32,
+                    (0.0, 2.0),
+                    &style,
+                )
+                .unwrap();
+        }
+        buffer
+    };
+    let scaled = render(2.0);
+    let large = render(1.0);
+    assert!(scaled.iter().any(|x| *x != 255));
+    assert_eq!(scaled, large);
+}
+
+#[test]
+fn test_rgba_bitmap_is_composited() {
+    use plotters::prelude::*;
+
+    let mut buffer = vec![0; 4 * 2 * 3];
+    {
+        let area = BitMapBackend::with_buffer(&mut buffer, (4, 2)).into_drawing_area();
+        area.fill(&WHITE).unwrap();
+        // An opaque, a half transparent and a transparent pixel
+        let pixels = vec![255, 0, 0, 255, 255, 0, 0, 128, 255, 0, 0, 0];
+        area.draw(&RGBAImage::new((0, 0), (3, 1), pixels).unwrap())
+            .unwrap();
+        area.present().unwrap();
+    }
+
+    assert_eq!(&buffer[0..3], &[255, 0, 0]);
+    assert_eq!(buffer[3], 255);
+    assert!((126..=128).contains(&buffer[4]) && buffer[4] == buffer[5]);
+    assert_eq!(&buffer[6..9], &[255, 255, 255]);
+    // The pixels out of the image are left untouched
+    assert_eq!(&buffer[12..15], &[255, 255, 255]);
+}
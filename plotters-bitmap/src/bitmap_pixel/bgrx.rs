@@ -1,7 +1,6 @@
 use super::pixel_format::blend;
 use super::PixelFormat;
 use crate::BitMapBackend;
-use plotters_backend::DrawingBackend;
 
 /// The marker type that indicates we are currently using a BGRX8888 pixel format
 pub struct BGRXPixel;
@@ -35,7 +34,7 @@ impl PixelFormat for BGRXPixel {
         b: u8,
         a: f64,
     ) {
-        let (w, h) = target.get_size();
+        let (w, h) = target.pixel_size();
         let a = a.clamp(0.0, 1.0);
         if a == 0.0 {
             return;
@@ -129,7 +128,7 @@ impl PixelFormat for BGRXPixel {
         g: u8,
         b: u8,
     ) {
-        let (w, h) = target.get_size();
+        let (w, h) = target.pixel_size();
         let (x0, y0) = (
             upper_left.0.min(bottom_right.0).max(0),
             upper_left.1.min(bottom_right.1).max(0),
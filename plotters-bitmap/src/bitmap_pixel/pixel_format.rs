@@ -1,5 +1,4 @@
 use crate::BitMapBackend;
-use plotters_backend::DrawingBackend;
 
 #[inline(always)]
 pub(super) fn blend(prev: &mut u8, new: u8, a: u64) {
@@ -55,7 +54,7 @@ pub trait PixelFormat: Sized {
         g: u8,
         b: u8,
     ) {
-        let (w, h) = target.get_size();
+        let (w, h) = target.pixel_size();
         let w = w as i32;
         let h = h as i32;
 
@@ -109,7 +108,7 @@ pub trait PixelFormat: Sized {
         alpha: f64,
     ) {
         let (x, y) = (point.0 as usize, point.1 as usize);
-        let (w, _) = target.get_size();
+        let (w, _) = target.pixel_size();
         let buf = target.get_raw_pixel_buffer();
         let w = w as usize;
         let base = (y * w + x) * Self::PIXEL_SIZE;
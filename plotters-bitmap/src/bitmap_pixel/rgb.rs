@@ -1,7 +1,6 @@
 use super::pixel_format::blend;
 use super::PixelFormat;
 use crate::BitMapBackend;
-use plotters_backend::DrawingBackend;
 
 /// The marker type that indicates we are currently using a RGB888 pixel format
 pub struct RGBPixel;
@@ -39,7 +38,7 @@ impl PixelFormat for RGBPixel {
         b: u8,
         a: f64,
     ) {
-        let (w, h) = target.get_size();
+        let (w, h) = target.pixel_size();
         let a = a.clamp(0.0, 1.0);
         if a == 0.0 {
             return;
@@ -144,7 +143,7 @@ impl PixelFormat for RGBPixel {
         g: u8,
         b: u8,
     ) {
-        let (w, h) = target.get_size();
+        let (w, h) = target.pixel_size();
         let (x0, y0) = (
             upper_left.0.min(bottom_right.0).max(0),
             upper_left.1.min(bottom_right.1).max(0),
@@ -4,7 +4,10 @@ use std::fs::File;
 use std::path::Path;
 
 pub(super) struct GifFile {
-    encoder: GifEncoder<File>,
+    // The file is only handed to the encoder with the first frame, since the encoder writes the
+    // size of the image in the header, and the size changes when the backend is scaled
+    file: Option<File>,
+    encoder: Option<GifEncoder<File>>,
     height: u32,
     width: u32,
     delay: u32,
@@ -16,34 +19,42 @@ impl GifFile {
         dim: (u32, u32),
         delay: u32,
     ) -> Result<Self, BitMapBackendError> {
-        let mut encoder = GifEncoder::new(
-            File::create(path.as_ref()).map_err(BitMapBackendError::IOError)?,
-            dim.0 as u16,
-            dim.1 as u16,
-            &[],
-        )
-        .map_err(BitMapBackendError::GifEncodingError)?;
-
-        encoder
-            .set_repeat(Repeat::Infinite)
-            .map_err(BitMapBackendError::GifEncodingError)?;
-
         Ok(Self {
-            encoder,
+            file: Some(File::create(path.as_ref()).map_err(BitMapBackendError::IOError)?),
+            encoder: None,
             width: dim.0,
             height: dim.1,
             delay: (delay + 5) / 10,
         })
     }
 
+    /// Change the size of the frames, which is only possible before the first frame
+    pub(super) fn set_size(&mut self, (w, h): (u32, u32)) {
+        if self.encoder.is_none() {
+            self.width = w;
+            self.height = h;
+        }
+    }
+
     pub(super) fn flush_frame(&mut self, buffer: &[u8]) -> Result<(), BitMapBackendError> {
+        if let Some(file) = self.file.take() {
+            let mut encoder = GifEncoder::new(file, self.width as u16, self.height as u16, &[])
+                .map_err(BitMapBackendError::GifEncodingError)?;
+            encoder
+                .set_repeat(Repeat::Infinite)
+                .map_err(BitMapBackendError::GifEncodingError)?;
+            self.encoder = Some(encoder);
+        }
+
         let mut frame = GifFrame::from_rgb_speed(self.width as u16, self.height as u16, buffer, 10);
 
         frame.delay = self.delay as u16;
 
-        self.encoder
-            .write_frame(&frame)
-            .map_err(BitMapBackendError::GifEncodingError)?;
+        if let Some(encoder) = &mut self.encoder {
+            encoder
+                .write_frame(&frame)
+                .map_err(BitMapBackendError::GifEncodingError)?;
+        }
 
         Ok(())
     }
@@ -0,0 +1,12 @@
+/*!
+   The Plotters PDF backend.
+
+   The plotters PDF backend allows you to render images by Plotters into PDF documents of one or
+   several pages, with vector paths and text in the standard PDF fonts, which can be embedded as
+   is into papers.
+
+   See the documentation for [PDFBackend](struct.PDFBackend.html) for more details.
+*/
+mod pdf;
+
+pub use pdf::PDFBackend;
@@ -0,0 +1,984 @@
+/*!
+The PDF vector drawing backend
+*/
+
+use plotters_backend::{
+    text_anchor::{HPos, VPos},
+    BackendColor, BackendCoord, BackendGradient, BackendStyle, BackendTextStyle, DrawingBackend,
+    DrawingErrorKind, FontFamily, FontStyle, GradientDirection, TextMetrics,
+};
+
+use std::fmt::{self, Write as _};
+use std::fs::File;
+use std::io::{BufWriter, Error, Write};
+use std::path::Path;
+
+/// The number of color bands a gradient fill is approximated with
+const GRADIENT_BANDS: usize = 64;
+
+/// The distance of the control points of the Bezier curve approximating a quarter of a circle,
+/// relative to the radius
+const CIRCLE_CONTROL: f64 = 0.552_284_75;
+
+/// The advance widths of the printable ASCII characters in Helvetica, in 1/1000 em
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/// Formats a number for the PDF content, with at most 3 decimals
+struct Num(f64);
+
+impl fmt::Display for Num {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = format!("{:.3}", self.0);
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        match text {
+            "" | "-" | "-0" => f.write_str("0"),
+            text => f.write_str(text),
+        }
+    }
+}
+
+/// Formats a color component for the PDF content, from 0 to 1
+fn channel(value: u8) -> Num {
+    Num(f64::from(value) / 255.0)
+}
+
+enum Target<'a> {
+    File(&'a Path),
+    Writer(Box<dyn Write + 'a>),
+}
+
+/// A bitmap blitted on the page, which is embedded as an image
+struct Image {
+    size: (u32, u32),
+    data: Vec<u8>,
+}
+
+/// Pick the standard PDF font which is the closest to the font family and style
+fn base_font(family: FontFamily, style: FontStyle) -> &'static str {
+    let faces = match family {
+        FontFamily::Serif => ["Times-Roman", "Times-Bold", "Times-Italic"],
+        FontFamily::Monospace => ["Courier", "Courier-Bold", "Courier-Oblique"],
+        FontFamily::SansSerif => ["Helvetica", "Helvetica-Bold", "Helvetica-Oblique"],
+        FontFamily::Name(name) => {
+            let name = name.to_lowercase();
+            if name.contains("mono") || name.contains("courier") {
+                ["Courier", "Courier-Bold", "Courier-Oblique"]
+            } else if name.contains("times") || (name.contains("serif") && !name.contains("sans")) {
+                ["Times-Roman", "Times-Bold", "Times-Italic"]
+            } else {
+                ["Helvetica", "Helvetica-Bold", "Helvetica-Oblique"]
+            }
+        }
+    };
+    match style {
+        FontStyle::Normal => faces[0],
+        FontStyle::Bold => faces[1],
+        FontStyle::Italic | FontStyle::Oblique => faces[2],
+    }
+}
+
+/// Estimate the width of a text written in a standard PDF font.
+/// The proportional fonts are all measured with the Helvetica metrics.
+fn text_width(text: &str, font: &str, size: f64) -> f64 {
+    let units: u32 = text
+        .chars()
+        .map(|c| match c as u32 {
+            _ if font.starts_with("Courier") => 600,
+            code @ 32..=126 => u32::from(HELVETICA_WIDTHS[code as usize - 32]),
+            _ => 556,
+        })
+        .sum();
+    f64::from(units) * size / 1000.0
+}
+
+/// The ascender and the descender of a standard PDF font, in 1/1000 em
+fn font_ascent_descent(font: &str) -> (f64, f64) {
+    if font.starts_with("Courier") {
+        (629.0, 157.0)
+    } else if font.starts_with("Times") {
+        (683.0, 217.0)
+    } else {
+        (718.0, 207.0)
+    }
+}
+
+/// Append a text to the content as a PDF string in the WinAnsi encoding. The characters which
+/// can't be encoded are replaced with `?`.
+fn push_pdf_string(buf: &mut String, text: &str) {
+    buf.push('(');
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            ' '..='~' => buf.push(c),
+            '\u{a0}'..='\u{ff}' => {
+                let _ = write!(buf, "\\{:03o}", c as u32);
+            }
+            _ => buf.push('?'),
+        }
+    }
+    buf.push(')');
+}
+
+/// The PDF drawing backend, which renders a PDF document with vector graphics.
+///
+/// The page has the size of the backend, with one pixel mapped to one point, thus the document
+/// can be scaled freely once embedded. A document of several pages is rendered by starting each
+/// page after the first with [PDFBackend::new_page](#method.new_page). The texts use the standard PDF fonts, which don't need to
+/// be embedded: the sans-serif family is rendered with Helvetica, the serif family with Times
+/// and the monospace family with Courier. The gradients are approximated with color bands.
+///
+/// The document is written when the backend is presented or dropped.
+pub struct PDFBackend<'a> {
+    target: Target<'a>,
+    size: (u32, u32),
+    // The content of the pages before the current one
+    pages: Vec<String>,
+    content: String,
+    fonts: Vec<&'static str>,
+    alphas: Vec<u16>,
+    images: Vec<Image>,
+    clip: Option<(BackendCoord, BackendCoord)>,
+    saved: bool,
+}
+
+impl<'a> PDFBackend<'a> {
+    fn with_target(target: Target<'a>, size: (u32, u32)) -> Self {
+        Self {
+            target,
+            size,
+            pages: vec![],
+            content: String::new(),
+            fonts: vec![],
+            alphas: vec![],
+            images: vec![],
+            clip: None,
+            saved: false,
+        }
+    }
+
+    /// Create a new PDF drawing backend
+    pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
+        Self::with_target(Target::File(path.as_ref()), size)
+    }
+
+    /// Create a new PDF drawing backend and write the document to a writer
+    pub fn with_writer<W: Write + 'a>(writer: W, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Writer(Box::new(writer)), size)
+    }
+
+    /// Start a new page of the size of the backend: the current page is done, and the following
+    /// drawings go to the new one, which has its own content. Since the drawing area owns its
+    /// backend, the backend of a document of several pages is shared with the drawing area
+    /// through a `Rc<RefCell<_>>`, so that it's still reachable between the pages.
+    ///
+    /// ```
+    /// use plotters::drawing::DrawingArea;
+    /// use plotters::prelude::*;
+    /// use plotters_pdf::PDFBackend;
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// let mut document = vec![];
+    /// {
+    ///     let backend = Rc::new(RefCell::new(PDFBackend::with_writer(&mut document, (400, 300))));
+    ///     let root = DrawingArea::from(&backend);
+    ///     for index in 0..3 {
+    ///         if index > 0 {
+    ///             backend.borrow_mut().new_page();
+    ///         }
+    ///         root.fill(&WHITE).unwrap();
+    ///         root.titled(&format!("Page {}", index + 1), ("sans-serif", 20))
+    ///             .unwrap();
+    ///     }
+    ///     root.present().unwrap();
+    /// }
+    /// ```
+    pub fn new_page(&mut self) {
+        let clip = self.clip;
+        self.set_clip_rect(None);
+        self.pages.push(std::mem::take(&mut self.content));
+        // The clipping of the drawings carries over to the new page
+        self.set_clip_rect(clip);
+    }
+
+    /// Set the transparency of the following fills and strokes, with a graphics state
+    fn push_alpha(&mut self, alpha: f64) {
+        if alpha >= 1.0 {
+            return;
+        }
+        let key = (alpha.max(0.0) * 1000.0).round() as u16;
+        if !self.alphas.contains(&key) {
+            self.alphas.push(key);
+        }
+        let _ = writeln!(self.content, "/GA{} gs", key);
+    }
+
+    /// Start a filled shape, which must be ended with `end_shape`
+    fn begin_fill(&mut self, color: BackendColor) {
+        self.content.push_str("q\n");
+        self.push_alpha(color.alpha);
+        let _ = writeln!(
+            self.content,
+            "{} {} {} rg",
+            channel(color.rgb.0),
+            channel(color.rgb.1),
+            channel(color.rgb.2)
+        );
+    }
+
+    /// Start a stroked shape, which must be ended with `end_shape`
+    fn begin_stroke<S: BackendStyle>(&mut self, style: &S) {
+        let color = style.color();
+        self.content.push_str("q\n");
+        self.push_alpha(color.alpha);
+        let _ = writeln!(
+            self.content,
+            "{} {} {} RG {} w",
+            channel(color.rgb.0),
+            channel(color.rgb.1),
+            channel(color.rgb.2),
+            style.stroke_width()
+        );
+        let dash = style.stroke_dash();
+        if !dash.is_empty() {
+            self.content.push('[');
+            for (i, length) in dash.iter().enumerate() {
+                let _ = write!(
+                    self.content,
+                    "{}{}",
+                    if i == 0 { "" } else { " " },
+                    Num(*length)
+                );
+            }
+            self.content.push_str("] 0 d\n");
+        }
+    }
+
+    fn end_shape(&mut self, op: &str) {
+        self.content.push_str(op);
+        self.content.push_str("\nQ\n");
+    }
+
+    fn push_path<I: IntoIterator<Item = (f64, f64)>>(&mut self, path: I) {
+        for (i, (x, y)) in path.into_iter().enumerate() {
+            let op = if i == 0 { 'm' } else { 'l' };
+            let _ = writeln!(self.content, "{} {} {}", Num(x), Num(y), op);
+        }
+    }
+
+    fn push_circle(&mut self, (x, y): BackendCoord, radius: u32) {
+        let (x, y, r) = (f64::from(x), f64::from(y), f64::from(radius));
+        let k = r * CIRCLE_CONTROL;
+        let _ = writeln!(self.content, "{} {} m", Num(x + r), Num(y));
+        for &(c1, c2, to) in &[
+            ((x + r, y + k), (x + k, y + r), (x, y + r)),
+            ((x - k, y + r), (x - r, y + k), (x - r, y)),
+            ((x - r, y - k), (x - k, y - r), (x, y - r)),
+            ((x + k, y - r), (x + r, y - k), (x + r, y)),
+        ] {
+            let _ = writeln!(
+                self.content,
+                "{} {} {} {} {} {} c",
+                Num(c1.0),
+                Num(c1.1),
+                Num(c2.0),
+                Num(c2.1),
+                Num(to.0),
+                Num(to.1)
+            );
+        }
+    }
+
+    fn font_index(&mut self, font: &'static str) -> usize {
+        match self.fonts.iter().position(|f| *f == font) {
+            Some(idx) => idx,
+            None => {
+                self.fonts.push(font);
+                self.fonts.len() - 1
+            }
+        }
+    }
+
+    /// Assemble the PDF document from the content of the pages and their resources
+    fn make_document(&self) -> Vec<u8> {
+        let (w, h) = self.size;
+        let pages = self.pages.len() + 1;
+        let content_base = 3 + pages;
+        let font_base = content_base + pages;
+        let alpha_base = font_base + self.fonts.len();
+        let image_base = alpha_base + self.alphas.len();
+
+        let mut objects: Vec<Vec<u8>> = vec![];
+        objects.push(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+        let kids: Vec<_> = (0..pages).map(|idx| format!("{} 0 R", 3 + idx)).collect();
+        objects.push(
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                kids.join(" "),
+                pages
+            )
+            .into_bytes(),
+        );
+
+        let mut resources = String::from("/Font <<");
+        for idx in 0..self.fonts.len() {
+            let _ = write!(resources, " /F{} {} 0 R", idx, font_base + idx);
+        }
+        resources.push_str(" >> /ExtGState <<");
+        for (idx, key) in self.alphas.iter().enumerate() {
+            let _ = write!(resources, " /GA{} {} 0 R", key, alpha_base + idx);
+        }
+        resources.push_str(" >> /XObject <<");
+        for idx in 0..self.images.len() {
+            let _ = write!(resources, " /Im{} {} 0 R", idx, image_base + idx);
+        }
+        resources.push_str(" >>");
+        for idx in 0..pages {
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << {} >> /Contents {} 0 R >>",
+                    w, h, resources, content_base + idx
+                )
+                .into_bytes(),
+            );
+        }
+
+        // Flip the y axis, so that the content uses the backend coordinates
+        let flip = format!("1 0 0 -1 0 {} cm\n", h);
+        for page in &self.pages {
+            objects.push(make_stream("", (flip.clone() + page).as_bytes()));
+        }
+        let mut content = flip + &self.content;
+        if self.clip.is_some() {
+            content.push_str("Q\n");
+        }
+        objects.push(make_stream("", content.as_bytes()));
+
+        for font in &self.fonts {
+            objects.push(
+                format!(
+                    "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+                    font
+                )
+                .into_bytes(),
+            );
+        }
+        for key in &self.alphas {
+            let alpha = Num(f64::from(*key) / 1000.0);
+            objects
+                .push(format!("<< /Type /ExtGState /CA {} /ca {} >>", alpha, alpha).into_bytes());
+        }
+        for image in &self.images {
+            let dict = format!(
+                "/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB /BitsPerComponent 8",
+                image.size.0, image.size.1
+            );
+            objects.push(make_stream(&dict, &image.data));
+        }
+
+        let mut doc = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = vec![];
+        for (idx, object) in objects.iter().enumerate() {
+            offsets.push(doc.len());
+            doc.extend_from_slice(format!("{} 0 obj\n", idx + 1).as_bytes());
+            doc.extend_from_slice(object);
+            doc.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref = doc.len();
+        let mut trailer = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+        for offset in offsets {
+            let _ = writeln!(trailer, "{:010} 00000 n ", offset);
+        }
+        let _ = write!(
+            trailer,
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref
+        );
+        doc.extend_from_slice(trailer.as_bytes());
+        doc
+    }
+}
+
+/// Make a stream object from its data and the extra entries of its dictionary
+fn make_stream(dict: &str, data: &[u8]) -> Vec<u8> {
+    let dict = if dict.is_empty() {
+        String::new()
+    } else {
+        format!("{} ", dict)
+    };
+    let mut ret = format!("<< {}/Length {} >>\nstream\n", dict, data.len()).into_bytes();
+    ret.extend_from_slice(data);
+    ret.extend_from_slice(b"\nendstream");
+    ret
+}
+
+impl<'a> DrawingBackend for PDFBackend<'a> {
+    type ErrorType = Error;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.saved {
+            let doc = self.make_document();
+            match self.target {
+                Target::File(path) => {
+                    let outfile = File::create(path).map_err(DrawingErrorKind::DrawingError)?;
+                    let mut outfile = BufWriter::new(outfile);
+                    outfile
+                        .write_all(&doc)
+                        .and_then(|_| outfile.flush())
+                        .map_err(DrawingErrorKind::DrawingError)?;
+                }
+                Target::Writer(ref mut writer) => {
+                    writer
+                        .write_all(&doc)
+                        .and_then(|_| writer.flush())
+                        .map_err(DrawingErrorKind::DrawingError)?;
+                }
+            }
+            self.saved = true;
+        }
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if color.alpha == 0.0 {
+            return Ok(());
+        }
+        self.begin_fill(color);
+        let _ = writeln!(self.content, "{} {} 1 1 re", point.0, point.1);
+        self.end_shape("f");
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        self.begin_stroke(style);
+        self.push_path(
+            [from, to]
+                .iter()
+                .map(|&(x, y)| (f64::from(x), f64::from(y))),
+        );
+        self.end_shape("S");
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        if fill {
+            self.begin_fill(style.color());
+        } else {
+            self.begin_stroke(style);
+        }
+        let _ = writeln!(
+            self.content,
+            "{} {} {} {} re",
+            upper_left.0,
+            upper_left.1,
+            bottom_right.0 - upper_left.0,
+            bottom_right.1 - upper_left.1
+        );
+        self.end_shape(if fill { "f" } else { "S" });
+        Ok(())
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        self.begin_stroke(style);
+        self.push_path(path.into_iter().map(|(x, y)| (f64::from(x), f64::from(y))));
+        self.end_shape("S");
+        Ok(())
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        self.begin_fill(style.color());
+        self.push_path(path.into_iter().map(|(x, y)| (f64::from(x), f64::from(y))));
+        self.end_shape("h f");
+        Ok(())
+    }
+
+    fn fill_polygon_gradient<I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        gradient: &BackendGradient,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = path
+            .into_iter()
+            .map(|(x, y)| (f64::from(x), f64::from(y)))
+            .collect();
+        if vert.is_empty() {
+            return Ok(());
+        }
+        let (x0, y0) = vert
+            .iter()
+            .fold(vert[0], |(x, y), p| (x.min(p.0), y.min(p.1)));
+        let (x1, y1) = vert
+            .iter()
+            .fold(vert[0], |(x, y), p| (x.max(p.0), y.max(p.1)));
+        let (w, h) = (x1 - x0, y1 - y0);
+
+        // The polygon clips a stack of bands, each filled with the color of its middle
+        self.content.push_str("q\n");
+        self.push_path(vert);
+        self.content.push_str("h W n\n");
+        let bands = GRADIENT_BANDS as f64;
+        for idx in 0..GRADIENT_BANDS {
+            let (t0, t1) = (idx as f64 / bands, (idx + 1) as f64 / bands);
+            let color = gradient.color_at((t0 + t1) / 2.0);
+            if color.alpha == 0.0 {
+                continue;
+            }
+            // Opaque bands overlap the next one, which avoids hairline seams in the viewers
+            let t1 = if color.alpha >= 1.0 {
+                (t1 + 0.5 / bands).min(1.0)
+            } else {
+                t1
+            };
+            let diagonal = |s: f64, u: f64| (x0 + w * (s - u), y0 + h * u);
+            let band = match gradient.direction {
+                GradientDirection::Vertical => [
+                    (x0, y0 + h * t0),
+                    (x1, y0 + h * t0),
+                    (x1, y0 + h * t1),
+                    (x0, y0 + h * t1),
+                ],
+                GradientDirection::Horizontal => [
+                    (x0 + w * t0, y0),
+                    (x0 + w * t1, y0),
+                    (x0 + w * t1, y1),
+                    (x0 + w * t0, y1),
+                ],
+                // The position along a diagonal gradient is the mean of the x and y ratios
+                GradientDirection::Diagonal => [
+                    diagonal(2.0 * t0, -1.0),
+                    diagonal(2.0 * t0, 2.0),
+                    diagonal(2.0 * t1, 2.0),
+                    diagonal(2.0 * t1, -1.0),
+                ],
+            };
+            self.begin_fill(color);
+            self.push_path(band.iter().copied());
+            self.end_shape("h f");
+        }
+        self.content.push_str("Q\n");
+
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        if fill {
+            self.begin_fill(style.color());
+        } else {
+            self.begin_stroke(style);
+        }
+        self.push_circle(center, radius);
+        self.end_shape(if fill { "h f" } else { "h S" });
+        Ok(())
+    }
+
+    fn draw_text<S: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &S,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = style.color();
+        if color.alpha == 0.0 {
+            return Ok(());
+        }
+
+        let font = base_font(style.family(), style.style());
+        let font_size = style.size() / 1.24;
+        let width = text_width(text, font, font_size);
+
+        // The offset from the anchor to the start of the baseline, before the rotation
+        let dx = match style.anchor().h_pos {
+            HPos::Left => 0.0,
+            HPos::Center => -width / 2.0,
+            HPos::Right => -width,
+        };
+        let dy = match style.anchor().v_pos {
+            VPos::Top => 0.76 * font_size,
+            VPos::Center => 0.26 * font_size,
+            VPos::Bottom => -0.26 * font_size,
+        };
+        let angle = style.transform().angle() + style.rotation();
+        let (sin, cos) = f64::to_radians(angle).sin_cos();
+        let x = f64::from(pos.0) + dx * cos - dy * sin;
+        let y = f64::from(pos.1) + dx * sin + dy * cos;
+
+        let font_idx = self.font_index(font);
+        self.begin_fill(color);
+        // The text matrix flips the glyphs back, since the y axis of the page is flipped
+        let _ = write!(
+            self.content,
+            "BT /F{} {} Tf {} {} {} {} {} {} Tm ",
+            font_idx,
+            Num(font_size),
+            Num(cos),
+            Num(sin),
+            Num(sin),
+            Num(-cos),
+            Num(x),
+            Num(y)
+        );
+        push_pdf_string(&mut self.content, text);
+        self.end_shape(" Tj ET");
+
+        Ok(())
+    }
+
+    fn estimate_text_size<S: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &S,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        let font = base_font(style.family(), style.style());
+        let width = text_width(text, font, style.size() / 1.24);
+        Ok((width.ceil() as u32, style.size().ceil() as u32))
+    }
+
+    fn estimate_text_metrics<S: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &S,
+    ) -> Result<TextMetrics, DrawingErrorKind<Self::ErrorType>> {
+        let (width, height) = self.estimate_text_size(text, style)?;
+        let (ascent, descent) = font_ascent_descent(base_font(style.family(), style.style()));
+        let em = style.size() / 1.24;
+        Ok(TextMetrics {
+            width,
+            height,
+            ascent: ascent * em / 1000.0,
+            descent: descent * em / 1000.0,
+            // The standard fonts come without a line gap, the size already leaves some room
+            line_gap: 0.0,
+        })
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (w, h): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let len = w as usize * h as usize * 3;
+        if src.len() < len {
+            return Err(DrawingErrorKind::DrawingError(Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "Image buffer of {} bytes is too short for a {}x{} image",
+                    src.len(),
+                    w,
+                    h
+                ),
+            )));
+        }
+        self.images.push(Image {
+            size: (w, h),
+            data: src[..len].to_vec(),
+        });
+        // The first row of an image is at the top of the unit square, which the flip turns over
+        let _ = writeln!(
+            self.content,
+            "q {} 0 0 {} {} {} cm /Im{} Do Q",
+            w,
+            -i64::from(h),
+            pos.0,
+            i64::from(pos.1) + i64::from(h),
+            self.images.len() - 1
+        );
+        Ok(())
+    }
+
+    fn set_clip_rect(&mut self, clip: Option<(BackendCoord, BackendCoord)>) {
+        if self.clip.is_some() {
+            self.content.push_str("Q\n");
+        }
+        self.clip = clip;
+        if let Some((a, b)) = clip {
+            let _ = writeln!(
+                self.content,
+                "q {} {} {} {} re W n",
+                a.0.min(b.0),
+                a.1.min(b.1),
+                (a.0 - b.0).abs(),
+                (a.1 - b.1).abs()
+            );
+        }
+    }
+}
+
+impl Drop for PDFBackend<'_> {
+    fn drop(&mut self) {
+        if !self.saved {
+            // drop should not panic, so we ignore a failed present
+            let _ = self.present();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use plotters::element::{Circle, PathElement};
+    use plotters::prelude::{
+        ChartBuilder, Color, GradientStyle, IntoDrawingArea, IntoFont, LineSeries, Polygon,
+        Rectangle, ShapeStyle, TextStyle, BLACK, BLUE, RED, WHITE,
+    };
+    use plotters::style::text_anchor::{HPos, Pos, VPos};
+    use plotters_backend::FontTransform;
+    use std::fs;
+
+    static DST_DIR: &str = "target/test/pdf";
+
+    fn checked_save_file(name: &str, content: &[u8]) {
+        /*
+          Please use the PDF file to manually verify the results.
+        */
+        fs::create_dir_all(DST_DIR).unwrap();
+        let file_name = format!("{}.pdf", name);
+        let file_path = Path::new(DST_DIR).join(file_name);
+        println!("{:?} created", file_path);
+        fs::write(file_path, content).unwrap();
+    }
+
+    /// Check the header and the cross-reference table of the document
+    fn check_structure(doc: &[u8]) {
+        assert!(doc.starts_with(b"%PDF-1."));
+        assert!(doc.ends_with(b"%%EOF\n"));
+
+        // The trailer is plain ASCII, unlike the binary marker after the header
+        let start = doc.windows(10).rposition(|w| w == b"startxref\n").unwrap() + 10;
+        let trailer = std::str::from_utf8(&doc[start..]).unwrap();
+        let xref: usize = trailer.lines().next().unwrap().parse().unwrap();
+        let table = std::str::from_utf8(&doc[xref..]).unwrap();
+
+        let mut lines = table.lines();
+        assert_eq!(lines.next(), Some("xref"));
+        let count: usize = lines.next().unwrap()[2..].parse().unwrap();
+        assert_eq!(lines.next(), Some("0000000000 65535 f "));
+        for id in 1..count {
+            let offset: usize = lines.next().unwrap()[..10].parse().unwrap();
+            assert!(doc[offset..].starts_with(format!("{} 0 obj\n", id).as_bytes()));
+        }
+        assert!(table.contains(&format!("<< /Size {} /Root 1 0 R >>", count)));
+    }
+
+    #[test]
+    fn test_draw_chart() {
+        let mut buffer = vec![];
+        {
+            let root = PDFBackend::with_writer(&mut buffer, (500, 400)).into_drawing_area();
+            root.fill(&WHITE).unwrap();
+
+            let mut chart = ChartBuilder::on(&root)
+                .caption("Chart (PDF)", ("sans-serif", 20))
+                .set_all_label_area_size(40)
+                .build_cartesian_2d(0.0..10.0, -1.0..1.0)
+                .unwrap();
+            chart.configure_mesh().draw().unwrap();
+            chart
+                .draw_series(LineSeries::new(
+                    (0..=100).map(|x| (x as f64 / 10.0, (x as f64 / 10.0).sin())),
+                    &RED,
+                ))
+                .unwrap();
+            root.present().unwrap();
+        }
+
+        check_structure(&buffer);
+        let text = String::from_utf8_lossy(&buffer);
+        assert!(text.contains("/MediaBox [0 0 500 400]"));
+        assert!(text.contains("0 0 500 400 re\nf\nQ\n"));
+        assert!(text.contains("/BaseFont /Helvetica "));
+        assert!(text.contains("(Chart \\(PDF\\)) Tj"));
+        checked_save_file("test_draw_chart", &buffer);
+    }
+
+    #[test]
+    fn test_draw_shapes() {
+        let mut buffer = vec![];
+        {
+            let mut backend = PDFBackend::with_writer(&mut buffer, (200, 200));
+            backend.blit_bitmap((150, 150), (2, 2), &[255; 12]).unwrap();
+            let root = backend.into_drawing_area();
+            root.draw(&Circle::new((50, 50), 20, BLUE.mix(0.5).filled()))
+                .unwrap();
+            root.draw(
+                &Polygon::new(vec![(100, 20), (180, 20), (140, 90)], RED)
+                    .with_gradient(GradientStyle::diagonal(&RED, &BLUE)),
+            )
+            .unwrap();
+            root.draw(&PathElement::new(
+                vec![(10, 150), (100, 120), (190, 190)],
+                ShapeStyle::from(&BLACK).stroke_width(2),
+            ))
+            .unwrap();
+            root.draw(&Rectangle::new([(20, 100), (60, 140)], BLACK))
+                .unwrap();
+
+            let style = TextStyle::from(("serif", 20).into_font())
+                .pos(Pos::new(HPos::Center, VPos::Center))
+                .transform(FontTransform::Rotate90);
+            root.draw_text("Rotated", &style, (100, 100)).unwrap();
+        }
+
+        check_structure(&buffer);
+        let text = String::from_utf8_lossy(&buffer);
+        assert!(text.contains("/Type /ExtGState /CA 0.5 /ca 0.5"));
+        assert!(text.contains("/BaseFont /Times-Roman "));
+        assert!(text.contains("/Subtype /Image /Width 2 /Height 2"));
+        assert!(text.contains("0 1 1 0 "));
+        checked_save_file("test_draw_shapes", &buffer);
+    }
+
+    #[test]
+    fn test_pages() {
+        use plotters::drawing::DrawingArea;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let mut buffer = vec![];
+        {
+            let backend = Rc::new(RefCell::new(PDFBackend::with_writer(
+                &mut buffer,
+                (300, 200),
+            )));
+            let root = DrawingArea::from(&backend);
+            assert_eq!(root.dim_in_pixel(), (300, 200));
+            for idx in 0..3 {
+                if idx > 0 {
+                    backend.borrow_mut().new_page();
+                }
+                root.fill(&WHITE).unwrap();
+                root.draw(&Circle::new((150, 100), 10 * (idx + 1), RED))
+                    .unwrap();
+            }
+            root.present().unwrap();
+        }
+
+        check_structure(&buffer);
+        let text = String::from_utf8_lossy(&buffer);
+        assert!(text.contains("<< /Type /Pages /Kids [3 0 R 4 0 R 5 0 R] /Count 3 >>"));
+        assert_eq!(text.matches("/MediaBox [0 0 300 200]").count(), 3);
+        // Each page has its own content, which holds the drawings made while it was current
+        for idx in 0..3 {
+            assert!(text.contains(&format!("/Contents {} 0 R", 6 + idx)));
+        }
+        let contents: Vec<_> = text.split("stream\n1 0 0 -1 0 200 cm\n").skip(1).collect();
+        assert_eq!(contents.len(), 3);
+        for (idx, content) in contents.iter().enumerate() {
+            let content = &content[..content.find("endstream").unwrap()];
+            assert_eq!(content.matches("0 0 300 200 re\nf\n").count(), 1);
+            assert!(content.contains(&format!("{} 100 m", 150 + 10 * (idx + 1))));
+        }
+        checked_save_file("test_pages", &buffer);
+    }
+
+    #[test]
+    fn test_new_page_keeps_clipping() {
+        let mut buffer = vec![];
+        {
+            let mut backend = PDFBackend::with_writer(&mut buffer, (100, 100));
+            backend.set_clip_rect(Some(((10, 10), (50, 50))));
+            backend.new_page();
+            backend.set_clip_rect(None);
+            backend.present().unwrap();
+        }
+
+        let text = String::from_utf8_lossy(&buffer);
+        assert_eq!(text.matches("q 10 10 40 40 re W n\nQ\n").count(), 2);
+    }
+
+    #[test]
+    fn test_blit_short_buffer() {
+        let mut backend = PDFBackend::with_writer(vec![], (100, 100));
+        match backend.blit_bitmap((0, 0), (2, 2), &[0; 11]) {
+            Err(DrawingErrorKind::DrawingError(e)) => {
+                assert_eq!(e.kind(), std::io::ErrorKind::InvalidInput)
+            }
+            _ => panic!("A short buffer should be rejected"),
+        }
+        assert!(backend.blit_bitmap((0, 0), (2, 2), &[0; 12]).is_ok());
+    }
+
+    #[test]
+    fn test_text_encoding() {
+        let mut content = String::new();
+        push_pdf_string(&mut content, "a(b)\\c é ✓");
+        assert_eq!(content, "(a\\(b\\)\\\\c \\351 ?)");
+
+        assert_eq!(text_width("ab", "Helvetica", 10.0), 11.12);
+        assert_eq!(text_width("ab", "Courier-Bold", 10.0), 12.0);
+    }
+
+    #[test]
+    fn test_text_metrics() {
+        let backend = PDFBackend::with_writer(vec![], (100, 100));
+        let style = TextStyle::from(("serif", 12.4).into_font());
+        let short = backend.estimate_text_metrics("Plot", &style).unwrap();
+        let long = backend.estimate_text_metrics("Plotters", &style).unwrap();
+        assert!(long.width > short.width);
+        assert_eq!(long.height, short.height);
+        assert!((long.ascent - 6.83).abs() < 1e-9);
+        assert!((long.descent - 2.17).abs() < 1e-9);
+    }
+}
@@ -5,6 +5,7 @@
 
    See the documentation for [SVGBackend](struct.SVGBackend.html) for more details.
 */
+mod png;
 mod svg;
 
-pub use svg::SVGBackend;
+pub use svg::{SVGBackend, SVGStyling};
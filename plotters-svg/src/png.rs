@@ -0,0 +1,101 @@
+/*!
+A minimal PNG encoder, which stores the pixels without compression so that the images can be
+embedded without the image crate
+*/
+
+/// The largest length of a stored deflate block
+const MAX_BLOCK: usize = 0xffff;
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+fn push_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Encode an RGBA image as a PNG file
+pub(crate) fn encode_rgba((w, h): (u32, u32), src: &[u8]) -> Vec<u8> {
+    let row_len = w as usize * 4;
+    // Each row starts with its filter type, which is none
+    let mut raw = Vec::with_capacity((row_len + 1) * h as usize);
+    for row in src.chunks(row_len.max(1)).take(h as usize) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+
+    // A zlib stream made of stored deflate blocks
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = raw.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        zlib.push(if blocks.peek().is_none() { 1 } else { 0 });
+        zlib.extend_from_slice(&(block.len() as u16).to_le_bytes());
+        zlib.extend_from_slice(&(!(block.len() as u16)).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&w.to_be_bytes());
+    header.extend_from_slice(&h.to_be_bytes());
+    // 8 bits per channel, RGBA, then the default compression, filtering and no interlacing
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    push_chunk(&mut png, b"IHDR", &header);
+    push_chunk(&mut png, b"IDAT", &zlib);
+    push_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_encode_rgba() {
+        let png = encode_rgba((2, 1), &[255, 0, 0, 255, 0, 0, 255, 128]);
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..29], &[0, 0, 0, 2, 0, 0, 0, 1, 8, 6, 0, 0, 0]);
+        // A single final stored block with the filtered row
+        assert_eq!(&png[37..41], b"IDAT");
+        assert_eq!(&png[41..48], &[0x78, 0x01, 1, 9, 0, 0xf6, 0xff]);
+        assert_eq!(&png[48..57], &[0, 255, 0, 0, 255, 0, 0, 255, 128]);
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+    }
+}
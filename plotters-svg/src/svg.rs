@@ -3,9 +3,10 @@ The SVG image drawing backend
 */
 
 use plotters_backend::{
+    rasterizer::{clip_line, clip_path},
     text_anchor::{HPos, VPos},
-    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
-    FontStyle, FontTransform,
+    BackendColor, BackendCoord, BackendGradient, BackendStyle, BackendTextStyle, DrawingBackend,
+    DrawingErrorKind, FontStyle, FontTransform, GradientDirection,
 };
 
 use std::fmt::Write as _;
@@ -20,6 +21,11 @@ fn make_svg_color(color: BackendColor) -> Rgb {
     Rgb(color.rgb.0, color.rgb.1, color.rgb.2)
 }
 
+// Rounds a computed length or angle to two decimals, which is finer than the pixels
+fn round_svg_value(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
 enum Target<'a> {
     File(String, &'a Path),
     Buffer(&'a mut String),
@@ -38,11 +44,18 @@ impl Target<'_> {
 enum SVGTag {
     Svg,
     Circle,
+    Ellipse,
     Line,
+    Path,
     Polygon,
     Polyline,
     Rectangle,
     Text,
+    Defs,
+    LinearGradient,
+    Stop,
+    ClipPath,
+    Group,
     #[allow(dead_code)]
     Image,
 }
@@ -52,24 +65,70 @@ impl SVGTag {
         match self {
             SVGTag::Svg => "svg",
             SVGTag::Circle => "circle",
+            SVGTag::Ellipse => "ellipse",
             SVGTag::Line => "line",
+            SVGTag::Path => "path",
             SVGTag::Polyline => "polyline",
             SVGTag::Rectangle => "rect",
             SVGTag::Text => "text",
             SVGTag::Image => "image",
             SVGTag::Polygon => "polygon",
+            SVGTag::Defs => "defs",
+            SVGTag::LinearGradient => "linearGradient",
+            SVGTag::Stop => "stop",
+            SVGTag::ClipPath => "clipPath",
+            SVGTag::Group => "g",
         }
     }
 }
 
+/// How the shapes written by an [`SVGBackend`] are styled
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SVGStyling {
+    /// The colors and the opacity of each shape are written as its attributes
+    Inline,
+    /// The shapes get the inline attributes and a `class` attribute as well, so that a
+    /// stylesheet can override their look, e.g. on hover
+    InlineAndClasses,
+    /// The shapes only get a `class` attribute, their colors and opacity are left to a
+    /// stylesheet. The geometry, such as the stroke width and the font size, is kept inline,
+    /// and so is the fill of the filled rectangles, circles and polygons, which tells them from
+    /// the outlined ones. The stylesheet still overrides it.
+    Classes,
+}
+
 /// The SVG image drawing backend
 pub struct SVGBackend<'a> {
     target: Target<'a>,
     size: (u32, u32),
     tag_stack: Vec<SVGTag>,
+    gradient_count: u32,
+    /// The rectangle the drawing is restricted to, the bottom-right corner excluded
+    clip: Option<(BackendCoord, BackendCoord)>,
+    /// The clip rectangle of the open clipping group, if any, with the id of its clip path
+    clip_group: Option<((BackendCoord, BackendCoord), u32)>,
+    clip_count: u32,
+    styling: SVGStyling,
+    /// The class names the shapes are tagged with
+    classes: Vec<String>,
+    /// The tooltips and the data attached to the shapes, the innermost one applies
+    metadata: Vec<Metadata>,
     saved: bool,
 }
 
+/// The tooltip and the data attached to a shape
+#[derive(Clone, Default)]
+struct Metadata {
+    tooltip: Option<String>,
+    data: Vec<(String, String)>,
+}
+
+/// The attributes written on a shape, besides its geometry and its style
+struct ShapeAttrs {
+    class: Option<String>,
+    metadata: Metadata,
+}
+
 trait FormatEscaped {
     fn format_escaped(buf: &mut String, s: Self);
 }
@@ -196,10 +255,57 @@ impl<'a> AttrWriter<'a, Init> {
         }
     }
 
+    /// Writes the `stroke-dasharray` attribute, unless the dash pattern is empty
+    fn write_dash_array(&mut self, pattern: &[f64]) {
+        if pattern.is_empty() {
+            return;
+        }
+        self.write_key("stroke-dasharray")
+            .write_value(FormatEscapedIter(
+                pattern
+                    .iter()
+                    .enumerate()
+                    .map(|(i, length)| (if i == 0 { "" } else { " " }, *length)),
+            ));
+    }
+
     fn close(self) {
         self.buf.push_str("/>\n");
     }
 
+    /// Writes the `class` and the `data-*` attributes of a shape
+    fn write_shape_attrs(&mut self, attrs: &ShapeAttrs) {
+        if let Some(class) = &attrs.class {
+            self.write_key("class").write_value(class.as_str());
+        }
+        for (key, value) in &attrs.metadata.data {
+            // The key is restricted to the characters allowed in an attribute name
+            let key: String = key
+                .chars()
+                .map(|c| match c.to_ascii_lowercase() {
+                    c @ ('a'..='z' | '0'..='9' | '-' | '_' | '.') => c,
+                    _ => '-',
+                })
+                .collect();
+            self.write_key(&format!("data-{}", key))
+                .write_value(value.as_str());
+        }
+    }
+
+    /// Closes a shape tag, with a `<title>` child holding the tooltip if any
+    fn close_with_tooltip(self, tooltip: Option<&str>) {
+        match tooltip {
+            Some(tooltip) => {
+                self.buf.push_str(">\n<title>");
+                FormatEscaped::format_escaped(self.buf, tooltip);
+                self.buf.push_str("</title>\n</");
+                self.buf.push_str(self.tag.to_tag_name());
+                self.buf.push_str(">\n");
+            }
+            None => self.close(),
+        }
+    }
+
     fn finish_without_closing(self) {
         self.tag_stack.push(self.tag);
         self.buf.push_str(">\n");
@@ -237,6 +343,129 @@ impl<'a> SVGBackend<'a> {
         AttrWriter::open_tag(self.target.get_mut(), tag, &mut self.tag_stack)
     }
 
+    /// The attributes of a shape: its class, made of the class of its kind and of the pushed
+    /// classes unless the shapes aren't tagged with classes, and the innermost pushed metadata
+    fn shape_attrs(&self, kind: &str) -> ShapeAttrs {
+        let class = if self.styling == SVGStyling::Inline {
+            None
+        } else {
+            let mut class = String::from(kind);
+            for pushed in &self.classes {
+                class.push(' ');
+                class.push_str(pushed);
+            }
+            Some(class)
+        };
+        ShapeAttrs {
+            class,
+            metadata: self.metadata.last().cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Checks if the colors and the opacity of the shapes are written inline
+    fn inline_paint(&self) -> bool {
+        self.styling != SVGStyling::Classes
+    }
+
+    /// Puts the following shapes into a group clipped to the clip rectangle, unless they are
+    /// in such a group already. The consecutive shapes with the same clipping share the group.
+    fn sync_clip(&mut self) {
+        if self.clip_group.map(|(rect, _)| rect) == self.clip {
+            return;
+        }
+        if self.clip_group.take().is_some() {
+            self.close_tag();
+        }
+        let ((x0, y0), (x1, y1)) = match self.clip {
+            Some(clip) => clip,
+            None => return,
+        };
+
+        self.clip_count += 1;
+        let id = self.clip_count;
+        self.open_tag(SVGTag::Defs).finish_without_closing();
+        let mut attrwriter = self.open_tag(SVGTag::ClipPath);
+        attrwriter.write_key("id").write_value(("clip-", id));
+        attrwriter.finish_without_closing();
+        let mut attrwriter = self.open_tag(SVGTag::Rectangle);
+        attrwriter.write_key("x").write_value(x0);
+        attrwriter.write_key("y").write_value(y0);
+        attrwriter.write_key("width").write_value(x1 - x0);
+        attrwriter.write_key("height").write_value(y1 - y0);
+        attrwriter.close();
+        self.close_tag();
+        self.close_tag();
+
+        let mut attrwriter = self.open_tag(SVGTag::Group);
+        attrwriter
+            .write_key("clip-path")
+            .write_value(("url(#clip-", id, ')'));
+        attrwriter.finish_without_closing();
+        self.clip_group = self.clip.map(|rect| (rect, id));
+    }
+
+    /// Checks if a shape with the given bounding box is entirely out of the clip rectangle
+    fn is_clipped_out(&self, (x0, y0): BackendCoord, (x1, y1): BackendCoord) -> bool {
+        match self.clip {
+            Some(((cx0, cy0), (cx1, cy1))) => {
+                x0.max(x1) < cx0 || x0.min(x1) >= cx1 || y0.max(y1) < cy0 || y0.min(y1) >= cy1
+            }
+            None => false,
+        }
+    }
+
+    /// The clip rectangle grown by a margin, which the lines are cut to so that the cut is
+    /// hidden by the clip path
+    fn clip_with_margin(&self, margin: u32) -> Option<(BackendCoord, BackendCoord)> {
+        let m = margin as i32 + 1;
+        self.clip
+            .map(|((x0, y0), (x1, y1))| ((x0 - m, y0 - m), (x1 + m, y1 + m)))
+    }
+
+    /// Writes a polyline, without checking the clipping
+    fn draw_polyline<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) {
+        self.sync_clip();
+        let (attrs, paint) = (self.shape_attrs("plotters-line"), self.inline_paint());
+        let mut attrwriter = self.open_tag(SVGTag::Polyline);
+        attrwriter.write_shape_attrs(&attrs);
+        attrwriter.write_key("fill").write_value("none");
+        if paint {
+            attrwriter
+                .write_key("opacity")
+                .write_value(style.color().alpha);
+            attrwriter
+                .write_key("stroke")
+                .write_value(make_svg_color(style.color()));
+        }
+        attrwriter
+            .write_key("stroke-width")
+            .write_value(style.stroke_width());
+        attrwriter.write_dash_array(style.stroke_dash());
+        attrwriter
+            .write_key("points")
+            .write_value(FormatEscapedIter(
+                path.into_iter().map(|c| (c.0, ',', c.1, ' ')),
+            ));
+        attrwriter.close_with_tooltip(attrs.metadata.tooltip.as_deref());
+    }
+
+    /// Checks if a polygon is entirely out of the clip rectangle
+    fn is_clipped_out_polygon(&self, path: &[BackendCoord]) -> bool {
+        let (mut upper_left, mut bottom_right) = match path.first() {
+            Some(&first) => (first, first),
+            None => return true,
+        };
+        for &(x, y) in path {
+            upper_left = (upper_left.0.min(x), upper_left.1.min(y));
+            bottom_right = (bottom_right.0.max(x), bottom_right.1.max(y));
+        }
+        self.is_clipped_out(upper_left, bottom_right)
+    }
+
     fn init_svg_file(&mut self, size: (u32, u32)) {
         let mut attrwriter = self.open_tag(SVGTag::Svg);
         attrwriter.write_key("width").write_value(size.0);
@@ -256,6 +485,13 @@ impl<'a> SVGBackend<'a> {
             target: Target::File(String::default(), path.as_ref()),
             size,
             tag_stack: vec![],
+            gradient_count: 0,
+            clip: None,
+            clip_group: None,
+            clip_count: 0,
+            styling: SVGStyling::Inline,
+            classes: vec![],
+            metadata: vec![],
             saved: false,
         };
 
@@ -269,6 +505,13 @@ impl<'a> SVGBackend<'a> {
             target: Target::Buffer(buf),
             size,
             tag_stack: vec![],
+            gradient_count: 0,
+            clip: None,
+            clip_group: None,
+            clip_count: 0,
+            styling: SVGStyling::Inline,
+            classes: vec![],
+            metadata: vec![],
             saved: false,
         };
 
@@ -276,6 +519,77 @@ impl<'a> SVGBackend<'a> {
 
         ret
     }
+
+    /// Set how the shapes are styled, with inline attributes by default. With classes, each
+    /// shape gets the class of its kind (`plotters-line`, `plotters-rect`, `plotters-circle`,
+    /// `plotters-ellipse`, `plotters-arc`, `plotters-polygon`, `plotters-text`, `plotters-pixel`
+    /// or `plotters-image`), followed by
+    /// the classes it's tagged with. The charts tag their shapes with `plotters-grid`,
+    /// `plotters-axis`, `plotters-series` or `plotters-legend`, and a series can get a class of
+    /// its own, e.g. `class="plotters-line plotters-series revenue"`.
+    ///
+    /// - `styling`: How the shapes are styled
+    /// - **returns**: The backend with the styling set
+    pub fn with_styling(mut self, styling: SVGStyling) -> Self {
+        self.styling = styling;
+        self
+    }
+
+    /// Embed a PNG image in the document, encoded in base64
+    fn push_png_image(&mut self, pos: BackendCoord, (w, h): (u32, u32), mut data: Vec<u8>) {
+        let padding = (3 - data.len() % 3) % 3;
+        data.resize(data.len() + padding, 0);
+
+        let mut rem_bits = 0;
+        let mut rem_num = 0;
+
+        fn cvt_base64(from: u8) -> char {
+            (if from < 26 {
+                b'A' + from
+            } else if from < 52 {
+                b'a' + from - 26
+            } else if from < 62 {
+                b'0' + from - 52
+            } else if from == 62 {
+                b'+'
+            } else {
+                b'/'
+            })
+            .into()
+        }
+
+        let mut buf = String::new();
+        buf.push_str("data:png;base64,");
+
+        for byte in data {
+            let value = (rem_bits << (6 - rem_num)) | (byte >> (rem_num + 2));
+            rem_bits = byte & ((1 << (2 + rem_num)) - 1);
+            rem_num += 2;
+
+            buf.push(cvt_base64(value));
+            if rem_num == 6 {
+                buf.push(cvt_base64(rem_bits));
+                rem_bits = 0;
+                rem_num = 0;
+            }
+        }
+
+        for _ in 0..padding {
+            buf.pop();
+            buf.push('=');
+        }
+
+        self.sync_clip();
+        let attrs = self.shape_attrs("plotters-image");
+        let mut attrwriter = self.open_tag(SVGTag::Image);
+        attrwriter.write_shape_attrs(&attrs);
+        attrwriter.write_key("x").write_value(pos.0);
+        attrwriter.write_key("y").write_value(pos.1);
+        attrwriter.write_key("width").write_value(w);
+        attrwriter.write_key("height").write_value(h);
+        attrwriter.write_key("href").write_value(buf.as_str());
+        attrwriter.close_with_tooltip(attrs.metadata.tooltip.as_deref());
+    }
 }
 
 impl<'a> DrawingBackend for SVGBackend<'a> {
@@ -292,6 +606,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
     fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
         if !self.saved {
             while self.close_tag() {}
+            self.clip_group = None;
             match self.target {
                 Target::File(ref buf, path) => {
                     let outfile = File::create(path).map_err(DrawingErrorKind::DrawingError)?;
@@ -312,20 +627,25 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         point: BackendCoord,
         color: BackendColor,
     ) -> Result<(), DrawingErrorKind<Error>> {
-        if color.alpha == 0.0 {
+        if color.alpha == 0.0 || self.is_clipped_out(point, point) {
             return Ok(());
         }
+        self.sync_clip();
+        let (attrs, paint) = (self.shape_attrs("plotters-pixel"), self.inline_paint());
         let mut attrwriter = self.open_tag(SVGTag::Rectangle);
+        attrwriter.write_shape_attrs(&attrs);
         attrwriter.write_key("x").write_value(point.0);
         attrwriter.write_key("y").write_value(point.1);
         attrwriter.write_key("width").write_value("1");
         attrwriter.write_key("height").write_value("1");
         attrwriter.write_key("stroke").write_value("none");
-        attrwriter.write_key("opacity").write_value(color.alpha);
-        attrwriter
-            .write_key("fill")
-            .write_value(make_svg_color(color));
-        attrwriter.close();
+        if paint {
+            attrwriter.write_key("opacity").write_value(color.alpha);
+            attrwriter
+                .write_key("fill")
+                .write_value(make_svg_color(color));
+        }
+        attrwriter.close_with_tooltip(attrs.metadata.tooltip.as_deref());
         Ok(())
     }
 
@@ -338,21 +658,34 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.color().alpha == 0.0 {
             return Ok(());
         }
+        let (from, to) = match self.clip_with_margin(style.stroke_width()) {
+            Some(clip) => match clip_line(from, to, clip) {
+                Some(line) => line,
+                None => return Ok(()),
+            },
+            None => (from, to),
+        };
+        self.sync_clip();
+        let (attrs, paint) = (self.shape_attrs("plotters-line"), self.inline_paint());
         let mut attrwriter = self.open_tag(SVGTag::Line);
-        attrwriter
-            .write_key("opacity")
-            .write_value(style.color().alpha);
-        attrwriter
-            .write_key("stroke")
-            .write_value(make_svg_color(style.color()));
+        attrwriter.write_shape_attrs(&attrs);
+        if paint {
+            attrwriter
+                .write_key("opacity")
+                .write_value(style.color().alpha);
+            attrwriter
+                .write_key("stroke")
+                .write_value(make_svg_color(style.color()));
+        }
         attrwriter
             .write_key("stroke-width")
             .write_value(style.stroke_width());
+        attrwriter.write_dash_array(style.stroke_dash());
         attrwriter.write_key("x1").write_value(from.0);
         attrwriter.write_key("y1").write_value(from.1);
         attrwriter.write_key("x2").write_value(to.0);
         attrwriter.write_key("y2").write_value(to.1);
-        attrwriter.close();
+        attrwriter.close_with_tooltip(attrs.metadata.tooltip.as_deref());
         Ok(())
     }
 
@@ -363,9 +696,10 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         style: &S,
         fill: bool,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.color().alpha == 0.0 {
+        if style.color().alpha == 0.0 || self.is_clipped_out(upper_left, bottom_right) {
             return Ok(());
         }
+        self.sync_clip();
 
         let color = make_svg_color(style.color());
         let (fill, stroke) = if !fill {
@@ -374,7 +708,9 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             (Some(color), None)
         };
 
+        let (attrs, paint) = (self.shape_attrs("plotters-rect"), self.inline_paint());
         let mut attrwriter = self.open_tag(SVGTag::Rectangle);
+        attrwriter.write_shape_attrs(&attrs);
         attrwriter.write_key("x").write_value(upper_left.0);
         attrwriter.write_key("y").write_value(upper_left.1);
         attrwriter
@@ -383,12 +719,20 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         attrwriter
             .write_key("height")
             .write_value(bottom_right.1 - upper_left.1);
-        attrwriter
-            .write_key("opacity")
-            .write_value(style.color().alpha);
+        if paint {
+            attrwriter
+                .write_key("opacity")
+                .write_value(style.color().alpha);
+        }
+        let stroked = stroke.is_some();
         attrwriter.write_key("fill").write_value(fill);
-        attrwriter.write_key("stroke").write_value(stroke);
-        attrwriter.close();
+        if paint || stroke.is_none() {
+            attrwriter.write_key("stroke").write_value(stroke);
+        }
+        if stroked {
+            attrwriter.write_dash_array(style.stroke_dash());
+        }
+        attrwriter.close_with_tooltip(attrs.metadata.tooltip.as_deref());
         Ok(())
     }
 
@@ -400,47 +744,103 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         if style.color().alpha == 0.0 {
             return Ok(());
         }
-        let mut attrwriter = self.open_tag(SVGTag::Polyline);
-        attrwriter.write_key("fill").write_value("none");
-        attrwriter
-            .write_key("opacity")
-            .write_value(style.color().alpha);
-        attrwriter
-            .write_key("stroke")
-            .write_value(make_svg_color(style.color()));
-        attrwriter
-            .write_key("stroke-width")
-            .write_value(style.stroke_width());
+        if let Some(clip) = self.clip_with_margin(style.stroke_width()) {
+            let path: Vec<_> = path.into_iter().collect();
+            for piece in clip_path(&path, clip) {
+                self.draw_polyline(piece, style);
+            }
+        } else {
+            self.draw_polyline(path, style);
+        }
+        Ok(())
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.color().alpha == 0.0 {
+            return Ok(());
+        }
+        let path: Vec<_> = path.into_iter().collect();
+        if self.is_clipped_out_polygon(&path) {
+            return Ok(());
+        }
+        self.sync_clip();
+        let (attrs, paint) = (self.shape_attrs("plotters-polygon"), self.inline_paint());
+        let mut attrwriter = self.open_tag(SVGTag::Polygon);
+        attrwriter.write_shape_attrs(&attrs);
+        if paint {
+            attrwriter
+                .write_key("opacity")
+                .write_value(style.color().alpha);
+            attrwriter
+                .write_key("fill")
+                .write_value(make_svg_color(style.color()));
+        }
         attrwriter
             .write_key("points")
             .write_value(FormatEscapedIter(
                 path.into_iter().map(|c| (c.0, ',', c.1, ' ')),
             ));
-        attrwriter.close();
+        attrwriter.close_with_tooltip(attrs.metadata.tooltip.as_deref());
+
         Ok(())
     }
 
-    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+    fn fill_polygon_gradient<I: IntoIterator<Item = BackendCoord>>(
         &mut self,
         path: I,
-        style: &S,
+        gradient: &BackendGradient,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.color().alpha == 0.0 {
+        let path: Vec<_> = path.into_iter().collect();
+        if self.is_clipped_out_polygon(&path) {
             return Ok(());
         }
+        self.sync_clip();
+        self.gradient_count += 1;
+        let id = self.gradient_count;
+
+        self.open_tag(SVGTag::Defs).finish_without_closing();
+        let mut attrwriter = self.open_tag(SVGTag::LinearGradient);
+        attrwriter.write_key("id").write_value(("gradient-", id));
+        let (x2, y2) = match gradient.direction {
+            GradientDirection::Vertical => (0, 1),
+            GradientDirection::Horizontal => (1, 0),
+            GradientDirection::Diagonal => (1, 1),
+        };
+        attrwriter.write_key("x1").write_value(0);
+        attrwriter.write_key("y1").write_value(0);
+        attrwriter.write_key("x2").write_value(x2);
+        attrwriter.write_key("y2").write_value(y2);
+        attrwriter.finish_without_closing();
+        for (pos, color) in gradient.stops.iter() {
+            let mut attrwriter = self.open_tag(SVGTag::Stop);
+            attrwriter.write_key("offset").write_value(*pos);
+            attrwriter
+                .write_key("stop-color")
+                .write_value(make_svg_color(*color));
+            attrwriter
+                .write_key("stop-opacity")
+                .write_value(color.alpha);
+            attrwriter.close();
+        }
+        self.close_tag();
+        self.close_tag();
+
+        let attrs = self.shape_attrs("plotters-polygon");
         let mut attrwriter = self.open_tag(SVGTag::Polygon);
-        attrwriter
-            .write_key("opacity")
-            .write_value(style.color().alpha);
+        attrwriter.write_shape_attrs(&attrs);
         attrwriter
             .write_key("fill")
-            .write_value(make_svg_color(style.color()));
+            .write_value(("url(#gradient-", id, ')'));
         attrwriter
             .write_key("points")
             .write_value(FormatEscapedIter(
                 path.into_iter().map(|c| (c.0, ',', c.1, ' ')),
             ));
-        attrwriter.close();
+        attrwriter.close_with_tooltip(attrs.metadata.tooltip.as_deref());
 
         Ok(())
     }
@@ -452,28 +852,154 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         style: &S,
         fill: bool,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
-        if style.color().alpha == 0.0 {
+        let r = (radius + style.stroke_width()) as i32;
+        if style.color().alpha == 0.0
+            || self.is_clipped_out((center.0 - r, center.1 - r), (center.0 + r, center.1 + r))
+        {
             return Ok(());
         }
+        self.sync_clip();
         let color = make_svg_color(style.color());
         let (stroke, fill) = if !fill {
             (Some(color), None)
         } else {
             (None, Some(color))
         };
+        let (attrs, paint) = (self.shape_attrs("plotters-circle"), self.inline_paint());
         let mut attrwriter = self.open_tag(SVGTag::Circle);
+        attrwriter.write_shape_attrs(&attrs);
         attrwriter.write_key("cx").write_value(center.0);
         attrwriter.write_key("cy").write_value(center.1);
         attrwriter.write_key("r").write_value(radius);
+        if paint {
+            attrwriter
+                .write_key("opacity")
+                .write_value(style.color().alpha);
+        }
+        attrwriter.write_key("fill").write_value(fill);
+        if paint || stroke.is_none() {
+            attrwriter.write_key("stroke").write_value(stroke);
+        }
+        attrwriter
+            .write_key("stroke-width")
+            .write_value(style.stroke_width());
+        attrwriter.write_dash_array(style.stroke_dash());
+        attrwriter.close_with_tooltip(attrs.metadata.tooltip.as_deref());
+        Ok(())
+    }
+
+    fn draw_ellipse<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radii: (f64, f64),
+        rotation: f64,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let r = (radii.0.abs().max(radii.1.abs()) + f64::from(style.stroke_width())).ceil() as i32;
+        if style.color().alpha == 0.0
+            || self.is_clipped_out((center.0 - r, center.1 - r), (center.0 + r, center.1 + r))
+        {
+            return Ok(());
+        }
+        self.sync_clip();
+        let color = make_svg_color(style.color());
+        let (stroke, fill) = if !fill {
+            (Some(color), None)
+        } else {
+            (None, Some(color))
+        };
+        let (attrs, paint) = (self.shape_attrs("plotters-ellipse"), self.inline_paint());
+        let mut attrwriter = self.open_tag(SVGTag::Ellipse);
+        attrwriter.write_shape_attrs(&attrs);
+        attrwriter.write_key("cx").write_value(center.0);
+        attrwriter.write_key("cy").write_value(center.1);
         attrwriter
-            .write_key("opacity")
-            .write_value(style.color().alpha);
+            .write_key("rx")
+            .write_value(round_svg_value(radii.0.abs()));
+        attrwriter
+            .write_key("ry")
+            .write_value(round_svg_value(radii.1.abs()));
+        if rotation != 0.0 {
+            attrwriter.write_key("transform").write_value((
+                "rotate(",
+                round_svg_value(rotation.to_degrees()),
+                ' ',
+                center.0,
+                ' ',
+                center.1,
+                ')',
+            ));
+        }
+        if paint {
+            attrwriter
+                .write_key("opacity")
+                .write_value(style.color().alpha);
+        }
         attrwriter.write_key("fill").write_value(fill);
-        attrwriter.write_key("stroke").write_value(stroke);
+        if paint || stroke.is_none() {
+            attrwriter.write_key("stroke").write_value(stroke);
+        }
         attrwriter
             .write_key("stroke-width")
             .write_value(style.stroke_width());
-        attrwriter.close();
+        attrwriter.write_dash_array(style.stroke_dash());
+        attrwriter.close_with_tooltip(attrs.metadata.tooltip.as_deref());
+        Ok(())
+    }
+
+    fn draw_arc<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        angles: (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let r = (radius + style.stroke_width()) as i32;
+        if style.color().alpha == 0.0
+            || self.is_clipped_out((center.0 - r, center.1 - r), (center.0 + r, center.1 + r))
+        {
+            return Ok(());
+        }
+        self.sync_clip();
+
+        // The arc is split in pieces of at most half a turn, whose ends are unambiguous
+        let radius = f64::from(radius);
+        let span = angles.1 - angles.0;
+        let pieces = (span.abs() / std::f64::consts::PI).ceil().max(1.0) as u32;
+        let sweep = if span < 0.0 { 0 } else { 1 };
+        let point = |angle: f64| {
+            let (sin, cos) = angle.sin_cos();
+            (
+                round_svg_value(f64::from(center.0) + radius * cos),
+                round_svg_value(f64::from(center.1) + radius * sin),
+            )
+        };
+        let (x, y) = point(angles.0);
+        let mut path = format!("M {} {}", x, y);
+        for idx in 1..=pieces {
+            let (x, y) = point(angles.0 + span * f64::from(idx) / f64::from(pieces));
+            let _ = write!(path, " A {} {} 0 0 {} {} {}", radius, radius, sweep, x, y);
+        }
+
+        let (attrs, paint) = (self.shape_attrs("plotters-arc"), self.inline_paint());
+        let mut attrwriter = self.open_tag(SVGTag::Path);
+        attrwriter.write_shape_attrs(&attrs);
+        attrwriter.write_key("d").write_value(path.as_str());
+        if paint {
+            attrwriter
+                .write_key("opacity")
+                .write_value(style.color().alpha);
+            attrwriter
+                .write_key("stroke")
+                .write_value(make_svg_color(style.color()));
+        }
+        attrwriter.write_key("fill").write_value("none");
+        attrwriter
+            .write_key("stroke-width")
+            .write_value(style.stroke_width());
+        attrwriter.write_dash_array(style.stroke_dash());
+        attrwriter.close_with_tooltip(attrs.metadata.tooltip.as_deref());
         Ok(())
     }
 
@@ -488,6 +1014,8 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             return Ok(());
         }
 
+        self.sync_clip();
+
         let (x0, y0) = pos;
         let text_anchor = match style.anchor().h_pos {
             HPos::Left => "start",
@@ -526,21 +1054,30 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 .unwrap();
         }
 
+        let (attrs, paint) = (self.shape_attrs("plotters-text"), self.inline_paint());
         let mut attrwriter = self.open_tag(SVGTag::Text);
+        attrwriter.write_shape_attrs(&attrs);
         attrwriter.write_key("x").write_value(x0);
         attrwriter.write_key("y").write_value(y0);
         attrwriter.write_key("dy").write_value(dy);
         attrwriter.write_key("text-anchor").write_value(text_anchor);
+        let mut font_family = style.family().as_str().to_string();
+        for family in style.fallback_families() {
+            font_family.push_str(", ");
+            font_family.push_str(family.as_str());
+        }
         attrwriter
             .write_key("font-family")
-            .write_value(style.family().as_str());
+            .write_value(font_family.as_str());
         attrwriter
             .write_key("font-size")
             .write_value(style.size() / 1.24);
-        attrwriter.write_key("opacity").write_value(color.alpha);
-        attrwriter
-            .write_key("fill")
-            .write_value(make_svg_color(color));
+        if paint {
+            attrwriter.write_key("opacity").write_value(color.alpha);
+            attrwriter
+                .write_key("fill")
+                .write_value(make_svg_color(color));
+        }
 
         match style.style() {
             FontStyle::Normal => {}
@@ -555,26 +1092,52 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         };
 
         let trans = style.transform();
-        match trans {
-            FontTransform::Rotate90 => {
-                attrwriter
-                    .write_key("transform")
-                    .write_value(("rotate(90, ", x0, ", ", y0, ')'));
-            }
-            FontTransform::Rotate180 => {
-                attrwriter
-                    .write_key("transform")
-                    .write_value(("rotate(180, ", x0, ", ", y0, ')'));
-            }
-            FontTransform::Rotate270 => {
-                attrwriter
-                    .write_key("transform")
-                    .write_value(("rotate(270, ", x0, ", ", y0, ')'));
+        if style.rotation() % 360.0 != 0.0 {
+            // The quarter turns of the transformation and the rotation make a single rotation
+            let angle = trans.angle() + style.rotation();
+            attrwriter
+                .write_key("transform")
+                .write_value(("rotate(", angle, ", ", x0, ", ", y0, ')'));
+        } else {
+            match trans {
+                FontTransform::Rotate90 => {
+                    attrwriter.write_key("transform").write_value((
+                        "rotate(90, ",
+                        x0,
+                        ", ",
+                        y0,
+                        ')',
+                    ));
+                }
+                FontTransform::Rotate180 => {
+                    attrwriter.write_key("transform").write_value((
+                        "rotate(180, ",
+                        x0,
+                        ", ",
+                        y0,
+                        ')',
+                    ));
+                }
+                FontTransform::Rotate270 => {
+                    attrwriter.write_key("transform").write_value((
+                        "rotate(270, ",
+                        x0,
+                        ", ",
+                        y0,
+                        ')',
+                    ));
+                }
+                _ => {}
             }
-            _ => {}
         }
         attrwriter.finish_without_closing();
 
+        if let Some(tooltip) = attrs.metadata.tooltip {
+            let buf = self.target.get_mut();
+            buf.push_str("<title>");
+            Self::escape_and_push(buf, &tooltip);
+            buf.push_str("</title>\n");
+        }
         Self::escape_and_push(self.target.get_mut(), text);
         self.target.get_mut().push('\n');
 
@@ -610,57 +1173,46 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             })?;
         }
 
-        let padding = (3 - data.len() % 3) % 3;
-        data.resize(data.len() + padding, 0);
+        self.push_png_image(pos, (w, h), data);
 
-        let mut rem_bits = 0;
-        let mut rem_num = 0;
+        Ok(())
+    }
 
-        fn cvt_base64(from: u8) -> char {
-            (if from < 26 {
-                b'A' + from
-            } else if from < 52 {
-                b'a' + from - 26
-            } else if from < 62 {
-                b'0' + from - 52
-            } else if from == 62 {
-                b'+'
-            } else {
-                b'/'
-            })
-            .into()
-        }
+    fn blit_rgba_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        (w, h): (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let data = crate::png::encode_rgba((w, h), src);
+        self.push_png_image(pos, (w, h), data);
 
-        let mut buf = String::new();
-        buf.push_str("data:png;base64,");
+        Ok(())
+    }
 
-        for byte in data {
-            let value = (rem_bits << (6 - rem_num)) | (byte >> (rem_num + 2));
-            rem_bits = byte & ((1 << (2 + rem_num)) - 1);
-            rem_num += 2;
+    fn set_clip_rect(&mut self, clip: Option<(BackendCoord, BackendCoord)>) {
+        // The clipping group is only opened with the next shape, so that nothing is written for
+        // the shapes which are entirely clipped out
+        self.clip = clip.map(|(a, b)| ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1))));
+    }
 
-            buf.push(cvt_base64(value));
-            if rem_num == 6 {
-                buf.push(cvt_base64(rem_bits));
-                rem_bits = 0;
-                rem_num = 0;
-            }
-        }
+    fn push_element_class(&mut self, class: &str) {
+        self.classes.push(class.to_owned());
+    }
 
-        for _ in 0..padding {
-            buf.pop();
-            buf.push('=');
-        }
+    fn pop_element_class(&mut self) {
+        self.classes.pop();
+    }
 
-        let mut attrwriter = self.open_tag(SVGTag::Image);
-        attrwriter.write_key("x").write_value(pos.0);
-        attrwriter.write_key("y").write_value(pos.1);
-        attrwriter.write_key("width").write_value(w);
-        attrwriter.write_key("height").write_value(h);
-        attrwriter.write_key("href").write_value(buf.as_str());
-        attrwriter.close();
+    fn push_element_metadata(&mut self, tooltip: Option<&str>, data: &[(String, String)]) {
+        self.metadata.push(Metadata {
+            tooltip: tooltip.map(str::to_owned),
+            data: data.to_owned(),
+        });
+    }
 
-        Ok(())
+    fn pop_element_metadata(&mut self) {
+        self.metadata.pop();
     }
 }
 
@@ -676,10 +1228,10 @@ impl Drop for SVGBackend<'_> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use plotters::element::Circle;
+    use plotters::element::{Circle, IntoMetadataElement, PathElement, Text};
     use plotters::prelude::{
-        ChartBuilder, Color, IntoDrawingArea, IntoFont, SeriesLabelPosition, TextStyle, BLACK,
-        BLUE, RED, WHITE,
+        ChartBuilder, Color, GradientStyle, IntoDrawingArea, IntoFont, Polygon, Rectangle,
+        SeriesLabelPosition, ShapeStyle, TextStyle, BLACK, BLUE, RED, WHITE,
     };
     use plotters::style::text_anchor::{HPos, Pos, VPos};
     use std::fs;
@@ -827,6 +1379,22 @@ mod test {
         assert_eq!(content.matches("goog").count(), 36);
     }
 
+    #[test]
+    fn test_text_fallback_families() {
+        let mut content: String = Default::default();
+        {
+            let mut root = SVGBackend::with_string(&mut content, (200, 100));
+            let style = TextStyle::from(("sans-serif", 20).into_font())
+                .fallback("Noto Sans CJK SC")
+                .fallback("Noto Color Emoji");
+            root.draw_text("Sales 销售", &style, (10, 50)).unwrap();
+        }
+
+        checked_save_file("test_text_fallback_families", &content);
+
+        assert!(content.contains("font-family=\"sans-serif, Noto Sans CJK SC, Noto Color Emoji\""));
+    }
+
     #[test]
     fn test_text_clipping() {
         let mut content: String = Default::default();
@@ -933,4 +1501,258 @@ mod test {
 
         checked_save_file("test_draw_pixel_alphas", &content);
     }
+
+    #[test]
+    fn test_dashed_lines() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            root.fill(&WHITE).unwrap();
+
+            root.draw(&PathElement::new(
+                vec![(10, 10), (90, 10), (90, 90)],
+                ShapeStyle::from(&RED).stroke_width(2).dashed(&[5.0, 2.5]),
+            ))
+            .unwrap();
+            root.draw(&Circle::new(
+                (50, 50),
+                20,
+                ShapeStyle::from(&BLUE).dashed(&[3.0]),
+            ))
+            .unwrap();
+            root.draw(&PathElement::new(vec![(10, 90), (90, 90)], BLACK))
+                .unwrap();
+        }
+
+        checked_save_file("test_dashed_lines", &content);
+
+        assert!(content.contains(r#"stroke-width="2" stroke-dasharray="5 2.5""#));
+        assert!(content.contains(r#"stroke-dasharray="3""#));
+        assert_eq!(content.matches("stroke-dasharray").count(), 2);
+    }
+
+    #[test]
+    fn test_gradient_fill() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            root.fill(&WHITE).unwrap();
+
+            let gradient = GradientStyle::vertical(&RED, &BLUE.mix(0.5));
+            root.draw(&Rectangle::new([(10, 10), (90, 50)], RED.filled()).with_gradient(gradient))
+                .unwrap();
+            root.draw(
+                &Polygon::new(vec![(10, 60), (90, 60), (50, 90)], RED)
+                    .with_gradient(GradientStyle::diagonal(&RED, &BLUE)),
+            )
+            .unwrap();
+        }
+
+        checked_save_file("test_gradient_fill", &content);
+
+        assert!(content.contains(r#"<linearGradient id="gradient-1" x1="0" y1="0" x2="0" y2="1">"#));
+        assert!(content.contains(r##"fill="url(#gradient-1)""##));
+        assert!(content.contains(r##"<stop offset="1" stop-color="#0000FF" stop-opacity="0.5"/>"##));
+        assert!(content.contains(r#"x2="1" y2="1""#));
+        assert!(content.contains(r##"fill="url(#gradient-2)""##));
+    }
+
+    #[test]
+    fn test_rotated_text() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            let style = TextStyle::from(("sans-serif", 20).into_font()).rotation(-45.0);
+            root.draw_text("rotated", &style, (50, 50)).unwrap();
+        }
+
+        checked_save_file("test_rotated_text", &content);
+
+        assert!(content.contains(r#"transform="rotate(-45, 50, 50)""#));
+    }
+    #[test]
+    fn test_present_closes_clip_group() {
+        let mut content = String::default();
+        {
+            let mut backend = SVGBackend::with_string(&mut content, (100, 100));
+            backend.set_clip_rect(Some(((10, 10), (60, 60))));
+            backend.draw_circle((30, 30), 5, &RED, true).unwrap();
+            backend.present().unwrap();
+            assert!(backend.clip_group.is_none());
+        }
+
+        assert_eq!(
+            content.matches("<g ").count(),
+            content.matches("</g>").count()
+        );
+    }
+
+    #[test]
+    fn test_clipped_shapes() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            let clipped = root.clip_rect((10, 10), (60, 60));
+            // The line is cut at the clip rectangle, grown by the stroke width
+            clipped
+                .draw(&PathElement::new(vec![(20, 20), (20, 90)], BLUE))
+                .unwrap();
+            // The circle out of the clip rectangle is skipped, the other one is clipped
+            clipped.draw(&Circle::new((90, 90), 5, RED)).unwrap();
+            clipped.draw(&Circle::new((60, 30), 5, RED)).unwrap();
+            // Nothing is clipped once out of the clipped area
+            root.draw(&Circle::new((90, 90), 3, BLACK)).unwrap();
+        }
+
+        checked_save_file("test_clipped_shapes", &content);
+
+        assert!(content.contains(r#"<clipPath id="clip-1">"#));
+        assert!(content.contains(r#"<rect x="10" y="10" width="50" height="50"/>"#));
+        assert_eq!(content.matches("<clipPath").count(), 1);
+        assert!(content.contains(r#"points="20,20 20,61 ""#));
+        assert!(content.contains(r#"<circle cx="60" cy="30" r="5""#));
+        assert!(!content.contains(r#"<circle cx="90" cy="90" r="5""#));
+        // The last circle follows the clipping group
+        let group_end = content.find("</g>").unwrap();
+        assert!(content.find(r#"<circle cx="90" cy="90" r="3""#).unwrap() > group_end);
+    }
+
+    fn draw_classed_chart(styling: SVGStyling) -> String {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (200, 200))
+                .with_styling(styling)
+                .into_drawing_area();
+
+            let mut chart = ChartBuilder::on(&root)
+                .set_all_label_area_size(30u32)
+                .build_cartesian_2d(0..10i32, 0..10i32)
+                .unwrap();
+            chart.configure_mesh().draw().unwrap();
+            chart
+                .draw_series_with_class(
+                    std::iter::once(PathElement::new(vec![(0, 0), (10, 10)], RED)),
+                    "revenue",
+                )
+                .unwrap()
+                .label("Revenue")
+                .legend(|(x, y)| Rectangle::new([(x, y - 3), (x + 6, y + 3)], RED.filled()));
+            chart
+                .draw_series(std::iter::once(Circle::new((5, 5), 3u32, BLUE.filled())))
+                .unwrap();
+            chart.configure_series_labels().draw().unwrap();
+        }
+        content
+    }
+
+    #[test]
+    fn test_element_classes() {
+        let content = draw_classed_chart(SVGStyling::InlineAndClasses);
+        checked_save_file("test_element_classes", &content);
+
+        assert!(content.contains(r#"<line class="plotters-line plotters-grid""#));
+        assert!(content.contains(r#"<polyline class="plotters-line plotters-axis""#));
+        assert!(content.contains(r#"<text class="plotters-text plotters-axis""#));
+        assert!(content.contains(r#"<polyline class="plotters-line plotters-series revenue""#));
+        assert!(content.contains(r#"<circle class="plotters-circle plotters-series""#));
+        assert!(content.contains(r#"<rect class="plotters-rect plotters-legend""#));
+        assert!(content.contains(r#"<text class="plotters-text plotters-legend""#));
+        // The inline styles are kept
+        assert!(content.contains(r##"stroke="#FF0000""##));
+
+        // Only the classes
+        let content = draw_classed_chart(SVGStyling::Classes);
+        assert!(content.contains(r#"<polyline class="plotters-line plotters-series revenue""#));
+        assert!(!content.contains("opacity="));
+        assert!(!content.contains(r##"stroke="#"##));
+        // The filled shapes keep their fill
+        assert!(content.contains(r##"<circle class="plotters-circle plotters-series" cx="##));
+        assert!(content.contains(r##"fill="#0000FF" stroke="none""##));
+        assert!(content.contains(r##"fill="#FF0000" stroke="none""##));
+
+        // No class by default
+        let content = draw_classed_chart(SVGStyling::Inline);
+        assert!(!content.contains("class="));
+    }
+
+    #[test]
+    fn test_tooltips() {
+        let mut content = String::default();
+        {
+            let root = SVGBackend::with_string(&mut content, (100, 100)).into_drawing_area();
+            root.draw(
+                &Circle::new((20, 20), 5u32, RED.filled())
+                    .with_tooltip("value: 42 <x>")
+                    .with_data("value", "42")
+                    .with_data("Series Name", "a&b"),
+            )
+            .unwrap();
+            root.draw(&Text::new("label", (50, 50), ("sans-serif", 10)).with_tooltip("a label"))
+                .unwrap();
+            root.draw(&Circle::new((80, 80), 5u32, BLUE.filled()))
+                .unwrap();
+        }
+
+        checked_save_file("test_tooltips", &content);
+
+        assert!(content.contains(
+            r#"<circle data-value="42" data-series-name="a&amp;b" cx="20" cy="20" r="5""#
+        ));
+        assert!(content.contains("<title>value: 42 &lt;x&gt;</title>\n</circle>"));
+        assert!(content.contains("<title>a label</title>\nlabel\n</text>"));
+        // The metadata only applies to its own element
+        assert!(content.contains(r#"<circle cx="80" cy="80" r="5""#));
+        assert_eq!(content.matches("<title>").count(), 2);
+    }
+
+    #[test]
+    fn test_ellipses_and_arcs() {
+        let mut content = String::default();
+        {
+            let mut backend = SVGBackend::with_string(&mut content, (100, 100));
+            backend
+                .draw_ellipse((50, 50), (30.0, 10.5), 0.0, &RED, true)
+                .unwrap();
+            let rotation = std::f64::consts::FRAC_PI_6;
+            backend
+                .draw_ellipse((50, 50), (30.0, 10.0), rotation, &BLUE, false)
+                .unwrap();
+            // A quarter turn, and a full turn split in two halves
+            backend
+                .draw_arc((50, 50), 20, (0.0, std::f64::consts::FRAC_PI_2), &BLACK)
+                .unwrap();
+            backend
+                .draw_arc((50, 50), 10, (0.0, -2.0 * std::f64::consts::PI), &BLACK)
+                .unwrap();
+        }
+
+        checked_save_file("test_ellipses_and_arcs", &content);
+
+        assert!(content.contains(
+            r##"<ellipse cx="50" cy="50" rx="30" ry="10.5" opacity="1" fill="#FF0000" stroke="none""##
+        ));
+        assert!(content.contains(r#"rx="30" ry="10" transform="rotate(30 50 50)""#));
+        assert!(content.contains(r#"<path d="M 70 50 A 20 20 0 0 1 50 70" opacity="1""#));
+        assert!(content.contains(r#"d="M 60 50 A 10 10 0 0 0 40 50 A 10 10 0 0 0 60 50""#));
+        assert_eq!(content.matches(r#"fill="none""#).count(), 3);
+    }
+
+    #[test]
+    fn test_rgba_bitmap() {
+        let mut content = String::default();
+        {
+            let mut backend = SVGBackend::with_string(&mut content, (100, 100));
+            backend
+                .blit_rgba_bitmap((10, 20), (2, 1), &[255, 0, 0, 255, 0, 0, 255, 128])
+                .unwrap();
+        }
+
+        checked_save_file("test_rgba_bitmap", &content);
+
+        // The image is embedded as is, with its alpha channel
+        assert!(content.contains(
+            r#"<image x="10" y="20" width="2" height="1" href="data:png;base64,iVBORw0KGgo"#
+        ));
+        assert!(!content.contains("<rect"));
+    }
 }
@@ -0,0 +1,13 @@
+/*!
+   The Plotters terminal backend.
+
+   The plotters terminal backend allows you to render images by Plotters as text in a terminal,
+   with the lines and points drawn with Unicode braille characters and the text drawn with
+   actual characters, colored with ANSI escape sequences. This is handy for the command line
+   tools and the dashboards used over SSH.
+
+   See the documentation for [TerminalBackend](struct.TerminalBackend.html) for more details.
+*/
+mod terminal;
+
+pub use terminal::TerminalBackend;
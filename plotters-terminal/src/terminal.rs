@@ -0,0 +1,465 @@
+/*!
+The terminal drawing backend
+*/
+
+use plotters_backend::{
+    text_anchor::{HPos, VPos},
+    BackendColor, BackendCoord, BackendTextStyle, DrawingBackend, DrawingErrorKind, TextMetrics,
+};
+
+use std::fmt::Write as _;
+use std::io::{Error, Write};
+
+/// The code point of the blank braille pattern, the dots being the lower bits
+const BRAILLE_BLANK: u32 = 0x2800;
+
+/// The bits of the braille dots in a cell, by row then column
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// The pixels fainter than this are not drawn, since a dot can't be partly visible
+const MIN_ALPHA: f64 = 0.5;
+
+/// The size of a character cell, in pixels
+const CELL_SIZE: (i32, i32) = (2, 4);
+
+enum Target<'a> {
+    Stdout,
+    Writer(Box<dyn Write + 'a>),
+}
+
+/// A character cell of the terminal, which either shows braille dots or a character
+#[derive(Clone, Copy, Default)]
+struct Cell {
+    dots: u8,
+    text: Option<char>,
+    color: Option<(u8, u8, u8)>,
+}
+
+/// Find the closest color of the 256 colors palette of the terminals
+fn ansi_color((r, g, b): (u8, u8, u8)) -> u8 {
+    // The levels of the 6x6x6 color cube, from index 16
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let level = |v: u8| {
+        (0..6)
+            .min_by_key(|&idx| (i32::from(LEVELS[idx]) - i32::from(v)).abs())
+            .unwrap_or(0)
+    };
+    let (lr, lg, lb) = (level(r), level(g), level(b));
+    let cube = (LEVELS[lr], LEVELS[lg], LEVELS[lb]);
+
+    // The gray ramp from index 232, which has finer levels than the cube
+    let mean = (u32::from(r) + u32::from(g) + u32::from(b)) / 3;
+    let gray_idx = (mean.max(8) - 8) / 10;
+    let gray_idx = gray_idx.min(23) as u8;
+    let gray = 8 + 10 * gray_idx;
+
+    let distance = |(cr, cg, cb): (u8, u8, u8)| {
+        [(cr, r), (cg, g), (cb, b)]
+            .iter()
+            .map(|&(a, b)| (i32::from(a) - i32::from(b)).pow(2))
+            .sum::<i32>()
+    };
+    if distance((gray, gray, gray)) < distance(cube) {
+        232 + gray_idx
+    } else {
+        16 + 36 * lr as u8 + 6 * lg as u8 + lb as u8
+    }
+}
+
+/// The terminal drawing backend, which renders the image as text on a grid of character cells.
+///
+/// Each cell holds 2x4 pixels, which are drawn as the dots of a braille character, thus the
+/// size of the backend in pixels is twice the number of columns by four times the number of
+/// rows. The texts are drawn with one character per cell, regardless of the font, and the
+/// colors are mapped to the closest colors of the 256 colors palette of the terminals.
+///
+/// A pixel drawn with the background color, which is white by default, erases the dot, thus
+/// filling the drawing area with it clears the grid. The pixels more than half transparent are
+/// not drawn, which hides the light mesh lines.
+///
+/// The grid is written when the backend is presented or dropped.
+pub struct TerminalBackend<'a> {
+    target: Target<'a>,
+    size: (u32, u32),
+    cells: Vec<Cell>,
+    background: (u8, u8, u8),
+    colored: bool,
+    clip: Option<(BackendCoord, BackendCoord)>,
+    saved: bool,
+}
+
+impl<'a> TerminalBackend<'a> {
+    fn with_target(target: Target<'a>, (cols, rows): (u32, u32)) -> Self {
+        Self {
+            target,
+            size: (cols, rows),
+            cells: vec![Cell::default(); cols as usize * rows as usize],
+            background: (255, 255, 255),
+            colored: true,
+            clip: None,
+            saved: false,
+        }
+    }
+
+    /// Create a new terminal drawing backend which writes to the standard output
+    /// - `size`: The number of columns and rows of characters
+    pub fn new(size: (u32, u32)) -> Self {
+        Self::with_target(Target::Stdout, size)
+    }
+
+    /// Create a new terminal drawing backend and write the text to a writer
+    /// - `size`: The number of columns and rows of characters
+    pub fn with_writer<W: Write + 'a>(writer: W, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Writer(Box::new(writer)), size)
+    }
+
+    /// Set the background color, which erases the dots it is drawn with
+    pub fn background(mut self, rgb: (u8, u8, u8)) -> Self {
+        self.background = rgb;
+        self
+    }
+
+    /// Set if the text is colored with ANSI escape sequences, which it is by default
+    pub fn colored(mut self, colored: bool) -> Self {
+        self.colored = colored;
+        self
+    }
+
+    /// Get the cell at a position in characters, if it is in the grid
+    fn cell_mut(&mut self, (col, row): (i32, i32)) -> Option<&mut Cell> {
+        let (cols, rows) = self.size;
+        if col < 0 || row < 0 || col >= cols as i32 || row >= rows as i32 {
+            return None;
+        }
+        self.cells
+            .get_mut(row as usize * cols as usize + col as usize)
+    }
+
+    fn is_clipped(&self, (x, y): BackendCoord) -> bool {
+        match self.clip {
+            Some((a, b)) => {
+                x < a.0.min(b.0) || x >= a.0.max(b.0) || y < a.1.min(b.1) || y >= a.1.max(b.1)
+            }
+            None => false,
+        }
+    }
+
+    /// Render the grid as text, one line per row
+    fn render(&self) -> String {
+        let mut text = String::new();
+        for row in self.cells.chunks(self.size.0.max(1) as usize) {
+            let mut current = None;
+            for cell in row {
+                // A blank braille pattern is shown as a space, which all the fonts have, and it
+                // keeps the current color, which saves a few escape sequences
+                let chr = match cell.text {
+                    Some(chr) => chr,
+                    None if cell.dots == 0 => ' ',
+                    None => {
+                        std::char::from_u32(BRAILLE_BLANK + u32::from(cell.dots)).unwrap_or(' ')
+                    }
+                };
+                let color = if chr == ' ' { current } else { cell.color };
+                if self.colored && color != current {
+                    match color {
+                        Some(rgb) => {
+                            let _ = write!(text, "\x1b[38;5;{}m", ansi_color(rgb));
+                        }
+                        None => text.push_str("\x1b[0m"),
+                    }
+                    current = color;
+                }
+                text.push(chr);
+            }
+            if self.colored && current.is_some() {
+                text.push_str("\x1b[0m");
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl<'a> DrawingBackend for TerminalBackend<'a> {
+    type ErrorType = Error;
+
+    fn get_size(&self) -> (u32, u32) {
+        (
+            self.size.0 * CELL_SIZE.0 as u32,
+            self.size.1 * CELL_SIZE.1 as u32,
+        )
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Error>> {
+        if !self.saved {
+            let text = self.render();
+            match self.target {
+                Target::Stdout => {
+                    let stdout = std::io::stdout();
+                    let mut stdout = stdout.lock();
+                    stdout
+                        .write_all(text.as_bytes())
+                        .and_then(|_| stdout.flush())
+                        .map_err(DrawingErrorKind::DrawingError)?;
+                }
+                Target::Writer(ref mut writer) => {
+                    writer
+                        .write_all(text.as_bytes())
+                        .and_then(|_| writer.flush())
+                        .map_err(DrawingErrorKind::DrawingError)?;
+                }
+            }
+            self.saved = true;
+        }
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if color.alpha < MIN_ALPHA || self.is_clipped(point) {
+            return Ok(());
+        }
+        let erase = color.rgb == self.background;
+        let dot = BRAILLE_DOTS[point.1.rem_euclid(CELL_SIZE.1) as usize]
+            [point.0.rem_euclid(CELL_SIZE.0) as usize];
+        let cell = (
+            point.0.div_euclid(CELL_SIZE.0),
+            point.1.div_euclid(CELL_SIZE.1),
+        );
+        if let Some(cell) = self.cell_mut(cell) {
+            // The later drawings go over the text
+            cell.text = None;
+            if erase {
+                cell.dots &= !dot;
+            } else {
+                cell.dots |= dot;
+                cell.color = Some(color.rgb);
+            }
+        }
+        Ok(())
+    }
+
+    fn draw_text<S: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &S,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = style.color();
+        if color.alpha < MIN_ALPHA {
+            return Ok(());
+        }
+
+        // The text runs along the rows, or along the columns when it is rotated by a quarter
+        let angle = (style.transform().angle() + style.rotation()).rem_euclid(360.0);
+        let step = if (angle - 90.0).abs() < 45.0 {
+            (0, 1)
+        } else if (angle - 270.0).abs() < 45.0 {
+            (0, -1)
+        } else {
+            (1, 0)
+        };
+        // The parts of the length and of the height of the text before the anchor
+        let along = match style.anchor().h_pos {
+            HPos::Left => 0.0,
+            HPos::Center => 0.5,
+            HPos::Right => 1.0,
+        };
+        let across = match style.anchor().v_pos {
+            VPos::Top => 0.0,
+            VPos::Center => 0.5,
+            VPos::Bottom => 1.0,
+        };
+        let len = text.chars().count() as f64;
+        let (w, h) = (f64::from(CELL_SIZE.0), f64::from(CELL_SIZE.1));
+        let (x, y) = (f64::from(pos.0), f64::from(pos.1));
+        // The upper left corner of the first character, the top of the glyphs facing right
+        // when the text goes down and left when it goes up
+        let (x, y) = match step {
+            (0, 1) => (x - w * (1.0 - across), y - h * len * along),
+            (0, _) => (x - w * across, y + h * len * along - h),
+            _ => (x - w * len * along, y - h * across),
+        };
+        let start = ((x / w).round() as i32, (y / h).round() as i32);
+
+        for (idx, chr) in text.chars().enumerate() {
+            let idx = idx as i32;
+            let cell = (start.0 + idx * step.0, start.1 + idx * step.1);
+            let pixel = (cell.0 * CELL_SIZE.0, cell.1 * CELL_SIZE.1);
+            if self.is_clipped(pixel) {
+                continue;
+            }
+            if let Some(cell) = self.cell_mut(cell) {
+                cell.dots = 0;
+                cell.text = if chr.is_whitespace() { None } else { Some(chr) };
+                cell.color = Some(color.rgb);
+            }
+        }
+        Ok(())
+    }
+
+    fn estimate_text_size<S: BackendTextStyle>(
+        &self,
+        text: &str,
+        _style: &S,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        Ok((
+            text.chars().count() as u32 * CELL_SIZE.0 as u32,
+            CELL_SIZE.1 as u32,
+        ))
+    }
+
+    fn estimate_text_metrics<S: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &S,
+    ) -> Result<TextMetrics, DrawingErrorKind<Self::ErrorType>> {
+        let (width, height) = self.estimate_text_size(text, style)?;
+        // The characters sit on the bottom row of dots of their cell
+        Ok(TextMetrics {
+            width,
+            height,
+            ascent: f64::from(CELL_SIZE.1 - 1),
+            descent: 1.0,
+            line_gap: 0.0,
+        })
+    }
+
+    fn set_clip_rect(&mut self, clip: Option<(BackendCoord, BackendCoord)>) {
+        self.clip = clip;
+    }
+}
+
+impl Drop for TerminalBackend<'_> {
+    fn drop(&mut self) {
+        if !self.saved {
+            // drop should not panic, so we ignore a failed present
+            let _ = self.present();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use plotters::prelude::{
+        ChartBuilder, IntoDrawingArea, LineSeries, PathElement, Text, BLACK, RED, WHITE,
+    };
+
+    #[test]
+    fn test_braille_dots() {
+        let mut backend = TerminalBackend::with_writer(std::io::sink(), (2, 1)).colored(false);
+        assert_eq!(backend.get_size(), (4, 4));
+        let red = BackendColor {
+            alpha: 1.0,
+            rgb: (255, 0, 0),
+        };
+        backend.draw_pixel((0, 0), red).unwrap();
+        backend.draw_pixel((1, 3), red).unwrap();
+        backend.draw_pixel((3, 1), red).unwrap();
+        assert_eq!(backend.render(), "\u{2881}\u{2810}\n");
+
+        // The background color erases the dots, and the faint pixels are skipped
+        let white = BackendColor {
+            alpha: 1.0,
+            rgb: (255, 255, 255),
+        };
+        backend.draw_pixel((3, 1), white).unwrap();
+        backend
+            .draw_pixel((2, 2), BackendColor { alpha: 0.2, ..red })
+            .unwrap();
+        assert_eq!(backend.render(), "\u{2881} \n");
+    }
+
+    #[test]
+    fn test_ansi_color() {
+        assert_eq!(ansi_color((0, 0, 0)), 16);
+        assert_eq!(ansi_color((255, 0, 0)), 196);
+        assert_eq!(ansi_color((0, 0, 255)), 21);
+        assert_eq!(ansi_color((128, 128, 128)), 244);
+
+        let mut backend = TerminalBackend::with_writer(std::io::sink(), (2, 1));
+        let red = BackendColor {
+            alpha: 1.0,
+            rgb: (255, 0, 0),
+        };
+        backend.draw_pixel((0, 0), red).unwrap();
+        assert_eq!(backend.render(), "\x1b[38;5;196m\u{2801} \x1b[0m\n");
+    }
+
+    #[test]
+    fn test_draw_text() {
+        let mut buffer = vec![];
+        {
+            let root = TerminalBackend::with_writer(&mut buffer, (10, 3))
+                .colored(false)
+                .into_drawing_area();
+            root.fill(&WHITE).unwrap();
+            root.draw(&PathElement::new(vec![(0, 11), (19, 11)], BLACK))
+                .unwrap();
+            root.draw(&Text::new("Hello", (2, 4), ("sans-serif", 12)))
+                .unwrap();
+            root.present().unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "          \n Hello    \n\u{28c0}\u{28c0}\u{28c0}\u{28c0}\u{28c0}\u{28c0}\u{28c0}\u{28c0}\u{28c0}\u{28c0}\n"
+        );
+    }
+
+    #[test]
+    fn test_sine_wave() {
+        let (cols, rows) = (60usize, 20usize);
+        let mut buffer = vec![];
+        {
+            let root = TerminalBackend::with_writer(&mut buffer, (cols as u32, rows as u32))
+                .colored(false)
+                .into_drawing_area();
+            root.fill(&WHITE).unwrap();
+            let mut chart = ChartBuilder::on(&root)
+                .build_cartesian_2d(0.0..std::f64::consts::PI * 2.0, -1.0..1.0)
+                .unwrap();
+            chart
+                .draw_series(LineSeries::new(
+                    (0..=200)
+                        .map(|x| x as f64 / 100.0 * std::f64::consts::PI)
+                        .map(|x| (x, x.sin())),
+                    RED,
+                ))
+                .unwrap();
+            root.present().unwrap();
+        }
+
+        let text = String::from_utf8(buffer).unwrap();
+        let grid: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+        assert_eq!(grid.len(), rows);
+        assert!(grid.iter().all(|line| line.len() == cols));
+
+        // Every column crosses the curve, around the row its value maps to
+        let height = (rows * 4 - 1) as f64;
+        let missing: Vec<_> = (0..cols)
+            .filter(|&col| {
+                let x = (col as f64 + 0.5) / cols as f64 * std::f64::consts::PI * 2.0;
+                let row = ((1.0 - x.sin()) / 2.0 * height / 4.0) as usize;
+                !(row.saturating_sub(1)..=(row + 1).min(rows - 1))
+                    .any(|row| ('\u{2801}'..='\u{28ff}').contains(&grid[row][col]))
+            })
+            .collect();
+        assert!(
+            missing.is_empty(),
+            "The curve is missing at {:?}:\n{}",
+            missing,
+            text
+        );
+        // And the rest of the grid is blank
+        let blank = grid.iter().flatten().filter(|&&c| c == ' ').count();
+        assert!(blank > cols * rows * 3 / 4);
+    }
+}
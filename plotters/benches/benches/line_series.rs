@@ -0,0 +1,40 @@
+use criterion::{criterion_group, Criterion};
+use plotters::prelude::*;
+
+const SIZE: (u32, u32) = (1024, 768);
+const POINTS: usize = 10_000_000;
+
+fn draw_series(data: &[(f64, f64)], simplified: bool) {
+    let mut buffer = vec![0; (SIZE.0 * SIZE.1 * 3) as usize];
+    let root = BitMapBackend::with_buffer(&mut buffer, SIZE).into_drawing_area();
+    let mut chart = ChartBuilder::on(&root)
+        .build_cartesian_2d(0.0..POINTS as f64, -1.5..1.5)
+        .unwrap();
+    let series = LineSeries::new(data.iter().copied(), &BLUE);
+    if simplified {
+        chart.draw_series(series.simplified()).unwrap();
+    } else {
+        chart.draw_series(series).unwrap();
+    }
+}
+
+fn draw_10m_points(c: &mut Criterion) {
+    let data: Vec<_> = (0..POINTS)
+        .map(|i| {
+            let x = i as f64;
+            (x, (x / 1e5).sin() + (x * 0.37).sin() * 0.2)
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("line_series::draw_10m_points");
+    group.sample_size(10);
+    group.bench_function("full", |b| b.iter(|| draw_series(&data, false)));
+    group.bench_function("simplified", |b| b.iter(|| draw_series(&data, true)));
+    group.finish();
+}
+
+criterion_group! {
+    name = line_series_group;
+    config = Criterion::default();
+    targets = draw_10m_points
+}
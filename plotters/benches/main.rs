@@ -3,5 +3,6 @@ use criterion::criterion_main;
 mod benches;
 
 criterion_main! {
-    benches::data::quartiles_group
+    benches::data::quartiles_group,
+    benches::line_series::line_series_group
 }
@@ -0,0 +1,44 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/3d-camera.gif";
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::gif(OUT_FILE_NAME, (600, 400), 50)?.into_drawing_area();
+
+    // Orbit the camera around the cube, a full turn in 72 frames
+    for frame in 0..72 {
+        root.fill(&WHITE)?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .caption("Orbiting camera", ("sans-serif", 20))
+            .build_cartesian_3d(-1.0..1.0, -1.0..1.0, -1.0..1.0)?;
+        chart.with_projection(|mut pb| {
+            let yaw = frame as f64 * std::f64::consts::PI / 36.0;
+            pb.perspective(1.0).camera_angle(0.4, yaw, 0.0).scale(0.6);
+            pb.into_matrix()
+        });
+
+        chart
+            .configure_axes()
+            .light_grid_style(BLACK.mix(0.15))
+            .max_light_lines(3)
+            .draw()?;
+
+        chart.draw_series(std::iter::once(Cubiod::new(
+            [(-0.5, -0.5, -0.5), (0.5, 0.5, 0.5)],
+            BLUE.mix(0.3),
+            BLUE,
+        )))?;
+
+        root.present()?;
+    }
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
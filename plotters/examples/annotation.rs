@@ -0,0 +1,84 @@
+use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/annotation.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // A spectrum made of two peaks over a slowly decaying background
+    let spectrum = |x: f64| {
+        let peak = |center: f64, width: f64, height: f64| {
+            height * (-((x - center) / width).powi(2) / 2.0).exp()
+        };
+        20.0 * (-x / 8.0).exp() + peak(3.2, 0.25, 60.0) + peak(6.5, 0.4, 25.0)
+    };
+    let points: Vec<(f64, f64)> = (0..=1000)
+        .map(|i| i as f64 / 100.0)
+        .map(|x| (x, spectrum(x)))
+        .collect();
+    let peak = points
+        .iter()
+        .copied()
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .unwrap();
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Annotated spectrum", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0.0..10.0, 0.0..100.0)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Energy (keV)")
+        .y_desc("Counts")
+        .draw()?;
+
+    chart.draw_series(LineSeries::new(points, BLUE.stroke_width(2)))?;
+
+    // The arrow goes from the label to just above the peak
+    let label = (5.0, 85.0);
+    chart.draw_series(std::iter::once(Arrow::new(
+        label,
+        (peak.0 + 0.05, peak.1 + 2.0),
+        BLACK.stroke_width(2),
+        ArrowHead::new(14),
+    )))?;
+    chart.draw_series(std::iter::once(Text::new(
+        format!("Main peak at {:.2} keV", peak.0),
+        label,
+        ("sans-serif", 20)
+            .into_font()
+            .color(&BLACK)
+            .pos(Pos::new(HPos::Left, VPos::Bottom)),
+    )))?;
+
+    // An open double headed arrow measures the distance between the two peaks
+    chart.draw_series(std::iter::once(Arrow::new(
+        (peak.0, 45.0),
+        (6.5, 45.0),
+        RED,
+        ArrowHead::new(10).open().ends(ArrowEnds::Both),
+    )))?;
+    chart.draw_series(std::iter::once(Text::new(
+        format!("{:.1} keV", 6.5 - peak.0),
+        ((peak.0 + 6.5) / 2.0, 45.0),
+        ("sans-serif", 16)
+            .into_font()
+            .color(&RED)
+            .pos(Pos::new(HPos::Center, VPos::Bottom)),
+    )))?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
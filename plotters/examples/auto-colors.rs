@@ -0,0 +1,53 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/auto-colors.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Harmonics", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(35)
+        .y_label_area_size(45)
+        .build_cartesian_2d(0.0..std::f64::consts::PI * 2.0, -1.2..1.2)?;
+
+    chart.configure_mesh().draw()?;
+
+    // None of the series is given a color, each one gets the next one of the palette
+    let mut colors = vec![];
+    for harmonic in 1..=5 {
+        chart
+            .draw_series_auto(|color| {
+                colors.push(color);
+                LineSeries::new(
+                    (0..=400)
+                        .map(|x| x as f64 * std::f64::consts::PI / 200.0)
+                        .map(|x| (x, (x * harmonic as f64).sin() / harmonic as f64)),
+                    color.stroke_width(2),
+                )
+            })?
+            .label(format!("n = {}", harmonic));
+    }
+    for (idx, color) in colors.iter().enumerate() {
+        assert!(!colors[..idx].contains(color), "The series colors differ");
+    }
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperRight)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,61 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/background-bands.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let fruits = ["apple", "banana", "cherry", "grape", "orange", "pear"];
+    // The boxes sold over four weeks
+    let sales = [
+        [3, 5, 2, 4],
+        [7, 6, 8, 5],
+        [1, 2, 2, 1],
+        [5, 4, 6, 6],
+        [3, 4, 2, 3],
+        [2, 2, 3, 3],
+    ];
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Monthly sales by fruit", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(35)
+        .y_label_area_size(40)
+        .build_cartesian_2d((0..fruits.len() - 1).into_segmented(), 0..30)?;
+
+    // Every other category is shaded, and the bands end exactly at the ticks between them
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .background_bands([WHITE, RGBColor(230, 230, 230)])
+        .x_label_formatter(&|x| match x {
+            SegmentValue::CenterOf(index) => fruits[*index].to_string(),
+            _ => String::new(),
+        })
+        .y_desc("Boxes sold")
+        .draw()?;
+
+    // The total sales of each fruit, as a bar centered in its band
+    chart.draw_series(
+        Histogram::vertical(&chart)
+            .style(BLUE.mix(0.7).filled())
+            .margin(20)
+            .data(
+                sales
+                    .iter()
+                    .enumerate()
+                    .map(|(fruit, weeks)| (fruit, weeks.iter().sum())),
+            ),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,75 @@
+use plotters::prelude::*;
+
+use rand::SeedableRng;
+use rand_distr::{Distribution, Normal, Pareto};
+use rand_xorshift::XorShiftRng;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/boxplot-from-data.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    // Three groups of raw response times, the last one with a long tail of slow requests
+    let mut rng = XorShiftRng::from_seed(*b"BoxplotFromData1");
+    let fast = Normal::new(40.0, 8.0)?;
+    let slow = Normal::new(75.0, 15.0)?;
+    let tail = Pareto::new(30.0, 2.5)?;
+    let groups = [
+        (
+            "cache",
+            (0..200)
+                .map(|_| fast.sample(&mut rng))
+                .collect::<Vec<f64>>(),
+        ),
+        (
+            "database",
+            (0..120).map(|_| slow.sample(&mut rng)).collect(),
+        ),
+        ("remote", (0..80).map(|_| tail.sample(&mut rng)).collect()),
+    ];
+    let names: Vec<_> = groups.iter().map(|(name, _)| *name).collect();
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Response times by backend", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(35)
+        .y_label_area_size(50)
+        .build_cartesian_2d(names.into_segmented(), 0f32..140f32)?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_label_formatter(&|x| match x {
+            SegmentValue::CenterOf(name) => name.to_string(),
+            _ => String::new(),
+        })
+        .y_desc("Time (ms)")
+        .draw()?;
+
+    // The quartiles and the outliers of each group are computed from its raw values, and the
+    // whiskers stop at the fences, or at the extreme values when they are closer
+    chart.draw_series(
+        BoxplotSeries::from_groups(
+            groups
+                .iter()
+                .map(|(name, values)| (SegmentValue::CenterOf(name), values)),
+            Quartiles::fair,
+        )
+        .width(60)
+        .width_by_count()
+        .style(BLUE)
+        .box_fill(BLUE.mix(0.2))
+        .outlier_style(RED.mix(0.6)),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,72 @@
+use plotters::data::fitting_range;
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/boxplot-styled.png";
+
+// Deterministic samples spread around a center, so the example needs no random generator
+fn samples(center: f64, spread: f64, count: usize) -> Vec<f64> {
+    (0..count)
+        .map(|idx| {
+            let t = (idx as f64 + 0.5) / count as f64;
+            center + spread * (t - 0.5) * 4.0 * (1.0 + 0.5 * (idx as f64 * 2.3).sin())
+        })
+        .collect()
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let groups = ["Control", "Dose A", "Dose B", "Dose C"];
+    let data: Vec<_> = groups
+        .iter()
+        .zip([(42.0, 6.0), (47.0, 8.0), (55.0, 5.0), (61.0, 9.0)].iter())
+        .map(|(name, &(center, spread))| (name, Quartiles::new(&samples(center, spread, 60))))
+        .collect();
+    let values: Vec<f32> = data.iter().flat_map(|(_, q)| q.values().to_vec()).collect();
+    let values_range = fitting_range(values.iter());
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Response by treatment", ("serif", 30))
+        .margin(20)
+        .x_label_area_size(40)
+        .y_label_area_size(70)
+        .build_cartesian_2d(
+            groups[..].into_segmented(),
+            values_range.start - 5.0..values_range.end + 5.0,
+        )?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .light_line_style(TRANSPARENT)
+        .bold_line_style(BLACK.mix(0.1))
+        .label_style(("serif", 18))
+        .x_label_formatter(&|group| match group {
+            SegmentValue::CenterOf(name) => name.to_string(),
+            _ => String::new(),
+        })
+        .y_desc("Response (mg/L)")
+        .draw()?;
+
+    // Light boxes under a dark outline, a thick median and caps at half of the box width
+    chart.draw_series(data.iter().map(|(name, quartiles)| {
+        Boxplot::new_vertical(SegmentValue::CenterOf(*name), quartiles)
+            .width(60)
+            .style(BLACK.stroke_width(1))
+            .whisker_cap_width(0.5)
+            .box_fill(RGBColor(70, 130, 180).mix(0.35))
+            .box_outline(BLACK.stroke_width(2))
+            .median_style(RGBColor(200, 30, 30).stroke_width(3))
+    }))?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,62 @@
+use plotters::prelude::*;
+
+use rand::SeedableRng;
+use rand_distr::{Distribution, Normal};
+use rand_xorshift::XorShiftRng;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/broken-axis.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The latency of 200 requests, most of them served in a few tens of milliseconds, and a few
+    // of them timing out after 5 seconds
+    let mut rand = XorShiftRng::from_seed(*b"MyFragileSeed123");
+    let latency = Normal::new(30.0f64, 8.0).unwrap();
+    let timeout = Normal::new(5000.0f64, 5.0).unwrap();
+    let requests: Vec<(u32, f64)> = (0..200)
+        .map(|idx| {
+            if idx % 37 == 11 {
+                (idx, timeout.sample(&mut rand))
+            } else {
+                (idx, latency.sample(&mut rand).max(1.0))
+            }
+        })
+        .collect();
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    // The Y axis skips from 70 ms to 4980 ms, leaving 5% of its height to the break
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Request latency", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(60)
+        .build_cartesian_2d(
+            0u32..200u32,
+            BrokenAxis::new(vec![0.0..70.0, 4980.0..5020.0], 0.05),
+        )?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .y_labels(12)
+        .x_desc("Request")
+        .y_desc("Latency (ms)")
+        .draw()?;
+
+    chart.draw_series(
+        requests
+            .iter()
+            .map(|&(idx, ms)| Circle::new((idx, ms), 3, BLUE.filled())),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,61 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/bubble.png";
+
+// Approximate figures for 2020: GDP per capita (USD), life expectancy (years), population (millions)
+const COUNTRIES: [(&str, f64, f64, f64); 10] = [
+    ("China", 10_500.0, 77.1, 1_411.0),
+    ("India", 1_900.0, 70.2, 1_380.0),
+    ("USA", 63_500.0, 77.3, 331.0),
+    ("Indonesia", 3_900.0, 71.9, 273.0),
+    ("Brazil", 6_800.0, 74.0, 212.0),
+    ("Nigeria", 2_100.0, 55.0, 206.0),
+    ("Japan", 40_100.0, 84.6, 126.0),
+    ("Germany", 46_200.0, 81.1, 83.0),
+    ("Ethiopia", 940.0, 66.6, 115.0),
+    ("Norway", 67_300.0, 83.2, 5.4),
+];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1024, 768)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Wealth and health of nations (2020)", ("sans-serif", 30))
+        .margin(20)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d((500.0..100_000.0).log_scale(), 50.0..90.0)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("GDP per capita (USD)")
+        .y_desc("Life expectancy (years)")
+        .x_label_formatter(&|x| format!("{:.0}", x))
+        .draw()?;
+
+    // The area of each bubble is proportional to the population of the country
+    chart.draw_series(
+        BubbleSeries::new(
+            COUNTRIES
+                .iter()
+                .map(|&(_, gdp, life, pop)| (gdp, life, pop)),
+            BLUE.mix(0.4).filled(),
+        )
+        .radius_range(3.0, 60.0),
+    )?;
+
+    chart.draw_series(COUNTRIES.iter().map(|&(name, gdp, life, _)| {
+        Text::new(name, (gdp, life), ("sans-serif", 15).into_font())
+    }))?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,83 @@
+use plotters::prelude::*;
+
+use rand::SeedableRng;
+use rand_distr::{Distribution, Normal};
+use rand_xorshift::XorShiftRng;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/chart-grid.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1024, 768)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = root.titled("Four charts in one report", ("sans-serif", 30))?;
+
+    let mut rng = XorShiftRng::from_seed(*b"ChartGridSeed123");
+    let normal = Normal::new(5.0, 1.5)?;
+    let samples: Vec<f64> = (0..500).map(|_| normal.sample(&mut rng)).collect();
+
+    // The cells are indexed by row, then by column
+    let grid = root.grid_of_charts(2, 2);
+
+    let mut line = ChartBuilder::on(&grid[0][0])
+        .caption("Line", ("sans-serif", 20))
+        .margin(10)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d(0.0..10.0, -1.2..1.2)?;
+    line.configure_mesh().draw()?;
+    line.draw_series(LineSeries::new(
+        (0..=200).map(|i| i as f64 / 20.0).map(|x| (x, x.sin())),
+        RED.stroke_width(2),
+    ))?;
+
+    let mut histogram = ChartBuilder::on(&grid[0][1])
+        .caption("Histogram", ("sans-serif", 20))
+        .margin(10)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d((0u32..10u32).into_segmented(), 0u32..150u32)?;
+    histogram.configure_mesh().disable_x_mesh().draw()?;
+    histogram.draw_series(
+        Histogram::vertical(&histogram)
+            .style(BLUE.mix(0.6).filled())
+            .margin(3)
+            .data(samples.iter().map(|x| (x.clamp(0.0, 9.0) as u32, 1))),
+    )?;
+
+    let mut scatter = ChartBuilder::on(&grid[1][0])
+        .caption("Scatter", ("sans-serif", 20))
+        .margin(10)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d(0.0..10.0, 0.0..10.0)?;
+    scatter.configure_mesh().draw()?;
+    scatter.draw_series(
+        samples
+            .chunks(2)
+            .map(|pair| Circle::new((pair[0], pair[1]), 2, GREEN.mix(0.6).filled())),
+    )?;
+
+    // A random walk of the deviations of the samples from their mean
+    let walk: Vec<(usize, f64)> = samples
+        .iter()
+        .scan(0.0, |total, x| {
+            *total += x - 5.0;
+            Some(*total)
+        })
+        .enumerate()
+        .collect();
+    let mut area = ChartBuilder::on(&grid[1][1])
+        .caption("Area", ("sans-serif", 20))
+        .margin(10)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d(0..walk.len(), (-40.0..40.0).step(10.0))?;
+    area.configure_mesh().draw()?;
+    area.draw_series(AreaSeries::new(walk, 0.0, MAGENTA.mix(0.3)).border_style(MAGENTA))?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,53 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/colored-spiral.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 800)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Spiral colored by angle", ("sans-serif", 30))
+        .margin(20)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(-1.1..1.1, -1.1..1.1)?;
+
+    chart.configure_mesh().draw()?;
+
+    // Three turns of an archimedean spiral, the value of each point is its angle
+    let turns = 3.0;
+    let max_angle = turns * 2.0 * std::f64::consts::PI;
+    let spiral = (0..=1000).map(|i| {
+        let angle = i as f64 / 1000.0 * max_angle;
+        let radius = angle / max_angle;
+        (radius * angle.cos(), radius * angle.sin(), angle)
+    });
+
+    chart
+        .draw_series(ColoredLineSeries::new(spiral, &ViridisRGB, 3))?
+        .label("Archimedean spiral")
+        .legend(|(x, y)| {
+            PathElement::new(
+                vec![(x, y), (x + 20, y)],
+                ViridisRGB.get_color(0.5).stroke_width(3),
+            )
+        });
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperLeft)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -3,8 +3,11 @@ use plotters::prelude::*;
 const OUT_FILE_NAME: &str = "plotters-doc-data/colormaps.png";
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let colormaps_rgb: [(Box<dyn ColorMap<RGBColor>>, &str); 4] = [
+    let colormaps_rgb: [(Box<dyn ColorMap<RGBColor>>, &str); 7] = [
         (Box::new(ViridisRGB {}), "Viridis"),
+        (Box::new(MagmaRGB {}), "Magma"),
+        (Box::new(InfernoRGB {}), "Inferno"),
+        (Box::new(PlasmaRGB {}), "Plasma"),
         (Box::new(BlackWhite {}), "BlackWhite"),
         (Box::new(Bone {}), "Bone"),
         (Box::new(Copper {}), "Copper"),
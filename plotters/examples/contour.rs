@@ -0,0 +1,49 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/contour.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // A gaussian bump, stretched along the X axis, sampled on a 101x101 grid
+    let (x_range, y_range) = (-3.0..3.0, -3.0..3.0);
+    let sample = |i: usize, n: usize, range: &std::ops::Range<f64>| {
+        range.start + (range.end - range.start) * i as f64 / (n - 1) as f64
+    };
+    let grid: Vec<Vec<f64>> = (0..101)
+        .map(|j| {
+            let y = sample(j, 101, &y_range);
+            (0..101)
+                .map(|i| {
+                    let x = sample(i, 101, &x_range);
+                    (-(x - 0.5) * (x - 0.5) / 2.0 - (y + 0.3) * (y + 0.3) / 0.8).exp()
+                })
+                .collect()
+        })
+        .collect();
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (640, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Contours of a gaussian bump", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(x_range.clone(), y_range.clone())?;
+
+    chart.configure_mesh().disable_mesh().draw()?;
+
+    let levels = (1..10).map(|level| level as f64 / 10.0);
+    chart.draw_series(
+        ContourSeries::new(x_range, y_range, &grid, levels, &ViridisRGB).stroke_width(2),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
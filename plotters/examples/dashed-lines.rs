@@ -0,0 +1,72 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/dashed-lines.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Daily response times of a service, checked against its latency target
+    let target = 120.0;
+    let latency: Vec<_> = (0..60)
+        .map(|d| {
+            let d = d as f64;
+            (d, 100.0 + 25.0 * (d / 6.0).sin() + 10.0 * (d / 1.7).cos())
+        })
+        .collect();
+    let average = latency.iter().map(|(_, l)| l).sum::<f64>() / latency.len() as f64;
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1024, 768)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .x_label_area_size(40)
+        .y_label_area_size(60)
+        .margin(10)
+        .caption("Response time", ("sans-serif", 40))
+        .build_cartesian_2d(0.0..59.0, 50.0..150.0)?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_desc("Day")
+        .y_desc("Latency (ms)")
+        .draw()?;
+
+    chart
+        .draw_series(LineSeries::new(latency, BLUE.stroke_width(2)))?
+        .label("Latency")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE.stroke_width(2)));
+
+    let target_style = RED.stroke_width(2).dashed(&[12.0, 6.0]);
+    chart
+        .draw_series(LineSeries::new(
+            vec![(0.0, target), (59.0, target)],
+            target_style,
+        ))?
+        .label("Target")
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], target_style));
+
+    let average_style = BLACK.stroke_width(2).dashed(&[2.0, 4.0]);
+    chart
+        .draw_series(LineSeries::new(
+            vec![(0.0, average), (59.0, average)],
+            average_style,
+        ))?
+        .label("Average")
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], average_style));
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::LowerLeft)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,61 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/dendrogram.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    // The average linkage of five animals, in the layout of SciPy: the leaves are the clusters 0
+    // to 4, and each merge creates the next cluster
+    let animals = ["cat", "lion", "wolf", "dog", "eagle"];
+    let merges = [(0, 1, 1.2), (2, 3, 1.8), (5, 6, 4.5), (4, 7, 7.0)];
+    let cut = 3.0;
+
+    let dendrogram = Dendrogram::new(&merges)
+        .color_threshold(cut)
+        .style(BLACK.stroke_width(2));
+    let order = dendrogram.leaf_order();
+    let positions: Vec<f64> = (0..order.len()).map(|k| k as f64).collect();
+
+    let (leaves, heights) = (dendrogram.leaf_range(), dendrogram.height_range());
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Hierarchical clustering", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(35)
+        .y_label_area_size(50)
+        .build_cartesian_2d(leaves.clone(), heights.start..heights.end * 1.1)?;
+
+    // The leaves are labeled with ticks at their positions
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_ticks(&positions)
+        .x_label_formatter(&|x| animals[order[x.round() as usize]].to_string())
+        .y_desc("Distance")
+        .draw()?;
+
+    chart.draw_series(dendrogram.cluster_styles([
+        BLUE.stroke_width(2),
+        RED.stroke_width(2),
+        GREEN.stroke_width(2),
+    ]))?;
+
+    // The cut height, below which the clusters are colored
+    chart.draw_series(DashedLineSeries::new(
+        vec![(leaves.start, cut), (leaves.end, cut)],
+        10,
+        5,
+        BLACK.mix(0.5).into(),
+    ))?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
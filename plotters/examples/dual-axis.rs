@@ -0,0 +1,79 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/dual-axis.png";
+
+const TEMPERATURE_COLOR: RGBColor = RGBColor(220, 80, 40);
+const HUMIDITY_COLOR: RGBColor = RGBColor(40, 110, 200);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Hourly readings of a weather station over a day
+    let temperature: Vec<_> = (0..24)
+        .map(|h| {
+            let phase = (h as f64 - 9.0) / 24.0 * 2.0 * std::f64::consts::PI;
+            (h, 18.0 + 7.0 * phase.sin())
+        })
+        .collect();
+    let humidity: Vec<_> = temperature
+        .iter()
+        .map(|&(h, t)| (h, 95.0 - 2.5 * t))
+        .collect();
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1024, 768)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .x_label_area_size(40)
+        .y_label_area_size(60)
+        .right_y_label_area_size(60)
+        .margin(10)
+        .caption("Temperature and Humidity", ("sans-serif", 40))
+        .build_cartesian_2d(0..23, 5.0..30.0)?
+        .set_secondary_coord(0..23, 0.0..100.0);
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_desc("Hour of the day")
+        .y_desc("Temperature")
+        .x_label_formatter(&|h| format!("{:02}:00", h))
+        .y_label_formatter(&|t| format!("{:.0}°C", t))
+        .y_label_style(("sans-serif", 15).into_font().color(&TEMPERATURE_COLOR))
+        .draw()?;
+
+    chart
+        .configure_secondary_axes()
+        .y_desc("Relative humidity")
+        .y_label_formatter(&|h| format!("{:.0}%", h))
+        .y_label_style(("sans-serif", 15).into_font().color(&HUMIDITY_COLOR))
+        .axis_style(HUMIDITY_COLOR)
+        .draw()?;
+
+    chart
+        .draw_series(LineSeries::new(
+            temperature,
+            TEMPERATURE_COLOR.stroke_width(2),
+        ))?
+        .label("Temperature")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], TEMPERATURE_COLOR));
+
+    chart
+        .draw_secondary_series(LineSeries::new(humidity, HUMIDITY_COLOR.stroke_width(2)))?
+        .label("Humidity")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], HUMIDITY_COLOR));
+
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
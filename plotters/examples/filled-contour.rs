@@ -0,0 +1,72 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/filled-contour.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Two gaussian bumps of different heights, sampled on a 61x61 grid
+    let (x_range, y_range) = (-3.0..3.0, -3.0..3.0);
+    let sample = |i: usize, n: usize, range: &std::ops::Range<f64>| {
+        range.start + (range.end - range.start) * i as f64 / (n - 1) as f64
+    };
+    let grid: Vec<Vec<f64>> = (0..61)
+        .map(|j| {
+            let y = sample(j, 61, &y_range);
+            (0..61)
+                .map(|i| {
+                    let x = sample(i, 61, &x_range);
+                    (-(x + 1.0).powi(2) / 1.5 - (y + 0.5).powi(2)).exp()
+                        + 0.6 * (-(x - 1.2).powi(2) - (y - 1.0).powi(2) / 0.8).exp()
+                })
+                .collect()
+        })
+        .collect();
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (720, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Filled contours of two gaussian bumps", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .right_legend_area_size(80)
+        .build_cartesian_2d(x_range.clone(), y_range.clone())?;
+
+    chart.configure_mesh().disable_mesh().draw()?;
+
+    // The levels span the values, thus the bands fill the whole grid
+    let levels: Vec<f64> = (0..=10).map(|level| level as f64 / 10.0).collect();
+    chart.draw_series(FilledContourSeries::new(
+        x_range.clone(),
+        y_range.clone(),
+        &grid,
+        levels.iter().cloned(),
+        &ViridisRGB,
+    ))?;
+    // The contour lines of the same levels outline the bands
+    let outline = DerivedColorMap::new(&[BLACK.mix(0.4), BLACK.mix(0.4)]);
+    chart.draw_series(ContourSeries::new(
+        x_range,
+        y_range,
+        &grid,
+        levels[1..10].iter().cloned(),
+        &outline,
+    ))?;
+
+    chart
+        .configure_colorbar(0.0..1.0, &ViridisRGB)
+        .bar_width(25)
+        .labels(11)
+        .label_formatter(|value| format!("{:.1}", value))
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
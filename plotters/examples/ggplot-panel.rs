@@ -0,0 +1,82 @@
+use plotters::prelude::*;
+
+use rand::SeedableRng;
+use rand_distr::{Distribution, Normal};
+use rand_xorshift::XorShiftRng;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/ggplot-panel.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The weight of cars against their fuel consumption, in three groups of cylinders
+    let mut rand = XorShiftRng::from_seed(*b"MyFragileSeed123");
+    let noise = Normal::new(0.0, 1.8).unwrap();
+    let groups: Vec<_> = [
+        ("4 cylinders", RGBColor(248, 118, 109), 1.6..2.8),
+        ("6 cylinders", RGBColor(0, 186, 56), 2.6..3.6),
+        ("8 cylinders", RGBColor(97, 156, 255), 3.4..5.4),
+    ]
+    .iter()
+    .map(|(name, color, weights)| {
+        let points: Vec<_> = (0..15)
+            .map(|i| {
+                let weight = weights.start + (weights.end - weights.start) * i as f64 / 14.0;
+                (weight, 37.0 - 5.0 * weight + noise.sample(&mut rand))
+            })
+            .collect();
+        (*name, *color, points)
+    })
+    .collect();
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 560)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    // The gray panel only covers the plotting area, the rest of the figure stays white
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Fuel consumption by weight", ("sans-serif", 28))
+        .margin(15)
+        .x_label_area_size(45)
+        .y_label_area_size(50)
+        .set_plot_area_background(&RGBColor(235, 235, 235))
+        .build_cartesian_2d(1.5..5.5, 5.0..35.0)?;
+
+    // The white grid lines go over the panel, and under the data
+    chart
+        .configure_mesh()
+        .axis_style(WHITE)
+        .bold_line_style(WHITE.stroke_width(2))
+        .light_line_style(WHITE.mix(0.6))
+        .max_light_lines(1)
+        .set_all_tick_mark_size(4)
+        .x_desc("Weight (1000 lbs)")
+        .y_desc("Miles per gallon")
+        .label_style(("sans-serif", 14).into_font().color(&RGBColor(77, 77, 77)))
+        .draw()?;
+
+    for (name, color, points) in groups {
+        chart
+            .draw_series(
+                points
+                    .into_iter()
+                    .map(|point| Circle::new(point, 5, color.filled())),
+            )?
+            .label(name)
+            .legend(move |(x, y)| Circle::new((x + 10, y), 5, color.filled()));
+    }
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperRight)
+        .background_style(WHITE)
+        .border_style(RGBColor(200, 200, 200))
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
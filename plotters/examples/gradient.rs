@@ -0,0 +1,85 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/gradient.png";
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sales = [34, 41, 38, 52, 61, 73, 80, 76, 64, 55, 47, 58];
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1024, 768)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let (upper, lower) = root.split_vertically(384);
+
+    // A bar chart whose bars fade from a deep to a light color
+    let mut chart = ChartBuilder::on(&upper)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .margin(10)
+        .caption("Monthly sales", ("sans-serif", 30))
+        .build_cartesian_2d((0..11).into_segmented(), 0..100)?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_labels(12)
+        .x_label_formatter(&|m| match m {
+            SegmentValue::CenterOf(m) => MONTHS[*m as usize].to_string(),
+            _ => String::new(),
+        })
+        .draw()?;
+
+    let bar_gradient = GradientStyle::vertical(&RGBColor(20, 60, 160), &RGBColor(120, 180, 250));
+    chart.draw_series(sales.iter().enumerate().map(|(m, s)| {
+        let mut bar = Rectangle::new(
+            [
+                (SegmentValue::Exact(m as i32), *s),
+                (SegmentValue::Exact(m as i32 + 1), 0),
+            ],
+            BLUE.filled(),
+        )
+        .with_gradient(bar_gradient.clone());
+        bar.set_margin(0, 0, 8, 8);
+        bar
+    }))?;
+
+    // An area chart fading out towards its baseline
+    let mut chart = ChartBuilder::on(&lower)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .margin(10)
+        .caption("Cumulative sales", ("sans-serif", 30))
+        .build_cartesian_2d(0.0..11.0, 0.0..700.0)?;
+
+    chart.configure_mesh().disable_x_mesh().draw()?;
+
+    let cumulative: Vec<_> = sales
+        .iter()
+        .scan(0, |total, s| {
+            *total += s;
+            Some(*total as f64)
+        })
+        .enumerate()
+        .map(|(m, total)| (m as f64, total))
+        .collect();
+    chart.draw_series(
+        AreaSeries::new(cumulative, 0.0, RED)
+            .with_gradient(
+                GradientStyle::vertical(&RED.mix(0.8), &RED.mix(0.0)).add_stop(0.4, &RED.mix(0.5)),
+            )
+            .border_style(RED.stroke_width(2)),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,60 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/grouped-bars.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The quarterly sales of three products, in thousands of units
+    let products = ["Widgets", "Gadgets", "Gizmos"];
+    let sales = vec![
+        (0, vec![12, 15, 18, 22]),
+        (1, vec![8, 11, 9, 14]),
+        (2, vec![20, 17, 23, 25]),
+    ];
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Quarterly sales", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d((0..2).into_segmented(), 0..30)?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .y_desc("Units sold (thousands)")
+        .x_label_formatter(&|x| match x {
+            SegmentValue::CenterOf(idx) => products[*idx as usize].to_string(),
+            _ => String::new(),
+        })
+        .label_style(("sans-serif", 16))
+        .draw()?;
+
+    GroupedBarSeries::new(sales)
+        .sub_series("Q1", Palette99::pick(0).filled())
+        .sub_series("Q2", Palette99::pick(1).filled())
+        .sub_series("Q3", Palette99::pick(2).filled())
+        .sub_series("Q4", Palette99::pick(3).filled())
+        .group_margin(60)
+        .bar_spacing(6)
+        .draw(&mut chart)?;
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperLeft)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
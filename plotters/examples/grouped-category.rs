@@ -0,0 +1,58 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/grouped-category.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sales = [
+        ("Fruits", "Apples", 42),
+        ("Fruits", "Bananas", 35),
+        ("Fruits", "Cherries", 18),
+        ("Vegetables", "Carrots", 27),
+        ("Vegetables", "Leeks", 12),
+        ("Vegetables", "Potatoes", 46),
+    ];
+
+    let products = GroupedCategory::new(vec![
+        ("Fruits", vec!["Apples", "Bananas", "Cherries"]),
+        ("Vegetables", vec!["Carrots", "Leeks", "Potatoes"]),
+    ]);
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (640, 480)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        // Leave room for the two rows of labels: the products and their groups
+        .x_label_area_size(60)
+        .y_label_area_size(40)
+        .margin(10)
+        .caption("Sales by product", ("sans-serif", 40))
+        .build_cartesian_2d(products.into_segmented(), 0..50)?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .y_desc("Sales")
+        .axis_desc_style(("sans-serif", 15))
+        .draw()?;
+
+    chart.draw_series(
+        Histogram::vertical(&chart)
+            .style(BLUE.mix(0.6).filled())
+            .margin(10)
+            .data(
+                sales
+                    .iter()
+                    .map(|(group, product, count)| ((*group, *product), *count)),
+            ),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
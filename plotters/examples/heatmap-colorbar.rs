@@ -0,0 +1,66 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/heatmap-colorbar.png";
+const SIZE: usize = 40;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The elevation of a landscape made of two hills and a basin
+    let elevation: Vec<Vec<f64>> = (0..SIZE)
+        .map(|row| {
+            (0..SIZE)
+                .map(|col| {
+                    let (x, y) = (col as f64 / SIZE as f64, row as f64 / SIZE as f64);
+                    let bump = |cx: f64, cy: f64, r: f64| {
+                        (-((x - cx).powi(2) + (y - cy).powi(2)) / (r * r)).exp()
+                    };
+                    1200.0 * bump(0.3, 0.7, 0.2) + 800.0 * bump(0.75, 0.35, 0.15)
+                        - 400.0 * bump(0.6, 0.8, 0.12)
+                        + 200.0
+                })
+                .collect()
+        })
+        .collect();
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (720, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Elevation map", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .right_legend_area_size(90)
+        .build_cartesian_2d(
+            (0..SIZE as i32 - 1).into_segmented(),
+            (0..SIZE as i32 - 1).into_segmented(),
+        )?;
+
+    chart
+        .configure_mesh()
+        .disable_mesh()
+        .x_desc("km")
+        .y_desc("km")
+        .draw()?;
+
+    let range = -200.0..1400.0;
+    chart.draw_series(Heatmap::new(&chart, &elevation, range.clone(), &ViridisRGB))?;
+
+    // The colorbar takes the area reserved at the right of the chart
+    chart
+        .configure_colorbar(range, &ViridisRGB)
+        .bar_width(25)
+        .labels(9)
+        .label_formatter(|value| format!("{} m", value))
+        .label_style(("sans-serif", 15))
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,70 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/heatmap-log-colorbar.png";
+const SIZE: usize = 50;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The light intensity around two lamps, fading with the square of the distance, which
+    // spans several orders of magnitude
+    let intensity: Vec<Vec<f64>> = (0..SIZE)
+        .map(|row| {
+            (0..SIZE)
+                .map(|col| {
+                    let (x, y) = (col as f64 + 0.5, row as f64 + 0.5);
+                    let lamp = |cx: f64, cy: f64, power: f64| {
+                        power / ((x - cx).powi(2) + (y - cy).powi(2) + 0.1)
+                    };
+                    lamp(15.0, 32.0, 500.0) + lamp(37.0, 14.0, 50.0) + 0.02
+                })
+                .collect()
+        })
+        .collect();
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (720, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Light intensity", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .right_legend_area_size(90)
+        .build_cartesian_2d(
+            (0..SIZE as i32 - 1).into_segmented(),
+            (0..SIZE as i32 - 1).into_segmented(),
+        )?;
+
+    chart
+        .configure_mesh()
+        .disable_mesh()
+        .x_desc("m")
+        .y_desc("m")
+        .draw()?;
+
+    // The colors are picked by orders of magnitude, like the colorbar spreads its values
+    let range = 0.01..10000.0;
+    chart.draw_series(Heatmap::new_log_scale(
+        &chart,
+        &intensity,
+        range.clone(),
+        &ViridisRGB,
+    ))?;
+
+    chart
+        .configure_colorbar(range, &ViridisRGB)
+        .log_scale()
+        .bar_width(25)
+        .labels(7)
+        .label_style(("sans-serif", 15))
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
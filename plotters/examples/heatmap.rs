@@ -0,0 +1,55 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/heatmap.png";
+
+const VARIABLES: [&str; 5] = ["Height", "Weight", "Age", "Income", "Sleep"];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let correlation = [
+        [1.0, 0.78, 0.12, 0.21, -0.05],
+        [0.78, 1.0, 0.35, 0.18, -0.22],
+        [0.12, 0.35, 1.0, 0.56, -0.41],
+        [0.21, 0.18, 0.56, 1.0, -0.30],
+        [-0.05, -0.22, -0.41, -0.30, 1.0],
+    ];
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (640, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Correlation matrix", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(70)
+        .build_cartesian_2d((0..4).into_segmented(), (0..4).into_segmented())?;
+
+    let label = |v: &SegmentValue<i32>| match v {
+        SegmentValue::CenterOf(i) => VARIABLES[*i as usize].to_string(),
+        _ => String::new(),
+    };
+    chart
+        .configure_mesh()
+        .disable_mesh()
+        .x_labels(5)
+        .y_labels(5)
+        .x_label_formatter(&label)
+        .y_label_formatter(&label)
+        .label_style(("sans-serif", 16))
+        .draw()?;
+
+    chart.draw_series(
+        Heatmap::new(&chart, &correlation, -1.0..1.0, &ViridisRGB)
+            .values(("sans-serif", 18))
+            .value_formatter(|v| format!("{:+.2}", v)),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
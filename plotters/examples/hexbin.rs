@@ -0,0 +1,68 @@
+use plotters::prelude::*;
+
+use rand::SeedableRng;
+use rand_distr::{Distribution, Normal};
+use rand_xorshift::XorShiftRng;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/hexbin.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // 100k points of two correlated normal variables
+    let points: Vec<(f64, f64)> = {
+        let norm_dist = Normal::new(0.0, 1.0).unwrap();
+        let mut x_rand = XorShiftRng::from_seed(*b"MyFragileSeed123");
+        let mut y_rand = XorShiftRng::from_seed(*b"MyFragileSeed321");
+        let x_iter = norm_dist.sample_iter(&mut x_rand);
+        let y_iter = norm_dist.sample_iter(&mut y_rand);
+        x_iter
+            .zip(y_iter)
+            .map(|(x, y)| (x, 0.6 * x + 0.8 * y))
+            .take(100_000)
+            .collect()
+    };
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (840, 740)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("100k correlated normal samples", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(40)
+        .right_legend_area_size(100)
+        .build_cartesian_2d(-4.0..4.0, -4.0..4.0)?;
+
+    chart
+        .configure_mesh()
+        .disable_mesh()
+        .x_desc("X")
+        .y_desc("Y")
+        .draw()?;
+
+    // The plotting area is about as wide as high, thus the hexagons are regular when their
+    // height is 0.866 times their width. The few samples out of the chart are left out.
+    let in_chart = |&(x, y): &(f64, f64)| x.abs() < 3.9 && y.abs() < 3.9;
+    let hexbin = HexBin::new(
+        points.into_iter().filter(in_chart),
+        (0.2, 0.2 * 0.866),
+        &ViridisRGB,
+    );
+    let counts = hexbin.count_range();
+    chart.draw_series(hexbin)?;
+
+    chart
+        .configure_colorbar(counts.start as f64..counts.end as f64, &ViridisRGB)
+        .label_formatter(|count| format!("{}", count))
+        .label_style(("sans-serif", 15))
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,76 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/inset.png";
+
+fn signal(x: f64) -> f64 {
+    (x * 0.8).sin() * 10.0 + (x * 25.0).sin() * 0.6
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1024, 640)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Signal with a zoomed inset", ("sans-serif", 30))
+        .margin(20)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0.0..20.0, -15.0..15.0)?;
+
+    chart.configure_mesh().draw()?;
+
+    let points: Vec<_> = (0..=4000)
+        .map(|i| i as f64 / 200.0)
+        .map(|x| (x, signal(x)))
+        .collect();
+    chart.draw_series(LineSeries::new(points.iter().cloned(), &BLUE))?;
+
+    // The zoomed window, outlined on the main chart
+    let (zoom_x, zoom_y) = (1.5..2.5, 8.0..12.0);
+    chart.draw_series(std::iter::once(Rectangle::new(
+        [(zoom_x.start, zoom_y.start), (zoom_x.end, zoom_y.end)],
+        RED.stroke_width(2),
+    )))?;
+
+    // The inset is carved in the upper right corner of the plotting area, and has its own axes
+    let plotting_area = chart.plotting_area();
+    let inset = plotting_area.sub_area(
+        ((58).percent_width(), (3).percent_height()),
+        ((40).percent_width(), (38).percent_height()),
+    );
+    inset.fill(&WHITE)?;
+    let (width, height) = inset.dim_in_pixel();
+    inset.draw(&Rectangle::new(
+        [(0, 0), (width as i32 - 1, height as i32 - 1)],
+        BLACK.mix(0.4),
+    ))?;
+
+    let mut inset_chart = ChartBuilder::on(&inset)
+        .margin(5)
+        .x_label_area_size(25)
+        .y_label_area_size(35)
+        .build_cartesian_2d(zoom_x.clone(), zoom_y.clone())?;
+
+    inset_chart
+        .configure_mesh()
+        .x_labels(5)
+        .y_labels(5)
+        .axis_style(RED.stroke_width(2))
+        .label_style(("sans-serif", 12))
+        .draw()?;
+
+    inset_chart.draw_series(LineSeries::new(
+        points.iter().filter(|(x, _)| zoom_x.contains(x)).cloned(),
+        BLUE.stroke_width(2),
+    ))?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
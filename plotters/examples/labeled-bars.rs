@@ -0,0 +1,57 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/labeled-bars.png";
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 480)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    // Rainy days per month
+    let data = [12u32, 10, 11, 9, 8, 6, 4, 5, 7, 10, 13, 15];
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Rainy days", ("sans-serif", 40))
+        .margin(10)
+        .x_label_area_size(35)
+        .y_label_area_size(40)
+        .build_cartesian_2d((0u32..11u32).into_segmented(), 0u32..15u32)?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .bold_line_style(WHITE.mix(0.3))
+        .x_label_formatter(&|x| match x {
+            SegmentValue::CenterOf(month) => MONTHS[*month as usize].to_string(),
+            _ => String::new(),
+        })
+        .y_desc("Days")
+        .draw()?;
+
+    // The label of December doesn't fit above its bar, so it's written inside of it
+    chart.draw_series(
+        Histogram::vertical(&chart)
+            .style(BLUE.mix(0.6).filled())
+            .margin(8)
+            .data(
+                data.iter()
+                    .enumerate()
+                    .map(|(month, days)| (month as u32, *days)),
+            )
+            .bars()
+            .with_labels(|days: &u32| days.to_string())
+            .label_style(("sans-serif", 15)),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
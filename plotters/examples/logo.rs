@@ -0,0 +1,71 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/logo.png";
+
+// A small round badge, blue with a lighter ring, transparent out of the disc
+fn make_logo(size: u32) -> Vec<u8> {
+    let center = f64::from(size) / 2.0;
+    let mut pixels = Vec::with_capacity(size as usize * size as usize * 4);
+    for y in 0..size {
+        for x in 0..size {
+            let dx = f64::from(x) + 0.5 - center;
+            let dy = f64::from(y) + 0.5 - center;
+            let dist = (dx * dx + dy * dy).sqrt() / center;
+            // The edge of the disc fades out over about a pixel
+            let alpha = ((1.0 - dist) * center).clamp(0.0, 1.0);
+            let color = if (0.6..0.75).contains(&dist) {
+                (120, 180, 255)
+            } else {
+                (20, 60, 160)
+            };
+            pixels.extend_from_slice(&[color.0, color.1, color.2, (alpha * 230.0) as u8]);
+        }
+    }
+    pixels
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Monthly revenue", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0.0..12.0, 0.0..100.0)?;
+
+    chart
+        .configure_mesh()
+        .label_style(("sans-serif", 16))
+        .draw()?;
+
+    chart.draw_series(LineSeries::new(
+        (0..=48)
+            .map(|x| x as f64 / 4.0)
+            .map(|x| (x, 40.0 + 4.0 * x + 8.0 * x.sin())),
+        RED.stroke_width(2),
+    ))?;
+
+    // The logo is rendered at 32x32 and enlarged to 64x64 in the upper right corner of the
+    // plotting area, the bilinear filter keeping its edge smooth
+    let logo = make_logo(32);
+    let plotting_area = chart.plotting_area();
+    let (width, _) = plotting_area.dim_in_pixel();
+    plotting_area.strip_coord_spec().draw(
+        &RGBAImage::with_ref((width as i32 - 74, 10), (32, 32), &logo)
+            .unwrap()
+            .scale_to((64, 64))
+            .filter(ScalingFilter::Bilinear),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
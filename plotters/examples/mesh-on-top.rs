@@ -0,0 +1,65 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/mesh-on-top.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The monthly rainfall, in millimeters
+    let months = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let rainfall = [78, 62, 58, 51, 64, 47, 42, 49, 56, 81, 94, 88];
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Monthly rainfall", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d((0..11).into_segmented(), 0..100)?;
+
+    // Faint white grid lines over the bars, which read as reference levels across them
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .bold_line_style(WHITE.mix(0.7).stroke_width(2))
+        .light_line_style(WHITE.mix(0.3))
+        .y_max_light_lines(1)
+        .draw_mesh_on_top(true)
+        .y_desc("Rainfall (mm)")
+        .x_label_formatter(&|x| match x {
+            SegmentValue::CenterOf(idx) => months[*idx as usize].to_string(),
+            _ => String::new(),
+        })
+        .label_style(("sans-serif", 16))
+        .draw()?;
+
+    chart
+        .draw_series(
+            Histogram::vertical(&chart)
+                .style(BLUE.mix(0.8).filled())
+                .margin(8)
+                .data(rainfall.iter().enumerate().map(|(x, y)| (x as i32, *y))),
+        )?
+        .label("Rainfall")
+        .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], BLUE.filled()));
+
+    // The legend draws the pending grid lines first, thus they go over the bars and under it
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperMiddle)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,69 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/minor-gridlines.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1024, 480)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let (left, right) = root.split_horizontally(512);
+
+    let mut chart = ChartBuilder::on(&left)
+        .caption("Linear axes", ("sans-serif", 25))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(0.0..10.0, -1.2..1.2)?;
+
+    chart
+        .configure_mesh()
+        .x_labels(6)
+        .y_labels(7)
+        .minor_lines(4)
+        .bold_line_style(BLACK.mix(0.3))
+        .minor_line_style(BLACK.mix(0.08))
+        .draw()?;
+
+    chart.draw_series(LineSeries::new(
+        (0..=200).map(|i| {
+            let x = i as f64 / 20.0;
+            (x, (x * 1.5).sin() * (-x / 8.0).exp())
+        }),
+        &BLUE,
+    ))?;
+
+    let mut chart = ChartBuilder::on(&right)
+        .caption("Log axis", ("sans-serif", 25))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0.0..10.0, (1.0..10000.0).log_scale())?;
+
+    // On the log axis, the minor lines fall at 2, 3, ..., 9 times each power of ten
+    chart
+        .configure_mesh()
+        .x_labels(6)
+        .minor_lines(4)
+        .bold_line_style(BLACK.mix(0.3))
+        .minor_line_style(BLACK.mix(0.08))
+        .y_label_formatter(&|y| format!("{:e}", y))
+        .draw()?;
+
+    chart.draw_series(LineSeries::new(
+        (0..=100).map(|i| {
+            let x = i as f64 / 10.0;
+            (x, 1.5f64.powf(x) * 50.0)
+        }),
+        &RED,
+    ))?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
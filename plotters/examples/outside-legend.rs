@@ -0,0 +1,54 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/outside-legend.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1024, 480)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Harmonics of a square wave", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .right_legend_area_size(160)
+        .build_cartesian_2d(0.0..std::f64::consts::PI * 2.0, -1.5..1.5)?;
+
+    chart.configure_mesh().draw()?;
+
+    for idx in 0..8 {
+        let k = (2 * idx + 1) as f64;
+        let color = Palette99::pick(idx).to_rgba();
+        chart
+            .draw_series(LineSeries::new(
+                (0..=500).map(|i| {
+                    let x = i as f64 / 500.0 * std::f64::consts::PI * 2.0;
+                    (x, 4.0 / std::f64::consts::PI * (k * x).sin() / k)
+                }),
+                color.stroke_width(2),
+            ))?
+            .label(format!("sin({}x) / {}", k, k))
+            .legend(move |(x, y)| {
+                PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2))
+            });
+    }
+
+    // The legend is drawn in the area reserved at the right, so it never covers the data
+    chart
+        .configure_series_labels()
+        .in_legend_area()
+        .position(SeriesLabelPosition::MiddleLeft)
+        .background_style(WHITE)
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
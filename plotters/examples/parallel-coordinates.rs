@@ -0,0 +1,49 @@
+use plotters::prelude::*;
+
+use rand::SeedableRng;
+use rand_distr::{Distribution, Normal};
+use rand_xorshift::XorShiftRng;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/parallel-coordinates.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (900, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = root.titled("Two classes of cars", ("sans-serif", 30))?;
+
+    // Four dimensions on their own scales, for two classes of cars
+    let classes = [
+        // The mean and the standard deviation of each dimension
+        [(22.0, 3.0), (1200.0, 150.0), (95.0, 15.0), (4.0, 0.5)],
+        [(15.0, 3.0), (1700.0, 200.0), (160.0, 25.0), (7.0, 1.0)],
+    ];
+    let mut rng = XorShiftRng::from_seed(*b"ParallelCoords12");
+    let mut records = vec![];
+    let mut record_classes = vec![];
+    for (class, dimensions) in classes.iter().enumerate() {
+        let distributions = dimensions
+            .iter()
+            .map(|&(mean, sd)| Normal::new(mean, sd))
+            .collect::<Result<Vec<_>, _>>()?;
+        for _ in 0..30 {
+            records.push(distributions.iter().map(|d| d.sample(&mut rng)).collect());
+            record_classes.push(class);
+        }
+    }
+
+    ParallelCoordinates::new(records)
+        .axis_names(["km/l", "weight (kg)", "power (hp)", "0-100 (s)"])
+        .classes(record_classes)
+        .class_styles([BLUE.mix(0.5), RED.mix(0.5)])
+        .draw(&root.margin(10, 10, 10, 10))?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
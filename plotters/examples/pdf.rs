@@ -0,0 +1,59 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/chart.pdf";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The page size is in points, and the vector output can be scaled freely once embedded
+    let root = PDFBackend::new(OUT_FILE_NAME, (640, 480)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Damped oscillations", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(35)
+        .y_label_area_size(45)
+        .build_cartesian_2d(0.0..10.0, -1.0..1.0)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Time (s)")
+        .y_desc("Amplitude")
+        .axis_desc_style(("serif", 18))
+        .draw()?;
+
+    for (idx, &damping) in [0.1f64, 0.3, 0.6].iter().enumerate() {
+        let color = Palette99::pick(idx).to_rgba();
+        chart
+            .draw_series(LineSeries::new(
+                (0..=500).map(|i| {
+                    let t = i as f64 / 50.0;
+                    (t, (-damping * t).exp() * (t * 3.0).cos())
+                }),
+                color.stroke_width(2),
+            ))?
+            .label(format!("damping = {}", damping))
+            .legend(move |(x, y)| {
+                PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2))
+            });
+    }
+
+    chart.draw_series(
+        (0..=10).map(|i| Circle::new((i as f64, (-0.6 * i as f64).exp()), 3, BLACK.filled())),
+    )?;
+
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,73 @@
+use plotters::prelude::*;
+use std::f64::consts::PI;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/pi-ticks.png";
+
+// Writes a multiple of π/2 as a fraction of π, such as "3π/2"
+fn format_pi(x: &f64) -> String {
+    let halves = (x / (PI / 2.0)).round() as i64;
+    match (halves, halves % 2 == 0) {
+        (0, _) => "0".to_string(),
+        (2, _) => "π".to_string(),
+        (-2, _) => "-π".to_string(),
+        (_, true) => format!("{}π", halves / 2),
+        (1, false) => "π/2".to_string(),
+        (-1, false) => "-π/2".to_string(),
+        (_, false) => format!("{}π/2", halves),
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 400)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Sine and cosine", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(35)
+        .y_label_area_size(40)
+        .build_cartesian_2d(-PI..2.0 * PI, -1.2..1.2)?;
+
+    // The ticks are at the multiples of π/2, and the grid lines follow them
+    let x_ticks: Vec<f64> = (-2..=4).map(|k| f64::from(k) * PI / 2.0).collect();
+    chart
+        .configure_mesh()
+        .x_ticks(&x_ticks)
+        .y_ticks(&[-1.0, -0.5, 0.0, 0.5, 1.0])
+        .x_label_formatter(&format_pi)
+        .label_style(("sans-serif", 16))
+        .draw()?;
+
+    let xs = (0..=600).map(|i| -PI + 3.0 * PI * f64::from(i) / 600.0);
+    chart
+        .draw_series(LineSeries::new(
+            xs.clone().map(|x| (x, x.sin())),
+            BLUE.stroke_width(2),
+        ))?
+        .label("sin(x)")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE.stroke_width(2)));
+    chart
+        .draw_series(LineSeries::new(
+            xs.map(|x| (x, x.cos())),
+            RED.stroke_width(2),
+        ))?
+        .label("cos(x)")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED.stroke_width(2)));
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::LowerRight)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
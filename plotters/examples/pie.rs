@@ -1,25 +1,57 @@
+use plotters::coord::Shift;
 use plotters::{prelude::*, style::full_palette::ORANGE};
 
 const OUT_FILE_NAME: &str = "plotters-doc-data/pie-chart.png";
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let root_area = BitMapBackend::new(&OUT_FILE_NAME, (950, 700)).into_drawing_area();
-    root_area.fill(&WHITE).unwrap();
+const SVG_OUT_FILE_NAME: &str = "plotters-doc-data/pie-chart.svg";
+
+fn draw_pie<DB: DrawingBackend>(
+    root_area: &DrawingArea<DB, Shift>,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    root_area.fill(&WHITE)?;
     let title_style = TextStyle::from(("sans-serif", 30).into_font()).color(&(BLACK));
-    root_area.titled("BEST CIRCLES", title_style).unwrap();
+    let root_area = root_area.titled("BEST CIRCLES", title_style)?;
 
     let dims = root_area.dim_in_pixel();
     let center = (dims.0 as i32 / 2, dims.1 as i32 / 2);
-    let radius = 300.0;
-    let sizes = vec![66.0, 33.0];
-    let _rgba = RGBAColor(0, 50, 255, 1.0);
-    let colors = vec![RGBColor(0, 50, 255), CYAN];
-    let labels = vec!["Pizza", "Pacman"];
+    let radius = 250.0;
+    let sizes = [45.0, 30.0, 20.0, 5.0];
+    let colors = [RGBColor(0, 50, 255), CYAN, ORANGE, RED];
+    let labels = ["Pizza", "Pacman", "Donut", "Cookie"];
 
     let mut pie = Pie::new(&center, &radius, &sizes, &colors, &labels);
     pie.start_angle(66.0);
-    pie.label_style((("sans-serif", 50).into_font()).color(&(ORANGE)));
+    pie.label_style((("sans-serif", 40).into_font()).color(&(ORANGE)));
     pie.percentages((("sans-serif", radius * 0.08).into_font()).color(&BLACK));
+    pie.donut_hole(60.0);
+    // Pull the smallest slice out to draw attention to it
+    pie.explode(3, 20.0);
     root_area.draw(&pie)?;
 
     Ok(())
 }
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root_area = BitMapBackend::new(OUT_FILE_NAME, (950, 700)).into_drawing_area();
+    draw_pie(&root_area)?;
+
+    // The same pie, drawn as a vector image
+    let svg_area = SVGBackend::new(SVG_OUT_FILE_NAME, (950, 700)).into_drawing_area();
+    draw_pie(&svg_area)?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root_area.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    svg_area.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!(
+        "Result has been saved to {} and {}",
+        OUT_FILE_NAME, SVG_OUT_FILE_NAME
+    );
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
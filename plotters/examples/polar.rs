@@ -0,0 +1,100 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/polar.png";
+
+const SKILLS: [&str; 6] = [
+    "Speed",
+    "Power",
+    "Defense",
+    "Stamina",
+    "Agility",
+    "Technique",
+];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1024, 512)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let (left, right) = root.split_horizontally(512);
+
+    // A rose curve, r = |cos(4θ)|, using the default trigonometric convention
+    let mut chart = ChartBuilder::on(&left)
+        .caption("Rose curve", ("sans-serif", 30))
+        .margin(10)
+        .set_all_label_area_size(40)
+        .build_polar(0.0..1.0)?;
+
+    chart.configure_mesh().angle_lines(8).draw()?;
+
+    chart.draw_series(LineSeries::new(
+        (0..=720).map(|i| {
+            let angle = i as f64 / 720.0 * 2.0 * std::f64::consts::PI;
+            (angle, (4.0 * angle).cos().abs())
+        }),
+        RED.stroke_width(2),
+    ))?;
+
+    // A radar chart, with the first axis pointing north and the angles in degrees
+    let mut chart = ChartBuilder::on(&right)
+        .caption("Radar chart", ("sans-serif", 30))
+        .margin(10)
+        .set_all_label_area_size(40)
+        .build_polar(0.0..10.0)?;
+
+    chart
+        .set_angle_unit(AngleUnit::Degrees)
+        .set_zero_angle(ZeroAngle::North)
+        .set_clockwise(true);
+
+    let step = 360.0 / SKILLS.len() as f64;
+    let skill_name = |angle: &f64| SKILLS[(angle / step).round() as usize].to_string();
+    chart
+        .configure_mesh()
+        .angle_lines(SKILLS.len())
+        .radius_labels(5)
+        .angle_formatter(&skill_name)
+        .draw()?;
+
+    let players = [
+        ("Player A", BLUE, [8.0, 6.0, 4.0, 7.0, 9.0, 5.0]),
+        ("Player B", GREEN, [5.0, 9.0, 8.0, 6.0, 4.0, 7.0]),
+    ];
+    for (name, color, scores) in players {
+        let points: Vec<_> = scores
+            .iter()
+            .enumerate()
+            .map(|(i, score)| (i as f64 * step, *score))
+            .collect();
+        chart.draw_series(std::iter::once(Polygon::new(
+            points.clone(),
+            color.mix(0.2),
+        )))?;
+        chart
+            .draw_series(std::iter::once(PathElement::new(
+                points
+                    .iter()
+                    .chain(points.first())
+                    .cloned()
+                    .collect::<Vec<_>>(),
+                color.stroke_width(2),
+            )))?
+            .label(name)
+            .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+    }
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::LowerRight)
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
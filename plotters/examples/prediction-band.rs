@@ -0,0 +1,66 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/prediction-band.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1024, 768)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Forecast with prediction intervals", ("sans-serif", 30))
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0.0..12.0, 0.0..14.0)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Month")
+        .y_desc("Sales")
+        .draw()?;
+
+    let xs: Vec<f64> = (0..=120).map(|x| x as f64 / 10.0).collect();
+    let forecast = |x: f64| 3.0 + 0.5 * x + (x * 1.2).sin();
+    // The uncertainty grows as the forecast goes further
+    let spread = |x: f64| 0.3 + 0.15 * x;
+
+    // The 90% interval is drawn first, the 50% interval overlaps it and looks darker
+    for (z, label, shade) in [(1.645, "90% interval", 0.2), (0.674, "50% interval", 0.36)] {
+        chart
+            .draw_series(FillBetweenSeries::new(
+                xs.iter().copied(),
+                xs.iter().map(|&x| forecast(x) - z * spread(x)),
+                xs.iter().map(|&x| forecast(x) + z * spread(x)),
+                BLUE.mix(0.2),
+            )?)?
+            .label(label)
+            .legend(move |(x, y)| {
+                Rectangle::new([(x, y - 5), (x + 20, y + 5)], BLUE.mix(shade).filled())
+            });
+    }
+
+    chart
+        .draw_series(LineSeries::new(
+            xs.iter().map(|&x| (x, forecast(x))),
+            BLUE.stroke_width(2),
+        ))?
+        .label("Forecast")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE.stroke_width(2)));
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperLeft)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
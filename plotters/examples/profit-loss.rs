@@ -0,0 +1,81 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/profit-loss.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The monthly net income of a seasonal business, in thousands of dollars
+    let income = [12, 8, -5, -14, -9, 3, 17, 24, 9, -3, -11, 6];
+    let balance: Vec<(i32, i32)> = income
+        .iter()
+        .scan(-20, |total, x| {
+            *total += x;
+            Some(*total)
+        })
+        .enumerate()
+        .map(|(month, total)| (month as i32, total))
+        .collect();
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 700)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let (upper, lower) = root.split_vertically(380);
+
+    // The bars above the baseline are profits, the ones below it are losses
+    let mut chart = ChartBuilder::on(&upper)
+        .caption("Monthly net income", ("sans-serif", 26))
+        .margin(10)
+        .x_label_area_size(35)
+        .y_label_area_size(50)
+        .build_cartesian_2d((0..11).into_segmented(), -20..30)?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_desc("Month")
+        .y_desc("k$")
+        .draw()?;
+
+    chart.draw_series(
+        Histogram::vertical(&chart)
+            .style_by_sign(GREEN.filled(), RED.filled())
+            .margin(8)
+            .data(
+                income
+                    .iter()
+                    .enumerate()
+                    .map(|(month, x)| (month as i32, *x)),
+            )
+            .bars()
+            .with_labels(|value: &i32| value.to_string()),
+    )?;
+
+    // The balance starts in debt, and the area changes color where it crosses zero
+    let mut chart = ChartBuilder::on(&lower)
+        .caption("Balance", ("sans-serif", 26))
+        .margin(10)
+        .x_label_area_size(35)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0..11, -60..60)?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_desc("Month")
+        .y_desc("k$")
+        .draw()?;
+
+    chart.draw_series(
+        AreaSeries::new(balance, 0, BLUE)
+            .style_by_sign(GREEN.mix(0.4), RED.mix(0.4))
+            .border_style(BLACK.stroke_width(2)),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
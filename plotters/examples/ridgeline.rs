@@ -0,0 +1,68 @@
+use plotters::prelude::*;
+
+use rand::SeedableRng;
+use rand_distr::{Distribution, Normal};
+use rand_xorshift::XorShiftRng;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/ridgeline.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The daily high temperatures of five cities, in degrees Celsius
+    let mut rand = XorShiftRng::from_seed(*b"MyFragileSeed123");
+    let data: Vec<(&str, Vec<f64>)> = [
+        ("Oslo", 9.0, 6.0),
+        ("London", 15.0, 5.0),
+        ("Paris", 16.0, 6.5),
+        ("Rome", 21.0, 6.0),
+        ("Cairo", 29.0, 4.5),
+    ]
+    .iter()
+    .map(|&(city, mean, std_dev)| {
+        let normal = Normal::new(mean, std_dev).unwrap();
+        let samples = (0..365).map(|_| normal.sample(&mut rand)).collect();
+        (city, samples)
+    })
+    .collect();
+
+    let ridges = RidgelineSeries::new(data)
+        .overlap(1.8)
+        .style_func(|idx, _| ViridisRGB::get_color(idx as f64 / 4.0).mix(0.9).filled());
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    // Each city gets a tick on the Y axis, at the baseline of its ridge
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Daily high temperatures", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(40)
+        .y_label_area_size(70)
+        .build_cartesian_2d(
+            -15.0..50.0,
+            ridges.y_range().with_key_points(ridges.baselines()),
+        )?;
+
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .x_desc("Temperature (°C)")
+        .y_label_formatter(&|y| {
+            ridges
+                .category_at(*y)
+                .map_or(String::new(), |c| c.to_string())
+        })
+        .label_style(("sans-serif", 16))
+        .draw()?;
+
+    chart.draw_series(ridges)?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
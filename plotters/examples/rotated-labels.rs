@@ -0,0 +1,68 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/rotated-labels.png";
+
+const CITIES: [(&str, f64); 20] = [
+    ("Amsterdam", 14.1),
+    ("Barcelona", 21.2),
+    ("Buenos Aires", 18.0),
+    ("Cape Town", 17.6),
+    ("Copenhagen", 9.4),
+    ("Istanbul", 15.1),
+    ("Johannesburg", 16.4),
+    ("Kuala Lumpur", 27.8),
+    ("Los Angeles", 19.3),
+    ("Mexico City", 16.9),
+    ("Montevideo", 16.7),
+    ("New York City", 13.2),
+    ("Rio de Janeiro", 25.2),
+    ("San Francisco", 14.6),
+    ("Santiago de Chile", 14.5),
+    ("Singapore", 27.6),
+    ("Stockholm", 7.4),
+    ("Tel Aviv", 20.9),
+    ("Vancouver", 11.1),
+    ("Wellington", 12.9),
+];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1024, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        // The rotated labels need a taller label area than horizontal ones
+        .x_label_area_size(110)
+        .y_label_area_size(50)
+        .margin(20)
+        .caption("Average yearly temperature", ("sans-serif", 40))
+        .build_cartesian_2d((0..CITIES.len() - 1).into_segmented(), 0.0..30.0)?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_labels(CITIES.len())
+        .x_label_rotation(-45.0)
+        .x_label_formatter(&|city| match city {
+            SegmentValue::CenterOf(idx) => CITIES[*idx].0.to_string(),
+            _ => String::new(),
+        })
+        .y_desc("Temperature (°C)")
+        .draw()?;
+
+    chart.draw_series(
+        Histogram::vertical(&chart)
+            .style(RED.mix(0.6).filled())
+            .margin(6)
+            .data(CITIES.iter().enumerate().map(|(idx, (_, t))| (idx, *t))),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
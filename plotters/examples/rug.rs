@@ -0,0 +1,61 @@
+use plotters::data::{Bandwidth, KernelDensity};
+use plotters::prelude::*;
+
+use rand::SeedableRng;
+use rand_distr::{Distribution, Normal};
+use rand_xorshift::XorShiftRng;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/rug.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 500)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    // A bimodal sample, mixing two normal distributions
+    let mut rng = XorShiftRng::from_seed(*b"RugPlotSeed12345");
+    let mut samples: Vec<f64> = Normal::new(3.0, 0.8)?
+        .sample_iter(&mut rng)
+        .take(150)
+        .collect();
+    samples.extend(Normal::new(7.0, 1.2)?.sample_iter(&mut rng).take(100));
+
+    let kde = KernelDensity::new(&samples, Bandwidth::Silverman);
+    let curve = kde.sample_range(200);
+    let max_density = curve.iter().fold(0.0f64, |max, &(_, d)| max.max(d));
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Density with a rug plot", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(35)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0.0..11.0, 0.0..max_density * 1.15)?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_desc("Value")
+        .y_desc("Density")
+        .draw()?;
+
+    chart.draw_series(
+        AreaSeries::new(curve.iter().cloned(), 0.0, BLUE.mix(0.15))
+            .border_style(BLUE.stroke_width(2)),
+    )?;
+
+    // The raw samples along the bottom edge, translucent and jittered so the dense parts show
+    chart.draw_series(
+        RugSeries::along_x(&chart, samples.iter().cloned(), BLACK.mix(0.4))
+            .tick_length(15)
+            .jitter(1),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,65 @@
+use plotters::prelude::*;
+
+use rand::SeedableRng;
+use rand_distr::{Distribution, Normal};
+use rand_xorshift::XorShiftRng;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/scatter-by-class.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    // Iris-like measurements: the sepal length and width of three species, around their means
+    let species = [
+        ("setosa", (5.0, 3.4), (0.35, 0.38)),
+        ("versicolor", (5.9, 2.8), (0.5, 0.3)),
+        ("virginica", (6.6, 3.0), (0.6, 0.3)),
+    ];
+    let mut rng = XorShiftRng::from_seed(*b"IrisLikeSeed1234");
+    let mut data = vec![];
+    for &(name, (length, width), (length_sd, width_sd)) in &species {
+        let lengths = Normal::new(length, length_sd)?;
+        let widths = Normal::new(width, width_sd)?;
+        for _ in 0..50 {
+            data.push((lengths.sample(&mut rng), widths.sample(&mut rng), name));
+        }
+    }
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Sepal measurements by species", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(4.0..8.5, 1.8..4.6)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Sepal length (cm)")
+        .y_desc("Sepal width (cm)")
+        .draw()?;
+
+    // One series for all the points, and one legend entry per species
+    chart.draw_points_by_key(data, 4, |&name| match name {
+        "setosa" => RED.mix(0.7).filled(),
+        "versicolor" => GREEN.mix(0.7).filled(),
+        _ => BLUE.mix(0.7).filled(),
+    })?;
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperRight)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
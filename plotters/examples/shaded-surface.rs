@@ -0,0 +1,47 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/shaded-surface.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Shaded paraboloid", ("sans-serif", 30))
+        .margin(10)
+        .build_cartesian_3d(-3.0..3.0, 0.0..9.0, -3.0..3.0)?;
+
+    chart.with_projection(|mut pb| {
+        pb.pitch = 0.6;
+        pb.yaw = 0.4;
+        pb.scale = 0.8;
+        pb.into_matrix()
+    });
+
+    chart
+        .configure_axes()
+        .light_grid_style(BLACK.mix(0.15))
+        .max_light_lines(3)
+        .draw()?;
+
+    // The light comes from above the front of the chart, lighting the far side of the bowl
+    chart.draw_series(
+        SurfaceSeries::xoz(
+            (-30..=30).map(|v| v as f64 / 10.0),
+            (-30..=30).map(|v| v as f64 / 10.0),
+            |x, z| (x * x + z * z) / 2.0,
+        )
+        .style(RGBColor(70, 130, 220).filled())
+        .with_light((0.5, 2.0, 1.5), 0.25),
+    )?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,67 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/spline.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // A few sparse measurements of the daily temperature
+    let data = [
+        (0.0, 8.0),
+        (3.0, 6.5),
+        (6.0, 7.0),
+        (9.0, 13.0),
+        (12.0, 18.5),
+        (15.0, 19.0),
+        (18.0, 14.0),
+        (21.0, 10.5),
+        (24.0, 9.0),
+    ];
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Straight vs splined series", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        .build_cartesian_2d(0.0..24.0, 0.0..25.0)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Hour")
+        .y_desc("Temperature (°C)")
+        .label_style(("sans-serif", 16))
+        .draw()?;
+
+    chart
+        .draw_series(LineSeries::new(data, BLACK.mix(0.4)).point_size(4))?
+        .label("Straight")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLACK.mix(0.4)));
+
+    chart
+        .draw_series(SplineSeries::new(data, RED.stroke_width(2)))?
+        .label("Catmull-Rom")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED.stroke_width(2)));
+
+    chart
+        .draw_series(SplineSeries::new(data, BLUE).tension(0.5).segments(8))?
+        .label("Tension 0.5")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperLeft)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
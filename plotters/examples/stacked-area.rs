@@ -0,0 +1,61 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/stacked-area.png";
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let processes = ["kernel", "browser", "compiler"];
+    let usage: Vec<Vec<(u32, f64)>> = vec![
+        (0..60)
+            .map(|t| (t, 5.0 + 2.0 * (t as f64 / 5.0).sin().abs()))
+            .collect(),
+        (0..60)
+            .map(|t| (t, 20.0 + 10.0 * (t as f64 / 10.0).cos()))
+            .collect(),
+        (0..60)
+            .map(|t| (t, if (20..45).contains(&t) { 55.0 } else { 5.0 }))
+            .collect(),
+    ];
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1024, 768)).into_drawing_area();
+
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .set_label_area_size(LabelAreaPosition::Left, 60)
+        .set_label_area_size(LabelAreaPosition::Bottom, 60)
+        .caption("CPU Usage by Process", ("sans-serif", 40))
+        .build_cartesian_2d(0..59u32, 0.0..100.0)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Time (s)")
+        .y_desc("CPU (%)")
+        .draw()?;
+
+    let colors = [BLUE, GREEN, RED];
+    for ((layer, name), color) in StackedAreaSeries::stack(usage, 0.0)?
+        .into_iter()
+        .zip(processes.iter())
+        .zip(colors)
+    {
+        chart
+            .draw_series(layer.style(color.mix(0.4)).border_style(color))?
+            .label(*name)
+            .legend(move |(x, y)| Rectangle::new([(x, y - 6), (x + 12, y + 6)], color.filled()));
+    }
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperLeft)
+        .background_style(WHITE.filled())
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
@@ -0,0 +1,66 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/stacked-bars.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The energy mix of three countries of very different sizes, in TWh
+    let countries = ["Iceland", "France", "China"];
+    let production = vec![
+        (0, vec![0.0, 13.5, 6.2, 0.0]),
+        (1, vec![25.0, 60.0, 48.0, 360.0]),
+        (2, vec![5400.0, 1350.0, 1180.0, 420.0]),
+    ];
+
+    let root = BitMapBackend::new(OUT_FILE_NAME, (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Electricity production by source", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(40)
+        .y_label_area_size(50)
+        // Some room is left above the bars for the legend
+        .build_cartesian_2d((0..2).into_segmented(), 0.0..1.2)?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_label_formatter(&|x| match x {
+            SegmentValue::CenterOf(idx) => countries[*idx as usize].to_string(),
+            _ => String::new(),
+        })
+        .y_labels(13)
+        .y_label_formatter(&|y| match *y {
+            y if y <= 1.0 => format!("{:.0}%", y * 100.0),
+            _ => String::new(),
+        })
+        .label_style(("sans-serif", 16))
+        .draw()?;
+
+    // Each bar is rescaled to 100%, whatever the total production of the country
+    StackedBarSeries::new(production)
+        .sub_series("Fossil", RGBColor(90, 90, 90).filled())
+        .sub_series("Hydro", RGBColor(30, 110, 200).filled())
+        .sub_series("Other renewables", RGBColor(60, 170, 80).filled())
+        .sub_series("Nuclear", RGBColor(240, 180, 30).filled())
+        .margin(120)
+        .normalized()
+        .draw(&mut chart)?;
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperMiddle)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
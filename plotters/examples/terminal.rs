@@ -0,0 +1,37 @@
+use plotters::prelude::*;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // 80 columns by 24 rows of characters, each of them holding 2x4 braille dots
+    let root = TerminalBackend::new((80, 24)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("y = sin(x)", ("sans-serif", 4))
+        .x_label_area_size(12)
+        .y_label_area_size(20)
+        .build_cartesian_2d(-std::f64::consts::PI..std::f64::consts::PI, -1.2..1.2)?;
+
+    chart
+        .configure_mesh()
+        .disable_mesh()
+        .x_labels(5)
+        .y_labels(5)
+        .x_label_formatter(&|x| format!("{:.1}", x))
+        .y_label_formatter(&|y| format!("{:.1}", y))
+        .draw()?;
+
+    chart.draw_series(LineSeries::new(
+        (-314..=314).map(|x| x as f64 / 100.0).map(|x| (x, x.sin())),
+        RED,
+    ))?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present()
+        .expect("Unable to write the result to the standard output");
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
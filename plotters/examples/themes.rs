@@ -0,0 +1,66 @@
+use plotters::prelude::*;
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/themes.png";
+
+fn draw_chart<DB: DrawingBackend>(
+    area: &mut DrawingArea<DB, plotters::coord::Shift>,
+    name: &str,
+    theme: &Theme,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    area.apply_theme(theme)?;
+
+    let mut chart = ChartBuilder::on(area)
+        .caption(format!("{} theme", name), theme.text_style(24.0))
+        .margin(15)
+        .x_label_area_size(35)
+        .y_label_area_size(40)
+        .build_cartesian_2d(0.0..10.0, -1.5..1.5)?;
+
+    // The mesh takes its line and label styles from the theme
+    chart.configure_mesh().x_desc("Time (s)").draw()?;
+
+    for (idx, name) in ["Damped", "Steady", "Growing"].iter().enumerate() {
+        // Each series gets the next color of the palette
+        let color = chart.next_series_color();
+        let rate = (idx as f64 - 1.0) * 0.05;
+        chart
+            .draw_series(LineSeries::new(
+                (0..=200)
+                    .map(|x| x as f64 / 20.0)
+                    .map(|x| (x, (x * 1.5 + idx as f64).sin() * (rate * x).exp() * 0.8)),
+                color.stroke_width(2),
+            ))?
+            .label(*name)
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+    }
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::LowerLeft)
+        .border_style(theme.axis_style)
+        .draw()?;
+
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (1200, 450)).into_drawing_area();
+    let (mut left, mut right) = root.split_horizontally(600);
+
+    // The same chart under two themes
+    draw_chart(&mut left, "Dark", &Theme::dark())?;
+    draw_chart(&mut right, "Solarized", &Theme::solarized())?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
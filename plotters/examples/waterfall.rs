@@ -0,0 +1,89 @@
+use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
+
+const OUT_FILE_NAME: &str = "plotters-doc-data/waterfall.png";
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new(OUT_FILE_NAME, (900, 550)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    // The bridge from the revenue to the net profit, in thousands of dollars
+    let steps = [
+        ("Revenue", 840.0, false),
+        ("Cost of sales", -365.0, false),
+        ("Gross profit", 0.0, true),
+        ("Marketing", -112.0, false),
+        ("R&D", -148.0, false),
+        ("Admin", -64.0, false),
+        ("Other income", 27.0, false),
+        ("Taxes", -45.0, false),
+        ("Net profit", 0.0, true),
+    ];
+    let labels: Vec<&str> = steps.iter().map(|&(label, _, _)| label).collect();
+    let series = WaterfallSeries::new(
+        labels
+            .iter()
+            .zip(steps.iter())
+            .map(|(label, &(_, delta, is_total))| (label, delta, is_total)),
+    );
+    let range = series.value_range();
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("From revenue to net profit", ("sans-serif", 30))
+        .margin(15)
+        .x_label_area_size(35)
+        .y_label_area_size(60)
+        .build_cartesian_2d(labels[..].into_segmented(), range.start..range.end * 1.1)?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_label_formatter(&|label| match label {
+            SegmentValue::CenterOf(name) => name.to_string(),
+            _ => String::new(),
+        })
+        .y_label_formatter(&|value| format!("${}k", value))
+        .draw()?;
+
+    series
+        .increase_style(RGBColor(46, 160, 67).filled())
+        .decrease_style(RGBColor(215, 58, 73).filled())
+        .total_style(RGBColor(3, 102, 214).filled())
+        .connector_style(Some(BLACK.mix(0.5)))
+        .margin(20)
+        .draw(&mut chart)?;
+
+    // The change of each step above its bar, and the amount of the totals
+    let mut total = 0.0;
+    let amounts: Vec<_> = labels
+        .iter()
+        .zip(steps.iter())
+        .map(|(label, &(_, delta, is_total))| {
+            let top = if is_total {
+                format!("{}", total)
+            } else {
+                total += delta;
+                format!("{:+}", delta)
+            };
+            (label, top, total.max(total - delta))
+        })
+        .collect();
+    let style = ("sans-serif", 15)
+        .into_font()
+        .color(&BLACK)
+        .pos(Pos::new(HPos::Center, VPos::Bottom));
+    chart.draw_series(amounts.iter().map(|(label, text, top)| {
+        EmptyElement::at((SegmentValue::CenterOf(*label), *top))
+            + Text::new(text.clone(), (0, -4), style.clone())
+    }))?;
+
+    // To avoid the IO failure being ignored silently, we manually call the present function
+    root.present().expect("Unable to write result to file, please make sure 'plotters-doc-data' dir exists under current dir");
+    println!("Result has been saved to {}", OUT_FILE_NAME);
+
+    Ok(())
+}
+#[test]
+fn entry_point() {
+    main().unwrap()
+}
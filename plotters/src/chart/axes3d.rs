@@ -216,16 +216,33 @@ where
         let parent_size = chart.drawing_area.dim_in_pixel();
         let base_tick_size = (5u32).percent().max(5).in_pixels(chart.plotting_area());
         let tick_size = base_tick_size;
+        let label_size = (12).percent().max(12).in_pixels(&parent_size);
+        // The defaults come from the theme, if any
+        let (bold_line_style, axis_panel_style, axis_style, label_style) =
+            match chart.drawing_area.theme() {
+                Some(theme) => (
+                    theme.bold_grid_style,
+                    theme.light_grid_style,
+                    theme.axis_style,
+                    theme.text_style(f64::from(label_size)),
+                ),
+                None => (
+                    Into::<ShapeStyle>::into(BLACK.mix(0.2)),
+                    Into::<ShapeStyle>::into(BLACK.mix(0.1)),
+                    Into::<ShapeStyle>::into(BLACK.mix(0.8)),
+                    ("sans-serif", label_size).into(),
+                ),
+            };
         Self {
             parent_size,
             tick_size,
             light_lines_limit: [10, 10, 10],
             n_labels: [10, 10, 10],
-            bold_line_style: Into::<ShapeStyle>::into(BLACK.mix(0.2)),
+            bold_line_style,
             light_line_style: Into::<ShapeStyle>::into(TRANSPARENT),
-            axis_panel_style: Into::<ShapeStyle>::into(BLACK.mix(0.1)),
-            axis_style: Into::<ShapeStyle>::into(BLACK.mix(0.8)),
-            label_style: ("sans-serif", (12).percent().max(12).in_pixels(&parent_size)).into(),
+            axis_panel_style,
+            axis_style,
+            label_style,
             format_x: &X::format,
             format_y: &Y::format,
             format_z: &Z::format,
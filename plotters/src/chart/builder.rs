@@ -1,11 +1,19 @@
+use std::ops::Range;
+
 use super::context::ChartContext;
 
 use crate::coord::cartesian::{Cartesian2d, Cartesian3d};
-use crate::coord::ranged1d::AsRangedCoord;
-use crate::coord::Shift;
+use crate::coord::combinators::{IntoNiceRange, WithKeyPoints};
+use crate::coord::polar::Polar;
+use crate::coord::ranged1d::{AsRangedCoord, BoldPoints, Ranged, ValueFormatter};
+use crate::coord::types::RangedCoordf64;
+use crate::coord::{CoordTranslate, Shift};
 
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::style::{IntoTextStyle, SizeDesc, TextStyle};
+use crate::style::{
+    AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, IntoTextStyle, RGBAColor,
+    SizeDesc, TextStyle, Theme,
+};
 
 use plotters_backend::DrawingBackend;
 
@@ -58,9 +66,14 @@ See [`ChartBuilder::on()`] for more information and examples.
 pub struct ChartBuilder<'a, 'b, DB: DrawingBackend> {
     label_area_size: [u32; 4], // [upper, lower, left, right]
     overlap_plotting_area: [bool; 4],
+    // The label style the areas sized automatically are measured with
+    auto_label_area_style: [Option<TextStyle<'b>>; 4],
     root_area: &'a DrawingArea<DB, Shift>,
     title: Option<(String, TextStyle<'b>)>,
     margin: [u32; 4],
+    legend_area_size: u32,
+    plot_area_background: Option<RGBAColor>,
+    theme: Option<Theme>,
 }
 
 impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
@@ -90,10 +103,14 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
     pub fn on(root: &'a DrawingArea<DB, Shift>) -> Self {
         Self {
             label_area_size: [0; 4],
+            auto_label_area_style: [None, None, None, None],
             root_area: root,
             title: None,
             margin: [0; 4],
             overlap_plotting_area: [false; 4],
+            legend_area_size: 0,
+            plot_area_background: None,
+            theme: None,
         }
     }
 
@@ -255,9 +272,217 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         let size = size.in_pixels(self.root_area);
         self.label_area_size[pos as usize] = size.unsigned_abs();
         self.overlap_plotting_area[pos as usize] = size < 0;
+        self.auto_label_area_style[pos as usize] = None;
+        self
+    }
+
+    /**
+    Sizes a chart label area to fit the tick labels of its axis, rather than to a given size.
+
+    When a 2D Cartesian chart is built with [`ChartBuilder::build_cartesian_2d_fitted()`], the
+    labels the mesh draws by default on the axis of the label area, i.e. the labels of the key points of the axis formatted by its default
+    formatter, are measured with the text metrics of the backend. The label area is then given
+    room for the widest label, or the highest one for an X axis, the tick marks, and a few
+    pixels of padding. The label areas on the right and on the top are measured with the
+    labels of the Y and X axes respectively.
+
+    The labels are measured with the default label font of the mesh. Labels drawn with another
+    font, or rotated with [`crate::chart::MeshStyle::x_label_rotation()`], are measured with
+    [`ChartBuilder::set_label_area_size_auto_with_style()`]. The other build methods don't
+    measure the labels, and leave no room for the label areas sized automatically.
+
+    - `pos`: The position of the label area to size automatically
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("label_area_size_auto.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_label_area_size_auto(LabelAreaPosition::Left)
+        .set_label_area_size_auto(LabelAreaPosition::Bottom)
+        .build_cartesian_2d_fitted(0.0..1.0, 0..2_500_000)
+        .unwrap();
+    chart.configure_mesh().draw().unwrap();
+    ```
+    */
+    pub fn set_label_area_size_auto(&mut self, pos: LabelAreaPosition) -> &mut Self {
+        // The default label font of the mesh, which is sized from the plotting area, that is
+        // smaller than the root area
+        let font = FontDesc::new(
+            FontFamily::SansSerif,
+            f64::from((12i32).percent().max(12).in_pixels(self.root_area)),
+            FontStyle::Normal,
+        );
+        self.set_label_area_size_auto_with_style(pos, font)
+    }
+
+    /**
+    Sizes a chart label area to fit the tick labels of its axis drawn with the given style,
+    which may be rotated.
+
+    - `pos`: The position of the label area to size automatically
+    - `style`: The style of the labels, the same as the one given to the mesh
+
+    See [`ChartBuilder::set_label_area_size_auto()`] for more information.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("label_area_size_auto_rotated.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let style = ("sans-serif", 14).into_font().rotation(45.0);
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_label_area_size_auto(LabelAreaPosition::Left)
+        .set_label_area_size_auto_with_style(LabelAreaPosition::Bottom, style.clone())
+        .build_cartesian_2d_fitted(1_000_000..9_000_000, 0..10)
+        .unwrap();
+    chart
+        .configure_mesh()
+        .x_label_style(style)
+        .draw()
+        .unwrap();
+    ```
+    */
+    pub fn set_label_area_size_auto_with_style<S: IntoTextStyle<'b>>(
+        &mut self,
+        pos: LabelAreaPosition,
+        style: S,
+    ) -> &mut Self {
+        self.label_area_size[pos as usize] = 0;
+        self.overlap_plotting_area[pos as usize] = false;
+        self.auto_label_area_style[pos as usize] = Some(style.into_text_style(self.root_area));
+        self
+    }
+
+    // The size of the label areas, where the areas sized automatically fit the labels the mesh
+    // draws by default on the given drawing area
+    fn fitted_label_area_size<X, Y>(
+        &self,
+        drawing_area: &DrawingArea<DB, Shift>,
+        x_spec: &X,
+        y_spec: &Y,
+    ) -> Result<[u32; 4], DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        X: Ranged + ValueFormatter<X::ValueType>,
+        Y: Ranged + ValueFormatter<Y::ValueType>,
+    {
+        // The number of labels of the mesh by default
+        const LABEL_COUNT: usize = 11;
+
+        let mut label_area_size = self.label_area_size;
+        for (idx, style) in self.auto_label_area_style.iter().enumerate() {
+            let style = match style {
+                Some(style) => style,
+                None => continue,
+            };
+            let labels: Vec<_> = if idx < 2 {
+                let points = x_spec.key_points(BoldPoints(LABEL_COUNT));
+                points.iter().map(|x| x_spec.format_ext(x)).collect()
+            } else {
+                let points = y_spec.key_points(BoldPoints(LABEL_COUNT));
+                points.iter().map(|y| y_spec.format_ext(y)).collect()
+            };
+
+            let tick_size = (5u32).percent().max(5).in_pixels(drawing_area).max(0) as u32;
+            label_area_size[idx] =
+                fitted_label_area_size(drawing_area, &labels, style, idx < 2, tick_size)?;
+        }
+        Ok(label_area_size)
+    }
+
+    /**
+    Reserves an area to the right of the chart for the series labels, so that the legend
+    doesn't cover the plotted data. The plotting area and the label areas shrink to leave
+    room for it.
+
+    - `size`: The width of the legend area in backend units (pixels).
+      If set to 0, no legend area is reserved.
+
+    The legend is drawn into this area with [`crate::chart::SeriesLabelStyle::in_legend_area()`].
+    See [`ChartContext::configure_series_labels()`] for more information and examples.
+    */
+    pub fn right_legend_area_size<S: SizeDesc>(&mut self, size: S) -> &mut Self {
+        self.legend_area_size = size.in_pixels(self.root_area).max(0) as u32;
         self
     }
 
+    /**
+    Sets the color the plotting area is filled with when the chart is built.
+
+    - `color`: The background color of the plotting area
+
+    Only the region inside the axes is filled, independently of the fill of the whole drawing
+    area, e.g. to get a gray panel on a white figure. Since the fill happens when the chart is
+    built, the mesh and the series drawn afterwards are on top of it.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("plot_area_background.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(35)
+        .set_plot_area_background(&RGBColor(235, 235, 235))
+        .build_cartesian_2d(0.0..4.0, 0.0..3.0)
+        .unwrap();
+    chart.configure_mesh().bold_line_style(WHITE).light_line_style(WHITE.mix(0.5)).draw().unwrap();
+    ```
+    */
+    pub fn set_plot_area_background<C: Color>(&mut self, color: &C) -> &mut Self {
+        self.plot_area_background = Some(color.to_rgba());
+        self
+    }
+
+    /**
+    Sets the theme of the chart, which fills the area of the chart with the background color
+    of the theme, and gives the default styles of the mesh, the legend and the series colors.
+
+    The charts take the theme applied to their drawing area by default, see [`Theme`] for more
+    details.
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("with_theme.svg", (300, 200)).into_drawing_area();
+    let theme = Theme::solarized();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .with_theme(&theme)
+        .caption("Solarized", theme.text_style(20.0))
+        .margin(10)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d(0.0..4.0, 0.0..2.0)
+        .unwrap();
+    chart.configure_mesh().draw().unwrap();
+    ```
+    */
+    pub fn with_theme(&mut self, theme: &Theme) -> &mut Self {
+        self.theme = Some(theme.clone());
+        self
+    }
+
+    // Fill the plotting area with its background color, if any
+    fn fill_plot_area(
+        &self,
+        plotting_area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        match &self.plot_area_background {
+            Some(color) => plotting_area.fill(color),
+            None => Ok(()),
+        }
+    }
+
+    // Split the legend area off the right side of what is left by the margins and the caption
+    fn split_legend_area(
+        &self,
+        drawing_area: DrawingArea<DB, Shift>,
+    ) -> (DrawingArea<DB, Shift>, Option<DrawingArea<DB, Shift>>) {
+        let (w, _) = drawing_area.dim_in_pixel();
+        if self.legend_area_size == 0 || self.legend_area_size >= w {
+            return (drawing_area, None);
+        }
+        let (chart, legend) = drawing_area.split_horizontally(w - self.legend_area_size);
+        (chart, Some(legend))
+    }
+
     /**
     Sets the title or caption of the chart.
 
@@ -313,10 +538,64 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
     ) -> Result<
         ChartContext<'c, DB, Cartesian2d<X::CoordDescType, Y::CoordDescType>>,
         DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        self.build_cartesian_2d_impl(x_spec, y_spec, None, |builder, _, _, _| {
+            Ok(builder.label_area_size)
+        })
+    }
+
+    /**
+    Builds a chart with a 2D Cartesian coordinate system, whose label areas sized automatically
+    fit the tick labels of their axis.
+
+    - `x_spec`: Specifies the X axis range and data properties
+    - `y_spec`: Specifies the Y axis range and data properties
+    - Returns: A `ChartContext` object, ready to visualize data.
+
+    This is [`ChartBuilder::build_cartesian_2d()`] for axes whose values can be formatted, which
+    measures the labels of the areas set with [`ChartBuilder::set_label_area_size_auto()`].
+    */
+    #[allow(clippy::type_complexity)]
+    pub fn build_cartesian_2d_fitted<'c, X: AsRangedCoord, Y: AsRangedCoord>(
+        &mut self,
+        x_spec: X,
+        y_spec: Y,
+    ) -> Result<
+        ChartContext<'c, DB, Cartesian2d<X::CoordDescType, Y::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    >
+    where
+        X::CoordDescType: ValueFormatter<X::Value>,
+        Y::CoordDescType: ValueFormatter<Y::Value>,
+    {
+        self.build_cartesian_2d_impl(x_spec, y_spec, None, Self::fitted_label_area_size)
+    }
+
+    // Builds a Cartesian chart, whose plotting area is shrunk and centered to give the X and Y
+    // spans of the data, when there are some, the same pixels per unit, and whose label areas are
+    // sized by the given function
+    #[allow(clippy::type_complexity)]
+    fn build_cartesian_2d_impl<'c, X: AsRangedCoord, Y: AsRangedCoord>(
+        &mut self,
+        x_spec: X,
+        y_spec: Y,
+        equal_spans: Option<(f64, f64)>,
+        label_area_size: fn(
+            &Self,
+            &DrawingArea<DB, Shift>,
+            &X::CoordDescType,
+            &Y::CoordDescType,
+        ) -> Result<[u32; 4], DrawingAreaErrorKind<DB::ErrorType>>,
+    ) -> Result<
+        ChartContext<'c, DB, Cartesian2d<X::CoordDescType, Y::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
     > {
         let mut label_areas = [None, None, None, None];
 
         let mut drawing_area = DrawingArea::clone(self.root_area);
+        if let Some(theme) = &self.theme {
+            drawing_area.apply_theme(theme)?;
+        }
 
         if *self.margin.iter().max().unwrap_or(&0) > 0 {
             drawing_area = drawing_area.margin(
@@ -336,7 +615,39 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             (0, 0)
         };
 
-        let (w, h) = drawing_area.dim_in_pixel();
+        let (mut drawing_area, legend_area) = self.split_legend_area(drawing_area);
+
+        let (x_spec, y_spec): (X::CoordDescType, Y::CoordDescType) = (x_spec.into(), y_spec.into());
+        let label_area_size = label_area_size(self, &drawing_area, &x_spec, &y_spec)?;
+
+        let (mut w, mut h) = drawing_area.dim_in_pixel();
+
+        // The label areas which don't overlap the plotting area take its room
+        let label_room = |idx: usize| {
+            if self.overlap_plotting_area[idx] {
+                0
+            } else {
+                label_area_size[idx]
+            }
+        };
+
+        // Letterbox the chart, so that the plotting area has the shape of the data
+        let mut letterbox = (0, 0);
+        if let Some((x_span, y_span)) = equal_spans {
+            let plot_w = w.saturating_sub(label_room(2) + label_room(3));
+            let plot_h = h.saturating_sub(label_room(0) + label_room(1));
+            if x_span > 0.0 && y_span > 0.0 && plot_w > 1 && plot_h > 1 {
+                // The pixel ranges span one pixel less than the plotting area
+                let scale = (f64::from(plot_w - 1) / x_span).min(f64::from(plot_h - 1) / y_span);
+                let fit_w = ((x_span * scale).round() as u32 + 1).min(plot_w);
+                let fit_h = ((y_span * scale).round() as u32 + 1).min(plot_h);
+                let (dw, dh) = ((plot_w - fit_w) as i32, (plot_h - fit_h) as i32);
+                letterbox = (dw / 2, dh / 2);
+                drawing_area = drawing_area.margin(dh / 2, dh - dh / 2, dw / 2, dw - dw / 2);
+                w -= dw as u32;
+                h -= dh as u32;
+            }
+        }
 
         let mut actual_drawing_area_pos = [0, h as i32, 0, w as i32];
 
@@ -347,7 +658,7 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 continue;
             }
 
-            let size = self.label_area_size[idx] as i32;
+            let size = label_area_size[idx] as i32;
 
             let split_point = if dx + dy < 0 { size } else { -size };
 
@@ -386,8 +697,8 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 if h > 0 && w > 0 {
                     std::mem::swap(&mut label_areas[dst_idx], &mut split[*src_idx]);
                 }
-            } else if self.label_area_size[dst_idx] != 0 {
-                let size = self.label_area_size[dst_idx] as i32;
+            } else if label_area_size[dst_idx] != 0 {
+                let size = label_area_size[dst_idx] as i32;
                 let (dw, dh) = drawing_area.dim_in_pixel();
                 let x0 = if DIR[dst_idx].0 > 0 {
                     dw as i32 - size
@@ -410,6 +721,8 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             }
         }
 
+        self.fill_plot_area(&drawing_area)?;
+
         let mut pixel_range = drawing_area.get_pixel_range();
         pixel_range.0.end -= 1;
         pixel_range.1.end -= 1;
@@ -432,13 +745,104 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 pixel_range,
             )),
             series_anno: vec![],
+            legend_area,
             drawing_area_pos: (
-                actual_drawing_area_pos[2] + title_dx + self.margin[2] as i32,
-                actual_drawing_area_pos[0] + title_dy + self.margin[0] as i32,
+                actual_drawing_area_pos[2] + title_dx + self.margin[2] as i32 + letterbox.0,
+                actual_drawing_area_pos[0] + title_dy + self.margin[0] as i32 + letterbox.1,
             ),
+            clip_series: true,
+            deferred_mesh: None,
+            palette: None,
+            color_idx: 0,
         })
     }
 
+    /**
+    Builds a chart with a 2D Cartesian coordinate system, whose ranges are expanded to round bounds.
+
+    - `x_range`: The range of the X data
+    - `y_range`: The range of the Y data
+    - Returns: A `ChartContext` object, ready to visualize data.
+
+    Both ranges are expanded with [`IntoNiceRange::nice()`], e.g. the data range `0.0..9734.0`
+    gets the axis `0.0..10000.0` with a tick mark every `1000.0`.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("build_cartesian_2d_nice.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(40)
+        .build_cartesian_2d_nice(0.0..9734.0, -0.37..0.82)
+        .unwrap();
+    assert_eq!(chart.x_range(), 0.0..10000.0);
+    assert_eq!(chart.y_range(), -0.4..1.0);
+    chart.configure_mesh().draw().unwrap();
+    ```
+    */
+    #[allow(clippy::type_complexity)]
+    pub fn build_cartesian_2d_nice<'c, X: IntoNiceRange, Y: IntoNiceRange>(
+        &mut self,
+        x_range: X,
+        y_range: Y,
+    ) -> Result<
+        ChartContext<
+            'c,
+            DB,
+            Cartesian2d<WithKeyPoints<X::CoordDescType>, WithKeyPoints<Y::CoordDescType>>,
+        >,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    >
+    where
+        X::Value: Clone,
+        Y::Value: Clone,
+    {
+        self.build_cartesian_2d(x_range.nice(), y_range.nice())
+    }
+
+    /**
+    Builds a chart with a 2D Cartesian coordinate system whose axes have the same scale: one
+    unit along X takes as many pixels as one unit along Y, thus a circle of the data looks
+    circular whatever the shape of the drawing area, as needed by maps and geometric plots.
+
+    - `x_range`: The range of the X axis
+    - `y_range`: The range of the Y axis
+    - Returns: A `ChartContext` object, ready to visualize data.
+
+    The plotting area is shrunk along one axis to the shape of the data, and the chart with its
+    labels is centered in the drawing area, leaving the rest of it blank.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("equal_aspect.svg", (400, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d_equal_aspect(-1.5..1.5, -1.5..1.5)
+        .unwrap();
+    chart.configure_mesh().draw().unwrap();
+    let circle = (0..=100).map(|i| {
+        let angle = i as f64 / 100.0 * 2.0 * std::f64::consts::PI;
+        (angle.cos(), angle.sin())
+    });
+    chart.draw_series(LineSeries::new(circle, &BLUE)).unwrap();
+    ```
+    */
+    pub fn build_cartesian_2d_equal_aspect<'c>(
+        &mut self,
+        x_range: Range<f64>,
+        y_range: Range<f64>,
+    ) -> Result<
+        ChartContext<'c, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let spans = (
+            (x_range.end - x_range.start).abs(),
+            (y_range.end - y_range.start).abs(),
+        );
+        self.build_cartesian_2d_impl(x_range, y_range, Some(spans), Self::fitted_label_area_size)
+    }
+
     /**
     Builds a chart with a 3D Cartesian coordinate system.
 
@@ -460,6 +864,9 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         DrawingAreaErrorKind<DB::ErrorType>,
     > {
         let mut drawing_area = DrawingArea::clone(self.root_area);
+        if let Some(theme) = &self.theme {
+            drawing_area.apply_theme(theme)?;
+        }
 
         if *self.margin.iter().max().unwrap_or(&0) > 0 {
             drawing_area = drawing_area.margin(
@@ -479,6 +886,9 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             (0, 0)
         };
 
+        let (drawing_area, legend_area) = self.split_legend_area(drawing_area);
+        self.fill_plot_area(&drawing_area)?;
+
         let pixel_range = drawing_area.get_pixel_range();
 
         Ok(ChartContext {
@@ -491,14 +901,111 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 pixel_range,
             )),
             series_anno: vec![],
+            legend_area,
+            drawing_area_pos: (
+                title_dx + self.margin[2] as i32,
+                title_dy + self.margin[0] as i32,
+            ),
+            clip_series: true,
+            deferred_mesh: None,
+            palette: None,
+            color_idx: 0,
+        })
+    }
+
+    /**
+    Builds a chart with a polar coordinate system.
+
+    The disc of the chart is centered in the area left by the margins and the caption. The
+    label areas are kept free around the disc, for the angle labels: use
+    [`ChartBuilder::set_all_label_area_size()`] to make room for them.
+
+    - `radius_spec`: Specifies the radius range and data properties
+    - Returns: A `ChartContext` object, ready to visualize data.
+
+    See [`Polar`] and [`ChartContext::configure_mesh()`] for more information and examples.
+    */
+    pub fn build_polar<'c, R: AsRangedCoord>(
+        &mut self,
+        radius_spec: R,
+    ) -> Result<ChartContext<'c, DB, Polar<R::CoordDescType>>, DrawingAreaErrorKind<DB::ErrorType>>
+    {
+        let mut drawing_area = DrawingArea::clone(self.root_area);
+        if let Some(theme) = &self.theme {
+            drawing_area.apply_theme(theme)?;
+        }
+
+        if *self.margin.iter().max().unwrap_or(&0) > 0 {
+            drawing_area = drawing_area.margin(
+                self.margin[0] as i32,
+                self.margin[1] as i32,
+                self.margin[2] as i32,
+                self.margin[3] as i32,
+            );
+        }
+
+        let (title_dx, title_dy) = if let Some((ref title, ref style)) = self.title {
+            let (origin_dx, origin_dy) = drawing_area.get_base_pixel();
+            drawing_area = drawing_area.titled(title, style.clone())?;
+            let (current_dx, current_dy) = drawing_area.get_base_pixel();
+            (current_dx - origin_dx, current_dy - origin_dy)
+        } else {
+            (0, 0)
+        };
+
+        let (drawing_area, legend_area) = self.split_legend_area(drawing_area);
+
+        let (x, y) = drawing_area.get_pixel_range();
+        let [top, bottom, left, right] = self.label_area_size.map(|size| size as i32);
+        let pixel_range = (x.start + left..x.end - right, y.start + top..y.end - bottom);
+        self.fill_plot_area(&drawing_area.margin(top, bottom, left, right))?;
+
+        Ok(ChartContext {
+            x_label_area: [None, None],
+            y_label_area: [None, None],
+            drawing_area: drawing_area.apply_coord_spec(Polar::new(radius_spec, pixel_range)),
+            series_anno: vec![],
+            legend_area,
             drawing_area_pos: (
                 title_dx + self.margin[2] as i32,
                 title_dy + self.margin[0] as i32,
             ),
+            clip_series: true,
+            deferred_mesh: None,
+            palette: None,
+            color_idx: 0,
         })
     }
 }
 
+// The size of a label area fitting the labels of its axis drawn with the style, which are away from
+// the axis by twice the size of the tick marks, plus some padding beyond them
+pub(super) fn fitted_label_area_size<DB: DrawingBackend, CT: CoordTranslate>(
+    area: &DrawingArea<DB, CT>,
+    labels: &[String],
+    style: &TextStyle,
+    x_axis: bool,
+    tick_size: u32,
+) -> Result<u32, DrawingAreaErrorKind<DB::ErrorType>> {
+    const PADDING: u32 = 5;
+
+    // The extent of a label across the axis is the one of its box, once rotated
+    let angle = style.font.get_transform().angle() + style.font.get_rotation();
+    let (sin, cos) = f64::to_radians(angle).sin_cos();
+    let upright = style.transform(FontTransform::None).rotation(0.0);
+    let mut extent: f64 = 0.0;
+    for label in labels {
+        let (w, h) = area.estimate_text_size(label, &upright)?;
+        let (w, h) = (f64::from(w), f64::from(h));
+        extent = extent.max(if x_axis {
+            w * sin.abs() + h * cos.abs()
+        } else {
+            w * cos.abs() + h * sin.abs()
+        });
+    }
+    Ok(2 * tick_size + extent.ceil() as u32 + PADDING)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -566,6 +1073,80 @@ mod test {
         assert_eq!(chart.title.as_ref().unwrap().1.font.get_name(), "serif");
     }
 
+    #[test]
+    fn test_plot_area_background() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, filled, upper_left, bottom_right| {
+                assert_eq!(c, GREEN.to_rgba());
+                assert!(filled);
+                assert_eq!(upper_left, (20, 0));
+                assert_eq!(bottom_right, (200, 190));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 1));
+        });
+
+        ChartBuilder::on(&drawing_area)
+            .x_label_area_size(10)
+            .y_label_area_size(20)
+            .set_plot_area_background(&GREEN)
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_label_area_size_auto() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |_| {});
+        let font: TextStyle = ("sans-serif", 12).into();
+        let widest = |labels: &[&str]| {
+            labels
+                .iter()
+                .map(|label| drawing_area.estimate_text_size(label, &font).unwrap().0)
+                .max()
+                .unwrap()
+        };
+
+        // The area of the 6-digit labels is at least as wide as the widest of them
+        let chart = ChartBuilder::on(&drawing_area)
+            .set_label_area_size_auto(LabelAreaPosition::Left)
+            .build_cartesian_2d_fitted(0..10, 100_000..900_000)
+            .unwrap();
+        let (width, _) = chart.y_label_area[0].as_ref().unwrap().dim_in_pixel();
+        assert!(width >= widest(&["100000", "500000", "900000"]));
+        let short = ChartBuilder::on(&drawing_area)
+            .set_label_area_size_auto(LabelAreaPosition::Left)
+            .build_cartesian_2d_fitted(0..10, 0..9)
+            .unwrap();
+        assert!(short.y_label_area[0].as_ref().unwrap().dim_in_pixel().0 < width);
+
+        // The labels rotated by a quarter turn are as high as they are wide
+        let chart = ChartBuilder::on(&drawing_area)
+            .set_label_area_size_auto_with_style(
+                LabelAreaPosition::Bottom,
+                font.transform(FontTransform::Rotate90),
+            )
+            .build_cartesian_2d_fitted(100_000..900_000, 0..10)
+            .unwrap();
+        let (_, height) = chart.x_label_area[1].as_ref().unwrap().dim_in_pixel();
+        assert!(height >= widest(&["100000", "500000", "900000"]));
+
+        // An explicit size takes over
+        let mut builder = ChartBuilder::on(&drawing_area);
+        builder
+            .set_label_area_size_auto(LabelAreaPosition::Left)
+            .y_label_area_size(20);
+        let chart = builder
+            .build_cartesian_2d_fitted(0..10, 0..900_000)
+            .unwrap();
+        assert_eq!(chart.y_label_area[0].as_ref().unwrap().dim_in_pixel().0, 20);
+
+        // The labels aren't measured without their formatter
+        let chart = ChartBuilder::on(&drawing_area)
+            .set_label_area_size_auto(LabelAreaPosition::Left)
+            .build_cartesian_2d(0..10, 0..900_000)
+            .unwrap();
+        assert!(chart.y_label_area[0].is_none());
+    }
+
     #[test]
     fn test_zero_limit_with_log_scale() {
         let drawing_area = create_mocked_drawing_area(640, 480, |_| {});
@@ -588,4 +1169,46 @@ mod test {
             )
             .unwrap();
     }
+
+    #[test]
+    fn test_equal_aspect() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let drawn = Rc::new(RefCell::new(vec![]));
+        let path = drawn.clone();
+        let drawing_area = create_mocked_drawing_area(400, 200, move |m| {
+            m.check_draw_path(move |_, _, points| *path.borrow_mut() = points);
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d_equal_aspect(-1.0..1.0, -1.0..1.0)
+            .unwrap();
+        let circle: Vec<_> = (0..360)
+            .map(|d| f64::from(d).to_radians())
+            .map(|a| (a.cos(), a.sin()))
+            .collect();
+        chart
+            .draw_series(std::iter::once(PathElement::new(circle, BLACK)))
+            .unwrap();
+
+        // The unit circle has the same radius in pixels along both axes
+        let points = drawn.borrow();
+        let (min_x, max_x) = (
+            points.iter().map(|p| p.0).min().unwrap(),
+            points.iter().map(|p| p.0).max().unwrap(),
+        );
+        let (min_y, max_y) = (
+            points.iter().map(|p| p.1).min().unwrap(),
+            points.iter().map(|p| p.1).max().unwrap(),
+        );
+        assert_eq!(max_x - min_x, max_y - min_y);
+        assert_eq!(max_y - min_y, 199);
+        // The plotting area is centered horizontally, the rest of the width being left blank
+        assert_eq!((min_x, max_x), (100, 299));
+
+        let origin = chart.backend_coord(&(0.0, 0.0));
+        let right = chart.backend_coord(&(1.0, 0.0));
+        let top = chart.backend_coord(&(0.0, 1.0));
+        assert_eq!(right.0 - origin.0, origin.1 - top.1);
+    }
 }
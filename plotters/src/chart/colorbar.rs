@@ -0,0 +1,153 @@
+use std::ops::Range;
+
+use super::ChartContext;
+use crate::coord::{CoordTranslate, Shift};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::ColorBar;
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::{Color, ShapeStyle, SizeDesc, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+/// The struct to specify the colorbar drawn next to a target chart context
+pub struct ColorBarStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
+    target: &'b ChartContext<'a, DB, CT>,
+    colorbar: Option<ColorBar<'b, BackendCoord>>,
+    bar_width: u32,
+    margin: u32,
+}
+
+impl<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> ColorBarStyle<'a, 'b, DB, CT> {
+    pub(super) fn new<C: Color, M: ColorMap<C, f64>>(
+        target: &'b ChartContext<'a, DB, CT>,
+        range: Range<f64>,
+        colormap: &'b M,
+    ) -> Self {
+        Self {
+            target,
+            colorbar: Some(ColorBar::vertical((0, 0), (0, 0), range, colormap)),
+            bar_width: 20,
+            margin: 10,
+        }
+    }
+
+    /**
+    Sets the width of the colorbar strip, 20 pixels by default.
+
+    - `value`: The size specification in backend units (pixels)
+
+    See [`ChartContext::configure_colorbar()`] for more information and examples.
+    */
+    pub fn bar_width<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.bar_width = value
+            .in_pixels(&self.target.plotting_area().dim_in_pixel())
+            .max(0) as u32;
+        self
+    }
+
+    /**
+    Sets the gap between the plotting area and the colorbar, 10 pixels by default.
+
+    - `value`: The size specification in backend units (pixels)
+
+    See [`ChartContext::configure_colorbar()`] for more information and examples.
+    */
+    pub fn margin<S: SizeDesc>(&mut self, value: S) -> &mut Self {
+        self.margin = value
+            .in_pixels(&self.target.plotting_area().dim_in_pixel())
+            .max(0) as u32;
+        self
+    }
+
+    /**
+    Sets the maximum number of labels along the colorbar, 5 by default.
+
+    See [`ChartContext::configure_colorbar()`] for more information and examples.
+    */
+    pub fn labels(&mut self, labels: usize) -> &mut Self {
+        self.update(|colorbar| colorbar.labels(labels))
+    }
+
+    /**
+    Spreads the values logarithmically along the colorbar, which must have a positive range,
+    with ticks at the powers of ten like a log axis.
+
+    See [`ChartContext::configure_colorbar()`] and [`ColorBar::log_scale()`] for more
+    information and examples.
+    */
+    pub fn log_scale(&mut self) -> &mut Self {
+        self.update(|colorbar| colorbar.log_scale())
+    }
+
+    /**
+    Sets how the values of the labels are formatted.
+
+    See [`ChartContext::configure_colorbar()`] for more information and examples.
+    */
+    pub fn label_formatter(&mut self, formatter: impl Fn(f64) -> String + 'b) -> &mut Self {
+        self.update(|colorbar| colorbar.label_formatter(formatter))
+    }
+
+    /**
+    Sets the style of the labels.
+
+    See [`ChartContext::configure_colorbar()`] for more information and examples.
+    */
+    pub fn label_style<S: Into<TextStyle<'b>>>(&mut self, style: S) -> &mut Self {
+        self.update(|colorbar| colorbar.label_style(style))
+    }
+
+    /**
+    Sets the style of the border of the colorbar and of its ticks.
+
+    See [`ChartContext::configure_colorbar()`] for more information and examples.
+    */
+    pub fn border_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.update(|colorbar| colorbar.border_style(style))
+    }
+
+    fn update(
+        &mut self,
+        f: impl FnOnce(ColorBar<'b, BackendCoord>) -> ColorBar<'b, BackendCoord>,
+    ) -> &mut Self {
+        self.colorbar = self.colorbar.take().map(f);
+        self
+    }
+
+    // The area reserved to the right of the plotting area, if any
+    fn reserved_area(&self) -> Option<&'b DrawingArea<DB, Shift>> {
+        let target = self.target;
+        target
+            .legend_area
+            .as_ref()
+            .or_else(|| target.y_label_area[1].as_ref())
+    }
+
+    /**
+    Draws the colorbar, along the right side of the plotting area.
+
+    The colorbar is drawn in the legend area reserved with
+    [`crate::chart::ChartBuilder::right_legend_area_size()`], or else in the label area at the
+    right of the chart. When neither of them is reserved, this fails with a layout error.
+
+    See [`ChartContext::configure_colorbar()`] for more information and examples.
+    */
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self
+            .reserved_area()
+            .ok_or(DrawingAreaErrorKind::LayoutError)?;
+        // The colorbar spans the height of the plotting area
+        let (_, plot_y) = self.target.plotting_area().get_pixel_range();
+        let (_, base_y) = area.get_base_pixel();
+        let height = (plot_y.end - plot_y.start).max(0) as u32;
+        match self.colorbar.as_mut() {
+            Some(colorbar) => {
+                colorbar.relocate(
+                    (self.margin as i32, plot_y.start - base_y),
+                    (self.bar_width, height),
+                );
+                area.draw(colorbar)
+            }
+            None => Ok(()),
+        }
+    }
+}
@@ -2,13 +2,17 @@ use std::borrow::Borrow;
 
 use plotters_backend::{BackendCoord, DrawingBackend};
 
+#[cfg(feature = "colormaps")]
+use crate::chart::ColorBarStyle;
 use crate::chart::{SeriesAnno, SeriesLabelStyle};
 use crate::coord::{CoordTranslate, ReverseCoordTranslate, Shift};
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::element::{CoordMapper, Drawable, PointCollection};
+use crate::element::{CoordMapper, Drawable, PathElement, PointCollection};
+use crate::style::{Color, RGBColor, ShapeStyle, Theme};
 
 pub(super) mod cartesian2d;
 pub(super) mod cartesian3d;
+pub(super) mod polar;
 
 pub(super) use cartesian3d::Coord3D;
 
@@ -27,7 +31,16 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(crate) y_label_area: [Option<DrawingArea<DB, Shift>>; 2],
     pub(crate) drawing_area: DrawingArea<DB, CT>,
     pub(crate) series_anno: Vec<SeriesAnno<'a, DB>>,
+    pub(crate) legend_area: Option<DrawingArea<DB, Shift>>,
     pub(crate) drawing_area_pos: (i32, i32),
+    pub(crate) clip_series: bool,
+    // The grid lines of a mesh drawn on top of the series, until they are drawn
+    pub(crate) deferred_mesh: Option<Vec<(BackendCoord, BackendCoord, ShapeStyle)>>,
+    // The palette set on the chart, which takes over the one of the theme
+    pub(crate) palette: Option<Vec<RGBColor>>,
+    // The index of the next color of the palette, which moves on with each series drawn and each
+    // color handed out by `next_color()`
+    pub(crate) color_idx: usize,
 }
 
 impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
@@ -36,6 +49,32 @@ impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT>
         let coord_spec = self.drawing_area.into_coord_spec();
         move |coord| coord_spec.reverse_translate(coord)
     }
+
+    /**
+    Maps a pixel of the backend back to the data coordinate under it, e.g. to find what a mouse
+    click points to, or to build the regions of an HTML image map. This is the inverse of
+    [`ChartContext::backend_coord()`], and unlike [`ChartContext::into_coord_trans()`] it leaves
+    the chart usable.
+
+    - `pixel`: The pixel, in the coordinates of the backend, whose origin is its upper left corner
+    - **returns** The data coordinate, or `None` if the pixel maps to no value of the axes
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("data_coord.svg", (300, 200)).into_drawing_area();
+    let chart = ChartBuilder::on(&drawing_area)
+        .margin(10)
+        .set_left_and_bottom_label_area_size(40)
+        .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+        .unwrap();
+    let pixel = chart.backend_coord(&(2.5, 7.5));
+    let (x, y): (f64, f64) = chart.data_coord(pixel).unwrap();
+    assert!((x - 2.5).abs() < 0.1 && (y - 7.5).abs() < 0.1);
+    ```
+    */
+    pub fn data_coord(&self, pixel: BackendCoord) -> Option<CT::From> {
+        self.drawing_area.as_coord_spec().reverse_translate(pixel)
+    }
 }
 
 impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
@@ -74,11 +113,191 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         SeriesLabelStyle::new(self)
     }
 
+    /**
+    Configures the colorbar showing the colors a colormap gives to a range of values, drawn
+    along the right side of the plotting area.
+
+    The colorbar needs an area to the right of the chart, reserved with
+    [`crate::chart::ChartBuilder::right_legend_area_size()`] or with a right label area.
+
+    - `range`: The range of values spanned by the colormap
+    - `colormap`: The colormap, usually the same as the one of the series
+    - **returns** The style of the colorbar, which is drawn with [`ColorBarStyle::draw()`]
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let matrix = [[0.1, 0.4], [0.8, 0.3]];
+    let drawing_area = SVGBackend::new("configure_colorbar.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .margin(10)
+        .set_left_and_bottom_label_area_size(20)
+        .right_legend_area_size(60)
+        .build_cartesian_2d((0..1).into_segmented(), (0..1).into_segmented())
+        .unwrap();
+    chart.configure_mesh().disable_mesh().draw().unwrap();
+    chart.draw_series(Heatmap::new(&chart, &matrix, 0.0..1.0, &ViridisRGB)).unwrap();
+    chart
+        .configure_colorbar(0.0..1.0, &ViridisRGB)
+        .label_formatter(|value| format!("{:.1}", value))
+        .draw()
+        .unwrap();
+    ```
+    */
+    #[cfg(feature = "colormaps")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "colormaps")))]
+    pub fn configure_colorbar<'b, C, M>(
+        &'b self,
+        range: std::ops::Range<f64>,
+        colormap: &'b M,
+    ) -> ColorBarStyle<'a, 'b, DB, CT>
+    where
+        C: crate::style::Color,
+        M: crate::style::colors::colormaps::ColorMap<C, f64>,
+    {
+        ColorBarStyle::new(self, range, colormap)
+    }
+
     /// Get a reference of underlying plotting area
     pub fn plotting_area(&self) -> &DrawingArea<DB, CT> {
         &self.drawing_area
     }
 
+    /**
+    Enables or disables the clipping of the series to the plotting area, which is enabled by
+    default.
+
+    The series drawn with [`ChartContext::draw_series()`] are restricted to the plotting area,
+    thus the data out of the ranges of the axes doesn't spill over the axes: the lines crossing
+    the border of the area are cut where they cross it, the markers out of the area are skipped,
+    and the markers on its border are partially drawn. Disabling the clipping lets the series
+    drawn as annotations, e.g. labels next to the data, overflow the plotting area. The elements
+    drawn straight onto [`ChartContext::plotting_area()`] are never clipped.
+
+    Note: the clipping is done by the drawing backend, the backends which don't support it draw
+    the whole series.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("series_clipping.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(20)
+        .build_cartesian_2d(0.0..10.0, 0.0..1.0)
+        .unwrap();
+    // The line is cut at the top of the plotting area
+    chart.draw_series(LineSeries::new((0..=10).map(|x| (x as f64, x as f64 / 8.0)), BLUE)).unwrap();
+    // The label goes past the right of the plotting area
+    chart.set_series_clipping(false);
+    chart
+        .draw_series(std::iter::once(Text::new("A long label", (9.0, 0.5), ("sans-serif", 12))))
+        .unwrap();
+    ```
+    */
+    pub fn set_series_clipping(&mut self, enabled: bool) -> &mut Self {
+        self.clip_series = enabled;
+        self
+    }
+
+    /**
+    Draws the grid lines of the mesh configured with
+    [`crate::chart::MeshStyle::draw_mesh_on_top()`], on top of the series drawn so far.
+
+    The grid lines are only drawn once, and this does nothing when there are no pending grid
+    lines. [`SeriesLabelStyle::draw()`] calls it before drawing the legend, thus the grid lines
+    are drawn over the series and under the legend without calling it explicitly.
+
+    - **returns** An error if the grid lines couldn't be drawn
+
+    See [`crate::chart::MeshStyle::draw_mesh_on_top()`] for more information and examples.
+    */
+    pub fn draw_deferred_mesh(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let lines = match self.deferred_mesh.take() {
+            Some(lines) => lines,
+            None => return Ok(()),
+        };
+        self.drawing_area.with_element_class("plotters-grid", || {
+            self.drawing_area.draw_backend_lines(&lines)
+        })
+    }
+
+    /**
+    Gets the color of the next series: the colors of the palette cycle with the series drawn on
+    the chart, so that each series gets its own color. The palette is the one set with
+    [`ChartContext::set_palette()`], or else the one of the theme. The charts without theme use
+    the palette of [`Theme::light()`].
+
+    This is the color [`ChartContext::next_color()`] hands out next, both move on along the
+    palette with each series drawn.
+
+    See [`Theme`] for more information and examples.
+    */
+    pub fn next_series_color(&self) -> RGBColor {
+        self.palette_color(self.color_idx)
+    }
+
+    /**
+    Sets the palette the series colors are picked from, instead of the one of the theme, and
+    restarts [`ChartContext::next_color()`] from its first color.
+
+    - `colors`: The colors of the palette, which cycle when they are exhausted
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("palette.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(0.0..4.0, 0.0..4.0)
+        .unwrap();
+    chart.set_palette((0..Palette99::COLORS.len()).map(Palette99::pick));
+    for offset in 0..3 {
+        chart
+            .draw_series_auto(|color| {
+                LineSeries::new((0..=4).map(|x| (x as f64, (x + offset) as f64 / 2.0)), color)
+            })
+            .unwrap()
+            .label(format!("offset {}", offset));
+    }
+    chart.configure_series_labels().draw().unwrap();
+    ```
+    */
+    pub fn set_palette<C: Color, I: IntoIterator<Item = C>>(&mut self, colors: I) -> &mut Self {
+        self.palette = Some(
+            colors
+                .into_iter()
+                .map(|color| {
+                    let (r, g, b) = color.rgb();
+                    RGBColor(r, g, b)
+                })
+                .collect(),
+        );
+        self.color_idx = 0;
+        self
+    }
+
+    /**
+    Hands out the next color of the palette, wrapping when it is exhausted. Unlike
+    [`ChartContext::next_series_color()`], which only peeks at it, each call moves to the next
+    color, whether or not a series is drawn with it.
+
+    See [`ChartContext::set_palette()`] for more information and examples.
+    */
+    pub fn next_color(&mut self) -> RGBColor {
+        let color = self.palette_color(self.color_idx);
+        self.color_idx += 1;
+        color
+    }
+
+    fn palette_color(&self, idx: usize) -> RGBColor {
+        match (&self.palette, self.drawing_area.theme()) {
+            (Some(palette), _) if !palette.is_empty() => palette[idx % palette.len()],
+            (_, Some(theme)) => theme.pick(idx),
+            _ => Theme::light().pick(idx),
+        }
+    }
+
     /// Cast the reference to a chart context to a reference to underlying coordinate specification.
     pub fn as_coord_spec(&self) -> &CT {
         self.drawing_area.as_coord_spec()
@@ -100,15 +319,44 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        for element in series {
-            self.drawing_area.draw(element.borrow())?;
+        self.draw_classed_series_impl(series, None)
+    }
+
+    fn draw_classed_series_impl<B, E, R, S>(
+        &mut self,
+        series: S,
+        class: Option<&str>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let area = &self.drawing_area;
+        area.push_element_class("plotters-series");
+        if let Some(class) = class {
+            area.push_element_class(class);
+        }
+        let result = series.into_iter().try_for_each(|element| {
+            if self.clip_series {
+                area.draw_clipped(element.borrow())
+            } else {
+                area.draw(element.borrow())
+            }
+        });
+        if class.is_some() {
+            area.pop_element_class();
         }
-        Ok(())
+        area.pop_element_class();
+        result
     }
 
     pub(crate) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
         let idx = self.series_anno.len();
         self.series_anno.push(SeriesAnno::new());
+        self.color_idx += 1;
         &mut self.series_anno[idx]
     }
 
@@ -131,6 +379,76 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         self.draw_series_impl(series)?;
         Ok(self.alloc_series_anno())
     }
+
+    /**
+    Draws a data series, like [`ChartContext::draw_series()`], and tags its shapes with a class
+    name. The backends writing a document, such as SVG, may attach the class to the shapes so
+    that the series can be restyled later, e.g. with CSS.
+
+    ```
+    use plotters::prelude::*;
+    let mut buffer = String::new();
+    {
+        let drawing_area = SVGBackend::with_string(&mut buffer, (300, 200))
+            .with_styling(SVGStyling::InlineAndClasses)
+            .into_drawing_area();
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        chart
+            .draw_series_with_class(LineSeries::new([(0.0, 0.0), (1.0, 1.0)], &RED), "revenue")
+            .unwrap();
+    }
+    assert!(buffer.contains("class=\"plotters-line plotters-series revenue\""));
+    ```
+
+    - `series`: The data series to draw
+    - `class`: The class name of the series
+    - **returns**: The annotation of the series, like [`ChartContext::draw_series()`]
+    */
+    pub fn draw_series_with_class<B, E, R, S>(
+        &mut self,
+        series: S,
+        class: &str,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        self.draw_classed_series_impl(series, Some(class))?;
+        Ok(self.alloc_series_anno())
+    }
+
+    /**
+    Draws a data series with the next color of the palette, see
+    [`ChartContext::next_series_color()`]. The legend of the series defaults to a line of that
+    color.
+
+    - `series`: Makes the series from its color
+
+    See [`ChartContext::set_palette()`] for more information and examples.
+    */
+    pub fn draw_series_auto<B, E, R, S, F>(
+        &mut self,
+        series: F,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+        F: FnOnce(RGBColor) -> S,
+    {
+        let color = self.next_series_color();
+        self.draw_series_impl(series(color))?;
+        Ok(self
+            .alloc_series_anno()
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color)))
+    }
 }
 
 #[cfg(test)]
@@ -186,6 +504,759 @@ mod test {
             .expect("Drawing error");
     }
 
+    #[test]
+    fn test_grouped_category_labels() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let drawn = labels.clone();
+        let drawing_area = create_mocked_drawing_area(300, 200, move |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                drawn.borrow_mut().push((text.to_string(), pos))
+            });
+        });
+
+        let quarters = GroupedCategory::new(vec![
+            (2021, vec!["Q1", "Q2", "Q3"]),
+            (2022, vec!["Q1", "Q2", "Q3"]),
+        ]);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(40)
+            .build_cartesian_2d(quarters.into_segmented(), 0..10)
+            .expect("Create chart");
+        chart.configure_mesh().draw().expect("Draw mesh");
+
+        let labels = labels.borrow();
+        let texts: Vec<_> = labels.iter().map(|(text, _)| &text[..]).collect();
+        assert_eq!(
+            texts,
+            vec!["Q1", "Q2", "Q3", "Q1", "Q2", "Q3", "2021", "2022"]
+        );
+
+        // Each group label is centered beneath its items, below the item labels
+        let (_, first_item) = labels[0];
+        let (_, first_group) = labels[6];
+        assert_eq!(first_group.0, labels[1].1 .0);
+        assert!(first_group.1 > first_item.1);
+    }
+
+    #[test]
+    fn test_number_label_formats() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let drawn = labels.clone();
+        let drawing_area = create_mocked_drawing_area(400, 300, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| drawn.borrow_mut().push(text.to_string()));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_left_and_bottom_label_area_size(60)
+            .build_cartesian_2d(0u32..5000, 0.0..3e6)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .x_labels(6)
+            .y_labels(4)
+            .x_labels_thousands()
+            .y_labels_si()
+            .draw()
+            .expect("Draw mesh");
+
+        let labels = labels.borrow();
+        for label in &["1,000", "2,000", "5,000", "0", "1M", "2M", "3M"] {
+            assert!(labels.contains(&label.to_string()), "{} is missing", label);
+        }
+    }
+
+    #[test]
+    fn test_manual_ticks() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let lines = Rc::new(RefCell::new(vec![]));
+        let (drawn_labels, drawn_lines) = (labels.clone(), lines.clone());
+        let drawing_area = create_mocked_drawing_area(400, 300, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                drawn_labels.borrow_mut().push(text.to_string())
+            });
+            m.check_draw_line(move |c, _, from, to| drawn_lines.borrow_mut().push((c, from, to)));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_left_and_bottom_label_area_size(40)
+            .build_cartesian_2d(0.0..10.0, 0.0..4.0)
+            .expect("Create chart");
+        let x_ticks = [0.0, 2.5, 7.0, 12.0];
+        chart
+            .configure_mesh()
+            .x_ticks(&x_ticks)
+            .y_ticks(&[1.0, 3.0])
+            .x_label_formatter(&|x| format!("x={}", x))
+            .y_label_formatter(&|y| format!("y={}", y))
+            .bold_line_style(RED)
+            .light_line_style(BLUE)
+            .x_minor_lines(4)
+            .draw()
+            .expect("Draw mesh");
+
+        // The labels are the ticks within the ranges
+        let labels = labels.borrow();
+        assert_eq!(
+            labels.as_slice(),
+            &["y=1", "y=3", "x=0", "x=2.5", "x=7"].map(String::from)
+        );
+
+        // The grid lines follow the ticks, without light or minor lines in between
+        let lines = lines.borrow();
+        let grid = |color: RGBColor, vertical: bool| {
+            lines
+                .iter()
+                .filter(|(c, from, to)| *c == color.to_rgba() && (from.0 == to.0) == vertical)
+                .map(|(_, from, _)| if vertical { from.0 } else { from.1 })
+                .collect::<Vec<_>>()
+        };
+        let x_pixel = |x: f64| chart.backend_coord(&(x, 0.0)).0;
+        assert_eq!(
+            grid(RED, true),
+            vec![x_pixel(0.0), x_pixel(2.5), x_pixel(7.0)]
+        );
+        assert_eq!(grid(RED, false).len(), 2);
+        assert!(grid(BLUE, true).is_empty());
+        assert!(grid(BLUE, false).is_empty());
+    }
+
+    #[test]
+    fn test_background_bands() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let rects = Rc::new(RefCell::new(vec![]));
+        let drawn_rects = rects.clone();
+        let drawing_area = create_mocked_drawing_area(400, 300, move |m| {
+            m.check_draw_rect(move |c, _, filled, from, to| {
+                drawn_rects.borrow_mut().push((c, filled, from, to))
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_left_and_bottom_label_area_size(40)
+            .build_cartesian_2d((0..5).into_segmented(), 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .background_bands([RED, BLUE])
+            .draw()
+            .expect("Draw mesh");
+
+        // One band per category from 0 to 5, from a boundary to the pixel before the next one
+        let x_pixel = |x| chart.backend_coord(&(x, 0)).0;
+        let mut edges: Vec<_> = (0..6).map(|x| x_pixel(SegmentValue::Exact(x))).collect();
+        edges.push(x_pixel(SegmentValue::Last));
+        let (_, y_pixels) = chart.plotting_area().get_pixel_range();
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 6);
+        for (index, &(c, filled, from, to)) in rects.iter().enumerate() {
+            let color = if index % 2 == 0 { RED } else { BLUE };
+            assert_eq!(c, color.to_rgba());
+            assert!(filled);
+            assert_eq!(from, (edges[index], y_pixels.start));
+            assert_eq!(to, (edges[index + 1] - 1, y_pixels.end - 1));
+        }
+    }
+
+    #[test]
+    fn test_rotated_labels_grow_label_area() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_left_and_bottom_label_area_size(20)
+            .build_cartesian_2d(100_000..900_000, 0..10)
+            .expect("Create chart");
+        let (_, rows) = chart.plotting_area().get_pixel_range();
+        let (_, bottom) = chart.x_label_area[1].as_ref().unwrap().get_pixel_range();
+        chart
+            .configure_mesh()
+            .x_label_rotation(90.0)
+            .draw()
+            .expect("Draw mesh");
+
+        // The bottom label area is as high as the labels are wide, and the plotting area, the
+        // Y axis and the left label area give it the room
+        let font: TextStyle = ("sans-serif", 12).into();
+        let (label_width, _) = drawing_area.estimate_text_size("900000", &font).unwrap();
+        let (_, label_rows) = chart.x_label_area[1].as_ref().unwrap().get_pixel_range();
+        let (_, plot_rows) = chart.plotting_area().get_pixel_range();
+        assert!(label_rows.end - label_rows.start >= label_width as i32);
+        assert_eq!(label_rows.start, plot_rows.end);
+        assert_eq!(label_rows.end, bottom.end);
+        assert_eq!(plot_rows.start, rows.start);
+        assert_eq!(chart.backend_coord(&(100_000, 0)).1, plot_rows.end - 1);
+        assert_eq!(chart.backend_coord(&(100_000, 10)).1, plot_rows.start);
+        let (_, left_rows) = chart.y_label_area[0].as_ref().unwrap().get_pixel_range();
+        assert_eq!(left_rows, plot_rows);
+    }
+
+    #[test]
+    fn test_pixel_coordinates() {
+        let drawing_area = create_mocked_drawing_area(300, 200, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(40)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        // The plotting area spans the pixels 50 to 289 across, and 10 to 159 down
+        assert_eq!(chart.backend_coord(&(0, 0)), (50, 159));
+        assert_eq!(chart.backend_coord(&(10, 0)), (289, 159));
+        assert_eq!(chart.backend_coord(&(0, 10)), (50, 10));
+        assert_eq!(chart.backend_coord(&(10, 10)), (289, 10));
+
+        // The corners map back to the data
+        for corner in [(0, 0), (10, 0), (0, 10), (10, 10)] {
+            assert_eq!(chart.data_coord(chart.backend_coord(&corner)), Some(corner));
+        }
+        assert_eq!(chart.data_coord((170, 85)), Some((5, 5)));
+    }
+
+    #[test]
+    fn test_points_by_key() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            // The points in the order of the data, then the legend in the order of the keys
+            let mut colors = vec![RED, BLUE, RED, GREEN, RED, BLUE, GREEN];
+            m.check_draw_circle(move |c, _, filled, _, radius| {
+                assert!(filled);
+                assert_eq!(radius, 3);
+                assert_eq!(c, colors.remove(0).to_rgba());
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 7));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+        let data = [
+            (1.0, 1.0, 'a'),
+            (2.0, 5.0, 'b'),
+            (3.0, 2.0, 'a'),
+            (8.0, 8.0, 'c'),
+        ];
+        chart
+            .draw_points_by_key(data, 3, |key| match key {
+                'a' => RED.filled(),
+                'b' => BLUE.filled(),
+                _ => GREEN.filled(),
+            })
+            .expect("Drawing error");
+
+        // One legend entry per distinct key
+        let labels: Vec<_> = chart.series_anno.iter().map(|a| a.get_label()).collect();
+        assert_eq!(labels, vec!["a", "b", "c"]);
+        chart.configure_series_labels().draw().expect("Draw legend");
+    }
+
+    #[test]
+    fn test_secondary_axes_style() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let draw_secondary_axes = |enable_mesh: bool| {
+            let grid_lines = Rc::new(Cell::new(0));
+            let counter = grid_lines.clone();
+            let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+                m.check_draw_line(move |c, _, _, _| {
+                    if c.0 == 0 && c.1 == 0 && c.2 == 0 && c.3 < 1.0 {
+                        counter.set(counter.get() + 1);
+                    }
+                });
+                m.check_draw_text(|c, _, _, _, text| {
+                    assert_eq!((c.0, c.1, c.2), (255, 0, 0));
+                    assert!(text.ends_with('%'));
+                });
+            });
+
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .y_label_area_size(20)
+                .set_label_area_size(LabelAreaPosition::Right, 30)
+                .build_cartesian_2d(0..10, 0..10)
+                .expect("Create chart")
+                .set_secondary_coord(0..10, 0..100);
+
+            let mut style = chart.configure_secondary_axes();
+            style
+                .y_label_formatter(&|y| format!("{}%", y))
+                .y_label_style(("sans-serif", 10).into_font().color(&RED));
+            if enable_mesh {
+                style.enable_y_mesh();
+            }
+            style.draw().expect("Draw secondary axes");
+
+            drop(chart);
+            drop(drawing_area);
+            grid_lines.get()
+        };
+
+        assert_eq!(draw_secondary_axes(false), 0);
+        assert!(draw_secondary_axes(true) > 0);
+    }
+
+    #[test]
+    fn test_log_secondary_coord() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let drawn = labels.clone();
+        let drawing_area = create_mocked_drawing_area(200, 101, move |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                drawn.borrow_mut().push((text.to_string(), pos.1))
+            });
+            m.check_draw_circle(|_, _, _, center, _| assert_eq!(center, (127, 50)));
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 1));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_label_area_size(LabelAreaPosition::Right, 40)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart")
+            .set_secondary_coord(0.0..10.0, (1.0..10000.0).log_scale());
+        chart
+            .configure_secondary_axes()
+            .y_labels(5)
+            .minor_lines(10)
+            .draw()
+            .expect("Draw secondary axes");
+        chart
+            .draw_secondary_series(std::iter::once(Circle::new((8.0, 100.0), 5, RED)))
+            .expect("Drawing error");
+
+        // The labels of the secondary axis are at the powers of ten, evenly spaced
+        let labels = labels.borrow();
+        let texts: Vec<_> = labels.iter().map(|(text, _)| &text[..]).collect();
+        assert_eq!(texts, vec!["1.0", "10.0", "100.0", "1000.0", "10000.0"]);
+        let y: Vec<_> = labels.iter().map(|(_, y)| *y).collect();
+        assert_eq!(y, vec![100, 75, 50, 25, 0]);
+    }
+
+    #[test]
+    fn test_minor_lines() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let minor_lines = Rc::new(RefCell::new(vec![]));
+        let lines = minor_lines.clone();
+        let drawing_area = create_mocked_drawing_area(101, 101, move |m| {
+            m.check_draw_line(move |c, _, from, to| {
+                if (c.0, c.1, c.2) == (255, 0, 0) {
+                    lines.borrow_mut().push((from, to));
+                }
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .x_labels(3)
+            .x_minor_lines(5)
+            .disable_y_mesh()
+            .minor_line_style(RED)
+            .draw()
+            .expect("Draw mesh");
+
+        // Each interval between the bold lines at 0, 5 and 10 is split into 5 parts
+        let x: Vec<_> = minor_lines
+            .borrow()
+            .iter()
+            .map(|(from, _)| from.0)
+            .collect();
+        assert_eq!(x, vec![10, 20, 30, 40, 60, 70, 80, 90]);
+        assert!(minor_lines.borrow().iter().all(|(from, to)| from.0 == to.0));
+    }
+
+    #[test]
+    fn test_mesh_on_top() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let calls = Rc::new(RefCell::new(vec![]));
+        let (lines, rects) = (calls.clone(), calls.clone());
+        let drawing_area = create_mocked_drawing_area(101, 101, move |m| {
+            m.check_draw_line(move |c, _, _, _| {
+                if (c.0, c.1, c.2) == (255, 0, 0) {
+                    lines.borrow_mut().push("grid");
+                }
+            });
+            m.check_draw_rect(move |c, _, _, _, _| {
+                if (c.0, c.1, c.2) == (0, 0, 255) {
+                    rects.borrow_mut().push("bar");
+                }
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .x_labels(3)
+            .y_labels(3)
+            .bold_line_style(RED)
+            .draw_mesh_on_top(true)
+            .draw()
+            .expect("Draw mesh");
+        assert!(calls.borrow().is_empty());
+
+        chart
+            .draw_series(std::iter::once(Rectangle::new([(2, 0), (4, 5)], BLUE)))
+            .expect("Drawing error");
+        // The legend draws the pending grid lines, and they are only drawn once
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing error");
+        chart.draw_deferred_mesh().expect("Drawing error");
+        assert_eq!(
+            *calls.borrow(),
+            vec!["bar", "grid", "grid", "grid", "grid", "grid", "grid"]
+        );
+    }
+
+    #[test]
+    fn test_theme() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let theme = Theme::dark();
+        let (background, text, axis) = (theme.background, theme.text_color, theme.axis_style);
+        let grid = [theme.bold_grid_style.color, theme.light_grid_style.color];
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_in = rects.clone();
+        let mut drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_rect(move |c, _, filled, _, _| {
+                rects_in.borrow_mut().push((c, filled));
+            });
+            m.check_draw_line(move |c, _, _, _| {
+                assert!(
+                    c == axis.color || grid.contains(&c),
+                    "Unexpected line {:?}",
+                    c
+                );
+            });
+            m.check_draw_text(move |c, _, _, _, _| {
+                assert_eq!((c.0, c.1, c.2), (text.0, text.1, text.2));
+            });
+            m.drop_check(|b| {
+                assert!(b.num_draw_line_call > 0);
+                assert!(b.num_draw_text_call > 0);
+            });
+        });
+        drawing_area.apply_theme(&theme).expect("Drawing error");
+        assert_eq!(
+            *rects.borrow(),
+            vec![(background.to_rgba(), true)],
+            "The area is filled with the background"
+        );
+
+        // The sub-areas keep the theme
+        let (left, _) = drawing_area.split_horizontally(150);
+        let mut chart = ChartBuilder::on(&left)
+            .set_left_and_bottom_label_area_size(20)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .x_desc("x")
+            .draw()
+            .expect("Draw mesh");
+
+        // The series colors cycle through the palette
+        for idx in 0..theme.palette.len() + 1 {
+            assert_eq!(chart.next_series_color(), theme.pick(idx));
+            chart
+                .draw_series(std::iter::empty::<Circle<(i32, i32), u32>>())
+                .expect("Drawing error")
+                .label("series");
+        }
+        assert_eq!(chart.next_series_color(), theme.pick(1));
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing error");
+
+        // A single chart can have its own theme, which fills its area
+        let mut chart = ChartBuilder::on(&create_mocked_drawing_area(100, 100, |_| {}))
+            .with_theme(&Theme::solarized())
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        assert_eq!(chart.next_series_color(), Theme::solarized().pick(0));
+        chart
+            .draw_series(std::iter::empty::<Circle<(i32, i32), u32>>())
+            .expect("Drawing error");
+        assert_eq!(chart.next_series_color(), Theme::solarized().pick(1));
+    }
+
+    #[test]
+    fn test_palette() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let paths = Rc::new(RefCell::new(vec![]));
+        let paths_in = paths.clone();
+        let drawing_area = create_mocked_drawing_area(200, 200, move |m| {
+            m.check_draw_path(move |c, _, _| {
+                paths_in.borrow_mut().push(c);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        // Without palette nor theme, the colors are the ones of the light theme
+        assert_eq!(chart.next_color(), Theme::light().pick(0));
+        assert_eq!(chart.next_color(), Theme::light().pick(1));
+
+        // Setting a palette restarts from its first color, and the colors wrap
+        let palette = [RED, GREEN, BLUE];
+        chart.set_palette(palette.iter());
+        assert_eq!(chart.next_series_color(), RED);
+        let expected: Vec<_> = [RED, GREEN, BLUE, RED].iter().map(Color::to_rgba).collect();
+        for _ in 0..4 {
+            chart
+                .draw_series_auto(|color| LineSeries::new(vec![(0, 0), (5, 5)], color))
+                .expect("Drawing error")
+                .label("series");
+        }
+        assert_eq!(*paths.borrow(), expected);
+
+        // The legend of each series is a line of its color
+        paths.borrow_mut().clear();
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing error");
+        assert_eq!(*paths.borrow(), expected);
+
+        // An empty palette falls back on the theme
+        chart.set_palette(Vec::<RGBColor>::new());
+        assert_eq!(chart.next_color(), Theme::light().pick(0));
+
+        // The colors handed out and the series drawn share the cursor of the palette
+        chart.set_palette(palette.iter());
+        assert_eq!(chart.next_color(), RED);
+        assert_eq!(chart.next_series_color(), GREEN);
+        chart
+            .draw_series(LineSeries::new(vec![(0, 0), (5, 5)], GREEN))
+            .expect("Drawing error");
+        assert_eq!(chart.next_series_color(), BLUE);
+        assert_eq!(chart.next_color(), BLUE);
+    }
+
+    #[test]
+    fn test_chart_state_keeps_series_clipping() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart.set_series_clipping(false);
+
+        let chart = chart.to_chart_state().restore(&drawing_area);
+        assert!(!chart.clip_series);
+        let chart = chart.into_shared_chart_state().restore(&drawing_area);
+        assert!(!chart.clip_series);
+    }
+
+    #[test]
+    fn test_outside_legend() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let texts = labels.clone();
+        let drawing_area = create_mocked_drawing_area(300, 100, move |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                texts.borrow_mut().push((pos, text.to_string()));
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .right_legend_area_size(100)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        // The plotting area shrinks to leave room for the legend
+        assert_eq!(chart.plotting_area().dim_in_pixel(), (200, 100));
+
+        for idx in 0..6 {
+            chart
+                .draw_series(std::iter::once(Circle::new((5, 5), 5, RED)))
+                .expect("Drawing error")
+                .label(format!("S{}", idx))
+                .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], RED));
+        }
+        chart
+            .configure_series_labels()
+            .in_legend_area()
+            .position(SeriesLabelPosition::MiddleLeft)
+            .label_font(("sans-serif", 20))
+            .margin(5)
+            .draw()
+            .expect("Drawing error");
+
+        {
+            let labels = labels.borrow();
+            assert_eq!(labels.len(), 6);
+            assert!(labels.iter().all(|((x, _), _)| *x >= 200));
+            // The labels don't fit in a single column of 100 pixels, thus they wrap
+            let columns: Vec<_> = labels.iter().map(|((x, _), _)| *x).collect();
+            assert_eq!(columns[0], columns[1]);
+            assert!(columns[5] > columns[0]);
+            assert!(labels.iter().all(|((_, y), _)| *y >= 0 && *y < 100));
+        }
+
+        // On the plotting area, the labels stay in a single column
+        labels.borrow_mut().clear();
+        chart
+            .configure_series_labels()
+            .label_font(("sans-serif", 20))
+            .draw()
+            .expect("Drawing error");
+        {
+            let labels = labels.borrow();
+            assert_eq!(labels.len(), 6);
+            assert!(labels
+                .iter()
+                .all(|((x, _), _)| *x == labels[0].0 .0 && *x < 200));
+        }
+
+        // The legend area survives a round trip through the chart state
+        let chart = chart.into_chart_state().restore(&drawing_area);
+        let legend_area = chart.legend_area.as_ref().expect("Legend area");
+        assert_eq!(legend_area.get_base_pixel(), (200, 0));
+        assert_eq!(legend_area.dim_in_pixel(), (100, 100));
+    }
+
+    #[test]
+    fn test_dedup_legend_entries() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let texts = labels.clone();
+        let drawing_area = create_mocked_drawing_area(300, 100, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts.borrow_mut().push(text.to_string());
+            });
+            // The background and the border of the legend, then the legend elements, where the
+            // series labeled "A" keeps the style of the first of them
+            m.check_draw_rect(|_, _, _, _, _| {});
+            m.check_draw_rect(|_, _, _, _, _| {});
+            m.check_draw_rect(|c, _, _, _, _| assert_eq!(c, RED.to_rgba()));
+            m.check_draw_rect(|c, _, _, _, _| assert_eq!(c, GREEN.to_rgba()));
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 4));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+
+        for (label, color) in [("A", RED), ("A", BLUE), ("B", GREEN)] {
+            chart
+                .draw_series(std::iter::empty::<Circle<(i32, i32), i32>>())
+                .expect("Drawing error")
+                .label(label)
+                .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color));
+        }
+        chart
+            .configure_series_labels()
+            .dedup_labels()
+            .draw()
+            .expect("Drawing error");
+
+        assert_eq!(*labels.borrow(), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn test_colorbar_layout() {
+        let drawing_area = create_mocked_drawing_area(300, 200, |m| {
+            m.check_draw_rect(|_, _, filled, u, d| {
+                if !filled {
+                    // The strip is at the left of the legend area, as high as the plotting area
+                    assert_eq!((u, d), ((240, 10), (260, 180)));
+                }
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 171));
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .x_label_area_size(10)
+            .right_legend_area_size(60)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .configure_colorbar(0.0..1.0, &ViridisRGB)
+            .labels(0)
+            .draw()
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_colorbar_without_reserved_area() {
+        let drawing_area = create_mocked_drawing_area(300, 200, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        assert!(matches!(
+            chart.configure_colorbar(0.0..1.0, &ViridisRGB).draw(),
+            Err(DrawingAreaErrorKind::LayoutError)
+        ));
+    }
+
+    #[test]
+    fn test_polar_chart_context() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_circle(|_, _, filled, center, _| {
+                assert!(!filled);
+                assert_eq!(center, (100, 100));
+            });
+            m.drop_check(|b| {
+                // The concentric circles at 2, 4, 6, 8 and the border of the disc
+                assert_eq!(b.num_draw_circle_call, 5);
+                assert_eq!(b.num_draw_path_call, 12 + 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .set_all_label_area_size(20)
+            .build_polar(0.0..10.0)
+            .expect("Create chart");
+        chart
+            .set_angle_unit(AngleUnit::Degrees)
+            .set_zero_angle(ZeroAngle::North);
+
+        assert_eq!(
+            chart.plotting_area().map_coordinate(&(0.0, 10.0)),
+            (100, 20)
+        );
+        assert_eq!(
+            chart.plotting_area().map_coordinate(&(90.0, 5.0)),
+            (60, 100)
+        );
+
+        chart
+            .configure_mesh()
+            .radius_labels(6)
+            .draw()
+            .expect("Draw mesh");
+        chart
+            .draw_series(LineSeries::new(vec![(0.0, 1.0), (90.0, 2.0)], RED))
+            .expect("Drawing error");
+    }
+
     #[test]
     fn test_chart_context_3d() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -23,6 +23,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         &mut self,
         (r, c): (YH, XH),
         (x_mesh, y_mesh): (bool, bool),
+        ticks: (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
         mesh_line_style: &ShapeStyle,
         mut fmt_label: FmtLabel,
     ) -> Result<(Vec<(i32, String)>, Vec<(i32, String)>), DrawingAreaErrorKind<DB::ErrorType>>
@@ -33,7 +34,8 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         let mut y_labels = vec![];
         let xr = self.drawing_area.as_coord_spec().x_spec();
         let yr = self.drawing_area.as_coord_spec().y_spec();
-        self.drawing_area.draw_mesh(
+        let deferred = &mut self.deferred_mesh;
+        self.drawing_area.draw_mesh_with_ticks(
             |b, l| {
                 let draw = match l {
                     MeshLine::XMesh((x, _), _, _) => {
@@ -49,14 +51,19 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                         y_mesh
                     }
                 };
-                if draw {
-                    l.draw(b, mesh_line_style)
-                } else {
-                    Ok(())
+                match (draw, deferred.as_mut(), &l) {
+                    (false, _, _) => Ok(()),
+                    (true, None, _) => l.draw(b, mesh_line_style),
+                    (true, Some(lines), MeshLine::XMesh(from, to, _))
+                    | (true, Some(lines), MeshLine::YMesh(from, to, _)) => {
+                        lines.push((*from, *to, *mesh_line_style));
+                        Ok(())
+                    }
                 }
             },
             r,
             c,
+            ticks,
         )?;
         Ok((x_labels, y_labels))
     }
@@ -87,6 +94,19 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
             axis_range.end -= y0;
         }
 
+        let mut breaks: Vec<_> = if orientation.0 == 0 {
+            self.drawing_area.get_x_axis_breaks()
+        } else {
+            self.drawing_area.get_y_axis_breaks()
+        }
+        .into_iter()
+        .map(|gap| {
+            let base = if orientation.0 == 0 { x0 } else { y0 };
+            gap.start - base..gap.end - base
+        })
+        .collect();
+        breaks.sort_by_key(|gap| gap.start);
+
         if let Some(axis_style) = axis_style {
             let mut x0 = if orientation.0 > 0 { 0 } else { tw as i32 - 1 };
             let mut y0 = if orientation.1 > 0 { 0 } else { th as i32 - 1 };
@@ -119,7 +139,41 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 y1 = axis_range.end;
             }
 
-            area.draw(&PathElement::new(vec![(x0, y0), (x1, y1)], *axis_style))?;
+            if breaks.is_empty() {
+                area.draw(&PathElement::new(vec![(x0, y0), (x1, y1)], *axis_style))?;
+            } else {
+                // The axis is drawn piece by piece, with a zig-zag mark across each break
+                const MARK_SIZE: i32 = 5;
+                let point = |along: i32, across: i32| {
+                    if orientation.0 == 0 {
+                        (along, y0 + across)
+                    } else {
+                        (x0 + across, along)
+                    }
+                };
+                let mut from = axis_range.start.min(axis_range.end);
+                for gap in breaks.iter() {
+                    let third = (gap.end - gap.start) / 3;
+                    let pieces = [
+                        vec![point(from, 0), point(gap.start, 0)],
+                        vec![
+                            point(gap.start, 0),
+                            point(gap.start + third, -MARK_SIZE),
+                            point(gap.end - third, MARK_SIZE),
+                            point(gap.end, 0),
+                        ],
+                    ];
+                    for piece in pieces.iter() {
+                        area.draw(&PathElement::new(piece.clone(), *axis_style))?;
+                    }
+                    from = gap.end;
+                }
+                let to = axis_range.start.max(axis_range.end);
+                area.draw(&PathElement::new(
+                    vec![point(from, 0), point(to, 0)],
+                    *axis_style,
+                ))?;
+            }
         }
 
         Ok(axis_range)
@@ -133,6 +187,7 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         area: Option<&DrawingArea<DB, Shift>>,
         axis_style: Option<&ShapeStyle>,
         labels: &[(i32, String)],
+        label_groups: &[(Range<i32>, String)],
         label_style: &TextStyle,
         label_offset: i32,
         orientation: (i16, i16),
@@ -241,6 +296,23 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
                 }
             };
 
+            /* A rotated label hangs away from the axis, starting or ending at its tick mark */
+            let rotation = label_style.font.get_rotation();
+            let (h_pos, v_pos) = match label_style.font.get_transform() {
+                trans if orientation.0 == 0 && rotation % 360.0 != 0.0 => {
+                    let sin = (trans.angle() + rotation).to_radians().sin();
+                    let away_from_top = matches!(v_pos, VPos::Top);
+                    if sin.abs() < 1e-6 {
+                        (h_pos, v_pos)
+                    } else if (sin > 0.0) == away_from_top {
+                        (HPos::Left, VPos::Center)
+                    } else {
+                        (HPos::Right, VPos::Center)
+                    }
+                }
+                _ => (h_pos, v_pos),
+            };
+
             let (text_x, text_y) = if orientation.0 == 0 {
                 (cx + label_offset, cy)
             } else {
@@ -285,6 +357,42 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
             }
         }
 
+        /* The group labels go beyond the labels of the values, centered on the span of each group */
+        if orientation.0 == 0 && !label_groups.is_empty() {
+            let label_height = labels
+                .iter()
+                .filter_map(|(_, text)| {
+                    self.drawing_area.estimate_text_size(text, label_style).ok()
+                })
+                .map(|(w, h)| {
+                    let rotation = label_style.font.get_rotation();
+                    label_style
+                        .font
+                        .get_transform()
+                        .transform_size(w, h, rotation)
+                        .1 as i32
+                })
+                .max()
+                .unwrap_or(0);
+            let group_dist = label_dist * 2 + label_height;
+            let (cy, v_pos) = if (orientation.1 > 0) == (tick_size >= 0) {
+                (group_dist, VPos::Top)
+            } else {
+                (th as i32 - group_dist, VPos::Bottom)
+            };
+            let group_style = &label_style.pos(Pos::new(HPos::Center, v_pos));
+
+            for (span, text) in label_groups {
+                let (start, end) = (span.start - x0, span.end - x0);
+                if end < axis_range.start.min(axis_range.end)
+                    || axis_range.end.max(axis_range.start) < start
+                {
+                    continue;
+                }
+                area.draw_text(text, group_style, ((start + end) / 2, cy))?;
+            }
+        }
+
         if let Some((text, style)) = axis_desc {
             let actual_style = if orientation.0 == 0 {
                 style.clone()
@@ -333,37 +441,46 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         y_desc: Option<String>,
         x_tick_size: [i32; 2],
         y_tick_size: [i32; 2],
+        x_label_groups: &[(Range<i32>, String)],
+        ticks: (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
         FmtLabel: FnMut(&X, &Y, &MeshLine<X, Y>) -> Option<String>,
     {
-        let (x_labels, y_labels) =
-            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, fmt_label)?;
-
-        for idx in 0..2 {
-            self.draw_axis_and_labels(
-                self.x_label_area[idx].as_ref(),
-                if x_axis { Some(axis_style) } else { None },
-                &x_labels[..],
-                x_label_style,
-                x_label_offset,
-                (0, -1 + idx as i16 * 2),
-                x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
-                x_tick_size[idx],
-            )?;
-
-            self.draw_axis_and_labels(
-                self.y_label_area[idx].as_ref(),
-                if y_axis { Some(axis_style) } else { None },
-                &y_labels[..],
-                y_label_style,
-                y_label_offset,
-                (-1 + idx as i16 * 2, 0),
-                y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
-                y_tick_size[idx],
-            )?;
-        }
+        self.drawing_area.push_element_class("plotters-grid");
+        let labels =
+            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), ticks, mesh_line_style, fmt_label);
+        self.drawing_area.pop_element_class();
+        let (x_labels, y_labels) = labels?;
 
-        Ok(())
+        self.drawing_area.with_element_class("plotters-axis", || {
+            for idx in 0..2 {
+                self.draw_axis_and_labels(
+                    self.x_label_area[idx].as_ref(),
+                    if x_axis { Some(axis_style) } else { None },
+                    &x_labels[..],
+                    x_label_groups,
+                    x_label_style,
+                    x_label_offset,
+                    (0, -1 + idx as i16 * 2),
+                    x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
+                    x_tick_size[idx],
+                )?;
+
+                self.draw_axis_and_labels(
+                    self.y_label_area[idx].as_ref(),
+                    if y_axis { Some(axis_style) } else { None },
+                    &y_labels[..],
+                    &[],
+                    y_label_style,
+                    y_label_offset,
+                    (-1 + idx as i16 * 2, 0),
+                    y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
+                    y_tick_size[idx],
+                )?;
+            }
+
+            Ok(())
+        })
     }
 }
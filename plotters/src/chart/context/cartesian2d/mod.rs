@@ -1,14 +1,17 @@
+use std::fmt::Display;
 use std::ops::Range;
 
 use plotters_backend::{BackendCoord, DrawingBackend};
 
-use crate::chart::{ChartContext, DualCoordChartContext, MeshStyle};
+use crate::chart::{ChartContext, DualCoordChartContext, MeshStyle, SeriesAnno};
 use crate::coord::{
     cartesian::Cartesian2d,
     ranged1d::{AsRangedCoord, Ranged, ValueFormatter},
     Shift,
 };
-use crate::drawing::DrawingArea;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{Circle, Ellipse};
+use crate::style::ShapeStyle;
 
 mod draw_impl;
 
@@ -39,6 +42,42 @@ where
         }
     }
 
+    // Grow the top (`idx == 0`) or bottom (`idx == 1`) X label area to the given height, taking
+    // the room from the plotting area, which keeps at least a pixel. The Y label areas follow
+    // the new span of the plotting area.
+    pub(crate) fn grow_x_label_area(&mut self, idx: usize, height: u32) {
+        let label_area = match self.x_label_area[idx].as_ref() {
+            Some(area) if !self.is_overlapping_drawing_area(Some(area)) => area,
+            _ => return,
+        };
+        let (_, label_span) = label_area.get_pixel_range();
+        let (_, plot_span) = self.drawing_area.get_pixel_range();
+        let delta = (height as i32 - (label_span.end - label_span.start))
+            .min(plot_span.end - plot_span.start - 1);
+        if delta <= 0 {
+            return;
+        }
+
+        let (label_span, plot_span) = if idx == 0 {
+            (
+                label_span.start..label_span.end + delta,
+                plot_span.start + delta..plot_span.end,
+            )
+        } else {
+            (
+                label_span.start - delta..label_span.end,
+                plot_span.start..plot_span.end - delta,
+            )
+        };
+        if idx == 0 {
+            self.drawing_area_pos.1 += delta;
+        }
+        self.set_plot_vertical_span(plot_span);
+        if let Some(area) = self.x_label_area[idx].as_mut() {
+            area.set_vertical_span(label_span.start, label_span.end);
+        }
+    }
+
     /// Initialize a mesh configuration object and mesh drawing can be finalized by calling
     /// the function `MeshStyle::draw`.
     pub fn configure_mesh(&mut self) -> MeshStyle<'a, '_, X, Y, DB> {
@@ -47,6 +86,14 @@ where
 }
 
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
+    // Move the plotting area, and the Y label areas beside it, to the given rows
+    pub(crate) fn set_plot_vertical_span(&mut self, span: Range<i32>) {
+        self.drawing_area.set_vertical_span(span.start, span.end);
+        for area in self.y_label_area.iter_mut().flatten() {
+            area.set_vertical_span(span.start, span.end);
+        }
+    }
+
     /// Get the range of X axis
     pub fn x_range(&self) -> Range<X::ValueType> {
         self.drawing_area.get_x_range()
@@ -58,10 +105,110 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
     }
 
     /// Maps the coordinate to the backend coordinate. This is typically used
-    /// with an interactive chart.
+    /// with an interactive chart, while [`ChartContext::data_coord()`] maps back a pixel.
     pub fn backend_coord(&self, coord: &(X::ValueType, Y::ValueType)) -> BackendCoord {
         self.drawing_area.map_coordinate(coord)
     }
+
+    /**
+    Draws a scatter plot whose points are styled by a key, such as the class of each point, and
+    adds one legend entry per distinct key, labeled with the key and showing its style. The
+    points are drawn as a single series, in the order of the data, and the legend entries come
+    in the order the keys first appear.
+
+    - `data`: The points, each with its key
+    - `size`: The radius of the points, in pixels
+    - `style`: Maps each key to the style of its points
+    - **returns** An error if the points couldn't be drawn
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("points_by_key.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(0.0..4.0, 0.0..4.0)
+        .unwrap();
+    let data = [(1.0, 1.0, "small"), (3.0, 2.5, "large"), (1.5, 0.5, "small")];
+    chart
+        .draw_points_by_key(data, 4, |&key| match key {
+            "small" => BLUE.filled(),
+            _ => RED.filled(),
+        })
+        .unwrap();
+    chart.configure_series_labels().draw().unwrap();
+    ```
+    */
+    pub fn draw_points_by_key<K, I, F, S>(
+        &mut self,
+        data: I,
+        size: u32,
+        style: F,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        K: Display + PartialEq,
+        I: IntoIterator<Item = (X::ValueType, Y::ValueType, K)>,
+        F: Fn(&K) -> S,
+        S: Into<ShapeStyle>,
+    {
+        let mut keys: Vec<(K, ShapeStyle)> = vec![];
+        self.draw_series_impl(data.into_iter().map(|(x, y, key)| {
+            let style = style(&key).into();
+            if keys.iter().all(|(known, _)| *known != key) {
+                keys.push((key, style));
+            }
+            Circle::new((x, y), size, style)
+        }))?;
+        for (key, style) in keys {
+            self.alloc_series_anno()
+                .label(key.to_string())
+                .legend(move |p| Circle::new(p, size, style));
+        }
+        Ok(())
+    }
+}
+
+impl<'a, DB, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    DB: DrawingBackend,
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+{
+    /**
+    Draws the confidence ellipse of a set of points, assumed to follow a 2D Gaussian
+    distribution: the ellipse holding the given share of the distribution, see
+    [`Ellipse::confidence_of()`]. Nothing is drawn when there are less than two points.
+
+    - `points`: The points, whose mean and sample covariance define the ellipse
+    - `confidence`: The confidence level, e.g. 0.95, in `[0, 1)`, clamped to it as in
+      [`Ellipse::confidence()`]
+    - `style`: The style of the ellipse, filled or stroked
+    - **returns** The annotation of the ellipse, to give it a legend entry
+
+    ```
+    use plotters::prelude::*;
+    let points = [(1.0, 2.0), (2.0, 3.5), (3.0, 3.0), (4.0, 5.5), (2.5, 2.0)];
+    let drawing_area = SVGBackend::new("confidence_ellipse.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(-2.0..8.0, -1.0..8.0)
+        .unwrap();
+    chart
+        .draw_series(points.iter().map(|&p| Circle::new(p, 3, BLUE.filled())))
+        .unwrap();
+    chart
+        .draw_confidence_ellipse(&points, 0.95, BLUE.mix(0.2).filled())
+        .unwrap()
+        .label("95% confidence");
+    ```
+    */
+    pub fn draw_confidence_ellipse<S: Into<ShapeStyle>>(
+        &mut self,
+        points: &[(f64, f64)],
+        confidence: f64,
+        style: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+        self.draw_series(Ellipse::confidence_of(points, confidence, style))
+    }
 }
 
 impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
@@ -82,7 +229,10 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesia
         Cartesian2d<X, Y>,
         Cartesian2d<SX::CoordDescType, SY::CoordDescType>,
     > {
+        // The secondary coordinate spans the same pixels as the primary one, see `ChartBuilder`
         let mut pixel_range = self.drawing_area.get_pixel_range();
+        pixel_range.0.end -= 1;
+        pixel_range.1.end -= 1;
         pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
 
         DualCoordChartContext::new(self, Cartesian2d::new(x_coord, y_coord, pixel_range))
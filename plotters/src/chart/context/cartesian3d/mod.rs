@@ -109,6 +109,19 @@ where
     ///   projection matrix. This function will allow you to adjust the pitch, yaw angle and the
     ///   centeral point of the projection, etc. You can also build a projection matrix which is not
     ///   relies on the default configuration as well.
+    ///
+    /// ```
+    /// use plotters::prelude::*;
+    /// let mut svg = String::new();
+    /// let drawing_area = SVGBackend::with_string(&mut svg, (300, 300)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&drawing_area)
+    ///     .build_cartesian_3d(0.0..1.0, 0.0..1.0, 0.0..1.0)
+    ///     .unwrap();
+    /// chart.with_projection(|mut pb| {
+    ///     pb.perspective(0.8).camera_angle(0.3, 0.7, 0.0).scale(0.8);
+    ///     pb.into_matrix()
+    /// });
+    /// ```
     pub fn with_projection<P: FnOnce(ProjectionMatrixBuilder) -> ProjectionMatrix>(
         &mut self,
         pf: P,
@@ -0,0 +1,52 @@
+use crate::chart::{polar::PolarMeshStyle, ChartContext};
+use crate::coord::{
+    polar::{AngleUnit, Polar, ZeroAngle},
+    ranged1d::{Ranged, ValueFormatter},
+};
+use plotters_backend::DrawingBackend;
+
+impl<'a, DB, R, RT> ChartContext<'a, DB, Polar<R>>
+where
+    DB: DrawingBackend,
+    R: Ranged<ValueType = RT> + ValueFormatter<RT>,
+{
+    /**
+    Create a mesh configuration object for the polar chart: the concentric circles of the radius,
+    the radial lines of the angle and their labels.
+
+    See [`Polar`] for more information and examples.
+    */
+    pub fn configure_mesh(&mut self) -> PolarMeshStyle<'a, '_, R, DB> {
+        PolarMeshStyle::new(self)
+    }
+}
+
+impl<'a, DB, R: Ranged> ChartContext<'a, DB, Polar<R>>
+where
+    DB: DrawingBackend,
+{
+    /// Set the unit of the angles of the chart, radians by default
+    /// - `unit`: The new angle unit
+    pub fn set_angle_unit(&mut self, unit: AngleUnit) -> &mut Self {
+        self.drawing_area.as_coord_spec_mut().set_angle_unit(unit);
+        self
+    }
+
+    /// Set the direction the zero angle of the chart points to, east by default
+    /// - `zero_angle`: The new zero angle direction
+    pub fn set_zero_angle(&mut self, zero_angle: ZeroAngle) -> &mut Self {
+        self.drawing_area
+            .as_coord_spec_mut()
+            .set_zero_angle(zero_angle);
+        self
+    }
+
+    /// Set if the angles of the chart grow clockwise, counterclockwise by default
+    /// - `clockwise`: If the angles grow clockwise
+    pub fn set_clockwise(&mut self, clockwise: bool) -> &mut Self {
+        self.drawing_area
+            .as_coord_spec_mut()
+            .set_clockwise(clockwise);
+        self
+    }
+}
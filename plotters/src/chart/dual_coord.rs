@@ -121,7 +121,12 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
                 y_label_area: secondary_y_label_area,
                 drawing_area: secondary_drawing_area,
                 series_anno: vec![],
+                legend_area: None,
                 drawing_area_pos: (0, 0),
+                clip_series: true,
+                deferred_mesh: None,
+                palette: None,
+                color_idx: 0,
             },
         }
     }
@@ -183,6 +188,9 @@ where
 {
     /// Start configure the style for the secondary axes
     pub fn configure_secondary_axes<'b>(&'b mut self) -> SecondaryMeshStyle<'a, 'b, SX, SY, DB> {
+        // The primary mesh may have shrunk the plotting area to fit rotated labels
+        let (_, span) = self.primary.drawing_area.get_pixel_range();
+        self.secondary.set_plot_vertical_span(span);
         SecondaryMeshStyle::new(&mut self.secondary)
     }
 }
@@ -203,9 +211,24 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged, SX: Ranged, SY: Ranged>
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
+        self.sync_plot_spans();
         self.secondary.draw_series_impl(series)?;
         Ok(self.primary.alloc_series_anno())
     }
+
+    // Either mesh may have shrunk its plotting area to fit rotated labels, and both plotting
+    // areas keep the rows they have in common
+    fn sync_plot_spans(&mut self) {
+        let (_, primary) = self.primary.drawing_area.get_pixel_range();
+        let (_, secondary) = self.secondary.drawing_area.get_pixel_range();
+        if primary == secondary {
+            return;
+        }
+        let span = primary.start.max(secondary.start)..primary.end.min(secondary.end);
+        self.primary.drawing_area_pos.1 += span.start - primary.start;
+        self.primary.set_plot_vertical_span(span.clone());
+        self.secondary.set_plot_vertical_span(span);
+    }
 }
 
 impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
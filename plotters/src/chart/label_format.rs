@@ -0,0 +1,140 @@
+// The number formatters of the axis labels, which the mesh styles select with helpers such as
+// `y_labels_si()`
+use crate::data::float::FloatPrettyPrinter;
+use num_traits::ToPrimitive;
+
+// The prefixes from 10^-24 to 10^24, by steps of 10^3
+const SI_PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+// The index of the empty prefix
+const SI_UNIT: i32 = 8;
+const SI_DIGITS: i32 = 3;
+
+/**
+Formats a number with a comma between each group of three digits of its integer part, such as
+`1,234,567.5`.
+
+```
+use plotters::chart::format_thousands;
+assert_eq!(format_thousands(&1_000_000), "1,000,000");
+assert_eq!(format_thousands(&-12345.25), "-12,345.25");
+```
+*/
+pub fn format_thousands<T: ToPrimitive>(value: &T) -> String {
+    let value = match value.to_f64() {
+        Some(value) if value.is_finite() => value,
+        _ => return String::new(),
+    };
+    let repr = FloatPrettyPrinter {
+        allow_scientific: false,
+        min_decimal: 0,
+        max_decimal: 10,
+    }
+    .print(value);
+
+    let (sign, repr) = match repr.strip_prefix('-') {
+        Some(repr) => ("-", repr),
+        None => ("", repr.as_str()),
+    };
+    let (int_part, dec_part) = match repr.find('.') {
+        Some(dot) => repr.split_at(dot),
+        None => (repr, ""),
+    };
+
+    let mut result = String::from(sign);
+    for (idx, digit) in int_part.chars().enumerate() {
+        if idx > 0 && (int_part.len() - idx) % 3 == 0 {
+            result.push(',');
+        }
+        result.push(digit);
+    }
+    result.push_str(dec_part);
+    result
+}
+
+/**
+Formats a number with the SI prefix of its magnitude, such as `1.5M` or `250µ`, keeping three
+significant digits. The prefix is chosen for each number, thus the labels of an axis may have
+different prefixes.
+
+```
+use plotters::chart::format_si;
+assert_eq!(format_si(&1_500_000), "1.5M");
+assert_eq!(format_si(&0.00025), "250µ");
+assert_eq!(format_si(&999.96), "1k");
+```
+*/
+pub fn format_si<T: ToPrimitive>(value: &T) -> String {
+    let value = match value.to_f64() {
+        Some(value) if value.is_finite() => value,
+        _ => return String::new(),
+    };
+    if value == 0.0 {
+        return "0".to_string();
+    }
+
+    let max_idx = SI_PREFIXES.len() as i32 - 1;
+    let mut idx = ((value.abs().log10() / 3.0).floor() as i32 + SI_UNIT).clamp(0, max_idx);
+    loop {
+        let scaled = value / 1000f64.powi(idx - SI_UNIT);
+        // The number of decimals which keeps the significant digits
+        let decimals = (SI_DIGITS - scaled.abs().log10().floor() as i32 - 1).max(0);
+        let factor = 10f64.powi(decimals);
+        let rounded = (scaled * factor).round() / factor;
+        // The rounding may carry to the next prefix, such as 999.96 to 1000
+        if rounded.abs() >= 1000.0 && idx < max_idx {
+            idx += 1;
+            continue;
+        }
+        let repr = FloatPrettyPrinter {
+            allow_scientific: false,
+            min_decimal: 0,
+            max_decimal: decimals,
+        }
+        .print(rounded);
+        return format!("{}{}", repr, SI_PREFIXES[idx as usize]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_thousands() {
+        assert_eq!(format_thousands(&0), "0");
+        assert_eq!(format_thousands(&999), "999");
+        assert_eq!(format_thousands(&1000), "1,000");
+        assert_eq!(format_thousands(&123_456u32), "123,456");
+        assert_eq!(format_thousands(&1_000_000u64), "1,000,000");
+        assert_eq!(format_thousands(&-1_234_567i64), "-1,234,567");
+        assert_eq!(format_thousands(&1234.5f32), "1,234.5");
+        assert_eq!(format_thousands(&-0.25), "-0.25");
+        assert_eq!(format_thousands(&(0.1 + 0.2)), "0.3");
+        assert_eq!(format_thousands(&f64::NAN), "");
+    }
+
+    #[test]
+    fn test_format_si() {
+        assert_eq!(format_si(&0), "0");
+        assert_eq!(format_si(&1), "1");
+        assert_eq!(format_si(&999), "999");
+        assert_eq!(format_si(&1000), "1k");
+        assert_eq!(format_si(&1500), "1.5k");
+        assert_eq!(format_si(&12_345), "12.3k");
+        assert_eq!(format_si(&250_000u32), "250k");
+        assert_eq!(format_si(&1_000_000u64), "1M");
+        assert_eq!(format_si(&-2_500_000_000i64), "-2.5G");
+        assert_eq!(format_si(&3.2e12), "3.2T");
+        assert_eq!(format_si(&0.5), "500m");
+        assert_eq!(format_si(&0.000_001_5), "1.5µ");
+        assert_eq!(format_si(&4.7e-9), "4.7n");
+        // The rounding carries to the next prefix
+        assert_eq!(format_si(&999.96), "1k");
+        assert_eq!(format_si(&999_600.0), "1M");
+        // Out of the prefixes, the number grows past three digits
+        assert_eq!(format_si(&2e27), "2000Y");
+        assert_eq!(format_si(&f64::INFINITY), "");
+    }
+}
@@ -1,15 +1,15 @@
 use std::marker::PhantomData;
 
-use super::builder::LabelAreaPosition;
+use super::builder::{fitted_label_area_size, LabelAreaPosition};
 use super::context::ChartContext;
+use super::label_format::{format_si, format_thousands};
 use crate::coord::cartesian::{Cartesian2d, MeshLine};
-use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged, ValueFormatter};
+use crate::coord::ranged1d::{BoldPoints, DiscreteRanged, LightPoints, Ranged, ValueFormatter};
 use crate::drawing::DrawingAreaErrorKind;
-use crate::style::{
-    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
-    SizeDesc, TextStyle,
-};
+use crate::element::Rectangle;
+use crate::style::{AsRelative, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, Theme};
 
+use num_traits::ToPrimitive;
 use plotters_backend::DrawingBackend;
 
 /// The style used to describe the mesh and axis for a secondary coordinate system.
@@ -55,6 +55,59 @@ where
         self
     }
 
+    /// Enable the mesh for the x axis, which is disabled by default for the secondary axes
+    pub fn enable_x_mesh(&mut self) -> &mut Self {
+        self.style.draw_x_mesh = true;
+        self
+    }
+
+    /// Enable the mesh for the y axis, which is disabled by default for the secondary axes
+    pub fn enable_y_mesh(&mut self) -> &mut Self {
+        self.style.draw_y_mesh = true;
+        self
+    }
+
+    /// Enable the meshes for both axes
+    pub fn enable_mesh(&mut self) -> &mut Self {
+        self.enable_x_mesh().enable_y_mesh()
+    }
+
+    /// Set the style for the coarse grind grid
+    /// - `style`: This is the coarse grind grid style
+    pub fn bold_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.bold_line_style(style);
+        self
+    }
+
+    /// Set the style for the fine grind grid
+    /// - `style`: The fine grind grid style
+    pub fn light_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.light_line_style(style);
+        self
+    }
+
+    /// Set the maximum number of divisions for the minor grid
+    /// - `value`: Maximum desired divisions between two consecutive labels in X and Y
+    pub fn max_light_lines(&mut self, value: usize) -> &mut Self {
+        self.style.max_light_lines(value);
+        self
+    }
+
+    /// Split each interval between two consecutive labels into `value` parts with minor grid
+    /// lines, in X and Y. See [MeshStyle::x_minor_lines](struct.MeshStyle.html#method.x_minor_lines)
+    /// - `value`: The number of parts between two consecutive labels
+    pub fn minor_lines(&mut self, value: usize) -> &mut Self {
+        self.style.minor_lines(value);
+        self
+    }
+
+    /// Set the style for the minor grid lines
+    /// - `style`: The minor grid line style
+    pub fn minor_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.style.minor_line_style(style);
+        self
+    }
+
     /// Set how many labels for the X axis at most
     /// - `value`: The maximum desired number of labels in the X axis
     pub fn x_labels(&mut self, value: usize) -> &mut Self {
@@ -69,6 +122,22 @@ where
         self
     }
 
+    /// Put the X ticks and their labels at the given values, see
+    /// [MeshStyle::x_ticks](struct.MeshStyle.html#method.x_ticks)
+    /// - `ticks`: The values of the ticks
+    pub fn x_ticks(&mut self, ticks: &'b [X::ValueType]) -> &mut Self {
+        self.style.x_ticks(ticks);
+        self
+    }
+
+    /// Put the Y ticks and their labels at the given values, see
+    /// [MeshStyle::x_ticks](struct.MeshStyle.html#method.x_ticks)
+    /// - `ticks`: The values of the ticks
+    pub fn y_ticks(&mut self, ticks: &'b [Y::ValueType]) -> &mut Self {
+        self.style.y_ticks(ticks);
+        self
+    }
+
     /// Set the formatter function for the X label text
     /// - `fmt`: The formatter function
     pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
@@ -83,6 +152,42 @@ where
         self
     }
 
+    /// Format the X labels with thousands separators, such as `1,000,000`
+    pub fn x_labels_thousands(&mut self) -> &mut Self
+    where
+        XT: ToPrimitive,
+    {
+        self.style.x_labels_thousands();
+        self
+    }
+
+    /// Format the Y labels with thousands separators, such as `1,000,000`
+    pub fn y_labels_thousands(&mut self) -> &mut Self
+    where
+        YT: ToPrimitive,
+    {
+        self.style.y_labels_thousands();
+        self
+    }
+
+    /// Format the X labels with SI prefixes, such as `1.5M`
+    pub fn x_labels_si(&mut self) -> &mut Self
+    where
+        XT: ToPrimitive,
+    {
+        self.style.x_labels_si();
+        self
+    }
+
+    /// Format the Y labels with SI prefixes, such as `1.5M`
+    pub fn y_labels_si(&mut self) -> &mut Self
+    where
+        YT: ToPrimitive,
+    {
+        self.style.y_labels_si();
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -116,6 +221,28 @@ where
         self
     }
 
+    /// Set the label style for the secondary X axis
+    /// - `style`: The text style that would be applied to the labels
+    pub fn x_label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.style.x_label_style(style);
+        self
+    }
+
+    /// Rotate the labels of the secondary X axis
+    /// - `angle`: The clockwise rotation of the labels in degrees
+    pub fn x_label_rotation(&mut self, angle: f64) -> &mut Self {
+        self.style.x_label_rotation(angle);
+        self
+    }
+
+    /// Set the label style for the secondary Y axis, e.g. to match the color of the series
+    /// drawn against it
+    /// - `style`: The text style that would be applied to the labels
+    pub fn y_label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
+        self.style.y_label_style(style);
+        self
+    }
+
     /// Set all the tick marks to the same size
     /// `value`: The new size
     pub fn set_all_tick_mark_size<S: SizeDesc>(&mut self, value: S) -> &mut Self {
@@ -148,10 +275,13 @@ pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) draw_y_mesh: bool,
     pub(super) draw_x_axis: bool,
     pub(super) draw_y_axis: bool,
+    pub(super) mesh_on_top: bool,
     pub(super) x_label_offset: i32,
     pub(super) y_label_offset: i32,
     pub(super) x_light_lines_limit: usize,
     pub(super) y_light_lines_limit: usize,
+    pub(super) x_minor_lines: Option<usize>,
+    pub(super) y_minor_lines: Option<usize>,
     pub(super) n_x_labels: usize,
     pub(super) n_y_labels: usize,
     pub(super) axis_desc_style: Option<TextStyle<'b>>,
@@ -159,11 +289,17 @@ pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) y_desc: Option<String>,
     pub(super) bold_line_style: Option<ShapeStyle>,
     pub(super) light_line_style: Option<ShapeStyle>,
+    pub(super) minor_line_style: Option<ShapeStyle>,
     pub(super) axis_style: Option<ShapeStyle>,
     pub(super) x_label_style: Option<TextStyle<'b>>,
     pub(super) y_label_style: Option<TextStyle<'b>>,
+    pub(super) x_label_rotation: Option<f64>,
     pub(super) format_x: Option<&'b dyn Fn(&X::ValueType) -> String>,
     pub(super) format_y: Option<&'b dyn Fn(&Y::ValueType) -> String>,
+    pub(super) x_ticks: Option<&'b [X::ValueType]>,
+    pub(super) y_ticks: Option<&'b [Y::ValueType]>,
+    pub(super) x_bands: (Vec<X::ValueType>, Vec<ShapeStyle>),
+    pub(super) y_bands: (Vec<Y::ValueType>, Vec<ShapeStyle>),
     pub(super) target: Option<&'b mut ChartContext<'a, DB, Cartesian2d<X, Y>>>,
     pub(super) _phantom_data: PhantomData<(X, Y)>,
     pub(super) x_tick_size: [i32; 2],
@@ -200,16 +336,25 @@ where
             draw_y_mesh: true,
             draw_x_axis: true,
             draw_y_axis: true,
+            mesh_on_top: false,
             x_light_lines_limit: 10,
             y_light_lines_limit: 10,
+            x_minor_lines: None,
+            y_minor_lines: None,
             n_x_labels: 11,
             n_y_labels: 11,
             bold_line_style: None,
             light_line_style: None,
+            minor_line_style: None,
             x_label_style: None,
             y_label_style: None,
+            x_label_rotation: None,
             format_x: None,
             format_y: None,
+            x_ticks: None,
+            y_ticks: None,
+            x_bands: (vec![], vec![]),
+            y_bands: (vec![], vec![]),
             target: Some(chart),
             _phantom_data: PhantomData,
             x_desc: None,
@@ -314,6 +459,46 @@ where
         self
     }
 
+    /**
+    Sets whether the grid lines are drawn on top of the series, rather than behind them, which
+    is the default.
+
+    The axes and the labels are drawn by [`MeshStyle::draw()`] as usual, but the grid lines are
+    kept until the series are drawn: they are drawn by
+    [`ChartContext::draw_deferred_mesh()`], or before the legend by
+    [`crate::chart::SeriesLabelStyle::draw()`]. This suits subtle reference lines over filled
+    series, such as bars or heatmaps.
+
+    - `on_top`: Whether the grid lines are drawn on top of the series
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("mesh_on_top.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(20)
+        .build_cartesian_2d((0..4).into_segmented(), 0..10)
+        .unwrap();
+    chart
+        .configure_mesh()
+        .light_line_style(WHITE.mix(0.3))
+        .bold_line_style(WHITE.mix(0.6))
+        .draw_mesh_on_top(true)
+        .draw()
+        .unwrap();
+    chart
+        .draw_series(Histogram::vertical(&chart).data([(0, 3), (1, 7), (2, 9), (3, 4)]))
+        .unwrap();
+    chart.draw_deferred_mesh().unwrap();
+    ```
+    */
+    pub fn draw_mesh_on_top(&mut self, on_top: bool) -> &mut Self {
+        self.mesh_on_top = on_top;
+        self
+    }
+
     /// Set the maximum number of divisions for the minor grid
     /// - `value`: Maximum desired divisions between two consecutive X labels
     pub fn x_max_light_lines(&mut self, value: usize) -> &mut Self {
@@ -336,6 +521,31 @@ where
         self
     }
 
+    /// Split each interval between two consecutive X labels into `value` parts with minor grid
+    /// lines, which replace the light lines of the X axis. On a log axis, the minor lines are
+    /// put at 2, 3, ..., 9 times each power of ten instead.
+    /// - `value`: The number of parts between two consecutive X labels
+    pub fn x_minor_lines(&mut self, value: usize) -> &mut Self {
+        self.x_minor_lines = Some(value);
+        self
+    }
+
+    /// Split each interval between two consecutive Y labels into `value` parts with minor grid
+    /// lines, which replace the light lines of the Y axis. On a log axis, the minor lines are
+    /// put at 2, 3, ..., 9 times each power of ten instead.
+    /// - `value`: The number of parts between two consecutive Y labels
+    pub fn y_minor_lines(&mut self, value: usize) -> &mut Self {
+        self.y_minor_lines = Some(value);
+        self
+    }
+
+    /// Split each interval between two consecutive labels into `value` parts with minor grid
+    /// lines, in X and Y. See [MeshStyle::x_minor_lines](#method.x_minor_lines)
+    /// - `value`: The number of parts between two consecutive labels
+    pub fn minor_lines(&mut self, value: usize) -> &mut Self {
+        self.x_minor_lines(value).y_minor_lines(value)
+    }
+
     /// Set how many labels for the X axis at most
     /// - `value`: The maximum desired number of labels in the X axis
     pub fn x_labels(&mut self, value: usize) -> &mut Self {
@@ -350,6 +560,24 @@ where
         self
     }
 
+    /// Put the X ticks, their labels and their grid lines at the given values, rather than at
+    /// the automatically chosen ones. The labels are formatted by the X label formatter, and
+    /// the light and minor grid lines of the X axis aren't drawn. The values out of the X range
+    /// are skipped.
+    /// - `ticks`: The values of the ticks, such as the times of some events
+    pub fn x_ticks(&mut self, ticks: &'b [X::ValueType]) -> &mut Self {
+        self.x_ticks = Some(ticks);
+        self
+    }
+
+    /// Put the Y ticks, their labels and their grid lines at the given values, rather than at
+    /// the automatically chosen ones. See [MeshStyle::x_ticks](#method.x_ticks)
+    /// - `ticks`: The values of the ticks
+    pub fn y_ticks(&mut self, ticks: &'b [Y::ValueType]) -> &mut Self {
+        self.y_ticks = Some(ticks);
+        self
+    }
+
     /// Set the style for the coarse grind grid
     /// - `style`: This is the coarse grind grid style
     pub fn bold_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
@@ -364,6 +592,13 @@ where
         self
     }
 
+    /// Set the style for the minor grid lines, the light line style is used by default
+    /// - `style`: The minor grid line style
+    pub fn minor_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.minor_line_style = Some(style.into());
+        self
+    }
+
     /// Set the style of the label text
     /// - `style`: The text style that would be applied to the labels
     pub fn label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -380,6 +615,16 @@ where
         self
     }
 
+    /// Rotate the labels of the X axis, which keeps long labels from overlapping each other.
+    /// Each label is rotated around its tick mark and hangs away from the axis. A label area
+    /// which is too short for the rotated labels grows to fit them, taking the room from the
+    /// plotting area, thus the mesh is better drawn before the series.
+    /// - `angle`: The clockwise rotation of the labels in degrees, e.g. `45.0` or `-45.0`
+    pub fn x_label_rotation(&mut self, angle: f64) -> &mut Self {
+        self.x_label_rotation = Some(angle);
+        self
+    }
+
     /// Set the style of the label Y axis text
     /// - `style`: The text style that would be applied to the labels
     pub fn y_label_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -401,6 +646,135 @@ where
         self
     }
 
+    /**
+    Format the X labels with a comma between each group of three digits, such as `1,000,000`.
+
+    See [`crate::chart::format_thousands()`] for more information and examples.
+    */
+    pub fn x_labels_thousands(&mut self) -> &mut Self
+    where
+        X::ValueType: ToPrimitive,
+    {
+        self.format_x = Some(&format_thousands::<X::ValueType>);
+        self
+    }
+
+    /**
+    Format the Y labels with a comma between each group of three digits, such as `1,000,000`.
+
+    See [`crate::chart::format_thousands()`] for more information and examples.
+    */
+    pub fn y_labels_thousands(&mut self) -> &mut Self
+    where
+        Y::ValueType: ToPrimitive,
+    {
+        self.format_y = Some(&format_thousands::<Y::ValueType>);
+        self
+    }
+
+    /**
+    Format the X labels with the SI prefix of their magnitude and three significant digits,
+    such as `1.5M` or `250µ`. The prefix is chosen for each label.
+
+    See [`MeshStyle::y_labels_si()`] for more information and examples.
+    */
+    pub fn x_labels_si(&mut self) -> &mut Self
+    where
+        X::ValueType: ToPrimitive,
+    {
+        self.format_x = Some(&format_si::<X::ValueType>);
+        self
+    }
+
+    /**
+    Format the Y labels with the SI prefix of their magnitude and three significant digits,
+    such as `1.5M` or `250µ`. The prefix is chosen for each label.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("labels_si.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(40)
+        .build_cartesian_2d(0..10, 0u64..5_000_000)
+        .unwrap();
+    chart.configure_mesh().y_labels_si().draw().unwrap();
+    ```
+
+    See [`crate::chart::format_si()`] for more information.
+    */
+    pub fn y_labels_si(&mut self) -> &mut Self
+    where
+        Y::ValueType: ToPrimitive,
+    {
+        self.format_y = Some(&format_si::<Y::ValueType>);
+        self
+    }
+
+    /**
+    Fill the intervals between the consecutive values of the X axis with the given styles in
+    turn, behind the grid and the series. On a segmented axis, these are the categories, and
+    the bands start and end at the ticks between them.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("background_bands.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d((0..5).into_segmented(), 0..10)
+        .unwrap();
+    chart
+        .configure_mesh()
+        .background_bands([WHITE.mix(0.0), BLACK.mix(0.1)])
+        .draw()
+        .unwrap();
+    ```
+
+    - `styles`: The styles of the bands, from the start of the axis, which cycle when there
+      are more intervals. The bands are always filled.
+    */
+    pub fn background_bands<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(
+        &mut self,
+        styles: I,
+    ) -> &mut Self
+    where
+        X: DiscreteRanged,
+    {
+        let styles: Vec<ShapeStyle> = styles.into_iter().map(|s| s.into().filled()).collect();
+        self.x_bands = (vec![], vec![]);
+        if let Some(target) = self.target.as_ref() {
+            let x_spec = target.drawing_area.as_coord_spec().x_spec();
+            let edges = (0..x_spec.size())
+                .filter_map(|index| x_spec.from_index(index))
+                .collect();
+            self.x_bands = (edges, styles);
+        }
+        self
+    }
+
+    /// Fill the intervals between the consecutive values of the Y axis with the given styles in
+    /// turn, see [MeshStyle::background_bands](#method.background_bands)
+    /// - `styles`: The styles of the bands, from the start of the axis
+    pub fn y_background_bands<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(
+        &mut self,
+        styles: I,
+    ) -> &mut Self
+    where
+        Y: DiscreteRanged,
+    {
+        let styles: Vec<ShapeStyle> = styles.into_iter().map(|s| s.into().filled()).collect();
+        self.y_bands = (vec![], vec![]);
+        if let Some(target) = self.target.as_ref() {
+            let y_spec = target.drawing_area.as_coord_spec().y_spec();
+            let edges = (0..y_spec.size())
+                .filter_map(|index| y_spec.from_index(index))
+                .collect();
+            self.y_bands = (edges, styles);
+        }
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -430,40 +804,152 @@ where
     {
         let target = self.target.take().unwrap();
 
-        let default_mesh_color_1 = RGBColor(0, 0, 0).mix(0.2);
-        let default_mesh_color_2 = RGBColor(0, 0, 0).mix(0.1);
-        let default_axis_color = RGBColor(0, 0, 0);
-        let default_label_font = FontDesc::new(
-            FontFamily::SansSerif,
-            f64::from((12i32).percent().max(12).in_pixels(&self.parent_size)),
-            FontStyle::Normal,
-        );
-
-        let bold_style = self
-            .bold_line_style
-            .unwrap_or_else(|| (&default_mesh_color_1).into());
-        let light_style = self
-            .light_line_style
-            .unwrap_or_else(|| (&default_mesh_color_2).into());
-        let axis_style = self
-            .axis_style
-            .unwrap_or_else(|| (&default_axis_color).into());
-
-        let x_label_style = self
+        // The rotated labels take the room they need from the plotting area, before anything
+        // is drawn on it
+        if self.draw_x_axis && self.x_label_rotation.is_some() {
+            self.fit_x_label_areas(target)?;
+        }
+
+        // The bands are behind everything else, even a mesh drawn on top. They are placed now,
+        // since the plotting area may have shrunk since they were configured.
+        if !self.x_bands.1.is_empty() || !self.y_bands.1.is_empty() {
+            let coord = target.drawing_area.as_coord_spec();
+            let (x_pixels, y_pixels) = target.drawing_area.get_pixel_range();
+            let x_edges: Vec<_> = self.x_bands.0.iter().map(|x| coord.x_pixel(x)).collect();
+            let y_edges: Vec<_> = self.y_bands.0.iter().map(|y| coord.y_pixel(y)).collect();
+            let x_rects = band_rects(&x_edges, &self.x_bands.1)
+                .map(|(x0, x1, style)| ([(x0, y_pixels.start), (x1, y_pixels.end - 1)], style));
+            let y_rects = band_rects(&y_edges, &self.y_bands.1)
+                .map(|(y0, y1, style)| ([(x_pixels.start, y0), (x_pixels.end - 1, y1)], style));
+            let screen = target.drawing_area.use_screen_coord();
+            for (corners, style) in y_rects.chain(x_rects) {
+                screen.draw(&Rectangle::new(corners, style))?;
+            }
+        }
+
+        // The grid lines of a mesh on top are added to the pending ones, the other meshes are
+        // drawn right away
+        let pending = target.deferred_mesh.take();
+        if self.mesh_on_top {
+            target.deferred_mesh = Some(pending.unwrap_or_default());
+            self.draw_on(target)
+        } else {
+            let result = self.draw_on(target);
+            target.deferred_mesh = pending;
+            result
+        }
+    }
+
+    // The styles of the X and Y labels, which come from the theme unless they are set
+    fn label_styles(&self, theme: &Theme) -> (TextStyle<'b>, TextStyle<'b>) {
+        let default_label_style = theme.text_style(f64::from(
+            (12i32).percent().max(12).in_pixels(&self.parent_size),
+        ));
+
+        let mut x_label_style = self
             .x_label_style
             .clone()
-            .unwrap_or_else(|| default_label_font.clone().into());
+            .unwrap_or_else(|| default_label_style.clone());
+        if let Some(angle) = self.x_label_rotation {
+            x_label_style = x_label_style.rotation(angle);
+        }
 
-        let y_label_style = self
-            .y_label_style
-            .clone()
-            .unwrap_or_else(|| default_label_font.into());
+        let y_label_style = self.y_label_style.clone().unwrap_or(default_label_style);
+        (x_label_style, y_label_style)
+    }
+
+    // Grow the X label areas which are too small for the labels, once rotated
+    fn fit_x_label_areas(
+        &self,
+        target: &mut ChartContext<'a, DB, Cartesian2d<X, Y>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        X: ValueFormatter<<X as Ranged>::ValueType>,
+        Y: ValueFormatter<<Y as Ranged>::ValueType>,
+    {
+        let theme = target.drawing_area.theme().cloned().unwrap_or_default();
+        let (x_label_style, _) = self.label_styles(&theme);
+
+        let x_spec = target.drawing_area.as_coord_spec().x_spec();
+        let format = |x: &X::ValueType| match self.format_x {
+            Some(fmt_func) => fmt_func(x),
+            None => x_spec.format_ext(x),
+        };
+        let labels: Vec<_> = match self.x_ticks {
+            Some(ticks) => ticks.iter().map(format).collect(),
+            None => x_spec
+                .key_points(BoldPoints(self.n_x_labels))
+                .iter()
+                .map(format)
+                .collect(),
+        };
+
+        for idx in 0..2 {
+            let tick_size = self.x_tick_size[idx].unsigned_abs();
+            let height = fitted_label_area_size(
+                &target.drawing_area,
+                &labels,
+                &x_label_style,
+                true,
+                tick_size,
+            )?;
+            target.grow_x_label_area(idx, height);
+        }
+        Ok(())
+    }
+
+    fn draw_on(
+        &self,
+        target: &mut ChartContext<'a, DB, Cartesian2d<X, Y>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        X: ValueFormatter<<X as Ranged>::ValueType>,
+        Y: ValueFormatter<<Y as Ranged>::ValueType>,
+    {
+        // The styles which aren't set come from the theme, if any
+        let theme = target.drawing_area.theme().cloned().unwrap_or_default();
+        let (x_label_style, y_label_style) = self.label_styles(&theme);
+
+        let bold_style = self.bold_line_style.unwrap_or(theme.bold_grid_style);
+        let light_style = self.light_line_style.unwrap_or(theme.light_grid_style);
+        let minor_style = self.minor_line_style.unwrap_or(light_style);
+        let axis_style = self.axis_style.unwrap_or(theme.axis_style);
 
         let axis_desc_style = self
             .axis_desc_style
             .clone()
             .unwrap_or_else(|| x_label_style.clone());
 
+        // The minor lines take the place of the light lines on their axis
+        let minor_divisions = |minor_lines: Option<usize>, draw_mesh: bool| match minor_lines {
+            Some(divisions) if draw_mesh => divisions,
+            _ => 0,
+        };
+        let deferred = &mut target.deferred_mesh;
+        target.drawing_area.draw_minor_mesh(
+            |b, from, to| match deferred {
+                Some(lines) => {
+                    lines.push((from, to, minor_style));
+                    Ok(())
+                }
+                None => b.draw_line(from, to, &minor_style),
+            },
+            (
+                self.n_y_labels,
+                minor_divisions(
+                    self.y_minor_lines,
+                    self.draw_y_mesh && self.y_ticks.is_none(),
+                ),
+            ),
+            (
+                self.n_x_labels,
+                minor_divisions(
+                    self.x_minor_lines,
+                    self.draw_x_mesh && self.x_ticks.is_none(),
+                ),
+            ),
+        )?;
+
         target.draw_mesh(
             (
                 LightPoints::new(self.n_y_labels, self.n_y_labels * self.y_light_lines_limit),
@@ -473,8 +959,8 @@ where
             &x_label_style,
             &y_label_style,
             |_, _, _| None,
-            self.draw_x_mesh,
-            self.draw_y_mesh,
+            self.draw_x_mesh && self.x_minor_lines.is_none() && self.x_ticks.is_none(),
+            self.draw_y_mesh && self.y_minor_lines.is_none() && self.y_ticks.is_none(),
             self.x_label_offset,
             self.y_label_offset,
             false,
@@ -485,8 +971,16 @@ where
             self.y_desc.clone(),
             self.x_tick_size,
             self.y_tick_size,
+            &[],
+            (None, None),
         )?;
 
+        let x_label_groups = if self.draw_x_axis {
+            target.drawing_area.as_coord_spec().get_x_label_groups()
+        } else {
+            vec![]
+        };
+
         target.draw_mesh(
             (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
             &bold_style,
@@ -528,6 +1022,23 @@ where
             None,
             self.x_tick_size,
             self.y_tick_size,
+            &x_label_groups,
+            (self.x_ticks, self.y_ticks),
         )
     }
 }
+
+// The pixel intervals between consecutive edges, each ending a pixel before the next one starts,
+// with the styles in turn
+fn band_rects<'s>(
+    edges: &'s [i32],
+    styles: &'s [ShapeStyle],
+) -> impl Iterator<Item = (i32, i32, ShapeStyle)> + 's {
+    edges
+        .windows(2)
+        .zip(styles.iter().cycle())
+        .map(|(pair, style)| {
+            let (start, end) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+            (start, (end - 1).max(start), *style)
+        })
+}
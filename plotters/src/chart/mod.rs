@@ -14,16 +14,27 @@ detailed description for each struct.
 
 mod axes3d;
 mod builder;
+#[cfg(feature = "colormaps")]
+mod colorbar;
 mod context;
 mod dual_coord;
+mod label_format;
 mod mesh;
+mod parallel;
+mod polar;
 mod series;
 mod state;
 
 pub use builder::{ChartBuilder, LabelAreaPosition};
+#[cfg(feature = "colormaps")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "colormaps")))]
+pub use colorbar::ColorBarStyle;
 pub use context::ChartContext;
 pub use dual_coord::{DualCoordChartContext, DualCoordChartState};
+pub use label_format::{format_si, format_thousands};
 pub use mesh::{MeshStyle, SecondaryMeshStyle};
+pub use parallel::ParallelCoordinates;
+pub use polar::PolarMeshStyle;
 pub use series::{SeriesAnno, SeriesLabelPosition, SeriesLabelStyle};
 pub use state::ChartState;
 
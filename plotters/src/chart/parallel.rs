@@ -0,0 +1,350 @@
+use std::ops::Range;
+
+use super::ChartBuilder;
+use crate::coord::ranged1d::{Ranged, ValueFormatter};
+use crate::coord::types::RangedCoordf64;
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{EmptyElement, PathElement, Text};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{IntoFont, Palette, Palette99, ShapeStyle, TextStyle, BLACK, BLUE};
+use plotters_backend::DrawingBackend;
+
+/**
+A parallel coordinates plot, which draws one vertical axis per dimension of the data and each
+record as a polyline crossing the axes at its values.
+
+Each axis has its own scale, which spans the values of its dimension by default, or the range set
+with [`ParallelCoordinates::axis_range()`]: the axes are all stretched to the height of the plot,
+and they are labeled with ticks of their own values. The records can be colored by class with
+[`ParallelCoordinates::classes()`]. A missing or NaN value breaks the polyline of the record.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("parallel_coordinates.svg", (400, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let records = vec![
+    vec![5.1, 3.5, 1.4],
+    vec![7.0, 3.2, 4.7],
+    vec![6.3, 3.3, 6.0],
+];
+ParallelCoordinates::new(records)
+    .axis_names(["length", "width", "petal"])
+    .classes([0, 1, 2])
+    .draw(&drawing_area)
+    .unwrap();
+```
+*/
+pub struct ParallelCoordinates<'a> {
+    records: Vec<Vec<f64>>,
+    ranges: Vec<Range<f64>>,
+    names: Vec<String>,
+    classes: Vec<usize>,
+    class_styles: Vec<ShapeStyle>,
+    style: ShapeStyle,
+    axis_style: ShapeStyle,
+    label_style: TextStyle<'a>,
+    ticks: usize,
+}
+
+impl<'a> ParallelCoordinates<'a> {
+    /**
+    Creates a new parallel coordinates plot.
+
+    - `records`: The records, each holding its values along the dimensions, in the order of the
+      axes
+    - **returns** The newly created plot, with one axis per dimension spanning its values
+
+    See [`ParallelCoordinates`] for more information and examples.
+    */
+    pub fn new(records: Vec<Vec<f64>>) -> Self {
+        let dimensions = records.iter().map(Vec::len).max().unwrap_or(0);
+        let ranges = (0..dimensions)
+            .map(|axis| {
+                let (min, max) = records
+                    .iter()
+                    .filter_map(|record| record.get(axis))
+                    .filter(|value| value.is_finite())
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                        (min.min(value), max.max(value))
+                    });
+                if min > max {
+                    0.0..1.0
+                } else if min == max {
+                    min - 0.5..max + 0.5
+                } else {
+                    min..max
+                }
+            })
+            .collect();
+        Self {
+            records,
+            ranges,
+            names: vec![],
+            classes: vec![],
+            class_styles: vec![],
+            style: BLUE.into(),
+            axis_style: BLACK.into(),
+            label_style: ("sans-serif", 12).into_font().into(),
+            ticks: 5,
+        }
+    }
+
+    /**
+    Sets the names of the axes, which are written under them.
+
+    See [`ParallelCoordinates`] for more information and examples.
+    */
+    pub fn axis_names<S: ToString, I: IntoIterator<Item = S>>(mut self, names: I) -> Self {
+        self.names = names.into_iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /**
+    Sets the range of an axis, instead of the range of the values of its dimension.
+
+    - `axis`: The index of the axis, which is the index of its dimension
+    - `range`: The values at the bottom and at the top of the axis
+
+    See [`ParallelCoordinates`] for more information and examples.
+    */
+    pub fn axis_range(mut self, axis: usize, range: Range<f64>) -> Self {
+        if let Some(axis_range) = self.ranges.get_mut(axis) {
+            *axis_range = range;
+        }
+        self
+    }
+
+    /**
+    Colors the records by class.
+
+    - `classes`: The class of each record, in the order of the records, which picks the style
+      of the record from [`ParallelCoordinates::class_styles()`]
+
+    See [`ParallelCoordinates`] for more information and examples.
+    */
+    pub fn classes<I: IntoIterator<Item = usize>>(mut self, classes: I) -> Self {
+        self.classes = classes.into_iter().collect();
+        self
+    }
+
+    /**
+    Sets the styles of the classes, which cycle when there are more classes. The default is the
+    colors of [`Palette99`].
+
+    See [`ParallelCoordinates`] for more information and examples.
+    */
+    pub fn class_styles<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(
+        mut self,
+        styles: I,
+    ) -> Self {
+        self.class_styles = styles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /**
+    Sets the style of the records without class. The default is blue.
+
+    See [`ParallelCoordinates`] for more information and examples.
+    */
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the axes and of their ticks.
+
+    See [`ParallelCoordinates`] for more information and examples.
+    */
+    pub fn axis_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.axis_style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the tick labels and of the axis names.
+
+    See [`ParallelCoordinates`] for more information and examples.
+    */
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /**
+    Sets the maximum number of ticks of each axis. The default is 5.
+
+    See [`ParallelCoordinates`] for more information and examples.
+    */
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    // The position of a value along its axis, from 0 at the bottom to 1 at the top
+    fn normalize(&self, axis: usize, value: f64) -> f64 {
+        let range = &self.ranges[axis];
+        (value - range.start) / (range.end - range.start)
+    }
+
+    // The polylines of a record, broken by the missing values, in the normalized coordinates
+    fn polylines(&self, record: &[f64]) -> Vec<Vec<(f64, f64)>> {
+        let mut lines = vec![vec![]];
+        for (axis, &value) in record.iter().enumerate().take(self.ranges.len()) {
+            if value.is_finite() {
+                lines
+                    .last_mut()
+                    .unwrap()
+                    .push((axis as f64, self.normalize(axis, value)));
+            } else if !lines.last().unwrap().is_empty() {
+                lines.push(vec![]);
+            }
+        }
+        lines.retain(|line| line.len() >= 2);
+        lines
+    }
+
+    fn record_style(&self, record: usize) -> ShapeStyle {
+        match self.classes.get(record) {
+            Some(&class) if self.class_styles.is_empty() => Palette99::pick(class).into(),
+            Some(&class) => self.class_styles[class % self.class_styles.len()],
+            None => self.style,
+        }
+    }
+
+    /**
+    Draws the records, then the axes with their ticks and names.
+
+    - `area`: The drawing area the plot fills, leaving room for the labels
+    - **returns** An error if the plot couldn't be drawn
+
+    See [`ParallelCoordinates`] for more information and examples.
+    */
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let last_axis = self.ranges.len().max(2) - 1;
+        let label_height = self.label_style.font.get_size() as i32;
+        let mut chart = ChartBuilder::on(area)
+            .margin_top(label_height)
+            .margin_bottom(label_height * 2 + 5)
+            .margin_left(label_height * 4)
+            .margin_right(label_height * 2)
+            .build_cartesian_2d(0.0..last_axis as f64, 0.0..1.0)?;
+
+        let lines: Vec<_> = self
+            .records
+            .iter()
+            .enumerate()
+            .flat_map(|(index, record)| {
+                let style = self.record_style(index);
+                self.polylines(record)
+                    .into_iter()
+                    .map(move |line| PathElement::new(line, style))
+            })
+            .collect();
+        chart.draw_series(lines)?;
+
+        let plot = chart.plotting_area();
+        let tick_style = self.label_style.pos(Pos::new(HPos::Right, VPos::Center));
+        let name_style = self.label_style.pos(Pos::new(HPos::Center, VPos::Top));
+        for (axis, range) in self.ranges.iter().enumerate() {
+            let x = axis as f64;
+            plot.draw(&PathElement::new(vec![(x, 0.0), (x, 1.0)], self.axis_style))?;
+            let scale = RangedCoordf64::from(range.clone());
+            let ticks = scale.key_points(self.ticks);
+            for tick in ticks {
+                let y = self.normalize(axis, tick);
+                if !(0.0..=1.0).contains(&y) {
+                    continue;
+                }
+                plot.draw(
+                    &(EmptyElement::at((x, y))
+                        + PathElement::new(vec![(0, 0), (-4, 0)], self.axis_style)
+                        + Text::new(RangedCoordf64::format(&tick), (-6, 0), tick_style.clone())),
+                )?;
+            }
+            if let Some(name) = self.names.get(axis) {
+                plot.draw(
+                    &(EmptyElement::at((x, 0.0))
+                        + Text::new(name.clone(), (0, 6), name_style.clone())),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_auto_ranges() {
+        let plot = ParallelCoordinates::new(vec![
+            vec![1.0, 10.0, 5.0],
+            vec![3.0, f64::NAN, 5.0],
+            vec![2.0, 30.0],
+        ]);
+        assert_eq!(plot.ranges, vec![1.0..3.0, 10.0..30.0, 4.5..5.5]);
+
+        // Each axis is stretched to the full height
+        assert_eq!(
+            plot.polylines(&[2.0, 20.0, 5.0]),
+            vec![vec![(0.0, 0.5), (1.0, 0.5), (2.0, 0.5)]]
+        );
+        // The missing values break the polylines, the lone points are left out
+        assert!(plot.polylines(&[1.0, f64::NAN, 5.0, 5.0]).is_empty());
+        let plot = plot.axis_range(0, 0.0..4.0).axis_range(9, 0.0..1.0);
+        assert_eq!(plot.ranges[0], 0.0..4.0);
+        assert_eq!(
+            plot.polylines(&[1.0, 30.0]),
+            vec![vec![(0.0, 0.25), (1.0, 1.0)]]
+        );
+    }
+
+    #[test]
+    fn test_record_styles() {
+        let plot = ParallelCoordinates::new(vec![vec![0.0, 1.0]; 4])
+            .classes([1, 0, 3])
+            .class_styles([RED, GREEN]);
+        let colors: Vec<_> = (0..4).map(|r| plot.record_style(r).color).collect();
+        assert_eq!(
+            colors,
+            vec![
+                GREEN.to_rgba(),
+                RED.to_rgba(),
+                GREEN.to_rgba(),
+                BLUE.to_rgba()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_draw_parallel_coordinates() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.check_draw_path(|c, _, path| {
+                if c == BLACK.to_rgba() {
+                    return;
+                }
+                // The records cross the three axes
+                assert_eq!(path.len(), 3);
+                assert!(c == RED.to_rgba() || c == BLUE.to_rgba());
+            });
+            m.drop_check(|b| {
+                assert!(b.num_draw_text_call >= 3 + 6);
+            });
+        });
+        ParallelCoordinates::new(vec![vec![0.0, 5.0, 100.0], vec![1.0, 10.0, 200.0]])
+            .axis_names(["a", "b", "c"])
+            .classes([0, 1])
+            .class_styles([RED, BLUE])
+            .draw(&drawing_area)
+            .expect("Drawing error");
+    }
+}
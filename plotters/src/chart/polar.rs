@@ -0,0 +1,232 @@
+use std::marker::PhantomData;
+
+use super::ChartContext;
+use crate::coord::polar::{AngleUnit, Polar};
+use crate::coord::ranged1d::{BoldPoints, Ranged, ValueFormatter};
+use crate::element::{Circle, PathElement};
+use crate::style::colors::BLACK;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::Color;
+use crate::style::{AsRelative, ShapeStyle, SizeDesc, TextStyle};
+
+use crate::drawing::DrawingAreaErrorKind;
+
+use plotters_backend::DrawingBackend;
+
+/**
+Implements polar chart mesh configurations: the concentric circles of the radius, the radial
+lines of the angle, and their labels.
+
+The best way to use this struct is by way of the [`ChartContext::configure_mesh()`] function
+of a chart built with [`crate::chart::ChartBuilder::build_polar()`].
+See [`Polar`] for more information and examples.
+*/
+pub struct PolarMeshStyle<'a, 'b, R: Ranged, DB: DrawingBackend> {
+    pub(super) parent_size: (u32, u32),
+    pub(super) target: Option<&'b mut ChartContext<'a, DB, Polar<R>>>,
+    pub(super) n_angle_lines: usize,
+    pub(super) n_radius_labels: usize,
+    pub(super) label_offset: i32,
+    pub(super) grid_style: ShapeStyle,
+    pub(super) axis_style: ShapeStyle,
+    pub(super) label_style: TextStyle<'b>,
+    pub(super) format_angle: Option<&'b dyn Fn(&f64) -> String>,
+    pub(super) format_radius: &'b dyn Fn(&R::ValueType) -> String,
+    _phantom: PhantomData<&'a R>,
+}
+
+impl<'a, 'b, R, RT, DB> PolarMeshStyle<'a, 'b, R, DB>
+where
+    R: Ranged<ValueType = RT> + ValueFormatter<RT>,
+    DB: DrawingBackend,
+{
+    /**
+    Set the number of radial lines, evenly spread over a full turn.
+
+    - `n`: The number of radial lines, each of them with an angle label
+
+    See [`Polar`] for more information and examples.
+    */
+    pub fn angle_lines(&mut self, n: usize) -> &mut Self {
+        self.n_angle_lines = n;
+        self
+    }
+
+    /**
+    Set the maximum number of concentric circles, each of them with a radius label.
+
+    See [`Polar`] for more information and examples.
+    */
+    pub fn radius_labels(&mut self, n: usize) -> &mut Self {
+        self.n_radius_labels = n;
+        self
+    }
+
+    /**
+    Set the distance between the border of the disc and the angle labels.
+
+    - `value`: Desired distance, in pixels.
+
+    See [`Polar`] for more information and examples.
+    */
+    pub fn label_offset<Size: SizeDesc>(&mut self, value: Size) -> &mut Self {
+        self.label_offset = value.in_pixels(&self.parent_size);
+        self
+    }
+
+    /**
+    Sets the style of the concentric circles and the radial lines.
+
+    See [`Polar`] for more information and examples.
+    */
+    pub fn grid_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.grid_style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the outer circle.
+
+    See [`Polar`] for more information and examples.
+    */
+    pub fn axis_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
+        self.axis_style = style.into();
+        self
+    }
+
+    /**
+    Sets the text style of the labels.
+
+    See [`Polar`] for more information and examples.
+    */
+    pub fn label_style<S: Into<TextStyle<'b>>>(&mut self, style: S) -> &mut Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /**
+    Specifies the string format of the angle labels. By default, degrees are rounded to the
+    unit and radians are displayed with two decimals.
+
+    See [`Polar`] for more information and examples.
+    */
+    pub fn angle_formatter<F: Fn(&f64) -> String>(&mut self, f: &'b F) -> &mut Self {
+        self.format_angle = Some(f);
+        self
+    }
+
+    /**
+    Specifies the string format of the radius labels.
+
+    See [`Polar`] for more information and examples.
+    */
+    pub fn radius_formatter<F: Fn(&R::ValueType) -> String>(&mut self, f: &'b F) -> &mut Self {
+        self.format_radius = f;
+        self
+    }
+
+    /**
+    Constructs a new configuration object and defines the defaults.
+
+    This is used internally by Plotters and should probably not be included in user code.
+    See [`Polar`] for more information and examples.
+    */
+    pub(crate) fn new(chart: &'b mut ChartContext<'a, DB, Polar<R>>) -> Self {
+        let parent_size = chart.drawing_area.dim_in_pixel();
+        let label_size = (12).percent().max(12).in_pixels(&parent_size);
+        // The defaults come from the theme, if any
+        let (grid_style, axis_style, label_style) = match chart.drawing_area.theme() {
+            Some(theme) => (
+                theme.bold_grid_style,
+                theme.axis_style,
+                theme.text_style(f64::from(label_size)),
+            ),
+            None => (
+                Into::<ShapeStyle>::into(BLACK.mix(0.2)),
+                Into::<ShapeStyle>::into(BLACK.mix(0.8)),
+                ("sans-serif", label_size).into(),
+            ),
+        };
+        Self {
+            parent_size,
+            n_angle_lines: 12,
+            n_radius_labels: 5,
+            label_offset: 5,
+            grid_style,
+            axis_style,
+            label_style,
+            format_angle: None,
+            format_radius: &R::format,
+            _phantom: PhantomData,
+            target: Some(chart),
+        }
+    }
+
+    /// Draw the configured mesh on the target plot
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let chart = self.target.take().unwrap();
+        let area = chart.drawing_area.strip_coord_spec();
+        let coord = chart.drawing_area.as_coord_spec();
+
+        let base = area.get_base_pixel();
+        let (cx, cy) = coord.center();
+        let center = (cx - base.0, cy - base.1);
+        let radius = coord.pixel_radius();
+
+        for value in coord
+            .radius_spec()
+            .key_points(BoldPoints(self.n_radius_labels))
+        {
+            let r = coord.radius_spec().map(&value, (0, radius));
+            if r <= 0 || r >= radius {
+                continue;
+            }
+            area.draw(&Circle::new(center, r as u32, self.grid_style))?;
+
+            let (x, y) = coord.project(0.0, f64::from(r));
+            let style = self.label_style.pos(Pos::new(HPos::Left, VPos::Bottom));
+            let text = (self.format_radius)(&value);
+            area.draw_text(&text, &style, (x - base.0 + 3, y - base.1 - 3))?;
+        }
+
+        area.draw(&Circle::new(center, radius as u32, self.axis_style))?;
+
+        let unit = coord.angle_unit();
+        let default_format = |angle: &f64| match unit {
+            AngleUnit::Degrees => format!("{:.0}°", angle),
+            AngleUnit::Radians => format!("{:.2}", angle),
+        };
+        let format_angle = self.format_angle.unwrap_or(&default_format);
+
+        for i in 0..self.n_angle_lines {
+            let angle = unit.full_turn() * i as f64 / self.n_angle_lines as f64;
+            let (x, y) = coord.project(angle, f64::from(radius));
+            area.draw(&PathElement::new(
+                vec![center, (x - base.0, y - base.1)],
+                self.grid_style,
+            ))?;
+
+            let distance = f64::from(radius + self.label_offset);
+            let (x, y) = coord.project(angle, distance);
+            let (dx, dy) = (f64::from(x - cx) / distance, f64::from(cy - y) / distance);
+            let h_pos = if dx > 0.1 {
+                HPos::Left
+            } else if dx < -0.1 {
+                HPos::Right
+            } else {
+                HPos::Center
+            };
+            let v_pos = if dy > 0.1 {
+                VPos::Bottom
+            } else if dy < -0.1 {
+                VPos::Top
+            } else {
+                VPos::Center
+            };
+            let style = self.label_style.pos(Pos::new(h_pos, v_pos));
+            area.draw_text(&format_angle(&angle), &style, (x - base.0, y - base.1))?;
+        }
+
+        Ok(())
+    }
+}
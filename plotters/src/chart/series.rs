@@ -1,6 +1,6 @@
 use super::ChartContext;
-use crate::coord::CoordTranslate;
-use crate::drawing::DrawingAreaErrorKind;
+use crate::coord::{CoordTranslate, Shift};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
 use crate::element::{DynElement, EmptyElement, IntoDynElement, MultiLineText, Rectangle};
 use crate::style::{IntoFont, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT};
 
@@ -125,6 +125,8 @@ pub struct SeriesLabelStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
     background: ShapeStyle,
     label_font: Option<TextStyle<'b>>,
     margin: u32,
+    dedup: bool,
+    in_legend_area: bool,
 }
 
 impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, 'b, DB, CT> {
@@ -137,6 +139,8 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             background: (&TRANSPARENT).into(),
             label_font: None,
             margin: 10,
+            dedup: false,
+            in_legend_area: false,
         }
     }
 
@@ -152,6 +156,21 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
+    /**
+    Draws the series labels in the legend area reserved to the right of the chart with
+    [`crate::chart::ChartBuilder::right_legend_area_size()`], so that they don't cover the
+    plotted data. The position is then relative to the legend area, and the labels which
+    don't fit into its height wrap into more columns.
+
+    When no legend area is reserved, the labels are drawn on the plotting area as usual.
+
+    See [`ChartContext::configure_series_labels()`] for more information and examples.
+    */
+    pub fn in_legend_area(&mut self) -> &mut Self {
+        self.in_legend_area = true;
+        self
+    }
+
     /**
     Sets the margin of the series label drawing area.
 
@@ -217,18 +236,49 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
+    /**
+    Merges the series sharing the same label into a single legend entry, which keeps the legend
+    element of the first of them. The labels must be exactly equal to be merged.
+
+    This is useful when the same kinds of series are drawn several times, e.g. once per facet.
+
+    See [`ChartContext::configure_series_labels()`] for more information and examples.
+    */
+    pub fn dedup_labels(&mut self) -> &mut Self {
+        self.dedup = true;
+        self
+    }
+
     /**
     Draws the series label area.
 
     See [`ChartContext::configure_series_labels()`] for more information and examples.
     */
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
-        let drawing_area = self.target.plotting_area().strip_coord_spec();
+        // The legend goes over the grid lines drawn on top of the series
+        self.target.draw_deferred_mesh()?;
 
-        // TODO: Issue #68 Currently generic font family doesn't load on OSX, change this after the issue
-        // resolved
-        let default_font = ("sans-serif", 12).into_font();
-        let default_style: TextStyle = default_font.into();
+        let (drawing_area, wrap) = match &self.target.legend_area {
+            Some(legend_area) if self.in_legend_area => (legend_area.clone(), true),
+            _ => (self.target.plotting_area().strip_coord_spec(), false),
+        };
+
+        drawing_area.push_element_class("plotters-legend");
+        let result = self.draw_on(&drawing_area, wrap);
+        drawing_area.pop_element_class();
+        result
+    }
+
+    fn draw_on(
+        &mut self,
+        drawing_area: &DrawingArea<DB, Shift>,
+        wrap: bool,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        // The default font comes from the theme, if any
+        let default_style = match drawing_area.theme() {
+            Some(theme) => theme.text_style(12.0),
+            None => ("sans-serif", 12).into_font().into(),
+        };
 
         let font = {
             let mut temp = None;
@@ -236,8 +286,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             temp.unwrap_or(default_style)
         };
 
-        let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
-        let mut funcs = vec![];
+        let mut entries = vec![];
 
         for anno in self.target.series_anno.iter() {
             let label_text = anno.get_label();
@@ -247,28 +296,53 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
                 continue;
             }
 
-            funcs.push(draw_func.unwrap_or(&|p: BackendCoord| EmptyElement::at(p).into_dyn()));
-            label_element.push_line(label_text);
-        }
+            if self.dedup
+                && !label_text.is_empty()
+                && entries.iter().any(|(text, _)| *text == label_text)
+            {
+                continue;
+            }
 
-        let (mut w, mut h) = label_element.estimate_dimension().map_err(|e| {
-            DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
-        })?;
+            entries.push((
+                label_text,
+                draw_func.unwrap_or(&|p: BackendCoord| EmptyElement::at(p).into_dyn()),
+            ));
+        }
 
         let margin = self.margin as i32;
+        let (area_w, area_h) = drawing_area.dim_in_pixel();
 
-        w += self.legend_area_size as i32 + margin * 2;
-        h += margin * 2;
+        // In the legend area, the labels which don't fit into its height wrap into more columns
+        let rows = if wrap {
+            let line_height = font.font.get_size() * 1.25;
+            let max_rows =
+                (f64::from(area_h as i32 - margin * 2 - 10) / line_height).floor() as usize;
+            max_rows.max(1).min(entries.len().max(1))
+        } else {
+            entries.len().max(1)
+        };
 
-        let (area_w, area_h) = drawing_area.dim_in_pixel();
+        let mut columns = vec![];
+        let (mut w, mut h) = (margin, 0);
+        for column in entries.chunks(rows) {
+            let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
+            for (label_text, _) in column {
+                label_element.push_line(*label_text);
+            }
+            let (column_w, column_h) = label_element.estimate_dimension().map_err(|e| {
+                DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
+            })?;
+            w += self.legend_area_size as i32 + column_w + margin;
+            h = h.max(column_h);
+            columns.push((label_element, column_w, column));
+        }
+        if columns.is_empty() {
+            w += self.legend_area_size as i32 + margin;
+        }
+        h += margin * 2;
 
         let (label_x, label_y) = self.position.layout_label_area((w, h), (area_w, area_h));
 
-        label_element.relocate((
-            label_x + self.legend_area_size as i32 + margin,
-            label_y + margin,
-        ));
-
         drawing_area.draw(&Rectangle::new(
             [(label_x, label_y), (label_x + w, label_y + h)],
             self.background.filled(),
@@ -277,18 +351,25 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             [(label_x, label_y), (label_x + w, label_y + h)],
             self.border_style,
         ))?;
-        drawing_area.draw(&label_element)?;
 
-        for (((_, y0), (_, y1)), make_elem) in label_element
-            .compute_line_layout()
-            .map_err(|e| {
-                DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
-            })?
-            .into_iter()
-            .zip(funcs.into_iter())
-        {
-            let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
-            drawing_area.draw(&legend_element)?;
+        let mut column_x = label_x + margin;
+        for (mut label_element, column_w, column) in columns {
+            label_element.relocate((column_x + self.legend_area_size as i32, label_y + margin));
+            drawing_area.draw(&label_element)?;
+
+            for (((_, y0), (_, y1)), (_, make_elem)) in label_element
+                .compute_line_layout()
+                .map_err(|e| {
+                    DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(Box::new(e)))
+                })?
+                .into_iter()
+                .zip(column.iter())
+            {
+                let legend_element = make_elem((column_x, (y0 + y1) / 2));
+                drawing_area.draw(&legend_element)?;
+            }
+
+            column_x += self.legend_area_size as i32 + column_w + margin;
         }
 
         Ok(())
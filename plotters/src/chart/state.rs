@@ -35,14 +35,30 @@ use plotters_backend::DrawingBackend;
 pub struct ChartState<CT: CoordTranslate> {
     drawing_area_pos: (i32, i32),
     drawing_area_size: (u32, u32),
+    legend_area: Option<((i32, i32), (u32, u32))>,
+    clip_series: bool,
     coord: CT,
 }
 
+// The position and the size of the legend area, relative to the area the chart is built on
+fn legend_area_state<DB: DrawingBackend, CT: CoordTranslate>(
+    chart: &ChartContext<DB, CT>,
+) -> Option<((i32, i32), (u32, u32))> {
+    let (x0, y0) = chart.drawing_area.get_base_pixel();
+    let (dx, dy) = chart.drawing_area_pos;
+    chart.legend_area.as_ref().map(|area| {
+        let (x, y) = area.get_base_pixel();
+        ((x - x0 + dx, y - y0 + dy), area.dim_in_pixel())
+    })
+}
+
 impl<'a, DB: DrawingBackend, CT: CoordTranslate> From<ChartContext<'a, DB, CT>> for ChartState<CT> {
     fn from(chart: ChartContext<'a, DB, CT>) -> ChartState<CT> {
         ChartState {
             drawing_area_pos: chart.drawing_area_pos,
             drawing_area_size: chart.drawing_area.dim_in_pixel(),
+            legend_area: legend_area_state(&chart),
+            clip_series: chart.clip_series,
             coord: chart.drawing_area.into_coord_spec(),
         }
     }
@@ -63,6 +79,8 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         ChartState {
             drawing_area_pos: self.drawing_area_pos,
             drawing_area_size: self.drawing_area.dim_in_pixel(),
+            legend_area: legend_area_state(&self),
+            clip_series: self.clip_series,
             coord: Arc::new(self.drawing_area.into_coord_spec()),
         }
     }
@@ -77,6 +95,8 @@ where
         ChartState {
             drawing_area_pos: chart.drawing_area_pos,
             drawing_area_size: chart.drawing_area.dim_in_pixel(),
+            legend_area: legend_area_state(chart),
+            clip_series: chart.clip_series,
             coord: chart.drawing_area.as_coord_spec().clone(),
         }
     }
@@ -98,6 +118,9 @@ impl<CT: CoordTranslate> ChartState<CT> {
         self,
         area: &DrawingArea<DB, Shift>,
     ) -> ChartContext<'a, DB, CT> {
+        let legend_area = self
+            .legend_area
+            .map(|(pos, size)| area.clone().shrink(pos, size));
         let area = area
             .clone()
             .shrink(self.drawing_area_pos, self.drawing_area_size);
@@ -106,7 +129,12 @@ impl<CT: CoordTranslate> ChartState<CT> {
             y_label_area: [None, None],
             drawing_area: area.apply_coord_spec(self.coord),
             series_anno: vec![],
+            legend_area,
             drawing_area_pos: self.drawing_area_pos,
+            clip_series: self.clip_series,
+            deferred_mesh: None,
+            palette: None,
+            color_idx: 0,
         }
     }
 }
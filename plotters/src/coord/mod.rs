@@ -21,6 +21,7 @@ See documentation for module [combinators](combinators/index.html)  for details.
 Currently we support the following 2D coordinate system:
 
 - 2-dimensional Cartesian Coordinate: This is done by the combinator [Cartesian2d](cartesian/struct.Cartesian2d.html).
+- Polar Coordinate: This is done by the combinator [Polar](polar/struct.Polar.html).
 
 */
 
@@ -52,6 +53,11 @@ pub mod cartesian {
     pub use super::ranged3d::Cartesian3d;
 }
 
+/// The polar coordinate system, used by radar and rose charts.
+pub mod polar {
+    pub use super::ranged2d::polar::{AngleUnit, Polar, ZeroAngle};
+}
+
 mod translate;
 pub use translate::{CoordTranslate, ReverseCoordTranslate};
 
@@ -0,0 +1,240 @@
+use crate::coord::ranged1d::{
+    AsRangedCoord, BoldPoints, KeyPointHint, LightPoints, NoDefaultFormatting, Ranged,
+    ValueFormatter,
+};
+use num_traits::ToPrimitive;
+use std::ops::Range;
+
+/// The coordinate decorator which breaks an axis into several sub-ranges, leaving out the values
+/// between them. This saves the space of a large gap in the data, e.g. values near 0 and near
+/// 10000 with nothing between.
+///
+/// Each sub-range is mapped to a band of pixels proportional to its span, and the bands are
+/// separated by gaps, whose size is a fraction of the whole axis. The axis line is interrupted
+/// in the gaps, which get a zig-zag break mark instead, and the key points are only taken
+/// within the sub-ranges, thus no tick mark nor grid line falls into a gap.
+///
+/// ```
+/// use plotters::prelude::*;
+/// let drawing_area = SVGBackend::new("broken_axis.svg", (300, 200)).into_drawing_area();
+/// drawing_area.fill(&WHITE).unwrap();
+/// let mut chart = ChartBuilder::on(&drawing_area)
+///     .set_left_and_bottom_label_area_size(40)
+///     .build_cartesian_2d(0.0..10.0, BrokenAxis::new(vec![0.0..50.0, 9950.0..10000.0], 0.05))
+///     .unwrap();
+/// chart.configure_mesh().draw().unwrap();
+/// ```
+///
+/// The breaks have a few limitations:
+/// - The sub-ranges must be increasing and must not overlap, and their values must convert to
+///   `f64`, which measures their spans
+/// - The values falling into a gap are mapped linearly across it, thus a line between two
+///   sub-ranges crosses the gap rather than being interrupted
+/// - The number of key points requested by the mesh is shared between the sub-ranges, in
+///   proportion of their bands, and each sub-range has at least one, thus a narrow sub-range
+///   may get a single tick mark
+/// - Only the axis lines of the cartesian charts are broken, and wrapping the axis into another
+///   decorator (other than custom key points) loses the break marks
+#[derive(Clone)]
+pub struct BrokenAxis<R: Ranged> {
+    segments: Vec<R>,
+    // The span of each sub-range, relative to the total span of the sub-ranges
+    weights: Vec<f64>,
+    gap: f64,
+}
+
+impl<R: Ranged> BrokenAxis<R>
+where
+    R::ValueType: ToPrimitive,
+{
+    /// Create a new broken axis. This function panics when no sub-range is given.
+    ///
+    /// - `ranges`: The sub-ranges of the axis, in increasing order
+    /// - `gap`: The size of each gap between two sub-ranges, as a fraction of the whole axis
+    /// - **returns**: The broken axis coordinate spec
+    pub fn new<A, I>(ranges: I, gap: f64) -> Self
+    where
+        A: AsRangedCoord<CoordDescType = R>,
+        I: IntoIterator<Item = A>,
+        R: From<A>,
+    {
+        let segments: Vec<R> = ranges.into_iter().map(R::from).collect();
+        assert!(!segments.is_empty(), "A broken axis needs a sub-range");
+
+        let spans: Vec<f64> = segments
+            .iter()
+            .map(|segment| {
+                let (low, high) = bounds(segment);
+                high - low
+            })
+            .collect();
+        let total: f64 = spans.iter().sum();
+        let weights = if total.is_finite() && total > 0.0 {
+            spans.iter().map(|span| span / total).collect()
+        } else {
+            vec![1.0 / segments.len() as f64; segments.len()]
+        };
+
+        let max_gap = 0.5 / (segments.len() - 1).max(1) as f64;
+        Self {
+            segments,
+            weights,
+            gap: if gap.is_nan() {
+                0.0
+            } else {
+                gap.clamp(0.0, max_gap)
+            },
+        }
+    }
+
+    // The pixel band of each sub-range
+    fn bands(&self, limit: (i32, i32)) -> Vec<(i32, i32)> {
+        let (from, len) = (f64::from(limit.0), f64::from(limit.1 - limit.0));
+        let usable = 1.0 - self.gap * (self.segments.len() - 1) as f64;
+        let mut pos = 0.0;
+        self.weights
+            .iter()
+            .map(|weight| {
+                let start = pos;
+                pos += weight * usable;
+                let band = (from + start * len, from + pos * len);
+                pos += self.gap;
+                (band.0.round() as i32, band.1.round() as i32)
+            })
+            .collect()
+    }
+
+    // The sub-range holding the value, or the closest one
+    fn segment_of(&self, value: f64) -> usize {
+        self.segments
+            .iter()
+            .position(|segment| value <= bounds(segment).1)
+            .unwrap_or(self.segments.len() - 1)
+    }
+}
+
+// The lowest and the highest values of a sub-range
+fn bounds<R: Ranged>(segment: &R) -> (f64, f64)
+where
+    R::ValueType: ToPrimitive,
+{
+    let range = segment.range();
+    let start = range.start.to_f64().unwrap_or(f64::NAN);
+    let end = range.end.to_f64().unwrap_or(f64::NAN);
+    (start.min(end), start.max(end))
+}
+
+impl<R: Ranged> Ranged for BrokenAxis<R>
+where
+    R::ValueType: ToPrimitive,
+{
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        let bands = self.bands(limit);
+        let v = value.to_f64().unwrap_or(f64::NAN);
+        let idx = self.segment_of(v);
+        let low = bounds(&self.segments[idx]).0;
+        if idx > 0 && v < low {
+            // The value is in the gap before this sub-range
+            let prev_high = bounds(&self.segments[idx - 1]).1;
+            let (gap_start, gap_end) = (bands[idx - 1].1, bands[idx].0);
+            let t = (v - prev_high) / (low - prev_high);
+            return (f64::from(gap_start) + t * f64::from(gap_end - gap_start)).round() as i32;
+        }
+        self.segments[idx].map(value, bands[idx])
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let share = |count: usize, weight: f64| ((count as f64 * weight).round() as usize).max(1);
+        self.segments
+            .iter()
+            .zip(self.weights.iter())
+            .flat_map(|(segment, weight)| {
+                let points = if hint.weight().allow_light_points() {
+                    segment.key_points(LightPoints::new(
+                        share(hint.bold_points(), *weight),
+                        share(hint.max_num_points(), *weight),
+                    ))
+                } else {
+                    segment.key_points(BoldPoints(share(hint.max_num_points(), *weight)))
+                };
+                let (low, high) = bounds(segment);
+                points.into_iter().filter(move |point| {
+                    let v = point.to_f64().unwrap_or(f64::NAN);
+                    v >= low && v <= high
+                })
+            })
+            .collect()
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        let first = self.segments[0].range();
+        let last = self.segments[self.segments.len() - 1].range();
+        first.start..last.end
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<Range<i32>> {
+        self.bands(limit)
+            .windows(2)
+            .map(|pair| {
+                let (a, b) = (pair[0].1, pair[1].0);
+                a.min(b)..a.max(b)
+            })
+            .collect()
+    }
+}
+
+impl<T, R> ValueFormatter<T> for BrokenAxis<R>
+where
+    R: Ranged<ValueType = T> + ValueFormatter<T>,
+    T: ToPrimitive,
+{
+    fn format(value: &T) -> String {
+        R::format(value)
+    }
+
+    fn format_ext(&self, value: &T) -> String {
+        let idx = self.segment_of(value.to_f64().unwrap_or(f64::NAN));
+        self.segments[idx].format_ext(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_broken_axis_mapping() {
+        // The spans are 100 and 50, the gap is 10% of the axis
+        let axis = BrokenAxis::new(vec![0.0..100.0, 1000.0..1050.0], 0.1);
+        assert_eq!(axis.range(), 0.0..1050.0);
+        assert_eq!(axis.map(&0.0, (0, 1000)), 0);
+        assert_eq!(axis.map(&100.0, (0, 1000)), 600);
+        assert_eq!(axis.map(&1000.0, (0, 1000)), 700);
+        assert_eq!(axis.map(&1050.0, (0, 1000)), 1000);
+        // The values in the gap are spread across it
+        assert_eq!(axis.map(&550.0, (0, 1000)), 650);
+        assert_eq!(axis.axis_breaks((0, 1000)), vec![600..700]);
+        // The vertical axes are mapped from the bottom
+        assert_eq!(axis.map(&0.0, (1000, 0)), 1000);
+        assert_eq!(axis.map(&1050.0, (1000, 0)), 0);
+        assert_eq!(axis.axis_breaks((1000, 0)), vec![300..400]);
+    }
+
+    #[test]
+    fn test_broken_axis_key_points() {
+        let axis = BrokenAxis::new(vec![0..10, 100..110, 200..210], 0.05);
+        let points = axis.key_points(BoldPoints(9));
+        assert!(!points.is_empty());
+        assert!(points
+            .iter()
+            .all(|p| (0..=10).contains(p) || (100..=110).contains(p) || (200..=210).contains(p)));
+        // Each sub-range gets some of the points
+        for low in [0, 100, 200] {
+            assert!(points.iter().any(|p| *p >= low && *p <= low + 10));
+        }
+        assert_eq!(axis.axis_breaks((0, 100)).len(), 2);
+    }
+}
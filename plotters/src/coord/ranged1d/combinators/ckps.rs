@@ -72,9 +72,25 @@ where
         }
     }
 
+    fn minor_grid_pixels(
+        &self,
+        _bold_points: usize,
+        divisions: usize,
+        limit: (i32, i32),
+    ) -> Vec<i32> {
+        // The inner range knows where its minor lines go, e.g. at each integer multiple of the
+        // powers of a log axis, as long as it's asked for as many bold points as there are here
+        self.inner
+            .minor_grid_pixels(self.bold_points.len(), divisions, limit)
+    }
+
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         self.inner.axis_pixel_range(limit)
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<Range<i32>> {
+        self.inner.axis_breaks(limit)
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for WithKeyPoints<R>
@@ -197,9 +213,22 @@ impl<R: Ranged> Ranged for WithKeyPointMethod<R> {
         }
     }
 
+    fn minor_grid_pixels(
+        &self,
+        bold_points: usize,
+        divisions: usize,
+        limit: (i32, i32),
+    ) -> Vec<i32> {
+        self.inner.minor_grid_pixels(bold_points, divisions, limit)
+    }
+
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         self.inner.axis_pixel_range(limit)
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<Range<i32>> {
+        self.inner.axis_breaks(limit)
+    }
 }
 
 impl<R: DiscreteRanged> DiscreteRanged for WithKeyPointMethod<R> {
@@ -224,7 +253,10 @@ mod test {
         assert_eq!(range.map(&3, (0, 1000)), 30);
         assert_eq!(range.range(), 0..100);
         assert_eq!(range.key_points(BoldPoints(100)), vec![1, 2, 3]);
-        assert_eq!(range.key_points(LightPoints::new(100, 100)), vec![]);
+        assert_eq!(
+            range.key_points(LightPoints::new(100, 100)),
+            Vec::<i32>::new()
+        );
         let range = range.with_light_points(5..10);
         assert_eq!(range.key_points(BoldPoints(10)), vec![1, 2, 3]);
         assert_eq!(
@@ -246,13 +278,34 @@ mod test {
         assert_eq!(range.bold_points_mut().len(), 3);
     }
 
+    #[test]
+    fn test_minor_grid_pixels_of_inner_range() {
+        use crate::coord::combinators::{IntoLogRange, LogCoord};
+
+        // The minor lines of a log axis stay at each multiple of the powers of ten
+        let log: LogCoord<f64> = (1.0..1000.0).log_scale().into();
+        let expected = log.minor_grid_pixels(10, 5, (0, 300));
+        assert!(!expected.is_empty());
+        let range = (1.0..1000.0)
+            .log_scale()
+            .with_key_points(vec![1.0, 10.0, 100.0, 1000.0]);
+        assert_eq!(range.minor_grid_pixels(10, 5, (0, 300)), expected);
+        let range = (1.0..1000.0)
+            .log_scale()
+            .with_key_point_func(|_| vec![1.0, 1000.0]);
+        assert_eq!(range.minor_grid_pixels(10, 5, (0, 300)), expected);
+    }
+
     #[test]
     fn test_with_key_point_method() {
         let range = (0..100).with_key_point_func(|_| vec![1, 2, 3]);
         assert_eq!(range.map(&3, (0, 1000)), 30);
         assert_eq!(range.range(), 0..100);
         assert_eq!(range.key_points(BoldPoints(100)), vec![1, 2, 3]);
-        assert_eq!(range.key_points(LightPoints::new(100, 100)), vec![]);
+        assert_eq!(
+            range.key_points(LightPoints::new(100, 100)),
+            Vec::<i32>::new()
+        );
         let range = range.with_light_point_func(|_| (5..10).collect());
         assert_eq!(range.key_points(BoldPoints(10)), vec![1, 2, 3]);
         assert_eq!(
@@ -240,7 +240,10 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
         }
 
         let mut ret = vec![];
-        let mut val = (base).powf((start.ln() / base_ln).ceil());
+        // The powers are computed from their exponent, which is nudged to absorb the error of
+        // the logarithm, e.g. ln(0.01) / ln(10) isn't exactly -2
+        let mut exponent = (start.ln() / base_ln - 1e-9).ceil();
+        let mut val = base.powf(exponent);
 
         while val <= end {
             if !self.is_inf(val) {
@@ -257,12 +260,39 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
                     ret.push(self.f64_to_value(v));
                 }
             }
-            val *= multiplier;
+            exponent += cnt as f64;
+            val = base.powf(exponent);
         }
 
         ret
     }
 
+    fn minor_grid_pixels(&self, _: usize, divisions: usize, limit: (i32, i32)) -> Vec<i32> {
+        // The minor lines of a log axis are always at 2, 3, ..., base - 1 times each power
+        if divisions < 2 || self.base.fract() != 0.0 {
+            return vec![];
+        }
+
+        let Range { mut start, mut end } = self.normalized;
+        if start > end {
+            std::mem::swap(&mut start, &mut end);
+        }
+
+        let base_ln = self.base.ln();
+        let mut power = self.base.powf((start.ln() / base_ln).floor());
+        let mut ret = vec![];
+        while power <= end {
+            for k in 2..self.base as u32 {
+                let v = power * f64::from(k);
+                if v >= start && v <= end && !self.is_inf(v) {
+                    ret.push(self.map(&self.f64_to_value(v), limit));
+                }
+            }
+            power *= self.base;
+        }
+        ret
+    }
+
     fn range(&self) -> Range<V> {
         self.logic.clone()
     }
@@ -296,4 +326,25 @@ mod test {
 
         range.key_points(100);
     }
+
+    #[test]
+    fn test_log_minor_grid_pixels() {
+        let range: LogCoord<f64> = (1.0..1000.0).log_scale().into();
+        let pixels = range.minor_grid_pixels(10, 5, (0, 300));
+        // 2, 3, ..., 9 times 1, 10 and 100
+        assert_eq!(pixels.len(), 24);
+        assert_eq!(pixels[0], range.map(&2.0, (0, 300)));
+        assert_eq!(pixels[8], range.map(&20.0, (0, 300)));
+        assert_eq!(pixels[23], range.map(&900.0, (0, 300)));
+        assert!(range.minor_grid_pixels(10, 0, (0, 300)).is_empty());
+    }
+
+    #[test]
+    fn test_log_key_points_at_exact_powers() {
+        let range: LogCoord<f64> = (0.01..10000.0).log_scale().into();
+        assert_eq!(
+            range.key_points(7),
+            vec![0.01, 0.1, 1.0, 10.0, 100.0, 1000.0, 10000.0]
+        );
+    }
 }
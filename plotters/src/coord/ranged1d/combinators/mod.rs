@@ -1,3 +1,6 @@
+mod broken;
+pub use broken::BrokenAxis;
+
 mod ckps;
 pub use ckps::{BindKeyPointMethod, BindKeyPoints, WithKeyPointMethod, WithKeyPoints};
 
@@ -13,6 +16,9 @@ pub use logarithmic::{IntoLogRange, LogCoord, LogScalable};
 #[allow(deprecated)]
 pub use logarithmic::LogRange;
 
+mod nice;
+pub use nice::IntoNiceRange;
+
 mod nested;
 pub use nested::{BuildNestedCoord, NestedRange, NestedValue};
 
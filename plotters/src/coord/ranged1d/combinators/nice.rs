@@ -0,0 +1,199 @@
+use std::ops::Range;
+
+use super::{BindKeyPoints, WithKeyPoints};
+use crate::coord::ranged1d::AsRangedCoord;
+
+// The `k`-th multiple of a step, computed as a division for the steps below 1, so that
+// 3 steps of 0.1 is 0.3 rather than 0.30000000000000004
+fn multiple_of(k: f64, step: f64) -> f64 {
+    if step < 1.0 {
+        k / (1.0 / step).round()
+    } else {
+        k * step
+    }
+}
+
+// Find the smallest 1/2/5 x 10^k step which splits the range, expanded outward to multiples
+// of the step, into at most `max_steps` steps. Returns the expanded bounds, the step and the
+// step of the light key points, or `None` if a bound isn't finite.
+fn nice_bounds(range: (f64, f64), max_steps: usize, min_step: f64) -> Option<(f64, f64, f64, f64)> {
+    if !range.0.is_finite() || !range.1.is_finite() || !(range.1 - range.0).is_finite() {
+        return None;
+    }
+    let (mut low, mut high) = (range.0.min(range.1), range.0.max(range.1));
+    if high <= low {
+        // A single value still gets an axis around it, as wide as its order of magnitude
+        let width = 10f64.powf(low.abs().max(1.0).log10().floor());
+        low -= width / 2.0;
+        high += width / 2.0;
+    }
+    let max_steps = max_steps.max(1) as f64;
+
+    let mut magnitude = 10f64.powf(((high - low) / max_steps).log10().floor());
+    loop {
+        for mantissa in [1.0, 2.0, 5.0] {
+            let step = (mantissa * magnitude).max(min_step);
+            // The tolerance keeps the bounds which are already multiples of the step
+            let (first, last) = ((low / step + 1e-9).floor(), (high / step - 1e-9).ceil());
+            if last - first <= max_steps {
+                let light_step = if mantissa == 2.0 {
+                    step / 4.0
+                } else {
+                    step / 5.0
+                };
+                return Some((
+                    multiple_of(first, step),
+                    multiple_of(last, step),
+                    step,
+                    light_step.max(min_step),
+                ));
+            }
+        }
+        magnitude *= 10.0;
+    }
+}
+
+/// The trait for the numeric ranges which can be expanded to round bounds.
+///
+/// The range is expanded outward until both of its bounds are multiples of a round step of
+/// the form 1, 2 or 5 times a power of ten, and the key points of the axis are put at each
+/// step, e.g. the data range `0.0..9734.0` becomes the axis `0.0..10000.0` with a tick mark
+/// every `1000.0`. The ranges are left untouched unless this trait is used.
+///
+/// Note: the key points are bound to the coordinate with
+/// [with_key_points](trait.BindKeyPoints.html#method.with_key_points), thus the maximum number
+/// of labels configured by the mesh style is ignored in favor of the number of steps given here.
+pub trait IntoNiceRange: AsRangedCoord + Sized {
+    /// Expand the range to round bounds, with at most 10 steps between them
+    ///
+    /// - **returns**: The expanded coordinate, with its key points on each step
+    fn nice(self) -> WithKeyPoints<Self::CoordDescType> {
+        self.nice_with_steps(10)
+    }
+
+    /// Expand the range to round bounds. A range with an infinite or NaN bound is kept as is,
+    /// without any key points.
+    ///
+    /// - `max_steps`: The maximum number of steps between the bounds
+    /// - **returns**: The expanded coordinate, with its key points on each step
+    fn nice_with_steps(self, max_steps: usize) -> WithKeyPoints<Self::CoordDescType>;
+}
+
+macro_rules! impl_nice_range {
+    ($type:ty, $min_step:expr) => {
+        impl IntoNiceRange for Range<$type> {
+            fn nice_with_steps(self, max_steps: usize) -> WithKeyPoints<Self::CoordDescType> {
+                let bounds =
+                    nice_bounds((self.start as f64, self.end as f64), max_steps, $min_step);
+                // A range with a bound which isn't finite is kept as is, without key points
+                let (start, end, step, light_step) = match bounds {
+                    Some(bounds) => bounds,
+                    None => return self.with_key_points(vec![]).with_light_points(vec![]),
+                };
+                let points = |step: f64| {
+                    let first = (start / step).round() as i64;
+                    let last = (end / step).round() as i64;
+                    (first..=last)
+                        .map(|k| multiple_of(k as f64, step) as $type)
+                        .collect::<Vec<_>>()
+                };
+                let (bold, light) = (points(step), points(light_step));
+                ((start as $type)..(end as $type))
+                    .with_key_points(bold)
+                    .with_light_points(light)
+            }
+        }
+    };
+}
+
+impl_nice_range!(f32, 0.0);
+impl_nice_range!(f64, 0.0);
+impl_nice_range!(i32, 1.0);
+impl_nice_range!(i64, 1.0);
+impl_nice_range!(u32, 1.0);
+impl_nice_range!(u64, 1.0);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged};
+
+    fn bounds<R: IntoNiceRange>(range: R) -> (Range<R::Value>, Vec<R::Value>)
+    where
+        R::Value: Clone,
+    {
+        let coord = range.nice();
+        (coord.range(), coord.key_points(BoldPoints(100)))
+    }
+
+    #[test]
+    fn test_nice_bounds() {
+        let (range, ticks) = bounds(0.0..9734.0);
+        assert_eq!(range, 0.0..10000.0);
+        assert_eq!(ticks.len(), 11);
+        assert_eq!(ticks[1], 1000.0);
+
+        let (range, ticks) = bounds(-0.37..0.82);
+        assert_eq!(range, -0.4..1.0);
+        assert_eq!(ticks, vec![-0.4, -0.2, 0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+
+        let (range, ticks) = bounds(0.0012..0.0031);
+        assert_eq!(range, 0.0012..0.0032);
+        assert_eq!(ticks[2], 0.0016);
+
+        let (range, ticks) = bounds(13.0..87.0);
+        assert_eq!(range, 10.0..90.0);
+        assert_eq!(
+            ticks,
+            vec![10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0]
+        );
+
+        // Already round bounds are kept
+        assert_eq!(bounds(0.0..1.0).0, 0.0..1.0);
+        // Reversed ranges are sorted
+        assert_eq!(bounds(1234.0..-56.0).0, -200.0..1400.0);
+        // A single value gets an axis around it
+        assert_eq!(bounds(5.0..5.0).0, 4.5..5.5);
+        assert_eq!(bounds(0.0..0.0).0, -0.5..0.5);
+
+        let (range, ticks) = bounds(3..97);
+        assert_eq!(range, 0..100);
+        assert_eq!(ticks, vec![0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100]);
+        // The steps of integer ranges are never fractional
+        assert_eq!(bounds(0u32..3).1, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_nice_minor_grid_pixels() {
+        // The minor lines split the steps of the expanded range
+        let coord = (0.0..9734.0).nice_with_steps(4);
+        assert_eq!(
+            coord.minor_grid_pixels(10, 5, (0, 100)),
+            vec![10, 20, 30, 40, 60, 70, 80, 90]
+        );
+    }
+
+    #[test]
+    fn test_nice_bounds_not_finite() {
+        let (range, ticks) = bounds(0.0..f64::INFINITY);
+        assert_eq!(range, 0.0..f64::INFINITY);
+        assert!(ticks.is_empty());
+        assert_eq!(bounds(f32::NEG_INFINITY..1.0).0, f32::NEG_INFINITY..1.0);
+        let (range, ticks) = bounds(f64::NAN..1.0);
+        assert!(range.start.is_nan() && range.end == 1.0);
+        assert!(ticks.is_empty());
+        // The bounds are finite, but not their distance
+        assert_eq!(bounds(-f64::MAX..f64::MAX).0, -f64::MAX..f64::MAX);
+    }
+
+    #[test]
+    fn test_nice_steps() {
+        let coord = (0.0..9734.0).nice_with_steps(4);
+        assert_eq!(coord.range(), 0.0..10000.0);
+        assert_eq!(
+            coord.key_points(BoldPoints(100)),
+            vec![0.0, 5000.0, 10000.0]
+        );
+        assert_eq!(coord.key_points(LightPoints::new(100, 100)).len(), 11);
+    }
+}
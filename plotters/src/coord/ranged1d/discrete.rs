@@ -134,6 +134,16 @@ where
             _ => "".to_string(),
         }
     }
+
+    fn label_groups(&self, limit: (i32, i32)) -> Vec<(Range<i32>, String)> {
+        // Each group spans until the end of the segment of its last value
+        let margin = ((limit.1 - limit.0) as f32 / self.0.size() as f32).round() as i32;
+        self.0
+            .label_groups((limit.0, limit.1 - margin))
+            .into_iter()
+            .map(|(span, label)| (span.start..span.end + margin, label))
+            .collect()
+    }
 }
 
 impl<D: DiscreteRanged> Ranged for SegmentedCoord<D> {
@@ -206,9 +216,13 @@ impl<T> From<T> for SegmentValue<T> {
 
 impl<DC: DiscreteRanged> ReversibleRanged for DC {
     fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<Self::ValueType> {
+        if input < limit.0.min(limit.1) || input > limit.0.max(limit.1) || limit.0 == limit.1 {
+            return None;
+        }
         let idx = (f64::from(input - limit.0) * (self.size() as f64) / f64::from(limit.1 - limit.0))
             .floor() as usize;
-        self.from_index(idx)
+        // The pixel at the end of the range belongs to the last value
+        self.from_index(idx.min(self.size().saturating_sub(1)))
     }
 }
 
@@ -271,4 +285,17 @@ mod test {
         assert_eq!(coord.map(&SegmentValue::Exact(0), (0, 24)), 0);
         assert_eq!(coord.map(&SegmentValue::Exact(1), (0, 24)), 2);
     }
+
+    #[test]
+    fn test_unmap() {
+        let range: crate::coord::ranged1d::types::RangedCoordi32 = (0..10).into();
+        for value in 0..=10 {
+            let pixel = range.map(&value, (0, 100));
+            assert_eq!(range.unmap(pixel, (0, 100)), Some(value));
+            let pixel = range.map(&value, (100, 0));
+            assert_eq!(range.unmap(pixel, (100, 0)), Some(value));
+        }
+        assert_eq!(range.unmap(-1, (0, 100)), None);
+        assert_eq!(range.unmap(101, (0, 100)), None);
+    }
 }
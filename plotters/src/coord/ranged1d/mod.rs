@@ -82,6 +82,13 @@ pub trait ValueFormatter<V> {
     fn format_ext(&self, value: &V) -> String {
         Self::format(value)
     }
+    /// Get the labels of the groups of values, which are drawn beyond the value labels and
+    /// centered on the span of each group. Most of the coordinates don't group their values.
+    /// - `limit`: The pixel range the coordinate is mapped to
+    /// - **returns** The pixel span and the label of each group
+    fn label_groups(&self, _limit: (i32, i32)) -> Vec<(Range<i32>, String)> {
+        vec![]
+    }
 }
 
 // By default the value is formatted by the debug trait
@@ -207,6 +214,51 @@ pub trait Ranged {
     /// Get the range of this value
     fn range(&self) -> Range<Self::ValueType>;
 
+    /// This function gives the pixels of the minor grid lines, which split each interval
+    /// between two consecutive bold key points into `divisions` equal parts. The intervals
+    /// before the first and after the last bold key point are split with the same spacing.
+    /// - `bold_points`: The maximum number of bold key points
+    /// - `divisions`: The number of parts each interval is split into
+    /// - `limit`: The pixel range of the axis
+    fn minor_grid_pixels(
+        &self,
+        bold_points: usize,
+        divisions: usize,
+        limit: (i32, i32),
+    ) -> Vec<i32> {
+        let mut bold: Vec<_> = self
+            .key_points(BoldPoints(bold_points))
+            .iter()
+            .map(|value| self.map(value, limit))
+            .collect();
+        bold.sort_unstable();
+        bold.dedup();
+        if bold.len() < 2 || divisions < 2 {
+            return vec![];
+        }
+
+        let (low, high) = (limit.0.min(limit.1), limit.0.max(limit.1));
+        let mut ret = vec![];
+        let mut push_interval = |from: i32, to: i32| {
+            for i in 1..divisions {
+                let pixel = f64::from(from) + f64::from(to - from) * i as f64 / divisions as f64;
+                let pixel = pixel.round() as i32;
+                if pixel >= low && pixel <= high {
+                    ret.push(pixel);
+                }
+            }
+        };
+
+        let (first, last) = (bold[0], bold[bold.len() - 1]);
+        push_interval(first, 2 * first - bold[1]);
+        for pair in bold.windows(2) {
+            push_interval(pair[0], pair[1]);
+        }
+        push_interval(last, 2 * last - bold[bold.len() - 2]);
+        ret.sort_unstable();
+        ret
+    }
+
     /// This function provides the on-axis part of its range
     #[allow(clippy::range_plus_one)]
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
@@ -216,6 +268,12 @@ pub trait Ranged {
             limit.1..limit.0
         }
     }
+
+    /// This function gives the pixel ranges of the breaks of a discontinuous axis, where the
+    /// axis line is interrupted by a break mark. The axes are continuous by default.
+    fn axis_breaks(&self, _limit: (i32, i32)) -> Vec<Range<i32>> {
+        vec![]
+    }
 }
 
 /// The trait indicates the ranged value can be map reversely, which means
@@ -828,6 +828,222 @@ impl Ranged for RangedDuration {
     }
 }
 
+/// The time values that can be formatted with a `strftime`-like format string,
+/// see [chrono::format::strftime](https://docs.rs/chrono/latest/chrono/format/strftime/index.html)
+/// for the supported specifiers.
+pub trait FormatTime: Datelike {
+    /// Format the time value
+    /// - `fmt`: The format string
+    /// - **returns** The formatted value, or the format string itself if it is invalid
+    fn format_time(&self, fmt: &str) -> String;
+}
+
+fn write_or_format_string<T: std::fmt::Display>(formatted: T, fmt: &str) -> String {
+    use std::fmt::Write;
+    let mut ret = String::new();
+    if write!(ret, "{}", formatted).is_err() {
+        return fmt.to_string();
+    }
+    ret
+}
+
+impl FormatTime for NaiveDate {
+    fn format_time(&self, fmt: &str) -> String {
+        write_or_format_string(self.format(fmt), fmt)
+    }
+}
+
+impl FormatTime for NaiveDateTime {
+    fn format_time(&self, fmt: &str) -> String {
+        write_or_format_string(self.format(fmt), fmt)
+    }
+}
+
+impl<Z: TimeZone> FormatTime for Date<Z>
+where
+    Z::Offset: std::fmt::Display,
+{
+    fn format_time(&self, fmt: &str) -> String {
+        write_or_format_string(self.format(fmt), fmt)
+    }
+}
+
+impl<Z: TimeZone> FormatTime for DateTime<Z>
+where
+    Z::Offset: std::fmt::Display,
+{
+    fn format_time(&self, fmt: &str) -> String {
+        write_or_format_string(self.format(fmt), fmt)
+    }
+}
+
+/// The coordinate decorator that formats the date and time labels with a format string.
+/// The month and week day names can be replaced by localized ones, since chrono only knows
+/// about the English names.
+/// See [BindDateFormat::with_format](trait.BindDateFormat.html#tymethod.with_format) for details.
+#[derive(Clone)]
+pub struct WithDateFormat<R: Ranged> {
+    inner: R,
+    format: String,
+    month_names: Option<Vec<String>>,
+    weekday_names: Option<Vec<String>>,
+}
+
+impl<R: Ranged> WithDateFormat<R>
+where
+    R::ValueType: FormatTime,
+{
+    /// Replace the month names in the labels.
+    /// The names are used for both the `%B` and the `%b` (or `%h`) specifiers.
+    /// - `names`: The names of the months, from January to December
+    /// - **returns** The decorated coordinate using the given names
+    pub fn month_names<S: AsRef<str>>(mut self, names: [S; 12]) -> Self {
+        self.month_names = Some(names.iter().map(|n| n.as_ref().to_string()).collect());
+        self
+    }
+
+    /// Replace the week day names in the labels.
+    /// The names are used for both the `%A` and the `%a` specifiers.
+    /// - `names`: The names of the week days, from Monday to Sunday
+    /// - **returns** The decorated coordinate using the given names
+    pub fn weekday_names<S: AsRef<str>>(mut self, names: [S; 7]) -> Self {
+        self.weekday_names = Some(names.iter().map(|n| n.as_ref().to_string()).collect());
+        self
+    }
+
+    /// Format a value the way it is labelled on the axis
+    /// - `value`: The value to format
+    /// - **returns** The label of the value
+    pub fn format_value(&self, value: &R::ValueType) -> String {
+        if self.month_names.is_none() && self.weekday_names.is_none() {
+            return value.format_time(&self.format);
+        }
+
+        fn push_escaped(fmt: &mut String, name: &str) {
+            fmt.push_str(&name.replace('%', "%%"));
+        }
+
+        let mut fmt = String::with_capacity(self.format.len());
+        let mut chars = self.format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                fmt.push(c);
+                continue;
+            }
+            match (chars.next(), &self.month_names, &self.weekday_names) {
+                (Some('B' | 'b' | 'h'), Some(months), _) => {
+                    push_escaped(&mut fmt, &months[value.month0() as usize])
+                }
+                (Some('A' | 'a'), _, Some(weekdays)) => push_escaped(
+                    &mut fmt,
+                    &weekdays[value.weekday().num_days_from_monday() as usize],
+                ),
+                (Some(spec), _, _) => {
+                    fmt.push('%');
+                    fmt.push(spec);
+                }
+                (None, _, _) => fmt.push('%'),
+            }
+        }
+        value.format_time(&fmt)
+    }
+}
+
+impl<R: Ranged> Ranged for WithDateFormat<R> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = R::ValueType;
+
+    fn range(&self) -> Range<Self::ValueType> {
+        self.inner.range()
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.inner.map(value, limit)
+    }
+
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Self::ValueType> {
+        self.inner.key_points(hint)
+    }
+
+    fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
+        self.inner.axis_pixel_range(limit)
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<Range<i32>> {
+        self.inner.axis_breaks(limit)
+    }
+}
+
+impl<R: DiscreteRanged> DiscreteRanged for WithDateFormat<R> {
+    fn size(&self) -> usize {
+        self.inner.size()
+    }
+    fn index_of(&self, value: &Self::ValueType) -> Option<usize> {
+        self.inner.index_of(value)
+    }
+    fn from_index(&self, index: usize) -> Option<Self::ValueType> {
+        self.inner.from_index(index)
+    }
+}
+
+impl<R: Ranged> ValueFormatter<R::ValueType> for WithDateFormat<R>
+where
+    R::ValueType: FormatTime + std::fmt::Debug,
+{
+    fn format(value: &R::ValueType) -> String {
+        format!("{:?}", value)
+    }
+
+    fn format_ext(&self, value: &R::ValueType) -> String {
+        self.format_value(value)
+    }
+}
+
+/// Bind an existing date or time coordinate spec with a format string for its labels.
+/// See [WithDateFormat](struct.WithDateFormat.html) for more details.
+pub trait BindDateFormat
+where
+    Self: AsRangedCoord,
+    Self::Value: FormatTime,
+{
+    /// Format the labels of a date or time coordinate with a `strftime`-like format string.
+    /// The key points are still picked by the decorated coordinate.
+    /// - `fmt`: The format string, for example `"%b %Y"`
+    /// - **returns** The decorated coordinate
+    ///
+    /// Example:
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use plotters::prelude::*;
+    /// let mut buffer = vec![0; 1024 * 768 * 3];
+    /// let root = BitMapBackend::with_buffer(&mut buffer, (1024, 768)).into_drawing_area();
+    /// let start = NaiveDate::from_ymd(2023, 1, 1);
+    /// let end = NaiveDate::from_ymd(2023, 12, 31);
+    /// let mut chart = ChartBuilder::on(&root)
+    ///     .x_label_area_size(30)
+    ///     .build_cartesian_2d(
+    ///         // Month boundaries labelled "janv.", "févr.", ...
+    ///         (start..end).monthly().with_format("%b").month_names([
+    ///             "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.",
+    ///             "oct.", "nov.", "déc.",
+    ///         ]),
+    ///         0..10,
+    ///     )
+    ///     .unwrap();
+    /// chart.configure_mesh().draw().unwrap();
+    /// ```
+    fn with_format<S: Into<String>>(self, fmt: S) -> WithDateFormat<Self::CoordDescType> {
+        WithDateFormat {
+            inner: self.into(),
+            format: fmt.into(),
+            month_names: None,
+            weekday_names: None,
+        }
+    }
+}
+
+impl<T: AsRangedCoord> BindDateFormat for T where T::Value: FormatTime {}
+
 #[allow(clippy::inconsistent_digit_grouping)]
 fn compute_period_per_point(total_ns: u64, max_points: usize, sub_daily: bool) -> Option<u64> {
     let min_ns_per_point = total_ns as f64 / max_points as f64;
@@ -1295,4 +1511,91 @@ mod test {
         let value = coord.unmap(500, (0, 1000));
         assert_eq!(value, Some(mid));
     }
+
+    #[test]
+    fn test_date_format() {
+        let coord = (Utc.ymd(2023, 3, 1)..Utc.ymd(2023, 4, 1)).with_format("%a %d %B %Y");
+        assert_eq!(coord.format_ext(&Utc.ymd(2023, 3, 6)), "Mon 06 March 2023");
+
+        let months = [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ];
+        let coord = coord
+            .month_names(months)
+            .weekday_names(["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."]);
+        assert_eq!(coord.format_ext(&Utc.ymd(2023, 3, 6)), "lun. 06 mars 2023");
+        assert_eq!(coord.format_ext(&Utc.ymd(2023, 3, 12)), "dim. 12 mars 2023");
+
+        // The names are escaped and literal percent signs are kept as they are
+        let coord = (NaiveDate::from_ymd(2023, 1, 1)..NaiveDate::from_ymd(2024, 1, 1))
+            .with_format("%b: 100%% %h")
+            .month_names(["J%m", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"]);
+        assert_eq!(
+            coord.format_ext(&NaiveDate::from_ymd(2023, 1, 15)),
+            "J%m: 100% J%m"
+        );
+
+        let coord: RangedDateTime<_> =
+            (Utc.ymd(2023, 1, 1).and_hms(0, 0, 0)..Utc.ymd(2023, 1, 2).and_hms(0, 0, 0)).into();
+        let coord = coord.with_format("%H:%M");
+        assert_eq!(
+            coord.format_ext(&Utc.ymd(2023, 1, 1).and_hms(13, 5, 0)),
+            "13:05"
+        );
+    }
+
+    #[test]
+    fn test_one_year_month_ticks() {
+        use crate::coord::ranged1d::BoldPoints;
+        use crate::prelude::*;
+        use std::{cell::RefCell, rc::Rc};
+
+        let labels = Rc::new(RefCell::new(vec![]));
+        let drawn = labels.clone();
+        let drawing_area = crate::create_mocked_drawing_area(1024, 200, move |m| {
+            m.check_draw_text(move |_, _, _, _, text| drawn.borrow_mut().push(text.to_string()));
+        });
+
+        let start = NaiveDate::from_ymd(2023, 1, 1);
+        let end = NaiveDate::from_ymd(2023, 12, 31);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(20)
+            .build_cartesian_2d((start..end).monthly(), 0..10)
+            .expect("Create chart");
+        chart
+            .configure_mesh()
+            .x_labels(12)
+            .x_label_formatter(&|d| {
+                assert_eq!(d.day(), 1);
+                d.format("%b").to_string()
+            })
+            .draw()
+            .expect("Draw mesh");
+
+        assert_eq!(
+            *labels.borrow(),
+            vec![
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"
+            ]
+        );
+
+        // With fewer labels, the ticks are still on month boundaries
+        let kps = (start..end)
+            .monthly()
+            .with_format("%b")
+            .key_points(BoldPoints(4));
+        let kps: Vec<_> = kps.into_iter().map(|d| (d.month(), d.day())).collect();
+        assert_eq!(kps, vec![(1, 1), (4, 1), (7, 1), (10, 1)]);
+    }
 }
@@ -0,0 +1,206 @@
+use crate::coord::ranged1d::{
+    DiscreteRanged, KeyPointHint, NoDefaultFormatting, Ranged, ValueFormatter,
+};
+use std::fmt::Display;
+use std::ops::Range;
+
+/**
+A categorical coordinate whose categories are split into labelled groups.
+
+Each value of the coordinate is a `(group, item)` pair, so the same item label can appear in
+several groups, e.g. the quarters of different years. The items are labelled along the axis
+and each group label is centered beneath the span of its items.
+
+Like the other discrete coordinates, it can be turned into a segmented coordinate with
+[into_segmented](../trait.IntoSegmentedCoord.html#method.into_segmented), which is what bar
+charts and histograms use.
+
+Please note: the behavior of constructing a coordinate without any item may cause panic
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("grouped_category.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let quarters = GroupedCategory::new(vec![
+    (2021, vec!["Q1", "Q2", "Q3", "Q4"]),
+    (2022, vec!["Q1", "Q2", "Q3", "Q4"]),
+]);
+let mut chart = ChartBuilder::on(&drawing_area)
+    .x_label_area_size(40)
+    .build_cartesian_2d(quarters.into_segmented(), 0..10)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_series(
+        Histogram::vertical(&chart)
+            .style(BLUE.filled())
+            .data(vec![((2021, "Q4"), 5), ((2022, "Q1"), 7)]),
+    )
+    .unwrap();
+```
+*/
+#[derive(Clone)]
+pub struct GroupedCategory<G, T> {
+    groups: Vec<(G, Vec<T>)>,
+}
+
+impl<G: Clone + PartialEq, T: Clone + PartialEq> GroupedCategory<G, T> {
+    /// Create a new grouped category coordinate
+    /// - `groups`: The groups, in the order of the axis, each with its items
+    /// - **returns** The newly created coordinate
+    pub fn new(groups: Vec<(G, Vec<T>)>) -> Self {
+        Self { groups }
+    }
+
+    /// Get the groups of the coordinate with their items
+    pub fn groups(&self) -> &[(G, Vec<T>)] {
+        &self.groups
+    }
+
+    fn map_index(&self, idx: usize, limit: (i32, i32)) -> i32 {
+        let intervals = self.size().max(2) - 1;
+        (f64::from(limit.0)
+            + f64::from(limit.1 - limit.0) * (f64::from(idx as u32) / f64::from(intervals as u32)))
+        .round() as i32
+    }
+}
+
+impl<G: Clone + PartialEq, T: Clone + PartialEq> From<Vec<(G, Vec<T>)>> for GroupedCategory<G, T> {
+    fn from(groups: Vec<(G, Vec<T>)>) -> Self {
+        Self::new(groups)
+    }
+}
+
+impl<G: Clone + PartialEq, T: Clone + PartialEq> Ranged for GroupedCategory<G, T> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = (G, T);
+
+    fn range(&self) -> Range<(G, T)> {
+        // If there's no item, we should always panic
+        self.from_index(0).unwrap()..self.from_index(self.size() - 1).unwrap()
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        match self.index_of(value) {
+            Some(idx) => self.map_index(idx, limit),
+            None => limit.0,
+        }
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<Self::ValueType> {
+        let max_points = hint.max_num_points();
+        if max_points == 0 {
+            return vec![];
+        }
+        let step = self.size().saturating_sub(1) / max_points + 1;
+        self.values().step_by(step.max(1)).collect()
+    }
+}
+
+impl<G: Clone + PartialEq, T: Clone + PartialEq> DiscreteRanged for GroupedCategory<G, T> {
+    fn size(&self) -> usize {
+        self.groups.iter().map(|(_, items)| items.len()).sum()
+    }
+
+    fn index_of(&self, value: &(G, T)) -> Option<usize> {
+        let mut offset = 0;
+        for (group, items) in self.groups.iter() {
+            if *group == value.0 {
+                return items
+                    .iter()
+                    .position(|item| *item == value.1)
+                    .map(|idx| offset + idx);
+            }
+            offset += items.len();
+        }
+        None
+    }
+
+    fn from_index(&self, mut index: usize) -> Option<(G, T)> {
+        for (group, items) in self.groups.iter() {
+            if index < items.len() {
+                return Some((group.clone(), items[index].clone()));
+            }
+            index -= items.len();
+        }
+        None
+    }
+}
+
+impl<G, T> ValueFormatter<(G, T)> for GroupedCategory<G, T>
+where
+    G: Clone + PartialEq + Display,
+    T: Clone + PartialEq + Display,
+{
+    fn format(value: &(G, T)) -> String {
+        value.1.to_string()
+    }
+
+    fn label_groups(&self, limit: (i32, i32)) -> Vec<(Range<i32>, String)> {
+        let mut offset = 0;
+        let mut ret = vec![];
+        for (group, items) in self.groups.iter() {
+            if !items.is_empty() {
+                let start = self.map_index(offset, limit);
+                let end = self.map_index(offset + items.len() - 1, limit);
+                ret.push((start..end, group.to_string()));
+            }
+            offset += items.len();
+        }
+        ret
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::ranged1d::{BoldPoints, IntoSegmentedCoord, SegmentValue};
+
+    fn quarters() -> GroupedCategory<i32, &'static str> {
+        GroupedCategory::new(vec![
+            (2021, vec!["Q1", "Q2", "Q3"]),
+            (2022, vec![]),
+            (2023, vec!["Q1", "Q2", "Q3"]),
+        ])
+    }
+
+    #[test]
+    fn test_grouped_category() {
+        let coord = quarters();
+
+        assert_eq!(coord.size(), 6);
+        assert_eq!(coord.range(), (2021, "Q1")..(2023, "Q3"));
+        assert_eq!(coord.index_of(&(2023, "Q2")), Some(4));
+        assert_eq!(coord.index_of(&(2022, "Q1")), None);
+        assert_eq!(coord.from_index(3), Some((2023, "Q1")));
+        assert_eq!(coord.from_index(6), None);
+
+        assert_eq!(coord.map(&(2021, "Q1"), (0, 50)), 0);
+        assert_eq!(coord.map(&(2023, "Q1"), (0, 50)), 30);
+        assert_eq!(coord.key_points(6).len(), 6);
+        assert_eq!(
+            coord.key_points(BoldPoints(3)),
+            vec![(2021, "Q1"), (2021, "Q3"), (2023, "Q2")]
+        );
+    }
+
+    #[test]
+    fn test_label_groups() {
+        let coord = quarters();
+        assert_eq!(GroupedCategory::<i32, &str>::format(&(2021, "Q2")), "Q2");
+        assert_eq!(
+            coord.label_groups((0, 50)),
+            vec![(0..20, "2021".to_string()), (30..50, "2023".to_string())]
+        );
+
+        // The groups of a segmented coordinate span the segments of their items
+        let coord = quarters().into_segmented();
+        assert_eq!(
+            coord.label_groups((0, 60)),
+            vec![(0..30, "2021".to_string()), (30..60, "2023".to_string())]
+        );
+        assert_eq!(coord.map(&SegmentValue::Exact((2023, "Q1")), (0, 60)), 30);
+    }
+}
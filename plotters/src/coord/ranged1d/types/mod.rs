@@ -3,9 +3,13 @@ mod datetime;
 #[cfg(feature = "chrono")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
 pub use datetime::{
-    IntoMonthly, IntoYearly, Monthly, RangedDate, RangedDateTime, RangedDuration, Yearly,
+    BindDateFormat, FormatTime, IntoMonthly, IntoYearly, Monthly, RangedDate, RangedDateTime,
+    RangedDuration, WithDateFormat, Yearly,
 };
 
+mod grouped;
+pub use grouped::GroupedCategory;
+
 mod numeric;
 pub use numeric::{
     RangedCoordf32, RangedCoordf64, RangedCoordi128, RangedCoordi32, RangedCoordi64,
@@ -7,7 +7,7 @@
  This type of coordinate system is used by the chart constructed with [ChartBuilder::build_cartesian_2d](../../chart/ChartBuilder.html#method.build_cartesian_2d).
 */
 
-use crate::coord::ranged1d::{KeyPointHint, Ranged, ReversibleRanged};
+use crate::coord::ranged1d::{KeyPointHint, Ranged, ReversibleRanged, ValueFormatter};
 use crate::coord::{CoordTranslate, ReverseCoordTranslate};
 
 use crate::style::ShapeStyle;
@@ -41,6 +41,11 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         }
     }
 
+    // Move the Y axis to another pixel range, as (bottom, top)
+    pub(crate) fn set_back_y(&mut self, back_y: (i32, i32)) {
+        self.back_y = back_y;
+    }
+
     /// Draw the mesh for the coordinate system
     pub fn draw_mesh<
         E,
@@ -51,34 +56,94 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         &self,
         h_limit: YH,
         v_limit: XH,
+        draw_mesh: DrawMesh,
+    ) -> Result<(), E> {
+        self.draw_mesh_with_ticks(h_limit, v_limit, (None, None), draw_mesh)
+    }
+
+    // Draw the mesh, the key points of an axis being replaced by its ticks when it has some. The
+    // ticks out of the range of their axis are skipped
+    pub(crate) fn draw_mesh_with_ticks<
+        E,
+        DrawMesh: FnMut(MeshLine<X, Y>) -> Result<(), E>,
+        XH: KeyPointHint,
+        YH: KeyPointHint,
+    >(
+        &self,
+        h_limit: YH,
+        v_limit: XH,
+        (x_ticks, y_ticks): (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
         mut draw_mesh: DrawMesh,
     ) -> Result<(), E> {
-        let (xkp, ykp) = (
-            self.logic_x.key_points(v_limit),
-            self.logic_y.key_points(h_limit),
-        );
+        let within = |v: i32, (a, b): (i32, i32)| a.min(b) <= v && v <= a.max(b);
+        let (auto_x, auto_y);
+        let xkp = match x_ticks {
+            Some(ticks) => ticks,
+            None => {
+                auto_x = self.logic_x.key_points(v_limit);
+                &auto_x[..]
+            }
+        };
+        let ykp = match y_ticks {
+            Some(ticks) => ticks,
+            None => {
+                auto_y = self.logic_y.key_points(h_limit);
+                &auto_y[..]
+            }
+        };
 
         for logic_x in xkp {
-            let x = self.logic_x.map(&logic_x, self.back_x);
+            let x = self.logic_x.map(logic_x, self.back_x);
+            if x_ticks.is_some() && !within(x, self.back_x) {
+                continue;
+            }
             draw_mesh(MeshLine::XMesh(
                 (x, self.back_y.0),
                 (x, self.back_y.1),
-                &logic_x,
+                logic_x,
             ))?;
         }
 
         for logic_y in ykp {
-            let y = self.logic_y.map(&logic_y, self.back_y);
+            let y = self.logic_y.map(logic_y, self.back_y);
+            if y_ticks.is_some() && !within(y, self.back_y) {
+                continue;
+            }
             draw_mesh(MeshLine::YMesh(
                 (self.back_x.0, y),
                 (self.back_x.1, y),
-                &logic_y,
+                logic_y,
             ))?;
         }
 
         Ok(())
     }
 
+    /// Draw the minor grid lines for the coordinate system, which split each interval between
+    /// two bold key points into equal parts, see [Ranged::minor_grid_pixels](../ranged1d/trait.Ranged.html#method.minor_grid_pixels)
+    pub fn draw_minor_mesh<E, DrawLine: FnMut(BackendCoord, BackendCoord) -> Result<(), E>>(
+        &self,
+        (h_bold, h_divisions): (usize, usize),
+        (v_bold, v_divisions): (usize, usize),
+        mut draw_line: DrawLine,
+    ) -> Result<(), E> {
+        for x in self
+            .logic_x
+            .minor_grid_pixels(v_bold, v_divisions, self.back_x)
+        {
+            draw_line((x, self.back_y.0), (x, self.back_y.1))?;
+        }
+
+        for y in self
+            .logic_y
+            .minor_grid_pixels(h_bold, h_divisions, self.back_y)
+        {
+            draw_line((self.back_x.0, y), (self.back_x.1, y))?;
+        }
+
+        Ok(())
+    }
+
     /// Get the range of X axis
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.logic_x.range()
@@ -99,6 +164,25 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
         self.logic_y.axis_pixel_range(self.back_y)
     }
 
+    /// Get the horizental backend coordinate ranges where the X axis is broken
+    pub fn get_x_axis_breaks(&self) -> Vec<Range<i32>> {
+        self.logic_x.axis_breaks(self.back_x)
+    }
+
+    /// Get the vertical backend coordinate ranges where the Y axis is broken
+    pub fn get_y_axis_breaks(&self) -> Vec<Range<i32>> {
+        self.logic_y.axis_breaks(self.back_y)
+    }
+
+    /// Get the labels of the groups of values along the X axis, along with their pixel span.
+    /// See [ValueFormatter::label_groups](../ranged1d/trait.ValueFormatter.html#method.label_groups)
+    pub fn get_x_label_groups(&self) -> Vec<(Range<i32>, String)>
+    where
+        X: ValueFormatter<X::ValueType>,
+    {
+        self.logic_x.label_groups(self.back_x)
+    }
+
     /// Get the 1D coordinate spec for X axis
     pub fn x_spec(&self) -> &X {
         &self.logic_x
@@ -108,6 +192,16 @@ impl<X: Ranged, Y: Ranged> Cartesian2d<X, Y> {
     pub fn y_spec(&self) -> &Y {
         &self.logic_y
     }
+
+    // The pixel of a value along the X axis
+    pub(crate) fn x_pixel(&self, x: &X::ValueType) -> i32 {
+        self.logic_x.map(x, self.back_x)
+    }
+
+    // The pixel of a value along the Y axis
+    pub(crate) fn y_pixel(&self, y: &Y::ValueType) -> i32 {
+        self.logic_y.map(y, self.back_y)
+    }
 }
 
 impl<X: Ranged, Y: Ranged> CoordTranslate for Cartesian2d<X, Y> {
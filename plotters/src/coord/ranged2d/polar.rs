@@ -0,0 +1,257 @@
+/*!
+ The 2-dimensional polar coordinate system.
+
+ This module provides the polar coordinate system, which maps an angle and a radius, described
+ by a ranged 1D coordinate specification, to a point of a disc centered in the drawing area.
+
+ This type of coordinate system is used by the chart constructed with [ChartBuilder::build_polar](../../chart/ChartBuilder.html#method.build_polar).
+*/
+
+use crate::coord::ranged1d::{Ranged, ReversibleRanged};
+use crate::coord::{CoordTranslate, ReverseCoordTranslate};
+
+use plotters_backend::BackendCoord;
+
+use std::f64::consts::PI;
+use std::ops::Range;
+
+/// The unit of the angles of a polar coordinate system
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AngleUnit {
+    /// The angles are in radians, a full turn is `2π`
+    Radians,
+    /// The angles are in degrees, a full turn is `360`
+    Degrees,
+}
+
+impl AngleUnit {
+    /// Get the value of a full turn in this unit.
+    ///
+    /// - **returns** The value of a full turn
+    pub fn full_turn(self) -> f64 {
+        match self {
+            AngleUnit::Radians => 2.0 * PI,
+            AngleUnit::Degrees => 360.0,
+        }
+    }
+
+    fn to_radians(self, angle: f64) -> f64 {
+        angle / self.full_turn() * 2.0 * PI
+    }
+
+    fn of_radians(self, angle: f64) -> f64 {
+        angle / (2.0 * PI) * self.full_turn()
+    }
+}
+
+/// The direction the zero angle of a polar coordinate system points to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZeroAngle {
+    /// The zero angle points to the top of the drawing area, as on a compass or a radar chart
+    North,
+    /// The zero angle points to the right of the drawing area, as in the trigonometric convention
+    East,
+}
+
+/**
+A polar coordinate system, which maps `(angle, radius)` pairs to the pixels of a disc.
+
+The disc is centered in the drawing area and is as large as it fits. The radius is described
+by a 1D ranged coordinate specification, whose start is mapped to the center of the disc and
+end to its border. The angle is a plain `f64`, whose unit, zero direction and rotation
+direction are configurable. By default, angles are in radians and counterclockwise from the
+east, as in the trigonometric convention.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("polar.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .set_all_label_area_size(30)
+    .build_polar(0.0..10.0).unwrap();
+chart
+    .set_angle_unit(AngleUnit::Degrees)
+    .set_zero_angle(ZeroAngle::North)
+    .set_clockwise(true);
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_series(LineSeries::new((0..=36).map(|a| (a as f64 * 10.0, a as f64 / 3.6)), &RED))
+    .unwrap();
+```
+*/
+#[derive(Clone)]
+pub struct Polar<R: Ranged> {
+    logic_radius: R,
+    angle_unit: AngleUnit,
+    zero_angle: ZeroAngle,
+    clockwise: bool,
+    center: BackendCoord,
+    back_radius: i32,
+}
+
+impl<R: Ranged> Polar<R> {
+    /// Create a new polar coordinate system
+    /// - `logic_radius`: The description for the 1D coordinate system of the radius
+    /// - `actual`: The pixel range on the screen for this coordinate system
+    pub fn new<IntoR: Into<R>>(logic_radius: IntoR, actual: (Range<i32>, Range<i32>)) -> Self {
+        let (x, y) = actual;
+        Self {
+            logic_radius: logic_radius.into(),
+            angle_unit: AngleUnit::Radians,
+            zero_angle: ZeroAngle::East,
+            clockwise: false,
+            center: ((x.start + x.end) / 2, (y.start + y.end) / 2),
+            back_radius: (x.end - x.start).min(y.end - y.start).max(0) / 2,
+        }
+    }
+
+    /// Set the unit of the angles
+    /// - `unit`: The new angle unit
+    pub fn set_angle_unit(&mut self, unit: AngleUnit) {
+        self.angle_unit = unit;
+    }
+
+    /// Set the direction the zero angle points to
+    /// - `zero_angle`: The new zero angle direction
+    pub fn set_zero_angle(&mut self, zero_angle: ZeroAngle) {
+        self.zero_angle = zero_angle;
+    }
+
+    /// Set if the angles grow clockwise
+    /// - `clockwise`: If the angles grow clockwise rather than counterclockwise
+    pub fn set_clockwise(&mut self, clockwise: bool) {
+        self.clockwise = clockwise;
+    }
+
+    /// Get the unit of the angles
+    pub fn angle_unit(&self) -> AngleUnit {
+        self.angle_unit
+    }
+
+    /// Get the direction the zero angle points to
+    pub fn zero_angle(&self) -> ZeroAngle {
+        self.zero_angle
+    }
+
+    /// Check if the angles grow clockwise
+    pub fn is_clockwise(&self) -> bool {
+        self.clockwise
+    }
+
+    /// Get the range of the radius
+    pub fn get_radius_range(&self) -> Range<R::ValueType> {
+        self.logic_radius.range()
+    }
+
+    /// Get the 1D coordinate spec for the radius
+    pub fn radius_spec(&self) -> &R {
+        &self.logic_radius
+    }
+
+    /// Get the backend coordinate of the center of the disc
+    pub fn center(&self) -> BackendCoord {
+        self.center
+    }
+
+    /// Get the radius of the disc in pixels
+    pub fn pixel_radius(&self) -> i32 {
+        self.back_radius
+    }
+
+    // Convert an angle into the counterclockwise angle from the east in radians
+    fn screen_angle(&self, angle: f64) -> f64 {
+        let angle = self.angle_unit.to_radians(angle);
+        let angle = if self.clockwise { -angle } else { angle };
+        match self.zero_angle {
+            ZeroAngle::North => angle + PI / 2.0,
+            ZeroAngle::East => angle,
+        }
+    }
+
+    /// Map an angle and a distance from the center in pixels to the backend coordinate
+    pub(crate) fn project(&self, angle: f64, pixel_radius: f64) -> BackendCoord {
+        let angle = self.screen_angle(angle);
+        (
+            self.center.0 + (pixel_radius * angle.cos()).round() as i32,
+            self.center.1 - (pixel_radius * angle.sin()).round() as i32,
+        )
+    }
+}
+
+impl<R: Ranged> CoordTranslate for Polar<R> {
+    type From = (f64, R::ValueType);
+
+    fn translate(&self, from: &Self::From) -> BackendCoord {
+        let radius = self.logic_radius.map(&from.1, (0, self.back_radius));
+        self.project(from.0, f64::from(radius))
+    }
+}
+
+impl<R: ReversibleRanged> ReverseCoordTranslate for Polar<R> {
+    fn reverse_translate(&self, input: BackendCoord) -> Option<Self::From> {
+        let dx = f64::from(input.0 - self.center.0);
+        let dy = f64::from(self.center.1 - input.1);
+        let radius = self
+            .logic_radius
+            .unmap(dx.hypot(dy).round() as i32, (0, self.back_radius))?;
+
+        let angle = match self.zero_angle {
+            ZeroAngle::North => dy.atan2(dx) - PI / 2.0,
+            ZeroAngle::East => dy.atan2(dx),
+        };
+        let angle = if self.clockwise { -angle } else { angle };
+        let angle = angle.rem_euclid(2.0 * PI);
+
+        Some((self.angle_unit.of_radians(angle), radius))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord::types::RangedCoordf64;
+
+    fn polar() -> Polar<RangedCoordf64> {
+        Polar::new(0.0..10.0, (0..200, 0..100))
+    }
+
+    #[test]
+    fn test_translate() {
+        let mut coord = polar();
+        assert_eq!(coord.center(), (100, 50));
+        assert_eq!(coord.pixel_radius(), 50);
+        assert_eq!(coord.translate(&(0.0, 0.0)), (100, 50));
+        assert_eq!(coord.translate(&(0.0, 10.0)), (150, 50));
+        assert_eq!(coord.translate(&(PI / 2.0, 10.0)), (100, 0));
+
+        coord.set_angle_unit(AngleUnit::Degrees);
+        assert_eq!(coord.translate(&(180.0, 5.0)), (75, 50));
+
+        coord.set_zero_angle(ZeroAngle::North);
+        assert_eq!(coord.translate(&(0.0, 10.0)), (100, 0));
+        assert_eq!(coord.translate(&(90.0, 10.0)), (50, 50));
+
+        coord.set_clockwise(true);
+        assert_eq!(coord.translate(&(90.0, 10.0)), (150, 50));
+    }
+
+    #[test]
+    fn test_reverse_translate() {
+        let mut coord = polar();
+        coord.set_angle_unit(AngleUnit::Degrees);
+        coord.set_zero_angle(ZeroAngle::North);
+        coord.set_clockwise(true);
+
+        let (angle, radius) = coord.reverse_translate((150, 50)).unwrap();
+        assert!((angle - 90.0).abs() < 1e-9);
+        assert!((radius - 10.0).abs() < 1e-9);
+
+        let (angle, radius) = coord.reverse_translate((100, 25)).unwrap();
+        assert!(angle.abs() < 1e-9);
+        assert!((radius - 5.0).abs() < 1e-9);
+
+        assert!(coord.reverse_translate((0, 0)).is_none());
+    }
+}
@@ -48,12 +48,7 @@ impl Mul<ProjectionMatrix> for ProjectionMatrix {
 impl Mul<(i32, i32, i32)> for ProjectionMatrix {
     type Output = (i32, i32);
     fn mul(self, (x, y, z): (i32, i32, i32)) -> (i32, i32) {
-        let (x, y, z) = (x as f64, y as f64, z as f64);
-        let m = self.0;
-        (
-            (x * m[0][0] + y * m[0][1] + z * m[0][2] + m[0][3]) as i32,
-            (x * m[1][0] + y * m[1][1] + z * m[1][2] + m[1][3]) as i32,
-        )
+        self * (x as f64, y as f64, z as f64)
     }
 }
 
@@ -61,9 +56,11 @@ impl Mul<(f64, f64, f64)> for ProjectionMatrix {
     type Output = (i32, i32);
     fn mul(self, (x, y, z): (f64, f64, f64)) -> (i32, i32) {
         let m = self.0;
+        // The homogeneous coordinate is 1 unless the matrix has a perspective
+        let w = x * m[3][0] + y * m[3][1] + z * m[3][2] + m[3][3];
         (
-            (x * m[0][0] + y * m[0][1] + z * m[0][2] + m[0][3]) as i32,
-            (x * m[1][0] + y * m[1][1] + z * m[1][2] + m[1][3]) as i32,
+            ((x * m[0][0] + y * m[0][1] + z * m[0][2] + m[0][3]) / w) as i32,
+            ((x * m[1][0] + y * m[1][1] + z * m[1][2] + m[1][3]) / w) as i32,
         )
     }
 }
@@ -121,6 +118,17 @@ impl ProjectionMatrix {
             [0.0, 0.0, 0.0, 1.0 / factor],
         ])
     }
+    /// Returns the matrix that applies a perspective, seen from a camera at the given distance
+    /// in front of the origin. The points at the depth of the origin keep their size, the
+    /// nearer points are enlarged and the farther ones are shrunk.
+    pub fn perspective(distance: f64) -> Self {
+        ProjectionMatrix([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0 / distance, 1.0],
+        ])
+    }
     /// Normalize the matrix, this will make the metric unit to 1
     pub fn normalize(&mut self) {
         if self.0[3][3] > 1e-20 {
@@ -146,8 +154,11 @@ pub struct ProjectionMatrixBuilder {
     pub yaw: f64,
     /// Specifies the pitch of the 3D coordinate system
     pub pitch: f64,
+    /// Specifies the roll of the 3D coordinate system, around the axis of the view
+    pub roll: f64,
     /// Specifies the scale of the 3D coordinate system
     pub scale: f64,
+    fov: Option<f64>,
     pivot_before: (i32, i32, i32),
     pivot_after: (i32, i32),
 }
@@ -157,7 +168,9 @@ impl Default for ProjectionMatrixBuilder {
         Self {
             yaw: 0.5,
             pitch: 0.15,
+            roll: 0.0,
             scale: 1.0,
+            fov: None,
             pivot_after: (0, 0),
             pivot_before: (0, 0, 0),
         }
@@ -178,6 +191,40 @@ impl ProjectionMatrixBuilder {
         self
     }
 
+    /// Set the angles of the camera looking at the 3D coordinate system
+    /// - `pitch`: The rotation around the horizontal axis, in radians
+    /// - `yaw`: The rotation around the vertical axis, in radians
+    /// - `roll`: The rotation around the axis of the view, in radians
+    pub fn camera_angle(&mut self, pitch: f64, yaw: f64, roll: f64) -> &mut Self {
+        self.pitch = pitch;
+        self.yaw = yaw;
+        self.roll = roll;
+        self
+    }
+
+    /// Set the scale of the 3D coordinate system
+    pub fn scale(&mut self, scale: f64) -> &mut Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Use a perspective projection, where the nearer parts of the 3D coordinate system look
+    /// larger than the farther ones.
+    /// - `fov`: The field of view in radians, which spans the whole coordinate system. The wider
+    ///   it is, the closer the camera and the stronger the perspective. It's clamped to 3π/4 at
+    ///   most, so the camera always stays outside of the coordinate system.
+    pub fn perspective(&mut self, fov: f64) -> &mut Self {
+        self.fov = Some(fov);
+        self
+    }
+
+    /// Use an orthographic projection, where the sizes don't depend on the depth. This is the
+    /// default.
+    pub fn orthographic(&mut self) -> &mut Self {
+        self.fov = None;
+        self
+    }
+
     /// Build the matrix based on the configuration
     pub fn into_matrix(self) -> ProjectionMatrix {
         let mut ret = if self.pivot_before == (0, 0, 0) {
@@ -195,6 +242,19 @@ impl ProjectionMatrixBuilder {
             ret = ret * ProjectionMatrix::rotate(self.pitch, 0.0, 0.0);
         }
 
+        if self.roll.abs() > 1e-20 {
+            ret = ret * ProjectionMatrix::rotate(0.0, 0.0, self.roll);
+        }
+
+        if let Some(fov) = self.fov {
+            // The camera is placed so that the sphere enclosing the coordinate system fits in
+            // the field of view, and the depth of the pivot remains the scale of reference
+            let (x, y, z) = self.pivot_before;
+            let radius = f64::from(x * x + y * y + z * z).sqrt().max(1.0);
+            let half_fov = fov.clamp(1e-3, PI * 0.75) / 2.0;
+            ret = ret * ProjectionMatrix::perspective(radius / half_fov.sin());
+        }
+
         if (self.scale - 1.0).abs() > 1e-20 {
             ret = ret * ProjectionMatrix::scale(self.scale);
         }
@@ -207,3 +267,53 @@ impl ProjectionMatrixBuilder {
         ret
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_perspective_projection() {
+        let mut pb = ProjectionMatrixBuilder::new();
+        pb.set_pivot((100, 100, 100), (500, 500))
+            .camera_angle(0.0, 0.0, 0.0);
+        let ortho = pb.into_matrix();
+        let persp = pb.perspective(PI / 2.0).into_matrix();
+
+        // The pivot is fixed under both projections, up to the rounding
+        let near_pivot = |(x, y): (i32, i32)| (x - 500).abs() <= 1 && (y - 500).abs() <= 1;
+        assert!(near_pivot(ortho * (100, 100, 100)));
+        assert!(near_pivot(persp * (100, 100, 100)));
+
+        // Two points with the same offset, the nearer is farther from the pivot on the screen
+        let (near, far) = ((200, 200, 200), (200, 200, 0));
+        assert!(ortho.projected_depth(near) < ortho.projected_depth(far));
+        assert!(persp.projected_depth(near) < persp.projected_depth(far));
+        assert_eq!(ortho * near, ortho * far);
+        let (near, far) = (persp * near, persp * far);
+        assert!(near.0 > far.0);
+        assert!(far.0 > 500);
+
+        // The depth is only scaled by the perspective, so the faces are sorted the same way
+        let points = [(0, 0, 0), (200, 0, 100), (13, 150, 200), (190, 190, 10)];
+        for a in points.iter().copied() {
+            for b in points.iter().copied() {
+                assert_eq!(
+                    ortho.projected_depth(a).cmp(&ortho.projected_depth(b)),
+                    persp.projected_depth(a).cmp(&persp.projected_depth(b))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_camera_roll() {
+        let mut pb = ProjectionMatrixBuilder::new();
+        pb.set_pivot((0, 0, 0), (0, 0))
+            .camera_angle(0.0, 0.0, PI / 2.0)
+            .scale(2.0);
+        let m = pb.into_matrix();
+        let (x, y) = m * (0.0, 10.0, 0.0);
+        assert_eq!((x.abs(), y.abs()), (20, 0));
+    }
+}
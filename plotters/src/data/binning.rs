@@ -0,0 +1,63 @@
+use super::Quartiles;
+
+/// Suggest a histogram bin width and bin count for the data, using the Freedman–Diaconis rule.
+///
+/// The bin width is `2 * IQR * n^(-1/3)`, and the bin count is the number of bins of this width
+/// needed to cover the range of the data.
+///
+/// When the IQR is zero, for example when all the values are identical, the rule is undefined:
+/// a single bin spanning the whole range of the data is suggested instead. When all the values
+/// are identical, this bin is as wide as the order of magnitude of the value, so that it never
+/// has a zero width.
+///
+/// - `s`: The array of the original values, which must not be empty
+/// - **returns** The suggested bin width and bin count
+///
+/// ```rust
+/// use plotters::data::freedman_diaconis_bins;
+///
+/// let (width, count) = freedman_diaconis_bins(&[1, 2, 3, 4, 5, 6, 7, 8]);
+/// assert_eq!(width, 3.5);
+/// assert_eq!(count, 2);
+/// ```
+pub fn freedman_diaconis_bins<T: Into<f64> + Copy + PartialOrd>(s: &[T]) -> (f64, usize) {
+    let iqr = Quartiles::new(s).iqr();
+    let (min, max) = s
+        .iter()
+        .map(|&v| v.into())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(v), max.max(v))
+        });
+    let range = max - min;
+
+    if range <= 0.0 {
+        return (10f64.powf(min.abs().max(1.0).log10().floor()), 1);
+    }
+
+    if iqr <= 0.0 {
+        return (range, 1);
+    }
+
+    let width = 2.0 * iqr / (s.len() as f64).cbrt();
+    let count = ((range / width).ceil() as usize).max(1);
+    (width, count)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_freedman_diaconis_bins() {
+        let data: Vec<_> = (0..1000).collect();
+        let (width, count) = freedman_diaconis_bins(&data);
+        assert!((width - 99.9).abs() < 1e-9);
+        assert!(count as f64 * width >= 999.0);
+        assert!((count - 1) as f64 * width < 999.0);
+
+        assert_eq!(freedman_diaconis_bins(&[4.0, 4.0, 4.0]), (1.0, 1));
+        assert_eq!(freedman_diaconis_bins(&[-2500.0, -2500.0]), (1000.0, 1));
+        assert_eq!(freedman_diaconis_bins(&[0.25]), (1.0, 1));
+        assert_eq!(freedman_diaconis_bins(&[1, 5, 5, 5, 5, 5, 9]), (8.0, 1));
+    }
+}
@@ -0,0 +1,535 @@
+/*!
+A CSV data source, which reads typed `(X, Y)` data points from two columns of a CSV file.
+
+The columns are selected by their index or, when the file has a header row, by their name.
+The fields are parsed with [`FromCsvField`], which is implemented for the numeric types,
+`String` and, with the `chrono` feature, the usual date and time types; a custom parser can be
+provided with [`CsvSource::columns_with`]. The data points are returned as an iterator, so that
+they can be passed directly to a series.
+
+```rust
+use plotters::data::csv::{CsvSource, MissingValues};
+use plotters::prelude::*;
+
+let csv = "day,temperature\n1,12.5\n2,\n3,14.0\n";
+let points = CsvSource::from_reader(csv.as_bytes())
+    .missing_values(MissingValues::Skip)
+    .columns::<u32, f64>("day", "temperature")
+    .unwrap();
+
+let drawing_area = SVGBackend::new("csv.svg", (300, 200)).into_drawing_area();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0u32..4u32, 10.0..15.0)
+    .unwrap();
+chart.draw_series(LineSeries::new(points, &RED)).unwrap();
+```
+*/
+
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+/// The error raised when the data points can't be read from a CSV source
+#[derive(Debug)]
+pub enum CsvError {
+    /// The source can't be read
+    Io(std::io::Error),
+    /// The requested column doesn't exist in the header row, or there is no header row
+    UnknownColumn(String),
+    /// A row can't be split into the expected fields
+    MalformedRow {
+        /// The line the row starts at
+        line: usize,
+        /// What is wrong with the row
+        message: String,
+    },
+    /// A field is missing, while the missing values are not skipped
+    MissingValue {
+        /// The line of the row
+        line: usize,
+        /// The column of the field
+        column: String,
+    },
+    /// A field can't be parsed to the requested type
+    InvalidValue {
+        /// The line of the row
+        line: usize,
+        /// The column of the field
+        column: String,
+        /// The content of the field
+        value: String,
+        /// The reason the parsing failed
+        message: String,
+    },
+}
+
+impl Display for CsvError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CsvError::Io(e) => write!(fmt, "Cannot read the CSV source: {}", e),
+            CsvError::UnknownColumn(column) => write!(fmt, "Unknown CSV column {:?}", column),
+            CsvError::MalformedRow { line, message } => {
+                write!(fmt, "Malformed CSV row at line {}: {}", line, message)
+            }
+            CsvError::MissingValue { line, column } => {
+                write!(fmt, "Missing value in column {:?} at line {}", column, line)
+            }
+            CsvError::InvalidValue {
+                line,
+                column,
+                value,
+                message,
+            } => write!(
+                fmt,
+                "Invalid value {:?} in column {:?} at line {}: {}",
+                value, column, line, message
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for CsvError {
+    fn from(e: std::io::Error) -> Self {
+        CsvError::Io(e)
+    }
+}
+
+/// What to do with the rows where one of the selected fields is empty
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MissingValues {
+    /// Fail with [`CsvError::MissingValue`]
+    Error,
+    /// Leave the row out of the data points
+    Skip,
+}
+
+/// A column of a CSV source, either by its index, starting at 0, or by its name in the header row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CsvColumn {
+    /// The column at the given index
+    Index(usize),
+    /// The column with the given name in the header row
+    Name(String),
+}
+
+impl From<usize> for CsvColumn {
+    fn from(idx: usize) -> Self {
+        CsvColumn::Index(idx)
+    }
+}
+
+impl From<&str> for CsvColumn {
+    fn from(name: &str) -> Self {
+        CsvColumn::Name(name.to_string())
+    }
+}
+
+impl From<String> for CsvColumn {
+    fn from(name: String) -> Self {
+        CsvColumn::Name(name)
+    }
+}
+
+/// The types which can be parsed from a CSV field
+pub trait FromCsvField: Sized {
+    /// Parse the value from a field, which has its surrounding spaces trimmed
+    /// - `field`: The content of the field
+    /// - **returns** The value, or the reason it can't be parsed
+    fn from_csv_field(field: &str) -> Result<Self, String>;
+}
+
+macro_rules! impl_from_csv_field {
+    ($($t:ty),*) => {
+        $(
+        impl FromCsvField for $t {
+            fn from_csv_field(field: &str) -> Result<Self, String> {
+                field.parse().map_err(|e| format!("{}", e))
+            }
+        }
+        )*
+    };
+}
+
+impl_from_csv_field!(
+    f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, String
+);
+
+#[cfg(feature = "chrono")]
+mod datetime {
+    use super::FromCsvField;
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+
+    /// Parsed from the `%Y-%m-%d` format, e.g. `2021-03-14`
+    impl FromCsvField for NaiveDate {
+        fn from_csv_field(field: &str) -> Result<Self, String> {
+            NaiveDate::parse_from_str(field, "%Y-%m-%d").map_err(|e| format!("{}", e))
+        }
+    }
+
+    /// Parsed from the `%Y-%m-%dT%H:%M:%S` format, e.g. `2021-03-14T15:09:26`, with an
+    /// optional fractional second, and a space rather than the `T` separator allowed
+    impl FromCsvField for NaiveDateTime {
+        fn from_csv_field(field: &str) -> Result<Self, String> {
+            NaiveDateTime::parse_from_str(field, "%Y-%m-%dT%H:%M:%S%.f")
+                .or_else(|_| NaiveDateTime::parse_from_str(field, "%Y-%m-%d %H:%M:%S%.f"))
+                .map_err(|e| format!("{}", e))
+        }
+    }
+
+    /// Parsed from the RFC 3339 format, e.g. `2021-03-14T15:09:26+01:00`
+    impl FromCsvField for DateTime<FixedOffset> {
+        fn from_csv_field(field: &str) -> Result<Self, String> {
+            DateTime::parse_from_rfc3339(field).map_err(|e| format!("{}", e))
+        }
+    }
+
+    /// Parsed from the RFC 3339 format, e.g. `2021-03-14T15:09:26Z`
+    impl FromCsvField for DateTime<Utc> {
+        fn from_csv_field(field: &str) -> Result<Self, String> {
+            DateTime::<FixedOffset>::from_csv_field(field).map(|t| t.with_timezone(&Utc))
+        }
+    }
+}
+
+/**
+A CSV source to read data points from.
+
+The source is configured with the builder methods, then the data points are read from two of
+its columns with [`CsvSource::columns`] or [`CsvSource::columns_with`]. By default, the first
+row is a header row, the fields are separated by commas and a missing value is an error.
+
+See the [module documentation](self) for an example.
+*/
+pub struct CsvSource<R: Read> {
+    reader: R,
+    delimiter: char,
+    has_headers: bool,
+    missing_values: MissingValues,
+}
+
+impl CsvSource<BufReader<File>> {
+    /// Open a CSV file
+    /// - `path`: The path of the file
+    /// - **returns** The CSV source, or the error raised when opening the file
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, CsvError> {
+        Ok(Self::from_reader(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R: Read> CsvSource<R> {
+    /// Create a CSV source from a reader
+    /// - `reader`: The reader of the CSV content
+    /// - **returns** The CSV source
+    pub fn from_reader(reader: R) -> Self {
+        Self {
+            reader,
+            delimiter: ',',
+            has_headers: true,
+            missing_values: MissingValues::Error,
+        }
+    }
+
+    /// Set the character separating the fields, a comma by default
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set if the first row is a header row, naming the columns, which is the default
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Set what to do with the rows where one of the selected fields is empty
+    pub fn missing_values(mut self, policy: MissingValues) -> Self {
+        self.missing_values = policy;
+        self
+    }
+
+    /// Read the data points from two columns, parsing the fields with [`FromCsvField`]
+    /// - `x`: The column of the X values, by index or by name
+    /// - `y`: The column of the Y values, by index or by name
+    /// - **returns** The iterator over the data points, or the first error met
+    pub fn columns<X: FromCsvField, Y: FromCsvField>(
+        self,
+        x: impl Into<CsvColumn>,
+        y: impl Into<CsvColumn>,
+    ) -> Result<std::vec::IntoIter<(X, Y)>, CsvError> {
+        self.columns_with(x, y, X::from_csv_field, Y::from_csv_field)
+    }
+
+    /// Read the data points from two columns, parsing the fields with custom parsers
+    /// - `x`: The column of the X values, by index or by name
+    /// - `y`: The column of the Y values, by index or by name
+    /// - `parse_x`: The parser of the X values, given the trimmed field
+    /// - `parse_y`: The parser of the Y values, given the trimmed field
+    /// - **returns** The iterator over the data points, or the first error met
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use plotters::data::csv::CsvSource;
+    ///
+    /// let csv = "14/03/2021;1.5\n15/03/2021;2.5\n";
+    /// let points: Vec<(NaiveDate, f64)> = CsvSource::from_reader(csv.as_bytes())
+    ///     .has_headers(false)
+    ///     .delimiter(';')
+    ///     .columns_with(0, 1, |d| NaiveDate::parse_from_str(d, "%d/%m/%Y"), |v| v.parse())
+    ///     .unwrap()
+    ///     .collect();
+    /// assert_eq!(points[1], (NaiveDate::from_ymd_opt(2021, 3, 15).unwrap(), 2.5));
+    /// ```
+    pub fn columns_with<X, Y, EX: Display, EY: Display>(
+        mut self,
+        x: impl Into<CsvColumn>,
+        y: impl Into<CsvColumn>,
+        parse_x: impl Fn(&str) -> Result<X, EX>,
+        parse_y: impl Fn(&str) -> Result<Y, EY>,
+    ) -> Result<std::vec::IntoIter<(X, Y)>, CsvError> {
+        let mut content = String::new();
+        self.reader.read_to_string(&mut content)?;
+        let mut rows = split_rows(&content, self.delimiter)?.into_iter();
+
+        let header = if self.has_headers {
+            rows.next().map(|(_, fields)| fields).unwrap_or_default()
+        } else {
+            vec![]
+        };
+        let x = resolve_column(x.into(), &header, self.has_headers)?;
+        let y = resolve_column(y.into(), &header, self.has_headers)?;
+
+        let mut points = vec![];
+        'rows: for (line, fields) in rows {
+            let mut values = [("", &x), ("", &y)];
+            for (value, (idx, name)) in values.iter_mut() {
+                let field = fields.get(*idx).ok_or_else(|| CsvError::MalformedRow {
+                    line,
+                    message: format!(
+                        "found {} fields, while column {:?} is field {}",
+                        fields.len(),
+                        name,
+                        *idx + 1
+                    ),
+                })?;
+                *value = field.trim();
+                if value.is_empty() {
+                    match self.missing_values {
+                        MissingValues::Skip => continue 'rows,
+                        MissingValues::Error => {
+                            return Err(CsvError::MissingValue {
+                                line,
+                                column: name.clone(),
+                            })
+                        }
+                    }
+                }
+            }
+
+            let invalid = |value: &str, name: &str, message: String| CsvError::InvalidValue {
+                line,
+                column: name.to_string(),
+                value: value.to_string(),
+                message,
+            };
+            let [(x_value, (_, x_name)), (y_value, (_, y_name))] = values;
+            let x = parse_x(x_value).map_err(|e| invalid(x_value, x_name, e.to_string()))?;
+            let y = parse_y(y_value).map_err(|e| invalid(y_value, y_name, e.to_string()))?;
+            points.push((x, y));
+        }
+
+        Ok(points.into_iter())
+    }
+}
+
+// Find the index of a column, and the name to report it with
+fn resolve_column(
+    column: CsvColumn,
+    header: &[String],
+    has_headers: bool,
+) -> Result<(usize, String), CsvError> {
+    match column {
+        CsvColumn::Index(idx) => {
+            let name = header
+                .get(idx)
+                .map(|name| name.trim().to_string())
+                .unwrap_or_else(|| format!("#{}", idx));
+            Ok((idx, name))
+        }
+        CsvColumn::Name(name) if has_headers => header
+            .iter()
+            .position(|h| h.trim() == name)
+            .map(|idx| (idx, name.clone()))
+            .ok_or(CsvError::UnknownColumn(name)),
+        CsvColumn::Name(name) => Err(CsvError::UnknownColumn(name)),
+    }
+}
+
+// Split the CSV content into rows of fields, along with the line each row starts at. Fields
+// may be quoted, which allows them to contain delimiters, line breaks and doubled quotes.
+// Blank lines are ignored.
+fn split_rows(content: &str, delimiter: char) -> Result<Vec<(usize, Vec<String>)>, CsvError> {
+    let mut rows = vec![];
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut row_line = 1;
+
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+        } else if c == '"' && field.trim().is_empty() {
+            in_quotes = true;
+            field.clear();
+        } else if c == delimiter {
+            fields.push(std::mem::take(&mut field));
+        } else if c == '\n' || c == '\r' {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+            fields.push(std::mem::take(&mut field));
+            if fields.len() > 1 || !fields[0].trim().is_empty() {
+                rows.push((row_line, std::mem::take(&mut fields)));
+            }
+            fields.clear();
+            line += 1;
+            row_line = line;
+        } else {
+            field.push(c);
+        }
+    }
+
+    if in_quotes {
+        return Err(CsvError::MalformedRow {
+            line: row_line,
+            message: "unterminated quoted field".to_string(),
+        });
+    }
+    if !fields.is_empty() || !field.trim().is_empty() {
+        fields.push(field);
+        rows.push((row_line, fields));
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn read<X: FromCsvField, Y: FromCsvField>(
+        csv: &str,
+        x: impl Into<CsvColumn>,
+        y: impl Into<CsvColumn>,
+    ) -> Result<Vec<(X, Y)>, CsvError> {
+        CsvSource::from_reader(csv.as_bytes())
+            .columns(x, y)
+            .map(Iterator::collect)
+    }
+
+    #[test]
+    fn test_columns() {
+        let csv = "x, label, y\n1, a, 2.5\n2, b, -1e3\n\n3, c, 4\n";
+        let points: Vec<(i32, f64)> = read(csv, "x", "y").unwrap();
+        assert_eq!(points, vec![(1, 2.5), (2, -1000.0), (3, 4.0)]);
+
+        let points: Vec<(String, i32)> = read(csv, 1, 0).unwrap();
+        assert_eq!(points[1], ("b".to_string(), 2));
+
+        let points: Vec<(u8, u8)> = CsvSource::from_reader("1\t2\r\n3\t4".as_bytes())
+            .has_headers(false)
+            .delimiter('\t')
+            .columns(0, 1)
+            .unwrap()
+            .collect();
+        assert_eq!(points, vec![(1, 2), (3, 4)]);
+    }
+
+    #[test]
+    fn test_quoted_fields() {
+        let csv = "name,value\n\"Smith, J.\",1\n\"say \"\"hi\"\"\n twice\",2\n";
+        let points: Vec<(String, u32)> = read(csv, "name", "value").unwrap();
+        assert_eq!(points[0].0, "Smith, J.");
+        assert_eq!(points[1].0, "say \"hi\"\n twice");
+
+        let err = read::<String, u32>("name,value\n\"open,1\n", 0, 1).unwrap_err();
+        assert!(matches!(err, CsvError::MalformedRow { line: 2, .. }));
+    }
+
+    #[test]
+    fn test_missing_values() {
+        let csv = "x,y\n1,2\n2,\n3, \n4,5\n";
+        let err = read::<u32, u32>(csv, "x", "y").unwrap_err();
+        assert_eq!(err.to_string(), "Missing value in column \"y\" at line 3");
+
+        let points: Vec<(u32, u32)> = CsvSource::from_reader(csv.as_bytes())
+            .missing_values(MissingValues::Skip)
+            .columns("x", "y")
+            .unwrap()
+            .collect();
+        assert_eq!(points, vec![(1, 2), (4, 5)]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = read::<u32, u32>("x,y\n1,2\n3\n", "x", "y").unwrap_err();
+        assert!(matches!(err, CsvError::MalformedRow { line: 3, .. }));
+
+        let err = read::<u32, f64>("x,y\n1,2\n2,abc\n", "x", "y").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid value \"abc\" in column \"y\" at line 3: invalid float literal"
+        );
+
+        let err = read::<u32, u32>("x,y\n1,2\n", "x", "z").unwrap_err();
+        assert!(matches!(err, CsvError::UnknownColumn(name) if name == "z"));
+
+        let err = CsvSource::from_reader("1,2\n".as_bytes())
+            .has_headers(false)
+            .columns::<u32, u32>("x", "y")
+            .unwrap_err();
+        assert!(matches!(err, CsvError::UnknownColumn(_)));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_datetime() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let csv = "date,time,value\n2021-03-14,2021-03-14T15:09:26Z,1\n";
+        let points: Vec<(NaiveDate, u32)> = read(csv, "date", "value").unwrap();
+        assert_eq!(points[0].0, NaiveDate::from_ymd_opt(2021, 3, 14).unwrap());
+
+        let points: Vec<(chrono::DateTime<Utc>, u32)> = read(csv, "time", "value").unwrap();
+        assert_eq!(
+            points[0].0,
+            Utc.with_ymd_and_hms(2021, 3, 14, 15, 9, 26).unwrap()
+        );
+
+        let err = read::<NaiveDate, u32>(csv, "time", "value").unwrap_err();
+        assert!(matches!(err, CsvError::InvalidValue { line: 2, .. }));
+    }
+}
@@ -4,6 +4,8 @@ use std::ops::Range;
 
 use num_traits::{One, Zero};
 
+use super::Quartiles;
+
 /// Build a range that fits the data
 ///
 /// - `iter`: the iterator over the data
@@ -40,3 +42,86 @@ where
 
     lb.unwrap_or_else(Zero::zero)..ub.unwrap_or_else(One::one)
 }
+
+/// Build a range that fits the bulk of the data, ignoring the outliers: the range spans a window
+/// of percentiles, such as the 1st to the 99th, and is widened by a margin on both sides. Unlike
+/// [`fitting_range`], a few extreme values don't squash the rest of the data, while the points
+/// out of the range are cut by the clipping of the series, see
+/// [`crate::chart::ChartContext::set_series_clipping()`].
+///
+/// - `values`: The data, which doesn't need to be sorted, the NaN values being ignored
+/// - `lo_pct`: The percentile at the start of the window, within `0..=100`
+/// - `hi_pct`: The percentile at the end of the window, within `0..=100`
+/// - `margin`: The share of the window added on each side, e.g. `0.05` for 5%
+/// - **returns** The resulting range, `0.0..1.0` when there is no value, and one unit wide around
+///   the value when the window holds a single value
+///
+/// ```rust
+/// use plotters::data::range_from_data_robust;
+///
+/// let data = [1.0, 2.0, 3.0, 4.0, 5.0, 1000.0];
+/// let range = range_from_data_robust(&data, 0.0, 80.0, 0.0);
+/// assert_eq!(range, 1.0..5.0);
+/// ```
+pub fn range_from_data_robust<T: Into<f64> + Copy + PartialOrd>(
+    values: &[T],
+    lo_pct: f64,
+    hi_pct: f64,
+    margin: f64,
+) -> Range<f64> {
+    let values: Vec<T> = values
+        .iter()
+        .copied()
+        .filter(|v| v.partial_cmp(v).is_some())
+        .collect();
+    let sorted = match Quartiles::sorted(&values) {
+        Ok(sorted) => sorted,
+        Err(_) => return 0.0..1.0,
+    };
+    let lo = Quartiles::percentile_of_sorted(&sorted, lo_pct);
+    let hi = Quartiles::percentile_of_sorted(&sorted, hi_pct);
+    let (lo, hi) = (lo.min(hi), lo.max(hi));
+    if lo == hi {
+        return lo - 0.5..hi + 0.5;
+    }
+    let margin = (hi - lo) * margin;
+    lo - margin..hi + margin
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_robust_range_ignores_outlier() {
+        // 0 to 99, then a huge outlier
+        let mut data: Vec<f64> = (0..100).map(f64::from).collect();
+        data.push(1e9);
+
+        // The full range is squashed by the outlier, the robust one tracks the window
+        assert_eq!(fitting_range(&data).end, 1e9);
+        let range = range_from_data_robust(&data, 1.0, 99.0, 0.0);
+        assert_eq!(range, 1.0..99.0);
+        let p99 = Quartiles::percentile(&data, 99.0);
+        assert_eq!(range.end, p99);
+
+        // The margin widens the window on both sides
+        let range = range_from_data_robust(&data, 1.0, 99.0, 0.1);
+        assert!((range.start - (1.0 - 9.8)).abs() < 1e-9);
+        assert!((range.end - (99.0 + 9.8)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_robust_range_degenerate() {
+        assert_eq!(range_from_data_robust::<f64>(&[], 1.0, 99.0, 0.1), 0.0..1.0);
+        assert_eq!(
+            range_from_data_robust(&[f64::NAN, 3.0, f64::NAN], 1.0, 99.0, 0.1),
+            2.5..3.5
+        );
+        // The swapped percentiles give the same range
+        assert_eq!(
+            range_from_data_robust(&[1, 2, 3, 4, 5], 75.0, 25.0, 0.0),
+            2.0..4.0
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use super::Quartiles;
+
+/// The method used to choose the bandwidth of a [`KernelDensity`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bandwidth {
+    /// Silverman's rule of thumb: `0.9 * min(σ, IQR / 1.34) * n^(-1/5)`
+    Silverman,
+    /// Scott's rule: `1.06 * σ * n^(-1/5)`
+    Scott,
+    /// A fixed bandwidth
+    Fixed(f64),
+}
+
+/// A kernel density estimation of the samples, using a Gaussian kernel.
+///
+/// This is the building block of violin plots: it gives a smooth estimation of the
+/// probability density of the distribution the samples were drawn from.
+///
+/// ```rust
+/// use plotters::data::{Bandwidth, KernelDensity};
+///
+/// let kde = KernelDensity::new(&[1.0, 2.0, 2.5, 3.0, 7.0], Bandwidth::Silverman);
+/// assert!(kde.evaluate(2.5) > kde.evaluate(5.0));
+/// let curve = kde.sample_range(100);
+/// assert_eq!(curve.len(), 100);
+/// ```
+#[derive(Clone, Debug)]
+pub struct KernelDensity {
+    samples: Vec<f64>,
+    bandwidth: f64,
+}
+
+impl KernelDensity {
+    /// Create a new kernel density estimation from the samples.
+    ///
+    /// When the rule of thumb gives a null bandwidth, e.g. when all the samples are identical,
+    /// a bandwidth of 1 is used instead.
+    ///
+    /// - `samples`: The samples, which must not be empty
+    /// - `bandwidth`: The method used to choose the bandwidth
+    /// - **returns** The newly created kernel density estimation
+    pub fn new(samples: &[f64], bandwidth: Bandwidth) -> Self {
+        assert!(!samples.is_empty());
+        let n = samples.len() as f64;
+        let mean = Quartiles::mean(samples);
+        let std_dev = (samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+
+        let bandwidth = match bandwidth {
+            Bandwidth::Silverman => {
+                let iqr = Quartiles::new(samples).iqr();
+                let spread = if iqr > 0.0 {
+                    std_dev.min(iqr / 1.34)
+                } else {
+                    std_dev
+                };
+                0.9 * spread * n.powf(-0.2)
+            }
+            Bandwidth::Scott => 1.06 * std_dev * n.powf(-0.2),
+            Bandwidth::Fixed(bandwidth) => bandwidth,
+        };
+
+        Self {
+            samples: samples.to_owned(),
+            bandwidth: if bandwidth > 0.0 { bandwidth } else { 1.0 },
+        }
+    }
+
+    /// Get the bandwidth of the kernel.
+    ///
+    /// - **returns** The bandwidth
+    pub fn bandwidth(&self) -> f64 {
+        self.bandwidth
+    }
+
+    /// Evaluate the estimated density at a given point.
+    ///
+    /// - `x`: The point to evaluate the density at
+    /// - **returns** The estimated density
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let norm = (2.0 * std::f64::consts::PI).sqrt() * self.bandwidth * self.samples.len() as f64;
+        self.samples
+            .iter()
+            .map(|s| (-0.5 * ((x - s) / self.bandwidth).powi(2)).exp())
+            .sum::<f64>()
+            / norm
+    }
+
+    /// Evaluate the estimated density on evenly spaced points covering the samples.
+    ///
+    /// The points span from 3 bandwidths below the smallest sample to 3 bandwidths above
+    /// the largest one, so that the tails of the density are included.
+    ///
+    /// - `n`: The number of points
+    /// - **returns** The `(x, density)` pairs
+    pub fn sample_range(&self, n: usize) -> Vec<(f64, f64)> {
+        let (min, max) = self
+            .samples
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+                (min.min(v), max.max(v))
+            });
+        let (start, end) = (min - 3.0 * self.bandwidth, max + 3.0 * self.bandwidth);
+        let step = if n > 1 {
+            (end - start) / (n - 1) as f64
+        } else {
+            0.0
+        };
+        (0..n)
+            .map(|i| {
+                let x = start + step * i as f64;
+                (x, self.evaluate(x))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_density_integrates_to_one() {
+        let samples = [1.0, 2.0, 2.5, 3.0, 3.2, 4.0, 7.0, 7.5, 10.0];
+        for bandwidth in [
+            Bandwidth::Silverman,
+            Bandwidth::Scott,
+            Bandwidth::Fixed(0.5),
+        ] {
+            let kde = KernelDensity::new(&samples, bandwidth);
+            let (start, end, n) = (-50.0, 60.0, 10_000);
+            let step = (end - start) / n as f64;
+            let integral: f64 = (0..n)
+                .map(|i| kde.evaluate(start + step * (i as f64 + 0.5)) * step)
+                .sum();
+            assert!((integral - 1.0).abs() < 1e-6, "{}", integral);
+        }
+    }
+
+    #[test]
+    fn test_bandwidth() {
+        let kde = KernelDensity::new(&[1.0, 2.0, 3.0], Bandwidth::Fixed(0.25));
+        assert_eq!(kde.bandwidth(), 0.25);
+
+        let kde = KernelDensity::new(&[4.0, 4.0, 4.0], Bandwidth::Silverman);
+        assert_eq!(kde.bandwidth(), 1.0);
+
+        let samples = [1.0, 2.0, 3.0, 4.0];
+        let std_dev = 1.25f64.sqrt();
+        let kde = KernelDensity::new(&samples, Bandwidth::Scott);
+        assert!((kde.bandwidth() - 1.06 * std_dev * 4f64.powf(-0.2)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sample_range() {
+        let kde = KernelDensity::new(&[0.0, 10.0], Bandwidth::Fixed(1.0));
+        let curve = kde.sample_range(17);
+        assert_eq!(curve.len(), 17);
+        assert_eq!(curve[0].0, -3.0);
+        assert_eq!(curve[16].0, 13.0);
+        assert!(curve[8].1 < curve[3].1);
+    }
+}
@@ -4,10 +4,35 @@ Such as, down-sampling, etc.
 */
 
 mod data_range;
-pub use data_range::fitting_range;
+pub use data_range::{fitting_range, range_from_data_robust};
+
+mod binning;
+pub use binning::freedman_diaconis_bins;
+
+mod kde;
+pub use kde::{Bandwidth, KernelDensity};
 
 mod quartiles;
-pub use quartiles::Quartiles;
+pub use quartiles::{QuartileMethod, Quartiles, QuartilesError};
+
+mod quartiles_estimator;
+pub use quartiles_estimator::QuartilesEstimator;
+
+mod regression;
+pub use regression::{linear_fit, polynomial_fit, r_squared, FitError};
+
+#[cfg(feature = "chrono")]
+mod resample;
+#[cfg(feature = "chrono")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+pub use resample::{resample, Aggregation, Period};
+
+mod smoothing;
+pub use smoothing::{exponential_moving_average, moving_average};
 
 /// Handles the printing of floating-point numbers.
 pub mod float;
+
+#[cfg(feature = "csv")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "csv")))]
+pub mod csv;
@@ -1,17 +1,121 @@
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// The error raised when the quartiles can't be computed from the input values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuartilesError {
+    /// There is no value to compute the quartiles from
+    Empty,
+    /// At least one of the values is not comparable, e.g. a NaN
+    ContainsNaN,
+}
+
+impl std::fmt::Display for QuartilesError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            QuartilesError::Empty => write!(fmt, "Cannot compute the quartiles of no value"),
+            QuartilesError::ContainsNaN => {
+                write!(fmt, "Cannot compute the quartiles of values containing NaN")
+            }
+        }
+    }
+}
+
+impl std::error::Error for QuartilesError {}
+
+/// The method used to compute the quartiles and their fences, which can be chosen at runtime,
+/// see [`Quartiles::with_method`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuartileMethod {
+    /// The quartiles are interpolated between the values, and the fences are 1.5 IQR away from
+    /// the box, as computed by [`Quartiles::new`]
+    Tukey,
+    /// The quartile `p` is interpolated at the rank `p (n + 1) / 4` over the whole sample, and
+    /// the fences are the extrema of the values, as computed by [`Quartiles::real`]
+    Exclusive,
+    /// The fences are 1.5 IQR away from the box, but within the extrema, as computed by
+    /// [`Quartiles::fair`]
+    Fair,
+}
+
+// Deriving the default variant needs a more recent compiler than the minimum supported one
+#[allow(clippy::derivable_impls)]
+impl Default for QuartileMethod {
+    fn default() -> Self {
+        QuartileMethod::Tukey
+    }
+}
+
 /// The quartiles
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Serialize, Deserialize),
+    serde(try_from = "UncheckedQuartiles")
+)]
 pub struct Quartiles {
     lower_fence: f64,
     lower: f64,
     median: f64,
     upper: f64,
     upper_fence: f64,
+    count: usize,
+}
+
+// The deserialized quartiles, before checking they are ordered
+#[cfg(feature = "serialization")]
+#[derive(Deserialize)]
+struct UncheckedQuartiles {
+    lower_fence: f64,
+    lower: f64,
+    median: f64,
+    upper: f64,
+    upper_fence: f64,
+    #[serde(default)]
+    count: usize,
+}
+
+#[cfg(feature = "serialization")]
+impl std::convert::TryFrom<UncheckedQuartiles> for Quartiles {
+    type Error = &'static str;
+
+    fn try_from(q: UncheckedQuartiles) -> Result<Self, Self::Error> {
+        if q.lower_fence <= q.lower
+            && q.lower <= q.median
+            && q.median <= q.upper
+            && q.upper <= q.upper_fence
+        {
+            Ok(Self {
+                lower_fence: q.lower_fence,
+                lower: q.lower,
+                median: q.median,
+                upper: q.upper,
+                upper_fence: q.upper_fence,
+                count: q.count,
+            })
+        } else {
+            Err("the quartiles must satisfy lower_fence <= lower <= median <= upper <= upper_fence")
+        }
+    }
 }
 
 impl Quartiles {
+    // Copy and sort `s`, checking the values can be ordered.
+    pub(crate) fn sorted<T: Copy + PartialOrd>(s: &[T]) -> Result<Vec<T>, QuartilesError> {
+        if s.is_empty() {
+            return Err(QuartilesError::Empty);
+        }
+        if s.iter().any(|v| v.partial_cmp(v).is_none()) {
+            return Err(QuartilesError::ContainsNaN);
+        }
+        let mut s = s.to_owned();
+        s.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        Ok(s)
+    }
+
     // Extract a value representing the `pct` percentile of a
     // sorted `s`, using linear interpolation.
-    fn percentile_of_sorted<T: Into<f64> + Copy>(s: &[T], pct: f64) -> f64 {
+    pub(crate) fn percentile_of_sorted<T: Into<f64> + Copy>(s: &[T], pct: f64) -> f64 {
         assert!(!s.is_empty());
         if s.len() == 1 {
             return s[0].into();
@@ -32,6 +136,24 @@ impl Quartiles {
         lo + (hi - lo) * d
     }
 
+    /// Compute an arbitrary percentile of the argument, using linear interpolation.
+    ///
+    /// - `s`: The array of the original values, it doesn't need to be sorted
+    /// - `pct`: The percentile to compute, must be within `0..=100`
+    /// - **returns** The value of the `pct` percentile
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let p90 = Quartiles::percentile(&[41, 7, 39, 15, 40, 36], 90.0);
+    /// assert_eq!(p90, 40.5);
+    /// ```
+    pub fn percentile<T: Into<f64> + Copy + PartialOrd>(s: &[T], pct: f64) -> f64 {
+        let mut s = s.to_owned();
+        s.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        Quartiles::percentile_of_sorted(&s, pct)
+    }
+
     /// Create a new quartiles struct with the values calculated from the argument.
     ///
     /// - `s`: The array of the original values
@@ -44,12 +166,36 @@ impl Quartiles {
     /// assert_eq!(quartiles.median(), 37.5);
     /// ```
     pub fn new<T: Into<f64> + Copy + PartialOrd>(s: &[T]) -> Self {
-        let mut s = s.to_owned();
-        s.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        Quartiles::with_method(s, QuartileMethod::Tukey)
+    }
+
+    /// Try to create a new quartiles struct with the values calculated from the argument.
+    ///
+    /// This is the fallible version of [`new`](Self::new).
+    ///
+    /// - `s`: The array of the original values
+    /// - **returns** The newly created quartiles, or an error if `s` is empty or contains NaN
+    ///
+    /// ```rust
+    /// use plotters::data::QuartilesError;
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::try_new(&[7, 15, 36, 39, 40, 41]).unwrap();
+    /// assert_eq!(quartiles.median(), 37.5);
+    /// assert_eq!(Quartiles::try_new(&[1.0, f64::NAN]), Err(QuartilesError::ContainsNaN));
+    /// ```
+    pub fn try_new<T: Into<f64> + Copy + PartialOrd>(s: &[T]) -> Result<Self, QuartilesError> {
+        let s = Quartiles::sorted(s)?;
 
         let lower = Quartiles::percentile_of_sorted(&s, 25_f64);
         let median = Quartiles::percentile_of_sorted(&s, 50_f64);
         let upper = Quartiles::percentile_of_sorted(&s, 75_f64);
+        Ok(Quartiles::with_tukey_fences(lower, median, upper, s.len()))
+    }
+
+    // Build the quartiles of `count` values from the lower quartile, the median and the
+    // upper quartile, placing the fences 1.5 IQR away from the box.
+    pub(crate) fn with_tukey_fences(lower: f64, median: f64, upper: f64, count: usize) -> Self {
         let iqr = upper - lower;
         let lower_fence = lower - 1.5 * iqr;
         let upper_fence = upper + 1.5 * iqr;
@@ -59,12 +205,63 @@ impl Quartiles {
             median,
             upper,
             upper_fence,
+            count,
         }
     }
 
+    /// Compute the arithmetic mean of the argument.
+    ///
+    /// - `s`: The array of the original values, which must not be empty
+    /// - **returns** The mean
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// assert_eq!(Quartiles::mean(&[7, 15, 36, 39, 40, 41]), 29.666666666666668);
+    /// ```
+    pub fn mean<T: Into<f64> + Copy>(s: &[T]) -> f64 {
+        assert!(!s.is_empty());
+        s.iter().map(|&v| v.into()).sum::<f64>() / s.len() as f64
+    }
+
+    /// Create a new quartiles struct with the values calculated from the argument,
+    /// alongside the mean of the values.
+    ///
+    /// - `s`: The array of the original values
+    /// - **returns** The newly created quartiles and the mean
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let (quartiles, mean) = Quartiles::new_with_mean(&[1, 2, 3, 4, 100]);
+    /// assert_eq!(quartiles.median(), 3.0);
+    /// assert_eq!(mean, 22.0);
+    /// ```
+    pub fn new_with_mean<T: Into<f64> + Copy + PartialOrd>(s: &[T]) -> (Self, f64) {
+        (Quartiles::new(s), Quartiles::mean(s))
+    }
+
+    /// Create a new quartiles struct with the values calculated from the argument,
+    /// alongside the outliers lying strictly beyond its fences.
+    ///
+    /// - `s`: The array of the original values
+    /// - **returns** The newly created quartiles and the outliers, in the order of `s`
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let (quartiles, outliers) = Quartiles::new_with_outliers(&[-50, 7, 15, 36, 39, 40, 41]);
+    /// assert_eq!(quartiles.median(), 36.0);
+    /// assert_eq!(outliers, [-50.0]);
+    /// ```
+    pub fn new_with_outliers<T: Into<f64> + Copy + PartialOrd>(s: &[T]) -> (Self, Vec<f64>) {
+        let quartiles = Quartiles::new(s);
+        let outliers = quartiles.outliers(s);
+        (quartiles, outliers)
+    }
 
     /// Create a new quartiles struct with the values calculated from the argument.
-    /// 
+    ///
     /// Unlike [`new`](Self::new), the wiskers, are the extrema
     ///
     /// - `s`: The array of the original values
@@ -77,44 +274,62 @@ impl Quartiles {
     /// assert_eq!(quartiles.median(), 37.5);
     /// ```
     pub fn real<T: Into<f64> + Copy + PartialOrd>(s: &[T]) -> Self {
-        let mut s = s.to_owned();
-        s.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        Quartiles::with_method(s, QuartileMethod::Exclusive)
+    }
+
+    /// Try to create a new quartiles struct with the values calculated from the argument.
+    ///
+    /// This is the fallible version of [`real`](Self::real).
+    ///
+    /// - `s`: The array of the original values
+    /// - **returns** The newly created quartiles, or an error if `s` is empty or contains NaN
+    ///
+    /// ```rust
+    /// use plotters::data::QuartilesError;
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::try_real(&[7, 15, 36, 39, 40, 41]).unwrap();
+    /// assert_eq!(quartiles.median(), 37.5);
+    /// assert_eq!(Quartiles::try_real::<f64>(&[]), Err(QuartilesError::Empty));
+    /// ```
+    pub fn try_real<T: Into<f64> + Copy + PartialOrd>(s: &[T]) -> Result<Self, QuartilesError> {
+        let s = Quartiles::sorted(s)?;
 
         let quartile = |p: f64| -> f64 {
-            let n = (s.len() + 1) as f64 ;
+            let n = (s.len() + 1) as f64;
             let alpha = p * n / 4.0;
             let k = alpha.floor();
             let alpha = alpha - k;
             let k = k as usize;
             if k == 0 {
-                return s[0].into()
+                return s[0].into();
             }
             let k = k - 1;
-            
+
             if k >= s.len() - 1 {
                 s[s.len() - 1].into()
             } else {
-                s[k].into() + alpha * (s[k+1].into() - s[k].into())
+                s[k].into() + alpha * (s[k + 1].into() - s[k].into())
             }
         };
 
-        
         let lower_fence = quartile(0_f64);
         let lower = quartile(1_f64);
         let median = quartile(2_f64);
         let upper = quartile(3_f64);
         let upper_fence = quartile(4_f64);
-        Self {
+        Ok(Self {
             lower_fence,
             lower,
             median,
             upper,
             upper_fence,
-        }
+            count: s.len(),
+        })
     }
 
     /// Create a new quartiles struct with the values calculated from the argument.
-    /// 
+    ///
     /// This is a mix of [`new`](Self::new) and [`real`](`Self::real`):<br>
     /// The lower fence is the max of the lower fences while the upper fence is the min of the upper fences
     ///
@@ -128,27 +343,45 @@ impl Quartiles {
     /// assert_eq!(quartiles.median(), 37.5);
     /// ```
     pub fn fair<T: Into<f64> + Copy + PartialOrd>(s: &[T]) -> Self {
-        let mut s = s.to_owned();
-        s.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        Quartiles::with_method(s, QuartileMethod::Fair)
+    }
+
+    /// Try to create a new quartiles struct with the values calculated from the argument.
+    ///
+    /// This is the fallible version of [`fair`](Self::fair).
+    ///
+    /// - `s`: The array of the original values
+    /// - **returns** The newly created quartiles, or an error if `s` is empty or contains NaN
+    ///
+    /// ```rust
+    /// use plotters::data::QuartilesError;
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::try_fair(&[7, 15, 36, 39, 40, 41]).unwrap();
+    /// assert_eq!(quartiles.median(), 37.5);
+    /// assert_eq!(Quartiles::try_fair::<f64>(&[]), Err(QuartilesError::Empty));
+    /// ```
+    pub fn try_fair<T: Into<f64> + Copy + PartialOrd>(s: &[T]) -> Result<Self, QuartilesError> {
+        let s = Quartiles::sorted(s)?;
 
         let quartile = |p: f64| -> f64 {
-            let n = (s.len() + 1) as f64 ;
+            let n = (s.len() + 1) as f64;
             let alpha = p * n / 4.0;
             let k = alpha.floor();
             let alpha = alpha - k;
             let k = k as usize;
             if k == 0 {
-                return s[0].into()
+                return s[0].into();
             }
             let k = k - 1;
-            
+
             if k >= s.len() - 1 {
                 s[s.len() - 1].into()
             } else {
-                s[k].into() + alpha * (s[k+1].into() - s[k].into())
+                s[k].into() + alpha * (s[k + 1].into() - s[k].into())
             }
         };
-        
+
         let lower = quartile(1_f64);
         let median = quartile(2_f64);
         let upper = quartile(3_f64);
@@ -157,12 +390,114 @@ impl Quartiles {
         let lower_fence = lower_fence.max(quartile(0_f64));
         let upper_fence = upper + 1.5 * iqr;
         let upper_fence = upper_fence.min(quartile(4_f64));
-        Self {
+        Ok(Self {
             lower_fence,
             lower,
             median,
             upper,
             upper_fence,
+            count: s.len(),
+        })
+    }
+
+    /// Create a new quartiles struct with the values calculated from the argument, with a method
+    /// chosen at runtime, such as from a configuration value.
+    ///
+    /// - `s`: The array of the original values
+    /// - `method`: The method computing the quartiles and their fences
+    /// - **returns** The newly created quartiles
+    ///
+    /// ```rust
+    /// use plotters::data::QuartileMethod;
+    /// use plotters::prelude::*;
+    ///
+    /// let method = match "fair" {
+    ///     "exclusive" => QuartileMethod::Exclusive,
+    ///     "fair" => QuartileMethod::Fair,
+    ///     _ => QuartileMethod::Tukey,
+    /// };
+    /// let quartiles = Quartiles::with_method(&[7, 15, 36, 39, 40, 41], method);
+    /// assert_eq!(quartiles.median(), 37.5);
+    /// ```
+    pub fn with_method<T: Into<f64> + Copy + PartialOrd>(s: &[T], method: QuartileMethod) -> Self {
+        Quartiles::try_with_method(s, method).unwrap()
+    }
+
+    /// Try to create a new quartiles struct with the values calculated from the argument, with
+    /// a method chosen at runtime.
+    ///
+    /// This is the fallible version of [`with_method`](Self::with_method).
+    ///
+    /// - `s`: The array of the original values
+    /// - `method`: The method computing the quartiles and their fences
+    /// - **returns** The newly created quartiles, or an error if `s` is empty or contains NaN
+    ///
+    /// ```rust
+    /// use plotters::data::QuartileMethod;
+    /// use plotters::prelude::*;
+    ///
+    /// let data = [7, 15, 36, 39, 40, 41];
+    /// let quartiles = Quartiles::try_with_method(&data, QuartileMethod::Exclusive).unwrap();
+    /// assert_eq!(quartiles, Quartiles::real(&data));
+    /// ```
+    pub fn try_with_method<T: Into<f64> + Copy + PartialOrd>(
+        s: &[T],
+        method: QuartileMethod,
+    ) -> Result<Self, QuartilesError> {
+        match method {
+            QuartileMethod::Tukey => Quartiles::try_new(s),
+            QuartileMethod::Exclusive => Quartiles::try_real(s),
+            QuartileMethod::Fair => Quartiles::try_fair(s),
+        }
+    }
+
+    /// Merge several quartiles computed on disjoint parts of a dataset.
+    ///
+    /// An exact merge is impossible without the original values, so the result is an
+    /// approximation: the lower quartile, the median and the upper quartile are the averages of
+    /// those of the parts, weighted by their sample counts, while the fences are the extrema of
+    /// the fences of the parts. The approximation is good when the parts are drawn from similar
+    /// distributions, and gets worse as they diverge.
+    ///
+    /// - `parts`: The quartiles of each part, with the number of values they were computed from
+    /// - **returns** The approximated quartiles of the whole dataset
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let a = Quartiles::new(&[1, 2, 3, 4, 5]);
+    /// let b = Quartiles::new(&[3, 4, 5, 6, 7, 8, 9]);
+    /// let merged = Quartiles::merge(&[(a, 5), (b, 7)]);
+    /// assert_eq!(merged.median(), 4.75);
+    /// assert_eq!(merged.count(), 12);
+    /// ```
+    pub fn merge(parts: &[(Quartiles, usize)]) -> Self {
+        let total: usize = parts.iter().map(|(_, count)| count).sum();
+        assert!(total > 0);
+
+        let weighted = |value: fn(&Quartiles) -> f64| -> f64 {
+            parts
+                .iter()
+                .map(|(q, count)| value(q) * *count as f64)
+                .sum::<f64>()
+                / total as f64
+        };
+        let lower_fence = parts
+            .iter()
+            .map(|(q, _)| q.lower_fence)
+            .fold(f64::INFINITY, f64::min);
+        let upper_fence = parts
+            .iter()
+            .map(|(q, _)| q.upper_fence)
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        Self {
+            lower_fence,
+            lower: weighted(|q| q.lower),
+            median: weighted(|q| q.median),
+            upper: weighted(|q| q.upper),
+            upper_fence,
+            count: total,
         }
     }
 
@@ -178,15 +513,116 @@ impl Quartiles {
     /// assert_eq!(values, [-9.0, 20.25, 37.5, 39.75, 69.0]);
     /// ```
     pub fn values(&self) -> [f32; 5] {
+        self.values_f64().map(|v| v as f32)
+    }
+
+    /// Get the quartiles values with full precision.
+    ///
+    /// - **returns** The array [lower fence, lower quartile, median, upper quartile, upper fence]
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let values = quartiles.values_f64();
+    /// assert_eq!(values, [-9.0, 20.25, 37.5, 39.75, 69.0]);
+    /// ```
+    pub fn values_f64(&self) -> [f64; 5] {
         [
-            self.lower_fence as f32,
-            self.lower as f32,
-            self.median as f32,
-            self.upper as f32,
-            self.upper_fence as f32,
+            self.lower_fence,
+            self.lower,
+            self.median,
+            self.upper,
+            self.upper_fence,
         ]
     }
 
+    /// Get the interquartile range, i.e. the distance between the lower and upper quartiles.
+    ///
+    /// - **returns** The interquartile range
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// assert_eq!(quartiles.iqr(), 19.5);
+    /// ```
+    pub fn iqr(&self) -> f64 {
+        self.upper - self.lower
+    }
+
+    /// Get the distance between the lower and upper fences.
+    ///
+    /// - **returns** The range covered by the whiskers
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// assert_eq!(quartiles.range(), 78.0);
+    /// ```
+    pub fn range(&self) -> f64 {
+        self.upper_fence - self.lower_fence
+    }
+
+    /// Get the number of values the quartiles were calculated from.
+    ///
+    /// - **returns** The sample count
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// assert_eq!(quartiles.count(), 6);
+    /// ```
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the bounds of the notch, i.e. the 95% confidence interval of the median,
+    /// computed as `median ± 1.58 * IQR / sqrt(n)`.
+    ///
+    /// The bounds are clamped to the lower and upper quartiles.
+    ///
+    /// - **returns** The lower and upper bounds of the notch
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    /// let (lower, upper) = quartiles.notch_bounds();
+    /// assert!((lower - 5.5375).abs() < 1e-9);
+    /// assert!((upper - 11.4625).abs() < 1e-9);
+    /// ```
+    pub fn notch_bounds(&self) -> (f64, f64) {
+        let half_width = 1.58 * self.iqr() / (self.count as f64).sqrt();
+        (
+            (self.median - half_width).max(self.lower),
+            (self.median + half_width).min(self.upper),
+        )
+    }
+
+    /// Get the values of the argument lying strictly beyond the fences.
+    ///
+    /// Values exactly on a fence are not considered as outliers.
+    ///
+    /// - `s`: The array of the original values
+    /// - **returns** The outliers, in the order of `s`
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let data = [-50, 7, 15, 36, 39, 40, 41, 100];
+    /// let quartiles = Quartiles::new(&data);
+    /// assert_eq!(quartiles.outliers(&data), [-50.0, 100.0]);
+    /// ```
+    pub fn outliers<T: Into<f64> + Copy>(&self, s: &[T]) -> Vec<f64> {
+        s.iter()
+            .map(|&v| v.into())
+            .filter(|&v| v < self.lower_fence || v > self.upper_fence)
+            .collect()
+    }
+
     /// Get the quartiles median.
     ///
     /// - **returns** The median
@@ -200,6 +636,62 @@ impl Quartiles {
     pub fn median(&self) -> f64 {
         self.median
     }
+
+    /// Get the quartiles lower fence.
+    ///
+    /// - **returns** The lower fence
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// assert_eq!(quartiles.lower_fence(), -9.0);
+    /// ```
+    pub fn lower_fence(&self) -> f64 {
+        self.lower_fence
+    }
+
+    /// Get the quartiles lower quartile.
+    ///
+    /// - **returns** The lower quartile
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// assert_eq!(quartiles.lower(), 20.25);
+    /// ```
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// Get the quartiles upper quartile.
+    ///
+    /// - **returns** The upper quartile
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// assert_eq!(quartiles.upper(), 39.75);
+    /// ```
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    /// Get the quartiles upper fence.
+    ///
+    /// - **returns** The upper fence
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// assert_eq!(quartiles.upper_fence(), 69.0);
+    /// ```
+    pub fn upper_fence(&self) -> f64 {
+        self.upper_fence
+    }
 }
 
 #[cfg(test)]
@@ -213,6 +705,45 @@ mod test {
         Quartiles::new(&empty_array);
     }
 
+    #[test]
+    fn test_try_new_errors() {
+        let empty_array: [i32; 0] = [];
+        assert_eq!(Quartiles::try_new(&empty_array), Err(QuartilesError::Empty));
+        assert_eq!(
+            Quartiles::try_real(&empty_array),
+            Err(QuartilesError::Empty)
+        );
+        assert_eq!(
+            Quartiles::try_fair(&empty_array),
+            Err(QuartilesError::Empty)
+        );
+
+        let nan_array = [1.0, f64::NAN, 3.0];
+        assert_eq!(
+            Quartiles::try_new(&nan_array),
+            Err(QuartilesError::ContainsNaN)
+        );
+        assert_eq!(
+            Quartiles::try_real(&nan_array),
+            Err(QuartilesError::ContainsNaN)
+        );
+        assert_eq!(
+            Quartiles::try_fair(&nan_array),
+            Err(QuartilesError::ContainsNaN)
+        );
+
+        assert_eq!(
+            Quartiles::try_new(&[7, 15, 36, 39, 40, 41]),
+            Ok(Quartiles::new(&[7, 15, 36, 39, 40, 41]))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nan_input() {
+        Quartiles::new(&[1.0, f64::NAN]);
+    }
+
     #[test]
     fn test_low_inputs() {
         assert_eq!(
@@ -252,4 +783,192 @@ mod test {
             [6.0, 15.0, 40.0, 43.0, 49.0]
         );
     }
+
+    #[test]
+    fn test_percentile() {
+        let data = [40, 7, 15, 41, 36, 39];
+        assert_eq!(Quartiles::percentile(&data, 0.0), 7.0);
+        assert_eq!(Quartiles::percentile(&data, 50.0), 37.5);
+        assert_eq!(Quartiles::percentile(&data, 100.0), 41.0);
+        assert_eq!(Quartiles::percentile(&[15.0], 95.0), 15.0);
+        assert_eq!(
+            Quartiles::percentile(&data, 25.0),
+            Quartiles::new(&data).lower()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_percentile_out_of_range() {
+        Quartiles::percentile(&[1, 2, 3], 101.0);
+    }
+
+    #[test]
+    fn test_outliers() {
+        let data = [1, 2, 3, 4, 5];
+        let (quartiles, outliers) = Quartiles::new_with_outliers(&data);
+        assert!(outliers.is_empty());
+        assert_eq!(quartiles.values(), [-1.0, 2.0, 3.0, 4.0, 7.0]);
+
+        // Values exactly on a fence aren't outliers
+        assert!(quartiles.outliers(&[-1, 7]).is_empty());
+        assert_eq!(quartiles.outliers(&[-2, -1, 3, 7, 8]), [-2.0, 8.0]);
+
+        // The fences of `real` are the extrema, so there's never any outlier
+        assert!(Quartiles::real(&[-100, 1, 2, 3, 100])
+            .outliers(&[-100, 1, 2, 3, 100])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_with_method() {
+        let inputs: [&[i32]; 3] = [&[-100, 1, 2, 3, 4, 100], &[7, 15, 36, 39, 40, 41], &[5]];
+        for data in inputs.iter() {
+            // Each variant matches its named constructor, and the implementation behind it
+            for (method, named, implementation) in [
+                (
+                    QuartileMethod::Tukey,
+                    Quartiles::new(data),
+                    Quartiles::try_new(data),
+                ),
+                (
+                    QuartileMethod::Exclusive,
+                    Quartiles::real(data),
+                    Quartiles::try_real(data),
+                ),
+                (
+                    QuartileMethod::Fair,
+                    Quartiles::fair(data),
+                    Quartiles::try_fair(data),
+                ),
+            ] {
+                assert_eq!(Quartiles::with_method(data, method), named);
+                assert_eq!(Quartiles::try_with_method(data, method), implementation);
+                assert_eq!(Ok(named), implementation);
+            }
+        }
+
+        // The methods differ on the quartiles, or on the fences
+        let data = [1, 2, 3, 4, 5, 6, 7, 8, 100];
+        assert_ne!(
+            Quartiles::with_method(&data, QuartileMethod::Tukey),
+            Quartiles::with_method(&data, QuartileMethod::Exclusive)
+        );
+        assert_ne!(
+            Quartiles::with_method(&data, QuartileMethod::Exclusive),
+            Quartiles::with_method(&data, QuartileMethod::Fair)
+        );
+
+        assert_eq!(QuartileMethod::default(), QuartileMethod::Tukey);
+        assert_eq!(
+            Quartiles::try_with_method::<f64>(&[], QuartileMethod::Fair),
+            Err(QuartilesError::Empty)
+        );
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_serialization_roundtrip() {
+        let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+        let json = serde_json::to_string(&quartiles).unwrap();
+        assert_eq!(
+            json,
+            r#"{"lower_fence":-9.0,"lower":20.25,"median":37.5,"upper":39.75,"upper_fence":69.0,"count":6}"#
+        );
+        let deserialized: Quartiles = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, quartiles);
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn test_deserialize_unordered() {
+        let json =
+            r#"{"lower_fence":-9.0,"lower":40.0,"median":37.5,"upper":39.75,"upper_fence":69.0}"#;
+        assert!(serde_json::from_str::<Quartiles>(json).is_err());
+    }
+
+    #[test]
+    fn test_notch_bounds() {
+        let quartiles = Quartiles::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+        assert_eq!(quartiles.count(), 16);
+        let (lo, hi) = quartiles.notch_bounds();
+        assert_eq!(lo, 8.5 - 1.58 * 7.5 / 4.0);
+        assert_eq!(hi, 8.5 + 1.58 * 7.5 / 4.0);
+
+        // With few values, the notch is clamped to the box
+        let quartiles = Quartiles::new(&[1, 2, 3]);
+        assert_eq!(
+            quartiles.notch_bounds(),
+            (quartiles.lower(), quartiles.upper())
+        );
+    }
+
+    #[test]
+    fn test_mean() {
+        assert_eq!(Quartiles::mean(&[15.0]), 15.0);
+        assert_eq!(Quartiles::mean(&[10, 20, 30, 40]), 25.0);
+        let (quartiles, mean) = Quartiles::new_with_mean(&[1, 1, 1, 1, 16]);
+        assert_eq!(quartiles.median(), 1.0);
+        assert_eq!(mean, 4.0);
+    }
+
+    #[test]
+    fn test_merge() {
+        // Shards of a same (pseudo-random) distribution
+        let mut state = 42_u32;
+        let data: Vec<f64> = (0..6000)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                f64::from(state >> 16) / 65536.0 * 100.0
+            })
+            .collect();
+        let parts: Vec<_> = data
+            .chunks(1000)
+            .map(|chunk| (Quartiles::new(chunk), chunk.len()))
+            .collect();
+
+        let merged = Quartiles::merge(&parts);
+        let exact = Quartiles::new(&data);
+        assert_eq!(merged.count(), 6000);
+        // The quartiles are within 2% of the IQR of the exact ones
+        for (m, e) in [
+            (merged.lower(), exact.lower()),
+            (merged.median(), exact.median()),
+            (merged.upper(), exact.upper()),
+        ] {
+            assert!((m - e).abs() < 0.02 * exact.iqr(), "{} vs {}", m, e);
+        }
+        // The fences are the most extreme ones, so they're at least as wide
+        assert!(merged.lower_fence() <= exact.lower_fence() + 0.02 * exact.iqr());
+        assert!(merged.upper_fence() >= exact.upper_fence() - 0.02 * exact.iqr());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_merge_empty() {
+        Quartiles::merge(&[]);
+    }
+
+    #[test]
+    fn test_values_f64_precision() {
+        let data = [1_000_000.01, 2_000_000.02, 3_000_000.03, 4_000_000.04];
+        assert_eq!(
+            Quartiles::real(&data).values_f64(),
+            [
+                1_000_000.01,
+                1_000_000.01 + 0.25 * (2_000_000.02 - 1_000_000.01),
+                2_000_000.02 + 0.5 * (3_000_000.03 - 2_000_000.02),
+                3_000_000.03 + 0.75 * (4_000_000.04 - 3_000_000.03),
+                4_000_000.04
+            ]
+        );
+        assert_eq!(
+            Quartiles::new(&[123_456_789.01]).values_f64(),
+            [123_456_789.01; 5]
+        );
+        assert_ne!(
+            Quartiles::new(&[123_456_789.01]).values()[2] as f64,
+            123_456_789.01
+        );
+    }
 }
@@ -0,0 +1,212 @@
+use super::Quartiles;
+
+// The target quantiles of the markers: the extrema, the three quartiles and the midpoints
+// between each of them, as in the extended P² algorithm.
+const MARKER_QUANTILES: [f64; 9] = [0.0, 0.125, 0.25, 0.375, 0.5, 0.625, 0.75, 0.875, 1.0];
+const MARKERS: usize = MARKER_QUANTILES.len();
+
+/// A streaming estimator of the quartiles, for datasets that don't fit in memory.
+///
+/// It implements the P² algorithm (Jain & Chlamtac, 1985), extended to track the three
+/// quartiles at once. Only a fixed number of markers is kept, whatever the number of
+/// pushed values, and each push runs in constant time.
+///
+/// The tradeoff is accuracy: the quartiles are approximations whose error depends on the
+/// distribution of the data and the order in which it arrives. For a few thousand
+/// samples of a smooth distribution, the error is typically a small fraction of the IQR.
+/// As long as fewer than 9 values were pushed, the result is exact.
+///
+/// The fences are computed as with [`Quartiles::new`], 1.5 IQR away from the box.
+///
+/// ```rust
+/// use plotters::prelude::*;
+///
+/// let mut estimator = QuartilesEstimator::new();
+/// for value in 1..=1000 {
+///     estimator.push(f64::from(value));
+/// }
+/// let quartiles = estimator.finish();
+/// assert!((quartiles.median() - 500.5).abs() < 1.0);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct QuartilesEstimator {
+    count: usize,
+    heights: [f64; MARKERS],
+    positions: [f64; MARKERS],
+    desired: [f64; MARKERS],
+}
+
+impl QuartilesEstimator {
+    /// Create a new estimator without any value.
+    ///
+    /// - **returns** The newly created estimator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the number of values pushed so far.
+    ///
+    /// - **returns** The number of values
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Check if no value has been pushed yet.
+    ///
+    /// - **returns** If the estimator is empty
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Push a new value into the estimator.
+    ///
+    /// - `value`: The value to account for
+    pub fn push(&mut self, value: f64) {
+        if self.count < MARKERS {
+            self.heights[self.count] = value;
+            self.count += 1;
+            if self.count == MARKERS {
+                self.heights
+                    .sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+                for (i, p) in MARKER_QUANTILES.iter().enumerate() {
+                    self.positions[i] = i as f64;
+                    self.desired[i] = p * (MARKERS - 1) as f64;
+                }
+            }
+            return;
+        }
+        self.count += 1;
+
+        let q = &mut self.heights;
+        let k = if value < q[0] {
+            q[0] = value;
+            0
+        } else if value >= q[MARKERS - 1] {
+            q[MARKERS - 1] = value;
+            MARKERS - 2
+        } else {
+            (0..MARKERS - 1)
+                .find(|&i| q[i] <= value && value < q[i + 1])
+                .unwrap_or(MARKERS - 2)
+        };
+
+        for i in k + 1..MARKERS {
+            self.positions[i] += 1.0;
+        }
+        for (desired, p) in self.desired.iter_mut().zip(MARKER_QUANTILES.iter()) {
+            *desired += p;
+        }
+
+        for i in 1..MARKERS - 1 {
+            let d = self.desired[i] - self.positions[i];
+            if (d >= 1.0 && self.positions[i + 1] - self.positions[i] > 1.0)
+                || (d <= -1.0 && self.positions[i - 1] - self.positions[i] < -1.0)
+            {
+                let d = d.signum();
+                let parabolic = self.parabolic(i, d);
+                self.heights[i] =
+                    if self.heights[i - 1] < parabolic && parabolic < self.heights[i + 1] {
+                        parabolic
+                    } else {
+                        self.linear(i, d)
+                    };
+                self.positions[i] += d;
+            }
+        }
+    }
+
+    // The piecewise-parabolic prediction of the height of the marker `i` moved by `d`.
+    fn parabolic(&self, i: usize, d: f64) -> f64 {
+        let (q, n) = (&self.heights, &self.positions);
+        q[i] + d / (n[i + 1] - n[i - 1])
+            * ((n[i] - n[i - 1] + d) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                + (n[i + 1] - n[i] - d) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]))
+    }
+
+    // The linear prediction of the height of the marker `i` moved by `d`.
+    fn linear(&self, i: usize, d: f64) -> f64 {
+        let (q, n) = (&self.heights, &self.positions);
+        let j = if d > 0.0 { i + 1 } else { i - 1 };
+        q[i] + d * (q[j] - q[i]) / (n[j] - n[i])
+    }
+
+    /// Consume the estimator and build the estimated quartiles.
+    ///
+    /// Panics if no value was pushed, as [`Quartiles::new`] does with an empty slice.
+    ///
+    /// - **returns** The estimated quartiles
+    pub fn finish(self) -> Quartiles {
+        if self.count < MARKERS {
+            return Quartiles::new(&self.heights[..self.count]);
+        }
+        Quartiles::with_tukey_fences(
+            self.heights[2],
+            self.heights[4],
+            self.heights[6],
+            self.count,
+        )
+    }
+}
+
+impl Extend<f64> for QuartilesEstimator {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // A deterministic standard normal sampler (xorshift + Box-Muller)
+    fn normal_samples(n: usize) -> Vec<f64> {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut uniform = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        (0..n)
+            .map(|_| {
+                let (u1, u2) = (1.0 - uniform(), uniform());
+                (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_matches_exact_quartiles() {
+        let data = normal_samples(10_000);
+        let exact = Quartiles::new(&data);
+
+        let mut estimator = QuartilesEstimator::new();
+        estimator.extend(data.iter().copied());
+        assert_eq!(estimator.len(), 10_000);
+        let estimated = estimator.finish();
+
+        let tolerance = 0.05 * (exact.upper() - exact.lower());
+        for (e, x) in estimated.values_f64().iter().zip(exact.values_f64().iter()) {
+            assert!((e - x).abs() < tolerance, "{} vs {}", e, x);
+        }
+    }
+
+    #[test]
+    fn test_few_values_are_exact() {
+        let mut estimator = QuartilesEstimator::new();
+        assert!(estimator.is_empty());
+        estimator.extend([7.0, 15.0, 36.0, 39.0, 40.0, 41.0]);
+        assert_eq!(
+            estimator.finish().values_f64(),
+            Quartiles::new(&[7, 15, 36, 39, 40, 41]).values_f64()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_estimator() {
+        QuartilesEstimator::new().finish();
+    }
+}
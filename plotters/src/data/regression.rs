@@ -0,0 +1,276 @@
+/// The error raised when a curve can't be fitted to the points
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FitError {
+    /// There are fewer points than the coefficients of the curve
+    NotEnoughPoints,
+    /// The points don't determine a single curve, e.g. they all share the same X coordinate
+    Degenerate,
+}
+
+impl std::fmt::Display for FitError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            FitError::NotEnoughPoints => write!(fmt, "Not enough points to fit the curve"),
+            FitError::Degenerate => write!(fmt, "The points don't determine a single curve"),
+        }
+    }
+}
+
+impl std::error::Error for FitError {}
+
+// The points whose coordinates are both numbers, the others are left out of the fits
+fn valid_points(points: &[(f64, f64)]) -> impl Iterator<Item = (f64, f64)> + '_ {
+    points
+        .iter()
+        .copied()
+        .filter(|(x, y)| !x.is_nan() && !y.is_nan())
+}
+
+/// Fit a straight line to the points, with the least squares method.
+///
+/// The points where any of the coordinates is NaN are left out.
+///
+/// - `points`: The `(x, y)` points to fit
+/// - **returns** The slope and the intercept of the line, or an error if there are fewer than
+///   two points or if all the points share the same X coordinate
+///
+/// ```rust
+/// use plotters::data::linear_fit;
+///
+/// let (slope, intercept) = linear_fit(&[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)]).unwrap();
+/// assert_eq!((slope, intercept), (2.0, 1.0));
+/// // The fitted line can then be sampled into a series
+/// let line: Vec<_> = (0..=10).map(|x| (x as f64, slope * x as f64 + intercept)).collect();
+/// ```
+pub fn linear_fit(points: &[(f64, f64)]) -> Result<(f64, f64), FitError> {
+    let (mut n, mut sum_x, mut sum_y) = (0.0, 0.0, 0.0);
+    for (x, y) in valid_points(points) {
+        n += 1.0;
+        sum_x += x;
+        sum_y += y;
+    }
+    if n < 2.0 {
+        return Err(FitError::NotEnoughPoints);
+    }
+
+    // The sums are centered on the means, which keeps them accurate for large coordinates
+    let (mean_x, mean_y) = (sum_x / n, sum_y / n);
+    let (mut sxx, mut sxy) = (0.0, 0.0);
+    for (x, y) in valid_points(points) {
+        sxx += (x - mean_x) * (x - mean_x);
+        sxy += (x - mean_x) * (y - mean_y);
+    }
+    if sxx == 0.0 {
+        return Err(FitError::Degenerate);
+    }
+
+    let slope = sxy / sxx;
+    Ok((slope, mean_y - slope * mean_x))
+}
+
+/// Fit a polynomial to the points, with the least squares method.
+///
+/// The points where any of the coordinates is NaN are left out.
+///
+/// - `points`: The `(x, y)` points to fit
+/// - `degree`: The degree of the polynomial
+/// - **returns** The `degree + 1` coefficients of the polynomial, from the constant one to the
+///   one of the highest power, or an error if there are fewer points than coefficients or if
+///   the points have fewer than `degree + 1` distinct X coordinates
+///
+/// ```rust
+/// use plotters::data::polynomial_fit;
+///
+/// let points: Vec<_> = (-3..=3).map(|x| x as f64).map(|x| (x, x * x - 2.0 * x + 3.0)).collect();
+/// let coefficients = polynomial_fit(&points, 2).unwrap();
+/// // The polynomial is evaluated with Horner's method
+/// let value_at = |x: f64| coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c);
+/// assert!((value_at(10.0) - 83.0).abs() < 1e-9);
+/// ```
+pub fn polynomial_fit(points: &[(f64, f64)], degree: usize) -> Result<Vec<f64>, FitError> {
+    let points: Vec<_> = valid_points(points).collect();
+    let cols = degree + 1;
+    if points.len() < cols {
+        return Err(FitError::NotEnoughPoints);
+    }
+
+    // The Vandermonde matrix of the X coordinates, stored by column, and the Y coordinates
+    let mut matrix: Vec<Vec<f64>> = (0..cols)
+        .map(|power| points.iter().map(|(x, _)| x.powi(power as i32)).collect())
+        .collect();
+    let mut rhs: Vec<f64> = points.iter().map(|(_, y)| *y).collect();
+
+    // The matrix is reduced to an upper triangular one with Householder reflections, which is
+    // much more stable than solving the normal equations
+    let scale = matrix
+        .iter()
+        .flatten()
+        .fold(0.0_f64, |max, value| max.max(value.abs()));
+    for k in 0..cols {
+        let norm = matrix[k][k..].iter().map(|v| v * v).sum::<f64>().sqrt();
+        if norm <= scale * 1e-12 {
+            return Err(FitError::Degenerate);
+        }
+        let alpha = if matrix[k][k] > 0.0 { -norm } else { norm };
+        let mut reflector = matrix[k][k..].to_vec();
+        reflector[0] -= alpha;
+        let reflector_norm2: f64 = reflector.iter().map(|v| v * v).sum();
+
+        let reflect = |column: &mut [f64]| {
+            let dot: f64 = reflector
+                .iter()
+                .zip(column.iter())
+                .map(|(a, b)| a * b)
+                .sum();
+            let factor = 2.0 * dot / reflector_norm2;
+            for (value, r) in column.iter_mut().zip(reflector.iter()) {
+                *value -= factor * r;
+            }
+        };
+        for column in matrix[k..].iter_mut() {
+            reflect(&mut column[k..]);
+        }
+        reflect(&mut rhs[k..]);
+    }
+
+    // Back substitution through the triangular matrix
+    let mut coefficients = vec![0.0; cols];
+    for row in (0..cols).rev() {
+        let known: f64 = (row + 1..cols)
+            .map(|col| matrix[col][row] * coefficients[col])
+            .sum();
+        coefficients[row] = (rhs[row] - known) / matrix[row][row];
+    }
+    Ok(coefficients)
+}
+
+/// Compute the coefficient of determination of a fitted curve, which is the proportion of the
+/// variance of the Y coordinates explained by the curve.
+///
+/// A perfect fit has a coefficient of 1, and a curve no better than the mean of the Y
+/// coordinates has a coefficient of 0 or less. The points where any of the coordinates is NaN
+/// are left out. When all the Y coordinates are equal the coefficient is undefined, and NaN is
+/// returned.
+///
+/// - `points`: The `(x, y)` points the curve was fitted to
+/// - `curve`: The fitted curve, which gives the predicted Y coordinate of a X coordinate
+/// - **returns** The coefficient of determination
+///
+/// ```rust
+/// use plotters::data::{linear_fit, r_squared};
+///
+/// let points = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 2.0)];
+/// let (slope, intercept) = linear_fit(&points).unwrap();
+/// let r2 = r_squared(&points, |x| slope * x + intercept);
+/// assert!(r2 > 0.8 && r2 < 1.0);
+/// ```
+pub fn r_squared<F: Fn(f64) -> f64>(points: &[(f64, f64)], curve: F) -> f64 {
+    let (n, sum_y) = valid_points(points).fold((0.0, 0.0), |(n, sum), (_, y)| (n + 1.0, sum + y));
+    let mean_y = sum_y / n;
+    let (mut ss_res, mut ss_tot) = (0.0, 0.0);
+    for (x, y) in valid_points(points) {
+        ss_res += (y - curve(x)).powi(2);
+        ss_tot += (y - mean_y).powi(2);
+    }
+    if ss_tot == 0.0 {
+        return f64::NAN;
+    }
+    1.0 - ss_res / ss_tot
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // A deterministic noise, uniformly spread between -1 and 1
+    fn noise(idx: usize) -> f64 {
+        ((idx * 7919 % 1000) as f64 / 500.0) - 1.0
+    }
+
+    #[test]
+    fn test_linear_fit_exact() {
+        let points: Vec<_> = (0..10).map(|x| (x as f64, 3.0 * x as f64 - 7.0)).collect();
+        let (slope, intercept) = linear_fit(&points).unwrap();
+        assert!((slope - 3.0).abs() < 1e-12);
+        assert!((intercept + 7.0).abs() < 1e-12);
+        assert!((r_squared(&points, |x| slope * x + intercept) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_linear_fit_noisy() {
+        let points: Vec<_> = (0..200)
+            .map(|idx| {
+                let x = idx as f64 / 10.0;
+                (x, 0.5 * x + 2.0 + noise(idx))
+            })
+            .collect();
+        let (slope, intercept) = linear_fit(&points).unwrap();
+        assert!((slope - 0.5).abs() < 0.05);
+        assert!((intercept - 2.0).abs() < 0.3);
+        let r2 = r_squared(&points, |x| slope * x + intercept);
+        assert!(r2 > 0.9 && r2 < 1.0);
+    }
+
+    #[test]
+    fn test_linear_fit_degenerate() {
+        assert_eq!(linear_fit(&[]), Err(FitError::NotEnoughPoints));
+        assert_eq!(
+            linear_fit(&[(1.0, 1.0), (f64::NAN, 2.0)]),
+            Err(FitError::NotEnoughPoints)
+        );
+        assert_eq!(
+            linear_fit(&[(1.0, 1.0), (1.0, 2.0)]),
+            Err(FitError::Degenerate)
+        );
+    }
+
+    #[test]
+    fn test_polynomial_fit_exact() {
+        let cubic = |x: f64| 0.5 * x * x * x - 2.0 * x * x + x - 4.0;
+        let points: Vec<_> = (-10..=10)
+            .map(|x| x as f64 / 2.0)
+            .map(|x| (x, cubic(x)))
+            .collect();
+        let coefficients = polynomial_fit(&points, 3).unwrap();
+        for (actual, expected) in coefficients.iter().zip([-4.0, 1.0, -2.0, 0.5]) {
+            assert!((actual - expected).abs() < 1e-9);
+        }
+        // A polynomial of degree 1 is the same as the linear fit
+        let line = polynomial_fit(&points, 1).unwrap();
+        let (slope, intercept) = linear_fit(&points).unwrap();
+        assert!((line[0] - intercept).abs() < 1e-9);
+        assert!((line[1] - slope).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_polynomial_fit_noisy() {
+        let points: Vec<_> = (0..300)
+            .map(|idx| {
+                let x = idx as f64 / 30.0 - 5.0;
+                (x, 2.0 * x * x - 3.0 + 0.5 * noise(idx))
+            })
+            .collect();
+        let coefficients = polynomial_fit(&points, 2).unwrap();
+        assert!((coefficients[0] + 3.0).abs() < 0.1);
+        assert!(coefficients[1].abs() < 0.1);
+        assert!((coefficients[2] - 2.0).abs() < 0.01);
+        let r2 = r_squared(&points, |x| {
+            coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+        });
+        assert!(r2 > 0.99);
+    }
+
+    #[test]
+    fn test_polynomial_fit_degenerate() {
+        assert_eq!(
+            polynomial_fit(&[(0.0, 1.0), (1.0, 2.0)], 2),
+            Err(FitError::NotEnoughPoints)
+        );
+        // Three points, but only two distinct X coordinates
+        assert_eq!(
+            polynomial_fit(&[(0.0, 1.0), (1.0, 2.0), (1.0, 3.0)], 2),
+            Err(FitError::Degenerate)
+        );
+        assert!(r_squared(&[(0.0, 1.0), (1.0, 1.0)], |_| 1.0).is_nan());
+    }
+}
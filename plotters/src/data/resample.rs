@@ -0,0 +1,239 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone};
+
+/// The calendar period the values are grouped by in [`resample`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Period {
+    /// The days, from midnight to midnight
+    Day,
+    /// The weeks, from Monday to Sunday
+    Week,
+    /// The months, from their first day
+    Month,
+    /// The years, from the first of January
+    Year,
+}
+
+impl Period {
+    // The first day of the period the date is in
+    fn start_of(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Period::Day => date,
+            Period::Week => date - Duration::days(i64::from(date.weekday().num_days_from_monday())),
+            Period::Month => date.with_day(1).unwrap_or(date),
+            Period::Year => date.with_ordinal(1).unwrap_or(date),
+        }
+    }
+}
+
+/// The way the values of each period are combined into one in [`resample`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aggregation {
+    /// The sum of the values
+    Sum,
+    /// The arithmetic mean of the values
+    Mean,
+    /// The smallest value
+    Min,
+    /// The largest value
+    Max,
+    /// The number of values
+    Count,
+}
+
+// The running statistics of the values of a period
+struct Bucket {
+    sum: f64,
+    count: usize,
+    min: f64,
+    max: f64,
+}
+
+impl Bucket {
+    fn value(&self, aggregation: Aggregation) -> f64 {
+        if self.count == 0 && aggregation != Aggregation::Count {
+            return f64::NAN;
+        }
+        match aggregation {
+            Aggregation::Sum => self.sum,
+            Aggregation::Mean => self.sum / self.count as f64,
+            Aggregation::Min => self.min,
+            Aggregation::Max => self.max,
+            Aggregation::Count => self.count as f64,
+        }
+    }
+}
+
+/// Group timestamped values by calendar period, and combine the values of each period into one.
+///
+/// The periods are aligned to the calendar in the time zone of the points: the days start at
+/// midnight, the weeks on Monday, the months on their first day and the years on the first of
+/// January. Each period which holds at least one point gives a point at its start, thus the
+/// result composes with the date and time coordinates, and the periods without points are left
+/// out. The points don't need to be sorted, while the result is sorted by time.
+///
+/// The NaN values are ignored: they aren't counted, and a period holding only NaN values is
+/// aggregated into NaN, or 0 for [`Aggregation::Count`].
+///
+/// - `points`: The timestamped values
+/// - `period`: The calendar period the values are grouped by
+/// - `aggregation`: The way the values of each period are combined
+/// - **returns** The start of each period with its aggregated value
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use plotters::data::{resample, Aggregation, Period};
+///
+/// let points = [
+///     (Utc.with_ymd_and_hms(2024, 1, 30, 8, 0, 0).unwrap(), 2.0),
+///     (Utc.with_ymd_and_hms(2024, 1, 31, 20, 0, 0).unwrap(), 3.0),
+///     (Utc.with_ymd_and_hms(2024, 2, 1, 9, 0, 0).unwrap(), 4.0),
+/// ];
+/// let monthly = resample(&points, Period::Month, Aggregation::Sum);
+/// assert_eq!(
+///     monthly,
+///     vec![
+///         (Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(), 5.0),
+///         (Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap(), 4.0),
+///     ]
+/// );
+/// ```
+pub fn resample<Tz: TimeZone>(
+    points: &[(DateTime<Tz>, f64)],
+    period: Period,
+    aggregation: Aggregation,
+) -> Vec<(DateTime<Tz>, f64)> {
+    let mut buckets: BTreeMap<NaiveDate, (DateTime<Tz>, Bucket)> = BTreeMap::new();
+    for (time, value) in points {
+        let start = period.start_of(time.date_naive());
+        let (_, bucket) = buckets.entry(start).or_insert_with(|| {
+            let bucket = Bucket {
+                sum: 0.0,
+                count: 0,
+                min: f64::INFINITY,
+                max: f64::NEG_INFINITY,
+            };
+            (time.clone(), bucket)
+        });
+        if value.is_nan() {
+            continue;
+        }
+        bucket.sum += value;
+        bucket.count += 1;
+        bucket.min = bucket.min.min(*value);
+        bucket.max = bucket.max.max(*value);
+    }
+
+    buckets
+        .into_iter()
+        .map(|(start, (time, bucket))| {
+            // The midnight starting the period, which a change of the offset may skip, in which
+            // case the period starts at its first point
+            let midnight = start
+                .and_hms_opt(0, 0, 0)
+                .and_then(|midnight| time.timezone().from_local_datetime(&midnight).earliest())
+                .unwrap_or(time);
+            (midnight, bucket.value(aggregation))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::{FixedOffset, Utc};
+
+    #[test]
+    fn test_hourly_to_daily_means() {
+        // A week of hourly values, each day rising from its index to its index plus 23
+        let start = Utc.with_ymd_and_hms(2024, 3, 4, 0, 0, 0).unwrap();
+        let points: Vec<_> = (0..7 * 24i32)
+            .map(|hour| {
+                let (day, hour_of_day) = (hour / 24, hour % 24);
+                (
+                    start + Duration::hours(i64::from(hour)),
+                    f64::from(day * 10 + hour_of_day),
+                )
+            })
+            .collect();
+
+        let daily = resample(&points, Period::Day, Aggregation::Mean);
+        assert_eq!(daily.len(), 7);
+        for (day, (time, mean)) in daily.iter().enumerate() {
+            assert_eq!(*time, start + Duration::days(day as i64));
+            assert_eq!(*mean, day as f64 * 10.0 + 11.5);
+        }
+
+        let counts = resample(&points, Period::Day, Aggregation::Count);
+        assert!(counts.iter().all(|&(_, count)| count == 24.0));
+        let maxima = resample(&points, Period::Day, Aggregation::Max);
+        assert_eq!(maxima[2].1, 43.0);
+        let minima = resample(&points, Period::Day, Aggregation::Min);
+        assert_eq!(minima[2].1, 20.0);
+
+        // The week starts on Monday the 4th, thus all the points are in the same week
+        let weekly = resample(&points, Period::Week, Aggregation::Count);
+        assert_eq!(weekly, vec![(start, 168.0)]);
+    }
+
+    #[test]
+    fn test_calendar_alignment() {
+        let at = |y, m, d, h| Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap();
+        // Out of order, across weeks, months and years
+        let points = [
+            (at(2024, 1, 2, 10), 4.0),
+            (at(2023, 12, 31, 23), 1.0),
+            (at(2024, 2, 29, 12), 2.0),
+            (at(2024, 1, 7, 8), f64::NAN),
+            (at(2024, 1, 8, 0), 3.0),
+        ];
+
+        assert_eq!(
+            resample(&points, Period::Week, Aggregation::Sum),
+            vec![
+                (at(2023, 12, 25, 0), 1.0),
+                (at(2024, 1, 1, 0), 4.0),
+                (at(2024, 1, 8, 0), 3.0),
+                (at(2024, 2, 26, 0), 2.0)
+            ]
+        );
+        assert_eq!(
+            resample(&points, Period::Month, Aggregation::Count),
+            vec![
+                (at(2023, 12, 1, 0), 1.0),
+                (at(2024, 1, 1, 0), 2.0),
+                (at(2024, 2, 1, 0), 1.0)
+            ]
+        );
+        assert_eq!(
+            resample(&points, Period::Year, Aggregation::Max),
+            vec![(at(2023, 1, 1, 0), 1.0), (at(2024, 1, 1, 0), 4.0)]
+        );
+
+        // A period holding only NaN values
+        let nan = resample(&points[3..4], Period::Day, Aggregation::Mean);
+        assert!(nan[0].1.is_nan());
+        assert_eq!(
+            resample(&points[3..4], Period::Day, Aggregation::Count)[0].1,
+            0.0
+        );
+        assert!(resample::<Utc>(&[], Period::Day, Aggregation::Sum).is_empty());
+    }
+
+    #[test]
+    fn test_local_time_zone() {
+        // The days are cut at the midnight of the time zone of the points
+        let tz = FixedOffset::east_opt(5 * 3600).unwrap();
+        let points = [
+            (tz.with_ymd_and_hms(2024, 5, 1, 23, 0, 0).unwrap(), 1.0),
+            (tz.with_ymd_and_hms(2024, 5, 2, 1, 0, 0).unwrap(), 2.0),
+        ];
+        let daily = resample(&points, Period::Day, Aggregation::Sum);
+        assert_eq!(daily.len(), 2);
+        assert_eq!(
+            daily[1].0,
+            tz.with_ymd_and_hms(2024, 5, 2, 0, 0, 0).unwrap()
+        );
+    }
+}
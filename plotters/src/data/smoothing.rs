@@ -0,0 +1,143 @@
+/// Smooth the values with a simple moving average, each output being the mean of the input
+/// value at the same index and of the `window - 1` values before it.
+///
+/// The output is aligned to the input: it has the same length, and the average at index `i`
+/// ends at the value at index `i`. The first `window - 1` points don't have enough values before
+/// them, so the window shrinks to the values available, starting with the first value itself.
+/// A window of 0 is handled as a window of 1, which returns the values unchanged.
+///
+/// The averages whose window contains a NaN are NaN, the following ones are not affected.
+///
+/// - `values`: The values to smooth, usually sampled at a regular interval
+/// - `window`: The number of values averaged at each point
+/// - **returns** The smoothed values
+///
+/// ```rust
+/// use plotters::data::moving_average;
+///
+/// let smoothed = moving_average(&[1.0, 3.0, 5.0, 7.0, 9.0], 3);
+/// assert_eq!(smoothed, vec![1.0, 2.0, 3.0, 5.0, 7.0]);
+/// ```
+pub fn moving_average(values: &[f64], window: usize) -> Vec<f64> {
+    let window = window.max(1);
+    let mut result = Vec::with_capacity(values.len());
+    // The NaNs are counted apart, so that they don't poison the running sum
+    let (mut sum, mut nans) = (0.0, 0);
+    for (idx, value) in values.iter().enumerate() {
+        if value.is_nan() {
+            nans += 1;
+        } else {
+            sum += value;
+        }
+        if idx >= window {
+            let dropped = values[idx - window];
+            if dropped.is_nan() {
+                nans -= 1;
+            } else {
+                sum -= dropped;
+            }
+        }
+        let len = (idx + 1).min(window);
+        result.push(if nans > 0 { f64::NAN } else { sum / len as f64 });
+    }
+    result
+}
+
+/// Smooth the values with an exponential moving average, each output being a weighted mean
+/// of the input value at the same index and of the previous output.
+///
+/// The output at index `i` is `alpha * values[i] + (1 - alpha) * output[i - 1]`, and the first
+/// output is the first value, thus the output is aligned to the input. The closer `alpha` is to
+/// 0 the smoother the output, and an `alpha` of 1 returns the values unchanged; it is clamped
+/// between 0 and 1.
+///
+/// The outputs of the NaN values are NaN, and the average carries on over them from the last
+/// value which isn't NaN.
+///
+/// - `values`: The values to smooth, usually sampled at a regular interval
+/// - `alpha`: The weight of each new value, between 0 and 1
+/// - **returns** The smoothed values
+///
+/// ```rust
+/// use plotters::data::exponential_moving_average;
+///
+/// let smoothed = exponential_moving_average(&[2.0, 4.0, 8.0], 0.5);
+/// assert_eq!(smoothed, vec![2.0, 3.0, 5.5]);
+/// ```
+pub fn exponential_moving_average(values: &[f64], alpha: f64) -> Vec<f64> {
+    let alpha = alpha.clamp(0.0, 1.0);
+    let mut average: Option<f64> = None;
+    values
+        .iter()
+        .map(|&value| {
+            if value.is_nan() {
+                return f64::NAN;
+            }
+            let next = match average {
+                Some(average) => alpha * value + (1.0 - alpha) * average,
+                None => value,
+            };
+            average = Some(next);
+            next
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!(
+                (a.is_nan() && e.is_nan()) || (a - e).abs() < 1e-9,
+                "{:?} != {:?}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_moving_average() {
+        let values = [4.0, 8.0, 6.0, 2.0, 10.0, 4.0];
+        // The first two points shrink the window to the values available
+        assert_close(
+            &moving_average(&values, 3),
+            &[4.0, 6.0, 6.0, 16.0 / 3.0, 6.0, 16.0 / 3.0],
+        );
+        assert_close(&moving_average(&values, 1), &values);
+        assert_close(&moving_average(&values, 0), &values);
+        // A window longer than the values averages all of the values before each point
+        assert_close(
+            &moving_average(&values, 10),
+            &[4.0, 6.0, 6.0, 5.0, 6.0, 34.0 / 6.0],
+        );
+        assert!(moving_average(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn test_moving_average_nan() {
+        let values = [1.0, 2.0, f64::NAN, 4.0, 6.0, 8.0];
+        assert_close(
+            &moving_average(&values, 2),
+            &[1.0, 1.5, f64::NAN, f64::NAN, 5.0, 7.0],
+        );
+    }
+
+    #[test]
+    fn test_exponential_moving_average() {
+        let values = [10.0, 20.0, 20.0, 0.0];
+        assert_close(
+            &exponential_moving_average(&values, 0.25),
+            &[10.0, 12.5, 14.375, 10.78125],
+        );
+        assert_close(&exponential_moving_average(&values, 1.0), &values);
+        assert_close(&exponential_moving_average(&values, -1.0), &[10.0; 4]);
+        assert_close(
+            &exponential_moving_average(&[f64::NAN, 2.0, f64::NAN, 4.0], 0.5),
+            &[f64::NAN, 2.0, f64::NAN, 3.0],
+        );
+    }
+}
@@ -3,7 +3,7 @@ use crate::coord::ranged1d::{KeyPointHint, Ranged};
 use crate::coord::{CoordTranslate, Shift};
 use crate::element::{CoordMapper, Drawable, PointCollection};
 use crate::style::text_anchor::{HPos, Pos, VPos};
-use crate::style::{Color, SizeDesc, TextStyle};
+use crate::style::{Color, ShapeStyle, SizeDesc, TextMetrics, TextStyle, Theme};
 
 /// The abstraction of a drawing area
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
@@ -12,7 +12,7 @@ use std::borrow::Borrow;
 use std::cell::RefCell;
 use std::error::Error;
 use std::iter::{once, repeat};
-use std::ops::Range;
+use std::ops::{Deref, Range};
 use std::rc::Rc;
 
 /// The representation of the rectangle in backend canvas
@@ -105,6 +105,28 @@ impl Rect {
         })
     }
 
+    /// Intersect the rectangle with another one, an empty intersection is kept empty
+    fn intersect(&self, other: &Rect) -> Rect {
+        let (x0, y0) = (self.x0.max(other.x0), self.y0.max(other.y0));
+        Rect {
+            x0,
+            y0,
+            x1: self.x1.min(other.x1).max(x0),
+            y1: self.y1.min(other.y1).max(y0),
+        }
+    }
+
+    /// Grow the rectangle by its own size on each side
+    fn grow(&self) -> Rect {
+        let (w, h) = (self.x1 - self.x0, self.y1 - self.y0);
+        Rect {
+            x0: self.x0.saturating_sub(w),
+            y0: self.y0.saturating_sub(h),
+            x1: self.x1.saturating_add(w),
+            y1: self.y1.saturating_add(h),
+        }
+    }
+
     /// Make the coordinate in the range of the rectangle
     pub fn truncate(&self, p: (i32, i32)) -> (i32, i32) {
         (p.0.min(self.x1).max(self.x0), p.1.min(self.y1).max(self.y0))
@@ -119,13 +141,17 @@ impl Rect {
 pub struct DrawingArea<DB: DrawingBackend, CT: CoordTranslate> {
     backend: Rc<RefCell<DB>>,
     rect: Rect,
+    clip: Option<Rect>,
     coord: CT,
+    theme: Option<Rc<Theme>>,
 }
 
 impl<DB: DrawingBackend, CT: CoordTranslate + Clone> Clone for DrawingArea<DB, CT> {
     fn clone(&self) -> Self {
         Self {
             backend: self.backend.clone(),
+            clip: self.clip.clone(),
+            theme: self.theme.clone(),
             rect: self.rect.clone(),
             coord: self.coord.clone(),
         }
@@ -162,6 +188,40 @@ impl<E: Error + Send + Sync> Error for DrawingAreaErrorKind<E> {}
 #[allow(type_alias_bounds)]
 type DrawingAreaError<T: DrawingBackend> = DrawingAreaErrorKind<T::ErrorType>;
 
+/**
+A guard which presents the pending changes of a drawing area when it is dropped.
+
+See [`DrawingArea::present_on_drop()`] for more information and examples.
+*/
+pub struct PresentGuard<'a, DB: DrawingBackend, CT: CoordTranslate> {
+    area: &'a DrawingArea<DB, CT>,
+    presented: bool,
+}
+
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> PresentGuard<'a, DB, CT> {
+    /// Present the pending changes now and return the error, instead of ignoring it on drop
+    pub fn present(mut self) -> Result<(), DrawingAreaError<DB>> {
+        self.presented = true;
+        self.area.present()
+    }
+}
+
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> Deref for PresentGuard<'a, DB, CT> {
+    type Target = DrawingArea<DB, CT>;
+    fn deref(&self) -> &Self::Target {
+        self.area
+    }
+}
+
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> Drop for PresentGuard<'a, DB, CT> {
+    fn drop(&mut self) {
+        if !self.presented {
+            // drop should not panic, so we ignore a failed present
+            let _ = self.area.present();
+        }
+    }
+}
+
 impl<DB: DrawingBackend> From<DB> for DrawingArea<DB, Shift> {
     fn from(backend: DB) -> Self {
         Self::with_rc_cell(Rc::new(RefCell::new(backend)))
@@ -187,19 +247,60 @@ impl<T: DrawingBackend> IntoDrawingArea for T {
 }
 
 impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, Cartesian2d<X, Y>> {
+    // Move the top and bottom edges of the area to the given rows, and the Y axis with them
+    pub(crate) fn set_vertical_span(&mut self, y0: i32, y1: i32) {
+        self.rect.y0 = y0;
+        self.rect.y1 = y1;
+        self.coord.set_back_y((y1 - 1, y0));
+    }
+
     /// Draw the mesh on a area
     pub fn draw_mesh<DrawFunc, YH: KeyPointHint, XH: KeyPointHint>(
+        &self,
+        draw_func: DrawFunc,
+        y_count_max: YH,
+        x_count_max: XH,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DrawFunc: FnMut(&mut DB, MeshLine<X, Y>) -> Result<(), DrawingErrorKind<DB::ErrorType>>,
+    {
+        self.draw_mesh_with_ticks(draw_func, y_count_max, x_count_max, (None, None))
+    }
+
+    // Draw the mesh, at the given ticks of the axes which have some
+    pub(crate) fn draw_mesh_with_ticks<DrawFunc, YH: KeyPointHint, XH: KeyPointHint>(
         &self,
         mut draw_func: DrawFunc,
         y_count_max: YH,
         x_count_max: XH,
+        ticks: (Option<&[X::ValueType]>, Option<&[Y::ValueType]>),
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
         DrawFunc: FnMut(&mut DB, MeshLine<X, Y>) -> Result<(), DrawingErrorKind<DB::ErrorType>>,
     {
         self.backend_ops(move |b| {
             self.coord
-                .draw_mesh(y_count_max, x_count_max, |line| draw_func(b, line))
+                .draw_mesh_with_ticks(y_count_max, x_count_max, ticks, |line| draw_func(b, line))
+        })
+    }
+
+    /// Draw the minor grid lines on a area
+    pub fn draw_minor_mesh<DrawFunc>(
+        &self,
+        mut draw_func: DrawFunc,
+        y_minor: (usize, usize),
+        x_minor: (usize, usize),
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DrawFunc: FnMut(
+            &mut DB,
+            BackendCoord,
+            BackendCoord,
+        ) -> Result<(), DrawingErrorKind<DB::ErrorType>>,
+    {
+        self.backend_ops(move |b| {
+            self.coord
+                .draw_minor_mesh(y_minor, x_minor, |from, to| draw_func(b, from, to))
         })
     }
 
@@ -222,6 +323,16 @@ impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, Cartesian2d<X, Y>
     pub fn get_y_axis_pixel_range(&self) -> Range<i32> {
         self.coord.get_y_axis_pixel_range()
     }
+
+    /// Get the ranges of X of the backend coordinate where the X axis is broken
+    pub fn get_x_axis_breaks(&self) -> Vec<Range<i32>> {
+        self.coord.get_x_axis_breaks()
+    }
+
+    /// Get the ranges of Y of the backend coordinate where the Y axis is broken
+    pub fn get_y_axis_breaks(&self) -> Vec<Range<i32>> {
+        self.coord.get_y_axis_breaks()
+    }
 }
 
 impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
@@ -235,19 +346,112 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         DrawingArea {
             rect: self.rect.clone(),
             backend: self.backend.clone(),
+            clip: self.clip.clone(),
+            theme: self.theme.clone(),
             coord: Shift((self.rect.x0, self.rect.y0)),
         }
     }
 
+    /**
+    Carves a sub-area anywhere in the drawing area, such as an inset in a corner of a chart.
+    Unlike the splits, the sub-area may overlap the rest of the area, and what is drawn on it
+    goes on top of what is already drawn there. The sub-area has no coordinate system of its
+    own, thus a chart with its own axes can be built on it.
+
+    - `left_upper`: The position of the left upper corner, relative to the drawing area
+    - `dimension`: The size of the sub-area, which is cut at the edges of the drawing area
+    - **returns**: The sub-area
+
+    ```
+    use plotters::prelude::*;
+    let root = SVGBackend::new("sub_area.svg", (400, 300)).into_drawing_area();
+    root.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&root)
+        .set_left_and_bottom_label_area_size(30)
+        .build_cartesian_2d(0.0..10.0, 0.0..100.0)
+        .unwrap();
+    chart.configure_mesh().draw().unwrap();
+    // An inset in the upper right corner of the plotting area, a third of its size
+    let plotting_area = chart.plotting_area();
+    let inset = plotting_area.sub_area(
+        ((60).percent_width(), 10),
+        ((33).percent_width(), (33).percent_height()),
+    );
+    inset.fill(&WHITE).unwrap();
+    let mut inset_chart = ChartBuilder::on(&inset)
+        .set_left_and_bottom_label_area_size(20)
+        .build_cartesian_2d(2.0..3.0, 4.0..9.0)
+        .unwrap();
+    inset_chart.configure_mesh().draw().unwrap();
+    ```
+    */
+    pub fn sub_area<A: SizeDesc, B: SizeDesc, C: SizeDesc, D: SizeDesc>(
+        &self,
+        left_upper: (A, B),
+        dimension: (C, D),
+    ) -> DrawingArea<DB, Shift> {
+        let (dx, dy) = (left_upper.0.in_pixels(self), left_upper.1.in_pixels(self));
+        let (w, h) = (dimension.0.in_pixels(self), dimension.1.in_pixels(self));
+        let mut area = self.strip_coord_spec();
+        let rect = &mut area.rect;
+        let (x0, y0) = (
+            (rect.x0 + dx).clamp(rect.x0, rect.x1),
+            (rect.y0 + dy).clamp(rect.y0, rect.y1),
+        );
+        rect.x1 = (x0 + w.max(0)).min(rect.x1);
+        rect.y1 = (y0 + h.max(0)).min(rect.y1);
+        rect.x0 = x0;
+        rect.y0 = y0;
+        area.coord = Shift((x0, y0));
+        area
+    }
+
     /// Strip the applied coordinate specification and returns a drawing area
     pub fn use_screen_coord(&self) -> DrawingArea<DB, Shift> {
         DrawingArea {
             rect: self.rect.clone(),
             backend: self.backend.clone(),
+            clip: self.clip.clone(),
+            theme: self.theme.clone(),
             coord: Shift((0, 0)),
         }
     }
 
+    /// Restrict the drawing on this area to a rectangle, so that the backend leaves the pixels
+    /// outside of it untouched. This allows redrawing only the part of a figure which has
+    /// changed, e.g. a single series of a real-time chart, while the rest of the frame is kept.
+    ///
+    /// The returned area keeps the size and the coordinate system of this area, thus the same
+    /// elements can be drawn onto it, and the areas split from it are restricted as well. The
+    /// clipping applies on the top of the clipping of this area, if any.
+    ///
+    /// Note: the clipping is done by the drawing backend, the backends which don't support it
+    /// draw the whole area.
+    ///
+    /// - `upper_left`: The upper-left corner of the rectangle, relative to this area
+    /// - `bottom_right`: The bottom-right corner of the rectangle, relative to this area and
+    ///   excluded from the rectangle
+    /// - **returns**: The restricted drawing area
+    pub fn clip_rect(&self, upper_left: BackendCoord, bottom_right: BackendCoord) -> Self
+    where
+        CT: Clone,
+    {
+        let rect = Rect {
+            x0: self.rect.x0 + upper_left.0.min(bottom_right.0),
+            y0: self.rect.y0 + upper_left.1.min(bottom_right.1),
+            x1: self.rect.x0 + upper_left.0.max(bottom_right.0),
+            y1: self.rect.y0 + upper_left.1.max(bottom_right.1),
+        };
+        let clip = match &self.clip {
+            Some(clip) => clip.intersect(&rect),
+            None => self.rect.intersect(&rect),
+        };
+        Self {
+            clip: Some(clip),
+            ..self.clone()
+        }
+    }
+
     /// Get the area dimension in pixel
     pub fn dim_in_pixel(&self) -> (u32, u32) {
         (
@@ -275,16 +479,48 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     fn backend_ops<R, O: FnOnce(&mut DB) -> Result<R, DrawingErrorKind<DB::ErrorType>>>(
         &self,
         ops: O,
+    ) -> Result<R, DrawingAreaError<DB>> {
+        self.backend_ops_with_clip(self.clip.as_ref(), ops)
+    }
+
+    /// Perform operation on the drawing backend, restricted to the given clip rectangle
+    fn backend_ops_with_clip<
+        R,
+        O: FnOnce(&mut DB) -> Result<R, DrawingErrorKind<DB::ErrorType>>,
+    >(
+        &self,
+        clip: Option<&Rect>,
+        ops: O,
     ) -> Result<R, DrawingAreaError<DB>> {
         if let Ok(mut db) = self.backend.try_borrow_mut() {
             db.ensure_prepared()
                 .map_err(DrawingAreaErrorKind::BackendError)?;
+            if let Some(clip) = clip {
+                db.set_clip_rect(Some(((clip.x0, clip.y0), (clip.x1, clip.y1))));
+                let result = ops(&mut db);
+                db.set_clip_rect(None);
+                return result.map_err(DrawingAreaErrorKind::BackendError);
+            }
             ops(&mut db).map_err(DrawingAreaErrorKind::BackendError)
         } else {
             Err(DrawingAreaErrorKind::SharingError)
         }
     }
 
+    /// Apply a theme to the drawing area, filling it with the background color of the theme.
+    ///
+    /// The charts built on this area, or on the areas split from it afterwards, then take their
+    /// default styles from the theme, see [`Theme`] for more details.
+    pub fn apply_theme(&mut self, theme: &Theme) -> Result<(), DrawingAreaError<DB>> {
+        self.theme = Some(Rc::new(theme.clone()));
+        self.fill(&theme.background)
+    }
+
+    /// Get the theme applied to the drawing area, if any
+    pub fn theme(&self) -> Option<&Theme> {
+        self.theme.as_deref()
+    }
+
     /// Fill the entire drawing area with a color
     pub fn fill<ColorType: Color>(&self, color: &ColorType) -> Result<(), DrawingAreaError<DB>> {
         self.backend_ops(|backend| {
@@ -307,11 +543,80 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         self.backend_ops(|b| b.draw_pixel(pos, color.to_backend_color()))
     }
 
+    /// Draw lines whose ends are given in backend coordinates, such as the grid lines of a chart
+    pub(crate) fn draw_backend_lines(
+        &self,
+        lines: &[(BackendCoord, BackendCoord, ShapeStyle)],
+    ) -> Result<(), DrawingAreaError<DB>> {
+        self.backend_ops(|b| {
+            for (from, to, style) in lines {
+                b.draw_line(*from, *to, style)?;
+            }
+            Ok(())
+        })
+    }
+
     /// Present all the pending changes to the backend
     pub fn present(&self) -> Result<(), DrawingAreaError<DB>> {
         self.backend_ops(|b| b.present())
     }
 
+    /**
+    Returns a guard which presents the pending changes when it goes out of scope, so that they
+    can't be forgotten. The guard dereferences to the drawing area.
+
+    The error of a present done on drop can't be returned, thus it is ignored:
+    [`PresentGuard::present()`] presents the changes earlier and returns the error, and
+    [`DrawingArea::draw_and_present()`] does the same for a closure.
+
+    ```
+    use plotters::prelude::*;
+    let root = SVGBackend::new("present_on_drop.svg", (300, 200)).into_drawing_area();
+    {
+        let area = root.present_on_drop();
+        area.fill(&WHITE).unwrap();
+        area.draw(&Circle::new((150, 100), 50, RED.filled())).unwrap();
+    } // The file is written here
+    ```
+    */
+    pub fn present_on_drop(&self) -> PresentGuard<'_, DB, CT> {
+        PresentGuard {
+            area: self,
+            presented: false,
+        }
+    }
+
+    /**
+    Runs a drawing closure on the drawing area then presents the changes, even if the closure
+    failed. The error of the closure is returned first, and then the error of the present.
+
+    - `draw`: The closure drawing on the area
+    - **returns**: The result of the closure, or the error of the present
+
+    ```
+    use plotters::prelude::*;
+    let root = SVGBackend::new("draw_and_present.svg", (300, 200)).into_drawing_area();
+    root.draw_and_present(|area| -> Result<_, Box<dyn std::error::Error>> {
+        area.fill(&WHITE)?;
+        let mut chart = ChartBuilder::on(area).build_cartesian_2d(0..10, 0..10)?;
+        chart.draw_series(LineSeries::new((0..10).map(|x| (x, x)), BLUE))?;
+        Ok(())
+    })
+    .unwrap();
+    ```
+    */
+    pub fn draw_and_present<R, E, F>(&self, draw: F) -> Result<R, E>
+    where
+        F: FnOnce(&Self) -> Result<R, E>,
+        E: From<DrawingAreaError<DB>>,
+    {
+        let result = draw(self);
+        let presented = self.present();
+        let value = result?;
+        presented?;
+        Ok(value)
+    }
+
     /// Draw an high-level element
     pub fn draw<'a, E, B>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
     where
@@ -326,6 +631,70 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         self.backend_ops(move |b| element.draw(backend_coords, b, self.dim_in_pixel()))
     }
 
+    /// Draw an high-level element, restricted to this area as if it were clipped with
+    /// [`DrawingArea::clip_rect`]: the parts of the element out of the area are left out
+    pub(crate) fn draw_clipped<'a, E, B>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
+    where
+        B: CoordMapper,
+        &'a E: PointCollection<'a, CT::From, B>,
+        E: Drawable<DB, B>,
+    {
+        let clip = match &self.clip {
+            Some(clip) => clip.intersect(&self.rect),
+            None => self.rect.clone(),
+        };
+        // The points slightly out of the area keep their position, thus the lines reaching
+        // them are cut where they actually cross the border
+        let bounds = self.rect.grow();
+        let backend_coords = element.point_iter().into_iter().map(move |p| {
+            let b = p.borrow();
+            B::map(&self.coord, b, &bounds)
+        });
+        self.backend_ops_with_clip(Some(&clip), move |b| {
+            element.draw(backend_coords, b, self.dim_in_pixel())
+        })
+    }
+
+    /// Tag the shapes drawn by a function with a class name. The backends writing a document,
+    /// such as SVG, may attach the class to the shapes so that they can be restyled later.
+    ///
+    /// ```
+    /// use plotters::prelude::*;
+    /// let mut buffer = String::new();
+    /// {
+    ///     let root = SVGBackend::with_string(&mut buffer, (100, 100))
+    ///         .with_styling(SVGStyling::InlineAndClasses)
+    ///         .into_drawing_area();
+    ///     root.with_element_class("highlight", || {
+    ///         root.draw(&Circle::new((50, 50), 10, RED.filled()))
+    ///     })
+    ///     .unwrap();
+    /// }
+    /// assert!(buffer.contains("class=\"plotters-circle highlight\""));
+    /// ```
+    ///
+    /// - `class`: The class name
+    /// - `draw`: The function drawing the shapes to tag
+    /// - **returns**: The result of `draw`
+    pub fn with_element_class<R, F: FnOnce() -> R>(&self, class: &str, draw: F) -> R {
+        self.push_element_class(class);
+        let result = draw();
+        self.pop_element_class();
+        result
+    }
+
+    pub(crate) fn push_element_class(&self, class: &str) {
+        if let Ok(mut db) = self.backend.try_borrow_mut() {
+            db.push_element_class(class);
+        }
+    }
+
+    pub(crate) fn pop_element_class(&self) {
+        if let Ok(mut db) = self.backend.try_borrow_mut() {
+            db.pop_element_class();
+        }
+    }
+
     /// Map coordinate to the backend coordinate
     pub fn map_coordinate(&self, coord: &CT::From) -> BackendCoord {
         self.coord.translate(coord)
@@ -346,9 +715,31 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     ) -> Result<(u32, u32), DrawingAreaError<DB>> {
         self.backend_ops(move |b| b.estimate_text_size(text, style))
     }
+
+    /// Estimate the dimension of the text if drawn on this drawing area, with the vertical
+    /// metrics of the font the backend renders it with. This is what is needed to lay out
+    /// several lines of text, or to align texts on their baselines.
+    ///
+    /// - `text`: The text we want to estimate
+    /// - `font`: The font spec in which we want to draw the text
+    /// - **return**: The size of the text and the metrics of its font if drawn on this area
+    pub fn estimate_text_metrics(
+        &self,
+        text: &str,
+        style: &TextStyle,
+    ) -> Result<TextMetrics, DrawingAreaError<DB>> {
+        self.backend_ops(move |b| b.estimate_text_metrics(text, style))
+    }
 }
 
 impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
+    // Move the top and bottom edges of the area to the given rows, keeping its left edge
+    pub(crate) fn set_vertical_span(&mut self, y0: i32, y1: i32) {
+        self.rect.y0 = y0;
+        self.rect.y1 = y1;
+        self.coord = Shift((self.rect.x0, y0));
+    }
+
     fn with_rc_cell(backend: Rc<RefCell<DB>>) -> Self {
         let (x1, y1) = RefCell::borrow(backend.borrow()).get_size();
         Self {
@@ -359,7 +750,9 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
                 y1: y1 as i32,
             },
             backend,
+            clip: None,
             coord: Shift((0, 0)),
+            theme: None,
         }
     }
 
@@ -387,6 +780,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         DrawingArea {
             rect: self.rect.clone(),
             backend: self.backend.clone(),
+            clip: self.clip.clone(),
+            theme: self.theme.clone(),
             coord: coord_spec,
         }
     }
@@ -411,6 +806,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
                 y1: self.rect.y1 - bottom,
             },
             backend: self.backend.clone(),
+            clip: self.clip.clone(),
+            theme: self.theme.clone(),
             coord: Shift((self.rect.x0 + left, self.rect.y0 + top)),
         }
     }
@@ -422,6 +819,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         let mut ret = self.rect.split(split_point.iter(), true).map(|rect| Self {
             rect: rect.clone(),
             backend: self.backend.clone(),
+            clip: self.clip.clone(),
+            theme: self.theme.clone(),
             coord: Shift((rect.x0, rect.y0)),
         });
 
@@ -435,6 +834,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
         let mut ret = self.rect.split(split_point.iter(), false).map(|rect| Self {
             rect: rect.clone(),
             backend: self.backend.clone(),
+            clip: self.clip.clone(),
+            theme: self.theme.clone(),
             coord: Shift((rect.x0, rect.y0)),
         });
 
@@ -448,11 +849,49 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             .map(|rect| Self {
                 rect: rect.clone(),
                 backend: self.backend.clone(),
+                clip: self.clip.clone(),
+                theme: self.theme.clone(),
                 coord: Shift((rect.x0, rect.y0)),
             })
             .collect()
     }
 
+    /**
+    Split the drawing area evenly into a grid of cells, one chart each, which are indexed by row
+    and then by column. This lays out several charts in a single output, such as a report: each
+    cell is given to its own [`ChartBuilder`](crate::chart::ChartBuilder).
+
+    ```
+    use plotters::prelude::*;
+    let root = SVGBackend::new("grid_of_charts.svg", (600, 400)).into_drawing_area();
+    root.fill(&WHITE).unwrap();
+    let grid = root.grid_of_charts(2, 2);
+    for (row, cells) in grid.iter().enumerate() {
+        for (col, cell) in cells.iter().enumerate() {
+            let mut chart = ChartBuilder::on(cell)
+                .caption(format!("Chart ({}, {})", row, col), ("sans-serif", 15))
+                .set_left_and_bottom_label_area_size(25)
+                .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+                .unwrap();
+            chart.configure_mesh().draw().unwrap();
+        }
+    }
+    ```
+
+    With a paged backend, such as the PDF backend, each page can be split from the whole area
+    with [`DrawingArea::split_evenly()`] first, then into its own grid.
+
+    - `rows`: The number of rows of the grid
+    - `cols`: The number of columns of the grid
+    - **returns** The rows of the grid from the top, each holding its cells from the left
+    */
+    pub fn grid_of_charts(&self, rows: usize, cols: usize) -> Vec<Vec<Self>> {
+        let mut cells = self.split_evenly((rows, cols)).into_iter();
+        (0..rows)
+            .map(|_| cells.by_ref().take(cols).collect())
+            .collect()
+    }
+
     /// Split the drawing area into a grid with specified breakpoints on both X axis and Y axis
     pub fn split_by_breakpoints<
         XSize: SizeDesc,
@@ -472,6 +911,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             .map(|rect| Self {
                 rect: rect.clone(),
                 backend: self.backend.clone(),
+                clip: self.clip.clone(),
+                theme: self.theme.clone(),
                 coord: Shift((rect.x0, rect.y0)),
             })
             .collect()
@@ -508,6 +949,8 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
                 y1: self.rect.y1,
             },
             backend: self.backend.clone(),
+            clip: self.clip.clone(),
+            theme: self.theme.clone(),
             coord: Shift((self.rect.x0, self.rect.y0 + y_padding * 2 + text_h as i32)),
         })
     }
@@ -600,6 +1043,25 @@ mod drawing_area_tests {
             });
     }
 
+    #[test]
+    fn test_grid_of_charts() {
+        let drawing_area = create_mocked_drawing_area(600, 400, |m| {
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 6));
+        });
+
+        let grid = drawing_area.grid_of_charts(2, 3);
+        assert_eq!(grid.len(), 2);
+        for (row, cells) in grid.iter().enumerate() {
+            assert_eq!(cells.len(), 3);
+            for (col, cell) in cells.iter().enumerate() {
+                assert_eq!(cell.get_base_pixel(), (200 * col as i32, 200 * row as i32));
+                assert_eq!(cell.dim_in_pixel(), (200, 200));
+                cell.fill(&WHITE).unwrap();
+            }
+        }
+        assert!(drawing_area.grid_of_charts(0, 2).is_empty());
+    }
+
     #[test]
     fn test_split_horizontally() {
         let drawing_area = create_mocked_drawing_area(1024, 768, |m| {
@@ -776,6 +1238,27 @@ mod drawing_area_tests {
         assert_eq!(y_range, 0..200);
     }
 
+    #[test]
+    fn test_estimate_text_metrics() {
+        let drawing_area = create_mocked_drawing_area(1024, 768, |_m| {});
+        let style = TextStyle::from(("sans-serif", 20).into_font());
+
+        let short = drawing_area.estimate_text_metrics("Plot", &style).unwrap();
+        let long = drawing_area
+            .estimate_text_metrics("Plotters", &style)
+            .unwrap();
+        assert!(long.width > short.width);
+        assert_eq!(
+            (long.width, long.height),
+            drawing_area.estimate_text_size("Plotters", &style).unwrap()
+        );
+
+        // The vertical metrics are the ones of the font, whatever the text
+        assert_eq!(long.ascent, short.ascent);
+        assert!(long.ascent > long.descent && long.descent > 0.0);
+        assert!(long.line_gap >= 0.0);
+    }
+
     #[test]
     fn test_relative_size() {
         let drawing_area = create_mocked_drawing_area(1024, 768, |_m| {});
@@ -856,4 +1339,80 @@ mod drawing_area_tests {
 
         drawing_area.fill(&RED).unwrap();
     }
+
+    #[cfg(feature = "svg_backend")]
+    #[test]
+    fn test_present_on_drop() {
+        let dir = std::path::Path::new("target/test/drawing_area");
+        std::fs::create_dir_all(dir).unwrap();
+        let path = dir.join("present_on_drop.svg");
+        let _ = std::fs::remove_file(&path);
+
+        let root = SVGBackend::new(&path, (100, 100)).into_drawing_area();
+        {
+            let area = root.present_on_drop();
+            area.fill(&WHITE).unwrap();
+            area.draw(&Circle::new((50, 50), 20, RED.filled())).unwrap();
+            assert!(!path.exists());
+        }
+        // The file is written when the guard drops, while the drawing area is still alive
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("<circle"));
+        drop(root);
+    }
+
+    #[test]
+    fn test_draw_and_present() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                // The present resets the draw count
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.draw_count, 0);
+            });
+        });
+
+        let value = drawing_area
+            .draw_and_present(|area| -> Result<_, DrawingAreaErrorKind<_>> {
+                area.fill(&WHITE)?;
+                Ok(42)
+            })
+            .unwrap();
+        assert_eq!(value, 42);
+
+        // The changes are presented even when the closure fails
+        let result: Result<(), _> = drawing_area.draw_and_present(|area| {
+            area.fill(&WHITE)?;
+            Err(DrawingAreaErrorKind::LayoutError)
+        });
+        assert!(matches!(result, Err(DrawingAreaErrorKind::LayoutError)));
+    }
+
+    #[test]
+    fn test_sub_area() {
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.check_draw_rect(|_, _, _, upper_left, bottom_right| {
+                assert_eq!(upper_left, (300, 50));
+                assert_eq!(bottom_right, (400, 300));
+            });
+            m.check_draw_rect(|_, _, _, upper_left, bottom_right| {
+                assert_eq!(upper_left, (110, 20));
+                assert_eq!(bottom_right, (190, 70));
+            });
+        });
+
+        // The sub-area is cut at the edges of the area
+        let corner = drawing_area.sub_area((300, 50), (200, 400));
+        assert_eq!(corner.dim_in_pixel(), (100, 250));
+        corner.fill(&WHITE).unwrap();
+
+        // It can be carved from a chart, whose coordinate system it doesn't keep
+        let chart = ChartBuilder::on(&drawing_area.margin(10, 10, 100, 100))
+            .build_cartesian_2d(0..10, 0..10)
+            .unwrap();
+        let inset = chart
+            .plotting_area()
+            .sub_area(((5).percent_width(), 10), (80, 50));
+        assert_eq!(inset.get_base_pixel(), (110, 20));
+        inset.fill(&RED).unwrap();
+    }
 }
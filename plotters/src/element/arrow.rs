@@ -0,0 +1,253 @@
+use crate::element::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The ends of an arrow which carry a head
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrowEnds {
+    /// The head is at the end point, the arrow points from the start to the end
+    End,
+    /// The head is at the start point, the arrow points from the end to the start
+    Start,
+    /// Both ends carry a head
+    Both,
+}
+
+/// Describes the heads of an [`Arrow`].
+///
+/// The heads are triangles whose length along the shaft is the size, and whose width is the same
+/// as the length. They are measured in pixels, thus they keep their shape whatever the scales of
+/// the coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArrowHead {
+    size: u32,
+    filled: bool,
+    ends: ArrowEnds,
+}
+
+impl ArrowHead {
+    /// Create a filled head of the given size, at the end point of the arrow
+    /// - `size`: The length of the head along the shaft, in pixels
+    /// - returns the created head
+    pub fn new(size: u32) -> Self {
+        Self {
+            size,
+            filled: true,
+            ends: ArrowEnds::End,
+        }
+    }
+
+    /// Draw the heads as two strokes rather than filled triangles
+    /// - returns the open head
+    pub fn open(mut self) -> Self {
+        self.filled = false;
+        self
+    }
+
+    /// Set the ends of the arrow which carry a head
+    /// - `ends`: The ends with a head
+    /// - returns the updated head
+    pub fn ends(mut self, ends: ArrowEnds) -> Self {
+        self.ends = ends;
+        self
+    }
+}
+
+impl Default for ArrowHead {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+/**
+An arrow from a start point to an end point, usually pointing from an annotation to a feature
+of the data.
+
+The arrow is made of a straight shaft drawn with the style, and of triangular heads described by
+an [`ArrowHead`], in the color of the style. The heads are oriented along the shaft once it is
+mapped to the pixels.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("arrow.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0.0..10.0, 0.0..100.0)
+    .unwrap();
+chart
+    .draw_series(std::iter::once(Arrow::new(
+        (2.0, 20.0),
+        (6.0, 80.0),
+        BLACK.stroke_width(2),
+        ArrowHead::new(12).ends(ArrowEnds::Both),
+    )))
+    .unwrap();
+```
+*/
+pub struct Arrow<Coord> {
+    points: [Coord; 2],
+    style: ShapeStyle,
+    head: ArrowHead,
+}
+
+impl<Coord> Arrow<Coord> {
+    /// Create a new arrow
+    /// - `start`: The start point of the arrow
+    /// - `end`: The end point of the arrow
+    /// - `style`: The style of the shaft, whose color is also the one of the heads
+    /// - `head`: The description of the heads
+    /// - returns the created element
+    pub fn new<S: Into<ShapeStyle>>(start: Coord, end: Coord, style: S, head: ArrowHead) -> Self {
+        Self {
+            points: [start, end],
+            style: style.into(),
+            head,
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Arrow<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+// The tip and the two corners of the base of a head, given the unit vector of the shaft pointing
+// to the tip
+fn head_points(tip: (f64, f64), (dx, dy): (f64, f64), size: f64) -> [BackendCoord; 3] {
+    let base = (tip.0 - dx * size, tip.1 - dy * size);
+    let (nx, ny) = (-dy * size / 2.0, dx * size / 2.0);
+    let round = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+    [
+        round(tip),
+        round((base.0 + nx, base.1 + ny)),
+        round((base.0 - nx, base.1 - ny)),
+    ]
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Arrow<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (start, end) = match (points.next(), points.next()) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return Ok(()),
+        };
+        let (start_f, end_f) = (
+            (f64::from(start.0), f64::from(start.1)),
+            (f64::from(end.0), f64::from(end.1)),
+        );
+        let length = (end_f.0 - start_f.0).hypot(end_f.1 - start_f.1);
+        if length == 0.0 {
+            return Ok(());
+        }
+        let dir = (
+            (end_f.0 - start_f.0) / length,
+            (end_f.1 - start_f.1) / length,
+        );
+
+        let size = f64::from(self.head.size);
+        let (at_start, at_end) = match self.head.ends {
+            ArrowEnds::End => (false, true),
+            ArrowEnds::Start => (true, false),
+            ArrowEnds::Both => (true, true),
+        };
+
+        // The filled heads cover the end of the shaft, which stops at their base so that a
+        // thick stroke doesn't stick out of the tip
+        let mut shaft = [start_f, end_f];
+        if self.head.filled {
+            let heads = f64::from(u8::from(at_start) + u8::from(at_end));
+            if length > size * heads {
+                if at_start {
+                    shaft[0] = (start_f.0 + dir.0 * size, start_f.1 + dir.1 * size);
+                }
+                if at_end {
+                    shaft[1] = (end_f.0 - dir.0 * size, end_f.1 - dir.1 * size);
+                }
+            }
+        }
+        backend.draw_path(
+            shaft
+                .iter()
+                .map(|(x, y)| (x.round() as i32, y.round() as i32)),
+            &self.style,
+        )?;
+
+        let heads = [(at_start, start_f, (-dir.0, -dir.1)), (at_end, end_f, dir)];
+        for (_, tip, dir) in heads.iter().filter(|(has_head, ..)| *has_head) {
+            let [tip, left, right] = head_points(*tip, *dir, size);
+            if self.head.filled {
+                backend.fill_polygon([tip, left, right], &self.style)?;
+            } else {
+                backend.draw_path([left, tip, right], &self.style)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_filled_arrow() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_path(|c, s, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(s, 3);
+                // The shaft stops at the base of the head
+                assert_eq!(path, vec![(10, 50), (100, 50)]);
+            });
+            m.check_fill_polygon(|c, points| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(points, vec![(110, 50), (100, 55), (100, 45)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+        da.draw(&Arrow::new(
+            (10, 50),
+            (110, 50),
+            RED.stroke_width(3),
+            ArrowHead::new(10),
+        ))
+        .expect("Drawing Failure");
+    }
+
+    #[test]
+    fn test_open_arrow_both_ends() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            let mut paths = vec![
+                vec![(50, 10), (50, 90)],
+                vec![(54, 18), (50, 10), (46, 18)],
+                vec![(46, 82), (50, 90), (54, 82)],
+            ];
+            m.check_draw_path(move |_, _, path| {
+                assert_eq!(path, paths.remove(0));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 3);
+                assert_eq!(b.num_fill_polygon_call, 0);
+            });
+        });
+        da.draw(&Arrow::new(
+            (50, 10),
+            (50, 90),
+            BLUE,
+            ArrowHead::new(8).open().ends(ArrowEnds::Both),
+        ))
+        .expect("Drawing Failure");
+    }
+}
@@ -1,5 +1,5 @@
 use super::{Drawable, PointCollection};
-use crate::style::{Color, ShapeStyle, SizeDesc};
+use crate::style::{Color, GradientStyle, ShapeStyle, SizeDesc};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 #[inline]
@@ -82,10 +82,25 @@ fn test_pixel_element() {
 #[deprecated(note = "Use new name PathElement instead")]
 pub type Path<Coord> = PathElement<Coord>;
 
+/// The position of the vertical segments of a stepped path, relative to its points
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepDirection {
+    /// The vertical segments are at the start of the steps: each point's value holds from the
+    /// previous point to this one, as matplotlib's `where="pre"`
+    Before,
+    /// The vertical segments are at the end of the steps: each point's value holds until the
+    /// next point, as matplotlib's `where="post"`
+    After,
+    /// The vertical segments are halfway between the points, as matplotlib's `where="mid"`
+    Middle,
+}
+
 /// An element of a series of connected lines
 pub struct PathElement<Coord> {
     points: Vec<Coord>,
     style: ShapeStyle,
+    simplified: bool,
+    step: Option<StepDirection>,
 }
 impl<Coord> PathElement<Coord> {
     /// Create a new path
@@ -96,8 +111,118 @@ impl<Coord> PathElement<Coord> {
         Self {
             points: points.into(),
             style: style.into(),
+            simplified: false,
+            step: None,
+        }
+    }
+
+    /// Draw the path as a staircase, which moves horizontally then vertically between the
+    /// points rather than diagonally. The right-angle vertices are added once the path is mapped
+    /// to the pixels, thus the steps are straight on any coordinate.
+    /// - `direction`: Where the vertical segments of the steps are
+    /// - returns the stepped path
+    pub fn step(mut self, direction: StepDirection) -> Self {
+        self.step = Some(direction);
+        self
+    }
+
+    /// Simplify the path once mapped to the pixels, before it's drawn. The runs of consecutive
+    /// points which fall in the same pixel column are collapsed into their first, lowest,
+    /// highest and last points, thus the path draws the same pixels and keeps its spikes, with
+    /// at most four vertices per column. This makes the paths with a huge number of points much
+    /// faster to draw, and much smaller in the vector backends.
+    /// - returns the simplified path
+    pub fn simplified(mut self) -> Self {
+        self.simplified = true;
+        self
+    }
+}
+
+/// A run of consecutive points in the same pixel column
+struct ColumnRun {
+    first: BackendCoord,
+    low: BackendCoord,
+    high: BackendCoord,
+    last: BackendCoord,
+    low_first: bool,
+}
+
+impl ColumnRun {
+    fn new(point: BackendCoord) -> Self {
+        Self {
+            first: point,
+            low: point,
+            high: point,
+            last: point,
+            low_first: true,
+        }
+    }
+
+    fn push(&mut self, point: BackendCoord) {
+        if point.1 < self.low.1 {
+            self.low = point;
+            self.low_first = false;
+        } else if point.1 > self.high.1 {
+            self.high = point;
+            self.low_first = true;
+        }
+        self.last = point;
+    }
+
+    fn flush(&self, path: &mut Vec<BackendCoord>) {
+        let (a, b) = if self.low_first {
+            (self.low, self.high)
+        } else {
+            (self.high, self.low)
+        };
+        for point in [self.first, a, b, self.last] {
+            if path.last() != Some(&point) {
+                path.push(point);
+            }
+        }
+    }
+}
+
+/// Add the right-angle vertices of a stepped path between its points
+fn step_in_pixels<I: Iterator<Item = BackendCoord>>(
+    points: I,
+    direction: StepDirection,
+) -> impl Iterator<Item = BackendCoord> {
+    let mut prev: Option<BackendCoord> = None;
+    points.flat_map(move |(x, y)| {
+        let corners = match prev.replace((x, y)) {
+            None => vec![],
+            Some((x0, y0)) => match direction {
+                StepDirection::Before => vec![(x0, y)],
+                StepDirection::After => vec![(x, y0)],
+                StepDirection::Middle => {
+                    let mid = x0 + (x - x0) / 2;
+                    vec![(mid, y0), (mid, y)]
+                }
+            },
+        };
+        corners.into_iter().chain(std::iter::once((x, y)))
+    })
+}
+
+/// Collapse the runs of consecutive points in the same pixel column of a path
+fn simplify_in_pixels<I: Iterator<Item = BackendCoord>>(points: I) -> Vec<BackendCoord> {
+    let mut path = vec![];
+    let mut run: Option<ColumnRun> = None;
+    for point in points {
+        match run.as_mut() {
+            Some(current) if current.first.0 == point.0 => current.push(point),
+            _ => {
+                if let Some(current) = run.replace(ColumnRun::new(point)) {
+                    current.flush(&mut path);
+                }
+            }
         }
     }
+    if let Some(current) = run {
+        current.flush(&mut path);
+    }
+    path
 }
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a PathElement<Coord> {
@@ -115,7 +240,17 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for PathElement<Coord> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        backend.draw_path(points, &self.style)
+        match (self.step, self.simplified) {
+            (Some(direction), true) => backend.draw_path(
+                simplify_in_pixels(step_in_pixels(points, direction)),
+                &self.style,
+            ),
+            (Some(direction), false) => {
+                backend.draw_path(step_in_pixels(points, direction), &self.style)
+            }
+            (None, true) => backend.draw_path(simplify_in_pixels(points), &self.style),
+            (None, false) => backend.draw_path(points, &self.style),
+        }
     }
 }
 
@@ -141,6 +276,88 @@ fn test_path_element() {
     .expect("Drawing Failure");
 }
 
+#[cfg(test)]
+#[test]
+fn test_stepped_path_element() {
+    use crate::prelude::*;
+    let expected = [
+        vec![(10, 10), (10, 40), (30, 40), (30, 20), (60, 20)],
+        vec![(10, 10), (30, 10), (30, 40), (60, 40), (60, 20)],
+        vec![
+            (10, 10),
+            (20, 10),
+            (20, 40),
+            (30, 40),
+            (45, 40),
+            (45, 20),
+            (60, 20),
+        ],
+    ];
+    let mut idx = 0;
+    let da = crate::create_mocked_drawing_area(300, 300, move |m| {
+        m.check_draw_path(move |_, _, path| {
+            assert_eq!(path, expected[idx]);
+            idx += 1;
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 3);
+        });
+    });
+    for direction in [
+        StepDirection::Before,
+        StepDirection::After,
+        StepDirection::Middle,
+    ] {
+        da.draw(&PathElement::new(vec![(10, 10), (30, 40), (60, 20)], BLUE).step(direction))
+            .expect("Drawing Failure");
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_simplified_path_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|_, _, path| {
+            assert_eq!(
+                path,
+                vec![
+                    (10, 50),
+                    (10, 20),
+                    (10, 90),
+                    (10, 60),
+                    (11, 60),
+                    (12, 70),
+                    (12, 40)
+                ]
+            );
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    // The spikes of each column are kept, in the order they were reached
+    da.draw(
+        &PathElement::new(
+            vec![
+                (10, 50),
+                (10, 20),
+                (10, 30),
+                (10, 90),
+                (10, 60),
+                (11, 60),
+                (11, 60),
+                (12, 70),
+                (12, 55),
+                (12, 40),
+            ],
+            BLUE,
+        )
+        .simplified(),
+    )
+    .expect("Drawing Failure");
+}
+
 /// An element of a series of connected lines in dash style.
 ///
 /// It's similar to [`PathElement`] but has a dash style.
@@ -393,6 +610,7 @@ fn test_dotted_path_element() {
 pub struct Rectangle<Coord> {
     points: [Coord; 2],
     style: ShapeStyle,
+    gradient: Option<GradientStyle>,
     margin: (u32, u32, u32, u32),
 }
 
@@ -405,10 +623,21 @@ impl<Coord> Rectangle<Coord> {
         Self {
             points,
             style: style.into(),
+            gradient: None,
             margin: (0, 0, 0, 0),
         }
     }
 
+    /// Fill the rectangle with a gradient rather than the color of its style. The gradient is
+    /// only used when the style is filled, the outline of an unfilled rectangle is drawn with
+    /// the color of its style.
+    /// - `gradient`: The gradient spanning the rectangle
+    /// - returns the updated rectangle
+    pub fn with_gradient(mut self, gradient: GradientStyle) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
     /// Set the margin of the rectangle
     /// - `t`: The top margin
     /// - `b`: The bottom margin
@@ -456,6 +685,14 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Rectangle<Coord> {
                 b.1 -= self.margin.1 as i32;
                 a.0 += self.margin.2 as i32;
                 b.0 -= self.margin.3 as i32;
+                if let Some(gradient) = self.gradient.as_ref().filter(|_| self.style.filled) {
+                    // A rectangle without any height or width has nothing to fill
+                    if a.0 >= b.0 || a.1 >= b.1 {
+                        return Ok(());
+                    }
+                    let corners = [a, (b.0, a.1), b, (a.0, b.1)];
+                    return backend.fill_polygon_gradient(corners, &gradient.to_backend_gradient());
+                }
                 backend.draw_rect(a, b, &self.style, self.style.filled)
             }
             _ => Ok(()),
@@ -504,6 +741,64 @@ fn test_rect_element() {
     }
 }
 
+#[cfg(test)]
+#[test]
+fn test_gradient_rect_element() {
+    use crate::prelude::*;
+    {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_line(|c, _, from, to| {
+                // The vertical gradient is uniform along the rows
+                assert_eq!(from.1, to.1);
+                assert_eq!(c.1, 0);
+                assert_eq!(u32::from(c.0) + u32::from(c.2), 255);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 0);
+                assert!(b.num_draw_line_call > 0);
+            });
+        });
+        da.draw(
+            &Rectangle::new([(100, 101), (107, 150)], BLUE.filled())
+                .with_gradient(GradientStyle::vertical(&RED, &BLUE)),
+        )
+        .expect("Drawing Failure");
+    }
+
+    {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.draw_count, 0);
+            });
+        });
+        da.draw(
+            &Rectangle::new([(100, 101), (150, 101)], BLUE.filled())
+                .with_gradient(GradientStyle::vertical(&RED, &BLUE)),
+        )
+        .expect("Drawing Failure");
+    }
+
+    {
+        // An unfilled rectangle keeps its plain outline
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_rect(|c, _, f, u, d| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(!f);
+                assert_eq!([u, d], [(100, 101), (107, 150)]);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.draw_count, 1);
+            });
+        });
+        da.draw(
+            &Rectangle::new([(100, 101), (107, 150)], BLUE)
+                .with_gradient(GradientStyle::vertical(&RED, &BLUE)),
+        )
+        .expect("Drawing Failure");
+    }
+}
+
 /// A circle element
 pub struct Circle<Coord, Size: SizeDesc> {
     center: Coord,
@@ -569,10 +864,116 @@ fn test_circle_element() {
         .expect("Drawing Failure");
 }
 
+/**
+An arc of a circle, whose radius is in pixels like the one of [`Circle`].
+
+The angles are in radians, measured from the X axis and turning clockwise as drawn, like the
+slices of [`crate::element::Pie`], the arc going from the start angle to the end angle. The
+arc is only stroked, whatever the style, with [`DrawingBackend::draw_arc()`]: natively by the
+SVG backend, and as a path approximating it by the other backends.
+
+# Example
+
+```
+use plotters::prelude::*;
+use std::f64::consts::PI;
+let drawing_area = SVGBackend::new("arc.svg", (200, 120)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+// A gauge: the upper half of a circle, and the part of it reached by the value
+drawing_area
+    .draw(&Arc::new((100, 100), 80, (PI, 2.0 * PI), BLACK.mix(0.2).stroke_width(15)))
+    .unwrap();
+drawing_area
+    .draw(&Arc::new((100, 100), 80, (PI, 1.6 * PI), BLUE.stroke_width(15)))
+    .unwrap();
+```
+*/
+pub struct Arc<Coord, Size: SizeDesc> {
+    center: Coord,
+    size: Size,
+    angles: (f64, f64),
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> Arc<Coord, Size> {
+    /**
+    Creates a new arc.
+
+    - `center`: The center of the circle
+    - `size`: The radius of the circle
+    - `angles`: The start and the end angles of the arc, in radians, clockwise from the X axis
+    - `style`: The style of the arc
+    - **returns** The newly created arc
+
+    See [`Arc`] for more information and examples.
+    */
+    pub fn new<S: Into<ShapeStyle>>(
+        center: Coord,
+        size: Size,
+        angles: (f64, f64),
+        style: S,
+    ) -> Self {
+        Self {
+            center,
+            size,
+            angles,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord, Size: SizeDesc> PointCollection<'a, Coord> for &'a Arc<Coord, Size> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Arc<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(center) = points.next() {
+            let size = self.size.in_pixels(&ps).max(0) as u32;
+            return backend.draw_arc(center, size, self.angles, &self.style);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_arc_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        // The arc goes clockwise from the right of the center to its bottom
+        m.check_draw_path(|c, _, points| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(points.first(), Some(&(170, 150)));
+            assert_eq!(points.last(), Some(&(150, 170)));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    da.draw(&Arc::new(
+        (150, 150),
+        20,
+        (0.0, std::f64::consts::FRAC_PI_2),
+        BLUE,
+    ))
+    .expect("Drawing Failure");
+}
+
 /// An element of a filled polygon
 pub struct Polygon<Coord> {
     points: Vec<Coord>,
     style: ShapeStyle,
+    gradient: Option<GradientStyle>,
 }
 impl<Coord> Polygon<Coord> {
     /// Create a new polygon
@@ -583,8 +984,17 @@ impl<Coord> Polygon<Coord> {
         Self {
             points: points.into(),
             style: style.into(),
+            gradient: None,
         }
     }
+
+    /// Fill the polygon with a gradient rather than the color of its style
+    /// - `gradient`: The gradient spanning the bounding box of the polygon
+    /// - returns the updated polygon
+    pub fn with_gradient(mut self, gradient: GradientStyle) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
 }
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a Polygon<Coord> {
@@ -602,6 +1012,9 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Polygon<Coord> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(gradient) = &self.gradient {
+            return backend.fill_polygon_gradient(points, &gradient.to_backend_gradient());
+        }
         backend.fill_polygon(points, &self.style.color.to_backend_color())
     }
 }
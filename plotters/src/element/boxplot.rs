@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use crate::data::Quartiles;
 use crate::element::{Drawable, PointCollection};
-use crate::style::{Color, ShapeStyle, BLACK};
+use crate::style::{Color, RGBAColor, ShapeStyle, BLACK};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 /// The boxplot orientation trait
@@ -47,15 +47,64 @@ impl<K, V> BoxplotOrient<K, V> for BoxplotOrientH<K, V> {
 }
 
 const DEFAULT_WIDTH: u32 = 10;
+const OUTLIER_RADIUS: u32 = 3;
 
-/// The boxplot element
+fn notch_values(quartiles: &Quartiles) -> [f32; 2] {
+    let (lower, upper) = quartiles.notch_bounds();
+    [lower as f32, upper as f32]
+}
+
+/**
+The boxplot element, which visualizes the quartiles of a dataset.
+
+The statistics are either computed beforehand, see [`Quartiles`], or from the raw values with
+[`Boxplot::from_data()`], which also draws the outliers.
+
+# Examples
+
+[`Boxplot::new_vertical()`] puts the keys on the X axis and the values on the Y axis, while
+[`Boxplot::new_horizontal()`] stacks the keys on the Y axis with the whiskers extending along
+the X axis:
+
+```
+use plotters::prelude::*;
+let data = [
+    ("a", Quartiles::new(&[6, 7, 15, 36, 39, 40, 41, 42, 43, 47, 49])),
+    ("b", Quartiles::new(&[16, 17, 50, 60, 40, 41, 42, 43, 47])),
+];
+let drawing_area = SVGBackend::new("boxplot_horizontal.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_builder = ChartBuilder::on(&drawing_area);
+chart_builder.margin(10).set_left_and_bottom_label_area_size(20);
+let mut chart_context = chart_builder
+    .build_cartesian_2d(0f32..80f32, ["a", "b"].into_segmented())
+    .unwrap();
+chart_context.configure_mesh().draw().unwrap();
+chart_context.draw_series(data.iter().map(|(key, quartiles)| {
+    Boxplot::new_horizontal(SegmentValue::CenterOf(key), quartiles)
+        .width(20)
+        .style(BLUE)
+})).unwrap();
+```
+*/
 pub struct Boxplot<K, O: BoxplotOrient<K, f32>> {
     style: ShapeStyle,
     width: u32,
+    width_factor: f64,
+    count: usize,
     whisker_width: f64,
     offset: f64,
     key: K,
     values: [f32; 5],
+    notch: [f32; 2],
+    notched: bool,
+    mean: Option<(f32, ShapeStyle)>,
+    median_style: Option<ShapeStyle>,
+    box_fill: Option<RGBAColor>,
+    box_outline: Option<ShapeStyle>,
+    outliers: Vec<f32>,
+    outlier_style: Option<ShapeStyle>,
+    show_outliers: bool,
     _p: PhantomData<O>,
 }
 
@@ -73,15 +122,31 @@ impl<K: Clone> Boxplot<K, BoxplotOrientV<K, f32>> {
     /// let plot = Boxplot::new_vertical("group", &quartiles);
     /// ```
     pub fn new_vertical(key: K, quartiles: &Quartiles) -> Self {
-        Self {
-            style: Into::<ShapeStyle>::into(BLACK),
-            width: DEFAULT_WIDTH,
-            whisker_width: 1.0,
-            offset: 0.0,
-            key,
-            values: quartiles.values(),
-            _p: PhantomData,
-        }
+        Self::with_quartiles(key, quartiles)
+    }
+
+    /// Create a new vertical boxplot element from the raw values, whose quartiles are computed
+    /// by the given quartiles constructor. The values beyond the fences are drawn as outliers,
+    /// see [`Boxplot::show_outliers()`].
+    ///
+    /// - `key`: The key (the X axis value)
+    /// - `data`: The raw values, which must neither be empty nor contain NaN
+    /// - `quartiles`: The constructor computing the quartiles and the fences, such as
+    ///   [`Quartiles::new`], [`Quartiles::real`] or [`Quartiles::fair`]
+    /// - **returns** The newly created boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let data = [-50, 7, 15, 36, 39, 40, 41];
+    /// let plot = Boxplot::from_data("group", &data, Quartiles::new);
+    /// ```
+    pub fn from_data<T: Into<f64> + Copy + PartialOrd, F: Fn(&[T]) -> Quartiles>(
+        key: K,
+        data: &[T],
+        quartiles: F,
+    ) -> Self {
+        Self::with_data(key, data, quartiles)
     }
 }
 
@@ -99,19 +164,66 @@ impl<K: Clone> Boxplot<K, BoxplotOrientH<K, f32>> {
     /// let plot = Boxplot::new_horizontal("group", &quartiles);
     /// ```
     pub fn new_horizontal(key: K, quartiles: &Quartiles) -> Self {
+        Self::with_quartiles(key, quartiles)
+    }
+
+    /// Create a new horizontal boxplot element from the raw values, see
+    /// [`Boxplot::from_data()`].
+    ///
+    /// - `key`: The key (the Y axis value)
+    /// - `data`: The raw values, which must neither be empty nor contain NaN
+    /// - `quartiles`: The constructor computing the quartiles and the fences
+    /// - **returns** The newly created boxplot element
+    pub fn from_data_horizontal<T: Into<f64> + Copy + PartialOrd, F: Fn(&[T]) -> Quartiles>(
+        key: K,
+        data: &[T],
+        quartiles: F,
+    ) -> Self {
+        Self::with_data(key, data, quartiles)
+    }
+}
+
+impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
+    fn with_quartiles(key: K, quartiles: &Quartiles) -> Self {
         Self {
             style: Into::<ShapeStyle>::into(BLACK),
             width: DEFAULT_WIDTH,
+            width_factor: 1.0,
+            count: quartiles.count(),
             whisker_width: 1.0,
             offset: 0.0,
             key,
             values: quartiles.values(),
+            notch: notch_values(quartiles),
+            notched: false,
+            mean: None,
+            median_style: None,
+            box_fill: None,
+            box_outline: None,
+            outliers: vec![],
+            outlier_style: None,
+            show_outliers: false,
             _p: PhantomData,
         }
     }
-}
 
-impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
+    // The outliers come from the same quartiles as the box, thus from the same constructor
+    pub(crate) fn with_data<T: Into<f64> + Copy + PartialOrd, F: Fn(&[T]) -> Quartiles>(
+        key: K,
+        data: &[T],
+        quartiles: F,
+    ) -> Self {
+        let quartiles = quartiles(data);
+        let mut boxplot = Self::with_quartiles(key, &quartiles);
+        boxplot.outliers = quartiles
+            .outliers(data)
+            .into_iter()
+            .map(|v| v as f32)
+            .collect();
+        boxplot.show_outliers = true;
+        boxplot
+    }
+
     /// Set the style of the boxplot.
     ///
     /// - `S`: The required style
@@ -144,6 +256,33 @@ impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
         self
     }
 
+    /// Scale the bar width by the square root of the sample count of the quartiles,
+    /// relative to the largest sample count of the series.
+    ///
+    /// The boxes built from `max_count` samples keep the bar width, the others get narrower,
+    /// so that groups with different sample sizes can be compared at a glance.
+    ///
+    /// - `max_count`: The largest sample count among the boxes of the series
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles_a = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let quartiles_b = Quartiles::new(&[7, 15, 36, 39, 40, 41, 42, 43, 44]);
+    /// let max_count = quartiles_a.count().max(quartiles_b.count());
+    /// let plot_a = Boxplot::new_vertical("a", &quartiles_a).width(20).width_by_count(max_count);
+    /// let plot_b = Boxplot::new_vertical("b", &quartiles_b).width(20).width_by_count(max_count);
+    /// ```
+    pub fn width_by_count(mut self, max_count: usize) -> Self {
+        self.width_factor = if max_count == 0 {
+            1.0
+        } else {
+            (self.count as f64 / max_count as f64).sqrt()
+        };
+        self
+    }
+
     /// Set the width of the whiskers as a fraction of the bar width.
     ///
     /// - `whisker_width`: The required fraction
@@ -160,6 +299,71 @@ impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
         self
     }
 
+    /// Set the width of the whisker caps as a fraction of the bar width, which is the same as
+    /// [`Boxplot::whisker_width()`]. The caps are centered on the whiskers and scale with the
+    /// bar width.
+    ///
+    /// - `fraction`: The required fraction
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).width(20).whisker_cap_width(0.5);
+    /// ```
+    pub fn whisker_cap_width(self, fraction: f64) -> Self {
+        self.whisker_width(fraction)
+    }
+
+    /// Set the style of the median line, which is the style of the boxplot by default.
+    ///
+    /// - `style`: The required style
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).median_style(RED.stroke_width(3));
+    /// ```
+    pub fn median_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.median_style = Some(style.into());
+        self
+    }
+
+    /// Fill the box with a color, under its outline. The box isn't filled by default.
+    ///
+    /// - `color`: The fill color
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).box_fill(BLUE.mix(0.3));
+    /// ```
+    pub fn box_fill<C: Color>(mut self, color: C) -> Self {
+        self.box_fill = Some(color.to_rgba());
+        self
+    }
+
+    /// Set the style of the box outline, which is the style of the boxplot by default.
+    ///
+    /// - `style`: The required style
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).box_outline(BLACK.stroke_width(2));
+    /// ```
+    pub fn box_outline<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.box_outline = Some(style.into());
+        self
+    }
+
     /// Set the element offset on the key axis.
     ///
     /// - `offset`: The required offset (on the X axis for vertical, on the Y axis for horizontal)
@@ -175,6 +379,76 @@ impl<K, O: BoxplotOrient<K, f32>> Boxplot<K, O> {
         self.offset = offset.into();
         self
     }
+
+    /// Mark the mean of the values with a diamond.
+    ///
+    /// - `mean`: The mean of the values, as computed by [`Quartiles::mean`]
+    /// - `style`: The style of the diamond
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let (quartiles, mean) = Quartiles::new_with_mean(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).mean_marker(mean, RED.filled());
+    /// ```
+    pub fn mean_marker<S: Into<ShapeStyle>>(mut self, mean: f64, style: S) -> Self {
+        self.mean = Some((mean as f32, style.into()));
+        self
+    }
+
+    /// Set whether the box is notched at the median.
+    ///
+    /// The notch spans the 95% confidence interval of the median, as given by
+    /// [`Quartiles::notch_bounds`], and pinches the box to half its width at the median.
+    ///
+    /// - `notched`: Whether the box should be notched
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let quartiles = Quartiles::new(&[7, 15, 36, 39, 40, 41]);
+    /// let plot = Boxplot::new_vertical("group", &quartiles).notched(true);
+    /// ```
+    pub fn notched(mut self, notched: bool) -> Self {
+        self.notched = notched;
+        self
+    }
+
+    /// Set whether the outliers are drawn, as circles beyond the whiskers. Only the boxplots
+    /// created from the raw values have outliers, which are drawn by default.
+    ///
+    /// - `show`: Whether the outliers should be drawn
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let data = [-50, 7, 15, 36, 39, 40, 41];
+    /// let plot = Boxplot::from_data("group", &data, Quartiles::new).show_outliers(false);
+    /// ```
+    pub fn show_outliers(mut self, show: bool) -> Self {
+        self.show_outliers = show;
+        self
+    }
+
+    /// Set the style of the outlier circles, which is the style of the boxplot by default.
+    ///
+    /// - `style`: The required style
+    /// - **returns** The up-to-dated boxplot element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let data = [-50, 7, 15, 36, 39, 40, 41];
+    /// let plot = Boxplot::from_data("group", &data, Quartiles::new)
+    ///     .outlier_style(RED.filled());
+    /// ```
+    pub fn outlier_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.outlier_style = Some(style.into());
+        self
+    }
 }
 
 impl<'a, K: Clone, O: BoxplotOrient<K, f32>> PointCollection<'a, (O::XType, O::YType)>
@@ -185,6 +459,9 @@ impl<'a, K: Clone, O: BoxplotOrient<K, f32>> PointCollection<'a, (O::XType, O::Y
     fn point_iter(self) -> Self::IntoIter {
         self.values
             .iter()
+            .chain(self.notch.iter())
+            .chain(self.mean.iter().map(|(mean, _)| mean))
+            .chain(self.outliers.iter().filter(|_| self.show_outliers))
             .map(|v| O::make_coord(self.key.clone(), *v))
             .collect()
     }
@@ -197,9 +474,9 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        let points: Vec<_> = points.take(5).collect();
-        if points.len() == 5 {
-            let width = f64::from(self.width);
+        let points: Vec<_> = points.collect();
+        if points.len() >= 7 {
+            let width = f64::from(self.width) * self.width_factor;
             let moved = |coord| O::with_offset(coord, self.offset);
             let start_bar = |coord| O::with_offset(moved(coord), -width / 2.0);
             let end_bar = |coord| O::with_offset(moved(coord), width / 2.0);
@@ -207,6 +484,8 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
                 |coord| O::with_offset(moved(coord), -width * self.whisker_width / 2.0);
             let end_whisker =
                 |coord| O::with_offset(moved(coord), width * self.whisker_width / 2.0);
+            let median_style = self.median_style.as_ref().unwrap_or(&self.style);
+            let outline_style = self.box_outline.as_ref().unwrap_or(&self.style);
 
             // |---[   |  ]----|
             // ^________________
@@ -225,17 +504,49 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
                 &self.style.color.to_backend_color(),
             )?;
 
-            // |---[   |  ]----|
-            // ____^______^_____
-            let corner1 = start_bar(points[3]);
-            let corner2 = end_bar(points[1]);
-            let upper_left = (corner1.0.min(corner2.0), corner1.1.min(corner2.1));
-            let bottom_right = (corner1.0.max(corner2.0), corner1.1.max(corner2.1));
-            backend.draw_rect(upper_left, bottom_right, &self.style, false)?;
-
-            // |---[   |  ]----|
-            // ________^________
-            backend.draw_line(start_bar(points[2]), end_bar(points[2]), &self.style)?;
+            if self.notched {
+                let start_notch = |coord| O::with_offset(moved(coord), -width / 4.0);
+                let end_notch = |coord| O::with_offset(moved(coord), width / 4.0);
+
+                // |---[  >|<  ]----|
+                // ____^^^^_^^^^_____
+                let mut outline = vec![
+                    start_bar(points[1]),
+                    start_bar(points[5]),
+                    start_notch(points[2]),
+                    start_bar(points[6]),
+                    start_bar(points[3]),
+                    end_bar(points[3]),
+                    end_bar(points[6]),
+                    end_notch(points[2]),
+                    end_bar(points[5]),
+                    end_bar(points[1]),
+                ];
+                if let Some(fill) = &self.box_fill {
+                    backend.fill_polygon(outline.clone(), fill)?;
+                }
+                outline.push(outline[0]);
+                backend.draw_path(outline, outline_style)?;
+
+                // |---[  >|<  ]----|
+                // ________^_________
+                backend.draw_line(start_notch(points[2]), end_notch(points[2]), median_style)?;
+            } else {
+                // |---[   |  ]----|
+                // ____^______^_____
+                let corner1 = start_bar(points[3]);
+                let corner2 = end_bar(points[1]);
+                let upper_left = (corner1.0.min(corner2.0), corner1.1.min(corner2.1));
+                let bottom_right = (corner1.0.max(corner2.0), corner1.1.max(corner2.1));
+                if let Some(fill) = &self.box_fill {
+                    backend.draw_rect(upper_left, bottom_right, fill, true)?;
+                }
+                backend.draw_rect(upper_left, bottom_right, outline_style, false)?;
+
+                // |---[   |  ]----|
+                // ________^________
+                backend.draw_line(start_bar(points[2]), end_bar(points[2]), median_style)?;
+            }
 
             // |---[   |  ]----|
             // ____________^^^^_
@@ -248,6 +559,39 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
                 end_whisker(points[4]),
                 &self.style,
             )?;
+
+            // |---[ <>|  ]----|
+            // ______^^_________
+            if let (Some((_, style)), Some(&mean)) = (&self.mean, points.get(7)) {
+                let (x, y) = moved(mean);
+                let radius = (width / 4.0) as i32;
+                let diamond = vec![
+                    (x - radius, y),
+                    (x, y - radius),
+                    (x + radius, y),
+                    (x, y + radius),
+                ];
+                if style.filled {
+                    backend.fill_polygon(diamond, &style.color)?;
+                } else {
+                    let mut path = diamond.clone();
+                    path.push(diamond[0]);
+                    backend.draw_path(path, style)?;
+                }
+            }
+
+            // o |---[   |  ]----| o
+            // ^___________________^
+            let outliers_start = 7 + self.mean.iter().count();
+            let outlier_style = self.outlier_style.as_ref().unwrap_or(&self.style);
+            for &outlier in points.iter().skip(outliers_start) {
+                backend.draw_circle(
+                    moved(outlier),
+                    OUTLIER_RADIUS,
+                    outlier_style,
+                    outlier_style.filled,
+                )?;
+            }
         }
         Ok(())
     }
@@ -256,6 +600,7 @@ impl<K, DB: DrawingBackend, O: BoxplotOrient<K, f32>> Drawable<DB> for Boxplot<K
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::data::QuartileMethod;
     use crate::prelude::*;
 
     #[test]
@@ -285,4 +630,186 @@ mod test {
             .draw(&Boxplot::new_horizontal(1, &values))
             .is_ok());
     }
+
+    #[test]
+    fn test_width_by_count() {
+        let root = crate::create_mocked_drawing_area(1024, 768, |m| {
+            m.check_draw_rect(|_, _, _, upper_left, bottom_right| {
+                assert_eq!(bottom_right.0 - upper_left.0, 10);
+            });
+            m.check_draw_rect(|_, _, _, upper_left, bottom_right| {
+                assert_eq!(bottom_right.0 - upper_left.0, 20);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+            });
+        });
+        let chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..3, 0f32..400f32)
+            .unwrap();
+
+        let small: Vec<_> = (0..100).collect();
+        let large: Vec<_> = (0..400).collect();
+        let (small, large) = (Quartiles::new(&small), Quartiles::new(&large));
+        let max_count = small.count().max(large.count());
+        for (key, quartiles) in [(1, &small), (2, &large)] {
+            assert!(chart
+                .plotting_area()
+                .draw(
+                    &Boxplot::new_vertical(key, quartiles)
+                        .width(20)
+                        .width_by_count(max_count)
+                )
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_draw_mean_marker() {
+        let root = crate::create_mocked_drawing_area(1024, 768, |m| {
+            m.check_fill_polygon(|c, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(path.len(), 4);
+                assert_eq!(path[2].0 - path[0].0, 10);
+                assert_eq!(path[1].0, path[3].0);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+            });
+        });
+        let chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..2, 0f32..120f32)
+            .unwrap();
+
+        let (values, mean) = Quartiles::new_with_mean(&[1, 2, 3, 4, 100]);
+        assert!(chart
+            .plotting_area()
+            .draw(
+                &Boxplot::new_vertical(1, &values)
+                    .width(20)
+                    .mean_marker(mean, RED.filled())
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn test_draw_notched() {
+        let root = crate::create_mocked_drawing_area(1024, 768, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.len(), 11);
+                assert_eq!(path.first(), path.last());
+                // The box is pinched to half its width at the median
+                assert_eq!(path[5].0 - path[4].0, 10);
+                assert_eq!(path[7].0 - path[2].0, 4);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_rect_call, 0);
+                assert_eq!(b.num_draw_line_call, 5);
+            });
+        });
+        let chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..2, 0f32..20f32)
+            .unwrap();
+
+        let values = Quartiles::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+        assert!(chart
+            .plotting_area()
+            .draw(&Boxplot::new_vertical(1, &values).notched(true))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_box_styles() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let lines = Rc::new(RefCell::new(vec![]));
+        let lines_in = lines.clone();
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_in = rects.clone();
+        let root = crate::create_mocked_drawing_area(1024, 768, move |m| {
+            m.check_draw_line(move |c, w, from, to| {
+                lines_in.borrow_mut().push((c, w, from, to));
+            });
+            m.check_draw_rect(move |c, w, filled, _, _| {
+                rects_in.borrow_mut().push((c, w, filled));
+            });
+        });
+        let chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..2, 0f32..20f32)
+            .unwrap();
+
+        let values = Quartiles::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        chart
+            .plotting_area()
+            .draw(
+                &Boxplot::new_vertical(1, &values)
+                    .width(40)
+                    .whisker_cap_width(0.5)
+                    .median_style(RED.stroke_width(3))
+                    .box_fill(BLUE.mix(0.5))
+                    .box_outline(GREEN.stroke_width(2)),
+            )
+            .unwrap();
+
+        // The box is filled, then outlined
+        assert_eq!(
+            *rects.borrow(),
+            vec![(BLUE.mix(0.5), 1, true), (GREEN.to_rgba(), 2, false)]
+        );
+
+        let lines = lines.borrow();
+        assert_eq!(lines.len(), 5);
+        // The caps are half of the bar width and centered on the whiskers
+        for &(c, w, from, to) in &[lines[0], lines[4]] {
+            assert_eq!((c, w), (BLACK.to_rgba(), 1));
+            assert_eq!(to.0 - from.0, 20);
+            assert_eq!(from.0 + to.0, 2 * lines[1].2 .0);
+        }
+        // The median is thicker and spans the box
+        let (c, w, from, to) = lines[2];
+        assert_eq!((c, w), (RED.to_rgba(), 3));
+        assert_eq!(to.0 - from.0, 40);
+    }
+
+    #[test]
+    fn test_from_data() {
+        let root = crate::create_mocked_drawing_area(1024, 768, |m| {
+            m.check_draw_circle(|c, _, filled, center, radius| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(filled);
+                assert_eq!(center.0, 511);
+                assert_eq!(radius, OUTLIER_RADIUS);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 2);
+            });
+        });
+        let chart = ChartBuilder::on(&root)
+            .build_cartesian_2d(0..2, -100f32..200f32)
+            .unwrap();
+
+        // The outliers are beyond the fences of the quartiles computed by the same constructor
+        let data = [-50, 7, 15, 36, 39, 40, 41, 150];
+        assert_eq!(
+            Boxplot::from_data(1, &data, Quartiles::real).outliers.len(),
+            0
+        );
+        let method = QuartileMethod::Exclusive;
+        let boxplot = Boxplot::from_data(1, &data, |s: &[i32]| Quartiles::with_method(s, method));
+        assert_eq!(boxplot.outliers.len(), 0);
+        let boxplot = Boxplot::from_data(1, &data, Quartiles::new);
+        assert_eq!(boxplot.values, Quartiles::new(&data).values());
+        assert_eq!(boxplot.outliers, [-50.0, 150.0]);
+
+        let plotting_area = chart.plotting_area();
+        plotting_area
+            .draw(&boxplot.width(20).outlier_style(RED.filled()))
+            .unwrap();
+        // The outliers can be hidden
+        plotting_area
+            .draw(&Boxplot::from_data(1, &data, Quartiles::new).show_outliers(false))
+            .unwrap();
+    }
 }
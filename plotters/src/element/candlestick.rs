@@ -5,16 +5,58 @@
 use std::cmp::Ordering;
 
 use crate::element::{Drawable, PointCollection};
-use crate::style::ShapeStyle;
+use crate::style::{Color, ShapeStyle, GREEN, RED};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
-/// The candlestick data point element
+/**
+The candlestick data point element
+
+A candlestick shows the open, high, low and close values of a period at `x`: the body spans
+the open and close values, and the wick, centered on the body, spans the high and low values.
+The gain candles, which close above their open value, and the loss candles can have different
+fill and stroke styles, and the gain candles can be drawn hollow.
+
+```rust
+use plotters::prelude::*;
+
+let data = [
+    (0, 10.0, 12.5, 9.5, 12.0),
+    (1, 12.0, 13.0, 10.5, 11.0),
+    (2, 11.0, 11.5, 10.0, 11.0),
+    (3, 11.0, 14.0, 10.5, 13.5),
+];
+let drawing_area = SVGBackend::new("candlestick.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(-1..4, 9.0..14.5)
+    .unwrap();
+chart
+    .draw_series(data.iter().map(|&(x, open, high, low, close)| {
+        CandleStick::ohlc(x, open, high, low, close)
+            .gain_style(GREEN, BLACK)
+            .loss_style(RED, BLACK)
+            .hollow_gain(true)
+            .body_width(21)
+    }))
+    .unwrap();
+```
+*/
 pub struct CandleStick<X, Y: PartialOrd> {
-    style: ShapeStyle,
+    gain: bool,
+    gain_fill: Option<ShapeStyle>,
+    gain_stroke: ShapeStyle,
+    loss_fill: Option<ShapeStyle>,
+    loss_stroke: ShapeStyle,
+    hollow_gain: bool,
     width: u32,
     points: [(X, Y); 4],
 }
 
+// Split a single style into the body fill, if the style is filled, and the stroke
+fn split_style(style: ShapeStyle) -> (Option<ShapeStyle>, ShapeStyle) {
+    (if style.filled { Some(style) } else { None }, style)
+}
+
 impl<X: Clone, Y: PartialOrd> CandleStick<X, Y> {
     /// Create a new candlestick element, which requires the Y coordinate can be compared
     ///
@@ -45,11 +87,15 @@ impl<X: Clone, Y: PartialOrd> CandleStick<X, Y> {
         loss_style: LS,
         width: u32,
     ) -> Self {
+        let (gain_fill, gain_stroke) = split_style(gain_style.into());
+        let (loss_fill, loss_stroke) = split_style(loss_style.into());
         Self {
-            style: match open.partial_cmp(&close) {
-                Some(Ordering::Less) => gain_style.into(),
-                _ => loss_style.into(),
-            },
+            gain: open.partial_cmp(&close) == Some(Ordering::Less),
+            gain_fill,
+            gain_stroke,
+            loss_fill,
+            loss_stroke,
+            hollow_gain: false,
             width,
             points: [
                 (x.clone(), open),
@@ -59,6 +105,100 @@ impl<X: Clone, Y: PartialOrd> CandleStick<X, Y> {
             ],
         }
     }
+
+    /// Create a new candlestick element with the default styles: filled green gain candles
+    /// and filled red loss candles, 15 pixels wide
+    ///
+    /// - `x`: The x coordinate
+    /// - `open`: The open value
+    /// - `high`: The high value
+    /// - `low`: The low value
+    /// - `close`: The close value
+    /// - **returns** The newly created candlestick element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let candlestick = CandleStick::ohlc(1, 130.06, 131.37, 128.83, 129.15);
+    /// ```
+    pub fn ohlc(x: X, open: Y, high: Y, low: Y, close: Y) -> Self {
+        Self::new(x, open, high, low, close, GREEN.filled(), RED.filled(), 15)
+    }
+}
+
+impl<X, Y: PartialOrd> CandleStick<X, Y> {
+    /// Set the styles of the gain candles, which close above their open value.
+    ///
+    /// - `fill`: The style used to fill the body
+    /// - `stroke`: The style of the wick and of the outline of the body
+    /// - **returns** The up-to-dated candlestick element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let candlestick = CandleStick::ohlc(1, 129.15, 131.37, 128.83, 130.06).gain_style(GREEN, BLACK);
+    /// ```
+    pub fn gain_style<F: Into<ShapeStyle>, S: Into<ShapeStyle>>(
+        mut self,
+        fill: F,
+        stroke: S,
+    ) -> Self {
+        self.gain_fill = Some(fill.into());
+        self.gain_stroke = stroke.into();
+        self
+    }
+
+    /// Set the styles of the loss candles, which close at or below their open value.
+    ///
+    /// - `fill`: The style used to fill the body
+    /// - `stroke`: The style of the wick and of the outline of the body
+    /// - **returns** The up-to-dated candlestick element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let candlestick = CandleStick::ohlc(1, 130.06, 131.37, 128.83, 129.15).loss_style(RED, BLACK);
+    /// ```
+    pub fn loss_style<F: Into<ShapeStyle>, S: Into<ShapeStyle>>(
+        mut self,
+        fill: F,
+        stroke: S,
+    ) -> Self {
+        self.loss_fill = Some(fill.into());
+        self.loss_stroke = stroke.into();
+        self
+    }
+
+    /// Set if the body of the gain candles is left hollow, showing only its outline.
+    ///
+    /// - `hollow`: If the gain candles are hollow
+    /// - **returns** The up-to-dated candlestick element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let candlestick = CandleStick::ohlc(1, 129.15, 131.37, 128.83, 130.06).hollow_gain(true);
+    /// ```
+    pub fn hollow_gain(mut self, hollow: bool) -> Self {
+        self.hollow_gain = hollow;
+        self
+    }
+
+    /// Set the width of the body.
+    ///
+    /// - `width`: The width of the body in pixels, an odd width centers the body on the wick
+    ///   exactly
+    /// - **returns** The up-to-dated candlestick element
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let candlestick = CandleStick::ohlc(1, 130.06, 131.37, 128.83, 129.15).body_width(9);
+    /// ```
+    pub fn body_width(mut self, width: u32) -> Self {
+        self.width = width;
+        self
+    }
 }
 
 impl<'a, X: 'a, Y: PartialOrd + 'a> PointCollection<'a, (X, Y)> for &'a CandleStick<X, Y> {
@@ -76,25 +216,123 @@ impl<X, Y: PartialOrd, DB: DrawingBackend> Drawable<DB> for CandleStick<X, Y> {
         backend: &mut DB,
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
-        let mut points: Vec<_> = points.take(4).collect();
+        let points: Vec<_> = points.take(4).collect();
         if points.len() == 4 {
-            let fill = self.style.filled;
-            if points[0].1 > points[3].1 {
-                points.swap(0, 3);
-            }
-            let (l, r) = (
-                self.width as i32 / 2,
-                self.width as i32 - self.width as i32 / 2,
-            );
+            let (fill, stroke) = if self.gain {
+                let fill = if self.hollow_gain {
+                    None
+                } else {
+                    self.gain_fill
+                };
+                (fill, &self.gain_stroke)
+            } else {
+                (self.loss_fill, &self.loss_stroke)
+            };
 
-            backend.draw_line(points[0], points[1], &self.style)?;
-            backend.draw_line(points[2], points[3], &self.style)?;
+            let x = points[1].0;
+            let top = points[0].1.min(points[3].1);
+            let bottom = points[0].1.max(points[3].1);
 
-            points[0].0 -= l;
-            points[3].0 += r;
+            backend.draw_line((x, points[1].1), (x, top), stroke)?;
+            backend.draw_line((x, bottom), (x, points[2].1), stroke)?;
 
-            backend.draw_rect(points[0], points[3], &self.style, fill)?;
+            let left = x - (self.width.max(1) as i32 - 1) / 2;
+            let right = left + self.width.max(1) as i32 - 1;
+
+            if top == bottom {
+                // A doji has no body, mark its open and close value with a line
+                return backend.draw_line((left, top), (right, top), stroke);
+            }
+
+            if let Some(fill) = fill {
+                backend.draw_rect((left, top), (right, bottom), &fill, true)?;
+            }
+            backend.draw_rect((left, top), (right, bottom), stroke, false)?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_wick_centered_on_body() {
+        let root = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_line(|_, _, from, to| {
+                assert_eq!(from.0, 50);
+                assert_eq!(to.0, 50);
+            });
+            m.check_draw_rect(|_, _, _, upper_left, bottom_right| {
+                assert_eq!(upper_left.0, 46);
+                assert_eq!(bottom_right.0, 54);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 4);
+                assert_eq!(b.num_draw_rect_call, 3);
+            });
+        });
+        root.draw(&CandleStick::new(50, 40, 10, 90, 60, GREEN, RED, 9))
+            .unwrap();
+        root.draw(
+            &CandleStick::ohlc(50, 40, 10, 90, 60)
+                .gain_style(GREEN, BLACK)
+                .body_width(9),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_gain_and_loss_styles() {
+        let root = crate::create_mocked_drawing_area(100, 100, |m| {
+            // The gain candle: wicks and outline only, as it is hollow
+            m.check_draw_line(|c, _, _, _| assert_eq!(c, BLUE.to_rgba()));
+            m.check_draw_line(|c, _, _, _| assert_eq!(c, BLUE.to_rgba()));
+            m.check_draw_rect(|c, _, fill, _, _| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(!fill);
+            });
+            // The loss candle: wicks, body and outline
+            m.check_draw_line(|c, _, _, _| assert_eq!(c, BLACK.to_rgba()));
+            m.check_draw_rect(|c, _, fill, _, _| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(fill);
+            });
+            m.check_draw_rect(|c, _, fill, _, _| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert!(!fill);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 4);
+                assert_eq!(b.num_draw_rect_call, 3);
+            });
+        });
+        let candle = |open, close| {
+            CandleStick::ohlc(50, open, 10, 90, close)
+                .gain_style(GREEN, BLUE)
+                .loss_style(RED, BLACK)
+                .hollow_gain(true)
+        };
+        root.draw(&candle(40, 60)).unwrap();
+        root.draw(&candle(60, 40)).unwrap();
+    }
+
+    #[test]
+    fn test_doji() {
+        let root = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_line(|_, _, from, to| assert_eq!(from.0, to.0));
+            m.check_draw_line(|_, _, from, to| assert_eq!(from.0, to.0));
+            m.check_draw_line(|_, _, from, to| {
+                assert_eq!(from, (43, 50));
+                assert_eq!(to, (57, 50));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 3);
+                assert_eq!(b.num_draw_rect_call, 0);
+            });
+        });
+        root.draw(&CandleStick::ohlc(50, 50, 10, 90, 50)).unwrap();
+    }
+}
@@ -0,0 +1,433 @@
+use std::ops::Range;
+
+use crate::coord::combinators::{IntoLogRange, LogCoord};
+use crate::coord::ranged1d::Ranged;
+use crate::coord::types::RangedCoordf64;
+use crate::element::{Drawable, PointCollection};
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, RGBAColor, ShapeStyle, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+// The length of the ticks and the gap between a tick and its label
+const TICK_SIZE: i32 = 5;
+const LABEL_GAP: i32 = 3;
+
+/**
+A colorbar, showing how a colormap maps the values of a range to colors.
+
+The colorbar is a strip filled with the colors of the range, with ticks and labels at round
+values, picked the same way as the key points of an axis. A vertical colorbar puts the end of
+the range at the top and its labels on the right, a horizontal one puts the start of the range
+at the left and its labels below.
+
+With [`ColorBar::log_scale()`], the values are spread logarithmically along the strip, the ticks
+are placed like the key points of a log axis, and the labels of the powers of ten are written
+as such. The range must then be positive.
+
+The strip is anchored by its upper left corner and sized in pixels, the labels are drawn out
+of it. To draw a colorbar next to a chart, in an area reserved for it, see
+[`crate::chart::ChartContext::configure_colorbar()`].
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("colorbar.svg", (100, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+drawing_area
+    .draw(
+        &ColorBar::vertical((10, 10), (20, 280), -1.0..1.0, &ViridisRGB)
+            .labels(5)
+            .label_formatter(|value| format!("{:+.1}", value)),
+    )
+    .unwrap();
+```
+*/
+pub struct ColorBar<'a, Coord> {
+    pos: Coord,
+    size: (u32, u32),
+    range: Range<f64>,
+    colormap: Box<dyn Fn(f64, f64, f64) -> RGBAColor + 'a>,
+    horizontal: bool,
+    log_scale: bool,
+    labels: usize,
+    label_formatter: Option<Box<dyn Fn(f64) -> String + 'a>>,
+    label_style: TextStyle<'a>,
+    border_style: ShapeStyle,
+}
+
+impl<'a, Coord> ColorBar<'a, Coord> {
+    /**
+    Creates a vertical colorbar, going from the start of the range at the bottom to its end at
+    the top.
+
+    - `pos`: The upper left corner of the strip
+    - `size`: The width and the height of the strip in pixels
+    - `range`: The range of values spanned by the colormap
+    - `colormap`: The colormap picking the color of each value
+    - **returns** The newly created colorbar
+
+    See [`ColorBar`] for more information and examples.
+    */
+    pub fn vertical<C: Color, M: ColorMap<C, f64>>(
+        pos: Coord,
+        size: (u32, u32),
+        range: Range<f64>,
+        colormap: &'a M,
+    ) -> Self {
+        Self::new(pos, size, range, colormap, false)
+    }
+
+    /**
+    Creates a horizontal colorbar, going from the start of the range at the left to its end at
+    the right.
+
+    - `pos`: The upper left corner of the strip
+    - `size`: The width and the height of the strip in pixels
+    - `range`: The range of values spanned by the colormap
+    - `colormap`: The colormap picking the color of each value
+    - **returns** The newly created colorbar
+
+    See [`ColorBar`] for more information and examples.
+    */
+    pub fn horizontal<C: Color, M: ColorMap<C, f64>>(
+        pos: Coord,
+        size: (u32, u32),
+        range: Range<f64>,
+        colormap: &'a M,
+    ) -> Self {
+        Self::new(pos, size, range, colormap, true)
+    }
+
+    fn new<C: Color, M: ColorMap<C, f64>>(
+        pos: Coord,
+        size: (u32, u32),
+        range: Range<f64>,
+        colormap: &'a M,
+        horizontal: bool,
+    ) -> Self {
+        Self {
+            pos,
+            size,
+            range,
+            colormap: Box::new(move |value, min, max| {
+                colormap.get_color_normalized(value, min, max).to_rgba()
+            }),
+            horizontal,
+            log_scale: false,
+            labels: 5,
+            label_formatter: None,
+            label_style: ("sans-serif", 12).into_font().into(),
+            border_style: BLACK.into(),
+        }
+    }
+
+    /**
+    Sets the maximum number of labels along the colorbar, 5 by default. Setting it to 0 draws
+    neither ticks nor labels.
+
+    See [`ColorBar`] for more information and examples.
+    */
+    pub fn labels(mut self, labels: usize) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /**
+    Spreads the values logarithmically along the colorbar, which must have a positive range.
+    The colors are picked from the colormap by the logarithm of the values, like
+    [`crate::series::Heatmap::new_log_scale()`] does.
+
+    See [`ColorBar`] for more information and examples.
+    */
+    pub fn log_scale(mut self) -> Self {
+        self.log_scale = true;
+        self
+    }
+
+    /**
+    Sets how the values of the labels are formatted. By default, they are written as is, and
+    the powers of ten of a log scaled colorbar are written as such, e.g. `10³`.
+
+    See [`ColorBar`] for more information and examples.
+    */
+    pub fn label_formatter(mut self, formatter: impl Fn(f64) -> String + 'a) -> Self {
+        self.label_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /**
+    Sets the style of the labels, which are always aligned on their tick.
+
+    See [`ColorBar`] for more information and examples.
+    */
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the border of the strip and of its ticks.
+
+    See [`ColorBar`] for more information and examples.
+    */
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+
+    pub(crate) fn relocate(&mut self, pos: Coord, size: (u32, u32)) {
+        self.pos = pos;
+        self.size = size;
+    }
+
+    // The value on the scale of the colorbar, which is its logarithm for a log scale
+    fn scaled(&self, value: f64) -> f64 {
+        if self.log_scale {
+            value.ln()
+        } else {
+            value
+        }
+    }
+
+    // The offset along the strip of the given value, from the start of the range
+    fn offset_of(&self, value: f64, length: i32) -> i32 {
+        let start = self.scaled(self.range.start);
+        let span = self.scaled(self.range.end) - start;
+        if span == 0.0 {
+            return 0;
+        }
+        ((self.scaled(value) - start) / span * f64::from(length)).round() as i32
+    }
+
+    // The values of the ticks, picked like the key points of an axis of the same scale
+    fn tick_values(&self) -> Vec<f64> {
+        let (min, max) = if self.range.end < self.range.start {
+            (self.range.end, self.range.start)
+        } else {
+            (self.range.start, self.range.end)
+        };
+        if self.log_scale {
+            LogCoord::from((min..max).log_scale()).key_points(self.labels)
+        } else {
+            RangedCoordf64::from(min..max).key_points(self.labels)
+        }
+    }
+
+    fn format_label(&self, value: f64) -> String {
+        match &self.label_formatter {
+            Some(formatter) => formatter(value),
+            None if self.log_scale => format_power_of_ten(value),
+            None => format!("{}", value),
+        }
+    }
+}
+
+// Writes the powers of ten with a superscript exponent, and the other values as is
+fn format_power_of_ten(value: f64) -> String {
+    const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    let exponent = value.log10().round();
+    if value <= 0.0 || (10f64.powf(exponent) - value).abs() > value * 1e-9 {
+        return format!("{}", value);
+    }
+    let mut label = String::from("10");
+    if exponent < 0.0 {
+        label.push('⁻');
+    }
+    for digit in (exponent.abs() as u32).to_string().bytes() {
+        label.push(SUPERSCRIPTS[usize::from(digit - b'0')]);
+    }
+    label
+}
+
+impl<'b, 'a, Coord> PointCollection<'b, Coord> for &'b ColorBar<'a, Coord> {
+    type Point = &'b Coord;
+    type IntoIter = std::iter::Once<&'b Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for ColorBar<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(pos) => pos,
+            None => return Ok(()),
+        };
+        let (w, h) = (self.size.0 as i32, self.size.1 as i32);
+        let (x1, y1) = (x0 + w, y0 + h);
+        let length = if self.horizontal { w } else { h };
+        if length <= 0 || (self.log_scale && self.range.start.min(self.range.end) <= 0.0) {
+            return Ok(());
+        }
+
+        // The strip is filled one pixel line at a time, with the color of the line's center
+        let (start, end) = (self.scaled(self.range.start), self.scaled(self.range.end));
+        for idx in 0..length {
+            let value = start + (end - start) * (f64::from(idx) + 0.5) / f64::from(length);
+            let style = ShapeStyle::from((self.colormap)(value, start, end)).filled();
+            let (from, to) = if self.horizontal {
+                ((x0 + idx, y0), (x0 + idx + 1, y1))
+            } else {
+                ((x0, y1 - idx - 1), (x1, y1 - idx))
+            };
+            backend.draw_rect(from, to, &style, true)?;
+        }
+        backend.draw_rect((x0, y0), (x1, y1), &self.border_style, false)?;
+
+        if self.labels == 0 {
+            return Ok(());
+        }
+        let label_style = if self.horizontal {
+            self.label_style.pos(Pos::new(HPos::Center, VPos::Top))
+        } else {
+            self.label_style.pos(Pos::new(HPos::Left, VPos::Center))
+        };
+        for value in self.tick_values() {
+            let offset = self.offset_of(value, length);
+            let (tick_from, tick_to, label_pos) = if self.horizontal {
+                let x = x0 + offset;
+                (
+                    (x, y1),
+                    (x, y1 + TICK_SIZE),
+                    (x, y1 + TICK_SIZE + LABEL_GAP),
+                )
+            } else {
+                let y = y1 - offset;
+                (
+                    (x1, y),
+                    (x1 + TICK_SIZE, y),
+                    (x1 + TICK_SIZE + LABEL_GAP, y),
+                )
+            };
+            backend.draw_line(tick_from, tick_to, &self.border_style)?;
+            backend.draw_text(&self.format_label(value), &label_style, label_pos)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::format_power_of_ten;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_vertical_colorbar() {
+        let colormap = DerivedColorMap::new(&[BLACK, WHITE]);
+        let drawing_area = crate::create_mocked_drawing_area(100, 200, |m| {
+            // The bottom line takes the start of the range, and the top line its end
+            m.check_draw_rect(|c, _, filled, u, d| {
+                assert!(filled);
+                assert_eq!((u, d), ((10, 109), (30, 110)));
+                assert!(c.0 < 5);
+            });
+            for _ in 1..99 {
+                m.check_draw_rect(|_, _, filled, _, _| assert!(filled));
+            }
+            m.check_draw_rect(|c, _, filled, u, d| {
+                assert!(filled);
+                assert_eq!((u, d), ((10, 10), (30, 11)));
+                assert!(c.0 > 250);
+            });
+            m.check_draw_rect(|c, _, filled, u, d| {
+                assert!(!filled);
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!((u, d), ((10, 10), (30, 110)));
+            });
+            let mut ticks = vec![(110, "0"), (60, "50"), (10, "100")];
+            let tick_ys: Vec<_> = ticks.iter().map(|(y, _)| *y).collect();
+            m.check_draw_line(move |_, _, from, to| {
+                assert_eq!(from.0, 30);
+                assert_eq!(to, (35, from.1));
+                assert!(tick_ys.contains(&from.1));
+            });
+            m.check_draw_text(move |_, _, _, pos, text| {
+                let (y, label) = ticks.remove(0);
+                assert_eq!(pos, (38, y));
+                assert_eq!(text, label);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 101);
+                assert_eq!(b.num_draw_line_call, 3);
+                assert_eq!(b.num_draw_text_call, 3);
+            });
+        });
+
+        drawing_area
+            .draw(&ColorBar::vertical((10, 10), (20, 100), 0.0..100.0, &colormap).labels(3))
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_horizontal_colorbar_formatter() {
+        let drawing_area = crate::create_mocked_drawing_area(200, 100, |m| {
+            m.check_draw_text(|_, _, _, pos, text| {
+                assert_eq!(pos.1, 28);
+                assert!(text.ends_with('%'));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 101);
+                assert!(b.num_draw_text_call > 1);
+            });
+        });
+
+        drawing_area
+            .draw(
+                &ColorBar::horizontal((0, 0), (100, 20), 0.0..1.0, &ViridisRGB)
+                    .label_formatter(|value| format!("{:.0}%", value * 100.0)),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_log_scale_colorbar() {
+        let colormap = DerivedColorMap::new(&[BLACK, WHITE]);
+        let drawing_area = crate::create_mocked_drawing_area(100, 200, |m| {
+            // The middle of the strip takes the middle of the colormap, at the geometric mean
+            for _ in 0..75 {
+                m.check_draw_rect(|_, _, filled, _, _| assert!(filled));
+            }
+            m.check_draw_rect(|c, _, filled, u, _| {
+                assert!(filled);
+                assert_eq!(u.1, 84);
+                assert!(c.0 >= 126 && c.0 <= 130);
+            });
+            m.check_draw_rect(|_, _, _, _, _| {});
+            // The ticks are at the powers of ten, evenly spaced
+            let mut ticks = vec![(160, "10⁰"), (110, "10¹"), (60, "10²"), (10, "10³")];
+            m.check_draw_text(move |_, _, _, pos, text| {
+                let (y, label) = ticks.remove(0);
+                assert_eq!(pos, (38, y));
+                assert_eq!(text, label);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 151);
+                assert_eq!(b.num_draw_text_call, 4);
+            });
+        });
+
+        drawing_area
+            .draw(
+                &ColorBar::vertical((10, 10), (20, 150), 1.0..1000.0, &colormap)
+                    .log_scale()
+                    .labels(4),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_format_power_of_ten() {
+        assert_eq!(format_power_of_ten(1.0), "10⁰");
+        assert_eq!(format_power_of_ten(1e12), "10¹²");
+        assert_eq!(format_power_of_ten(0.001), "10⁻³");
+        assert_eq!(format_power_of_ten(20.0), "20");
+        assert_eq!(format_power_of_ten(0.0), "0");
+    }
+}
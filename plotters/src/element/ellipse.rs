@@ -0,0 +1,315 @@
+use super::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+An ellipse, sized and rotated in the coordinates of the chart, unlike [`crate::element::Circle`]
+whose radius is in pixels.
+
+The ellipse is given by its center, its two radii and the rotation of its first radius, counter
+clockwise from the X axis, in radians. A filled style fills it, and any other style strokes its
+outline. The ellipse is drawn with [`DrawingBackend::draw_ellipse()`], natively by the SVG
+backend, and as a polygon approximating it by the other backends. Its shape on the backend is
+found from the images of its center and of the ends of its radii, which is exact on linear axes.
+
+[`Ellipse::confidence()`] and [`Ellipse::confidence_of()`] build the confidence ellipse of a 2D
+Gaussian distribution, and [`crate::chart::ChartContext::draw_confidence_ellipse()`] draws the
+confidence ellipse of a set of points on a chart.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("ellipse.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(-5.0..5.0, -3.0..3.0)
+    .unwrap();
+chart
+    .draw_series(std::iter::once(Ellipse::new(
+        (0.0, 0.0),
+        (4.0, 1.5),
+        std::f64::consts::FRAC_PI_6,
+        BLUE.mix(0.3).filled(),
+    )))
+    .unwrap();
+```
+*/
+pub struct Ellipse {
+    center: (f64, f64),
+    radii: (f64, f64),
+    rotation: f64,
+    style: ShapeStyle,
+    // The center, and the ends of the two radii
+    points: [(f64, f64); 3],
+}
+
+impl Ellipse {
+    /**
+    Creates a new ellipse.
+
+    - `center`: The center of the ellipse
+    - `radii`: The radius along the rotated X axis, and the radius along the rotated Y axis
+    - `rotation`: The rotation of the ellipse, counter clockwise, in radians
+    - `style`: The style of the ellipse, filled or stroked
+    - **returns** The newly created ellipse
+
+    See [`Ellipse`] for more information and examples.
+    */
+    pub fn new<S: Into<ShapeStyle>>(
+        center: (f64, f64),
+        radii: (f64, f64),
+        rotation: f64,
+        style: S,
+    ) -> Self {
+        let (sin, cos) = rotation.sin_cos();
+        let points = [
+            center,
+            (center.0 + radii.0 * cos, center.1 + radii.0 * sin),
+            (center.0 - radii.1 * sin, center.1 + radii.1 * cos),
+        ];
+        Self {
+            center,
+            radii,
+            rotation,
+            style: style.into(),
+            points,
+        }
+    }
+
+    /**
+    Creates the confidence ellipse of a 2D Gaussian distribution: the region holding the given
+    share of the distribution.
+
+    The axes of the ellipse are the eigenvectors of the covariance matrix, and its radii are the
+    square roots of the eigenvalues, i.e. the standard deviations along the axes, scaled by the
+    square root of the quantile of the chi-square distribution with two degrees of freedom.
+
+    - `mean`: The mean of the distribution, which is the center of the ellipse
+    - `covariance`: The covariance matrix, `[[var_x, cov_xy], [cov_xy, var_y]]`
+    - `confidence`: The confidence level, e.g. 0.95, in `[0, 1)`. Levels out of this range are
+      clamped to it: a level of 0 or below gives an ellipse of zero radii, and a level of 1 or
+      above gives the ellipse of the largest level below 1, since the exact one is unbounded.
+    - `style`: The style of the ellipse, filled or stroked
+    - **returns** The newly created ellipse
+
+    See [`Ellipse`] for more information and examples.
+    */
+    pub fn confidence<S: Into<ShapeStyle>>(
+        mean: (f64, f64),
+        covariance: [[f64; 2]; 2],
+        confidence: f64,
+        style: S,
+    ) -> Self {
+        let ((major, minor), rotation) = principal_axes(covariance);
+        // Keep the level in [0, 1) so the radii stay finite, a NaN level is taken as 0
+        let confidence = if confidence.is_nan() {
+            0.0
+        } else {
+            confidence.clamp(0.0, 1.0 - f64::EPSILON)
+        };
+        // The quantile of the chi-square distribution with two degrees of freedom
+        let scale = -2.0 * (1.0 - confidence).ln();
+        Self::new(
+            mean,
+            ((major * scale).sqrt(), (minor * scale).sqrt()),
+            rotation,
+            style,
+        )
+    }
+
+    /**
+    Creates the confidence ellipse of a set of points, from their mean and their sample
+    covariance, see [`Ellipse::confidence()`].
+
+    - `points`: The points, assumed to follow a 2D Gaussian distribution
+    - `confidence`: The confidence level, e.g. 0.95, in `[0, 1)`, clamped to it as in
+      [`Ellipse::confidence()`]
+    - `style`: The style of the ellipse, filled or stroked
+    - **returns** The newly created ellipse, or `None` when there are less than two points
+
+    See [`Ellipse`] for more information and examples.
+    */
+    pub fn confidence_of<S: Into<ShapeStyle>>(
+        points: &[(f64, f64)],
+        confidence: f64,
+        style: S,
+    ) -> Option<Self> {
+        if points.len() < 2 {
+            return None;
+        }
+        let n = points.len() as f64;
+        let (sx, sy) = points
+            .iter()
+            .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+        let mean = (sx / n, sy / n);
+        let (mut xx, mut xy, mut yy) = (0.0, 0.0, 0.0);
+        for (x, y) in points {
+            let (dx, dy) = (x - mean.0, y - mean.1);
+            xx += dx * dx;
+            xy += dx * dy;
+            yy += dy * dy;
+        }
+        let covariance = [
+            [xx / (n - 1.0), xy / (n - 1.0)],
+            [xy / (n - 1.0), yy / (n - 1.0)],
+        ];
+        Some(Self::confidence(mean, covariance, confidence, style))
+    }
+
+    /// Returns the center of the ellipse
+    pub fn center(&self) -> (f64, f64) {
+        self.center
+    }
+
+    /// Returns the radius along the rotated X axis and the radius along the rotated Y axis
+    pub fn radii(&self) -> (f64, f64) {
+        self.radii
+    }
+
+    /// Returns the rotation of the ellipse, counter clockwise, in radians
+    pub fn rotation(&self) -> f64 {
+        self.rotation
+    }
+}
+
+// The eigenvalues of a symmetric 2x2 matrix, the largest one first, and the angle of the
+// eigenvector of the largest one
+fn principal_axes([[a, b], [_, d]]: [[f64; 2]; 2]) -> ((f64, f64), f64) {
+    let half_trace = (a + d) / 2.0;
+    let delta = (((a - d) / 2.0).powi(2) + b * b).sqrt();
+    let eigenvalues = (half_trace + delta, (half_trace - delta).max(0.0));
+    (eigenvalues, 0.5 * (2.0 * b).atan2(a - d))
+}
+
+impl<'a> PointCollection<'a, (f64, f64)> for &'a Ellipse {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64); 3];
+    fn point_iter(self) -> &'a [(f64, f64); 3] {
+        &self.points
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for Ellipse {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (center, u, v) = match (points.next(), points.next(), points.next()) {
+            (Some(center), Some(u), Some(v)) => (center, u, v),
+            _ => return Ok(()),
+        };
+        // The ellipse on the backend is the image of the unit circle by the matrix whose
+        // columns are the mapped radii, whose axes are the eigenvectors of M * M^T
+        let (ux, uy) = (f64::from(u.0 - center.0), f64::from(u.1 - center.1));
+        let (vx, vy) = (f64::from(v.0 - center.0), f64::from(v.1 - center.1));
+        let ((major, minor), rotation) = principal_axes([
+            [ux * ux + vx * vx, ux * uy + vx * vy],
+            [ux * uy + vx * vy, uy * uy + vy * vy],
+        ]);
+        backend.draw_ellipse(
+            center,
+            (major.sqrt(), minor.sqrt()),
+            rotation,
+            &self.style,
+            self.style.filled,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_axis_aligned_confidence_ellipse() {
+        // The radii are the standard deviations scaled by the square root of the chi-square
+        // quantile, which is 5.991 for 95% with two degrees of freedom
+        let ellipse = Ellipse::confidence((1.0, 2.0), [[9.0, 0.0], [0.0, 4.0]], 0.95, RED);
+        let scale = 5.991464547107979f64.sqrt();
+        assert_eq!(ellipse.center(), (1.0, 2.0));
+        assert_close(ellipse.radii().0, 3.0 * scale);
+        assert_close(ellipse.radii().1, 2.0 * scale);
+        assert_close(ellipse.rotation(), 0.0);
+
+        // The larger variance along Y turns the ellipse by a quarter turn
+        let ellipse = Ellipse::confidence((0.0, 0.0), [[1.0, 0.0], [0.0, 4.0]], 0.95, RED);
+        assert_close(ellipse.radii().0, 2.0 * scale);
+        assert_close(ellipse.radii().1, scale);
+        assert_close(ellipse.rotation(), std::f64::consts::FRAC_PI_2);
+    }
+
+    #[test]
+    fn test_confidence_out_of_range() {
+        let covariance = [[9.0, 0.0], [0.0, 4.0]];
+        for &confidence in &[1.0, 1.5, f64::INFINITY] {
+            let ellipse = Ellipse::confidence((0.0, 0.0), covariance, confidence, RED);
+            let (major, minor) = ellipse.radii();
+            assert!(major.is_finite() && minor.is_finite());
+            assert!(major > 3.0 && minor > 2.0);
+        }
+        for &confidence in &[0.0, -1.0, f64::NAN] {
+            let ellipse = Ellipse::confidence((0.0, 0.0), covariance, confidence, RED);
+            assert_eq!(ellipse.radii(), (0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_confidence_ellipse_of_points() {
+        // The points spread along the diagonal
+        let points = [
+            (-1.0, -1.0),
+            (1.0, 1.0),
+            (-1.0, 1.0),
+            (1.0, -1.0),
+            (2.0, 2.0),
+        ];
+        let ellipse = Ellipse::confidence_of(&points, 0.5, RED).unwrap();
+        assert_close(ellipse.center().0, 0.4);
+        assert_close(ellipse.center().1, 0.4);
+        assert_close(ellipse.rotation(), std::f64::consts::FRAC_PI_4);
+        assert!(ellipse.radii().0 > ellipse.radii().1);
+        assert!(Ellipse::confidence_of(&points[..1], 0.5, RED).is_none());
+    }
+
+    #[test]
+    fn test_ellipse_element() {
+        let da = crate::create_mocked_drawing_area(101, 101, |m| {
+            // The ellipse is turned clockwise on the backend, whose Y axis goes down
+            m.check_fill_polygon(|c, points| {
+                assert_eq!(c, BLUE.to_rgba());
+                // The rotated major axis ends at the top right and the bottom left
+                assert_eq!(points[0], (80, 20));
+                assert!(points.contains(&(20, 80)));
+            });
+            m.check_draw_path(|c, _, points| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(points.first(), Some(&(80, 20)));
+                assert_eq!(points.first(), points.last());
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&da)
+            .build_cartesian_2d(0.0..100.0, 0.0..100.0)
+            .unwrap();
+        let (radii, rotation) = ((30.0 * 2f64.sqrt(), 10.0), std::f64::consts::FRAC_PI_4);
+        let styles = [BLUE.filled(), RED.into()];
+        chart
+            .draw_series(
+                styles
+                    .iter()
+                    .map(|&style| Ellipse::new((50.0, 50.0), radii, rotation, style)),
+            )
+            .unwrap();
+    }
+}
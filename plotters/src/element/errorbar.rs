@@ -71,6 +71,8 @@ An error bar, which visualizes the minimum, average, and maximum of a dataset.
 
 Unlike [`crate::series::Histogram`], the `ErrorBar` code does not classify or aggregate data.
 These operations must be done before building error bars.
+The minimum, average, and maximum are absolute values mapped through the coordinate system,
+so error bars are rendered correctly on non-linear axes such as logarithmic ones.
 
 # Examples
 
@@ -221,3 +223,41 @@ fn test_preserve_stroke_width() {
     da.draw(&h).expect("Drawing Failure");
     da.draw(&v).expect("Drawing Failure");
 }
+
+#[cfg(test)]
+#[test]
+fn test_log_scale_caps() {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let caps = Rc::new(RefCell::new(vec![]));
+    let recorded = caps.clone();
+    let da = crate::create_mocked_drawing_area(300, 300, move |m| {
+        for _ in 0..2 {
+            let recorded = recorded.clone();
+            m.check_draw_line(move |_, _, from, to| {
+                recorded.borrow_mut().push((from, to));
+            });
+        }
+    });
+    let chart = ChartBuilder::on(&da)
+        .build_cartesian_2d(0..10, (1f64..10000f64).log_scale())
+        .unwrap();
+    chart
+        .plotting_area()
+        .draw(&ErrorBar::new_vertical(5, 10.0, 100.0, 1000.0, BLUE, 10))
+        .expect("Drawing Failure");
+
+    // The bounds are mapped through the log coordinate, so the caps are evenly spaced
+    // around the average, unlike on a linear axis
+    let (lower, avg, upper) = (
+        chart.backend_coord(&(5, 10.0)),
+        chart.backend_coord(&(5, 100.0)),
+        chart.backend_coord(&(5, 1000.0)),
+    );
+    let caps = caps.borrow();
+    assert_eq!(caps[0], ((lower.0 - 5, lower.1), (lower.0 + 5, lower.1)));
+    assert_eq!(caps[1], ((upper.0 - 5, upper.1), (upper.0 + 5, upper.1)));
+    assert!(((lower.1 - avg.1) - (avg.1 - upper.1)).abs() <= 1);
+}
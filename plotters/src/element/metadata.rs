@@ -0,0 +1,162 @@
+use super::{Drawable, DynElement, IntoDynElement, PointCollection};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+An element carrying metadata, such as a tooltip or data attributes, for the backends writing an
+interactive document. The SVG backend renders the tooltip as a `<title>` child of each shape of
+the element, which the browsers show when hovering it, and the data as `data-*` attributes.
+The other backends draw the element as usual.
+
+The metadata is attached with [`IntoMetadataElement::with_tooltip`] and
+[`IntoMetadataElement::with_data`], which are available on every element, like
+[`IntoDynElement::into_dyn`], and turn it into a dynamically dispatched element.
+
+# Example
+
+```
+use plotters::prelude::*;
+let mut buffer = String::new();
+{
+    let drawing_area = SVGBackend::with_string(&mut buffer, (300, 200)).into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(0.0..10.0, 0.0..100.0)
+        .unwrap();
+    chart
+        .draw_series([(2.0, 42.0), (7.0, 64.0)].iter().map(|&(x, y)| {
+            Circle::new((x, y), 5, BLUE.filled())
+                .with_tooltip(format!("value: {}", y))
+                .with_data("value", y.to_string())
+        }))
+        .unwrap();
+}
+assert!(buffer.contains("<title>value: 42</title>"));
+assert!(buffer.contains("data-value=\"64\""));
+```
+*/
+pub struct WithMetadata<'a, DB: DrawingBackend, Coord: Clone> {
+    element: DynElement<'a, DB, Coord>,
+    tooltip: Option<String>,
+    data: Vec<(String, String)>,
+}
+
+impl<'a, DB: DrawingBackend, Coord: Clone> WithMetadata<'a, DB, Coord> {
+    fn new(element: DynElement<'a, DB, Coord>) -> Self {
+        Self {
+            element,
+            tooltip: None,
+            data: vec![],
+        }
+    }
+
+    /**
+    Sets the tooltip of the element, replacing the previous one.
+
+    See [`WithMetadata`] for more information and examples.
+    */
+    pub fn with_tooltip<S: Into<String>>(mut self, tooltip: S) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /**
+    Adds a key/value pair to the data of the element, rendered as a `data-<key>` attribute by
+    the SVG backend. The key should only contain lowercase letters, digits and hyphens.
+
+    See [`WithMetadata`] for more information and examples.
+    */
+    pub fn with_data<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.data.push((key.into(), value.into()));
+        self
+    }
+}
+
+/// Attaches metadata to any element, see [`WithMetadata`]
+pub trait IntoMetadataElement<'a, DB: DrawingBackend, Coord: Clone> {
+    /**
+    Attaches a tooltip to the element.
+
+    See [`WithMetadata`] for more information and examples.
+    */
+    fn with_tooltip<S: Into<String>>(self, tooltip: S) -> WithMetadata<'a, DB, Coord>;
+
+    /**
+    Attaches a key/value pair of data to the element.
+
+    See [`WithMetadata`] for more information and examples.
+    */
+    fn with_data<K: Into<String>, V: Into<String>>(
+        self,
+        key: K,
+        value: V,
+    ) -> WithMetadata<'a, DB, Coord>;
+}
+
+impl<'a, DB: DrawingBackend, Coord: Clone, T: IntoDynElement<'a, DB, Coord>>
+    IntoMetadataElement<'a, DB, Coord> for T
+{
+    fn with_tooltip<S: Into<String>>(self, tooltip: S) -> WithMetadata<'a, DB, Coord> {
+        WithMetadata::new(self.into_dyn()).with_tooltip(tooltip)
+    }
+
+    fn with_data<K: Into<String>, V: Into<String>>(
+        self,
+        key: K,
+        value: V,
+    ) -> WithMetadata<'a, DB, Coord> {
+        WithMetadata::new(self.into_dyn()).with_data(key, value)
+    }
+}
+
+impl<'a, 'b: 'a, DB: DrawingBackend, Coord: Clone> PointCollection<'a, Coord>
+    for &'a WithMetadata<'b, DB, Coord>
+{
+    type Point = &'a Coord;
+    type IntoIter = &'a Vec<Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        self.element.point_iter()
+    }
+}
+
+impl<'a, DB: DrawingBackend, Coord: Clone> Drawable<DB> for WithMetadata<'a, DB, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        backend.push_element_metadata(self.tooltip.as_deref(), &self.data);
+        let result = self.element.draw(pos, backend, parent_dim);
+        backend.pop_element_metadata();
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_metadata_element() {
+        let element: WithMetadata<MockedBackend, _> = Circle::new((10, 10), 5, RED.filled())
+            .with_tooltip("first")
+            .with_tooltip("second")
+            .with_data("value", "42");
+        assert_eq!(element.tooltip.as_deref(), Some("second"));
+        assert_eq!(element.data, vec![("value".to_string(), "42".to_string())]);
+
+        // The element is drawn as usual on the other backends
+        let da = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_circle(|c, _, f, _, r| {
+                assert_eq!(c, RED.to_rgba());
+                assert!(f);
+                assert_eq!(r, 5);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 1);
+            });
+        });
+        da.draw(&element).unwrap();
+    }
+}
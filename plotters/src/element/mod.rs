@@ -166,6 +166,9 @@ pub use basic_shapes_3d::*;
 mod text;
 pub use text::*;
 
+mod arrow;
+pub use arrow::{Arrow, ArrowEnds, ArrowHead};
+
 mod points;
 pub use points::*;
 
@@ -189,6 +192,14 @@ mod boxplot;
 #[cfg(feature = "boxplot")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "boxplot")))]
 pub use boxplot::Boxplot;
+#[cfg(feature = "boxplot")]
+pub(crate) use boxplot::{BoxplotOrient, BoxplotOrientH, BoxplotOrientV};
+
+#[cfg(feature = "colormaps")]
+mod colorbar;
+#[cfg(feature = "colormaps")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "colormaps")))]
+pub use colorbar::ColorBar;
 
 #[cfg(feature = "bitmap_backend")]
 mod image;
@@ -196,9 +207,18 @@ mod image;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_backend")))]
 pub use self::image::BitMapElement;
 
+mod rgba_image;
+pub use rgba_image::{RGBAImage, ScalingFilter};
+
 mod dynelem;
 pub use dynelem::{DynElement, IntoDynElement};
 
+mod metadata;
+pub use metadata::{IntoMetadataElement, WithMetadata};
+
+mod ellipse;
+pub use ellipse::Ellipse;
+
 mod pie;
 pub use pie::Pie;
 
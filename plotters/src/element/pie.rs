@@ -31,7 +31,8 @@ pub struct Pie<'a, Coord, Label: Display> {
     label_style: TextStyle<'a>,
     label_offset: f64,
     percentage_style: Option<TextStyle<'a>>,
-    donut_hole: f64, // radius of the hole in case of a donut chart
+    donut_hole: f64,   // radius of the hole in case of a donut chart
+    explode: Vec<f64>, // offset of each wedge from the center
 }
 
 impl<'a, Label: Display> Pie<'a, (i32, i32), Label> {
@@ -64,6 +65,7 @@ impl<'a, Label: Display> Pie<'a, (i32, i32), Label> {
             label_offset: radius_5pct,
             percentage_style: None,
             donut_hole: 0.0,
+            explode: Vec::new(),
         }
     }
 
@@ -102,6 +104,21 @@ impl<'a, Label: Display> Pie<'a, (i32, i32), Label> {
             self.donut_hole = hole_radius;
         }
     }
+
+    /// Pulls the wedge of the given index out of the pie, by moving it away from the center.
+    ///
+    /// The offset is in pixels, along the middle of the wedge. Its label and percentage move with it.
+    /// ```
+    /// use plotters::prelude::*;
+    /// let mut pie = Pie::new(&(50,50), &10.0, &[50.0, 25.25, 20.0, 5.5], &[RED, BLUE, GREEN, WHITE], &["Red", "Blue", "Green", "White"]);
+    /// pie.explode(1, 3.0);  // highlight the blue wedge
+    /// ```
+    pub fn explode(&mut self, index: usize, offset: f64) {
+        if self.explode.len() <= index {
+            self.explode.resize(index + 1, 0.0);
+        }
+        self.explode[index] = offset;
+    }
 }
 
 impl<'a, DB: DrawingBackend, Label: Display> Drawable<DB> for Pie<'a, (i32, i32), Label> {
@@ -128,18 +145,23 @@ impl<'a, DB: DrawingBackend, Label: Display> Drawable<DB> for Pie<'a, (i32, i32)
                 .labels
                 .get(index)
                 .ok_or_else(|| DrawingErrorKind::FontError(Box::new(PieError::LengthMismatch)))?;
-            // start building wedge line against the previous edge
-            let mut points = if self.donut_hole == 0.0 {
-                vec![*self.center]
-            } else {
-                vec![]
-            };
             let ratio = slice / self.total;
             let theta_final = ratio * 2.0 * PI + offset_theta; // end radian for the wedge
 
             // calculate middle for labels before mutating offset
             let middle_theta = ratio * PI + offset_theta;
 
+            // an exploded wedge is drawn around a center moved along its middle
+            let explode = self.explode.get(index).copied().unwrap_or(0.0);
+            let center = theta_to_ordinal_coord(explode, middle_theta, self.center);
+
+            // start building wedge line against the previous edge
+            let mut points = if self.donut_hole == 0.0 {
+                vec![center]
+            } else {
+                vec![]
+            };
+
             let slice_start = offset_theta;
 
             // calculate every fraction of radian for the wedge, offsetting for every iteration, clockwise
@@ -148,23 +170,23 @@ impl<'a, DB: DrawingBackend, Label: Display> Drawable<DB> for Pie<'a, (i32, i32)
             // but f64 doesn't implement the Range trait, and it would requires the Step trait (increment by 1.0 or 0.0001?)
             // which is unstable therefore cannot be implemented outside of std, even as a newtype for radians.
             while offset_theta <= theta_final {
-                let coord = theta_to_ordinal_coord(*self.radius, offset_theta, self.center);
+                let coord = theta_to_ordinal_coord(*self.radius, offset_theta, &center);
                 points.push(coord);
                 offset_theta += radian_increment;
             }
             // final point of the wedge may not fall exactly on a radian, so add it extra
-            let final_coord = theta_to_ordinal_coord(*self.radius, theta_final, self.center);
+            let final_coord = theta_to_ordinal_coord(*self.radius, theta_final, &center);
             points.push(final_coord);
 
             if self.donut_hole > 0.0 {
                 while offset_theta >= slice_start {
-                    let coord = theta_to_ordinal_coord(self.donut_hole, offset_theta, self.center);
+                    let coord = theta_to_ordinal_coord(self.donut_hole, offset_theta, &center);
                     points.push(coord);
                     offset_theta -= radian_increment;
                 }
                 // final point of the wedge may not fall exactly on a radian, so add it extra
                 let final_coord_inner =
-                    theta_to_ordinal_coord(self.donut_hole, slice_start, self.center);
+                    theta_to_ordinal_coord(self.donut_hole, slice_start, &center);
                 points.push(final_coord_inner);
             }
 
@@ -176,14 +198,21 @@ impl<'a, DB: DrawingBackend, Label: Display> Drawable<DB> for Pie<'a, (i32, i32)
             // future
             backend.fill_polygon(points, slice_style)?;
 
+            // a wedge narrower than a degree may cover no pixel, so keep it visible as a hairline
+            if *slice > 0.0 && ratio * 360.0 < 1.0 {
+                let inner = theta_to_ordinal_coord(self.donut_hole, middle_theta, &center);
+                let outer = theta_to_ordinal_coord(*self.radius, middle_theta, &center);
+                backend.draw_line(inner, outer, slice_style)?;
+            }
+
             // label coords from the middle
             let mut mid_coord =
-                theta_to_ordinal_coord(self.radius + self.label_offset, middle_theta, self.center);
+                theta_to_ordinal_coord(self.radius + self.label_offset, middle_theta, &center);
 
             // ensure label's doesn't fall in the circle
             let label_size = backend.estimate_text_size(&label.to_string(), &self.label_style)?;
             // if on the left hand side of the pie, offset whole label to the left
-            if mid_coord.0 <= self.center.0 {
+            if mid_coord.0 <= center.0 {
                 mid_coord.0 -= label_size.0 as i32;
             }
             // put label
@@ -197,7 +226,7 @@ impl<'a, DB: DrawingBackend, Label: Display> Drawable<DB> for Pie<'a, (i32, i32)
                 let perc_coord = theta_to_ordinal_coord(
                     perc_radius,
                     middle_theta,
-                    &(self.center.0 - text_x_mid, self.center.1 - text_y_mid),
+                    &(center.0 - text_x_mid, center.1 - text_y_mid),
                 );
                 // perc_coord.0 -= middle_label_size.0.round() as i32;
                 perc_labels.push((perc_label, perc_coord));
@@ -237,7 +266,7 @@ fn theta_to_ordinal_coord(radius: f64, theta: f64, ordinal_offset: &(i32, i32))
 #[cfg(test)]
 mod test {
     use super::*;
-    // use crate::prelude::*;
+    use crate::prelude::*;
 
     #[test]
     fn polar_coord_to_cartestian_coord() {
@@ -264,4 +293,44 @@ mod test {
         assert!(labels.first().is_none());
         assert_eq!(radius, 801.0);
     }
+
+    #[test]
+    fn exploded_wedge() {
+        let drawing_area = crate::create_mocked_drawing_area(200, 200, |m| {
+            m.check_fill_polygon(|_, points| assert_eq!(points[0], (100, 100)));
+            // the second wedge spans the bottom half, so it moves downwards
+            m.check_fill_polygon(|_, points| assert_eq!(points[0], (100, 110)));
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 2));
+        });
+
+        let mut pie = Pie::new(&(100, 100), &50.0, &[50.0, 50.0], &[RED, BLUE], &["a", "b"]);
+        pie.start_angle(180.0);
+        pie.explode(1, 10.0);
+        drawing_area.draw(&pie).expect("Drawing Error");
+    }
+
+    #[test]
+    fn tiny_wedge_hairline() {
+        let drawing_area = crate::create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_line(|c, _, from, to| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(from, (100, 100));
+                assert_eq!(to.0, 150);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 3);
+                assert_eq!(b.num_draw_line_call, 1);
+            });
+        });
+
+        let sizes = [99.8, 0.2, 0.0];
+        let pie = Pie::new(
+            &(100, 100),
+            &50.0,
+            &sizes,
+            &[RED, BLUE, GREEN],
+            &["", "", ""],
+        );
+        drawing_area.draw(&pie).expect("Drawing Error");
+    }
 }
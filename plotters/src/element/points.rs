@@ -129,6 +129,261 @@ impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for TriangleMarker<
     }
 }
 
+/**
+A square marker for visualizing data series, centered on its coordinate.
+
+The size is half of the side of the square. The square is filled if the style is filled,
+otherwise its outline is drawn with the stroke of the style.
+
+# Example
+
+```
+use plotters::prelude::*;
+let mut svg = String::new();
+let drawing_area = SVGBackend::with_string(&mut svg, (250, 50)).into_drawing_area();
+drawing_area.draw(&Square::new((25, 25), 10, RED.filled()))?;
+drawing_area.draw(&Diamond::new((75, 25), 10, BLUE.filled()))?;
+drawing_area.draw(&Cross::new((125, 25), 10, GREEN.stroke_width(2)))?;
+drawing_area.draw(&Plus::new((175, 25), 10, MAGENTA.stroke_width(2)))?;
+drawing_area.draw(&Star::new((225, 25), 10, BLACK))?;
+# Ok::<(), Box<dyn std::error::Error>>(())
+```
+
+Unlike [`Rectangle`], which is anchored at its corners, the square is centered on its coordinate.
+
+See [`EmptyElement`] for more information and examples.
+*/
+pub struct Square<Coord, Size: SizeDesc> {
+    center: Coord,
+    size: Size,
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> Square<Coord, Size> {
+    /**
+    Creates a square marker.
+
+    See [`EmptyElement`] for more information and examples.
+    */
+    pub fn new<T: Into<ShapeStyle>>(coord: Coord, size: Size, style: T) -> Self {
+        Self {
+            center: coord,
+            size,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord: 'a, Size: SizeDesc> PointCollection<'a, Coord> for &'a Square<Coord, Size> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Square<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            let size = self.size.in_pixels(&ps);
+            backend.draw_rect(
+                (x - size, y - size),
+                (x + size, y + size),
+                &self.style,
+                self.style.filled,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/**
+A diamond marker for visualizing data series.
+
+The size is the distance from the center to the corners. The diamond is filled if the
+style is filled, otherwise its outline is drawn with the stroke of the style.
+
+See [`EmptyElement`] for more information and examples.
+*/
+pub struct Diamond<Coord, Size: SizeDesc> {
+    center: Coord,
+    size: Size,
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> Diamond<Coord, Size> {
+    /**
+    Creates a diamond marker.
+
+    See [`EmptyElement`] for more information and examples.
+    */
+    pub fn new<T: Into<ShapeStyle>>(coord: Coord, size: Size, style: T) -> Self {
+        Self {
+            center: coord,
+            size,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord: 'a, Size: SizeDesc> PointCollection<'a, Coord> for &'a Diamond<Coord, Size> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Diamond<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            let size = self.size.in_pixels(&ps);
+            let points = vec![(x, y - size), (x + size, y), (x, y + size), (x - size, y)];
+            draw_marker_polygon(backend, points, &self.style)?;
+        }
+        Ok(())
+    }
+}
+
+/**
+A plus marker for visualizing data series, which is an upright [`Cross`].
+
+The size is half the length of the strokes, which are drawn with the stroke of the style.
+
+See [`EmptyElement`] for more information and examples.
+*/
+pub struct Plus<Coord, Size: SizeDesc> {
+    center: Coord,
+    size: Size,
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> Plus<Coord, Size> {
+    /**
+    Creates a plus marker.
+
+    See [`EmptyElement`] for more information and examples.
+    */
+    pub fn new<T: Into<ShapeStyle>>(coord: Coord, size: Size, style: T) -> Self {
+        Self {
+            center: coord,
+            size,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord: 'a, Size: SizeDesc> PointCollection<'a, Coord> for &'a Plus<Coord, Size> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Plus<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            let size = self.size.in_pixels(&ps);
+            backend.draw_line((x - size, y), (x + size, y), &self.style)?;
+            backend.draw_line((x, y - size), (x, y + size), &self.style)?;
+        }
+        Ok(())
+    }
+}
+
+/**
+A five-pointed star marker for visualizing data series, with a point upwards.
+
+The size is the distance from the center to the points. The star is filled if the style is
+filled, otherwise its outline is drawn with the stroke of the style.
+
+See [`EmptyElement`] for more information and examples.
+*/
+pub struct Star<Coord, Size: SizeDesc> {
+    center: Coord,
+    size: Size,
+    style: ShapeStyle,
+}
+
+impl<Coord, Size: SizeDesc> Star<Coord, Size> {
+    /**
+    Creates a star marker.
+
+    See [`EmptyElement`] for more information and examples.
+    */
+    pub fn new<T: Into<ShapeStyle>>(coord: Coord, size: Size, style: T) -> Self {
+        Self {
+            center: coord,
+            size,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'a, Coord: 'a, Size: SizeDesc> PointCollection<'a, Coord> for &'a Star<Coord, Size> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend, Size: SizeDesc> Drawable<DB> for Star<Coord, Size> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        ps: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            let size = self.size.in_pixels(&ps);
+            // The inner corners are on the lines joining the points, which makes a regular star
+            let inner = f64::from(size) * (18f64.to_radians().sin() / 126f64.to_radians().sin());
+            let points = (0..10)
+                .map(|i| {
+                    let rad = f64::from(i * 36 - 90).to_radians();
+                    let radius = if i % 2 == 0 { f64::from(size) } else { inner };
+                    (
+                        (rad.cos() * radius + f64::from(x)).round() as i32,
+                        (rad.sin() * radius + f64::from(y)).round() as i32,
+                    )
+                })
+                .collect();
+            draw_marker_polygon(backend, points, &self.style)?;
+        }
+        Ok(())
+    }
+}
+
+// Fill the polygon of a marker if the style is filled, otherwise draw its outline
+fn draw_marker_polygon<DB: DrawingBackend>(
+    backend: &mut DB,
+    points: Vec<BackendCoord>,
+    style: &ShapeStyle,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    if style.filled {
+        return backend.fill_polygon(points, style);
+    }
+    let first = points[0];
+    backend.draw_path(points.into_iter().chain(std::iter::once(first)), style)
+}
+
 impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Cross<Coord, Size> {
     fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
         Self::new(pos, size, style)
@@ -141,6 +396,30 @@ impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for TriangleMarker<Coord,
     }
 }
 
+impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Square<Coord, Size> {
+    fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
+        Self::new(pos, size, style)
+    }
+}
+
+impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Diamond<Coord, Size> {
+    fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
+        Self::new(pos, size, style)
+    }
+}
+
+impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Plus<Coord, Size> {
+    fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
+        Self::new(pos, size, style)
+    }
+}
+
+impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Star<Coord, Size> {
+    fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
+        Self::new(pos, size, style)
+    }
+}
+
 impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Circle<Coord, Size> {
     fn make_point(pos: Coord, size: Size, style: ShapeStyle) -> Self {
         Self::new(pos, size, style)
@@ -152,3 +431,44 @@ impl<Coord, Size: SizeDesc> PointElement<Coord, Size> for Pixel<Coord> {
         Self::new(pos, style)
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_marker_fill_and_stroke() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_rect(|c, _, f, u, d| {
+            assert_eq!(c, RED.to_rgba());
+            assert!(f);
+            assert_eq!(u, (95, 95));
+            assert_eq!(d, (105, 105));
+        });
+        m.check_fill_polygon(|c, points| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(points, vec![(100, 90), (110, 100), (100, 110), (90, 100)]);
+        });
+        m.check_draw_path(|c, s, points| {
+            assert_eq!(c, BLACK.to_rgba());
+            assert_eq!(s, 2);
+            // The outline of the star is closed
+            assert_eq!(points.len(), 11);
+            assert_eq!(points[0], (100, 90));
+            assert_eq!(points[10], (100, 90));
+        });
+
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 1);
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+            assert_eq!(b.num_draw_line_call, 2);
+        });
+    });
+    da.draw(&Square::new((100, 100), 5, RED.filled()))
+        .expect("Drawing Failure");
+    da.draw(&Diamond::new((100, 100), 10, BLUE.filled()))
+        .expect("Drawing Failure");
+    da.draw(&Star::new((100, 100), 10, BLACK.stroke_width(2)))
+        .expect("Drawing Failure");
+    da.draw(&Plus::new((100, 100), 10, GREEN))
+        .expect("Drawing Failure");
+}
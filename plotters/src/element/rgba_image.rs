@@ -0,0 +1,269 @@
+#[cfg(all(
+    not(all(target_arch = "wasm32", not(target_os = "wasi"))),
+    feature = "image"
+))]
+use image::{DynamicImage, GenericImageView};
+
+use super::{Drawable, PointCollection};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+use std::borrow::Cow;
+
+/// How an image is resampled when it is drawn at another size than its own
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScalingFilter {
+    /// Each pixel takes the color of the nearest pixel of the image, which keeps the edges sharp
+    Nearest,
+    /// Each pixel blends the four nearest pixels of the image, which gives smooth gradients
+    Bilinear,
+}
+
+/**
+An image with an alpha channel, such as a logo, which is composited over what is under it.
+
+The image is a buffer of RGBA pixels, row by row. It is anchored by its upper left corner, and
+drawn at its own size unless [`RGBAImage::scale_to()`] sets another size in pixels, in which
+case it is resampled with the [`ScalingFilter`] set by [`RGBAImage::filter()`].
+
+The backends which can embed images, such as the SVG backend, embed it as a PNG image, the
+other ones draw its pixels one by one.
+
+# Example
+
+```
+use plotters::prelude::*;
+// A red square fading to transparent from left to right
+let pixels: Vec<u8> = (0..16 * 16)
+    .flat_map(|idx| vec![255, 0, 0, (idx % 16 * 16) as u8])
+    .collect();
+let drawing_area = SVGBackend::new("rgba_image.svg", (100, 100)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+drawing_area
+    .draw(
+        &RGBAImage::new((10, 10), (16, 16), pixels)
+            .unwrap()
+            .scale_to((80, 80))
+            .filter(ScalingFilter::Nearest),
+    )
+    .unwrap();
+```
+*/
+pub struct RGBAImage<'a, Coord> {
+    pixels: Cow<'a, [u8]>,
+    size: (u32, u32),
+    target_size: (u32, u32),
+    filter: ScalingFilter,
+    pos: Coord,
+}
+
+impl<'a, Coord> RGBAImage<'a, Coord> {
+    /**
+    Create a new image, taking the ownership of its pixels.
+
+    - `pos`: The left upper coordinate of the image
+    - `size`: The size of the image
+    - `buf`: The RGBA pixels of the image
+    - **returns**: The newly created image, or `None` if the buffer doesn't fit the size
+    */
+    pub fn new(pos: Coord, size: (u32, u32), buf: Vec<u8>) -> Option<Self> {
+        Self::with_pixels(pos, size, Cow::Owned(buf))
+    }
+
+    /**
+    Create a new image with a borrowed buffer.
+
+    - `pos`: The left upper coordinate of the image
+    - `size`: The size of the image
+    - `buf`: The RGBA pixels of the image
+    - **returns**: The newly created image, or `None` if the buffer doesn't fit the size
+    */
+    pub fn with_ref(pos: Coord, size: (u32, u32), buf: &'a [u8]) -> Option<Self> {
+        Self::with_pixels(pos, size, Cow::Borrowed(buf))
+    }
+
+    fn with_pixels(pos: Coord, size: (u32, u32), pixels: Cow<'a, [u8]>) -> Option<Self> {
+        if pixels.len() < size.0 as usize * size.1 as usize * 4 {
+            return None;
+        }
+        Some(Self {
+            pixels,
+            size,
+            target_size: size,
+            filter: ScalingFilter::Bilinear,
+            pos,
+        })
+    }
+
+    /**
+    Set the size the image is drawn at, in pixels.
+
+    See [`RGBAImage`] for more information and examples.
+    */
+    pub fn scale_to(mut self, size: (u32, u32)) -> Self {
+        self.target_size = size;
+        self
+    }
+
+    /**
+    Set how the image is resampled when it is drawn at another size, which is bilinear by
+    default.
+
+    See [`RGBAImage`] for more information and examples.
+    */
+    pub fn filter(mut self, filter: ScalingFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// The pixels of the image at its target size
+    fn scaled_pixels(&self) -> Cow<'_, [u8]> {
+        let (sw, sh) = self.size;
+        let (dw, dh) = self.target_size;
+        if (sw, sh) == (dw, dh) {
+            return Cow::Borrowed(&self.pixels[..]);
+        }
+        let src = &self.pixels;
+        let pixel = |x: u32, y: u32| &src[(y as usize * sw as usize + x as usize) * 4..][..4];
+        // The position in the image of the center of a pixel of the target
+        let map =
+            |v: u32, from: u32, to: u32| (f64::from(v) + 0.5) * f64::from(from) / f64::from(to);
+
+        let mut scaled = Vec::with_capacity(dw as usize * dh as usize * 4);
+        for y in 0..dh {
+            let sy = map(y, sh, dh);
+            for x in 0..dw {
+                let sx = map(x, sw, dw);
+                match self.filter {
+                    ScalingFilter::Nearest => {
+                        let (px, py) = ((sx as u32).min(sw - 1), (sy as u32).min(sh - 1));
+                        scaled.extend_from_slice(pixel(px, py));
+                    }
+                    ScalingFilter::Bilinear => {
+                        let (fx, fy) = ((sx - 0.5).max(0.0), (sy - 0.5).max(0.0));
+                        let (x0, y0) = ((fx as u32).min(sw - 1), (fy as u32).min(sh - 1));
+                        let (x1, y1) = ((x0 + 1).min(sw - 1), (y0 + 1).min(sh - 1));
+                        let (tx, ty) = (fx - f64::from(x0), fy - f64::from(y0));
+                        // The colors are weighted by their alpha, so that the transparent
+                        // pixels don't darken the edges
+                        let mut sum = [0.0; 4];
+                        for &(px, py, weight) in &[
+                            (x0, y0, (1.0 - tx) * (1.0 - ty)),
+                            (x1, y0, tx * (1.0 - ty)),
+                            (x0, y1, (1.0 - tx) * ty),
+                            (x1, y1, tx * ty),
+                        ] {
+                            let p = pixel(px, py);
+                            let alpha = f64::from(p[3]) * weight;
+                            for c in 0..3 {
+                                sum[c] += f64::from(p[c]) * alpha;
+                            }
+                            sum[3] += alpha;
+                        }
+                        for c in 0..3 {
+                            let value = if sum[3] > 0.0 { sum[c] / sum[3] } else { 0.0 };
+                            scaled.push(value.round() as u8);
+                        }
+                        scaled.push(sum[3].round() as u8);
+                    }
+                }
+            }
+        }
+        Cow::Owned(scaled)
+    }
+}
+
+#[cfg(all(
+    not(all(target_arch = "wasm32", not(target_os = "wasi"))),
+    feature = "image"
+))]
+impl<'a, Coord> From<(Coord, DynamicImage)> for RGBAImage<'a, Coord> {
+    fn from((pos, image): (Coord, DynamicImage)) -> Self {
+        let (w, h) = image.dimensions();
+        Self {
+            pixels: Cow::Owned(image.to_rgba8().into_raw()),
+            size: (w, h),
+            target_size: (w, h),
+            filter: ScalingFilter::Bilinear,
+            pos,
+        }
+    }
+}
+
+impl<'a, 'b, Coord> PointCollection<'a, Coord> for &'a RGBAImage<'b, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for RGBAImage<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if self.size.0 == 0 || self.size.1 == 0 {
+            return Ok(());
+        }
+        if let Some(pos) = points.next() {
+            return backend.blit_rgba_bitmap(pos, self.target_size, &self.scaled_pixels());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scaling() {
+        let pixels = vec![
+            255, 0, 0, 255, 0, 0, 255, 255, //
+            0, 255, 0, 255, 0, 0, 0, 0,
+        ];
+        assert!(RGBAImage::new((0, 0), (2, 2), pixels[..12].to_vec()).is_none());
+
+        let image = RGBAImage::with_ref((0, 0), (2, 2), &pixels).unwrap();
+        assert_eq!(&image.scaled_pixels()[..], &pixels[..]);
+
+        // Each pixel becomes a 2x2 block
+        let image = image.scale_to((4, 4)).filter(ScalingFilter::Nearest);
+        let scaled = image.scaled_pixels();
+        assert_eq!(scaled.len(), 4 * 4 * 4);
+        assert_eq!(&scaled[4..8], &[255, 0, 0, 255]);
+        assert_eq!(&scaled[8..12], &[0, 0, 255, 255]);
+        assert_eq!(&scaled[60..64], &[0, 0, 0, 0]);
+
+        // The blended pixels ignore the colors of the transparent ones
+        let image = image.scale_to((3, 1)).filter(ScalingFilter::Bilinear);
+        let scaled = image.scaled_pixels();
+        assert_eq!(scaled.len(), 3 * 4);
+        assert_eq!(&scaled[0..4], &[128, 128, 0, 255]);
+        assert_eq!(&scaled[8..12], &[0, 0, 255, 128]);
+    }
+
+    #[test]
+    fn test_draw_rgba_image() {
+        let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                // The transparent pixels are skipped
+                assert_eq!(b.num_draw_pixel_call, 3 * 4);
+            });
+        });
+        let pixels = vec![
+            255, 0, 0, 255, 0, 0, 255, 255, //
+            0, 255, 0, 255, 0, 0, 0, 0,
+        ];
+        drawing_area
+            .draw(
+                &RGBAImage::new((10, 10), (2, 2), pixels)
+                    .unwrap()
+                    .scale_to((4, 4))
+                    .filter(ScalingFilter::Nearest),
+            )
+            .unwrap();
+    }
+}
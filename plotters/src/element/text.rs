@@ -1,15 +1,22 @@
 use std::borrow::Borrow;
 
 use super::{Drawable, PointCollection};
+use crate::style::text_anchor::VPos;
 use crate::style::{FontDesc, FontResult, LayoutBox, TextStyle};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
-/// A single line text element. This can be owned or borrowed string, dependents on
-/// `String` or `str` moved into.
+/// A text element. This can be owned or borrowed string, dependents on `String` or `str`
+/// moved into.
+///
+/// The text is split into lines on each `\n`, and the lines are stacked with the anchor of
+/// the text style applying to the block of lines. With a maximum width, the long lines are
+/// also wrapped at word boundaries.
 pub struct Text<'a, Coord, T: Borrow<str>> {
     text: T,
     coord: Coord,
     style: TextStyle<'a>,
+    line_height: f64,
+    max_width: u32,
 }
 
 impl<'a, Coord, T: Borrow<str>> Text<'a, Coord, T> {
@@ -23,8 +30,98 @@ impl<'a, Coord, T: Borrow<str>> Text<'a, Coord, T> {
             text,
             coord: points,
             style: style.into(),
+            line_height: 1.25,
+            max_width: 0,
+        }
+    }
+
+    /// Set the line height of the text element, relative to the font size
+    pub fn set_line_height(&mut self, value: f64) -> &mut Self {
+        self.line_height = value;
+        self
+    }
+
+    /// Set the maximum width of the text element. The lines wider than this width are
+    /// wrapped at word boundaries, a single word wider than this width is kept on its own
+    /// line. If 0 is given, do not do any line wrapping
+    pub fn set_max_width(&mut self, value: u32) -> &mut Self {
+        self.max_width = value;
+        self
+    }
+
+    /// Split the text into the lines to draw
+    fn lines(&self) -> FontResult<Vec<&str>> {
+        let mut ret = vec![];
+        for line in self.text.borrow().split('\n') {
+            if self.max_width == 0 {
+                ret.push(line);
+            } else {
+                wrap_words(line, self.max_width, &self.style.font, &mut ret)?;
+            }
+        }
+        Ok(ret)
+    }
+
+    /// The distance in pixels between the tops of two consecutive lines
+    fn line_pitch(&self) -> f64 {
+        self.style.font.get_size() * self.line_height
+    }
+
+    /// Estimate the text element's dimension: the width of the widest line by the total height
+    /// of the lines
+    pub fn estimate_dimension(&self) -> FontResult<(i32, i32)> {
+        let (mut mx, mut my) = (0, 0);
+
+        for (idx, line) in self.lines()?.into_iter().enumerate() {
+            let (dx, dy) = self.style.font.box_size(line)?;
+            let y = (idx as f64 * self.line_pitch()).round() as i32;
+            mx = mx.max(dx as i32);
+            my = my.max(y + dy as i32);
+        }
+
+        Ok((mx, my))
+    }
+}
+
+// Break a line at the word boundaries, so that each piece fits into `max_width`
+fn wrap_words<'t>(
+    line: &'t str,
+    max_width: u32,
+    font: &FontDesc,
+    lines: &mut Vec<&'t str>,
+) -> FontResult<()> {
+    let mut words = vec![];
+    let mut word_start = None;
+    for (idx, c) in line.char_indices() {
+        if c.is_whitespace() {
+            if let Some(start) = word_start.take() {
+                words.push((start, idx));
+            }
+        } else if word_start.is_none() {
+            word_start = Some(idx);
         }
     }
+    if let Some(start) = word_start {
+        words.push((start, line.len()));
+    }
+
+    let mut words = words.into_iter();
+    let (mut start, mut end) = match words.next() {
+        Some(word) => word,
+        None => {
+            lines.push(line);
+            return Ok(());
+        }
+    };
+    for (word_start, word_end) in words {
+        if font.box_size(&line[start..word_end])?.0 > max_width {
+            lines.push(&line[start..end]);
+            start = word_start;
+        }
+        end = word_end;
+    }
+    lines.push(&line[start..end]);
+    Ok(())
 }
 
 impl<'b, 'a, Coord: 'a, T: Borrow<str> + 'a> PointCollection<'a, Coord> for &'a Text<'b, Coord, T> {
@@ -43,7 +140,26 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for Text<'a
         _: (u32, u32),
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some(a) = points.next() {
-            return backend.draw_text(self.text.borrow(), &self.style, a);
+            let lines = self
+                .lines()
+                .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+            if lines.len() == 1 {
+                return backend.draw_text(lines[0], &self.style, a);
+            }
+
+            // The anchor of the style applies to the whole block of lines
+            let pitch = self.line_pitch();
+            let block_offset = match self.style.pos.v_pos {
+                VPos::Top => 0.0,
+                VPos::Center => -pitch * (lines.len() - 1) as f64 / 2.0,
+                VPos::Bottom => -pitch * (lines.len() - 1) as f64,
+            };
+            let transform = self.style.font.get_transform();
+            for (idx, line) in lines.into_iter().enumerate() {
+                let dy = (block_offset + pitch * idx as f64).round() as i32;
+                let (dx, dy) = transform.transform(0, dy);
+                backend.draw_text(line, &self.style, (a.0 + dx, a.1 + dy))?;
+            }
         }
         Ok(())
     }
@@ -272,3 +388,56 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB>
         Ok(())
     }
 }
+
+#[cfg(feature = "ttf")]
+#[test]
+fn test_text_bounding_box() {
+    use plotters_backend::{FontFamily, FontStyle};
+
+    let font = FontDesc::new(FontFamily::SansSerif, 20.0, FontStyle::Normal);
+    let text = Text::new("one\nthree three\ntwo", (0, 0), font.clone());
+    let (w, h) = text.estimate_dimension().unwrap();
+    // The widest line by the top of the last line plus its height
+    assert_eq!(w, font.box_size("three three").unwrap().0 as i32);
+    assert_eq!(h, 50 + font.box_size("two").unwrap().1 as i32);
+
+    let mut text = Text::new("one\nthree three\ntwo", (0, 0), font.clone());
+    text.set_line_height(2.0);
+    assert_eq!(text.estimate_dimension().unwrap().1, h + 30);
+
+    // A single line keeps the dimension of the whole string
+    let text = Text::new("three three", (0, 0), font.clone());
+    assert_eq!(
+        text.estimate_dimension().unwrap(),
+        (w, font.box_size("three three").unwrap().1 as i32)
+    );
+}
+
+#[cfg(feature = "ttf")]
+#[test]
+fn test_text_word_wrap() {
+    use plotters_backend::{FontFamily, FontStyle};
+
+    let font = FontDesc::new(FontFamily::SansSerif, 20.0, FontStyle::Normal);
+    let max_width = font.box_size("aaa bbb").unwrap().0;
+    let mut text = Text::new("aaa bbb ccc\nddd  eeeeeeeeeeeeeeeeee", (10, 10), font);
+    text.set_max_width(max_width);
+    assert_eq!(
+        text.lines().unwrap(),
+        vec!["aaa bbb", "ccc", "ddd", "eeeeeeeeeeeeeeeeee"]
+    );
+
+    let area = crate::create_mocked_drawing_area(200, 200, |m| {
+        let mut lines = vec![];
+        m.check_draw_text(move |_, _, _, pos, text| {
+            lines.push((pos, text.to_string()));
+            if lines.len() == 4 {
+                let y: Vec<_> = lines.iter().map(|((_, y), _)| *y).collect();
+                assert_eq!(y, vec![10, 35, 60, 85]);
+                assert!(lines.iter().all(|((x, _), _)| *x == 10));
+            }
+        });
+        m.drop_check(|b| assert_eq!(b.num_draw_text_call, 4));
+    });
+    area.draw(&text).unwrap();
+}
@@ -671,6 +671,8 @@ The following list is a complete list of features that can be opted in or out.
 |---------|--------------|--------|------------|
 | bitmap\_encoder  | Allow `BitMapBackend` to save the result to bitmap files | image, rusttype, font-kit | Yes |
 | svg\_backend     | Enable `SVGBackend` Support | None | Yes |
+| pdf\_backend     | Enable `PDFBackend` Support | None | No |
+| terminal\_backend | Enable `TerminalBackend` Support | None | No |
 | bitmap\_gif| Opt-in GIF animation Rendering support for `BitMapBackend`, implies `bitmap` enabled | gif | Yes |
 
 - Font manipulation features
@@ -724,7 +726,11 @@ pub fn register_font(
 | area\_series | The area series support | None | Yes |
 | line\_series | The line series support | None | Yes |
 | histogram | The histogram series support | None | Yes |
+| heatmap | The heatmap series support | None | Yes |
+| hexbin | The hexagonal binning series support | None | Yes |
+| contour\_series | The contour line and filled contour series support | None | Yes |
 | point\_series| The point series support | None | Yes |
+| csv | The CSV data source adapter | None | No |
 
 - Misc
 
@@ -807,17 +813,21 @@ pub use crate::drawing::{check_color, create_mocked_drawing_area};
 /// The module imports the most commonly used types and modules in Plotters
 pub mod prelude {
     // Chart related types
-    pub use crate::chart::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesLabelPosition};
+    pub use crate::chart::{
+        ChartBuilder, ChartContext, LabelAreaPosition, ParallelCoordinates, SeriesLabelPosition,
+    };
 
     // Coordinates
     pub use crate::coord::{
         cartesian::Cartesian2d,
         combinators::{
-            make_partial_axis, BindKeyPointMethod, BindKeyPoints, BuildNestedCoord, GroupBy,
-            IntoLinspace, IntoLogRange, IntoPartialAxis, Linspace, LogCoord, LogScalable,
-            NestedRange, NestedValue, ToGroupByRange,
+            make_partial_axis, BindKeyPointMethod, BindKeyPoints, BrokenAxis, BuildNestedCoord,
+            GroupBy, IntoLinspace, IntoLogRange, IntoNiceRange, IntoPartialAxis, Linspace,
+            LogCoord, LogScalable, NestedRange, NestedValue, ToGroupByRange,
         },
+        polar::{AngleUnit, Polar, ZeroAngle},
         ranged1d::{DiscreteRanged, IntoSegmentedCoord, Ranged, SegmentValue},
+        types::GroupedCategory,
         CoordTranslate,
     };
 
@@ -827,7 +837,7 @@ pub mod prelude {
     #[cfg(feature = "chrono")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
     pub use crate::coord::types::{
-        IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDuration,
+        BindDateFormat, IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDuration,
     };
 
     // Re-export the backend for backward compatibility
@@ -836,21 +846,41 @@ pub mod prelude {
     pub use crate::drawing::*;
 
     // Series helpers
-    #[cfg(feature = "area_series")]
-    #[cfg_attr(doc_cfg, doc(cfg(feature = "area_series")))]
-    pub use crate::series::AreaSeries;
-    #[cfg(feature = "histogram")]
-    #[cfg_attr(doc_cfg, doc(cfg(feature = "histogram")))]
-    pub use crate::series::Histogram;
+    #[cfg(feature = "boxplot")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "boxplot")))]
+    pub use crate::series::BoxplotSeries;
+    #[cfg(all(feature = "line_series", feature = "colormaps"))]
+    #[cfg_attr(doc_cfg, doc(cfg(all(feature = "line_series", feature = "colormaps"))))]
+    pub use crate::series::ColoredLineSeries;
+    #[cfg(feature = "heatmap")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "heatmap")))]
+    pub use crate::series::Heatmap;
+    #[cfg(feature = "hexbin")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "hexbin")))]
+    pub use crate::series::HexBin;
     #[cfg(feature = "point_series")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "point_series")))]
     pub use crate::series::PointSeries;
     #[cfg(feature = "surface_series")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "surface_series")))]
     pub use crate::series::SurfaceSeries;
+    #[cfg(feature = "area_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "area_series")))]
+    pub use crate::series::{AreaSeries, FillBetweenSeries, RidgelineSeries, StackedAreaSeries};
+    #[cfg(feature = "point_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "point_series")))]
+    pub use crate::series::{BubbleScale, BubbleSeries, RugSeries};
+    #[cfg(feature = "contour_series")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "contour_series")))]
+    pub use crate::series::{ContourSeries, FilledContourSeries};
     #[cfg(feature = "line_series")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "line_series")))]
-    pub use crate::series::{DashedLineSeries, DottedLineSeries, LineSeries};
+    pub use crate::series::{
+        DashedLineSeries, Dendrogram, DottedLineSeries, LineSeries, SplineSeries,
+    };
+    #[cfg(feature = "histogram")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "histogram")))]
+    pub use crate::series::{GroupedBarSeries, Histogram, StackedBarSeries, WaterfallSeries};
 
     // Styles
     pub use crate::style::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
@@ -864,15 +894,16 @@ pub mod prelude {
     pub use crate::style::colors::colormaps::*;
 
     pub use crate::style::{
-        AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, HSLColor, IntoFont,
-        IntoTextStyle, Palette, Palette100, Palette99, Palette9999, PaletteColor, RGBAColor,
-        RGBColor, ShapeStyle, TextStyle,
+        AsRelative, Color, DashPattern, FontDesc, FontFamily, FontStyle, FontTransform,
+        GradientDirection, GradientStyle, HSLColor, IntoFont, IntoTextStyle, Palette, Palette100,
+        Palette99, Palette9999, PaletteColor, RGBAColor, RGBColor, ShapeStyle, TextStyle, Theme,
     };
 
     // Elements
     pub use crate::element::{
-        Circle, Cross, Cubiod, DynElement, EmptyElement, IntoDynElement, MultiLineText,
-        PathElement, Pie, Pixel, Polygon, Rectangle, Text, TriangleMarker,
+        Arc, Arrow, ArrowEnds, ArrowHead, Circle, Cross, Cubiod, Diamond, DynElement, Ellipse,
+        EmptyElement, IntoDynElement, IntoMetadataElement, MultiLineText, PathElement, Pie, Pixel,
+        Plus, Polygon, Rectangle, Square, Star, StepDirection, Text, TriangleMarker, WithMetadata,
     };
 
     #[cfg(feature = "boxplot")]
@@ -881,6 +912,9 @@ pub mod prelude {
     #[cfg(feature = "candlestick")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "candlestick")))]
     pub use crate::element::CandleStick;
+    #[cfg(feature = "colormaps")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "colormaps")))]
+    pub use crate::element::ColorBar;
     #[cfg(feature = "errorbar")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "errorbar")))]
     pub use crate::element::ErrorBar;
@@ -888,9 +922,10 @@ pub mod prelude {
     #[cfg(feature = "bitmap_backend")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "bitmap_backend")))]
     pub use crate::element::BitMapElement;
+    pub use crate::element::{RGBAImage, ScalingFilter};
 
     // Data
-    pub use crate::data::Quartiles;
+    pub use crate::data::{Quartiles, QuartilesEstimator};
 
     // TODO: This should be deprecated and completely removed
     #[cfg(feature = "deprecated_items")]
@@ -916,7 +951,15 @@ pub mod prelude {
 
     #[cfg(feature = "svg_backend")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "svg_backend")))]
-    pub use plotters_svg::SVGBackend;
+    pub use plotters_svg::{SVGBackend, SVGStyling};
+
+    #[cfg(feature = "pdf_backend")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "pdf_backend")))]
+    pub use plotters_pdf::PDFBackend;
+
+    #[cfg(feature = "terminal_backend")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "terminal_backend")))]
+    pub use plotters_terminal::TerminalBackend;
 }
 
 /// This module contains some useful re-export of backend related types.
@@ -930,7 +973,15 @@ pub mod backend {
     };
     #[cfg(feature = "svg_backend")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "svg_backend")))]
-    pub use plotters_svg::SVGBackend;
+    pub use plotters_svg::{SVGBackend, SVGStyling};
+
+    #[cfg(feature = "pdf_backend")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "pdf_backend")))]
+    pub use plotters_pdf::PDFBackend;
+
+    #[cfg(feature = "terminal_backend")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "terminal_backend")))]
+    pub use plotters_terminal::TerminalBackend;
 }
 
 #[cfg(test)]
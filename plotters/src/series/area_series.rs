@@ -1,7 +1,7 @@
-use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
-use crate::style::colors::TRANSPARENT;
-use crate::style::ShapeStyle;
-use plotters_backend::DrawingBackend;
+use crate::element::{Drawable, DynElement, IntoDynElement, PathElement, PointCollection, Polygon};
+use crate::style::colors::{BLACK, TRANSPARENT};
+use crate::style::{GradientStyle, ShapeStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 /**
 An area series is similar to a line series but uses a filled polygon.
@@ -30,7 +30,11 @@ The result is a chart with three line series; one of them has a highlighted blue
 */
 pub struct AreaSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
     area_style: ShapeStyle,
+    area_gradient: Option<GradientStyle>,
     border_style: ShapeStyle,
+    // The positive and negative styles, and whether each data point is on the positive side
+    sign_styles: Option<(ShapeStyle, ShapeStyle, Vec<bool>)>,
+    baseline_style: Option<ShapeStyle>,
     baseline: Y,
     data: Vec<(X, Y)>,
     state: u32,
@@ -50,6 +54,9 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
     ) -> Self {
         Self {
             area_style: area_style.into(),
+            area_gradient: None,
+            sign_styles: None,
+            baseline_style: None,
             baseline,
             data: iter.into_iter().collect(),
             state: 0,
@@ -67,6 +74,86 @@ impl<DB: DrawingBackend, X: Clone, Y: Clone> AreaSeries<DB, X, Y> {
         self.border_style = style.into();
         self
     }
+
+    /**
+    Fills the area with a gradient rather than the color of the area style.
+    The gradient spans the bounding box of the area, baseline included.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("area_series_gradient.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart_context = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(0.0..4.0, 0.0..3.0)
+        .unwrap();
+    let gradient = GradientStyle::vertical(&BLUE.mix(0.8), &BLUE.mix(0.0));
+    chart_context
+        .draw_series(
+            AreaSeries::new((0..=40).map(|x| x as f64 / 10.0).map(|x| (x, 2. - 0.1 * x * x)), 0., BLUE)
+                .with_gradient(gradient)
+                .border_style(BLUE),
+        )
+        .unwrap();
+    ```
+
+    See [`AreaSeries`] for more information and examples.
+    */
+    pub fn with_gradient(mut self, gradient: GradientStyle) -> Self {
+        self.area_gradient = Some(gradient);
+        self
+    }
+
+    /**
+    Fills the parts of the area above the baseline with the positive style, and the parts below
+    it with the negative style. The area is split where the line crosses the baseline. This
+    overrides the area style and the gradient.
+
+    The baseline itself is drawn under the line, in black unless another style is given with
+    [`AreaSeries::baseline_style()`].
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("area_series_sign.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart_context = ChartBuilder::on(&drawing_area)
+        .build_cartesian_2d(0.0..6.0, -1.0..1.0)
+        .unwrap();
+    chart_context
+        .draw_series(
+            AreaSeries::new((0..=60).map(|x| x as f64 / 10.0).map(|x| (x, x.sin())), 0., BLUE)
+                .style_by_sign(GREEN.mix(0.5), RED.mix(0.5)),
+        )
+        .unwrap();
+    ```
+
+    See [`AreaSeries`] for more information and examples.
+    */
+    pub fn style_by_sign<P: Into<ShapeStyle>, N: Into<ShapeStyle>>(
+        mut self,
+        positive: P,
+        negative: N,
+    ) -> Self
+    where
+        Y: PartialOrd,
+    {
+        let signs = self.data.iter().map(|(_, y)| *y >= self.baseline).collect();
+        self.sign_styles = Some((positive.into(), negative.into(), signs));
+        if self.baseline_style.is_none() {
+            self.baseline_style = Some(BLACK.into());
+        }
+        self
+    }
+
+    /**
+    Draws the baseline under the line with the given style, from the first data point to the
+    last one.
+
+    See [`AreaSeries`] for more information and examples.
+    */
+    pub fn baseline_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.baseline_style = Some(style.into());
+        self
+    }
 }
 
 impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for AreaSeries<DB, X, Y> {
@@ -82,11 +169,46 @@ impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for Ar
 
             self.state = 1;
 
-            Some(Polygon::new(data, self.area_style).into_dyn())
+            if let Some((positive, negative, signs)) = self.sign_styles.take() {
+                return Some(
+                    SignedArea {
+                        points: data,
+                        signs,
+                        positive,
+                        negative,
+                    }
+                    .into_dyn(),
+                );
+            }
+
+            let polygon = Polygon::new(data, self.area_style);
+            Some(match self.area_gradient.take() {
+                Some(gradient) => polygon.with_gradient(gradient).into_dyn(),
+                None => polygon.into_dyn(),
+            })
         } else if self.state == 1 {
+            self.state = 2;
+
+            // The baseline goes under the line, thus it's drawn first
+            if let (Some(style), Some(first), Some(last)) =
+                (self.baseline_style, self.data.first(), self.data.last())
+            {
+                return Some(
+                    PathElement::new(
+                        vec![
+                            (first.0.clone(), self.baseline.clone()),
+                            (last.0.clone(), self.baseline.clone()),
+                        ],
+                        style,
+                    )
+                    .into_dyn(),
+                );
+            }
+            self.next()
+        } else if self.state == 2 {
             let data: Vec<_> = self.data.clone();
 
-            self.state = 2;
+            self.state = 3;
 
             Some(PathElement::new(data, self.border_style).into_dyn())
         } else {
@@ -94,3 +216,119 @@ impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator for Ar
         }
     }
 }
+
+/// The fill of an area styled by sign: the data points followed by the two ends of the baseline
+struct SignedArea<Coord> {
+    points: Vec<Coord>,
+    signs: Vec<bool>,
+    positive: ShapeStyle,
+    negative: ShapeStyle,
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a SignedArea<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for SignedArea<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        if points.len() < 3 || points.len() != self.signs.len() + 2 {
+            return Ok(());
+        }
+        let base = points[points.len() - 1].1;
+        let data = &points[..self.signs.len()];
+
+        // Each run of points on the same side is closed by the baseline, and the runs meet where
+        // the line crosses it
+        let mut run = vec![(data[0].0, base), data[0]];
+        let mut sign = self.signs[0];
+        for (idx, (&point, &point_sign)) in data.iter().zip(self.signs.iter()).enumerate().skip(1) {
+            if point_sign != sign {
+                let prev = data[idx - 1];
+                let dy = f64::from(point.1 - prev.1);
+                let t = if dy == 0.0 {
+                    0.5
+                } else {
+                    (f64::from(base - prev.1) / dy).clamp(0.0, 1.0)
+                };
+                let x = f64::from(prev.0) + t * f64::from(point.0 - prev.0);
+                let crossing = (x.round() as i32, base);
+                run.push(crossing);
+                self.fill_run(&run, sign, backend)?;
+                run = vec![crossing];
+                sign = point_sign;
+            }
+            run.push(point);
+        }
+        run.push((data[data.len() - 1].0, base));
+        self.fill_run(&run, sign, backend)
+    }
+}
+
+impl<Coord> SignedArea<Coord> {
+    fn fill_run<DB: DrawingBackend>(
+        &self,
+        run: &[BackendCoord],
+        positive: bool,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let style = if positive {
+            &self.positive
+        } else {
+            &self.negative
+        };
+        backend.fill_polygon(run.iter().copied(), style)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_area_series_style_by_sign() {
+        let drawing_area = crate::create_mocked_drawing_area(200, 200, |m| {
+            let mut polygons = vec![
+                (GREEN.to_rgba(), vec![(0, 100), (0, 50), (50, 100)]),
+                (RED.to_rgba(), vec![(50, 100), (99, 150), (149, 100)]),
+                (GREEN.to_rgba(), vec![(149, 100), (199, 50), (199, 100)]),
+            ];
+            m.check_fill_polygon(move |c, points| {
+                let (color, expected) = polygons.remove(0);
+                assert_eq!(c, color);
+                assert_eq!(points, expected);
+            });
+            // The baseline is drawn under the line
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(path, vec![(0, 100), (199, 100)]);
+            });
+            m.check_draw_path(|c, _, _| assert_eq!(c, BLUE.to_rgba()));
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 3);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..200, -100..100)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                AreaSeries::new(vec![(0, 50), (100, -50), (200, 50)], 0, BLUE)
+                    .border_style(BLUE)
+                    .style_by_sign(GREEN, RED),
+            )
+            .expect("Drawing Error");
+    }
+}
@@ -0,0 +1,193 @@
+use crate::data::Quartiles;
+use crate::element::{Boxplot, BoxplotOrient, BoxplotOrientH, BoxplotOrientV};
+use crate::style::{Color, ShapeStyle};
+
+/**
+The boxplot series, which computes the quartiles of several groups of raw values and draws a
+[`Boxplot`] per group, with its outliers.
+
+The quartiles and the outliers of each group come from the same quartiles constructor, such as
+[`Quartiles::new`], [`Quartiles::real`] or [`Quartiles::fair`]. The groups which are empty or
+contain NaN are left out.
+
+# Example
+
+```
+use plotters::prelude::*;
+let mut svg = String::new();
+let drawing_area = SVGBackend::with_string(&mut svg, (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(["a", "b"].into_segmented(), -60f32..80f32)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let groups = [
+    (&"a", vec![-50, 7, 15, 36, 39, 40, 41]),
+    (&"b", vec![16, 17, 50, 60, 40, 41, 42, 43, 47]),
+];
+chart
+    .draw_series(
+        BoxplotSeries::from_groups(
+            groups.iter().map(|(key, values)| (SegmentValue::CenterOf(*key), values)),
+            Quartiles::new,
+        )
+        .width(20)
+        .style(BLUE),
+    )
+    .unwrap();
+```
+*/
+pub struct BoxplotSeries<K, O: BoxplotOrient<K, f32>> {
+    boxes: Vec<Boxplot<K, O>>,
+    max_count: usize,
+}
+
+impl<K: Clone> BoxplotSeries<K, BoxplotOrientV<K, f32>> {
+    /// Create a series of vertical boxplots from groups of raw values.
+    ///
+    /// - `groups`: The groups, each with its key on the X axis and its raw values
+    /// - `quartiles`: The constructor computing the quartiles and the fences of each group
+    /// - **returns** The newly created series
+    pub fn from_groups<T, D, I, F>(groups: I, quartiles: F) -> Self
+    where
+        T: Into<f64> + Copy + PartialOrd,
+        D: AsRef<[T]>,
+        I: IntoIterator<Item = (K, D)>,
+        F: Fn(&[T]) -> Quartiles,
+    {
+        Self::with_groups(groups, quartiles)
+    }
+}
+
+impl<K: Clone> BoxplotSeries<K, BoxplotOrientH<K, f32>> {
+    /// Create a series of horizontal boxplots from groups of raw values.
+    ///
+    /// - `groups`: The groups, each with its key on the Y axis and its raw values
+    /// - `quartiles`: The constructor computing the quartiles and the fences of each group
+    /// - **returns** The newly created series
+    pub fn from_groups_horizontal<T, D, I, F>(groups: I, quartiles: F) -> Self
+    where
+        T: Into<f64> + Copy + PartialOrd,
+        D: AsRef<[T]>,
+        I: IntoIterator<Item = (K, D)>,
+        F: Fn(&[T]) -> Quartiles,
+    {
+        Self::with_groups(groups, quartiles)
+    }
+}
+
+impl<K, O: BoxplotOrient<K, f32>> BoxplotSeries<K, O> {
+    fn with_groups<T, D, I, F>(groups: I, quartiles: F) -> Self
+    where
+        T: Into<f64> + Copy + PartialOrd,
+        D: AsRef<[T]>,
+        I: IntoIterator<Item = (K, D)>,
+        F: Fn(&[T]) -> Quartiles,
+    {
+        let mut max_count = 0;
+        let boxes = groups
+            .into_iter()
+            .filter_map(|(key, values)| {
+                let values = values.as_ref();
+                max_count = max_count.max(values.len());
+                // The quartiles constructors panic on the groups they can't summarize
+                if values.is_empty() || values.iter().any(|&v| v.into().is_nan()) {
+                    return None;
+                }
+                Some(Boxplot::with_data(key, values, &quartiles))
+            })
+            .collect();
+        Self { boxes, max_count }
+    }
+
+    fn map_boxes<F: FnMut(Boxplot<K, O>) -> Boxplot<K, O>>(mut self, f: F) -> Self {
+        self.boxes = self.boxes.into_iter().map(f).collect();
+        self
+    }
+
+    /// Set the style of the boxplots, see [`Boxplot::style()`]
+    pub fn style<S: Into<ShapeStyle>>(self, style: S) -> Self {
+        let style = style.into();
+        self.map_boxes(|boxplot| boxplot.style(style))
+    }
+
+    /// Set the bar width of the boxplots, see [`Boxplot::width()`]
+    pub fn width(self, width: u32) -> Self {
+        self.map_boxes(|boxplot| boxplot.width(width))
+    }
+
+    /// Scale the bar width of the boxplots by the square root of the size of their group,
+    /// relative to the largest group, see [`Boxplot::width_by_count()`]
+    pub fn width_by_count(self) -> Self {
+        let max_count = self.max_count;
+        self.map_boxes(|boxplot| boxplot.width_by_count(max_count))
+    }
+
+    /// Fill the boxes with a color, see [`Boxplot::box_fill()`]
+    pub fn box_fill<C: Color>(self, color: C) -> Self {
+        let color = color.to_rgba();
+        self.map_boxes(|boxplot| boxplot.box_fill(color))
+    }
+
+    /// Set whether the outliers are drawn, which they are by default, see
+    /// [`Boxplot::show_outliers()`]
+    pub fn show_outliers(self, show: bool) -> Self {
+        self.map_boxes(|boxplot| boxplot.show_outliers(show))
+    }
+
+    /// Set the style of the outlier circles, see [`Boxplot::outlier_style()`]
+    pub fn outlier_style<S: Into<ShapeStyle>>(self, style: S) -> Self {
+        let style = style.into();
+        self.map_boxes(|boxplot| boxplot.outlier_style(style))
+    }
+}
+
+impl<K, O: BoxplotOrient<K, f32>> IntoIterator for BoxplotSeries<K, O> {
+    type Item = Boxplot<K, O>;
+    type IntoIter = std::vec::IntoIter<Boxplot<K, O>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.boxes.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_boxplot_series() {
+        let drawing_area = create_mocked_drawing_area(300, 200, |m| {
+            m.check_draw_circle(|c, _, _, _, _| assert_eq!(c, RED.to_rgba()));
+            m.drop_check(|b| {
+                // The outlier of the first group, and the three of the second one
+                assert_eq!(b.num_draw_circle_call, 4);
+                assert_eq!(b.num_draw_rect_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..4, -100f32..100f32)
+            .unwrap();
+
+        let groups = vec![
+            (1, vec![-90.0, 7.0, 15.0, 36.0, 39.0, 40.0, 41.0]),
+            (2, vec![]),
+            (3, vec![1.0, f64::NAN]),
+            (
+                4,
+                vec![16.0, 17.0, 50.0, 60.0, 40.0, 41.0, 42.0, 43.0, 47.0],
+            ),
+        ];
+        let series = BoxplotSeries::from_groups(groups, Quartiles::new)
+            .width(20)
+            .width_by_count()
+            .outlier_style(RED);
+        // The empty group and the one with a NaN are left out
+        assert_eq!(series.boxes.len(), 2);
+        assert_eq!(series.max_count, 9);
+        chart.draw_series(series).unwrap();
+    }
+}
@@ -0,0 +1,158 @@
+use crate::element::Circle;
+use crate::style::ShapeStyle;
+
+/// How the values of a [`BubbleSeries`] are mapped to the sizes of the bubbles
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BubbleScale {
+    /// The area of the bubbles is proportional to the values, thus the radius grows as the
+    /// square root of the value. This is the default, since the area is what the eye compares.
+    Sqrt,
+    /// The radius of the bubbles is proportional to the values, which exaggerates the large
+    /// values, as the area grows as the square of the value
+    Linear,
+}
+
+/**
+The bubble series object, which takes an iterator of `(x, y, value)` data points and creates a
+circle for each of them, whose size encodes the value.
+
+The values are mapped from zero to the largest value onto the radius range: the largest value
+has the maximum radius, and by default the area of the other bubbles is proportional to their
+value, which is the perceptually correct encoding (see [`BubbleScale`]). The bubbles smaller
+than the minimum radius are enlarged to it, so they remain visible. The negative values are
+treated as zero.
+
+# Example
+
+```
+use plotters::prelude::*;
+let mut svg = String::new();
+let drawing_area = SVGBackend::with_string(&mut svg, (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..4.0, 0.0..4.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let data = [(1.0, 1.0, 10.0), (2.0, 3.0, 40.0), (3.0, 2.0, 90.0)];
+chart
+    .draw_series(BubbleSeries::new(data, BLUE.mix(0.5).filled()).radius_range(2.0, 30.0))
+    .unwrap();
+```
+*/
+pub struct BubbleSeries<X, Y> {
+    data: std::vec::IntoIter<(X, Y, f64)>,
+    max_value: f64,
+    scale: BubbleScale,
+    radius_range: (f64, f64),
+    style: ShapeStyle,
+}
+
+impl<X, Y> BubbleSeries<X, Y> {
+    /// Create a new bubble series
+    /// - `iter`: The iterator of the `(x, y, value)` data points
+    /// - `style`: The style of the bubbles
+    /// - returns the created series, with the area of the bubbles proportional to the values
+    ///   and a radius from 2 to 20 pixels
+    pub fn new<V: Into<f64>, I: IntoIterator<Item = (X, Y, V)>, S: Into<ShapeStyle>>(
+        iter: I,
+        style: S,
+    ) -> Self {
+        let data: Vec<_> = iter
+            .into_iter()
+            .map(|(x, y, value)| (x, y, value.into().max(0.0)))
+            .collect();
+        let max_value = data.iter().fold(0.0, |max: f64, point| max.max(point.2));
+        Self {
+            data: data.into_iter(),
+            max_value,
+            scale: BubbleScale::Sqrt,
+            radius_range: (2.0, 20.0),
+            style: style.into(),
+        }
+    }
+
+    /// Set how the values are mapped to the sizes of the bubbles
+    /// - `scale`: The scale of the sizes, [`BubbleScale::Sqrt`] by default
+    /// - returns the modified series
+    pub fn scale(mut self, scale: BubbleScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the range of the radius of the bubbles
+    /// - `min`: The minimum radius in pixels, which the smaller bubbles are enlarged to
+    /// - `max`: The radius in pixels of the bubble with the largest value
+    /// - returns the modified series
+    pub fn radius_range(mut self, min: f64, max: f64) -> Self {
+        self.radius_range = (min.min(max), max.max(min));
+        self
+    }
+
+    fn radius(&self, value: f64) -> f64 {
+        let (min, max) = self.radius_range;
+        if self.max_value <= 0.0 {
+            return min;
+        }
+        let ratio = value / self.max_value;
+        let ratio = match self.scale {
+            BubbleScale::Sqrt => ratio.sqrt(),
+            BubbleScale::Linear => ratio,
+        };
+        (max * ratio).max(min)
+    }
+}
+
+impl<X, Y> Iterator for BubbleSeries<X, Y> {
+    type Item = Circle<(X, Y), f64>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, value) = self.data.next()?;
+        Some(Circle::new((x, y), self.radius(value), self.style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bubble_radius() {
+        let series = BubbleSeries::new([(0, 0, 100.0), (1, 1, 25.0), (2, 2, 0.01)], RED)
+            .radius_range(2.0, 20.0);
+        assert_eq!(series.radius(100.0), 20.0);
+        // A quarter of the value makes a quarter of the area
+        assert_eq!(series.radius(25.0), 10.0);
+        assert_eq!(series.radius(0.01), 2.0);
+
+        let series = series.scale(BubbleScale::Linear);
+        assert_eq!(series.radius(25.0), 5.0);
+    }
+
+    #[test]
+    fn test_bubble_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            let mut radius = vec![30, 15, 3];
+            m.check_draw_circle(move |c, _, f, _, r| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(f);
+                assert_eq!(r, radius.remove(0));
+            });
+
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                BubbleSeries::new(vec![(2, 2, 400u32), (5, 5, 100), (8, 8, 0)], BLUE.filled())
+                    .radius_range(3.0, 30.0),
+            )
+            .expect("Drawing Error");
+    }
+}
@@ -0,0 +1,153 @@
+use std::ops::Range;
+
+use crate::element::PathElement;
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::{Color, RGBAColor, ShapeStyle};
+
+/**
+A line series whose color changes along the line, following a colormap.
+
+It takes an iterator of `(x, y, value)` data points, and each segment of the line takes the
+color the colormap gives to the average of the values at its ends. The values are mapped onto
+the colormap from their minimum to their maximum, unless a range is set with
+[`ColoredLineSeries::value_range()`]. The segments whose value is NaN are not drawn.
+
+The segments are drawn as a single series, thus the series has a single legend entry.
+
+# Example
+
+```
+use plotters::prelude::*;
+let mut svg = String::new();
+let drawing_area = SVGBackend::with_string(&mut svg, (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0.0..4.0, -1.0..1.0)
+    .unwrap();
+// The speed is the absolute value of the derivative
+let data = (0..=40).map(|x| x as f64 / 10.0).map(|x| (x, x.sin(), x.cos().abs()));
+chart
+    .draw_series(ColoredLineSeries::new(data, &ViridisRGB, 2))
+    .unwrap()
+    .label("sin(x)")
+    .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], ViridisRGB.get_color(0.5)));
+chart.configure_series_labels().draw().unwrap();
+```
+*/
+pub struct ColoredLineSeries<'a, X, Y> {
+    data: Vec<(X, Y, f64)>,
+    colormap: Box<dyn Fn(f64, f64, f64) -> RGBAColor + 'a>,
+    range: Range<f64>,
+    stroke_width: u32,
+    idx: usize,
+}
+
+impl<'a, X, Y> ColoredLineSeries<'a, X, Y> {
+    /**
+    Creates a line series colored by the values of its points.
+
+    - `iter`: The iterator of the `(x, y, value)` data points
+    - `colormap`: The colormap picking the color of each segment
+    - `stroke_width`: The width of the line
+    - **returns** The newly created series
+
+    See [`ColoredLineSeries`] for more information and examples.
+    */
+    pub fn new<V, I, C, M>(iter: I, colormap: &'a M, stroke_width: u32) -> Self
+    where
+        V: Into<f64>,
+        I: IntoIterator<Item = (X, Y, V)>,
+        C: Color,
+        M: ColorMap<C, f64>,
+    {
+        let data: Vec<_> = iter
+            .into_iter()
+            .map(|(x, y, value)| (x, y, value.into()))
+            .collect();
+        let (min, max) = data
+            .iter()
+            .map(|point| point.2)
+            .filter(|value| !value.is_nan())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        Self {
+            data,
+            colormap: Box::new(move |value, min, max| {
+                colormap.get_color_normalized(value, min, max).to_rgba()
+            }),
+            range: if min < max { min..max } else { 0.0..1.0 },
+            stroke_width,
+            idx: 0,
+        }
+    }
+
+    /**
+    Sets the range of values spanned by the colormap, the values outside of it take the color
+    of its closest bound.
+
+    See [`ColoredLineSeries`] for more information and examples.
+    */
+    pub fn value_range(mut self, range: Range<f64>) -> Self {
+        self.range = range;
+        self
+    }
+}
+
+impl<'a, X: Clone, Y: Clone> Iterator for ColoredLineSeries<'a, X, Y> {
+    type Item = PathElement<(X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx + 1 < self.data.len() {
+            let (x0, y0, v0) = &self.data[self.idx];
+            let (x1, y1, v1) = &self.data[self.idx + 1];
+            self.idx += 1;
+
+            let value = (v0 + v1) / 2.0;
+            if value.is_nan() {
+                continue;
+            }
+            let value = value.max(self.range.start).min(self.range.end);
+            let color = (self.colormap)(value, self.range.start, self.range.end);
+            return Some(PathElement::new(
+                vec![(x0.clone(), y0.clone()), (x1.clone(), y1.clone())],
+                ShapeStyle::from(color).stroke_width(self.stroke_width),
+            ));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_colored_line_segments() {
+        let colormap = DerivedColorMap::new(&[BLACK, WHITE]);
+        let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+            let mut colors = vec![BLACK, WHITE];
+            m.check_draw_path(move |c, s, path| {
+                assert_eq!(c, colors.remove(0).to_rgba());
+                assert_eq!(s, 3);
+                assert_eq!(path.len(), 2);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build chart error");
+
+        // The segments from 1 to 3 aren't drawn, since their average value is NaN
+        let data = [
+            (0, 0, -1.0),
+            (1, 1, 1.0),
+            (2, 2, f64::NAN),
+            (3, 3, 2.0),
+            (4, 4, 2.0),
+        ];
+        chart
+            .draw_series(ColoredLineSeries::new(data, &colormap, 3).value_range(0.0..2.0))
+            .expect("Drawing Error");
+    }
+}
@@ -0,0 +1,376 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use super::progress::Progress;
+use crate::element::PathElement;
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::{Color, ShapeStyle};
+
+// A grid edge a contour crosses: the horizontal edge from the grid point `(i, j)` to `(i + 1, j)`,
+// or the vertical edge from `(i, j)` to `(i, j + 1)`
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Edge {
+    Horizontal(usize, usize),
+    Vertical(usize, usize),
+}
+
+/**
+Presents a scalar field sampled on a regular grid as iso-contour lines.
+
+The contours are computed with the marching squares algorithm: each level produces one path
+per connected contour line, colored by mapping the level onto a colormap spanning the lowest
+to the highest level. The saddle cells, whose opposite corners are on the same side of the
+level, are disambiguated with the average of their four corners. A contour enclosed in the grid
+is a closed path, while a contour reaching the border of the grid ends exactly on it.
+
+The value `grid[j][i]` is the value of the field at the `i`-th column and the `j`-th row of the
+grid, the columns being evenly spread over the X range and the rows over the Y range. The cells
+having a NaN corner are skipped.
+
+# Example
+
+```
+use plotters::prelude::*;
+// A bump centered in the grid
+let grid: Vec<Vec<f64>> = (-10..=10)
+    .map(|j| (-10..=10).map(|i| (-f64::from(i * i + j * j) / 50.0).exp()).collect())
+    .collect();
+let drawing_area = SVGBackend::new("contour_series.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(-1.0..1.0, -1.0..1.0)
+    .unwrap();
+chart
+    .draw_series(ContourSeries::new(
+        -1.0..1.0,
+        -1.0..1.0,
+        &grid,
+        vec![0.2, 0.5, 0.8],
+        &ViridisRGB,
+    ))
+    .unwrap();
+```
+*/
+pub struct ContourSeries {
+    paths: std::vec::IntoIter<(Vec<(f64, f64)>, ShapeStyle)>,
+    stroke_width: u32,
+    progress: Progress<'static>,
+}
+
+impl ContourSeries {
+    /**
+    Creates the contour lines of a scalar field.
+
+    - `x_range`: The X coordinates spanned by the columns of the grid
+    - `y_range`: The Y coordinates spanned by the rows of the grid
+    - `grid`: The rows of the sampled values
+    - `levels`: The values whose contour lines are drawn
+    - `colormap`: The colormap picking the color of each level
+    - **returns** The newly created series
+
+    See [`ContourSeries`] for more information and examples.
+    */
+    pub fn new<R, L, C, M>(
+        x_range: Range<f64>,
+        y_range: Range<f64>,
+        grid: &[R],
+        levels: L,
+        colormap: &M,
+    ) -> Self
+    where
+        R: AsRef<[f64]>,
+        L: IntoIterator<Item = f64>,
+        C: Color,
+        M: ColorMap<C, f64>,
+    {
+        let levels: Vec<f64> = levels.into_iter().collect();
+        let min = levels.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = levels.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        let rows = grid.len();
+        let cols = grid.iter().map(|row| row.as_ref().len()).min().unwrap_or(0);
+        let step = |range: &Range<f64>, n: usize| (range.end - range.start) / (n.max(2) - 1) as f64;
+        let (dx, dy) = (step(&x_range, cols), step(&y_range, rows));
+        let value = |i: usize, j: usize| grid[j].as_ref()[i];
+
+        let mut paths = vec![];
+        for level in levels.iter().copied() {
+            let color = if min < max {
+                colormap.get_color_normalized(level, min, max)
+            } else {
+                colormap.get_color(0.5)
+            };
+            let style = color.stroke_width(1);
+
+            // The position of an edge crossing, interpolated between the two ends of the edge
+            let crossing = |edge: Edge| {
+                let ((i0, j0), (i1, j1)) = match edge {
+                    Edge::Horizontal(i, j) => ((i, j), (i + 1, j)),
+                    Edge::Vertical(i, j) => ((i, j), (i, j + 1)),
+                };
+                let (v0, v1) = (value(i0, j0), value(i1, j1));
+                let t = (level - v0) / (v1 - v0);
+                (
+                    x_range.start + (i0 as f64 + t * (i1 - i0) as f64) * dx,
+                    y_range.start + (j0 as f64 + t * (j1 - j0) as f64) * dy,
+                )
+            };
+
+            let segments = Self::march(rows, cols, &value, level);
+            for path in Self::join(&segments) {
+                let points = path.into_iter().map(&crossing).collect();
+                paths.push((points, style));
+            }
+        }
+
+        Self {
+            paths: paths.into_iter(),
+            stroke_width: 1,
+            progress: Progress::none(),
+        }
+    }
+
+    /**
+    Sets the stroke width of the contour lines.
+
+    - `width`: The stroke width, in backend pixels
+    - **returns** The updated series
+    */
+    pub fn stroke_width(mut self, width: u32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /**
+    Reports the progress of the drawing of the contour lines: the callback gets the fraction of
+    the lines handed out so far, about each percent, and 1.0 once they have all been handed out.
+
+    - `callback`: The function getting the fractions, e.g. to update a progress bar
+    - **returns** The updated series
+    */
+    pub fn progress<F: FnMut(f64) + 'static>(mut self, callback: F) -> Self {
+        self.progress = Progress::new(callback, self.paths.len());
+        self
+    }
+
+    // Find the segments of the contour lines crossing each cell of the grid
+    fn march(
+        rows: usize,
+        cols: usize,
+        value: &impl Fn(usize, usize) -> f64,
+        level: f64,
+    ) -> Vec<(Edge, Edge)> {
+        let mut segments = vec![];
+        for j in 0..rows.saturating_sub(1) {
+            for i in 0..cols.saturating_sub(1) {
+                // The corners, counterclockwise from the lower left one
+                let corners = [
+                    value(i, j),
+                    value(i + 1, j),
+                    value(i + 1, j + 1),
+                    value(i, j + 1),
+                ];
+                if corners.iter().any(|v| v.is_nan()) {
+                    continue;
+                }
+                // The edges, each following the corner it starts from
+                let edges = [
+                    Edge::Horizontal(i, j),
+                    Edge::Vertical(i + 1, j),
+                    Edge::Horizontal(i, j + 1),
+                    Edge::Vertical(i, j),
+                ];
+                let above = corners.map(|v| v >= level);
+                let crossed = (0..4).filter(|&k| above[k] != above[(k + 1) % 4]).count();
+                if crossed == 2 {
+                    let mut ends = (0..4).filter(|&k| above[k] != above[(k + 1) % 4]);
+                    if let (Some(a), Some(b)) = (ends.next(), ends.next()) {
+                        segments.push((edges[a], edges[b]));
+                    }
+                } else if crossed == 4 {
+                    // A saddle: the corners on the other side of the level than the center of
+                    // the cell are cut off from it
+                    let center = corners.iter().sum::<f64>() / 4.0 >= level;
+                    for k in (0..4).filter(|&k| above[k] != center) {
+                        segments.push((edges[(k + 3) % 4], edges[k]));
+                    }
+                }
+            }
+        }
+        segments
+    }
+
+    // Chain the segments sharing an edge into paths, the open paths first and then the loops
+    fn join(segments: &[(Edge, Edge)]) -> Vec<Vec<Edge>> {
+        let mut adjacency: HashMap<Edge, Vec<usize>> = HashMap::new();
+        for (idx, (a, b)) in segments.iter().enumerate() {
+            adjacency.entry(*a).or_default().push(idx);
+            adjacency.entry(*b).or_default().push(idx);
+        }
+
+        let mut used = vec![false; segments.len()];
+        let trace = |start: Edge, mut seg: usize, used: &mut [bool]| {
+            let mut path = vec![start];
+            let mut edge = start;
+            loop {
+                used[seg] = true;
+                let (a, b) = segments[seg];
+                edge = if a == edge { b } else { a };
+                path.push(edge);
+                match adjacency[&edge].iter().find(|&&next| !used[next]) {
+                    Some(next) => seg = *next,
+                    None => return path,
+                }
+            }
+        };
+
+        let mut paths = vec![];
+        for pass_loops in [false, true] {
+            for seg in 0..segments.len() {
+                if used[seg] {
+                    continue;
+                }
+                let (a, b) = segments[seg];
+                if pass_loops || adjacency[&a].len() == 1 {
+                    paths.push(trace(a, seg, &mut used));
+                } else if adjacency[&b].len() == 1 {
+                    paths.push(trace(b, seg, &mut used));
+                }
+            }
+        }
+        paths
+    }
+}
+
+impl Iterator for ContourSeries {
+    type Item = PathElement<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let stroke_width = self.stroke_width;
+        let path = self
+            .paths
+            .next()
+            .map(|(points, style)| PathElement::new(points, style.stroke_width(stroke_width)));
+        self.progress.advance(path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn contours(grid: &[Vec<f64>], levels: Vec<f64>) -> Vec<Vec<(f64, f64)>> {
+        ContourSeries::new(0.0..1.0, 0.0..1.0, grid, levels, &ViridisRGB)
+            .paths
+            .map(|(points, _)| points)
+            .collect()
+    }
+
+    #[test]
+    fn test_closed_contour() {
+        let grid = vec![
+            vec![0.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 0.0],
+        ];
+        let paths = contours(&grid, vec![0.5]);
+        assert_eq!(paths.len(), 1);
+        // A diamond around the peak, which ends where it starts
+        assert_eq!(paths[0].len(), 5);
+        assert_eq!(paths[0].first(), paths[0].last());
+        for p in [(0.5, 0.25), (0.75, 0.5), (0.5, 0.75), (0.25, 0.5)] {
+            assert!(paths[0].contains(&p));
+        }
+
+        assert!(contours(&grid, vec![2.0]).is_empty());
+    }
+
+    #[test]
+    fn test_open_contour() {
+        // A ramp along the X axis, whose contours are vertical lines ending on the border
+        let grid = vec![vec![0.0, 1.0, 2.0]; 3];
+        let paths = contours(&grid, vec![0.5, 1.5]);
+        assert_eq!(paths.len(), 2);
+        for (path, x) in paths.iter().zip([0.25, 0.75]) {
+            assert_eq!(path.len(), 3);
+            assert!(path.iter().all(|p| p.0 == x));
+            let mut ys: Vec<_> = path.iter().map(|p| p.1).collect();
+            ys.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(ys, vec![0.0, 0.5, 1.0]);
+        }
+    }
+
+    #[test]
+    fn test_saddle() {
+        let grid = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+
+        // The center is above the level: the low corners are cut off
+        let mut paths = contours(&grid, vec![0.4]);
+        paths.sort_by(|a, b| a[0].partial_cmp(&b[0]).unwrap());
+        let cut_off: Vec<_> = paths
+            .iter()
+            .map(|path| {
+                let mut path = path.clone();
+                path.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                path
+            })
+            .collect();
+        assert_eq!(
+            cut_off,
+            vec![vec![(0.0, 0.6), (0.4, 1.0)], vec![(0.6, 0.0), (1.0, 0.4)]]
+        );
+
+        // The center is below the level: the high corners are cut off
+        let mut paths = contours(&grid, vec![0.6]);
+        for path in paths.iter_mut() {
+            path.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        }
+        paths.sort_by(|a, b| a[0].partial_cmp(&b[0]).unwrap());
+        assert_eq!(
+            paths,
+            vec![vec![(0.0, 0.4), (0.4, 0.0)], vec![(0.6, 1.0), (1.0, 0.6)]]
+        );
+    }
+
+    #[test]
+    fn test_contour_style() {
+        let grid = vec![vec![0.0, 1.0, 2.0]; 3];
+        let colormap = DerivedColorMap::new(&[BLACK, WHITE]);
+        let paths: Vec<_> =
+            ContourSeries::new(0.0..1.0, 0.0..1.0, &grid, vec![0.5, 1.5], &colormap)
+                .stroke_width(3)
+                .collect();
+        assert_eq!(paths.len(), 2);
+
+        let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+            let mut colors = vec![];
+            m.check_draw_path(move |c, s, _| {
+                assert_eq!(s, 3);
+                colors.push(c);
+                if colors.len() == 2 {
+                    assert_eq!(colors, vec![BLACK.to_rgba(), WHITE.to_rgba()]);
+                }
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        chart.draw_series(paths).unwrap();
+    }
+
+    #[test]
+    fn test_contour_progress() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let grid = vec![vec![0.0, 1.0, 2.0, 3.0]; 3];
+        let fractions = Rc::new(RefCell::new(vec![]));
+        let sink = fractions.clone();
+        let series =
+            ContourSeries::new(0.0..1.0, 0.0..1.0, &grid, vec![0.5, 1.5, 2.5], &BlackWhite)
+                .progress(move |fraction| sink.borrow_mut().push(fraction));
+        assert_eq!(series.count(), 3);
+        assert_eq!(*fractions.borrow(), vec![1.0 / 3.0, 2.0 / 3.0, 1.0]);
+    }
+}
@@ -0,0 +1,366 @@
+use std::ops::Range;
+
+use crate::element::PathElement;
+use crate::style::{Palette, Palette99, ShapeStyle, BLACK};
+
+/**
+The dendrogram of a hierarchical clustering, which draws each merge of two clusters as a bracket
+joining them at the height of the merge.
+
+The clustering is given as a linkage, in the same layout as the one of SciPy: with `n` leaves,
+there are `n - 1` merges `(a, b, height)`, the leaves are the clusters `0` to `n - 1` and the
+`i`-th merge creates the cluster `n + i`. A merge which refers to a cluster not created, or
+already merged, is ignored.
+
+The leaves are laid out from the tree, so that the brackets don't cross: the leaf at position
+`k` is the leaf `leaf_order()[k]`, and the positions are `0.0`, `1.0`, ... along the axis of the
+leaves, thus this axis is usually made of the leaf labels put at these positions with ticks, see
+[`Dendrogram::leaf_range`] and [`Dendrogram::leaf_order`]. The other axis holds the heights of
+the merges: by default, the leaves are along the X axis and the merges go up along the Y axis,
+while [`Dendrogram::horizontal`] swaps the axes. The root can be turned toward the other side by
+reversing the range of the heights.
+
+With [`Dendrogram::color_threshold`], each cluster formed below a cut height is drawn in its own
+color, picked from [`Dendrogram::cluster_styles`], while the links above the cut keep the style
+of the dendrogram.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("dendrogram_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+// Four leaves: 0 and 1 merge first, then 2 and 3, then both clusters
+let dendrogram = Dendrogram::new(&[(0, 1, 1.0), (2, 3, 1.5), (4, 5, 4.0)]).color_threshold(2.0);
+let mut chart_builder = ChartBuilder::on(&drawing_area);
+chart_builder.margin(5).set_left_and_bottom_label_area_size(20);
+let mut chart_context = chart_builder
+    .build_cartesian_2d(dendrogram.leaf_range(), dendrogram.height_range())
+    .unwrap();
+chart_context.configure_mesh().draw().unwrap();
+chart_context.draw_series(dendrogram).unwrap();
+```
+*/
+pub struct Dendrogram {
+    merges: Vec<(usize, usize, f64)>,
+    horizontal: bool,
+    threshold: Option<f64>,
+    style: ShapeStyle,
+    cluster_styles: Vec<ShapeStyle>,
+}
+
+impl Dendrogram {
+    /**
+    Creates a new dendrogram from a linkage.
+
+    - `merges`: The merges `(a, b, height)` of the clusters, the `i`-th one creating the cluster
+      `n + i`, where `n` is the number of leaves, that is the number of merges plus one
+    - **returns** The newly created dendrogram, vertical, black, and without color threshold
+
+    See [`Dendrogram`] for more information and examples.
+    */
+    pub fn new(merges: &[(usize, usize, f64)]) -> Self {
+        Self {
+            merges: merges.to_vec(),
+            horizontal: false,
+            threshold: None,
+            style: BLACK.into(),
+            cluster_styles: vec![],
+        }
+    }
+
+    /**
+    Puts the leaves along the Y axis and the heights along the X axis.
+
+    See [`Dendrogram`] for more information and examples.
+    */
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    /**
+    Sets the style of the links, or of the links above the cut height when there is a color
+    threshold.
+
+    See [`Dendrogram`] for more information and examples.
+    */
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /**
+    Colors the clusters formed below a cut height: each subtree whose merges are all lower than
+    the cut gets its own style.
+
+    - `height`: The cut height
+
+    See [`Dendrogram`] for more information and examples.
+    */
+    pub fn color_threshold(mut self, height: f64) -> Self {
+        self.threshold = Some(height);
+        self
+    }
+
+    /**
+    Sets the styles of the clusters below the cut height, which are used from left to right and
+    cycled. The default is the colors of [`Palette99`].
+
+    See [`Dendrogram`] for more information and examples.
+    */
+    pub fn cluster_styles<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(
+        mut self,
+        styles: I,
+    ) -> Self {
+        self.cluster_styles = styles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    fn leaf_count(&self) -> usize {
+        self.merges.len() + 1
+    }
+
+    // The children of each cluster, None for the leaves and the ignored merges
+    fn children(&self) -> Vec<Option<(usize, usize)>> {
+        let n = self.leaf_count();
+        let mut merged = vec![false; n + self.merges.len()];
+        let mut children = vec![None; n + self.merges.len()];
+        for (i, &(a, b, _)) in self.merges.iter().enumerate() {
+            let exists = |c: usize| c < n || (c < n + i && children[c].is_some());
+            if a == b || !exists(a) || !exists(b) || merged[a] || merged[b] {
+                continue;
+            }
+            merged[a] = true;
+            merged[b] = true;
+            children[n + i] = Some((a, b));
+        }
+        children
+    }
+
+    // The order of the leaves and the cluster of each node below the cut, walking the trees
+    // from the left, the trees of the last merges first and the lone leaves last
+    fn layout(&self) -> (Vec<usize>, Vec<Option<usize>>) {
+        let n = self.leaf_count();
+        let children = self.children();
+        let mut is_child = vec![false; children.len()];
+        for &(a, b) in children.iter().flatten() {
+            is_child[a] = true;
+            is_child[b] = true;
+        }
+        let roots = (n..children.len())
+            .rev()
+            .filter(|&c| children[c].is_some())
+            .chain(0..n)
+            .filter(|&c| !is_child[c]);
+
+        let mut order = Vec::with_capacity(n);
+        let mut clusters = vec![None; children.len()];
+        let mut next_cluster = 0;
+        for root in roots {
+            let mut stack = vec![(root, None)];
+            while let Some((node, cluster)) = stack.pop() {
+                let (a, b) = match children[node] {
+                    Some(pair) => pair,
+                    None => {
+                        order.push(node);
+                        continue;
+                    }
+                };
+                let height = self.merges[node - n].2;
+                let cluster = cluster.or_else(|| match self.threshold {
+                    Some(cut) if height < cut => {
+                        next_cluster += 1;
+                        Some(next_cluster - 1)
+                    }
+                    _ => None,
+                });
+                clusters[node] = cluster;
+                stack.push((b, cluster));
+                stack.push((a, cluster));
+            }
+        }
+        (order, clusters)
+    }
+
+    /**
+    Computes the order of the leaves along their axis: the leaf at the position `k` is the
+    `k`-th one of the result.
+
+    See [`Dendrogram`] for more information and examples.
+    */
+    pub fn leaf_order(&self) -> Vec<usize> {
+        self.layout().0
+    }
+
+    /**
+    Gives the range of the axis of the leaves, which puts them at the center of equal slots.
+
+    See [`Dendrogram`] for more information and examples.
+    */
+    pub fn leaf_range(&self) -> Range<f64> {
+        -0.5..self.leaf_count() as f64 - 0.5
+    }
+
+    /**
+    Computes the range of the axis of the heights, from zero to the highest merge.
+
+    See [`Dendrogram`] for more information and examples.
+    */
+    pub fn height_range(&self) -> Range<f64> {
+        let children = self.children();
+        let n = self.leaf_count();
+        let top = self
+            .merges
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| children[n + i].is_some())
+            .fold(0.0f64, |top, (_, &(_, _, height))| top.max(height));
+        0.0..top
+    }
+
+    // The bracket of each merge, from the top of the first cluster to the top of the second one,
+    // with its style
+    fn links(&self) -> Vec<([(f64, f64); 4], ShapeStyle)> {
+        let n = self.leaf_count();
+        let children = self.children();
+        let (order, clusters) = self.layout();
+
+        // The position of each node along the axis of the leaves, and its height
+        let mut nodes = vec![(0.0, 0.0); children.len()];
+        for (position, &leaf) in order.iter().enumerate() {
+            nodes[leaf].0 = position as f64;
+        }
+        let mut links = vec![];
+        for c in n..children.len() {
+            let (a, b) = match children[c] {
+                Some(pair) => pair,
+                None => continue,
+            };
+            let height = self.merges[c - n].2;
+            let ((pa, ha), (pb, hb)) = (nodes[a], nodes[b]);
+            nodes[c] = ((pa + pb) / 2.0, height);
+
+            let mut bracket = [(pa, ha), (pa, height), (pb, height), (pb, hb)];
+            if self.horizontal {
+                bracket = bracket.map(|(p, h)| (h, p));
+            }
+            let style = match clusters[c] {
+                Some(cluster) => self.cluster_style(cluster),
+                None => self.style,
+            };
+            links.push((bracket, style));
+        }
+        links
+    }
+
+    fn cluster_style(&self, cluster: usize) -> ShapeStyle {
+        if self.cluster_styles.is_empty() {
+            Palette99::pick(cluster).into()
+        } else {
+            self.cluster_styles[cluster % self.cluster_styles.len()]
+        }
+    }
+}
+
+impl IntoIterator for Dendrogram {
+    type Item = PathElement<(f64, f64)>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.links()
+            .into_iter()
+            .map(|(bracket, style)| PathElement::new(bracket.to_vec(), style))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    // Five leaves: (1, 3) and (0, 4) merge low, 2 joins (0, 4), then everything merges
+    const LINKAGE: [(usize, usize, f64); 4] = [(1, 3, 1.0), (0, 4, 1.5), (2, 6, 2.5), (5, 7, 6.0)];
+
+    #[test]
+    fn test_dendrogram_layout() {
+        let dendrogram = Dendrogram::new(&LINKAGE);
+        assert_eq!(dendrogram.leaf_order(), vec![1, 3, 2, 0, 4]);
+        assert_eq!(dendrogram.leaf_range(), -0.5..4.5);
+        assert_eq!(dendrogram.height_range(), 0.0..6.0);
+
+        let links: Vec<_> = dendrogram.links().into_iter().map(|(b, _)| b).collect();
+        assert_eq!(
+            links,
+            vec![
+                [(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)],
+                [(3.0, 0.0), (3.0, 1.5), (4.0, 1.5), (4.0, 0.0)],
+                [(2.0, 0.0), (2.0, 2.5), (3.5, 2.5), (3.5, 1.5)],
+                [(0.5, 1.0), (0.5, 6.0), (2.75, 6.0), (2.75, 2.5)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dendrogram_horizontal() {
+        let links = Dendrogram::new(&[(0, 1, 2.0)]).horizontal().links();
+        assert_eq!(links[0].0, [(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)]);
+    }
+
+    #[test]
+    fn test_dendrogram_color_threshold() {
+        let styles: Vec<_> = Dendrogram::new(&LINKAGE)
+            .color_threshold(2.0)
+            .cluster_styles([RED, BLUE])
+            .links()
+            .into_iter()
+            .map(|(_, style)| style.color)
+            .collect();
+        // The clusters below the cut are colored from the left, the links above keep the style
+        assert_eq!(
+            styles,
+            vec![
+                RED.to_rgba(),
+                BLUE.to_rgba(),
+                BLACK.to_rgba(),
+                BLACK.to_rgba()
+            ]
+        );
+
+        // Without threshold, all the links have the style of the dendrogram
+        assert!(Dendrogram::new(&LINKAGE)
+            .style(GREEN)
+            .links()
+            .iter()
+            .all(|(_, style)| style.color == GREEN.to_rgba()));
+    }
+
+    #[test]
+    fn test_dendrogram_ignores_invalid_merges() {
+        // The second merge reuses the leaf 0, the third one the cluster the second one ignored
+        let dendrogram = Dendrogram::new(&[(0, 1, 1.0), (0, 2, 2.0), (2, 5, 3.0)]);
+        assert_eq!(dendrogram.leaf_order(), vec![0, 1, 2, 3]);
+        assert_eq!(dendrogram.height_range(), 0.0..1.0);
+        assert_eq!(dendrogram.links().len(), 1);
+    }
+
+    #[test]
+    fn test_draw_dendrogram() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!(path.len(), 4);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 4);
+            });
+        });
+        let dendrogram = Dendrogram::new(&LINKAGE);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(dendrogram.leaf_range(), dendrogram.height_range())
+            .expect("Build chart error");
+        chart.draw_series(dendrogram).expect("Drawing Error");
+    }
+}
@@ -0,0 +1,157 @@
+use super::MisalignedSeriesError;
+use crate::element::Polygon;
+use crate::style::ShapeStyle;
+
+/**
+Fills the area between a lower and an upper curve sharing the same X coordinates, such as a
+confidence band.
+
+The area is made of one polygon, going forward along the upper curve and backward along the
+lower one. The points where any of the coordinates is NaN are gaps, which split the area into
+several polygons. A semi-transparent style lets the overlapping bands show through each other.
+
+# Example
+
+```
+use plotters::prelude::*;
+let xs: Vec<f64> = (0..=40).map(|x| x as f64 / 10.0).collect();
+// The band widens as the prediction goes further
+let lower = xs.iter().map(|x| 1.0 + 0.2 * x - 0.1 - 0.05 * x);
+let upper = xs.iter().map(|x| 1.0 + 0.2 * x + 0.1 + 0.05 * x);
+let mut svg = String::new();
+let drawing_area = SVGBackend::with_string(&mut svg, (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..4.0, 0.0..3.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart
+    .draw_series(FillBetweenSeries::new(xs.iter().copied(), lower, upper, BLUE.mix(0.2)).unwrap())
+    .unwrap();
+chart
+    .draw_series(LineSeries::new(xs.iter().map(|x| (*x, 1.0 + 0.2 * x)), BLUE))
+    .unwrap();
+```
+*/
+pub struct FillBetweenSeries<X, Y> {
+    polygons: std::vec::IntoIter<Vec<(X, Y)>>,
+    style: ShapeStyle,
+}
+
+impl<X: Clone + PartialEq, Y: Clone + PartialEq> FillBetweenSeries<X, Y> {
+    /**
+    Creates the series filling the area between two curves.
+
+    - `xs`: The X coordinates shared by both curves
+    - `lower_ys`: The Y coordinates of the lower curve
+    - `upper_ys`: The Y coordinates of the upper curve
+    - `style`: The style of the area
+    - **returns** The series, or an error if a curve doesn't have as many points as the X
+      coordinates, where the X coordinates are the series 0, the lower curve the series 1 and the
+      upper curve the series 2
+
+    See [`FillBetweenSeries`] for more information and examples.
+    */
+    pub fn new<XI, LI, UI, S>(
+        xs: XI,
+        lower_ys: LI,
+        upper_ys: UI,
+        style: S,
+    ) -> Result<Self, MisalignedSeriesError>
+    where
+        XI: IntoIterator<Item = X>,
+        LI: IntoIterator<Item = Y>,
+        UI: IntoIterator<Item = Y>,
+        S: Into<ShapeStyle>,
+    {
+        let xs: Vec<_> = xs.into_iter().collect();
+        let lower: Vec<_> = lower_ys.into_iter().collect();
+        let upper: Vec<_> = upper_ys.into_iter().collect();
+        for (series, len) in [(1, lower.len()), (2, upper.len())] {
+            if len != xs.len() {
+                return Err(MisalignedSeriesError {
+                    series,
+                    point: len.min(xs.len()),
+                });
+            }
+        }
+
+        let mut polygons = vec![];
+        let mut run = 0..0;
+        for idx in 0..=xs.len() {
+            let is_gap =
+                idx == xs.len() || is_nan(&xs[idx]) || is_nan(&lower[idx]) || is_nan(&upper[idx]);
+            if !is_gap {
+                run.end = idx + 1;
+                continue;
+            }
+            // A single point doesn't enclose any area
+            if run.len() > 1 {
+                let forward = run.clone().map(|i| (xs[i].clone(), upper[i].clone()));
+                let backward = run.clone().rev().map(|i| (xs[i].clone(), lower[i].clone()));
+                polygons.push(forward.chain(backward).collect());
+            }
+            run = idx + 1..idx + 1;
+        }
+
+        Ok(Self {
+            polygons: polygons.into_iter(),
+            style: style.into(),
+        })
+    }
+}
+
+// NaN is the only value which isn't equal to itself
+#[allow(clippy::eq_op)]
+fn is_nan<T: PartialEq>(value: &T) -> bool {
+    value != value
+}
+
+impl<X, Y> Iterator for FillBetweenSeries<X, Y> {
+    type Item = Polygon<(X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let points = self.polygons.next()?;
+        Some(Polygon::new(points, self.style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_fill_between_polygons() {
+        let series = FillBetweenSeries::new(
+            [0.0, 1.0, 2.0, 3.0, 4.0, 5.0],
+            [0.0, 1.0, f64::NAN, 3.0, 4.0, 5.0],
+            [1.0, 2.0, 3.0, 4.0, 5.0, f64::NAN],
+            BLUE.mix(0.5),
+        )
+        .unwrap();
+        let polygons: Vec<_> = series.polygons.clone().collect();
+        // The gaps at x = 2 and x = 5 leave two runs of two points
+        assert_eq!(
+            polygons,
+            vec![
+                vec![(0.0, 1.0), (1.0, 2.0), (1.0, 1.0), (0.0, 0.0)],
+                vec![(3.0, 4.0), (4.0, 5.0), (4.0, 4.0), (3.0, 3.0)],
+            ]
+        );
+        assert_eq!(series.count(), 2);
+    }
+
+    #[test]
+    fn test_fill_between_unequal_lengths() {
+        let result = FillBetweenSeries::new([0, 1, 2], [0, 1, 2], [1, 2], BLUE);
+        assert_eq!(
+            result.err(),
+            Some(MisalignedSeriesError {
+                series: 2,
+                point: 2
+            })
+        );
+    }
+}
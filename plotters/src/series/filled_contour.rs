@@ -0,0 +1,359 @@
+use std::ops::Range;
+
+use super::progress::Progress;
+use crate::element::Polygon;
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::{Color, ShapeStyle};
+
+// A point of the grid, in grid units, with the value of the field there
+type GridPoint = (f64, f64, f64);
+
+// Cut the part of a convex polygon where the value, linear along its edges, is on one side of
+// a level: above it when `above` is set, below it otherwise
+fn clip(polygon: &[GridPoint], level: f64, above: bool) -> Vec<GridPoint> {
+    let inside = |p: &GridPoint| if above { p.2 >= level } else { p.2 <= level };
+    let mut clipped = vec![];
+    for (idx, p) in polygon.iter().enumerate() {
+        let q = &polygon[(idx + 1) % polygon.len()];
+        if inside(p) {
+            clipped.push(*p);
+        }
+        if inside(p) != inside(q) {
+            let t = (level - p.2) / (q.2 - p.2);
+            clipped.push((p.0 + t * (q.0 - p.0), p.1 + t * (q.1 - p.1), level));
+        }
+    }
+    clipped
+}
+
+/**
+Presents a scalar field sampled on a regular grid as filled contour bands.
+
+Each band is the region where the field is between two consecutive levels, filled with the
+color the colormap gives to the middle of the band, the colormap spanning the lowest to the
+highest level. The regions below the lowest level and above the highest one are not filled,
+thus the levels spanning the range of the values fill the whole grid.
+
+The bands are computed with the marching squares algorithm: each cell of the grid is split into
+four triangles meeting at its center, whose value is the average of its four corners, as the
+saddles of [`crate::series::ContourSeries`] are disambiguated. The field is interpolated
+linearly on each triangle, which is cut along the levels, thus the bands fit each other and the
+contour lines of the same levels. The bands reaching the border of the grid are cut there, and
+the cells having a NaN corner are left empty. A run of cells of a row which are inside the
+same band is filled with a single rectangle.
+
+The value `grid[j][i]` is the value of the field at the `i`-th column and the `j`-th row of the
+grid, the columns being evenly spread over the X range and the rows over the Y range.
+
+# Example
+
+```
+use plotters::prelude::*;
+// A bump centered in the grid
+let grid: Vec<Vec<f64>> = (-10..=10)
+    .map(|j| (-10..=10).map(|i| (-f64::from(i * i + j * j) / 50.0).exp()).collect())
+    .collect();
+let drawing_area = SVGBackend::new("filled_contour_series.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(-1.0..1.0, -1.0..1.0)
+    .unwrap();
+let levels = vec![0.0, 0.2, 0.4, 0.6, 0.8, 1.0];
+chart
+    .draw_series(FilledContourSeries::new(
+        -1.0..1.0,
+        -1.0..1.0,
+        &grid,
+        levels.clone(),
+        &ViridisRGB,
+    ))
+    .unwrap();
+chart
+    .draw_series(ContourSeries::new(-1.0..1.0, -1.0..1.0, &grid, levels, &BlackWhite))
+    .unwrap();
+```
+*/
+pub struct FilledContourSeries {
+    polygons: std::vec::IntoIter<(Vec<(f64, f64)>, ShapeStyle)>,
+    progress: Progress<'static>,
+}
+
+impl FilledContourSeries {
+    /**
+    Creates the filled contour bands of a scalar field.
+
+    - `x_range`: The X coordinates spanned by the columns of the grid
+    - `y_range`: The Y coordinates spanned by the rows of the grid
+    - `grid`: The rows of the sampled values
+    - `levels`: The bounds of the bands, each band lying between two consecutive levels
+    - `colormap`: The colormap picking the color of each band
+    - **returns** The newly created series
+
+    See [`FilledContourSeries`] for more information and examples.
+    */
+    pub fn new<R, L, C, M>(
+        x_range: Range<f64>,
+        y_range: Range<f64>,
+        grid: &[R],
+        levels: L,
+        colormap: &M,
+    ) -> Self
+    where
+        R: AsRef<[f64]>,
+        L: IntoIterator<Item = f64>,
+        C: Color,
+        M: ColorMap<C, f64>,
+    {
+        let mut levels: Vec<f64> = levels.into_iter().filter(|l| !l.is_nan()).collect();
+        levels.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        levels.dedup();
+        let (min, max) = match (levels.first(), levels.last()) {
+            (Some(&min), Some(&max)) => (min, max),
+            _ => (0.0, 0.0),
+        };
+        let styles: Vec<ShapeStyle> = levels
+            .windows(2)
+            .map(|bounds| {
+                let middle = (bounds[0] + bounds[1]) / 2.0;
+                colormap.get_color_normalized(middle, min, max).filled()
+            })
+            .collect();
+        // The band a value is in, the values equal to a level being in the band above it
+        // except for the highest level
+        let band_of = |v: f64| {
+            if styles.is_empty() || !(min..=max).contains(&v) {
+                return None;
+            }
+            let idx = levels.partition_point(|&l| l <= v);
+            Some((idx.max(1) - 1).min(styles.len() - 1))
+        };
+
+        let rows = grid.len();
+        let cols = grid.iter().map(|row| row.as_ref().len()).min().unwrap_or(0);
+        let step = |range: &Range<f64>, n: usize| (range.end - range.start) / (n.max(2) - 1) as f64;
+        let (dx, dy) = (step(&x_range, cols), step(&y_range, rows));
+        let value = |i: usize, j: usize| grid[j].as_ref()[i];
+        let to_coord = |x: f64, y: f64| (x_range.start + x * dx, y_range.start + y * dy);
+
+        let mut polygons = vec![];
+        let mut push_run = |band: usize, from: usize, to: usize, j: usize| {
+            let (x0, x1) = (from as f64, to as f64);
+            let (y0, y1) = (j as f64, j as f64 + 1.0);
+            let points = vec![
+                to_coord(x0, y0),
+                to_coord(x1, y0),
+                to_coord(x1, y1),
+                to_coord(x0, y1),
+            ];
+            polygons.push((points, styles[band]));
+        };
+        let mut pieces = vec![];
+
+        for j in 0..rows.saturating_sub(1) {
+            // The band and the first column of the run of uniform cells in progress
+            let mut run: Option<(usize, usize)> = None;
+            for i in 0..cols.saturating_sub(1) {
+                // The corners, counterclockwise from the lower left one
+                let corners = [
+                    (i as f64, j as f64, value(i, j)),
+                    (i as f64 + 1.0, j as f64, value(i + 1, j)),
+                    (i as f64 + 1.0, j as f64 + 1.0, value(i + 1, j + 1)),
+                    (i as f64, j as f64 + 1.0, value(i, j + 1)),
+                ];
+                let bands = corners.map(|c| band_of(c.2));
+                let uniform = match bands[0] {
+                    Some(band) if bands.iter().all(|&b| b == Some(band)) => Some(band),
+                    _ => None,
+                };
+                match (run, uniform) {
+                    (Some((band, _)), Some(cell_band)) if band == cell_band => continue,
+                    (Some((band, from)), _) => push_run(band, from, i, j),
+                    _ => {}
+                }
+                run = uniform.map(|band| (band, i));
+                if uniform.is_some() || corners.iter().any(|c| c.2.is_nan()) {
+                    continue;
+                }
+
+                let center = (
+                    i as f64 + 0.5,
+                    j as f64 + 0.5,
+                    corners.iter().map(|c| c.2).sum::<f64>() / 4.0,
+                );
+                for k in 0..4 {
+                    let triangle = [corners[k], corners[(k + 1) % 4], center];
+                    let low = triangle.iter().map(|p| p.2).fold(f64::INFINITY, f64::min);
+                    let high = triangle
+                        .iter()
+                        .map(|p| p.2)
+                        .fold(f64::NEG_INFINITY, f64::max);
+                    for (band, bounds) in levels.windows(2).enumerate() {
+                        if bounds[1] < low || bounds[0] > high {
+                            continue;
+                        }
+                        let piece = clip(&clip(&triangle, bounds[0], true), bounds[1], false);
+                        if piece.len() >= 3 {
+                            let points = piece.iter().map(|p| to_coord(p.0, p.1)).collect();
+                            pieces.push((points, styles[band]));
+                        }
+                    }
+                }
+            }
+            if let Some((band, from)) = run {
+                push_run(band, from, cols - 1, j);
+            }
+        }
+        polygons.append(&mut pieces);
+
+        Self {
+            polygons: polygons.into_iter(),
+            progress: Progress::none(),
+        }
+    }
+
+    /**
+    Reports the progress of the drawing of the bands: the callback gets the fraction of the
+    polygons handed out so far, about each percent, and 1.0 once they have all been handed out.
+
+    - `callback`: The function getting the fractions, e.g. to update a progress bar
+    - **returns** The updated series
+    */
+    pub fn progress<F: FnMut(f64) + 'static>(mut self, callback: F) -> Self {
+        self.progress = Progress::new(callback, self.polygons.len());
+        self
+    }
+}
+
+impl Iterator for FilledContourSeries {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let polygon = self
+            .polygons
+            .next()
+            .map(|(points, style)| Polygon::new(points, style));
+        self.progress.advance(polygon)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    fn bands(grid: &[Vec<f64>], levels: Vec<f64>) -> Vec<(Vec<(f64, f64)>, ShapeStyle)> {
+        FilledContourSeries::new(0.0..1.0, 0.0..1.0, grid, levels, &ViridisRGB)
+            .polygons
+            .collect()
+    }
+
+    fn area(points: &[(f64, f64)]) -> f64 {
+        let twice: f64 = (0..points.len())
+            .map(|k| {
+                let (p, q) = (points[k], points[(k + 1) % points.len()]);
+                p.0 * q.1 - q.0 * p.1
+            })
+            .sum();
+        twice.abs() / 2.0
+    }
+
+    #[test]
+    fn test_bands_cover_grid() {
+        // A bump with a saddle, sampled on an uneven grid
+        let grid: Vec<Vec<f64>> = (0..7)
+            .map(|j| {
+                (0..9)
+                    .map(|i| {
+                        let (x, y) = (i as f64 / 8.0, j as f64 / 6.0);
+                        (x * 6.0).sin() * (y * 5.0).cos() + x * y
+                    })
+                    .collect()
+            })
+            .collect();
+        let (min, max) = grid
+            .iter()
+            .flatten()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+                (min.min(v), max.max(v))
+            });
+        let levels: Vec<f64> = (0..=6)
+            .map(|k| min + (max - min) * k as f64 / 6.0)
+            .collect();
+
+        let polygons = bands(&grid, levels);
+        let total: f64 = polygons.iter().map(|(points, _)| area(points)).sum();
+        assert!((total - 1.0).abs() < 1e-9, "The bands cover {}", total);
+
+        // The points are in the grid
+        for (points, _) in &polygons {
+            assert!(points
+                .iter()
+                .all(|p| (0.0..=1.0).contains(&p.0) && (0.0..=1.0).contains(&p.1)));
+        }
+    }
+
+    #[test]
+    fn test_ramp_bands() {
+        // A ramp along the X axis: each band is a vertical strip
+        let grid = vec![vec![0.0, 1.0, 2.0, 3.0, 4.0]; 3];
+        let polygons = bands(&grid, vec![0.0, 1.0, 2.5, 4.0]);
+        let mut areas = [0.0; 3];
+        let colors: Vec<_> = [0.5 / 4.0, 1.75 / 4.0, 3.25 / 4.0]
+            .iter()
+            .map(|&v| ViridisRGB.get_color(v).to_rgba())
+            .collect();
+        for (points, style) in &polygons {
+            let band = colors.iter().position(|&c| c == style.color).unwrap();
+            areas[band] += area(points);
+        }
+        for (area, expected) in areas.iter().zip([0.25, 0.375, 0.375]) {
+            assert!((area - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_uniform_cells_merged() {
+        // The cells inside a single band are filled with a rectangle per row
+        let grid = vec![vec![0.0, 0.2, 0.4, 0.6]; 3];
+        let polygons = bands(&grid, vec![0.0, 1.0, 2.0]);
+        assert_eq!(polygons.len(), 2);
+        assert_eq!(
+            polygons[0].0,
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 0.5), (0.0, 0.5)]
+        );
+    }
+
+    #[test]
+    fn test_levels_outside_values() {
+        let grid = vec![vec![0.0, 1.0], vec![1.0, 2.0]];
+        // The values out of the levels are not filled
+        let total: f64 = bands(&grid, vec![0.5, 1.5])
+            .iter()
+            .map(|(points, _)| area(points))
+            .sum();
+        assert!(total > 0.0 && total < 1.0);
+        assert!(bands(&grid, vec![3.0, 4.0]).is_empty());
+        assert!(bands(&grid, vec![1.0]).is_empty());
+        // A NaN corner leaves its cell empty
+        assert!(bands(&[vec![0.0, f64::NAN], vec![1.0, 2.0]], vec![0.0, 2.0]).is_empty());
+    }
+
+    #[test]
+    fn test_draw_filled_contour() {
+        let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| assert!(b.num_fill_polygon_call > 0));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..1.0)
+            .unwrap();
+        let grid = vec![vec![0.0, 1.0], vec![1.0, 0.0]];
+        chart
+            .draw_series(FilledContourSeries::new(
+                0.0..1.0,
+                0.0..1.0,
+                &grid,
+                vec![0.0, 0.5, 1.0],
+                &ViridisRGB,
+            ))
+            .unwrap();
+    }
+}
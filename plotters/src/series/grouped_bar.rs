@@ -0,0 +1,293 @@
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{DiscreteRanged, Ranged};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Drawable, PointCollection, Rectangle};
+use crate::style::{Color, Palette, Palette99, ShapeStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+A grouped (clustered) bar chart: each category of a discrete X axis gets a group of bars, one
+per sub-series, laid out side by side within the slot of the category.
+
+The data gives the values of the sub-series for each category, in the order of the
+sub-series, and each sub-series is declared with [`GroupedBarSeries::sub_series`], which sets
+its label and its style. [`GroupedBarSeries::draw`] draws the sub-series one after the other,
+thus each of them gets its own legend entry. The sub-series which aren't declared are drawn in
+the colors of [`Palette99`], without legend entry.
+
+The groups are separated by [`GroupedBarSeries::group_margin`], and the bars of a group by
+[`GroupedBarSeries::bar_spacing`], both in pixels. Like [`crate::series::Histogram`], the X axis
+is usually made of the categories turned into a segmented coordinate, so that each group is
+centered on its category.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("grouped_bar_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_builder = ChartBuilder::on(&drawing_area);
+chart_builder.margin(5).set_left_and_bottom_label_area_size(20);
+let mut chart_context = chart_builder
+    .build_cartesian_2d((0..2).into_segmented(), 0..10)
+    .unwrap();
+chart_context.configure_mesh().draw().unwrap();
+GroupedBarSeries::new(vec![(0, vec![3, 5]), (1, vec![6, 4]), (2, vec![8, 9])])
+    .sub_series("2022", RED.filled())
+    .sub_series("2023", BLUE.filled())
+    .group_margin(10)
+    .bar_spacing(2)
+    .draw(&mut chart_context)
+    .unwrap();
+chart_context.configure_series_labels().draw().unwrap();
+```
+*/
+pub struct GroupedBarSeries<X, Y> {
+    data: Vec<(X, Vec<Y>)>,
+    sub_series: Vec<(String, ShapeStyle)>,
+    group_margin: u32,
+    bar_spacing: u32,
+    baseline: Option<Y>,
+}
+
+impl<X: Clone, Y: Clone> GroupedBarSeries<X, Y> {
+    /**
+    Creates a new grouped bar series.
+
+    - `data`: The categories, each with the values of the sub-series
+    - **returns** The newly created series
+
+    See [`GroupedBarSeries`] for more information and examples.
+    */
+    pub fn new<I: IntoIterator<Item = (X, Vec<Y>)>>(data: I) -> Self {
+        Self {
+            data: data.into_iter().collect(),
+            sub_series: vec![],
+            group_margin: 10,
+            bar_spacing: 2,
+            baseline: None,
+        }
+    }
+
+    /**
+    Declares the next sub-series, which gets a legend entry with its label.
+
+    See [`GroupedBarSeries`] for more information and examples.
+    */
+    pub fn sub_series<L: Into<String>, S: Into<ShapeStyle>>(mut self, label: L, style: S) -> Self {
+        self.sub_series.push((label.into(), style.into()));
+        self
+    }
+
+    /**
+    Sets the space between two groups of bars, in pixels, which is split on both sides of each
+    group. The default is 10.
+
+    See [`GroupedBarSeries`] for more information and examples.
+    */
+    pub fn group_margin(mut self, margin: u32) -> Self {
+        self.group_margin = margin;
+        self
+    }
+
+    /**
+    Sets the space between two bars of a group, in pixels. The default is 2.
+
+    See [`GroupedBarSeries`] for more information and examples.
+    */
+    pub fn bar_spacing(mut self, spacing: u32) -> Self {
+        self.bar_spacing = spacing;
+        self
+    }
+
+    /**
+    Sets the value the bars start from, which is the bottom of the Y axis by default.
+
+    See [`GroupedBarSeries`] for more information and examples.
+    */
+    pub fn baseline(mut self, baseline: Y) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /**
+    Draws the bars on a chart, one series per sub-series.
+
+    - `chart`: The chart, whose X axis is a discrete coordinate holding the categories
+    - **returns** An error if the bars couldn't be drawn
+
+    See [`GroupedBarSeries`] for more information and examples.
+    */
+    pub fn draw<'a, DB, XR, YR>(
+        self,
+        chart: &mut ChartContext<'a, DB, Cartesian2d<XR, YR>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DB: DrawingBackend + 'a,
+        XR: DiscreteRanged,
+        X: Into<XR::ValueType>,
+        YR: Ranged<ValueType = Y>,
+    {
+        let count = self
+            .data
+            .iter()
+            .map(|(_, values)| values.len())
+            .max()
+            .unwrap_or(0)
+            .max(self.sub_series.len());
+        let baseline = match &self.baseline {
+            Some(baseline) => baseline.clone(),
+            None => chart.as_coord_spec().y_spec().range().start,
+        };
+
+        for idx in 0..count {
+            let (label, style) = match self.sub_series.get(idx) {
+                Some((label, style)) => (Some(label), *style),
+                None => (None, Palette99::pick(idx).filled()),
+            };
+            // Each bar spans the slot of its category, from its value to the next one of the axis
+            let bars: Vec<_> = {
+                let xr = chart.as_coord_spec().x_spec();
+                self.data
+                    .iter()
+                    .filter_map(|(x, values)| {
+                        let value = values.get(idx)?;
+                        let x_idx = xr.index_of(&x.clone().into())?;
+                        let (x, nx) = (xr.from_index(x_idx)?, xr.from_index(x_idx + 1)?);
+                        Some(GroupedBar {
+                            rect: Rectangle::new(
+                                [(x, value.clone()), (nx, baseline.clone())],
+                                style,
+                            ),
+                            index: idx,
+                            count,
+                            group_margin: self.group_margin,
+                            bar_spacing: self.bar_spacing,
+                        })
+                    })
+                    .collect()
+            };
+            let anno = chart.draw_series(bars)?;
+            if let Some(label) = label {
+                let legend_style = ShapeStyle {
+                    filled: true,
+                    ..style
+                };
+                anno.label(label.as_str()).legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 5), (x + 10, y + 5)], legend_style)
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The horizontal span of a bar within the slot of its group, in pixels
+pub(super) fn bar_span(
+    (left, right): (i32, i32),
+    index: usize,
+    count: usize,
+    group_margin: u32,
+    bar_spacing: u32,
+) -> (i32, i32) {
+    let (left, right) = (left.min(right), left.max(right));
+    let margin = f64::from(group_margin) / 2.0;
+    let spacing = f64::from(bar_spacing);
+    let group = (f64::from(right - left) - 2.0 * margin).max(0.0);
+    let width = ((group - spacing * (count - 1) as f64) / count as f64).max(1.0);
+    let start = f64::from(left) + margin + (width + spacing) * index as f64;
+    (start.round() as i32, (start + width).round() as i32)
+}
+
+// A bar of a group: the rectangle spans the whole slot of the group, and the bar is drawn in
+// its part of it
+pub(super) struct GroupedBar<Coord> {
+    pub(super) rect: Rectangle<Coord>,
+    pub(super) index: usize,
+    pub(super) count: usize,
+    pub(super) group_margin: u32,
+    pub(super) bar_spacing: u32,
+}
+
+impl<'b, Coord> PointCollection<'b, Coord> for &'b GroupedBar<Coord> {
+    type Point = &'b Coord;
+    type IntoIter = &'b [Coord];
+    fn point_iter(self) -> &'b [Coord] {
+        self.rect.point_iter()
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for GroupedBar<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (end, base) = match (points.next(), points.next()) {
+            (Some(end), Some(base)) => (end, base),
+            _ => return Ok(()),
+        };
+        let (left, right) = bar_span(
+            (end.0, base.0),
+            self.index,
+            self.count,
+            self.group_margin,
+            self.bar_spacing,
+        );
+        self.rect.draw(
+            [(left, end.1), (right, base.1)].iter().copied(),
+            backend,
+            parent_dim,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bar_span() {
+        // A 100 pixels slot, 10 pixels of margin split on both sides, 4 pixels between bars
+        let spans: Vec<_> = (0..3).map(|i| bar_span((0, 100), i, 3, 10, 4)).collect();
+        assert_eq!(spans, vec![(5, 32), (36, 64), (68, 95)]);
+        assert_eq!(bar_span((100, 0), 0, 1, 10, 4), (5, 95));
+        // The bars are at least one pixel wide
+        assert_eq!(bar_span((0, 10), 1, 4, 10, 4), (10, 11));
+    }
+
+    #[test]
+    fn test_grouped_bars() {
+        let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|c, _, filled, u, d| {
+                assert!(filled);
+                // The red bars are on the left of their group, the blue ones on the right
+                if c == RED.to_rgba() {
+                    assert!(u.0 % 50 < 25 && d.0 % 50 <= 25);
+                } else {
+                    assert_eq!(c, BLUE.to_rgba());
+                    assert!(u.0 % 50 >= 25);
+                }
+                assert_eq!(d.1, 99);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..1).into_segmented(), 0..10)
+            .expect("Build chart error");
+
+        // The second category has no blue bar
+        GroupedBarSeries::new(vec![(0, vec![3, 5]), (1, vec![6])])
+            .sub_series("red", RED.filled())
+            .sub_series("blue", BLUE.filled())
+            .draw(&mut chart)
+            .expect("Drawing Error");
+        assert_eq!(chart.series_anno.len(), 2);
+    }
+}
@@ -0,0 +1,319 @@
+use std::ops::Range;
+
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::DiscreteRanged;
+use crate::element::{Drawable, PointCollection};
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, ShapeStyle, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+Presents a 2D matrix of values as a grid of colored cells.
+
+Each cell of the matrix is drawn as a filled rectangle whose color is picked from a colormap,
+and the numeric value of the cell can optionally be written at its center.
+
+The heatmap is drawn on a chart whose both axes are discrete coordinates: the column `c` of
+the matrix spans the values from index `c` to index `c + 1` of the X axis, and the row `r`
+spans the values from index `r` to index `r + 1` of the Y axis, which puts the first row at
+the bottom of the chart. Segmented coordinates, created with
+[`crate::coord::ranged1d::IntoSegmentedCoord::into_segmented()`], provide exactly one segment
+per cell and center the axis labels on the cells. The cells falling outside of the
+coordinates, as well as the cells whose value is NaN, are not drawn.
+
+# Example
+
+```
+use plotters::prelude::*;
+let matrix = [[1.0, 0.2, -0.4], [0.2, 1.0, 0.7], [-0.4, 0.7, 1.0]];
+let drawing_area = SVGBackend::new("heatmap.svg", (300, 300)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d((0..2).into_segmented(), (0..2).into_segmented())
+    .unwrap();
+chart.configure_mesh().disable_mesh().draw().unwrap();
+chart
+    .draw_series(
+        Heatmap::new(&chart, &matrix, -1.0..1.0, &ViridisRGB)
+            .values(("sans-serif", 15).into_font().color(&WHITE)),
+    )
+    .unwrap();
+```
+*/
+pub struct Heatmap<'a, X, Y> {
+    cells: std::vec::IntoIter<([(X, Y); 2], f64, ShapeStyle)>,
+    value_style: Option<TextStyle<'a>>,
+    value_formatter: Box<dyn Fn(f64) -> String + 'a>,
+}
+
+impl<'a, X, Y> Heatmap<'a, X, Y> {
+    /**
+    Creates a heatmap of a matrix.
+
+    - `parent`: The chart the heatmap is drawn on, both of its axes must be discrete
+    - `matrix`: The rows of the matrix
+    - `range`: The range of values spanned by the colormap, the values outside of it take the
+      color of its closest bound
+    - `colormap`: The colormap picking the color of each cell
+    - **returns** The newly created heatmap
+
+    See [`Heatmap`] for more information and examples.
+    */
+    pub fn new<DB, XR, YR, R, C, M>(
+        parent: &ChartContext<DB, Cartesian2d<XR, YR>>,
+        matrix: &[R],
+        range: Range<f64>,
+        colormap: &M,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        XR: DiscreteRanged<ValueType = X>,
+        YR: DiscreteRanged<ValueType = Y>,
+        Y: Clone,
+        R: AsRef<[f64]>,
+        C: Color,
+        M: ColorMap<C, f64>,
+    {
+        Self::build(parent, matrix, range, colormap, false)
+    }
+
+    /**
+    Creates a heatmap of a matrix whose values span several orders of magnitude: the colors
+    are picked from the colormap by the logarithm of the values, to match a log scaled
+    colorbar, see [`crate::element::ColorBar::log_scale()`]. The cells whose value isn't
+    positive are not drawn.
+
+    - `parent`: The chart the heatmap is drawn on, both of its axes must be discrete
+    - `matrix`: The rows of the matrix
+    - `range`: The positive range of values spanned by the colormap, the values outside of it
+      take the color of its closest bound
+    - `colormap`: The colormap picking the color of each cell
+    - **returns** The newly created heatmap
+
+    See [`Heatmap`] for more information and examples.
+    */
+    pub fn new_log_scale<DB, XR, YR, R, C, M>(
+        parent: &ChartContext<DB, Cartesian2d<XR, YR>>,
+        matrix: &[R],
+        range: Range<f64>,
+        colormap: &M,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        XR: DiscreteRanged<ValueType = X>,
+        YR: DiscreteRanged<ValueType = Y>,
+        Y: Clone,
+        R: AsRef<[f64]>,
+        C: Color,
+        M: ColorMap<C, f64>,
+    {
+        Self::build(parent, matrix, range, colormap, true)
+    }
+
+    fn build<DB, XR, YR, R, C, M>(
+        parent: &ChartContext<DB, Cartesian2d<XR, YR>>,
+        matrix: &[R],
+        range: Range<f64>,
+        colormap: &M,
+        log_scale: bool,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+        XR: DiscreteRanged<ValueType = X>,
+        YR: DiscreteRanged<ValueType = Y>,
+        Y: Clone,
+        R: AsRef<[f64]>,
+        C: Color,
+        M: ColorMap<C, f64>,
+    {
+        let coord = parent.as_coord_spec();
+        let (x_spec, y_spec) = (coord.x_spec(), coord.y_spec());
+        let scaled = |value: f64| if log_scale { value.ln() } else { value };
+        let mut cells = vec![];
+        for (r, row) in matrix.iter().enumerate() {
+            let (top, bottom) = match (y_spec.from_index(r), y_spec.from_index(r + 1)) {
+                (Some(top), Some(bottom)) => (top, bottom),
+                _ => break,
+            };
+            for (c, value) in row.as_ref().iter().enumerate() {
+                let (left, right) = match (x_spec.from_index(c), x_spec.from_index(c + 1)) {
+                    (Some(left), Some(right)) => (left, right),
+                    _ => break,
+                };
+                if value.is_nan() || (log_scale && *value <= 0.0) {
+                    continue;
+                }
+                let style = colormap
+                    .get_color_normalized(scaled(*value), scaled(range.start), scaled(range.end))
+                    .filled();
+                cells.push((
+                    [(left, top.clone()), (right, bottom.clone())],
+                    *value,
+                    style,
+                ));
+            }
+        }
+        Self {
+            cells: cells.into_iter(),
+            value_style: None,
+            value_formatter: Box::new(|value| format!("{:.2}", value)),
+        }
+    }
+
+    /**
+    Writes the value of each cell at its center.
+
+    - `style`: The style of the values, which are always centered on their cell
+    - **returns** The updated heatmap
+    */
+    pub fn values<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        let style = style.into();
+        self.value_style = Some(style.pos(Pos::new(HPos::Center, VPos::Center)));
+        self
+    }
+
+    /**
+    Sets how the values written on the cells are formatted, by default with two decimals.
+
+    This has no effect unless the values are written with [`Heatmap::values()`].
+    */
+    pub fn value_formatter(mut self, formatter: impl Fn(f64) -> String + 'a) -> Self {
+        self.value_formatter = Box::new(formatter);
+        self
+    }
+}
+
+impl<'a, X, Y> Iterator for Heatmap<'a, X, Y> {
+    type Item = HeatmapCell<'a, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (points, value, style) = self.cells.next()?;
+        let value = self
+            .value_style
+            .as_ref()
+            .map(|text_style| ((self.value_formatter)(value), text_style.clone()));
+        Some(HeatmapCell {
+            points,
+            style,
+            value,
+        })
+    }
+}
+
+/// A single cell of a [`Heatmap`]: a filled rectangle, optionally with its value at its center
+pub struct HeatmapCell<'a, Coord> {
+    points: [Coord; 2],
+    style: ShapeStyle,
+    value: Option<(String, TextStyle<'a>)>,
+}
+
+impl<'a, 'b, Coord> PointCollection<'b, Coord> for &'b HeatmapCell<'a, Coord> {
+    type Point = &'b Coord;
+    type IntoIter = &'b [Coord];
+    fn point_iter(self) -> &'b [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for HeatmapCell<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let (Some(a), Some(b)) = (points.next(), points.next()) {
+            let (a, b) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+            backend.draw_rect(a, b, &self.style, true)?;
+            if let Some((text, style)) = &self.value {
+                backend.draw_text(text, style, ((a.0 + b.0) / 2, (a.1 + b.1) / 2))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_heatmap_cells() {
+        let drawing_area = crate::create_mocked_drawing_area(200, 100, |m| {
+            let mut cells = vec![];
+            m.check_draw_rect(move |c, _, filled, u, d| {
+                assert!(filled);
+                cells.push((u, d, c));
+                if cells.len() == 5 {
+                    assert_eq!(
+                        cells,
+                        vec![
+                            ((0, 50), (49, 99), BLACK.to_rgba()),
+                            ((99, 50), (149, 99), WHITE.to_rgba()),
+                            ((149, 50), (199, 99), WHITE.to_rgba()),
+                            ((0, 0), (49, 50), RGBColor(128, 128, 128).to_rgba()),
+                            ((49, 0), (99, 50), WHITE.to_rgba()),
+                        ]
+                    );
+                }
+            });
+            let mut texts = vec![];
+            m.check_draw_text(move |_, _, _, pos, text| {
+                texts.push((pos, text.to_string()));
+                if texts.len() == 5 {
+                    let texts: Vec<_> = texts.iter().map(|(_, text)| text.as_str()).collect();
+                    assert_eq!(texts, vec!["-1", "5", "2", "0", "1"]);
+                }
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 5);
+                assert_eq!(b.num_draw_text_call, 5);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..3).into_segmented(), (0..1).into_segmented())
+            .unwrap();
+        let colormap = DerivedColorMap::new(&[BLACK, WHITE]);
+        // The NaN cell and the cells outside of the coordinate are skipped
+        let matrix = vec![
+            vec![-1.0, f64::NAN, 5.0, 2.0, 3.0],
+            vec![0.0, 1.0],
+            vec![0.0],
+        ];
+        chart
+            .draw_series(
+                Heatmap::new(&chart, &matrix, -1.0..1.0, &colormap)
+                    .values(("sans-serif", 10))
+                    .value_formatter(|v| format!("{}", v)),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_log_scale_heatmap() {
+        let drawing_area = crate::create_mocked_drawing_area(300, 100, |m| {
+            let mut colors = vec![BLACK.to_rgba(), RGBColor(128, 128, 128).to_rgba()];
+            colors.push(WHITE.to_rgba());
+            m.check_draw_rect(move |c, _, _, _, _| assert_eq!(c, colors.remove(0)));
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 3));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..3).into_segmented(), (0..0).into_segmented())
+            .unwrap();
+        let colormap = DerivedColorMap::new(&[BLACK, WHITE]);
+        // The colors are picked by orders of magnitude, and the zero cell is skipped
+        let matrix = [[1.0, 10.0, 0.0, 100.0]];
+        chart
+            .draw_series(Heatmap::new_log_scale(
+                &chart,
+                &matrix,
+                1.0..100.0,
+                &colormap,
+            ))
+            .unwrap();
+    }
+}
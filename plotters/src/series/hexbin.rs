@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::element::Polygon;
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::{Color, ShapeStyle};
+
+/**
+Presents the density of a large number of points, by counting them into hexagonal cells.
+
+The plane is tiled with hexagons whose tops are pointy, laid out in rows: the centers of the
+hexagons of a row are `width` apart, the rows are `height` apart, and every other row is
+shifted by half a hexagon. Each point falls into the hexagon it is the closest to the center of,
+and each hexagon holding at least one point is filled with the color the colormap gives to its
+count, from the lowest count to the highest one. The empty hexagons are not drawn, nor are the
+points whose coordinates are NaN.
+
+Both sizes are in the units of the chart coordinates. The hexagons are regular on screen when
+the height is about 0.866 times the width, once converted to pixels.
+
+# Example
+
+```
+use plotters::prelude::*;
+let points = (0..1000).map(|i| {
+    let t = i as f64 / 1000.0;
+    (t, (t * 20.0).sin() * t)
+});
+let drawing_area = SVGBackend::new("hexbin.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .build_cartesian_2d(0.0..1.0, -1.0..1.0)
+    .unwrap();
+chart
+    .draw_series(HexBin::new(points, (0.05, 0.1), &ViridisRGB))
+    .unwrap();
+```
+*/
+pub struct HexBin {
+    cells: std::vec::IntoIter<(Vec<(f64, f64)>, ShapeStyle)>,
+    count_range: Range<usize>,
+}
+
+impl HexBin {
+    /**
+    Counts the points into hexagonal cells.
+
+    - `points`: The iterator of the `(x, y)` points
+    - `size`: The width and the height between the centers of the hexagons, see [`HexBin`]
+    - `colormap`: The colormap picking the color of each cell from its count
+    - **returns** The newly created series
+
+    See [`HexBin`] for more information and examples.
+    */
+    pub fn new<X, Y, I, C, M>(points: I, size: (f64, f64), colormap: &M) -> Self
+    where
+        X: Into<f64>,
+        Y: Into<f64>,
+        I: IntoIterator<Item = (X, Y)>,
+        C: Color,
+        M: ColorMap<C, f64>,
+    {
+        let (width, height) = size;
+        let mut counts: HashMap<(i64, i64), usize> = HashMap::new();
+        if width > 0.0 && height > 0.0 {
+            for (x, y) in points {
+                let (x, y) = (x.into(), y.into());
+                if x.is_nan() || y.is_nan() {
+                    continue;
+                }
+                *counts.entry(hexagon_of(x / width, y / height)).or_default() += 1;
+            }
+        }
+
+        let (min, max) = counts.values().fold((usize::MAX, 0), |(min, max), &count| {
+            (min.min(count), max.max(count))
+        });
+        let mut cells: Vec<_> = counts.into_iter().collect();
+        cells.sort_unstable();
+
+        let cells: Vec<_> = cells
+            .into_iter()
+            .map(|((col, row), count)| {
+                let style = if min < max {
+                    colormap.get_color_normalized(count as f64, min as f64, max as f64)
+                } else {
+                    colormap.get_color(1.0)
+                };
+                (hexagon_points((col, row), size), style.filled())
+            })
+            .collect();
+
+        Self {
+            cells: cells.into_iter(),
+            count_range: if min <= max { min..max } else { 0..0 },
+        }
+    }
+
+    /**
+    Gets the lowest and the highest counts of the cells, which span the colormap. This is the
+    range to give to a colorbar, see [`crate::chart::ChartContext::configure_colorbar()`].
+    */
+    pub fn count_range(&self) -> Range<usize> {
+        self.count_range.clone()
+    }
+}
+
+// The center of the hexagon `(col, row)` is at `col + row / 2` along the row, in units of the
+// width, thus the odd rows are shifted by half a hexagon
+fn hexagon_center((col, row): (i64, i64)) -> (f64, f64) {
+    (col as f64 + (row & 1) as f64 / 2.0, row as f64)
+}
+
+// Finds the hexagon of a point, given in units of the width and of the height. The closest
+// center is either on the closest even row or on the closest odd row, and the distance is
+// measured after scaling the rows to make the hexagons regular.
+fn hexagon_of(u: f64, v: f64) -> (i64, i64) {
+    let row_scale = 3f64.sqrt() / 2.0;
+    let even_row = (v / 2.0).round() as i64 * 2;
+    let odd_row = (v / 2.0).floor() as i64 * 2 + 1;
+    let candidates = [
+        (u.round() as i64, even_row),
+        ((u - 0.5).round() as i64, odd_row),
+    ];
+    let distance = |hexagon: &(i64, i64)| {
+        let (cu, cv) = hexagon_center(*hexagon);
+        (u - cu).powi(2) + ((v - cv) * row_scale).powi(2)
+    };
+    if distance(&candidates[0]) <= distance(&candidates[1]) {
+        candidates[0]
+    } else {
+        candidates[1]
+    }
+}
+
+fn hexagon_points(hexagon: (i64, i64), (width, height): (f64, f64)) -> Vec<(f64, f64)> {
+    let (cu, cv) = hexagon_center(hexagon);
+    let (cx, cy) = (cu * width, cv * height);
+    let (dx, dy) = (width / 2.0, height / 3.0);
+    vec![
+        (cx, cy + 2.0 * dy),
+        (cx + dx, cy + dy),
+        (cx + dx, cy - dy),
+        (cx, cy - 2.0 * dy),
+        (cx - dx, cy - dy),
+        (cx - dx, cy + dy),
+    ]
+}
+
+impl Iterator for HexBin {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (points, style) = self.cells.next()?;
+        Some(Polygon::new(points, style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_hexagon_of() {
+        // The centers of the hexagons are in their own hexagon
+        assert_eq!(hexagon_of(0.0, 0.0), (0, 0));
+        assert_eq!(hexagon_of(3.0, 2.0), (3, 2));
+        assert_eq!(hexagon_of(0.5, 1.0), (0, 1));
+        assert_eq!(hexagon_of(-1.5, -1.0), (-2, -1));
+        // Close to the top of a hexagon, but still in it
+        assert_eq!(hexagon_of(0.0, 0.6), (0, 0));
+        // Between two hexagons of a row, and just above, in the next row
+        assert_eq!(hexagon_of(0.45, 0.2), (0, 0));
+        assert_eq!(hexagon_of(0.5, 0.4), (0, 1));
+    }
+
+    #[test]
+    fn test_hexbin_counts() {
+        let colormap = DerivedColorMap::new(&[BLACK, WHITE]);
+        let drawing_area = crate::create_mocked_drawing_area(200, 200, |m| {
+            // The cell holding one point is black, the one holding three points is white
+            let mut colors = vec![BLACK, WHITE];
+            m.check_fill_polygon(move |c, points| {
+                assert_eq!(c, colors.remove(0).to_rgba());
+                assert_eq!(points.len(), 6);
+            });
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 2));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-1.0..10.0, -1.0..10.0)
+            .expect("Build chart error");
+
+        let points = [
+            (0.1, 0.1),
+            (4.0, 4.1),
+            (3.9, 4.0),
+            (4.1, 3.9),
+            (f64::NAN, 4.0),
+        ];
+        let hexbin = HexBin::new(points, (2.0, 2.0), &colormap);
+        assert_eq!(hexbin.count_range(), 1..3);
+        chart.draw_series(hexbin).expect("Drawing Error");
+    }
+}
@@ -1,13 +1,14 @@
 use std::collections::{hash_map::IntoIter as HashMapIter, HashMap};
 use std::marker::PhantomData;
-use std::ops::AddAssign;
+use std::ops::{AddAssign, Range};
 
 use crate::chart::ChartContext;
 use crate::coord::cartesian::Cartesian2d;
 use crate::coord::ranged1d::{DiscreteRanged, Ranged};
-use crate::element::Rectangle;
-use crate::style::{Color, ShapeStyle, GREEN};
-use plotters_backend::DrawingBackend;
+use crate::element::{Drawable, PointCollection, Rectangle};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, ShapeStyle, TextStyle, BLACK, GREEN};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
 pub trait HistogramType {}
 pub struct Vertical;
@@ -76,6 +77,15 @@ to the right of `.margin(10)`. The lower portion of the bars are removed; the re
 to the right:
 
 ![](https://cdn.jsdelivr.net/gh/facorread/plotters-doc-data@a617d37/apidoc/histogram_baseline_func.svg)
+
+[`Histogram::bars()`] turns the histogram into [`HistogramBars`], which can write the value of each
+bar beyond its end, or inside of the bar when the label wouldn't fit in the chart. Let's add
+`.bars().with_labels(|count: &i32| count.to_string())` to the right of `.data(...)` to print the
+counts above the bars.
+
+[`Histogram::style_by_sign()`] colors the bars by their side of the baseline, e.g.
+`.style_by_sign(GREEN.filled(), RED.filled())` for profits and losses. The baseline is drawn
+across the bars of [`Histogram::bars()`], with the style set by [`HistogramBars::baseline_style()`].
 */
 pub struct Histogram<'a, BR, A, Tag = Vertical>
 where
@@ -84,9 +94,11 @@ where
     Tag: HistogramType,
 {
     style: Box<dyn Fn(&BR::ValueType, &A) -> ShapeStyle + 'a>,
+    sign_style: Option<Box<dyn Fn(&A, &A) -> ShapeStyle + 'a>>,
     margin: u32,
     iter: HashMapIter<usize, A>,
     baseline: Box<dyn Fn(&BR::ValueType) -> A + 'a>,
+    value_range: Range<i32>,
     br: BR,
     _p: PhantomData<Tag>,
 }
@@ -97,12 +109,14 @@ where
     A: AddAssign<A> + Default + 'a,
     Tag: HistogramType,
 {
-    fn empty(br: &BR) -> Self {
+    fn empty(br: &BR, value_range: Range<i32>) -> Self {
         Self {
             style: Box::new(|_, _| GREEN.filled()),
+            sign_style: None,
             margin: 5,
             iter: HashMap::new().into_iter(),
             baseline: Box::new(|_| A::default()),
+            value_range,
             br: br.clone(),
             _p: PhantomData,
         }
@@ -133,6 +147,37 @@ where
         self
     }
 
+    /**
+    Styles the bars by their side of the baseline: the bars reaching the baseline or beyond it
+    get the positive style, and the bars below it get the negative one. This overrides
+    [`Histogram::style()`] and [`Histogram::style_func()`].
+
+    The baseline itself is drawn across the bars of [`Histogram::bars()`], in black unless
+    another style is given with [`HistogramBars::baseline_style()`].
+
+    See [`Histogram`] for more information and examples.
+    */
+    pub fn style_by_sign<P: Into<ShapeStyle>, N: Into<ShapeStyle>>(
+        mut self,
+        positive: P,
+        negative: N,
+    ) -> Self
+    where
+        A: PartialOrd,
+    {
+        let (positive, negative) = (positive.into(), negative.into());
+        self.sign_style = Some(Box::new(
+            move |value, base| {
+                if value >= base {
+                    positive
+                } else {
+                    negative
+                }
+            },
+        ));
+        self
+    }
+
     /**
     Sets the baseline of the histogram.
 
@@ -186,6 +231,22 @@ where
         self.iter = buffer.into_iter();
         self
     }
+
+    /**
+    Turns the histogram into an iterator of [`HistogramBar`] elements rather than plain
+    rectangles, which can be labeled with their values and crossed by their baseline.
+
+    See [`Histogram`] for more information and examples.
+    */
+    pub fn bars(self) -> HistogramBars<'a, BR, A, Tag> {
+        let baseline_style = self.sign_style.as_ref().map(|_| BLACK.into());
+        HistogramBars {
+            histogram: self,
+            label_formatter: None,
+            label_style: ("sans-serif", 12).into_font().into(),
+            baseline_style,
+        }
+    }
 }
 
 impl<'a, BR, A> Histogram<'a, BR, A, Vertical>
@@ -205,8 +266,9 @@ where
         ACoord: Ranged<ValueType = A>,
     {
         let dp = parent.as_coord_spec().x_spec();
+        let (_, value_range) = parent.plotting_area().get_pixel_range();
 
-        Self::empty(dp)
+        Self::empty(dp, value_range)
     }
 }
 
@@ -227,17 +289,21 @@ where
         ACoord: Ranged<ValueType = A>,
     {
         let dp = parent.as_coord_spec().y_spec();
-        Self::empty(dp)
+        let (value_range, _) = parent.plotting_area().get_pixel_range();
+        Self::empty(dp, value_range)
     }
 }
 
-impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Vertical>
+impl<'a, BR, A> Histogram<'a, BR, A, Vertical>
 where
     BR: DiscreteRanged,
     A: AddAssign<A> + Default,
 {
-    type Item = Rectangle<(BR::ValueType, A)>;
-    fn next(&mut self) -> Option<Self::Item> {
+    // The next bar along with its value formatted by the label formatter, if any
+    fn next_bar(
+        &mut self,
+        formatter: Option<&dyn Fn(&A) -> String>,
+    ) -> Option<(Rectangle<(BR::ValueType, A)>, Option<String>)> {
         while let Some((x, y)) = self.iter.next() {
             if let Some((x, Some(nx))) = self
                 .br
@@ -245,23 +311,41 @@ where
                 .map(|v| (v, self.br.from_index(x + 1)))
             {
                 let base = (self.baseline)(&x);
-                let style = (self.style)(&x, &y);
+                let style = match &self.sign_style {
+                    Some(sign_style) => sign_style(&y, &base),
+                    None => (self.style)(&x, &y),
+                };
+                let label = formatter.map(|formatter| formatter(&y));
                 let mut rect = Rectangle::new([(x, y), (nx, base)], style);
                 rect.set_margin(0, 0, self.margin, self.margin);
-                return Some(rect);
+                return Some((rect, label));
             }
         }
         None
     }
 }
 
-impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Horizontal>
+impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Vertical>
 where
     BR: DiscreteRanged,
     A: AddAssign<A> + Default,
 {
-    type Item = Rectangle<(A, BR::ValueType)>;
+    type Item = Rectangle<(BR::ValueType, A)>;
     fn next(&mut self) -> Option<Self::Item> {
+        self.next_bar(None).map(|(rect, _)| rect)
+    }
+}
+
+impl<'a, BR, A> Histogram<'a, BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default,
+{
+    // The next bar along with its value formatted by the label formatter, if any
+    fn next_bar(
+        &mut self,
+        formatter: Option<&dyn Fn(&A) -> String>,
+    ) -> Option<(Rectangle<(A, BR::ValueType)>, Option<String>)> {
         while let Some((y, x)) = self.iter.next() {
             if let Some((y, Some(ny))) = self
                 .br
@@ -269,12 +353,288 @@ where
                 .map(|v| (v, self.br.from_index(y + 1)))
             {
                 let base = (self.baseline)(&y);
-                let style = (self.style)(&y, &x);
+                let style = match &self.sign_style {
+                    Some(sign_style) => sign_style(&x, &base),
+                    None => (self.style)(&y, &x),
+                };
+                let label = formatter.map(|formatter| formatter(&x));
                 let mut rect = Rectangle::new([(x, y), (base, ny)], style);
                 rect.set_margin(self.margin, self.margin, 0, 0);
-                return Some(rect);
+                return Some((rect, label));
             }
         }
         None
     }
 }
+
+impl<'a, BR, A> Iterator for Histogram<'a, BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default,
+{
+    type Item = Rectangle<(A, BR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_bar(None).map(|(rect, _)| rect)
+    }
+}
+
+/**
+The bars of a [`Histogram`], created with [`Histogram::bars()`]. Each bar may be labeled with its
+value and crossed by its baseline.
+
+See [`Histogram`] for more information and examples.
+*/
+pub struct HistogramBars<'a, BR, A, Tag = Vertical>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default,
+    Tag: HistogramType,
+{
+    histogram: Histogram<'a, BR, A, Tag>,
+    label_formatter: Option<Box<dyn Fn(&A) -> String + 'a>>,
+    label_style: TextStyle<'a>,
+    baseline_style: Option<ShapeStyle>,
+}
+
+impl<'a, BR, A, Tag> HistogramBars<'a, BR, A, Tag>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default,
+    Tag: HistogramType,
+{
+    /**
+    Writes the value of each bar beyond its end, which is above the vertical bars with positive
+    values. The label is written inside of the bar instead when it would go out of the chart.
+
+    - `formatter`: The function formatting the value of a bar, which is the accumulated value
+      rather than its height above the baseline
+    - **returns** The updated histogram bars
+
+    See [`Histogram`] for more information and examples.
+    */
+    pub fn with_labels(mut self, formatter: impl Fn(&A) -> String + 'a) -> Self {
+        self.label_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /**
+    Sets the style of the labels of the bars, which are 12 pixels high sans-serif by default.
+    The labels are always centered on their bar.
+
+    This has no effect unless the labels are enabled with [`HistogramBars::with_labels()`].
+    */
+    pub fn label_style<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /**
+    Draws the baseline across the bars with the given style. The baseline is drawn in black
+    by default when the histogram is styled with [`Histogram::style_by_sign()`].
+
+    See [`Histogram`] for more information and examples.
+    */
+    pub fn baseline_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.baseline_style = Some(style.into());
+        self
+    }
+
+    fn make_bar<Coord>(
+        &self,
+        rect: Rectangle<Coord>,
+        label: Option<String>,
+        horizontal: bool,
+    ) -> HistogramBar<'a, Coord> {
+        HistogramBar {
+            rect,
+            label: label.map(|text| (text, self.label_style.clone())),
+            value_range: self.histogram.value_range.clone(),
+            baseline_style: self.baseline_style,
+            horizontal,
+        }
+    }
+}
+
+impl<'a, BR, A> Iterator for HistogramBars<'a, BR, A, Vertical>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default,
+{
+    type Item = HistogramBar<'a, (BR::ValueType, A)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (rect, label) = self.histogram.next_bar(self.label_formatter.as_deref())?;
+        Some(self.make_bar(rect, label, false))
+    }
+}
+
+impl<'a, BR, A> Iterator for HistogramBars<'a, BR, A, Horizontal>
+where
+    BR: DiscreteRanged,
+    A: AddAssign<A> + Default,
+{
+    type Item = HistogramBar<'a, (A, BR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (rect, label) = self.histogram.next_bar(self.label_formatter.as_deref())?;
+        Some(self.make_bar(rect, label, true))
+    }
+}
+
+/// The gap in pixels between the end of a [`HistogramBar`] and its label
+const LABEL_GAP: i32 = 3;
+
+/// A single bar of [`HistogramBars`]: a rectangle, optionally labeled with its value and crossed
+/// by its baseline
+pub struct HistogramBar<'a, Coord> {
+    rect: Rectangle<Coord>,
+    label: Option<(String, TextStyle<'a>)>,
+    value_range: Range<i32>,
+    baseline_style: Option<ShapeStyle>,
+    horizontal: bool,
+}
+
+impl<'a, 'b, Coord> PointCollection<'b, Coord> for &'b HistogramBar<'a, Coord> {
+    type Point = &'b Coord;
+    type IntoIter = &'b [Coord];
+    fn point_iter(self) -> &'b [Coord] {
+        self.rect.point_iter()
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for HistogramBar<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        // The first point is at the end of the bar, and the second one at its baseline
+        let (end, base) = match (points.next(), points.next()) {
+            (Some(end), Some(base)) => (end, base),
+            _ => return Ok(()),
+        };
+        self.rect
+            .draw([end, base].iter().copied(), backend, parent_dim)?;
+
+        // The baseline spans the whole slot of the bar, margins included, so that the segments
+        // of the adjacent bars join
+        if let Some(style) = &self.baseline_style {
+            let from = if self.horizontal {
+                (base.0, end.1)
+            } else {
+                (end.0, base.1)
+            };
+            backend.draw_line(from, base, style)?;
+        }
+
+        let (text, style) = match &self.label {
+            Some(label) => label,
+            None => return Ok(()),
+        };
+        let (w, h) = backend.estimate_text_size(text, style)?;
+        let (end, base, size, center) = if self.horizontal {
+            (end.0, base.0, w as i32, (end.1 + base.1) / 2)
+        } else {
+            (end.1, base.1, h as i32, (end.0 + base.0) / 2)
+        };
+
+        // Put the label beyond the end of the bar, unless it goes out of the chart
+        let (outside, inside) = if end < base {
+            (end - LABEL_GAP - size, end + LABEL_GAP)
+        } else {
+            (end + LABEL_GAP, end - LABEL_GAP - size)
+        };
+        let start = if outside >= self.value_range.start && outside + size <= self.value_range.end {
+            outside
+        } else {
+            inside
+        };
+
+        if self.horizontal {
+            let style = style.pos(Pos::new(HPos::Left, VPos::Center));
+            backend.draw_text(text, &style, (start, center))
+        } else {
+            let style = style.pos(Pos::new(HPos::Center, VPos::Top));
+            backend.draw_text(text, &style, (center, start))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_histogram_labels() {
+        let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+            let mut labels = vec![];
+            m.check_draw_text(move |_, _, _, pos, text| {
+                labels.push((text.to_string(), pos));
+                labels.sort();
+                if labels.len() == 2 {
+                    // The label of the full height bar doesn't fit above it, so it's inside
+                    assert_eq!(labels[0].0, "10");
+                    assert!(labels[0].1 .1 > 0);
+                    assert_eq!(labels[1].0, "5");
+                    assert!(labels[1].1 .1 < 50);
+                }
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_text_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..1).into_segmented(), 0..10)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .data([(0, 10), (1, 5)])
+                    .bars()
+                    .with_labels(|value: &i32| value.to_string()),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_histogram_style_by_sign() {
+        let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|c, _, filled, u, d| {
+                assert!(filled);
+                // The first bar goes up from the baseline, the second one goes down
+                if u.0 < 50 {
+                    assert_eq!(c, GREEN.to_rgba());
+                    assert!(u.1 < d.1 && d.1 == 50);
+                } else {
+                    assert_eq!(c, RED.to_rgba());
+                    assert!(u.1 == 50 && u.1 < d.1);
+                }
+            });
+            for _ in 0..2 {
+                m.check_draw_line(|c, _, from, to| {
+                    assert_eq!(c, BLACK.to_rgba());
+                    assert_eq!((from.1, to.1), (50, 50));
+                });
+            }
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_line_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..1).into_segmented(), -10..10)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .style_by_sign(GREEN.filled(), RED.filled())
+                    .data([(0, 5), (1, -5)])
+                    .bars(),
+            )
+            .expect("Drawing Error");
+    }
+}
@@ -1,8 +1,10 @@
 use crate::element::{
     Circle, DashedPathElement, DottedPathElement, DynElement, IntoDynElement, PathElement,
+    StepDirection,
 };
 use crate::style::{ShapeStyle, SizeDesc};
 use plotters_backend::{BackendCoord, DrawingBackend};
+use std::any::Any;
 use std::marker::PhantomData;
 
 /**
@@ -30,32 +32,83 @@ chart_context.draw_series(LineSeries::new(x_values.map(|x| (x, 2. - 0.1 * x * x)
 The result is a chart with three line series; two of them have their data points highlighted:
 
 ![](https://cdn.jsdelivr.net/gh/facorread/plotters-doc-data@64e0a28/apidoc/line_series_point_size.svg)
+
+The points of `(f64, f64)` or `(f32, f32)` coordinates with a NaN coordinate, i.e. the missing
+data, break the line: it's drawn as one path per run of points between them, and no segment
+bridges the gap. Other values, or the NaN values of other coordinate types, can be marked as
+missing with [`LineSeries::gaps_at()`].
 */
 pub struct LineSeries<DB: DrawingBackend, Coord> {
     style: ShapeStyle,
     data: Vec<Coord>,
+    runs: std::vec::IntoIter<Vec<Coord>>,
+    is_gap: Option<Box<dyn Fn(&Coord) -> bool>>,
     point_idx: usize,
     point_size: u32,
+    simplified: bool,
+    step: Option<StepDirection>,
     phantom: PhantomData<DB>,
 }
 
+// Whether a point of float coordinates has a NaN coordinate
+fn has_nan<Coord: 'static>(coord: &Coord) -> bool {
+    let coord = coord as &dyn Any;
+    if let Some((x, y)) = coord.downcast_ref::<(f64, f64)>() {
+        return x.is_nan() || y.is_nan();
+    }
+    if let Some((x, y)) = coord.downcast_ref::<(f32, f32)>() {
+        return x.is_nan() || y.is_nan();
+    }
+    false
+}
+
+impl<DB: DrawingBackend, Coord: Clone + 'static> LineSeries<DB, Coord> {
+    // Whether the point is missing: a float coordinate is NaN, or the point is marked as missing
+    fn is_gap(&self, coord: &Coord) -> bool {
+        has_nan(coord) || matches!(&self.is_gap, Some(is_gap) if is_gap(coord))
+    }
+
+    // Split the points into the runs between the missing points
+    fn split_runs(&mut self) {
+        let mut runs = vec![];
+        let mut run = vec![];
+        for coord in std::mem::take(&mut self.data) {
+            if !self.is_gap(&coord) {
+                run.push(coord);
+            } else if !run.is_empty() {
+                runs.push(std::mem::take(&mut run));
+            }
+        }
+        if !run.is_empty() {
+            runs.push(run);
+        }
+        self.runs = runs.into_iter();
+    }
+}
+
 impl<DB: DrawingBackend, Coord: Clone + 'static> Iterator for LineSeries<DB, Coord> {
     type Item = DynElement<'static, DB, Coord>;
     fn next(&mut self) -> Option<Self::Item> {
-        if !self.data.is_empty() {
-            if self.point_size > 0 && self.point_idx < self.data.len() {
-                let idx = self.point_idx;
-                self.point_idx += 1;
+        while self.point_size > 0 && self.point_idx < self.data.len() {
+            let idx = self.point_idx;
+            self.point_idx += 1;
+            if !self.is_gap(&self.data[idx]) {
                 return Some(
                     Circle::new(self.data[idx].clone(), self.point_size, self.style).into_dyn(),
                 );
             }
-            let mut data = vec![];
-            std::mem::swap(&mut self.data, &mut data);
-            Some(PathElement::new(data, self.style).into_dyn())
-        } else {
-            None
         }
+        if !self.data.is_empty() {
+            self.split_runs();
+        }
+        let mut path = PathElement::new(self.runs.next()?, self.style);
+        if let Some(direction) = self.step {
+            path = path.step(direction);
+        }
+        if self.simplified {
+            path = path.simplified();
+        }
+        Some(path.into_dyn())
     }
 }
 
@@ -69,8 +122,12 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
         Self {
             style: style.into(),
             data: iter.into_iter().collect(),
+            runs: vec![].into_iter(),
+            is_gap: None,
             point_size: 0,
             point_idx: 0,
+            simplified: false,
+            step: None,
             phantom: PhantomData,
         }
     }
@@ -84,6 +141,82 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
         self.point_size = size;
         self
     }
+
+    /**
+    Simplifies the line in the screen space when it's drawn: the runs of consecutive points
+    which fall in the same pixel column are collapsed into their first, lowest, highest and last
+    points. The drawn pixels are the same and the spikes are kept, but a series with millions of
+    points is drawn much faster, and makes a much smaller file with the vector backends.
+
+    See [`PathElement::simplified`] for more information.
+    */
+    pub fn simplified(mut self) -> Self {
+        self.simplified = true;
+        self
+    }
+
+    /**
+    Draws the line as a staircase, which moves horizontally then vertically between the points
+    rather than diagonally, e.g. for signal states or cumulative counts. The direction tells
+    where the vertical segments are, like the `where` parameter of matplotlib.
+
+    ```
+    use plotters::prelude::*;
+    let mut svg = String::new();
+    {
+        let drawing_area = SVGBackend::with_string(&mut svg, (300, 200)).into_drawing_area();
+        drawing_area.fill(&WHITE).unwrap();
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .set_left_and_bottom_label_area_size(20)
+            .build_cartesian_2d(0..5, 0..4)
+            .unwrap();
+        chart.configure_mesh().draw().unwrap();
+        // Each value holds until the next point, as matplotlib's `where="post"`
+        chart
+            .draw_series(
+                LineSeries::new([(0, 1), (1, 3), (2, 2), (4, 3), (5, 0)], &BLUE)
+                    .step(StepDirection::After),
+            )
+            .unwrap()
+            .label("state")
+            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+        chart.configure_series_labels().draw().unwrap();
+    }
+    ```
+    */
+    pub fn step(mut self, direction: StepDirection) -> Self {
+        self.step = Some(direction);
+        self
+    }
+
+    /**
+    Marks the points for which the predicate holds as missing, in addition to the points of
+    float coordinates with a NaN coordinate: the line is broken at them, e.g. at a sentinel value, or at the infinite
+    values which would otherwise be drawn to the border of the chart.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("line_series_gaps.svg", (300, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .set_left_and_bottom_label_area_size(20)
+        .build_cartesian_2d(0.0..5.0, 0.0..4.0)
+        .unwrap();
+    // The sensor reads -999 when it's off
+    let readings = [1.0, 1.5, -999.0, 2.5, f64::NAN, 3.5];
+    chart
+        .draw_series(
+            LineSeries::new(readings.iter().enumerate().map(|(x, y)| (x as f64, *y)), &BLUE)
+                .gaps_at(|&(_, y)| y == -999.0 || y.is_infinite()),
+        )
+        .unwrap();
+    ```
+    */
+    pub fn gaps_at<F: Fn(&Coord) -> bool + 'static>(mut self, is_gap: F) -> Self {
+        self.is_gap = Some(Box::new(is_gap));
+        self
+    }
 }
 
 /// A dashed line series, map an iterable object to the dashed line element. Can be used to draw simple dashed and dotted lines.
@@ -99,11 +232,7 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
 ///         data_series,
 ///         5, /* size = length of dash */
 ///         10, /* spacing */
-///         ShapeStyle {
-///             color: BLACK.mix(1.0),
-///             filled: false,
-///             stroke_width: 1,
-///         },
+///         BLACK.stroke_width(1),
 ///     ))
 ///     .unwrap();
 /// ```
@@ -115,11 +244,7 @@ impl<DB: DrawingBackend, Coord> LineSeries<DB, Coord> {
 ///         data_series,
 ///         1, /* size = length of dash */
 ///         4, /* spacing, best to keep this at least 1 larger than size */
-///         ShapeStyle {
-///             color: BLACK.mix(1.0),
-///             filled: false,
-///             stroke_width: 1,
-///         },
+///         BLACK.stroke_width(1),
 ///     ))
 ///     .unwrap();
 /// ```
@@ -255,4 +380,119 @@ mod test {
             .draw_series(DottedLineSeries::new((0..=50).map(|x| (x, 0)), 5, 5, mk_f))
             .expect("Drawing Error");
     }
+
+    #[test]
+    fn test_simplified_line_series() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert!(path.len() <= 4 * 200);
+                // The single point spike is still drawn
+                assert_eq!(path.iter().map(|p| p.1).min(), Some(0));
+            });
+
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100_000, 0..100)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                LineSeries::new(
+                    (0..100_000).map(|x| (x, if x == 54_321 { 100 } else { x % 50 })),
+                    &RED,
+                )
+                .simplified(),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_stepped_line_series() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|c, s, path| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(s, 2);
+                // Each diagonal becomes a horizontal and a vertical segment
+                assert_eq!(path.len(), 5);
+                for pair in path.windows(2) {
+                    assert!(pair[0].0 == pair[1].0 || pair[0].1 == pair[1].1);
+                }
+            });
+
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_circle_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                LineSeries::new(
+                    vec![(0, 0), (5, 8), (10, 3)],
+                    Into::<ShapeStyle>::into(RED).stroke_width(2),
+                )
+                .point_size(2)
+                .step(StepDirection::After),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_line_series_gaps() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let paths = Rc::new(RefCell::new(vec![]));
+        let drawn = paths.clone();
+        let drawing_area = create_mocked_drawing_area(101, 101, move |m| {
+            m.check_draw_path(move |_, _, path| drawn.borrow_mut().push(path));
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..4.0, 0.0..4.0)
+            .expect("Build chart error");
+
+        // No segment connects the points around the NaN
+        chart
+            .draw_series(LineSeries::new(
+                vec![(0.0, 1.0), (1.0, f64::NAN), (2.0, 3.0)],
+                &RED,
+            ))
+            .expect("Drawing Error");
+        assert_eq!(*paths.borrow(), vec![vec![(0, 75)], vec![(50, 25)]]);
+
+        // The sentinel values break the line too, and their points aren't drawn
+        paths.borrow_mut().clear();
+        chart
+            .draw_series(
+                LineSeries::new(
+                    vec![(0.0, 1.0), (1.0, 2.0), (2.0, -1.0), (3.0, 3.0), (4.0, 4.0)],
+                    &RED,
+                )
+                .point_size(2)
+                .gaps_at(|&(_, y)| y < 0.0),
+            )
+            .expect("Drawing Error");
+        assert_eq!(
+            *paths.borrow(),
+            vec![vec![(0, 75), (25, 50)], vec![(75, 25), (100, 0)]]
+        );
+
+        // The coordinates don't need to be comparable
+        #[derive(Clone)]
+        struct Point;
+        let series = LineSeries::<crate::drawing::MockedBackend, _>::new(vec![Point], &RED);
+        assert_eq!(series.count(), 1);
+    }
 }
@@ -12,27 +12,109 @@
 
 #[cfg(feature = "area_series")]
 mod area_series;
+#[cfg(feature = "boxplot")]
+mod boxplot;
+#[cfg(feature = "point_series")]
+mod bubble;
+#[cfg(all(feature = "line_series", feature = "colormaps"))]
+mod colored_line;
+#[cfg(feature = "contour_series")]
+mod contour;
+#[cfg(feature = "line_series")]
+mod dendrogram;
+#[cfg(feature = "area_series")]
+mod fill_between;
+#[cfg(feature = "contour_series")]
+mod filled_contour;
+#[cfg(feature = "histogram")]
+mod grouped_bar;
+#[cfg(feature = "heatmap")]
+mod heatmap;
+#[cfg(feature = "hexbin")]
+mod hexbin;
 #[cfg(feature = "histogram")]
 mod histogram;
 #[cfg(feature = "line_series")]
 mod line_series;
 #[cfg(feature = "point_series")]
 mod point_series;
+#[cfg(any(feature = "surface_series", feature = "contour_series"))]
+mod progress;
+#[cfg(feature = "area_series")]
+mod ridgeline;
+#[cfg(feature = "point_series")]
+mod rug;
+#[cfg(feature = "line_series")]
+mod spline;
+#[cfg(feature = "area_series")]
+mod stacked_area;
+#[cfg(feature = "histogram")]
+mod stacked_bar;
 #[cfg(feature = "surface_series")]
 mod surface;
+#[cfg(feature = "histogram")]
+mod waterfall;
 
 #[cfg(feature = "area_series")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "area_series")))]
 pub use area_series::AreaSeries;
+#[cfg(feature = "boxplot")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "boxplot")))]
+pub use boxplot::BoxplotSeries;
+#[cfg(feature = "point_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "point_series")))]
+pub use bubble::{BubbleScale, BubbleSeries};
+#[cfg(all(feature = "line_series", feature = "colormaps"))]
+#[cfg_attr(doc_cfg, doc(cfg(all(feature = "line_series", feature = "colormaps"))))]
+pub use colored_line::ColoredLineSeries;
+#[cfg(feature = "contour_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "contour_series")))]
+pub use contour::ContourSeries;
+#[cfg(feature = "line_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "line_series")))]
+pub use dendrogram::Dendrogram;
+#[cfg(feature = "area_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "area_series")))]
+pub use fill_between::FillBetweenSeries;
+#[cfg(feature = "contour_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "contour_series")))]
+pub use filled_contour::FilledContourSeries;
+#[cfg(feature = "histogram")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "histogram")))]
+pub use grouped_bar::GroupedBarSeries;
+#[cfg(feature = "heatmap")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "heatmap")))]
+pub use heatmap::{Heatmap, HeatmapCell};
+#[cfg(feature = "hexbin")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "hexbin")))]
+pub use hexbin::HexBin;
 #[cfg(feature = "histogram")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "histogram")))]
-pub use histogram::Histogram;
+pub use histogram::{Histogram, HistogramBar, HistogramBars};
 #[cfg(feature = "line_series")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "line_series")))]
 pub use line_series::{DashedLineSeries, DottedLineSeries, LineSeries};
 #[cfg(feature = "point_series")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "point_series")))]
 pub use point_series::PointSeries;
+#[cfg(feature = "area_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "area_series")))]
+pub use ridgeline::RidgelineSeries;
+#[cfg(feature = "point_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "point_series")))]
+pub use rug::{RugSeries, RugTick};
+#[cfg(feature = "line_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "line_series")))]
+pub use spline::SplineSeries;
+#[cfg(feature = "area_series")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "area_series")))]
+pub use stacked_area::{MisalignedSeriesError, StackedAreaSeries};
+#[cfg(feature = "histogram")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "histogram")))]
+pub use stacked_bar::StackedBarSeries;
 #[cfg(feature = "surface_series")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "surface_series")))]
 pub use surface::SurfaceSeries;
+#[cfg(feature = "histogram")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "histogram")))]
+pub use waterfall::WaterfallSeries;
@@ -45,6 +45,28 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E, Size: SizeDesc + Clone>
     /// Create a new point series. Similar to `PointSeries::new` but it doesn't
     /// requires the element implements point trait. So instead of using the point
     /// constructor, it uses the customized function for element creation
+    ///
+    /// The data items don't have to be coordinates: the function receives each item as is,
+    /// thus any field of the datum can be encoded in the element, e.g. the size of the marker.
+    /// See also [`BubbleSeries`](crate::series::BubbleSeries) for sizing the markers by a value.
+    ///
+    /// ```
+    /// use plotters::prelude::*;
+    /// let mut svg = String::new();
+    /// let drawing_area = SVGBackend::with_string(&mut svg, (300, 200)).into_drawing_area();
+    /// let mut chart = ChartBuilder::on(&drawing_area)
+    ///     .build_cartesian_2d(0.0..4.0, 0.0..4.0)
+    ///     .unwrap();
+    /// let data = [(1.0, 1.0, 5), (2.0, 3.0, 10), (3.0, 2.0, 15)];
+    /// chart
+    ///     .draw_series(PointSeries::of_element(
+    ///         data,
+    ///         1,
+    ///         RED.filled(),
+    ///         &|(x, y, size), _, style| Circle::new((x, y), size, style),
+    ///     ))
+    ///     .unwrap();
+    /// ```
     pub fn of_element<S: Into<ShapeStyle>, F: Fn(Coord, Size, ShapeStyle) -> E>(
         iter: I,
         size: Size,
@@ -0,0 +1,98 @@
+// The number of reports over a whole series, besides the final one
+const REPORTS: usize = 100;
+
+// Reports the fraction of the elements of a series handed out so far to an optional callback,
+// about each percent and once the series is exhausted. Without callback, it does nothing.
+pub(super) struct Progress<'a> {
+    callback: Option<Box<dyn FnMut(f64) + 'a>>,
+    done: usize,
+    total: usize,
+    next_report: usize,
+    finished: bool,
+}
+
+impl<'a> Progress<'a> {
+    pub(super) fn none() -> Self {
+        Self {
+            callback: None,
+            done: 0,
+            total: 0,
+            next_report: 0,
+            finished: false,
+        }
+    }
+
+    pub(super) fn new<F: FnMut(f64) + 'a>(callback: F, total: usize) -> Self {
+        Self {
+            callback: Some(Box::new(callback)),
+            done: 0,
+            total,
+            next_report: (total / REPORTS).max(1).min(total),
+            finished: false,
+        }
+    }
+
+    // Counts an element handed out, when there is one, or else reports the end of the series
+    #[inline]
+    pub(super) fn advance<T>(&mut self, element: Option<T>) -> Option<T> {
+        if self.callback.is_some() {
+            self.update(element.is_some());
+        }
+        element
+    }
+
+    fn update(&mut self, handed_out: bool) {
+        let callback = match &mut self.callback {
+            Some(callback) if !self.finished => callback,
+            _ => return,
+        };
+        if !handed_out {
+            self.finished = true;
+            callback(1.0);
+            return;
+        }
+        self.done += 1;
+        if self.done >= self.total {
+            self.finished = true;
+            callback(1.0);
+        } else if self.done >= self.next_report {
+            self.next_report = (self.done + (self.total / REPORTS).max(1)).min(self.total);
+            callback(self.done as f64 / self.total as f64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn reports(total: usize, elements: usize) -> Vec<f64> {
+        let fractions = Rc::new(RefCell::new(vec![]));
+        let sink = fractions.clone();
+        let mut progress = Progress::new(move |f| sink.borrow_mut().push(f), total);
+        for _ in 0..elements {
+            progress.advance(Some(()));
+        }
+        progress.advance(None::<()>);
+        progress.advance(None::<()>);
+        let fractions = fractions.borrow().clone();
+        fractions
+    }
+
+    #[test]
+    fn test_progress_reports() {
+        let fractions = reports(1000, 1000);
+        // About each percent, then the end, and only once
+        assert_eq!(fractions.len(), 100);
+        assert_eq!(fractions[0], 0.01);
+        assert!(fractions.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(fractions.last(), Some(&1.0));
+
+        assert_eq!(reports(3, 3), vec![1.0 / 3.0, 2.0 / 3.0, 1.0]);
+        // The series ending early, or empty, still reports its end
+        assert_eq!(reports(4, 2).last(), Some(&1.0));
+        assert_eq!(reports(0, 0), vec![1.0]);
+    }
+}
@@ -0,0 +1,347 @@
+use std::ops::Range;
+
+use crate::data::{Bandwidth, KernelDensity};
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::style::{Color, Palette, Palette99, ShapeStyle, BLACK};
+use plotters_backend::DrawingBackend;
+
+// The categories with fewer samples than this don't get a density, only a line
+const MIN_SAMPLES: usize = 3;
+
+/**
+A ridgeline plot (also known as joy plot): the densities of the samples of several categories,
+stacked vertically and slightly overlapping, which makes it easy to compare the distributions.
+
+The density of each category is estimated with [`KernelDensity`], evaluated on an X range
+shared by all the categories, and drawn on top of the baseline of the category. The baselines
+are the integers of the Y axis: the first category is the top one, at `categories - 1`, and
+the last category is the bottom one, at `0`. [`RidgelineSeries::y_range`] and
+[`RidgelineSeries::baselines`] give the Y coordinate to plot the series on, and
+[`RidgelineSeries::category_at`] gives the labels of the ticks.
+
+The densities are scaled together, so that the highest one reaches `overlap` times the distance
+between two baselines. A category with fewer than 3 samples doesn't get a density: only its
+baseline is drawn.
+
+# Example
+
+```
+use plotters::prelude::*;
+let data = vec![
+    ("A", vec![1.0, 1.5, 2.0, 2.2, 3.0]),
+    ("B", vec![2.0, 3.5, 4.0, 4.1, 5.0]),
+    ("C", vec![4.0]),
+];
+let ridges = RidgelineSeries::new(data).overlap(1.5);
+let drawing_area = SVGBackend::new("ridgeline_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_builder = ChartBuilder::on(&drawing_area);
+chart_builder.margin(10).set_left_and_bottom_label_area_size(20);
+let mut chart_context = chart_builder
+    .build_cartesian_2d(0.0..6.0, ridges.y_range().with_key_points(ridges.baselines()))
+    .unwrap();
+chart_context
+    .configure_mesh()
+    .y_label_formatter(&|y| ridges.category_at(*y).map_or(String::new(), |c| c.to_string()))
+    .draw()
+    .unwrap();
+chart_context.draw_series(ridges).unwrap();
+```
+*/
+pub struct RidgelineSeries<DB: DrawingBackend, C> {
+    categories: Vec<(C, Vec<f64>)>,
+    overlap: f64,
+    bandwidth: Bandwidth,
+    resolution: usize,
+    styles: Vec<ShapeStyle>,
+    line_style: ShapeStyle,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+// The geometry of a ridge, in the chart coordinate
+struct Ridge {
+    baseline: f64,
+    // The top of the ridge, none when there isn't enough samples for a density
+    curve: Option<Vec<(f64, f64)>>,
+}
+
+impl<DB: DrawingBackend, C> RidgelineSeries<DB, C> {
+    /**
+    Creates a new ridgeline series.
+
+    The ridges are filled with the colors of [`Palette99`] by default, and outlined in black.
+
+    - `categories`: The categories, from the top to the bottom, each with its samples
+    - **returns** The newly created series
+
+    See [`RidgelineSeries`] for more information and examples.
+    */
+    pub fn new(categories: Vec<(C, Vec<f64>)>) -> Self {
+        let styles = (0..categories.len())
+            .map(|idx| Palette99::pick(idx).mix(0.7).filled())
+            .collect();
+        Self {
+            categories,
+            overlap: 1.0,
+            bandwidth: Bandwidth::Silverman,
+            resolution: 200,
+            styles,
+            line_style: BLACK.into(),
+            elements: None,
+        }
+    }
+
+    /**
+    Sets the height of the highest ridge, relative to the distance between two baselines.
+    A value above 1 makes the ridges overlap, the default is 1.
+
+    See [`RidgelineSeries`] for more information and examples.
+    */
+    pub fn overlap(mut self, overlap: f64) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /**
+    Sets the method choosing the bandwidth of the density estimations, Silverman's rule of
+    thumb by default.
+
+    See [`RidgelineSeries`] for more information and examples.
+    */
+    pub fn bandwidth(mut self, bandwidth: Bandwidth) -> Self {
+        self.bandwidth = bandwidth;
+        self
+    }
+
+    /**
+    Sets the number of points the densities are evaluated at, 200 by default.
+
+    See [`RidgelineSeries`] for more information and examples.
+    */
+    pub fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution.max(2);
+        self
+    }
+
+    /**
+    Sets the fill style of each ridge, computed from its index and its category.
+
+    See [`RidgelineSeries`] for more information and examples.
+    */
+    pub fn style_func<F: Fn(usize, &C) -> ShapeStyle>(mut self, style_func: F) -> Self {
+        self.styles = self
+            .categories
+            .iter()
+            .enumerate()
+            .map(|(idx, (category, _))| style_func(idx, category))
+            .collect();
+        self
+    }
+
+    /**
+    Sets the style of the outline of the ridges, and of the lines drawn for the categories
+    without enough samples.
+
+    See [`RidgelineSeries`] for more information and examples.
+    */
+    pub fn line_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.line_style = style.into();
+        self
+    }
+
+    /**
+    Gets the Y range covering all the ridges, from the bottom baseline to the top of the
+    highest possible ridge.
+
+    See [`RidgelineSeries`] for more information and examples.
+    */
+    pub fn y_range(&self) -> Range<f64> {
+        0.0..(self.categories.len().max(1) - 1) as f64 + self.overlap.max(0.0)
+    }
+
+    /**
+    Gets the baselines of the categories, which are the positions of their ticks on the Y axis.
+
+    See [`RidgelineSeries`] for more information and examples.
+    */
+    pub fn baselines(&self) -> Vec<f64> {
+        (0..self.categories.len()).map(|idx| idx as f64).collect()
+    }
+
+    /**
+    Gets the category whose baseline is the given Y coordinate, if any.
+
+    See [`RidgelineSeries`] for more information and examples.
+    */
+    pub fn category_at(&self, y: f64) -> Option<&C> {
+        let n = self.categories.len();
+        if y.fract() != 0.0 || y < 0.0 || y >= n as f64 {
+            return None;
+        }
+        Some(&self.categories[n - 1 - y as usize].0)
+    }
+
+    fn layout(&self) -> (Range<f64>, Vec<Ridge>) {
+        let n = self.categories.len();
+        let densities: Vec<_> = self
+            .categories
+            .iter()
+            .map(|(_, samples)| {
+                if samples.len() >= MIN_SAMPLES {
+                    Some(KernelDensity::new(samples, self.bandwidth))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // The shared X range covers the tails of all the densities
+        let (start, end) = self.categories.iter().zip(densities.iter()).fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(start, end), ((_, samples), density)| {
+                let tail = density.as_ref().map_or(0.0, |d| 3.0 * d.bandwidth());
+                samples.iter().fold((start, end), |(start, end), &x| {
+                    (start.min(x - tail), end.max(x + tail))
+                })
+            },
+        );
+        if start > end {
+            return (0.0..0.0, vec![]);
+        }
+
+        let step = (end - start) / (self.resolution - 1) as f64;
+        let xs: Vec<f64> = (0..self.resolution)
+            .map(|i| start + step * i as f64)
+            .collect();
+        let curves: Vec<Option<Vec<f64>>> = densities
+            .iter()
+            .map(|density| {
+                density
+                    .as_ref()
+                    .map(|d| xs.iter().map(|&x| d.evaluate(x)).collect())
+            })
+            .collect();
+        let highest = curves
+            .iter()
+            .flatten()
+            .flatten()
+            .fold(0.0f64, |highest, &d| highest.max(d));
+        let scale = if highest > 0.0 {
+            self.overlap / highest
+        } else {
+            0.0
+        };
+
+        let ridges = curves
+            .into_iter()
+            .enumerate()
+            .map(|(idx, curve)| {
+                let baseline = (n - 1 - idx) as f64;
+                Ridge {
+                    baseline,
+                    curve: curve.map(|curve| {
+                        xs.iter()
+                            .zip(curve)
+                            .map(|(&x, d)| (x, baseline + d * scale))
+                            .collect()
+                    }),
+                }
+            })
+            .collect();
+        (start..end, ridges)
+    }
+
+    fn build_elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let (span, ridges) = self.layout();
+        let mut elements = vec![];
+        // The top ridge is drawn first, so that each ridge covers the one behind it
+        for (ridge, style) in ridges.into_iter().zip(self.styles.iter()) {
+            let baseline = ridge.baseline;
+            match ridge.curve {
+                Some(curve) => {
+                    let mut area = curve.clone();
+                    area.push((span.end, baseline));
+                    area.push((span.start, baseline));
+                    elements.push(Polygon::new(area, *style).into_dyn());
+                    elements.push(PathElement::new(curve, self.line_style).into_dyn());
+                }
+                None => {
+                    let line = vec![(span.start, baseline), (span.end, baseline)];
+                    elements.push(PathElement::new(line, self.line_style).into_dyn());
+                }
+            }
+        }
+        elements
+    }
+}
+
+impl<DB: DrawingBackend, C> Iterator for RidgelineSeries<DB, C> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.build_elements().into_iter());
+        }
+        self.elements.as_mut().and_then(|elements| elements.next())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+
+    type Ridges = RidgelineSeries<MockedBackend, &'static str>;
+
+    #[test]
+    fn test_ridgeline_layout() {
+        let ridges = Ridges::new(vec![
+            ("a", vec![0.0, 1.0, 2.0]),
+            ("b", vec![5.0, 5.5, 6.0, 7.0]),
+            ("c", vec![3.0]),
+        ])
+        .overlap(1.5)
+        .bandwidth(Bandwidth::Fixed(0.5))
+        .resolution(50);
+
+        assert_eq!(ridges.y_range(), 0.0..3.5);
+        assert_eq!(ridges.baselines(), vec![0.0, 1.0, 2.0]);
+        assert_eq!(ridges.category_at(2.0), Some(&"a"));
+        assert_eq!(ridges.category_at(0.0), Some(&"c"));
+        assert_eq!(ridges.category_at(0.5), None);
+        assert_eq!(ridges.category_at(3.0), None);
+
+        let (span, layout) = ridges.layout();
+        assert_eq!(span, -1.5..8.5);
+        assert_eq!(
+            layout.iter().map(|r| r.baseline).collect::<Vec<_>>(),
+            vec![2.0, 1.0, 0.0]
+        );
+
+        // The densities start on their baseline, and the highest one reaches the overlap
+        let mut highest = 0.0f64;
+        for ridge in &layout[..2] {
+            let curve = ridge.curve.as_ref().unwrap();
+            assert_eq!(curve.len(), 50);
+            assert_eq!(curve[0].0, -1.5);
+            assert!((curve[49].0 - 8.5).abs() < 1e-9);
+            assert!(curve.iter().all(|&(_, y)| y >= ridge.baseline));
+            highest = curve
+                .iter()
+                .fold(highest, |h, &(_, y)| h.max(y - ridge.baseline));
+        }
+        assert!((highest - 1.5).abs() < 1e-9);
+
+        // Too few samples for a density
+        assert!(layout[2].curve.is_none());
+    }
+
+    #[test]
+    fn test_ridgeline_elements() {
+        let ridges = Ridges::new(vec![("a", vec![0.0, 1.0, 2.0]), ("b", vec![3.0])]);
+        // The first ridge has a fill and an outline, the second one a single line
+        assert_eq!(ridges.count(), 3);
+
+        let empty = Ridges::new(vec![("a", vec![]), ("b", vec![])]);
+        assert_eq!(empty.count(), 0);
+    }
+}
@@ -0,0 +1,276 @@
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::element::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+A tick of a rug plot, which is a short line starting from an anchor point and pointing toward
+another point, thus perpendicular to the edge of the plotting area it is anchored to.
+
+See [`RugSeries`] for more information and examples.
+*/
+pub struct RugTick<Coord> {
+    points: [Coord; 2],
+    length: u32,
+    shift: i32,
+    style: ShapeStyle,
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a RugTick<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for RugTick<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (from, toward) = match (points.next(), points.next()) {
+            (Some(from), Some(toward)) => (from, toward),
+            _ => return Ok(()),
+        };
+        let (dx, dy) = (f64::from(toward.0 - from.0), f64::from(toward.1 - from.1));
+        let norm = dx.hypot(dy);
+        if norm == 0.0 {
+            return Ok(());
+        }
+        let (dx, dy) = (dx / norm, dy / norm);
+        // The jitter moves the tick along the edge, that is perpendicular to the tick
+        let shift = f64::from(self.shift);
+        let x = f64::from(from.0) - dy * shift;
+        let y = f64::from(from.1) + dx * shift;
+        let length = f64::from(self.length);
+        backend.draw_line(
+            (x.round() as i32, y.round() as i32),
+            (
+                (x + dx * length).round() as i32,
+                (y + dy * length).round() as i32,
+            ),
+            &self.style,
+        )
+    }
+}
+
+/**
+The rug plot series, which marks each value of a dataset with a short tick along an axis, to
+show the raw values under a histogram or a density curve.
+
+The ticks are anchored to the edge of the plotting area where the range of the other axis
+starts, which is the bottom edge for the X axis and the left edge for the Y axis, and point
+into the plotting area, whose ranges are taken from the chart the series is created for. The dense data can be made readable with a translucent style, such as
+`BLACK.mix(0.3)`, and with [`RugSeries::jitter()`], which spreads the ticks of close values.
+
+# Example
+
+```
+use plotters::prelude::*;
+let mut svg = String::new();
+let drawing_area = SVGBackend::with_string(&mut svg, (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..10.0, 0.0..1.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+let data = [1.2, 2.5, 2.7, 3.1, 5.0, 5.2, 7.9];
+chart
+    .draw_series(RugSeries::along_x(&chart, data, BLACK.mix(0.5)).tick_length(8))
+    .unwrap();
+```
+*/
+pub struct RugSeries<X, Y> {
+    data: std::vec::IntoIter<[(X, Y); 2]>,
+    length: u32,
+    jitter: u32,
+    index: u64,
+    style: ShapeStyle,
+}
+
+impl<X: Clone, Y: Clone> RugSeries<X, Y> {
+    /// Create a rug plot of values along the X axis, anchored to the bottom edge of a chart
+    /// - `parent`: The chart the series is drawn on
+    /// - `values`: The values on the X axis
+    /// - `style`: The style of the ticks
+    /// - returns the created series, with ticks of 5 pixels
+    pub fn along_x<XR, YR, DB, I, S>(
+        parent: &ChartContext<DB, Cartesian2d<XR, YR>>,
+        values: I,
+        style: S,
+    ) -> Self
+    where
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        DB: DrawingBackend,
+        I: IntoIterator<Item = X>,
+        S: Into<ShapeStyle>,
+    {
+        let y_range = parent.y_range();
+        let data = values
+            .into_iter()
+            .map(|x| [(x.clone(), y_range.start.clone()), (x, y_range.end.clone())])
+            .collect();
+        Self::with_data(data, style.into())
+    }
+
+    /// Create a rug plot of values along the Y axis, anchored to the left edge of a chart
+    /// - `parent`: The chart the series is drawn on
+    /// - `values`: The values on the Y axis
+    /// - `style`: The style of the ticks
+    /// - returns the created series, with ticks of 5 pixels
+    pub fn along_y<XR, YR, DB, I, S>(
+        parent: &ChartContext<DB, Cartesian2d<XR, YR>>,
+        values: I,
+        style: S,
+    ) -> Self
+    where
+        XR: Ranged<ValueType = X>,
+        YR: Ranged<ValueType = Y>,
+        DB: DrawingBackend,
+        I: IntoIterator<Item = Y>,
+        S: Into<ShapeStyle>,
+    {
+        let x_range = parent.x_range();
+        let data = values
+            .into_iter()
+            .map(|y| [(x_range.start.clone(), y.clone()), (x_range.end.clone(), y)])
+            .collect();
+        Self::with_data(data, style.into())
+    }
+
+    fn with_data(data: Vec<[(X, Y); 2]>, style: ShapeStyle) -> Self {
+        Self {
+            data: data.into_iter(),
+            length: 5,
+            jitter: 0,
+            index: 0,
+            style,
+        }
+    }
+}
+
+impl<X, Y> RugSeries<X, Y> {
+    /// Set the length of the ticks
+    /// - `length`: The length in pixels
+    /// - returns the modified series
+    pub fn tick_length(mut self, length: u32) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Move each tick by a pseudo-random amount along the axis, so that the ticks of the values
+    /// which are too close to be told apart spread out. The amounts are the same on each
+    /// rendering.
+    /// - `jitter`: The largest move in pixels, on either side
+    /// - returns the modified series
+    pub fn jitter(mut self, jitter: u32) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    // A deterministic pseudo-random shift within the jitter, from the index of the tick
+    fn shift(&self) -> i32 {
+        if self.jitter == 0 {
+            return 0;
+        }
+        // The finalizer of SplitMix64, which scatters the successive indices
+        let mut z = self
+            .index
+            .wrapping_add(1)
+            .wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        let span = 2 * u64::from(self.jitter) + 1;
+        (z % span) as i32 - self.jitter as i32
+    }
+}
+
+impl<X, Y> Iterator for RugSeries<X, Y> {
+    type Item = RugTick<(X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let points = self.data.next()?;
+        let shift = self.shift();
+        self.index += 1;
+        Some(RugTick {
+            points,
+            length: self.length,
+            shift,
+            style: self.style,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rug_along_x() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            let mut xs = vec![10, 50, 90];
+            m.check_draw_line(move |c, _, from, to| {
+                assert_eq!(c, BLACK.mix(0.5));
+                // The ticks start from the bottom edge and point up
+                assert_eq!(from, (xs.remove(0), 99));
+                assert_eq!((to.0 - from.0, to.1 - from.1), (0, -8));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 3);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..99.0, 0.0..1.0)
+            .expect("Create chart");
+        chart
+            .draw_series(
+                RugSeries::along_x(&chart, [10.0, 50.0, 90.0], BLACK.mix(0.5)).tick_length(8),
+            )
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_rug_along_y() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_line(|_, _, from, to| {
+                // The ticks start from the left edge and point right
+                assert_eq!(from.0, 0);
+                assert_eq!((to.0 - from.0, to.1 - from.1), (5, 0));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_line_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        chart
+            .draw_series(RugSeries::along_y(&chart, [2, 7], BLACK))
+            .expect("Drawing error");
+    }
+
+    #[test]
+    fn test_rug_jitter() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |_| {});
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Create chart");
+        let series = RugSeries::along_x(&chart, vec![5; 200], BLACK).jitter(3);
+        let shifts: Vec<_> = series.map(|tick| tick.shift).collect();
+        assert!(shifts.iter().all(|shift| shift.abs() <= 3));
+        // All the shifts occur, and they are the same on each rendering
+        for shift in -3..=3 {
+            assert!(shifts.contains(&shift));
+        }
+        let again = RugSeries::along_x(&chart, vec![5; 200], BLACK).jitter(3);
+        assert_eq!(shifts, again.map(|tick| tick.shift).collect::<Vec<_>>());
+    }
+}
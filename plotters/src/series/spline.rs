@@ -0,0 +1,183 @@
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+
+/**
+A smooth line series, which draws a cardinal spline through its points: a curve made of a
+cubic piece between each two consecutive points, whose tangent at a point is parallel to the
+line joining the points around it. The curve passes through all the points, unlike a
+smoothing of the data.
+
+The tension sets how tight the curve is: 0 gives a Catmull-Rom spline, which is the default,
+and 1 gives straight segments. Each piece of the curve is sampled into
+[`SplineSeries::segments()`] straight segments. A series of less than 3 points is drawn with
+straight segments.
+
+# Example
+
+```
+use plotters::prelude::*;
+let data = [(0.0, 1.0), (1.0, 2.5), (2.0, 0.5), (3.0, 2.0), (4.0, 1.5)];
+let drawing_area = SVGBackend::new("spline_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .margin(10)
+    .set_left_and_bottom_label_area_size(20)
+    .build_cartesian_2d(0.0..4.0, 0.0..3.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+chart.draw_series(LineSeries::new(data, BLACK.mix(0.3))).unwrap();
+chart
+    .draw_series(SplineSeries::new(data, BLUE).tension(0.2).segments(32))
+    .unwrap();
+```
+*/
+pub struct SplineSeries {
+    points: Vec<(f64, f64)>,
+    style: ShapeStyle,
+    tension: f64,
+    segments: usize,
+}
+
+impl SplineSeries {
+    /**
+    Creates a new smooth line series.
+
+    - `points`: The points the curve goes through
+    - `style`: The style of the curve
+    - **returns** The newly created series
+
+    See [`SplineSeries`] for more information and examples.
+    */
+    pub fn new<I: IntoIterator<Item = (f64, f64)>, S: Into<ShapeStyle>>(
+        points: I,
+        style: S,
+    ) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            style: style.into(),
+            tension: 0.0,
+            segments: 16,
+        }
+    }
+
+    /**
+    Sets the tension of the curve, between 0 for a Catmull-Rom spline, which is the default,
+    and 1 for straight segments.
+
+    See [`SplineSeries`] for more information and examples.
+    */
+    pub fn tension(mut self, tension: f64) -> Self {
+        self.tension = tension.clamp(0.0, 1.0);
+        self
+    }
+
+    /**
+    Sets the number of straight segments each piece of the curve is sampled into, which is 16
+    by default.
+
+    See [`SplineSeries`] for more information and examples.
+    */
+    pub fn segments(mut self, segments: usize) -> Self {
+        self.segments = segments.max(1);
+        self
+    }
+
+    // The points of the sampled curve, which include the points of the series
+    fn sample(&self) -> Vec<(f64, f64)> {
+        let points = &self.points;
+        if points.len() < 3 {
+            return points.clone();
+        }
+        // The tangent at a point, the ends being their own outer neighbors
+        let scale = (1.0 - self.tension) / 2.0;
+        let tangent = |idx: usize| {
+            let (prev, next) = (
+                points[idx.saturating_sub(1)],
+                points[(idx + 1).min(points.len() - 1)],
+            );
+            ((next.0 - prev.0) * scale, (next.1 - prev.1) * scale)
+        };
+
+        let mut curve = Vec::with_capacity((points.len() - 1) * self.segments + 1);
+        curve.push(points[0]);
+        for idx in 0..points.len() - 1 {
+            let (from, to) = (points[idx], points[idx + 1]);
+            let (from_tangent, to_tangent) = (tangent(idx), tangent(idx + 1));
+            for step in 1..=self.segments {
+                // The cubic Hermite basis functions
+                let t = step as f64 / self.segments as f64;
+                let (t2, t3) = (t * t, t * t * t);
+                let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+                let h10 = t3 - 2.0 * t2 + t;
+                let h01 = 3.0 * t2 - 2.0 * t3;
+                let h11 = t3 - t2;
+                curve.push((
+                    h00 * from.0 + h10 * from_tangent.0 + h01 * to.0 + h11 * to_tangent.0,
+                    h00 * from.1 + h10 * from_tangent.1 + h01 * to.1 + h11 * to_tangent.1,
+                ));
+            }
+        }
+        curve
+    }
+}
+
+impl IntoIterator for SplineSeries {
+    type Item = PathElement<(f64, f64)>;
+    type IntoIter = std::iter::Once<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        std::iter::once(PathElement::new(self.sample(), self.style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_spline_sampling() {
+        let points = vec![(0.0, 0.0), (1.0, 2.0), (2.0, 2.0), (4.0, 3.0)];
+        let curve = SplineSeries::new(points.clone(), RED).segments(8).sample();
+        assert_eq!(curve.len(), 3 * 8 + 1);
+        // The curve goes through the points
+        for (idx, point) in points.iter().enumerate() {
+            let (x, y) = curve[idx * 8];
+            assert!((x - point.0).abs() < 1e-9 && (y - point.1).abs() < 1e-9);
+        }
+        // The curve bulges above the flat piece between (1, 2) and (2, 2)
+        assert!(curve[8..16].iter().any(|&(_, y)| y > 2.0));
+
+        // A tension of 1 gives straight segments
+        let curve = SplineSeries::new(points.clone(), RED).tension(1.0).sample();
+        assert!((curve[8].1 - 1.0).abs() < 1e-9);
+
+        // Less than 3 points are joined by a straight line
+        let curve = SplineSeries::new(points[..2].to_vec(), RED).sample();
+        assert_eq!(curve, points[..2].to_vec());
+    }
+
+    #[test]
+    fn test_spline_series() {
+        let drawing_area = crate::create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_path(|c, _, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!(path.first(), Some(&(0, 100)));
+                assert_eq!(path.last(), Some(&(100, 0)));
+                assert!(path.len() > 3);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, 0.0..2.0)
+            .unwrap();
+        chart
+            .draw_series(SplineSeries::new(
+                vec![(0.0, 0.0), (1.0, 1.5), (2.0, 2.0)],
+                BLUE,
+            ))
+            .unwrap();
+    }
+}
@@ -0,0 +1,232 @@
+use std::ops::Add;
+
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::style::colors::TRANSPARENT;
+use crate::style::{Color, Palette, Palette99, ShapeStyle};
+use plotters_backend::DrawingBackend;
+
+/// The error raised when the series to stack don't share the same X coordinates
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MisalignedSeriesError {
+    /// The index of the series which isn't aligned with the first one
+    pub series: usize,
+    /// The index of the first point which isn't aligned with the first series
+    pub point: usize,
+}
+
+impl std::fmt::Display for MisalignedSeriesError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            fmt,
+            "Point {} of series {} isn't aligned with the first series",
+            self.point, self.series
+        )
+    }
+}
+
+impl std::error::Error for MisalignedSeriesError {}
+
+/**
+A layer of a stacked area chart, filling the area between the cumulative sum of the previous
+layers and the cumulative sum including this one.
+
+The layers are built all at once by [`StackedAreaSeries::stack`], and each of them is drawn
+as its own series, so that it gets its own legend entry.
+
+# Example
+
+```
+use plotters::prelude::*;
+let x_values = [0.0f64, 1., 2., 3., 4.];
+let data = vec![
+    x_values.map(|x| (x, 0.5 + 0.1 * x)).to_vec(),
+    x_values.map(|x| (x, 1.0 - 0.2 * x)).to_vec(),
+    x_values.map(|x| (x, 0.3 + 0.05 * x * x)).to_vec(),
+];
+let drawing_area = SVGBackend::new("stacked_area_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_builder = ChartBuilder::on(&drawing_area);
+chart_builder.margin(10).set_left_and_bottom_label_area_size(20);
+let mut chart_context = chart_builder.build_cartesian_2d(0.0..4.0, 0.0..3.0).unwrap();
+chart_context.configure_mesh().draw().unwrap();
+let colors = [RED, GREEN, BLUE];
+for (idx, layer) in StackedAreaSeries::stack(data, 0.0).unwrap().into_iter().enumerate() {
+    let color = colors[idx];
+    chart_context
+        .draw_series(layer.style(color.mix(0.3)).border_style(color))
+        .unwrap()
+        .label(format!("layer {}", idx))
+        .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], color.filled()));
+}
+chart_context.configure_series_labels().draw().unwrap();
+```
+*/
+pub struct StackedAreaSeries<DB: DrawingBackend, X: Clone, Y: Clone> {
+    area_style: ShapeStyle,
+    border_style: ShapeStyle,
+    lower: Vec<(X, Y)>,
+    upper: Vec<(X, Y)>,
+    state: u32,
+    _p: std::marker::PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend, X: Clone + PartialEq, Y: Clone + Add<Output = Y>>
+    StackedAreaSeries<DB, X, Y>
+{
+    /**
+    Stacks the series on top of each other, the first one being at the bottom.
+
+    All the series must share the same X coordinates, in the same order: missing points are
+    not interpolated. The layers are filled with the colors of [`Palette99`] by default.
+
+    - `series`: The series to stack, from the bottom to the top
+    - `baseline`: The value the bottom layer is stacked on
+    - **returns** The layers, in the same order as `series`, or an error if a series isn't
+      aligned with the first one
+
+    See [`StackedAreaSeries`] for more information and examples.
+    */
+    pub fn stack<I, S>(series: I, baseline: Y) -> Result<Vec<Self>, MisalignedSeriesError>
+    where
+        I: IntoIterator<Item = S>,
+        S: IntoIterator<Item = (X, Y)>,
+    {
+        let series: Vec<Vec<(X, Y)>> = series
+            .into_iter()
+            .map(|s| s.into_iter().collect())
+            .collect();
+
+        let mut layers = Vec::with_capacity(series.len());
+        let mut lower: Option<Vec<(X, Y)>> = None;
+        for (idx, data) in series.iter().enumerate() {
+            let lower_data = match lower.take() {
+                Some(lower) => lower,
+                None => data
+                    .iter()
+                    .map(|(x, _)| (x.clone(), baseline.clone()))
+                    .collect(),
+            };
+            if let Some(point) = (0..data.len().max(lower_data.len())).find(|&i| {
+                match (data.get(i), lower_data.get(i)) {
+                    (Some((x, _)), Some((lx, _))) => x != lx,
+                    _ => true,
+                }
+            }) {
+                return Err(MisalignedSeriesError { series: idx, point });
+            }
+
+            let upper_data: Vec<_> = data
+                .iter()
+                .zip(lower_data.iter())
+                .map(|((x, y), (_, base))| (x.clone(), base.clone() + y.clone()))
+                .collect();
+
+            lower = Some(upper_data.clone());
+            layers.push(Self {
+                area_style: Palette99::pick(idx).filled(),
+                border_style: (&TRANSPARENT).into(),
+                lower: lower_data,
+                upper: upper_data,
+                state: 0,
+                _p: std::marker::PhantomData,
+            });
+        }
+        Ok(layers)
+    }
+
+    /**
+    Sets the fill style of the layer.
+
+    See [`StackedAreaSeries`] for more information and examples.
+    */
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.area_style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the upper border of the layer.
+
+    See [`StackedAreaSeries`] for more information and examples.
+    */
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone + 'static, Y: Clone + 'static> Iterator
+    for StackedAreaSeries<DB, X, Y>
+{
+    type Item = DynElement<'static, DB, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == 0 {
+            let data: Vec<_> = self
+                .upper
+                .iter()
+                .chain(self.lower.iter().rev())
+                .cloned()
+                .collect();
+
+            self.state = 1;
+
+            Some(Polygon::new(data, self.area_style).into_dyn())
+        } else if self.state == 1 {
+            let data: Vec<_> = self.upper.clone();
+
+            self.state = 2;
+
+            Some(PathElement::new(data, self.border_style).into_dyn())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::drawing::MockedBackend;
+
+    type Layer = StackedAreaSeries<MockedBackend, i32, i32>;
+
+    #[test]
+    fn test_stack_cumulative_sums() {
+        let layers = Layer::stack(
+            vec![
+                vec![(0, 1), (1, 2)],
+                vec![(0, 3), (1, 4)],
+                vec![(0, 5), (1, 6)],
+            ],
+            10,
+        )
+        .unwrap();
+        assert_eq!(layers.len(), 3);
+        assert_eq!(layers[0].lower, vec![(0, 10), (1, 10)]);
+        assert_eq!(layers[0].upper, vec![(0, 11), (1, 12)]);
+        assert_eq!(layers[1].lower, layers[0].upper);
+        assert_eq!(layers[1].upper, vec![(0, 14), (1, 16)]);
+        assert_eq!(layers[2].upper, vec![(0, 19), (1, 22)]);
+    }
+
+    #[test]
+    fn test_stack_misaligned() {
+        let missing = Layer::stack(vec![vec![(0, 1), (1, 2)], vec![(0, 3)]], 0);
+        assert_eq!(
+            missing.err(),
+            Some(MisalignedSeriesError {
+                series: 1,
+                point: 1
+            })
+        );
+
+        let shifted = Layer::stack(vec![vec![(0, 1), (1, 2)], vec![(0, 3), (2, 4)]], 0);
+        assert_eq!(
+            shifted.err(),
+            Some(MisalignedSeriesError {
+                series: 1,
+                point: 1
+            })
+        );
+    }
+}
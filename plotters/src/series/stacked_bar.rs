@@ -0,0 +1,263 @@
+use super::grouped_bar::GroupedBar;
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{DiscreteRanged, Ranged};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::Rectangle;
+use crate::style::{Color, Palette, Palette99, ShapeStyle};
+use plotters_backend::DrawingBackend;
+
+/**
+A stacked bar chart: each category of a discrete X axis gets a single bar, made of the segments
+of the sub-series stacked on top of each other, the first sub-series being at the bottom.
+
+The data gives the values of the sub-series for each category, and each sub-series is declared
+with [`StackedBarSeries::sub_series`], like for [`crate::series::GroupedBarSeries`]: it is drawn
+as its own series, with its own legend entry.
+
+With [`StackedBarSeries::normalized`], each stack is rescaled so that its segments sum to 1,
+which compares the shares of the sub-series across categories of different sizes. The Y axis
+is then expected to be `0.0..1.0`, and a category whose total is zero isn't drawn.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("stacked_bar_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let mut chart_builder = ChartBuilder::on(&drawing_area);
+chart_builder.margin(5).set_left_and_bottom_label_area_size(20);
+let mut chart_context = chart_builder
+    .build_cartesian_2d((0..2).into_segmented(), 0.0..1.0)
+    .unwrap();
+chart_context.configure_mesh().draw().unwrap();
+StackedBarSeries::new(vec![(0, vec![3.0, 5.0]), (1, vec![60.0, 40.0]), (2, vec![8.0, 1.0])])
+    .sub_series("2022", RED.filled())
+    .sub_series("2023", BLUE.filled())
+    .normalized()
+    .draw(&mut chart_context)
+    .unwrap();
+chart_context.configure_series_labels().draw().unwrap();
+```
+*/
+pub struct StackedBarSeries<X> {
+    data: Vec<(X, Vec<f64>)>,
+    sub_series: Vec<(String, ShapeStyle)>,
+    margin: u32,
+    normalized: bool,
+}
+
+impl<X: Clone> StackedBarSeries<X> {
+    /**
+    Creates a new stacked bar series.
+
+    - `data`: The categories, each with the values of the sub-series, from the bottom to the top
+    - **returns** The newly created series
+
+    See [`StackedBarSeries`] for more information and examples.
+    */
+    pub fn new<I: IntoIterator<Item = (X, Vec<f64>)>>(data: I) -> Self {
+        Self {
+            data: data.into_iter().collect(),
+            sub_series: vec![],
+            margin: 10,
+            normalized: false,
+        }
+    }
+
+    /**
+    Declares the next sub-series, which gets a legend entry with its label.
+
+    See [`StackedBarSeries`] for more information and examples.
+    */
+    pub fn sub_series<L: Into<String>, S: Into<ShapeStyle>>(mut self, label: L, style: S) -> Self {
+        self.sub_series.push((label.into(), style.into()));
+        self
+    }
+
+    /**
+    Sets the space between two bars, in pixels, which is split on both sides of each bar.
+    The default is 10.
+
+    See [`StackedBarSeries`] for more information and examples.
+    */
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /**
+    Rescales each stack so that its segments sum to 1, to draw it on a `0.0..1.0` Y axis.
+
+    See [`StackedBarSeries`] for more information and examples.
+    */
+    pub fn normalized(mut self) -> Self {
+        self.normalized = true;
+        self
+    }
+
+    // The bottom and top of each segment of each category, or `None` for the categories which
+    // aren't drawn
+    fn stacks(&self) -> Vec<Option<Vec<(f64, f64)>>> {
+        self.data
+            .iter()
+            .map(|(_, values)| {
+                let scale = if self.normalized {
+                    let total: f64 = values.iter().sum();
+                    if total == 0.0 || !total.is_finite() {
+                        return None;
+                    }
+                    total
+                } else {
+                    1.0
+                };
+                let mut top = 0.0;
+                Some(
+                    values
+                        .iter()
+                        .map(|value| {
+                            let bottom = top;
+                            top += value / scale;
+                            (bottom, top)
+                        })
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    /**
+    Draws the bars on a chart, one series per sub-series.
+
+    - `chart`: The chart, whose X axis is a discrete coordinate holding the categories
+    - **returns** An error if the bars couldn't be drawn
+
+    See [`StackedBarSeries`] for more information and examples.
+    */
+    pub fn draw<'a, DB, XR, YR>(
+        self,
+        chart: &mut ChartContext<'a, DB, Cartesian2d<XR, YR>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DB: DrawingBackend + 'a,
+        XR: DiscreteRanged,
+        X: Into<XR::ValueType>,
+        YR: Ranged<ValueType = f64>,
+    {
+        let count = self
+            .data
+            .iter()
+            .map(|(_, values)| values.len())
+            .max()
+            .unwrap_or(0)
+            .max(self.sub_series.len());
+        let stacks = self.stacks();
+
+        for idx in 0..count {
+            let (label, style) = match self.sub_series.get(idx) {
+                Some((label, style)) => (Some(label), *style),
+                None => (None, Palette99::pick(idx).filled()),
+            };
+            // Each segment spans the slot of its category, from its value to the next one of
+            // the axis
+            let segments: Vec<_> = {
+                let xr = chart.as_coord_spec().x_spec();
+                self.data
+                    .iter()
+                    .zip(stacks.iter())
+                    .filter_map(|((x, _), stack)| {
+                        let &(bottom, top) = stack.as_ref()?.get(idx)?;
+                        let x_idx = xr.index_of(&x.clone().into())?;
+                        let (x, nx) = (xr.from_index(x_idx)?, xr.from_index(x_idx + 1)?);
+                        Some(GroupedBar {
+                            rect: Rectangle::new([(x, top), (nx, bottom)], style),
+                            index: 0,
+                            count: 1,
+                            group_margin: self.margin,
+                            bar_spacing: 0,
+                        })
+                    })
+                    .collect()
+            };
+            let anno = chart.draw_series(segments)?;
+            if let Some(label) = label {
+                let legend_style = ShapeStyle {
+                    filled: true,
+                    ..style
+                };
+                anno.label(label.as_str()).legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 5), (x + 10, y + 5)], legend_style)
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_stacks() {
+        let data = vec![(0, vec![1.0, 3.0]), (1, vec![0.0, 0.0]), (2, vec![5.0])];
+        let stacks = StackedBarSeries::new(data.clone()).stacks();
+        assert_eq!(
+            stacks,
+            vec![
+                Some(vec![(0.0, 1.0), (1.0, 4.0)]),
+                Some(vec![(0.0, 0.0), (0.0, 0.0)]),
+                Some(vec![(0.0, 5.0)]),
+            ]
+        );
+
+        // A category whose total is zero isn't drawn
+        let stacks = StackedBarSeries::new(data).normalized().stacks();
+        assert_eq!(
+            stacks,
+            vec![
+                Some(vec![(0.0, 0.25), (0.25, 1.0)]),
+                None,
+                Some(vec![(0.0, 1.0)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalized_stacked_bars() {
+        let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_rect(|c, _, filled, u, d| {
+                assert!(filled);
+                // Both stacks are full height, split at a quarter and at a half
+                if c == RED.to_rgba() {
+                    assert_eq!(d.1, 99);
+                    assert!(u.1 == 75 || u.1 == 50);
+                } else {
+                    assert_eq!(c, BLUE.to_rgba());
+                    assert_eq!(u.1, 0);
+                    assert!(d.1 == 75 || d.1 == 50);
+                }
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..2).into_segmented(), 0.0..1.0)
+            .expect("Build chart error");
+
+        StackedBarSeries::new(vec![
+            (0, vec![1.0, 3.0]),
+            (1, vec![50.0, 50.0]),
+            (2, vec![0.0, 0.0]),
+        ])
+        .sub_series("red", RED.filled())
+        .sub_series("blue", BLUE.filled())
+        .normalized()
+        .draw(&mut chart)
+        .expect("Drawing Error");
+        assert_eq!(chart.series_anno.len(), 2);
+    }
+}
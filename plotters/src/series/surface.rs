@@ -1,5 +1,6 @@
+use super::progress::Progress;
 use crate::element::Polygon;
-use crate::style::{colors::BLUE, Color, ShapeStyle};
+use crate::style::{colors::BLUE, Color, RGBAColor, ShapeStyle};
 use std::marker::PhantomData;
 
 /// Any type that describe a surface orientation
@@ -11,6 +12,10 @@ pub trait Direction<X, Y, Z> {
     /// The output of the surface function
     type OutputType;
 
+    /// The axis the output of the surface function is mapped to: 0 for X, 1 for Y and 2 for Z.
+    /// The normals of the surface point toward the increasing outputs.
+    const OUTPUT_AXIS: usize = 2;
+
     /// The function that maps a point on surface into the coordinate system
     fn make_coord(
         free_vars: (Self::Input1Type, Self::Input2Type),
@@ -19,13 +24,14 @@ pub trait Direction<X, Y, Z> {
 }
 
 macro_rules! define_panel_descriptor {
-    ($name: ident, $var1: ident, $var2: ident, $out: ident, ($first: ident, $second:ident) -> $result: ident = $output: expr) => {
+    ($name: ident, $var1: ident, $var2: ident, $out: ident, $axis: expr, ($first: ident, $second:ident) -> $result: ident = $output: expr) => {
         #[allow(clippy::upper_case_acronyms)]
         pub struct $name;
         impl<X, Y, Z> Direction<X, Y, Z> for $name {
             type Input1Type = $var1;
             type Input2Type = $var2;
             type OutputType = $out;
+            const OUTPUT_AXIS: usize = $axis;
             fn make_coord(
                 ($first, $second): (Self::Input1Type, Self::Input2Type),
                 $result: Self::OutputType,
@@ -36,9 +42,9 @@ macro_rules! define_panel_descriptor {
     };
 }
 
-define_panel_descriptor!(XOY, X, Y, Z, (x, y) -> z = (x,y,z));
-define_panel_descriptor!(XOZ, X, Z, Y, (x, z) -> y = (x,y,z));
-define_panel_descriptor!(YOZ, Y, Z, X, (y, z) -> x = (x,y,z));
+define_panel_descriptor!(XOY, X, Y, Z, 2, (x, y) -> z = (x,y,z));
+define_panel_descriptor!(XOZ, X, Z, Y, 1, (x, z) -> y = (x,y,z));
+define_panel_descriptor!(YOZ, Y, Z, X, 0, (y, z) -> x = (x,y,z));
 
 enum StyleConfig<'a, T> {
     Fixed(ShapeStyle),
@@ -54,6 +60,47 @@ impl<T> StyleConfig<'_, T> {
     }
 }
 
+// A directional light shading the faces of a surface
+struct Light<X, Y, Z> {
+    direction: (f64, f64, f64),
+    ambient: f64,
+    position: fn(&(X, Y, Z)) -> (f64, f64, f64),
+}
+
+impl<X, Y, Z> Light<X, Y, Z> {
+    // Darken the style of a quad face according to the angle between its normal and the light
+    fn shade(&self, face: &[(X, Y, Z)], output_axis: usize, style: ShapeStyle) -> ShapeStyle {
+        let p: Vec<_> = face.iter().map(self.position).collect();
+        // The cross product of the diagonals is normal to the face, even if it isn't planar
+        let d1 = (p[2].0 - p[0].0, p[2].1 - p[0].1, p[2].2 - p[0].2);
+        let d2 = (p[3].0 - p[1].0, p[3].1 - p[1].1, p[3].2 - p[1].2);
+        let mut normal = [
+            d2.1 * d1.2 - d2.2 * d1.1,
+            d2.2 * d1.0 - d2.0 * d1.2,
+            d2.0 * d1.1 - d2.1 * d1.0,
+        ];
+        if normal[output_axis] < 0.0 {
+            normal = normal.map(|v| -v);
+        }
+        let light = [self.direction.0, self.direction.1, self.direction.2];
+        let norm = |v: &[f64; 3]| v.iter().map(|c| c * c).sum::<f64>().sqrt();
+        let (n, l) = (norm(&normal), norm(&light));
+        let dot: f64 = normal.iter().zip(light.iter()).map(|(a, b)| a * b).sum();
+        let cos = if n > 0.0 && l > 0.0 {
+            dot / (n * l)
+        } else {
+            0.0
+        };
+        let factor = self.ambient + (1.0 - self.ambient) * cos.max(0.0);
+        let RGBAColor(r, g, b, a) = style.color;
+        let scale = |c: u8| (f64::from(c) * factor).round() as u8;
+        ShapeStyle {
+            color: RGBAColor(scale(r), scale(g), scale(b), a),
+            ..style
+        }
+    }
+}
+
 /**
 Represents functions of two variables.
 
@@ -102,6 +149,8 @@ where
     free_var_2: Vec<D::Input2Type>,
     surface_f: SurfaceFunc,
     style: StyleConfig<'a, D::OutputType>,
+    light: Option<Light<X, Y, Z>>,
+    progress: Progress<'a>,
     vidx_1: usize,
     vidx_2: usize,
     _phantom: PhantomData<(X, Y, Z, D)>,
@@ -123,6 +172,8 @@ where
             free_var_2: second_iter.collect(),
             surface_f: func,
             style: StyleConfig::Fixed(BLUE.mix(0.4).filled()),
+            light: None,
+            progress: Progress::none(),
             vidx_1: 0,
             vidx_2: 0,
             _phantom: PhantomData,
@@ -169,6 +220,85 @@ where
         self.style = StyleConfig::Fixed(s.into());
         self
     }
+
+    /**
+    Shades the faces of the surface with a directional light.
+
+    Each face keeps the color of its style when it faces the light, and gets darker as its
+    normal turns away from the light, down to the ambient fraction of its color. The normals
+    are computed in the coordinates of the data and point toward the increasing values of the
+    surface function.
+
+    - `direction`: The direction the light comes from, pointing from the surface to the light
+    - `ambient`: The fraction of the color kept by the faces the light doesn't reach, from 0 to 1
+    - **returns** The updated surface series
+
+    # Examples
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("surface_series_light.svg", (640, 480)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart_context = ChartBuilder::on(&drawing_area)
+        .margin(10)
+        .build_cartesian_3d(-3.0..3.0f64, 0.0..9.0f64, -3.0..3.0f64)
+        .unwrap();
+    chart_context.configure_axes().draw().unwrap();
+    chart_context.draw_series(
+        SurfaceSeries::xoz(
+            (-30..30).map(|v| v as f64 / 10.0),
+            (-30..30).map(|v| v as f64 / 10.0),
+            |x: f64, z: f64| (x * x + z * z) / 2.0,
+        )
+        .style(BLUE.filled())
+        .with_light((1.0, 1.0, -1.0), 0.3),
+    ).unwrap();
+    ```
+    */
+    pub fn with_light(mut self, direction: (f64, f64, f64), ambient: f64) -> Self
+    where
+        X: Clone + Into<f64>,
+        Y: Clone + Into<f64>,
+        Z: Clone + Into<f64>,
+    {
+        self.light = Some(Light {
+            direction,
+            ambient: ambient.clamp(0.0, 1.0),
+            position: |(x, y, z)| (x.clone().into(), y.clone().into(), z.clone().into()),
+        });
+        self
+    }
+
+    /**
+    Reports the progress of the drawing of the surface, for the large surfaces which take long
+    to draw: the callback gets the fraction of the faces handed out so far, about each percent,
+    and 1.0 once they have all been handed out.
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("surface_series_progress.svg", (640, 480)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let mut chart_context = ChartBuilder::on(&drawing_area)
+        .build_cartesian_3d(-3.0..3.0f64, -1.0..1.0f64, -3.0..3.0f64)
+        .unwrap();
+    let mut fractions = vec![];
+    chart_context.draw_series(
+        SurfaceSeries::xoz(
+            (-30..30).map(|v| v as f64 / 10.0),
+            (-30..30).map(|v| v as f64 / 10.0),
+            |x: f64, z: f64| (x * x + z * z).cos(),
+        )
+        .progress(|fraction| fractions.push(fraction)),
+    ).unwrap();
+    assert_eq!(fractions.last(), Some(&1.0));
+    ```
+    */
+    pub fn progress<F: FnMut(f64) + 'a>(mut self, callback: F) -> Self {
+        let faces =
+            self.free_var_1.len().saturating_sub(1) * self.free_var_2.len().saturating_sub(1);
+        self.progress = Progress::new(callback, faces);
+        self
+    }
 }
 
 macro_rules! impl_constructor {
@@ -204,6 +334,19 @@ where
 {
     type Item = Polygon<(X, Y, Z)>;
     fn next(&mut self) -> Option<Self::Item> {
+        let face = self.next_face();
+        self.progress.advance(face)
+    }
+}
+
+impl<'a, X, Y, Z, D, SurfaceFunc> SurfaceSeries<'a, X, Y, Z, D, SurfaceFunc>
+where
+    D: Direction<X, Y, Z>,
+    D::Input1Type: Clone,
+    D::Input2Type: Clone,
+    SurfaceFunc: Fn(D::Input1Type, D::Input2Type) -> D::OutputType,
+{
+    fn next_face(&mut self) -> Option<Polygon<(X, Y, Z)>> {
         let (b0, b1) = if let (Some(b0), Some(b1)) = (
             self.free_var_2.get(self.vidx_2),
             self.free_var_2.get(self.vidx_2 + 1),
@@ -226,7 +369,7 @@ where
         ) {
             (Some(a0), Some(a1)) => {
                 let value = (self.surface_f)(a0.clone(), b0.clone());
-                let style = self.style.get_style(&value);
+                let mut style = self.style.get_style(&value);
                 let vert = vec![
                     D::make_coord((a0.clone(), b0.clone()), value),
                     D::make_coord(
@@ -242,9 +385,71 @@ where
                         (self.surface_f)(a1.clone(), b0.clone()),
                     ),
                 ];
+                if let Some(light) = &self.light {
+                    style = light.shade(&vert, D::OUTPUT_AXIS, style);
+                }
                 Some(Polygon::new(vert, style))
             }
             _ => None,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_surface_light() {
+        let drawing_area = crate::create_mocked_drawing_area(400, 400, |m| {
+            let mut colors = vec![];
+            m.check_fill_polygon(move |c, _| {
+                colors.push(c);
+                if colors.len() == 2 {
+                    // The first face rises toward the light, the second one falls away from it
+                    assert_eq!(colors[0], RGBColor(200, 100, 50).to_rgba());
+                    assert_eq!(colors[1], RGBColor(50, 25, 13).to_rgba());
+                }
+            });
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(-1.0..1.0, 0.0..1.0, -1.0..1.0)
+            .unwrap();
+        chart
+            .draw_series(
+                SurfaceSeries::xoy(
+                    vec![-1.0, 0.0, 1.0].into_iter(),
+                    vec![0.0, 1.0].into_iter(),
+                    |x: f64, _| -x.abs(),
+                )
+                .style(RGBColor(200, 100, 50).filled())
+                .with_light((-1.0, 0.0, 1.0), 0.25),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_surface_progress() {
+        let drawing_area = crate::create_mocked_drawing_area(400, 400, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_3d(0.0..1.0, 0.0..1.0, 0.0..1.0)
+            .unwrap();
+        let mut fractions = vec![];
+        chart
+            .draw_series(
+                SurfaceSeries::xoz(
+                    (0..=20).map(|v| v as f64 / 20.0),
+                    (0..=10).map(|v| v as f64 / 10.0),
+                    |x: f64, z: f64| x * z,
+                )
+                .progress(|fraction| fractions.push(fraction)),
+            )
+            .unwrap();
+
+        // 200 faces, reported every other face, up to the end
+        assert_eq!(fractions.len(), 100);
+        assert!(fractions.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(fractions.last(), Some(&1.0));
+    }
+}
@@ -0,0 +1,365 @@
+use std::ops::Range;
+
+use super::grouped_bar::{bar_span, GroupedBar};
+use crate::chart::ChartContext;
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{DiscreteRanged, Ranged};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Drawable, PointCollection, Rectangle};
+use crate::style::{Color, ShapeStyle, BLACK, BLUE, GREEN, RED};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+A waterfall (bridge) chart: each category of a discrete X axis gets a floating bar, which starts
+where the running total of the previous bars ended and spans the change of its category, thus
+the bars walk from a starting value to a final one.
+
+The data gives the change of each category, and whether it is a total: a total bar is anchored
+to zero and spans the running total of the bars before it, its own change being ignored. The
+increases, the decreases and the totals are drawn in different styles, and a connector line
+joins the end of each bar to the start of the next one.
+
+Like [`crate::series::StackedBarSeries`], the X axis is usually made of the categories turned
+into a segmented coordinate, and [`WaterfallSeries::value_range`] gives a Y range which fits all
+the bars.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("waterfall_series.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let series = WaterfallSeries::new(vec![
+    (0, 100.0, false),
+    (1, -30.0, false),
+    (2, 15.0, false),
+    (3, 0.0, true),
+]);
+let mut chart_builder = ChartBuilder::on(&drawing_area);
+chart_builder.margin(5).set_left_and_bottom_label_area_size(20);
+let mut chart_context = chart_builder
+    .build_cartesian_2d((0..3).into_segmented(), series.value_range())
+    .unwrap();
+chart_context.configure_mesh().draw().unwrap();
+series
+    .increase_style(GREEN.filled())
+    .decrease_style(RED.filled())
+    .total_style(BLUE.filled())
+    .draw(&mut chart_context)
+    .unwrap();
+```
+*/
+pub struct WaterfallSeries<X> {
+    data: Vec<(X, f64, bool)>,
+    increase_style: ShapeStyle,
+    decrease_style: ShapeStyle,
+    total_style: ShapeStyle,
+    connector_style: Option<ShapeStyle>,
+    margin: u32,
+}
+
+impl<X: Clone> WaterfallSeries<X> {
+    /**
+    Creates a new waterfall series.
+
+    - `data`: The categories, each with its change and whether it is a total bar
+    - **returns** The newly created series, with green increases, red decreases, blue totals
+      and black connectors
+
+    See [`WaterfallSeries`] for more information and examples.
+    */
+    pub fn new<I: IntoIterator<Item = (X, f64, bool)>>(data: I) -> Self {
+        Self {
+            data: data.into_iter().collect(),
+            increase_style: GREEN.filled(),
+            decrease_style: RED.filled(),
+            total_style: BLUE.filled(),
+            connector_style: Some(BLACK.into()),
+            margin: 10,
+        }
+    }
+
+    /**
+    Sets the style of the bars of the positive changes.
+
+    See [`WaterfallSeries`] for more information and examples.
+    */
+    pub fn increase_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.increase_style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the bars of the negative changes.
+
+    See [`WaterfallSeries`] for more information and examples.
+    */
+    pub fn decrease_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.decrease_style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the total bars.
+
+    See [`WaterfallSeries`] for more information and examples.
+    */
+    pub fn total_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.total_style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the lines joining the bars, or removes them with `None`.
+
+    See [`WaterfallSeries`] for more information and examples.
+    */
+    pub fn connector_style<S: Into<ShapeStyle>>(mut self, style: Option<S>) -> Self {
+        self.connector_style = style.map(Into::into);
+        self
+    }
+
+    /**
+    Sets the space between two bars, in pixels, which is split on both sides of each bar.
+    The default is 10.
+
+    See [`WaterfallSeries`] for more information and examples.
+    */
+    pub fn margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    // The start and the end of each bar, the end being the running total after it
+    fn spans(&self) -> Vec<(f64, f64)> {
+        let mut total = 0.0;
+        self.data
+            .iter()
+            .map(|&(_, delta, is_total)| {
+                if is_total {
+                    (0.0, total)
+                } else {
+                    let start = total;
+                    total += delta;
+                    (start, total)
+                }
+            })
+            .collect()
+    }
+
+    /**
+    Computes the range of the values the bars span, which always includes zero, to build the
+    Y axis of the chart.
+
+    See [`WaterfallSeries`] for more information and examples.
+    */
+    pub fn value_range(&self) -> Range<f64> {
+        self.spans().iter().fold(0.0..0.0, |range, &(start, end)| {
+            range.start.min(start).min(end)..range.end.max(start).max(end)
+        })
+    }
+
+    /**
+    Draws the bars on a chart, then the connectors between them.
+
+    - `chart`: The chart, whose X axis is a discrete coordinate holding the categories
+    - **returns** An error if the bars couldn't be drawn
+
+    See [`WaterfallSeries`] for more information and examples.
+    */
+    pub fn draw<'a, DB, XR, YR>(
+        self,
+        chart: &mut ChartContext<'a, DB, Cartesian2d<XR, YR>>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DB: DrawingBackend + 'a,
+        XR: DiscreteRanged,
+        X: Into<XR::ValueType>,
+        YR: Ranged<ValueType = f64>,
+    {
+        // The index of each category on the axis, if it is there
+        let indices: Vec<_> = {
+            let xr = chart.as_coord_spec().x_spec();
+            self.data
+                .iter()
+                .map(|(x, _, _)| xr.index_of(&x.clone().into()))
+                .collect()
+        };
+        let spans = self.spans();
+
+        // Each bar spans the slot of its category, from its value to the next one of the axis
+        let bars: Vec<_> = {
+            let xr = chart.as_coord_spec().x_spec();
+            self.data
+                .iter()
+                .zip(indices.iter().zip(spans.iter()))
+                .filter_map(|(&(_, delta, is_total), (x_idx, &(start, end)))| {
+                    let x_idx = (*x_idx)?;
+                    let (x, nx) = (xr.from_index(x_idx)?, xr.from_index(x_idx + 1)?);
+                    let style = if is_total {
+                        self.total_style
+                    } else if delta >= 0.0 {
+                        self.increase_style
+                    } else {
+                        self.decrease_style
+                    };
+                    Some(GroupedBar {
+                        rect: Rectangle::new([(x, end), (nx, start)], style),
+                        index: 0,
+                        count: 1,
+                        group_margin: self.margin,
+                        bar_spacing: 0,
+                    })
+                })
+                .collect()
+        };
+        chart.draw_series(bars)?;
+
+        if let Some(style) = self.connector_style {
+            let connectors: Vec<_> = {
+                let xr = chart.as_coord_spec().x_spec();
+                indices
+                    .windows(2)
+                    .zip(spans.iter())
+                    .filter_map(|(pair, &(_, level))| {
+                        let (x_idx, next_idx) = (pair[0]?, pair[1]?);
+                        let points = [
+                            (xr.from_index(x_idx)?, level),
+                            (xr.from_index(x_idx + 1)?, level),
+                            (xr.from_index(next_idx)?, level),
+                            (xr.from_index(next_idx + 1)?, level),
+                        ];
+                        Some(WaterfallConnector {
+                            points,
+                            margin: self.margin,
+                            style,
+                        })
+                    })
+                    .collect()
+            };
+            chart.draw_series(connectors)?;
+        }
+        Ok(())
+    }
+}
+
+// A line from the right edge of a bar to the left edge of the next one: the points are the
+// bounds of the slots of both bars, at the level of the line
+struct WaterfallConnector<Coord> {
+    points: [Coord; 4],
+    margin: u32,
+    style: ShapeStyle,
+}
+
+impl<'b, Coord> PointCollection<'b, Coord> for &'b WaterfallConnector<Coord> {
+    type Point = &'b Coord;
+    type IntoIter = &'b [Coord];
+    fn point_iter(self) -> &'b [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for WaterfallConnector<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        if points.len() != 4 {
+            return Ok(());
+        }
+        let (_, from) = bar_span((points[0].0, points[1].0), 0, 1, self.margin, 0);
+        let (to, _) = bar_span((points[2].0, points[3].0), 0, 1, self.margin, 0);
+        backend.draw_line((from, points[0].1), (to, points[0].1), &self.style)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_waterfall_spans() {
+        let series = WaterfallSeries::new(vec![
+            (0, 100.0, false),
+            (1, -30.0, false),
+            (2, 0.0, true),
+            (3, 50.0, false),
+            (4, -150.0, false),
+            (5, 999.0, true),
+        ]);
+        assert_eq!(
+            series.spans(),
+            vec![
+                (0.0, 100.0),
+                (100.0, 70.0),
+                (0.0, 70.0),
+                (70.0, 120.0),
+                (120.0, -30.0),
+                (0.0, -30.0),
+            ]
+        );
+        assert_eq!(series.value_range(), -30.0..120.0);
+    }
+
+    #[test]
+    fn test_draw_waterfall() {
+        let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+            // The bars float from the running total, the total is anchored to zero
+            let mut bars = vec![
+                (GREEN.to_rgba(), 50, 99),
+                (RED.to_rgba(), 50, 75),
+                (GREEN.to_rgba(), 50, 75),
+                (BLUE.to_rgba(), 50, 99),
+            ];
+            m.check_draw_rect(move |c, _, filled, u, d| {
+                assert!(filled);
+                assert_eq!((c, u.1, d.1), bars.remove(0));
+            });
+            // The connectors join the bars across the margin, at the level of the running total
+            let mut levels = vec![50, 75, 50];
+            m.check_draw_line(move |c, _, from, to| {
+                assert_eq!(c, BLACK.to_rgba());
+                assert_eq!((from.1, to.1), (levels[0], levels.remove(0)));
+                assert!((9..=11).contains(&(to.0 - from.0)));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 4);
+                assert_eq!(b.num_draw_line_call, 3);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..3).into_segmented(), 0.0..2.0)
+            .expect("Build chart error");
+        WaterfallSeries::new(vec![
+            (0, 1.0, false),
+            (1, -0.5, false),
+            (2, 0.5, false),
+            (3, 0.0, true),
+        ])
+        .draw(&mut chart)
+        .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_waterfall_without_connectors() {
+        let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_draw_line_call, 0);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d((0..1).into_segmented(), 0.0..2.0)
+            .expect("Build chart error");
+        WaterfallSeries::new(vec![(0, 1.0, false), (1, 0.0, true)])
+            .connector_style(None::<ShapeStyle>)
+            .draw(&mut chart)
+            .expect("Drawing Error");
+    }
+}
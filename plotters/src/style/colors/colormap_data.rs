@@ -0,0 +1,288 @@
+// The 256 colors of the perceptually uniform colormaps of matplotlib, by Stéfan van der Walt and
+// Nathaniel Smith, which are released to the public domain (CC0).
+
+use crate::style::{RGBAColor, RGBColor};
+
+// The colors of a table, fully opaque
+pub(super) const fn opaque<const N: usize>(colors: [RGBColor; N]) -> [RGBAColor; N] {
+    let mut opaque = [RGBAColor(0, 0, 0, 1.0); N];
+    let mut index = 0;
+    while index < N {
+        let RGBColor(r, g, b) = colors[index];
+        opaque[index] = RGBAColor(r, g, b, 1.0);
+        index += 1;
+    }
+    opaque
+}
+
+#[rustfmt::skip]
+pub(super) const VIRIDIS: [RGBColor; 256] = [
+    RGBColor(68, 1, 84), RGBColor(68, 2, 86), RGBColor(69, 4, 87), RGBColor(69, 5, 89),
+    RGBColor(70, 7, 90), RGBColor(70, 8, 92), RGBColor(70, 10, 93), RGBColor(70, 11, 94),
+    RGBColor(71, 13, 96), RGBColor(71, 14, 97), RGBColor(71, 16, 99), RGBColor(71, 17, 100),
+    RGBColor(71, 19, 101), RGBColor(72, 20, 103), RGBColor(72, 22, 104), RGBColor(72, 23, 105),
+    RGBColor(72, 24, 106), RGBColor(72, 26, 108), RGBColor(72, 27, 109), RGBColor(72, 28, 110),
+    RGBColor(72, 29, 111), RGBColor(72, 31, 112), RGBColor(72, 32, 113), RGBColor(72, 33, 115),
+    RGBColor(72, 35, 116), RGBColor(72, 36, 117), RGBColor(72, 37, 118), RGBColor(72, 38, 119),
+    RGBColor(72, 40, 120), RGBColor(72, 41, 121), RGBColor(71, 42, 122), RGBColor(71, 44, 122),
+    RGBColor(71, 45, 123), RGBColor(71, 46, 124), RGBColor(71, 47, 125), RGBColor(70, 48, 126),
+    RGBColor(70, 50, 126), RGBColor(70, 51, 127), RGBColor(70, 52, 128), RGBColor(69, 53, 129),
+    RGBColor(69, 55, 129), RGBColor(69, 56, 130), RGBColor(68, 57, 131), RGBColor(68, 58, 131),
+    RGBColor(68, 59, 132), RGBColor(67, 61, 132), RGBColor(67, 62, 133), RGBColor(66, 63, 133),
+    RGBColor(66, 64, 134), RGBColor(66, 65, 134), RGBColor(65, 66, 135), RGBColor(65, 68, 135),
+    RGBColor(64, 69, 136), RGBColor(64, 70, 136), RGBColor(63, 71, 136), RGBColor(63, 72, 137),
+    RGBColor(62, 73, 137), RGBColor(62, 74, 137), RGBColor(62, 76, 138), RGBColor(61, 77, 138),
+    RGBColor(61, 78, 138), RGBColor(60, 79, 138), RGBColor(60, 80, 139), RGBColor(59, 81, 139),
+    RGBColor(59, 82, 139), RGBColor(58, 83, 139), RGBColor(58, 84, 140), RGBColor(57, 85, 140),
+    RGBColor(57, 86, 140), RGBColor(56, 88, 140), RGBColor(56, 89, 140), RGBColor(55, 90, 140),
+    RGBColor(55, 91, 141), RGBColor(54, 92, 141), RGBColor(54, 93, 141), RGBColor(53, 94, 141),
+    RGBColor(53, 95, 141), RGBColor(52, 96, 141), RGBColor(52, 97, 141), RGBColor(51, 98, 141),
+    RGBColor(51, 99, 141), RGBColor(50, 100, 142), RGBColor(50, 101, 142), RGBColor(49, 102, 142),
+    RGBColor(49, 103, 142), RGBColor(49, 104, 142), RGBColor(48, 105, 142), RGBColor(48, 106, 142),
+    RGBColor(47, 107, 142), RGBColor(47, 108, 142), RGBColor(46, 109, 142), RGBColor(46, 110, 142),
+    RGBColor(46, 111, 142), RGBColor(45, 112, 142), RGBColor(45, 113, 142), RGBColor(44, 113, 142),
+    RGBColor(44, 114, 142), RGBColor(44, 115, 142), RGBColor(43, 116, 142), RGBColor(43, 117, 142),
+    RGBColor(42, 118, 142), RGBColor(42, 119, 142), RGBColor(42, 120, 142), RGBColor(41, 121, 142),
+    RGBColor(41, 122, 142), RGBColor(41, 123, 142), RGBColor(40, 124, 142), RGBColor(40, 125, 142),
+    RGBColor(39, 126, 142), RGBColor(39, 127, 142), RGBColor(39, 128, 142), RGBColor(38, 129, 142),
+    RGBColor(38, 130, 142), RGBColor(38, 130, 142), RGBColor(37, 131, 142), RGBColor(37, 132, 142),
+    RGBColor(37, 133, 142), RGBColor(36, 134, 142), RGBColor(36, 135, 142), RGBColor(35, 136, 142),
+    RGBColor(35, 137, 142), RGBColor(35, 138, 141), RGBColor(34, 139, 141), RGBColor(34, 140, 141),
+    RGBColor(34, 141, 141), RGBColor(33, 142, 141), RGBColor(33, 143, 141), RGBColor(33, 144, 141),
+    RGBColor(33, 145, 140), RGBColor(32, 146, 140), RGBColor(32, 146, 140), RGBColor(32, 147, 140),
+    RGBColor(31, 148, 140), RGBColor(31, 149, 139), RGBColor(31, 150, 139), RGBColor(31, 151, 139),
+    RGBColor(31, 152, 139), RGBColor(31, 153, 138), RGBColor(31, 154, 138), RGBColor(30, 155, 138),
+    RGBColor(30, 156, 137), RGBColor(30, 157, 137), RGBColor(31, 158, 137), RGBColor(31, 159, 136),
+    RGBColor(31, 160, 136), RGBColor(31, 161, 136), RGBColor(31, 161, 135), RGBColor(31, 162, 135),
+    RGBColor(32, 163, 134), RGBColor(32, 164, 134), RGBColor(33, 165, 133), RGBColor(33, 166, 133),
+    RGBColor(34, 167, 133), RGBColor(34, 168, 132), RGBColor(35, 169, 131), RGBColor(36, 170, 131),
+    RGBColor(37, 171, 130), RGBColor(37, 172, 130), RGBColor(38, 173, 129), RGBColor(39, 173, 129),
+    RGBColor(40, 174, 128), RGBColor(41, 175, 127), RGBColor(42, 176, 127), RGBColor(44, 177, 126),
+    RGBColor(45, 178, 125), RGBColor(46, 179, 124), RGBColor(47, 180, 124), RGBColor(49, 181, 123),
+    RGBColor(50, 182, 122), RGBColor(52, 182, 121), RGBColor(53, 183, 121), RGBColor(55, 184, 120),
+    RGBColor(56, 185, 119), RGBColor(58, 186, 118), RGBColor(59, 187, 117), RGBColor(61, 188, 116),
+    RGBColor(63, 188, 115), RGBColor(64, 189, 114), RGBColor(66, 190, 113), RGBColor(68, 191, 112),
+    RGBColor(70, 192, 111), RGBColor(72, 193, 110), RGBColor(74, 193, 109), RGBColor(76, 194, 108),
+    RGBColor(78, 195, 107), RGBColor(80, 196, 106), RGBColor(82, 197, 105), RGBColor(84, 197, 104),
+    RGBColor(86, 198, 103), RGBColor(88, 199, 101), RGBColor(90, 200, 100), RGBColor(92, 200, 99),
+    RGBColor(94, 201, 98), RGBColor(96, 202, 96), RGBColor(99, 203, 95), RGBColor(101, 203, 94),
+    RGBColor(103, 204, 92), RGBColor(105, 205, 91), RGBColor(108, 205, 90), RGBColor(110, 206, 88),
+    RGBColor(112, 207, 87), RGBColor(115, 208, 86), RGBColor(117, 208, 84), RGBColor(119, 209, 83),
+    RGBColor(122, 209, 81), RGBColor(124, 210, 80), RGBColor(127, 211, 78), RGBColor(129, 211, 77),
+    RGBColor(132, 212, 75), RGBColor(134, 213, 73), RGBColor(137, 213, 72), RGBColor(139, 214, 70),
+    RGBColor(142, 214, 69), RGBColor(144, 215, 67), RGBColor(147, 215, 65), RGBColor(149, 216, 64),
+    RGBColor(152, 216, 62), RGBColor(155, 217, 60), RGBColor(157, 217, 59), RGBColor(160, 218, 57),
+    RGBColor(162, 218, 55), RGBColor(165, 219, 54), RGBColor(168, 219, 52), RGBColor(170, 220, 50),
+    RGBColor(173, 220, 48), RGBColor(176, 221, 47), RGBColor(178, 221, 45), RGBColor(181, 222, 43),
+    RGBColor(184, 222, 41), RGBColor(186, 222, 40), RGBColor(189, 223, 38), RGBColor(192, 223, 37),
+    RGBColor(194, 223, 35), RGBColor(197, 224, 33), RGBColor(200, 224, 32), RGBColor(202, 225, 31),
+    RGBColor(205, 225, 29), RGBColor(208, 225, 28), RGBColor(210, 226, 27), RGBColor(213, 226, 26),
+    RGBColor(216, 226, 25), RGBColor(218, 227, 25), RGBColor(221, 227, 24), RGBColor(223, 227, 24),
+    RGBColor(226, 228, 24), RGBColor(229, 228, 25), RGBColor(231, 228, 25), RGBColor(234, 229, 26),
+    RGBColor(236, 229, 27), RGBColor(239, 229, 28), RGBColor(241, 229, 29), RGBColor(244, 230, 30),
+    RGBColor(246, 230, 32), RGBColor(248, 230, 33), RGBColor(251, 231, 35), RGBColor(253, 231, 37),
+];
+
+#[rustfmt::skip]
+pub(super) const MAGMA: [RGBColor; 256] = [
+    RGBColor(0, 0, 4), RGBColor(1, 0, 5), RGBColor(1, 1, 6), RGBColor(1, 1, 8),
+    RGBColor(2, 1, 9), RGBColor(2, 2, 11), RGBColor(2, 2, 13), RGBColor(3, 3, 15),
+    RGBColor(3, 3, 18), RGBColor(4, 4, 20), RGBColor(5, 4, 22), RGBColor(6, 5, 24),
+    RGBColor(6, 5, 26), RGBColor(7, 6, 28), RGBColor(8, 7, 30), RGBColor(9, 7, 32),
+    RGBColor(10, 8, 34), RGBColor(11, 9, 36), RGBColor(12, 9, 38), RGBColor(13, 10, 41),
+    RGBColor(14, 11, 43), RGBColor(16, 11, 45), RGBColor(17, 12, 47), RGBColor(18, 13, 49),
+    RGBColor(19, 13, 52), RGBColor(20, 14, 54), RGBColor(21, 14, 56), RGBColor(22, 15, 59),
+    RGBColor(24, 15, 61), RGBColor(25, 16, 63), RGBColor(26, 16, 66), RGBColor(28, 16, 68),
+    RGBColor(29, 17, 71), RGBColor(30, 17, 73), RGBColor(32, 17, 75), RGBColor(33, 17, 78),
+    RGBColor(34, 17, 80), RGBColor(36, 18, 83), RGBColor(37, 18, 85), RGBColor(39, 18, 88),
+    RGBColor(41, 17, 90), RGBColor(42, 17, 92), RGBColor(44, 17, 95), RGBColor(45, 17, 97),
+    RGBColor(47, 17, 99), RGBColor(49, 17, 101), RGBColor(51, 16, 103), RGBColor(52, 16, 105),
+    RGBColor(54, 16, 107), RGBColor(56, 16, 108), RGBColor(57, 15, 110), RGBColor(59, 15, 112),
+    RGBColor(61, 15, 113), RGBColor(63, 15, 114), RGBColor(64, 15, 116), RGBColor(66, 15, 117),
+    RGBColor(68, 15, 118), RGBColor(69, 16, 119), RGBColor(71, 16, 120), RGBColor(73, 16, 120),
+    RGBColor(74, 16, 121), RGBColor(76, 17, 122), RGBColor(78, 17, 123), RGBColor(79, 18, 123),
+    RGBColor(81, 18, 124), RGBColor(82, 19, 124), RGBColor(84, 19, 125), RGBColor(86, 20, 125),
+    RGBColor(87, 21, 126), RGBColor(89, 21, 126), RGBColor(90, 22, 126), RGBColor(92, 22, 127),
+    RGBColor(93, 23, 127), RGBColor(95, 24, 127), RGBColor(96, 24, 128), RGBColor(98, 25, 128),
+    RGBColor(100, 26, 128), RGBColor(101, 26, 128), RGBColor(103, 27, 128), RGBColor(104, 28, 129),
+    RGBColor(106, 28, 129), RGBColor(107, 29, 129), RGBColor(109, 29, 129), RGBColor(110, 30, 129),
+    RGBColor(112, 31, 129), RGBColor(114, 31, 129), RGBColor(115, 32, 129), RGBColor(117, 33, 129),
+    RGBColor(118, 33, 129), RGBColor(120, 34, 129), RGBColor(121, 34, 130), RGBColor(123, 35, 130),
+    RGBColor(124, 35, 130), RGBColor(126, 36, 130), RGBColor(128, 37, 130), RGBColor(129, 37, 129),
+    RGBColor(131, 38, 129), RGBColor(132, 38, 129), RGBColor(134, 39, 129), RGBColor(136, 39, 129),
+    RGBColor(137, 40, 129), RGBColor(139, 41, 129), RGBColor(140, 41, 129), RGBColor(142, 42, 129),
+    RGBColor(144, 42, 129), RGBColor(145, 43, 129), RGBColor(147, 43, 128), RGBColor(148, 44, 128),
+    RGBColor(150, 44, 128), RGBColor(152, 45, 128), RGBColor(153, 45, 128), RGBColor(155, 46, 127),
+    RGBColor(156, 46, 127), RGBColor(158, 47, 127), RGBColor(160, 47, 127), RGBColor(161, 48, 126),
+    RGBColor(163, 48, 126), RGBColor(165, 49, 126), RGBColor(166, 49, 125), RGBColor(168, 50, 125),
+    RGBColor(170, 51, 125), RGBColor(171, 51, 124), RGBColor(173, 52, 124), RGBColor(174, 52, 123),
+    RGBColor(176, 53, 123), RGBColor(178, 53, 123), RGBColor(179, 54, 122), RGBColor(181, 54, 122),
+    RGBColor(183, 55, 121), RGBColor(184, 55, 121), RGBColor(186, 56, 120), RGBColor(188, 57, 120),
+    RGBColor(189, 57, 119), RGBColor(191, 58, 119), RGBColor(192, 58, 118), RGBColor(194, 59, 117),
+    RGBColor(196, 60, 117), RGBColor(197, 60, 116), RGBColor(199, 61, 115), RGBColor(200, 62, 115),
+    RGBColor(202, 62, 114), RGBColor(204, 63, 113), RGBColor(205, 64, 113), RGBColor(207, 64, 112),
+    RGBColor(208, 65, 111), RGBColor(210, 66, 111), RGBColor(211, 67, 110), RGBColor(213, 68, 109),
+    RGBColor(214, 69, 108), RGBColor(216, 69, 108), RGBColor(217, 70, 107), RGBColor(219, 71, 106),
+    RGBColor(220, 72, 105), RGBColor(222, 73, 104), RGBColor(223, 74, 104), RGBColor(224, 76, 103),
+    RGBColor(226, 77, 102), RGBColor(227, 78, 101), RGBColor(228, 79, 100), RGBColor(229, 80, 100),
+    RGBColor(231, 82, 99), RGBColor(232, 83, 98), RGBColor(233, 84, 98), RGBColor(234, 86, 97),
+    RGBColor(235, 87, 96), RGBColor(236, 88, 96), RGBColor(237, 90, 95), RGBColor(238, 91, 94),
+    RGBColor(239, 93, 94), RGBColor(240, 95, 94), RGBColor(241, 96, 93), RGBColor(242, 98, 93),
+    RGBColor(242, 100, 92), RGBColor(243, 101, 92), RGBColor(244, 103, 92), RGBColor(244, 105, 92),
+    RGBColor(245, 107, 92), RGBColor(246, 108, 92), RGBColor(246, 110, 92), RGBColor(247, 112, 92),
+    RGBColor(247, 114, 92), RGBColor(248, 116, 92), RGBColor(248, 118, 92), RGBColor(249, 120, 93),
+    RGBColor(249, 121, 93), RGBColor(249, 123, 93), RGBColor(250, 125, 94), RGBColor(250, 127, 94),
+    RGBColor(250, 129, 95), RGBColor(251, 131, 95), RGBColor(251, 133, 96), RGBColor(251, 135, 97),
+    RGBColor(252, 137, 97), RGBColor(252, 138, 98), RGBColor(252, 140, 99), RGBColor(252, 142, 100),
+    RGBColor(252, 144, 101), RGBColor(253, 146, 102), RGBColor(253, 148, 103), RGBColor(253, 150, 104),
+    RGBColor(253, 152, 105), RGBColor(253, 154, 106), RGBColor(253, 155, 107), RGBColor(254, 157, 108),
+    RGBColor(254, 159, 109), RGBColor(254, 161, 110), RGBColor(254, 163, 111), RGBColor(254, 165, 113),
+    RGBColor(254, 167, 114), RGBColor(254, 169, 115), RGBColor(254, 170, 116), RGBColor(254, 172, 118),
+    RGBColor(254, 174, 119), RGBColor(254, 176, 120), RGBColor(254, 178, 122), RGBColor(254, 180, 123),
+    RGBColor(254, 182, 124), RGBColor(254, 183, 126), RGBColor(254, 185, 127), RGBColor(254, 187, 129),
+    RGBColor(254, 189, 130), RGBColor(254, 191, 132), RGBColor(254, 193, 133), RGBColor(254, 194, 135),
+    RGBColor(254, 196, 136), RGBColor(254, 198, 138), RGBColor(254, 200, 140), RGBColor(254, 202, 141),
+    RGBColor(254, 204, 143), RGBColor(254, 205, 144), RGBColor(254, 207, 146), RGBColor(254, 209, 148),
+    RGBColor(254, 211, 149), RGBColor(254, 213, 151), RGBColor(254, 215, 153), RGBColor(254, 216, 154),
+    RGBColor(253, 218, 156), RGBColor(253, 220, 158), RGBColor(253, 222, 160), RGBColor(253, 224, 161),
+    RGBColor(253, 226, 163), RGBColor(253, 227, 165), RGBColor(253, 229, 167), RGBColor(253, 231, 169),
+    RGBColor(253, 233, 170), RGBColor(253, 235, 172), RGBColor(252, 236, 174), RGBColor(252, 238, 176),
+    RGBColor(252, 240, 178), RGBColor(252, 242, 180), RGBColor(252, 244, 182), RGBColor(252, 246, 184),
+    RGBColor(252, 247, 185), RGBColor(252, 249, 187), RGBColor(252, 251, 189), RGBColor(252, 253, 191),
+];
+
+#[rustfmt::skip]
+pub(super) const INFERNO: [RGBColor; 256] = [
+    RGBColor(0, 0, 4), RGBColor(1, 0, 5), RGBColor(1, 1, 6), RGBColor(1, 1, 8),
+    RGBColor(2, 1, 10), RGBColor(2, 2, 12), RGBColor(2, 2, 14), RGBColor(3, 2, 16),
+    RGBColor(4, 3, 18), RGBColor(4, 3, 20), RGBColor(5, 4, 23), RGBColor(6, 4, 25),
+    RGBColor(7, 5, 27), RGBColor(8, 5, 29), RGBColor(9, 6, 31), RGBColor(10, 7, 34),
+    RGBColor(11, 7, 36), RGBColor(12, 8, 38), RGBColor(13, 8, 41), RGBColor(14, 9, 43),
+    RGBColor(16, 9, 45), RGBColor(17, 10, 48), RGBColor(18, 10, 50), RGBColor(20, 11, 52),
+    RGBColor(21, 11, 55), RGBColor(22, 11, 57), RGBColor(24, 12, 60), RGBColor(25, 12, 62),
+    RGBColor(27, 12, 65), RGBColor(28, 12, 67), RGBColor(30, 12, 69), RGBColor(31, 12, 72),
+    RGBColor(33, 12, 74), RGBColor(35, 12, 76), RGBColor(36, 12, 79), RGBColor(38, 12, 81),
+    RGBColor(40, 11, 83), RGBColor(41, 11, 85), RGBColor(43, 11, 87), RGBColor(45, 11, 89),
+    RGBColor(47, 10, 91), RGBColor(49, 10, 92), RGBColor(50, 10, 94), RGBColor(52, 10, 95),
+    RGBColor(54, 9, 97), RGBColor(56, 9, 98), RGBColor(57, 9, 99), RGBColor(59, 9, 100),
+    RGBColor(61, 9, 101), RGBColor(62, 9, 102), RGBColor(64, 10, 103), RGBColor(66, 10, 104),
+    RGBColor(68, 10, 104), RGBColor(69, 10, 105), RGBColor(71, 11, 106), RGBColor(73, 11, 106),
+    RGBColor(74, 12, 107), RGBColor(76, 12, 107), RGBColor(77, 13, 108), RGBColor(79, 13, 108),
+    RGBColor(81, 14, 108), RGBColor(82, 14, 109), RGBColor(84, 15, 109), RGBColor(85, 15, 109),
+    RGBColor(87, 16, 110), RGBColor(89, 16, 110), RGBColor(90, 17, 110), RGBColor(92, 18, 110),
+    RGBColor(93, 18, 110), RGBColor(95, 19, 110), RGBColor(97, 19, 110), RGBColor(98, 20, 110),
+    RGBColor(100, 21, 110), RGBColor(101, 21, 110), RGBColor(103, 22, 110), RGBColor(105, 22, 110),
+    RGBColor(106, 23, 110), RGBColor(108, 24, 110), RGBColor(109, 24, 110), RGBColor(111, 25, 110),
+    RGBColor(113, 25, 110), RGBColor(114, 26, 110), RGBColor(116, 26, 110), RGBColor(117, 27, 110),
+    RGBColor(119, 28, 109), RGBColor(120, 28, 109), RGBColor(122, 29, 109), RGBColor(124, 29, 109),
+    RGBColor(125, 30, 109), RGBColor(127, 30, 108), RGBColor(128, 31, 108), RGBColor(130, 32, 108),
+    RGBColor(132, 32, 107), RGBColor(133, 33, 107), RGBColor(135, 33, 107), RGBColor(136, 34, 106),
+    RGBColor(138, 34, 106), RGBColor(140, 35, 105), RGBColor(141, 35, 105), RGBColor(143, 36, 105),
+    RGBColor(144, 37, 104), RGBColor(146, 37, 104), RGBColor(147, 38, 103), RGBColor(149, 38, 103),
+    RGBColor(151, 39, 102), RGBColor(152, 39, 102), RGBColor(154, 40, 101), RGBColor(155, 41, 100),
+    RGBColor(157, 41, 100), RGBColor(159, 42, 99), RGBColor(160, 42, 99), RGBColor(162, 43, 98),
+    RGBColor(163, 44, 97), RGBColor(165, 44, 96), RGBColor(166, 45, 96), RGBColor(168, 46, 95),
+    RGBColor(169, 46, 94), RGBColor(171, 47, 94), RGBColor(173, 48, 93), RGBColor(174, 48, 92),
+    RGBColor(176, 49, 91), RGBColor(177, 50, 90), RGBColor(179, 50, 90), RGBColor(180, 51, 89),
+    RGBColor(182, 52, 88), RGBColor(183, 53, 87), RGBColor(185, 53, 86), RGBColor(186, 54, 85),
+    RGBColor(188, 55, 84), RGBColor(189, 56, 83), RGBColor(191, 57, 82), RGBColor(192, 58, 81),
+    RGBColor(193, 58, 80), RGBColor(195, 59, 79), RGBColor(196, 60, 78), RGBColor(198, 61, 77),
+    RGBColor(199, 62, 76), RGBColor(200, 63, 75), RGBColor(202, 64, 74), RGBColor(203, 65, 73),
+    RGBColor(204, 66, 72), RGBColor(206, 67, 71), RGBColor(207, 68, 70), RGBColor(208, 69, 69),
+    RGBColor(210, 70, 68), RGBColor(211, 71, 67), RGBColor(212, 72, 66), RGBColor(213, 74, 65),
+    RGBColor(215, 75, 63), RGBColor(216, 76, 62), RGBColor(217, 77, 61), RGBColor(218, 78, 60),
+    RGBColor(219, 80, 59), RGBColor(221, 81, 58), RGBColor(222, 82, 56), RGBColor(223, 83, 55),
+    RGBColor(224, 85, 54), RGBColor(225, 86, 53), RGBColor(226, 87, 52), RGBColor(227, 89, 51),
+    RGBColor(228, 90, 49), RGBColor(229, 92, 48), RGBColor(230, 93, 47), RGBColor(231, 94, 46),
+    RGBColor(232, 96, 45), RGBColor(233, 97, 43), RGBColor(234, 99, 42), RGBColor(235, 100, 41),
+    RGBColor(235, 102, 40), RGBColor(236, 103, 38), RGBColor(237, 105, 37), RGBColor(238, 106, 36),
+    RGBColor(239, 108, 35), RGBColor(239, 110, 33), RGBColor(240, 111, 32), RGBColor(241, 113, 31),
+    RGBColor(241, 115, 29), RGBColor(242, 116, 28), RGBColor(243, 118, 27), RGBColor(243, 120, 25),
+    RGBColor(244, 121, 24), RGBColor(245, 123, 23), RGBColor(245, 125, 21), RGBColor(246, 126, 20),
+    RGBColor(246, 128, 19), RGBColor(247, 130, 18), RGBColor(247, 132, 16), RGBColor(248, 133, 15),
+    RGBColor(248, 135, 14), RGBColor(248, 137, 12), RGBColor(249, 139, 11), RGBColor(249, 140, 10),
+    RGBColor(249, 142, 9), RGBColor(250, 144, 8), RGBColor(250, 146, 7), RGBColor(250, 148, 7),
+    RGBColor(251, 150, 6), RGBColor(251, 151, 6), RGBColor(251, 153, 6), RGBColor(251, 155, 6),
+    RGBColor(251, 157, 7), RGBColor(252, 159, 7), RGBColor(252, 161, 8), RGBColor(252, 163, 9),
+    RGBColor(252, 165, 10), RGBColor(252, 166, 12), RGBColor(252, 168, 13), RGBColor(252, 170, 15),
+    RGBColor(252, 172, 17), RGBColor(252, 174, 18), RGBColor(252, 176, 20), RGBColor(252, 178, 22),
+    RGBColor(252, 180, 24), RGBColor(251, 182, 26), RGBColor(251, 184, 29), RGBColor(251, 186, 31),
+    RGBColor(251, 188, 33), RGBColor(251, 190, 35), RGBColor(250, 192, 38), RGBColor(250, 194, 40),
+    RGBColor(250, 196, 42), RGBColor(250, 198, 45), RGBColor(249, 199, 47), RGBColor(249, 201, 50),
+    RGBColor(249, 203, 53), RGBColor(248, 205, 55), RGBColor(248, 207, 58), RGBColor(247, 209, 61),
+    RGBColor(247, 211, 64), RGBColor(246, 213, 67), RGBColor(246, 215, 70), RGBColor(245, 217, 73),
+    RGBColor(245, 219, 76), RGBColor(244, 221, 79), RGBColor(244, 223, 83), RGBColor(244, 225, 86),
+    RGBColor(243, 227, 90), RGBColor(243, 229, 93), RGBColor(242, 230, 97), RGBColor(242, 232, 101),
+    RGBColor(242, 234, 105), RGBColor(241, 236, 109), RGBColor(241, 237, 113), RGBColor(241, 239, 117),
+    RGBColor(241, 241, 121), RGBColor(242, 242, 125), RGBColor(242, 244, 130), RGBColor(243, 245, 134),
+    RGBColor(243, 246, 138), RGBColor(244, 248, 142), RGBColor(245, 249, 146), RGBColor(246, 250, 150),
+    RGBColor(248, 251, 154), RGBColor(249, 252, 157), RGBColor(250, 253, 161), RGBColor(252, 255, 164),
+];
+
+#[rustfmt::skip]
+pub(super) const PLASMA: [RGBColor; 256] = [
+    RGBColor(13, 8, 135), RGBColor(16, 7, 136), RGBColor(19, 7, 137), RGBColor(22, 7, 138),
+    RGBColor(25, 6, 140), RGBColor(27, 6, 141), RGBColor(29, 6, 142), RGBColor(32, 6, 143),
+    RGBColor(34, 6, 144), RGBColor(36, 6, 145), RGBColor(38, 5, 145), RGBColor(40, 5, 146),
+    RGBColor(42, 5, 147), RGBColor(44, 5, 148), RGBColor(46, 5, 149), RGBColor(47, 5, 150),
+    RGBColor(49, 5, 151), RGBColor(51, 5, 151), RGBColor(53, 4, 152), RGBColor(55, 4, 153),
+    RGBColor(56, 4, 154), RGBColor(58, 4, 154), RGBColor(60, 4, 155), RGBColor(62, 4, 156),
+    RGBColor(63, 4, 156), RGBColor(65, 4, 157), RGBColor(67, 3, 158), RGBColor(68, 3, 158),
+    RGBColor(70, 3, 159), RGBColor(72, 3, 159), RGBColor(73, 3, 160), RGBColor(75, 3, 161),
+    RGBColor(76, 2, 161), RGBColor(78, 2, 162), RGBColor(80, 2, 162), RGBColor(81, 2, 163),
+    RGBColor(83, 2, 163), RGBColor(85, 2, 164), RGBColor(86, 1, 164), RGBColor(88, 1, 164),
+    RGBColor(89, 1, 165), RGBColor(91, 1, 165), RGBColor(92, 1, 166), RGBColor(94, 1, 166),
+    RGBColor(96, 1, 166), RGBColor(97, 0, 167), RGBColor(99, 0, 167), RGBColor(100, 0, 167),
+    RGBColor(102, 0, 167), RGBColor(103, 0, 168), RGBColor(105, 0, 168), RGBColor(106, 0, 168),
+    RGBColor(108, 0, 168), RGBColor(110, 0, 168), RGBColor(111, 0, 168), RGBColor(113, 0, 168),
+    RGBColor(114, 1, 168), RGBColor(116, 1, 168), RGBColor(117, 1, 168), RGBColor(119, 1, 168),
+    RGBColor(120, 1, 168), RGBColor(122, 2, 168), RGBColor(123, 2, 168), RGBColor(125, 3, 168),
+    RGBColor(126, 3, 168), RGBColor(128, 4, 168), RGBColor(129, 4, 167), RGBColor(131, 5, 167),
+    RGBColor(132, 5, 167), RGBColor(134, 6, 166), RGBColor(135, 7, 166), RGBColor(136, 8, 166),
+    RGBColor(138, 9, 165), RGBColor(139, 10, 165), RGBColor(141, 11, 165), RGBColor(142, 12, 164),
+    RGBColor(143, 13, 164), RGBColor(145, 14, 163), RGBColor(146, 15, 163), RGBColor(148, 16, 162),
+    RGBColor(149, 17, 161), RGBColor(150, 19, 161), RGBColor(152, 20, 160), RGBColor(153, 21, 159),
+    RGBColor(154, 22, 159), RGBColor(156, 23, 158), RGBColor(157, 24, 157), RGBColor(158, 25, 157),
+    RGBColor(160, 26, 156), RGBColor(161, 27, 155), RGBColor(162, 29, 154), RGBColor(163, 30, 154),
+    RGBColor(165, 31, 153), RGBColor(166, 32, 152), RGBColor(167, 33, 151), RGBColor(168, 34, 150),
+    RGBColor(170, 35, 149), RGBColor(171, 36, 148), RGBColor(172, 38, 148), RGBColor(173, 39, 147),
+    RGBColor(174, 40, 146), RGBColor(176, 41, 145), RGBColor(177, 42, 144), RGBColor(178, 43, 143),
+    RGBColor(179, 44, 142), RGBColor(180, 46, 141), RGBColor(181, 47, 140), RGBColor(182, 48, 139),
+    RGBColor(183, 49, 138), RGBColor(184, 50, 137), RGBColor(186, 51, 136), RGBColor(187, 52, 136),
+    RGBColor(188, 53, 135), RGBColor(189, 55, 134), RGBColor(190, 56, 133), RGBColor(191, 57, 132),
+    RGBColor(192, 58, 131), RGBColor(193, 59, 130), RGBColor(194, 60, 129), RGBColor(195, 61, 128),
+    RGBColor(196, 62, 127), RGBColor(197, 64, 126), RGBColor(198, 65, 125), RGBColor(199, 66, 124),
+    RGBColor(200, 67, 123), RGBColor(201, 68, 122), RGBColor(202, 69, 122), RGBColor(203, 70, 121),
+    RGBColor(204, 71, 120), RGBColor(204, 73, 119), RGBColor(205, 74, 118), RGBColor(206, 75, 117),
+    RGBColor(207, 76, 116), RGBColor(208, 77, 115), RGBColor(209, 78, 114), RGBColor(210, 79, 113),
+    RGBColor(211, 81, 113), RGBColor(212, 82, 112), RGBColor(213, 83, 111), RGBColor(213, 84, 110),
+    RGBColor(214, 85, 109), RGBColor(215, 86, 108), RGBColor(216, 87, 107), RGBColor(217, 88, 106),
+    RGBColor(218, 90, 106), RGBColor(218, 91, 105), RGBColor(219, 92, 104), RGBColor(220, 93, 103),
+    RGBColor(221, 94, 102), RGBColor(222, 95, 101), RGBColor(222, 97, 100), RGBColor(223, 98, 99),
+    RGBColor(224, 99, 99), RGBColor(225, 100, 98), RGBColor(226, 101, 97), RGBColor(226, 102, 96),
+    RGBColor(227, 104, 95), RGBColor(228, 105, 94), RGBColor(229, 106, 93), RGBColor(229, 107, 93),
+    RGBColor(230, 108, 92), RGBColor(231, 110, 91), RGBColor(231, 111, 90), RGBColor(232, 112, 89),
+    RGBColor(233, 113, 88), RGBColor(233, 114, 87), RGBColor(234, 116, 87), RGBColor(235, 117, 86),
+    RGBColor(235, 118, 85), RGBColor(236, 119, 84), RGBColor(237, 121, 83), RGBColor(237, 122, 82),
+    RGBColor(238, 123, 81), RGBColor(239, 124, 81), RGBColor(239, 126, 80), RGBColor(240, 127, 79),
+    RGBColor(240, 128, 78), RGBColor(241, 129, 77), RGBColor(241, 131, 76), RGBColor(242, 132, 75),
+    RGBColor(243, 133, 75), RGBColor(243, 135, 74), RGBColor(244, 136, 73), RGBColor(244, 137, 72),
+    RGBColor(245, 139, 71), RGBColor(245, 140, 70), RGBColor(246, 141, 69), RGBColor(246, 143, 68),
+    RGBColor(247, 144, 68), RGBColor(247, 145, 67), RGBColor(247, 147, 66), RGBColor(248, 148, 65),
+    RGBColor(248, 149, 64), RGBColor(249, 151, 63), RGBColor(249, 152, 62), RGBColor(249, 154, 62),
+    RGBColor(250, 155, 61), RGBColor(250, 156, 60), RGBColor(250, 158, 59), RGBColor(251, 159, 58),
+    RGBColor(251, 161, 57), RGBColor(251, 162, 56), RGBColor(252, 163, 56), RGBColor(252, 165, 55),
+    RGBColor(252, 166, 54), RGBColor(252, 168, 53), RGBColor(252, 169, 52), RGBColor(253, 171, 51),
+    RGBColor(253, 172, 51), RGBColor(253, 174, 50), RGBColor(253, 175, 49), RGBColor(253, 177, 48),
+    RGBColor(253, 178, 47), RGBColor(253, 180, 47), RGBColor(253, 181, 46), RGBColor(254, 183, 45),
+    RGBColor(254, 184, 44), RGBColor(254, 186, 44), RGBColor(254, 187, 43), RGBColor(254, 189, 42),
+    RGBColor(254, 190, 42), RGBColor(254, 192, 41), RGBColor(253, 194, 41), RGBColor(253, 195, 40),
+    RGBColor(253, 197, 39), RGBColor(253, 198, 39), RGBColor(253, 200, 39), RGBColor(253, 202, 38),
+    RGBColor(253, 203, 38), RGBColor(252, 205, 37), RGBColor(252, 206, 37), RGBColor(252, 208, 37),
+    RGBColor(252, 210, 37), RGBColor(251, 211, 36), RGBColor(251, 213, 36), RGBColor(251, 215, 36),
+    RGBColor(250, 216, 36), RGBColor(250, 218, 36), RGBColor(249, 220, 36), RGBColor(249, 221, 37),
+    RGBColor(248, 223, 37), RGBColor(248, 225, 37), RGBColor(247, 226, 37), RGBColor(247, 228, 37),
+    RGBColor(246, 230, 38), RGBColor(246, 232, 38), RGBColor(245, 233, 38), RGBColor(245, 235, 39),
+    RGBColor(244, 237, 39), RGBColor(243, 238, 39), RGBColor(243, 240, 39), RGBColor(242, 242, 39),
+    RGBColor(241, 244, 38), RGBColor(241, 245, 37), RGBColor(240, 247, 36), RGBColor(240, 249, 33),
+];
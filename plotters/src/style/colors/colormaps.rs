@@ -1,3 +1,4 @@
+use super::colormap_data::{opaque, INFERNO, MAGMA, PLASMA, VIRIDIS};
 use crate::style::{HSLColor, RGBAColor, RGBColor};
 
 /// Converts scalar values to colors.
@@ -294,38 +295,58 @@ macro_rules! def_linear_colormap{
     }
 }
 
-def_linear_colormap! {
-    ViridisRGBA,
-    RGBAColor,
-    "A colormap optimized for visually impaired people (RGBA format).
-    It is currently the default colormap also used by [matplotlib](https://matplotlib.org/stable/tutorials/colors/colormaps.html).
-    Read more in this [paper](https://doi.org/10.1371/journal.pone.0199239)",
-    ( 68,   1,  84, 1.0),
-    ( 70,  50, 127, 1.0),
-    ( 54,  92, 141, 1.0),
-    ( 39, 127, 143, 1.0),
-    ( 31, 162, 136, 1.0),
-    ( 74, 194, 110, 1.0),
-    (160, 219,  57, 1.0),
-    (254, 232,  37, 1.0)
+/// A colormap optimized for visually impaired people (RGBA format).
+/// It is currently the default colormap also used by [matplotlib](https://matplotlib.org/stable/tutorials/colors/colormaps.html).
+/// Read more in this [paper](https://doi.org/10.1371/journal.pone.0199239)
+pub struct ViridisRGBA;
+
+impl ViridisRGBA {
+    const COLORS: [RGBAColor; 256] = opaque(VIRIDIS);
 }
 
-def_linear_colormap! {
-    ViridisRGB,
-    RGBColor,
-    "A colormap optimized for visually impaired people (RGB Format).
-    It is currently the default colormap also used by [matplotlib](https://matplotlib.org/stable/tutorials/colors/colormaps.html).
-    Read more in this [paper](https://doi.org/10.1371/journal.pone.0199239)",
-    ( 68,   1,  84),
-    ( 70,  50, 127),
-    ( 54,  92, 141),
-    ( 39, 127, 143),
-    ( 31, 162, 136),
-    ( 74, 194, 110),
-    (160, 219,  57),
-    (254, 232,  37)
+implement_linear_interpolation_color_map! {ViridisRGBA, RGBAColor}
+
+/// A colormap optimized for visually impaired people (RGB Format).
+/// It is currently the default colormap also used by [matplotlib](https://matplotlib.org/stable/tutorials/colors/colormaps.html).
+/// Read more in this [paper](https://doi.org/10.1371/journal.pone.0199239)
+pub struct ViridisRGB;
+
+impl ViridisRGB {
+    const COLORS: [RGBColor; 256] = VIRIDIS;
 }
 
+implement_linear_interpolation_color_map! {ViridisRGB, RGBColor}
+
+/// A perceptually uniform colormap going from black over purple and red to pale yellow (RGB Format).
+/// It is part of the same family as [ViridisRGB] in [matplotlib](https://matplotlib.org/stable/tutorials/colors/colormaps.html).
+pub struct MagmaRGB;
+
+impl MagmaRGB {
+    const COLORS: [RGBColor; 256] = MAGMA;
+}
+
+implement_linear_interpolation_color_map! {MagmaRGB, RGBColor}
+
+/// A perceptually uniform colormap going from black over purple and orange to light yellow (RGB Format).
+/// It is part of the same family as [ViridisRGB] in [matplotlib](https://matplotlib.org/stable/tutorials/colors/colormaps.html).
+pub struct InfernoRGB;
+
+impl InfernoRGB {
+    const COLORS: [RGBColor; 256] = INFERNO;
+}
+
+implement_linear_interpolation_color_map! {InfernoRGB, RGBColor}
+
+/// A perceptually uniform colormap going from dark blue over magenta and orange to yellow (RGB Format).
+/// It is part of the same family as [ViridisRGB] in [matplotlib](https://matplotlib.org/stable/tutorials/colors/colormaps.html).
+pub struct PlasmaRGB;
+
+impl PlasmaRGB {
+    const COLORS: [RGBColor; 256] = PLASMA;
+}
+
+implement_linear_interpolation_color_map! {PlasmaRGB, RGBColor}
+
 def_linear_colormap! {
     BlackWhite,
     RGBColor,
@@ -368,3 +389,51 @@ def_linear_colormap! {
     BROWN,
     ORANGE
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::RGBColor;
+
+    #[test]
+    fn test_viridis_endpoints() {
+        assert_eq!(ViridisRGB::get_color(0.0), RGBColor(68, 1, 84));
+        assert_eq!(ViridisRGB::get_color(1.0), RGBColor(253, 231, 37));
+        assert_eq!(ViridisRGBA::get_color(1.0), RGBAColor(253, 231, 37, 1.0));
+    }
+
+    #[test]
+    fn test_perceptual_colormaps_hit_every_color() {
+        // Each of the 256 colors is at its own fraction of the range, with no interpolation
+        for (index, color) in VIRIDIS.iter().enumerate() {
+            assert_eq!(ViridisRGB::get_color(index as f64 / 255.0), *color);
+        }
+        assert_eq!(MagmaRGB::get_color(128.0 / 255.0), MAGMA[128]);
+        assert_eq!(InfernoRGB::get_color(64.0 / 255.0), INFERNO[64]);
+        assert_eq!(PlasmaRGB::get_color(192.0 / 255.0), PLASMA[192]);
+    }
+
+    #[test]
+    fn test_perceptual_colormaps() {
+        assert_eq!(MagmaRGB::get_color(0.0), RGBColor(0, 0, 4));
+        assert_eq!(MagmaRGB::get_color(1.0), RGBColor(252, 253, 191));
+        assert_eq!(InfernoRGB::get_color(0.0), RGBColor(0, 0, 4));
+        assert_eq!(InfernoRGB::get_color(1.0), RGBColor(252, 255, 164));
+        assert_eq!(PlasmaRGB::get_color(0.0), RGBColor(13, 8, 135));
+        assert_eq!(PlasmaRGB::get_color(1.0), RGBColor(240, 249, 33));
+
+        // The values out of the bounds take the color of the closest bound
+        assert_eq!(
+            PlasmaRGB::get_color_normalized(-5.0, 0.0, 10.0),
+            RGBColor(13, 8, 135)
+        );
+        assert_eq!(
+            MagmaRGB::get_color_normalized(20.0, 0.0, 10.0),
+            RGBColor(252, 253, 191)
+        );
+        assert_eq!(
+            InfernoRGB::get_color_normalized(5.0, 0.0, 10.0),
+            InfernoRGB::get_color(0.5)
+        );
+    }
+}
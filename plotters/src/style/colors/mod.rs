@@ -56,6 +56,8 @@ define_color!(CYAN, 0, 255, 255, "Cyan");
 define_color!(MAGENTA, 255, 0, 255, "Magenta");
 define_color!(TRANSPARENT, 0, 0, 0, 0.0, "Transparent");
 
+#[cfg(feature = "colormaps")]
+mod colormap_data;
 #[cfg(feature = "colormaps")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "colormaps")))]
 /// Colormaps can be used to simply go from a scalar value to a color value which will be more/less
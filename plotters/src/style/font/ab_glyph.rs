@@ -98,6 +98,18 @@ impl FontData for FontDataInternal {
                 .clone(),
         })
     }
+    fn has_glyph(&self, c: char) -> bool {
+        // The glyph 0 is the one drawn for the missing characters
+        self.font_ref.glyph_id(c).0 != 0
+    }
+    fn vertical_metrics(&self, size: f64) -> (f64, f64, f64) {
+        let font = self.font_ref.as_scaled(size as f32);
+        (
+            f64::from(font.ascent()),
+            -f64::from(font.descent()),
+            f64::from(font.line_gap()),
+        )
+    }
     // TODO: ngl, it makes no sense that this uses the same error type as `new`
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType> {
         let pixel_per_em = size / 1.24;
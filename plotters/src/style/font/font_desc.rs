@@ -3,8 +3,9 @@ use crate::style::text_anchor::Pos;
 use crate::style::{Color, TextStyle};
 
 use std::convert::From;
+use std::path::Path;
 
-pub use plotters_backend::{FontFamily, FontStyle, FontTransform};
+pub use plotters_backend::{FontFamily, FontStyle, FontTransform, TextMetrics};
 
 /// The error type for the font implementation
 pub type FontError = <FontDataInternal as FontData>::ErrorType;
@@ -19,7 +20,10 @@ pub struct FontDesc<'a> {
     family: FontFamily<'a>,
     data: FontResult<FontDataInternal>,
     transform: FontTransform,
+    rotation: f64,
     style: FontStyle,
+    fallback_families: Vec<FontFamily<'a>>,
+    fallbacks: Vec<FontDataInternal>,
 }
 
 impl<'a> FontDesc<'a> {
@@ -35,7 +39,10 @@ impl<'a> FontDesc<'a> {
             family,
             data: FontDataInternal::new(family, style),
             transform: FontTransform::None,
+            rotation: 0.0,
             style,
+            fallback_families: vec![],
+            fallbacks: vec![],
         }
     }
 
@@ -49,7 +56,10 @@ impl<'a> FontDesc<'a> {
             family: self.family,
             data: self.data.clone(),
             transform: self.transform.clone(),
+            rotation: self.rotation,
             style: self.style,
+            fallback_families: self.fallback_families.clone(),
+            fallbacks: self.fallbacks.clone(),
         }
     }
 
@@ -63,7 +73,10 @@ impl<'a> FontDesc<'a> {
             family: self.family,
             data: self.data.clone(),
             transform: self.transform.clone(),
+            rotation: self.rotation,
             style,
+            fallback_families: self.fallback_families.clone(),
+            fallbacks: self.fallbacks.clone(),
         }
     }
 
@@ -77,15 +90,93 @@ impl<'a> FontDesc<'a> {
             family: self.family,
             data: self.data.clone(),
             transform: trans,
+            rotation: self.rotation,
             style: self.style,
+            fallback_families: self.fallback_families.clone(),
+            fallbacks: self.fallbacks.clone(),
         }
     }
 
+    /// Set the rotation of the font, on top of its transformation
+    ///
+    /// - `angle`: The clockwise angle of the rotation in degrees, which may be any angle
+    /// - **returns** The new font description with this rotation applied
+    pub fn rotation(&self, angle: f64) -> Self {
+        let mut font = self.clone();
+        font.rotation = angle;
+        font
+    }
+
+    /** Adds a fallback font, which draws the characters missing from this font and from the
+    fallback fonts added before it.
+
+    Each character is drawn with the first font having a glyph for it, thus a text mixing
+    scripts, such as latin and CJK characters, can be drawn with fonts covering only one of
+    them. The characters no font has are drawn with this font, usually as a placeholder box.
+    The fallback fonts have the style of this font, and the ones which can't be loaded, such
+    as the families which aren't installed, are left out.
+
+    With the `ttf` feature, the family may also be the path to a font file, which doesn't
+    need to be installed. The vector backends, such as the SVG one, list the fallback families
+    after the family of this font and leave the fallback to the program showing the image,
+    thus the font files are left out of them.
+
+    - `family`: The family name of the fallback font, or the path to a font file
+    - **returns** The new font description with this fallback font
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let font = ("sans-serif", 20).into_font().fallback("Noto Sans CJK SC");
+    let drawing_area = SVGBackend::new("font_desc_fallback.svg", (200, 100)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    drawing_area.draw_text("Sales 销售", &font.color(&BLACK), (10, 50)).unwrap();
+    ```
+    */
+    pub fn fallback<F: Into<FontFamily<'a>>>(&self, family: F) -> Self {
+        let family = family.into();
+        let mut font = self.clone();
+        match family {
+            FontFamily::Name(name) if Path::new(name).is_file() => {}
+            _ => font.fallback_families.push(family),
+        }
+        match FontDataInternal::new(family, self.style) {
+            Ok(data) if data.is_family(family) => font.fallbacks.push(data),
+            _ => {}
+        }
+        font
+    }
+
+    /// Get the families of the fallback fonts, other than the font files, in order
+    pub fn get_fallback_families(&self) -> &[FontFamily<'a>] {
+        &self.fallback_families
+    }
+
+    // Splits the text into runs of characters drawn with the same font, this font first and
+    // then the fallback fonts in order
+    fn font_runs<'t>(
+        &'t self,
+        font: &'t FontDataInternal,
+        text: &'t str,
+    ) -> Vec<(&'t FontDataInternal, &'t str)> {
+        let fonts: Vec<_> = std::iter::once(font).chain(self.fallbacks.iter()).collect();
+        split_runs(text, &fonts, |font, c| font.has_glyph(c))
+            .into_iter()
+            .map(|(idx, run)| (fonts[idx], run))
+            .collect()
+    }
+
     /// Get the font transformation description
     pub fn get_transform(&self) -> FontTransform {
         self.transform.clone()
     }
 
+    /// Get the rotation of the font on top of its transformation, in degrees clockwise
+    pub fn get_rotation(&self) -> f64 {
+        self.rotation
+    }
+
     /** Returns a new text style object with the specified `color`.
 
     # Example
@@ -142,19 +233,47 @@ impl<'a> FontDesc<'a> {
     /// For a TTF type, zero point of the layout box is the left most baseline char of the string
     /// Thus the upper bound of the box is most likely be negative
     pub fn layout_box(&self, text: &str) -> FontResult<((i32, i32), (i32, i32))> {
+        let font = match &self.data {
+            Ok(ref font) => font,
+            Err(e) => return Err(e.clone()),
+        };
+        if self.fallbacks.is_empty() {
+            return font.estimate_layout(self.size, text);
+        }
+        // The runs are laid out one after the other, on the same baseline
+        let ((mut min_x, mut min_y), (mut max_x, mut max_y)) = ((0, 0), (0, 0));
+        let mut x = 0;
+        for (font, run) in self.font_runs(font, text) {
+            let ((run_min_x, run_min_y), (run_max_x, run_max_y)) =
+                font.estimate_layout(self.size, run)?;
+            min_x = min_x.min(x + run_min_x);
+            max_x = max_x.max(x + run_max_x);
+            min_y = min_y.min(run_min_y);
+            max_y = max_y.max(run_max_y);
+            x += run_max_x;
+        }
+        Ok(((min_x, min_y), (max_x, max_y)))
+    }
+
+    /// Get the ascent, the descent and the line gap of this font in pixels, see [`TextMetrics`].
+    /// The metrics of the fallback fonts are left out
+    pub fn vertical_metrics(&self) -> FontResult<(f64, f64, f64)> {
         match &self.data {
-            Ok(ref font) => font.estimate_layout(self.size, text),
+            Ok(font) => Ok(font.vertical_metrics(self.size)),
             Err(e) => Err(e.clone()),
         }
     }
 
     /// Get the size of the text if rendered in this font.
-    /// This is similar to `layout_box` function, but it apply the font transformation
-    /// and estimate the overall size of the font
+    /// This is similar to `layout_box` function, but it apply the font transformation and
+    /// rotation, and estimate the overall size of the font
     pub fn box_size(&self, text: &str) -> FontResult<(u32, u32)> {
         let ((min_x, min_y), (max_x, max_y)) = self.layout_box(text)?;
-        let (w, h) = self.get_transform().transform(max_x - min_x, max_y - min_y);
-        Ok((w.unsigned_abs(), h.unsigned_abs()))
+        Ok(self.get_transform().transform_size(
+            (max_x - min_x) as u32,
+            (max_y - min_y) as u32,
+            self.rotation,
+        ))
     }
 
     /// Actually draws a font with a drawing function
@@ -164,11 +283,54 @@ impl<'a> FontDesc<'a> {
         (x, y): (i32, i32),
         draw: DrawFunc,
     ) -> FontResult<Result<(), E>> {
-        match &self.data {
-            Ok(ref font) => font.draw((x, y), self.size, text, draw),
-            Err(e) => Err(e.clone()),
+        let font = match &self.data {
+            Ok(ref font) => font,
+            Err(e) => return Err(e.clone()),
+        };
+        if self.fallbacks.is_empty() {
+            return font.draw((x, y), self.size, text, draw);
         }
+        let mut draw = draw;
+        let mut run_x = x;
+        for (font, run) in self.font_runs(font, text) {
+            if let Err(e) = font.draw((run_x, y), self.size, run, &mut draw)? {
+                return Ok(Err(e));
+            }
+            run_x += font.estimate_layout(self.size, run)?.1 .0;
+        }
+        Ok(Ok(()))
+    }
+}
+
+// Splits the text into runs of consecutive characters drawn with the same font, each character
+// being drawn with the first font having a glyph for it, or with the first font when none has.
+// The runs are given with the index of their font.
+fn split_runs<'t, F>(
+    text: &'t str,
+    fonts: &[F],
+    has_glyph: impl Fn(&F, char) -> bool,
+) -> Vec<(usize, &'t str)> {
+    let mut runs = vec![];
+    let mut start = 0;
+    let mut current = None;
+    for (offset, c) in text.char_indices() {
+        let font = fonts
+            .iter()
+            .position(|font| has_glyph(font, c))
+            .unwrap_or(0);
+        match current {
+            Some(idx) if idx != font => {
+                runs.push((idx, &text[start..offset]));
+                start = offset;
+            }
+            _ => {}
+        }
+        current = Some(font);
+    }
+    if let Some(idx) = current {
+        runs.push((idx, &text[start..]));
     }
+    runs
 }
 
 impl<'a> From<&'a str> for FontDesc<'a> {
@@ -218,3 +380,165 @@ impl<'a, T: Into<FontDesc<'a>>> IntoFont<'a> for T {
         self.into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_runs() {
+        // A latin font and a CJK font
+        let fonts: [fn(char) -> bool; 2] =
+            [|c| c.is_ascii(), |c| ('\u{4e00}'..='\u{9fff}').contains(&c)];
+        let has_glyph = |font: &fn(char) -> bool, c| font(c);
+
+        assert_eq!(
+            split_runs("Sales 销售额 2024", &fonts, has_glyph),
+            vec![(0, "Sales "), (1, "销售额"), (0, " 2024")]
+        );
+        // The characters no font has are drawn with the first font
+        assert_eq!(
+            split_runs("图☃x", &fonts, has_glyph),
+            vec![(1, "图"), (0, "☃x")]
+        );
+        assert_eq!(split_runs("latin", &fonts, has_glyph), vec![(0, "latin")]);
+        assert!(split_runs("", &fonts, has_glyph).is_empty());
+    }
+
+    // Builds a TrueType font with a single glyph, a square covering the character cell
+    // horizontally from `x0` to `x1` out of 1000 units
+    #[cfg(all(feature = "ttf", not(target_arch = "wasm32")))]
+    fn single_glyph_font(c: char, (x0, x1): (i16, i16)) -> Vec<u8> {
+        fn push(data: &mut Vec<u8>, values: &[i32]) {
+            for v in values {
+                data.extend_from_slice(&(*v as u16).to_be_bytes());
+            }
+        }
+        let c = c as i32;
+
+        let mut head = vec![0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0x5f, 0x0f, 0x3c, 0xf5];
+        push(&mut head, &[0x000b, 1000]);
+        head.extend_from_slice(&[0; 16]);
+        push(&mut head, &[x0.into(), 0, x1.into(), 700, 0, 8, 2, 0, 0]);
+
+        let mut hhea = vec![0, 1, 0, 0];
+        push(
+            &mut hhea,
+            &[800, -200, 0, 1000, 0, 0, 1000, 1, 0, 0, 0, 0, 0, 0, 0, 2],
+        );
+
+        let mut maxp = vec![0, 1, 0, 0];
+        push(&mut maxp, &[2, 4, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        // A format 4 mapping of the character to the glyph 1
+        let mut cmap = vec![];
+        push(&mut cmap, &[0, 1, 3, 1, 0, 12]);
+        push(&mut cmap, &[4, 32, 0, 4, 4, 1, 0, c, 0xffff, 0]);
+        push(&mut cmap, &[c, 0xffff, 1 - c, 1, 0, 0]);
+
+        let mut glyf = vec![];
+        push(&mut glyf, &[1, x0.into(), 0, x1.into(), 700, 3, 0]);
+        glyf.extend_from_slice(&[1; 4]);
+        push(&mut glyf, &[x0.into(), 0, (x1 - x0).into(), 0]);
+        push(&mut glyf, &[0, 700, 0, -700]);
+
+        let mut loca = vec![];
+        push(&mut loca, &[0, 0, glyf.len() as i32 / 2]);
+
+        let mut hmtx = vec![];
+        push(&mut hmtx, &[1000, 0, 1000, x0.into()]);
+
+        let tables = [
+            (b"cmap", cmap),
+            (b"glyf", glyf),
+            (b"head", head),
+            (b"hhea", hhea),
+            (b"hmtx", hmtx),
+            (b"loca", loca),
+            (b"maxp", maxp),
+        ];
+        let mut font = vec![0, 1, 0, 0];
+        push(&mut font, &[tables.len() as i32, 64, 2, 48]);
+        let mut offset = 12 + 16 * tables.len();
+        let mut body = vec![];
+        for (tag, data) in tables.iter() {
+            font.extend_from_slice(&tag[..]);
+            font.extend_from_slice(&[0; 4]);
+            font.extend_from_slice(&(offset as u32).to_be_bytes());
+            font.extend_from_slice(&(data.len() as u32).to_be_bytes());
+            body.extend_from_slice(data);
+            while body.len() % 4 != 0 {
+                body.push(0);
+            }
+            offset = 12 + 16 * tables.len() + body.len();
+        }
+        font.extend(body);
+        font
+    }
+
+    #[cfg(all(feature = "ttf", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_fallback_font_drawing() {
+        use std::collections::HashSet;
+
+        // A latin font without any CJK glyph, and a CJK one which only has the CJK glyph
+        let dir = std::env::temp_dir();
+        let latin_path = dir.join(format!("plotters-latin-{}.ttf", std::process::id()));
+        let cjk_path = dir.join(format!("plotters-cjk-{}.ttf", std::process::id()));
+        std::fs::write(&latin_path, single_glyph_font('A', (100, 400))).unwrap();
+        std::fs::write(&cjk_path, single_glyph_font('销', (0, 1000))).unwrap();
+        let (latin_path, cjk_path) = (latin_path.to_str().unwrap(), cjk_path.to_str().unwrap());
+
+        let font = FontDesc::new(latin_path.into(), 20.0, FontStyle::Normal);
+        let fallback = FontDesc::new(cjk_path.into(), 20.0, FontStyle::Normal);
+        let mixed = font.fallback(cjk_path);
+        assert!(matches!(&font.data, Ok(f) if f.has_glyph('A') && !f.has_glyph('销')));
+        assert!(matches!(&fallback.data, Ok(f) if f.has_glyph('销')));
+        // The font files are left out of the families listed by the vector backends
+        assert!(mixed.get_fallback_families().is_empty());
+
+        let pixels = |font: &FontDesc, text: &str, pos| {
+            let mut pixels = HashSet::new();
+            font.draw(text, pos, |x, y, alpha| {
+                if alpha > 0.0 {
+                    pixels.insert((x, y));
+                }
+                Ok::<(), ()>(())
+            })
+            .unwrap()
+            .unwrap();
+            pixels
+        };
+
+        // The latin letter is drawn with the main font and the CJK one with the fallback
+        let latin_width = font.box_size("A").unwrap().0 as i32;
+        let latin = pixels(&font, "A", (0, 0));
+        let cjk = pixels(&fallback, "销", (latin_width, 0));
+        assert!(!latin.is_empty() && !cjk.is_empty());
+        let expected: HashSet<_> = latin.union(&cjk).copied().collect();
+        assert_eq!(pixels(&mixed, "A销", (0, 0)), expected);
+        assert_eq!(
+            mixed.box_size("A销").unwrap().0,
+            font.box_size("A").unwrap().0 + fallback.box_size("销").unwrap().0
+        );
+        // Without the fallback, the CJK character is left blank by the latin font
+        assert_eq!(pixels(&font, "A销", (0, 0)), latin);
+
+        std::fs::remove_file(latin_path).unwrap();
+        std::fs::remove_file(cjk_path).unwrap();
+    }
+
+    #[cfg(all(feature = "ttf", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_missing_fallback_font() {
+        // The missing family isn't replaced by the default font, but still listed for the
+        // vector backends
+        let font = FontDesc::from(("sans-serif", 20)).fallback("No Such Font Family");
+        assert!(font.fallbacks.is_empty());
+        assert_eq!(font.get_fallback_families().len(), 1);
+        assert_eq!(
+            font.get_fallback_families()[0].as_str(),
+            "No Such Font Family"
+        );
+    }
+}
@@ -65,6 +65,23 @@ pub trait FontData: Clone {
     type ErrorType: Sized + std::error::Error + Clone;
     fn new(family: FontFamily, style: FontStyle) -> Result<Self, Self::ErrorType>;
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType>;
+    /// Tells if the font has a glyph for the character, the fonts which can't tell are assumed
+    /// to have all of them
+    fn has_glyph(&self, _c: char) -> bool {
+        true
+    }
+    /// Tells if the font belongs to the family, rather than being the replacement loaded when
+    /// the family is missing, the fonts which can't tell are assumed to belong to it
+    fn is_family(&self, _family: FontFamily) -> bool {
+        true
+    }
+    /// Gets the ascent, the descent and the line gap of the font at the size, in pixels. The
+    /// fonts which can't tell split the size between an ascent of one em and a descent of the
+    /// rest
+    fn vertical_metrics(&self, size: f64) -> (f64, f64, f64) {
+        let em = size / 1.24;
+        (em, size - em, 0.0)
+    }
     fn draw<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
         &self,
         _pos: (i32, i32),
@@ -1,6 +1,7 @@
 use std::borrow::{Borrow, Cow};
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 
 use lazy_static::lazy_static;
@@ -161,9 +162,16 @@ fn load_font_data(face: FontFamily, style: FontStyle) -> FontResult<FontExt> {
     let make_not_found_error =
         || FontError::NoSuchFont(face.as_str().to_owned(), style.as_str().to_owned());
 
-    if let Ok(handle) = FONT_SOURCE
-        .with(|source| source.select_best_match(&[family, FamilyName::SansSerif], &properties))
-    {
+    // A family naming a font file is loaded from this file, which doesn't need to be installed
+    let handle = match face {
+        FontFamily::Name(name) if Path::new(name).is_file() => {
+            Ok(Handle::from_path(PathBuf::from(name), 0))
+        }
+        _ => FONT_SOURCE
+            .with(|source| source.select_best_match(&[family, FamilyName::SansSerif], &properties)),
+    };
+
+    if let Ok(handle) = handle {
         let font = handle
             .load()
             .map(FontExt::new)
@@ -204,6 +212,30 @@ impl FontData for FontDataInternal {
         Ok(FontDataInternal(load_font_data(family, style)?))
     }
 
+    fn has_glyph(&self, c: char) -> bool {
+        self.0.glyph_for_char(c).is_some()
+    }
+
+    fn is_family(&self, family: FontFamily) -> bool {
+        match family {
+            // The missing families are replaced by the default sans-serif font
+            FontFamily::Name(name) if !Path::new(name).is_file() => {
+                self.0.family_name().eq_ignore_ascii_case(name)
+            }
+            _ => true,
+        }
+    }
+
+    fn vertical_metrics(&self, size: f64) -> (f64, f64, f64) {
+        let metrics = self.0.metrics();
+        let scale = size / 1.24 / f64::from(metrics.units_per_em);
+        (
+            f64::from(metrics.ascent) * scale,
+            -f64::from(metrics.descent) * scale,
+            f64::from(metrics.line_gap) * scale,
+        )
+    }
+
     fn estimate_layout(&self, size: f64, text: &str) -> Result<LayoutBox, Self::ErrorType> {
         let font = &self.0;
         let pixel_per_em = size / 1.24;
@@ -317,4 +349,16 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_load_font_file() -> FontResult<()> {
+        // The test only runs where the font is installed at the usual place
+        let path = "/usr/share/fonts/truetype/dejavu/DejaVuSerif.ttf";
+        if !Path::new(path).is_file() {
+            return Ok(());
+        }
+        let font = load_font_data(FontFamily::Name(path), FontStyle::Normal)?;
+        assert_eq!(font.full_name(), "DejaVu Serif");
+        Ok(())
+    }
 }
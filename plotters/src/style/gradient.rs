@@ -0,0 +1,132 @@
+use super::color::{Color, RGBAColor};
+use plotters_backend::BackendGradient;
+
+pub use plotters_backend::GradientDirection;
+
+/**
+A linear gradient fill, made of two or more color stops along a direction.
+
+The gradient spans the bounding box of the filled shape: a vertical gradient goes from the
+top to the bottom of the shape, a horizontal one from its left to its right, and a diagonal
+one from its upper left corner to its lower right corner. The colors are interpolated between
+the stops, including their transparency.
+
+# Example
+
+```
+use plotters::prelude::*;
+let drawing_area = SVGBackend::new("gradient_style.svg", (300, 200)).into_drawing_area();
+drawing_area.fill(&WHITE).unwrap();
+let sunset = GradientStyle::vertical(&BLUE, &RED).add_stop(0.7, &YELLOW);
+drawing_area
+    .draw(&Rectangle::new([(50, 20), (250, 180)], BLUE.filled()).with_gradient(sunset))
+    .unwrap();
+```
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct GradientStyle {
+    direction: GradientDirection,
+    stops: Vec<(f64, RGBAColor)>,
+}
+
+impl GradientStyle {
+    /// Create a new gradient going from one color to another
+    /// - `direction`: The direction of the gradient
+    /// - `from`: The color the gradient starts with
+    /// - `to`: The color the gradient ends with
+    /// - **returns** The newly created gradient
+    pub fn new<A: Color, B: Color>(direction: GradientDirection, from: &A, to: &B) -> Self {
+        Self {
+            direction,
+            stops: vec![(0.0, from.to_rgba()), (1.0, to.to_rgba())],
+        }
+    }
+
+    /// Create a new gradient from the top to the bottom of the shape
+    /// - `top`: The color at the top of the shape
+    /// - `bottom`: The color at the bottom of the shape
+    /// - **returns** The newly created gradient
+    pub fn vertical<A: Color, B: Color>(top: &A, bottom: &B) -> Self {
+        Self::new(GradientDirection::Vertical, top, bottom)
+    }
+
+    /// Create a new gradient from the left to the right of the shape
+    /// - `left`: The color at the left of the shape
+    /// - `right`: The color at the right of the shape
+    /// - **returns** The newly created gradient
+    pub fn horizontal<A: Color, B: Color>(left: &A, right: &B) -> Self {
+        Self::new(GradientDirection::Horizontal, left, right)
+    }
+
+    /// Create a new gradient from the upper left corner to the lower right corner of the shape
+    /// - `upper_left`: The color at the upper left corner of the shape
+    /// - `bottom_right`: The color at the lower right corner of the shape
+    /// - **returns** The newly created gradient
+    pub fn diagonal<A: Color, B: Color>(upper_left: &A, bottom_right: &B) -> Self {
+        Self::new(GradientDirection::Diagonal, upper_left, bottom_right)
+    }
+
+    /// Add a color stop to the gradient
+    /// - `pos`: The position of the stop along the gradient, from 0 to 1
+    /// - `color`: The color at this position
+    /// - **returns** The gradient with the new stop
+    pub fn add_stop<C: Color>(mut self, pos: f64, color: &C) -> Self {
+        let pos = pos.clamp(0.0, 1.0);
+        let idx = self.stops.iter().take_while(|(p, _)| *p <= pos).count();
+        self.stops.insert(idx, (pos, color.to_rgba()));
+        self
+    }
+
+    /// Get the direction of the gradient
+    pub fn direction(&self) -> GradientDirection {
+        self.direction
+    }
+
+    /// Get the color stops of the gradient, sorted by position
+    pub fn stops(&self) -> &[(f64, RGBAColor)] {
+        &self.stops
+    }
+
+    /// Convert the gradient to the form used by the drawing backends
+    pub fn to_backend_gradient(&self) -> BackendGradient {
+        BackendGradient {
+            direction: self.direction,
+            stops: self
+                .stops
+                .iter()
+                .map(|(pos, color)| (*pos, color.to_backend_color()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::style::{BLUE, GREEN, RED};
+
+    #[test]
+    fn test_stops() {
+        let gradient = GradientStyle::horizontal(&RED, &BLUE)
+            .add_stop(0.5, &GREEN)
+            .add_stop(2.0, &RED);
+        assert_eq!(gradient.direction(), GradientDirection::Horizontal);
+        let positions: Vec<_> = gradient.stops().iter().map(|(p, _)| *p).collect();
+        assert_eq!(positions, vec![0.0, 0.5, 1.0, 1.0]);
+        assert_eq!(gradient.stops()[1].1, GREEN.to_rgba());
+    }
+
+    #[test]
+    fn test_backend_gradient() {
+        let gradient = GradientStyle::vertical(&RED, &BLUE.mix(0.5)).to_backend_gradient();
+        assert_eq!(gradient.color_at(-1.0).rgb, (255, 0, 0));
+        let middle = gradient.color_at(0.5);
+        assert_eq!(middle.rgb, (128, 0, 128));
+        assert!((middle.alpha - 0.75).abs() < 1e-9);
+        assert_eq!(gradient.color_at(2.0).rgb, (0, 0, 255));
+
+        assert_eq!(gradient.position((5, 15), (0, 10), (10, 20)), 0.5);
+        // A flat bounding box takes the color of the start of the gradient
+        assert_eq!(gradient.position((5, 10), (0, 10), (10, 10)), 0.0);
+    }
+}
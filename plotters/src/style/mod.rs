@@ -4,10 +4,12 @@
 mod color;
 pub mod colors;
 mod font;
+mod gradient;
 mod palette;
 mod shape;
 mod size;
 mod text;
+mod theme;
 
 /// Definitions of palettes of accessibility
 pub use self::palette::*;
@@ -22,9 +24,12 @@ pub use colors::full_palette;
 pub use font::register_font;
 pub use font::{
     FontDesc, FontError, FontFamily, FontResult, FontStyle, FontTransform, IntoFont, LayoutBox,
+    TextMetrics,
 };
 
-pub use shape::ShapeStyle;
+pub use gradient::{GradientDirection, GradientStyle};
+pub use shape::{DashPattern, ShapeStyle, MAX_DASH_PATTERN_LEN};
 pub use size::{AsRelative, RelativeSize, SizeDesc};
 pub use text::text_anchor;
 pub use text::{IntoTextStyle, TextStyle};
+pub use theme::Theme;
@@ -10,6 +10,44 @@ pub struct ShapeStyle {
     pub filled: bool,
     /// Stroke width.
     pub stroke_width: u32,
+    // Dash pattern of the stroke, `None` for a solid stroke
+    pub(crate) stroke_dash: Option<DashPattern>,
+}
+
+/// The maximum number of lengths in a [`DashPattern`]
+pub const MAX_DASH_PATTERN_LEN: usize = 8;
+
+/**
+A dash pattern, following the SVG `stroke-dasharray` convention: the alternating lengths, in
+pixels, of the dashes and of the gaps between them, starting with a dash.
+
+The pattern is stored inline so that [`ShapeStyle`] remains `Copy`, which limits it to
+[`MAX_DASH_PATTERN_LEN`] lengths.
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DashPattern {
+    lengths: [f64; MAX_DASH_PATTERN_LEN],
+    len: usize,
+}
+
+impl DashPattern {
+    /// Create a new dash pattern
+    /// - `lengths`: The on/off lengths of the pattern, any length past the first
+    ///   [`MAX_DASH_PATTERN_LEN`] ones is ignored
+    pub fn new(lengths: &[f64]) -> Self {
+        let len = lengths.len().min(MAX_DASH_PATTERN_LEN);
+        let mut pattern = Self {
+            lengths: [0.0; MAX_DASH_PATTERN_LEN],
+            len,
+        };
+        pattern.lengths[..len].copy_from_slice(&lengths[..len]);
+        pattern
+    }
+
+    /// Get the on/off lengths of the pattern
+    pub fn lengths(&self) -> &[f64] {
+        &self.lengths[..self.len]
+    }
 }
 
 impl ShapeStyle {
@@ -20,11 +58,7 @@ impl ShapeStyle {
 
     ```
     use plotters::prelude::*;
-    let original_style = ShapeStyle {
-        color: BLUE.mix(0.6),
-        filled: false,
-        stroke_width: 2,
-    };
+    let original_style = BLUE.mix(0.6).stroke_width(2);
     let filled_style = original_style.filled();
     let drawing_area = SVGBackend::new("shape_style_filled.svg", (400, 200)).into_drawing_area();
     drawing_area.fill(&WHITE).unwrap();
@@ -41,6 +75,7 @@ impl ShapeStyle {
             color: self.color.to_rgba(),
             filled: true,
             stroke_width: self.stroke_width,
+            stroke_dash: self.stroke_dash,
         }
     }
 
@@ -51,11 +86,7 @@ impl ShapeStyle {
 
     ```
     use plotters::prelude::*;
-    let original_style = ShapeStyle {
-        color: BLUE.mix(0.6),
-        filled: false,
-        stroke_width: 2,
-    };
+    let original_style = BLUE.mix(0.6).stroke_width(2);
     let new_style = original_style.stroke_width(5);
     let drawing_area = SVGBackend::new("shape_style_stroke_width.svg", (400, 200)).into_drawing_area();
     drawing_area.fill(&WHITE).unwrap();
@@ -72,8 +103,48 @@ impl ShapeStyle {
             color: self.color.to_rgba(),
             filled: self.filled,
             stroke_width: width,
+            stroke_dash: self.stroke_dash,
+        }
+    }
+
+    /**
+    Returns a new style with the same color and stroke width, stroked with the specified dash
+    pattern.
+
+    - `pattern`: The on/off lengths of the dashes and the gaps, in pixels, following the SVG
+      `stroke-dasharray` convention. An odd number of lengths is repeated, so `&[5.0]` means
+      5 pixels on, 5 pixels off. An empty pattern gives a solid stroke again.
+
+    # Example
+
+    ```
+    use plotters::prelude::*;
+    let drawing_area = SVGBackend::new("shape_style_dash.svg", (400, 200)).into_drawing_area();
+    drawing_area.fill(&WHITE).unwrap();
+    let dashed = BLUE.stroke_width(2).dashed(&[10.0, 5.0]);
+    let dotted = RED.stroke_width(2).dashed(&[2.0, 4.0]);
+    assert_eq!(dashed.dash_pattern().unwrap().lengths(), &[10.0, 5.0]);
+    drawing_area.draw(&PathElement::new(vec![(20, 60), (380, 60)], dashed));
+    drawing_area.draw(&PathElement::new(vec![(20, 140), (380, 140)], dotted));
+    ```
+    */
+    pub fn dashed(&self, pattern: &[f64]) -> Self {
+        Self {
+            color: self.color.to_rgba(),
+            filled: self.filled,
+            stroke_width: self.stroke_width,
+            stroke_dash: if pattern.is_empty() {
+                None
+            } else {
+                Some(DashPattern::new(pattern))
+            },
         }
     }
+
+    /// Get the dash pattern of the stroke, `None` for a solid stroke
+    pub fn dash_pattern(&self) -> Option<&DashPattern> {
+        self.stroke_dash.as_ref()
+    }
 }
 
 impl<T: Color> From<T> for ShapeStyle {
@@ -82,6 +153,7 @@ impl<T: Color> From<T> for ShapeStyle {
             color: f.to_rgba(),
             filled: false,
             stroke_width: 1,
+            stroke_dash: None,
         }
     }
 }
@@ -95,4 +167,8 @@ impl BackendStyle for ShapeStyle {
     fn stroke_width(&self) -> u32 {
         self.stroke_width
     }
+    /// Returns the dash pattern, empty for a solid stroke.
+    fn stroke_dash(&self) -> &[f64] {
+        self.stroke_dash.as_ref().map_or(&[], DashPattern::lengths)
+    }
 }
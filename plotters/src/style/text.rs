@@ -171,6 +171,47 @@ impl<'a> TextStyle<'a> {
         }
     }
 
+    /// Sets the rotation of the style, on top of its font transformation. Unlike the
+    /// transformation, the rotation may be any angle.
+    ///
+    /// - `angle`: The clockwise angle of the rotation in degrees
+    /// - **returns** The up-to-dated text style
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let style = TextStyle::from(("sans-serif", 20).into_font()).rotation(-45.0);
+    /// ```
+    pub fn rotation(&self, angle: f64) -> Self {
+        Self {
+            font: self.font.rotation(angle),
+            color: self.color,
+            pos: self.pos,
+        }
+    }
+
+    /// Adds a fallback font, which draws the characters missing from the font of the style.
+    ///
+    /// - `family`: The family name of the fallback font, or the path to a font file
+    /// - **returns** The up-to-dated text style
+    ///
+    /// ```rust
+    /// use plotters::prelude::*;
+    ///
+    /// let style = TextStyle::from(("sans-serif", 20).into_font()).fallback("Noto Sans CJK SC");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// [`FontDesc::fallback()`]
+    pub fn fallback<F: Into<FontFamily<'a>>>(&self, family: F) -> Self {
+        Self {
+            font: self.font.fallback(family),
+            color: self.color,
+            pos: self.pos,
+        }
+    }
+
     /// Sets the anchor position.
     ///
     /// - `pos`: The required anchor position
@@ -295,6 +336,10 @@ impl<'a> BackendTextStyle for TextStyle<'a> {
         self.font.get_transform()
     }
 
+    fn rotation(&self) -> f64 {
+        self.font.get_rotation()
+    }
+
     fn style(&self) -> FontStyle {
         self.font.get_style()
     }
@@ -304,6 +349,10 @@ impl<'a> BackendTextStyle for TextStyle<'a> {
         self.font.layout_box(text)
     }
 
+    fn vertical_metrics(&self) -> Result<(f64, f64, f64), Self::FontError> {
+        self.font.vertical_metrics()
+    }
+
     fn anchor(&self) -> text_anchor::Pos {
         self.pos
     }
@@ -312,6 +361,10 @@ impl<'a> BackendTextStyle for TextStyle<'a> {
         self.font.get_family()
     }
 
+    fn fallback_families(&self) -> &[FontFamily<'_>] {
+        self.font.get_fallback_families()
+    }
+
     fn draw<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(
         &self,
         text: &str,
@@ -324,4 +377,29 @@ impl<'a> BackendTextStyle for TextStyle<'a> {
             draw(x, y, mix_color)
         })
     }
+
+    #[allow(clippy::type_complexity)]
+    fn layout_box_scaled(
+        &self,
+        text: &str,
+        scale: f64,
+    ) -> Result<((i32, i32), (i32, i32)), Self::FontError> {
+        self.font
+            .resize(self.font.get_size() * scale)
+            .layout_box(text)
+    }
+
+    fn draw_scaled<E, DrawFunc: FnMut(i32, i32, BackendColor) -> Result<(), E>>(
+        &self,
+        text: &str,
+        pos: BackendCoord,
+        scale: f64,
+        mut draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::FontError> {
+        // The font is rasterized at the scaled size rather than the pixels being enlarged
+        let color = self.color.color();
+        self.font
+            .resize(self.font.get_size() * scale)
+            .draw(text, pos, move |x, y, a| draw(x, y, color.mix(a as f64)))
+    }
 }
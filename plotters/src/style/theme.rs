@@ -0,0 +1,178 @@
+use super::color::{Color, RGBColor};
+use super::font::{FontDesc, FontFamily, FontStyle};
+use super::shape::ShapeStyle;
+use super::text::TextStyle;
+
+/**
+A chart theme, gathering the colors, the font and the line styles the charts are drawn with
+when they don't set their own.
+
+A theme is applied to a drawing area with [`crate::drawing::DrawingArea::apply_theme()`],
+which fills it with the background color, and it then applies to the charts built on it or
+on its sub-areas. It can also be set on a single chart with
+[`crate::chart::ChartBuilder::with_theme()`].
+
+The mesh takes its axis, grid line and label styles from the theme, unless they are set on
+the [`crate::chart::MeshStyle`], and so does the font of the legend. The series colors cycle
+through the palette with [`crate::chart::ChartContext::next_series_color()`]. The styles
+given explicitly, such as the caption style, are kept as is: [`Theme::text_style()`] gives a
+text style with the font and the text color of the theme.
+
+# Example
+
+```
+use plotters::prelude::*;
+let theme = Theme::dark();
+let mut drawing_area = SVGBackend::new("theme.svg", (300, 200)).into_drawing_area();
+drawing_area.apply_theme(&theme).unwrap();
+let mut chart = ChartBuilder::on(&drawing_area)
+    .caption("Dark theme", theme.text_style(20.0))
+    .margin(10)
+    .set_left_and_bottom_label_area_size(30)
+    .build_cartesian_2d(0.0..4.0, 0.0..2.0)
+    .unwrap();
+chart.configure_mesh().draw().unwrap();
+for slope in 1..4 {
+    let color = chart.next_series_color();
+    chart
+        .draw_series(LineSeries::new(
+            (0..=4).map(|x| (x as f64, x as f64 * slope as f64 / 6.0)),
+            color,
+        ))
+        .unwrap();
+}
+```
+*/
+#[derive(Clone)]
+pub struct Theme {
+    /// The colors the series cycle through
+    pub palette: Vec<RGBColor>,
+    /// The font family of the texts
+    pub font: FontFamily<'static>,
+    /// The color of the texts
+    pub text_color: RGBColor,
+    /// The background color of the drawing area
+    pub background: RGBColor,
+    /// The style of the axes and of the tick marks
+    pub axis_style: ShapeStyle,
+    /// The style of the grid lines at the labeled key points
+    pub bold_grid_style: ShapeStyle,
+    /// The style of the grid lines between them
+    pub light_grid_style: ShapeStyle,
+}
+
+impl Theme {
+    /// The light theme, black on white, which matches the styles the charts have without theme
+    pub fn light() -> Self {
+        let black = RGBColor(0, 0, 0);
+        Self {
+            palette: vec![
+                RGBColor(31, 119, 180),
+                RGBColor(255, 127, 14),
+                RGBColor(44, 160, 44),
+                RGBColor(214, 39, 40),
+                RGBColor(148, 103, 189),
+                RGBColor(140, 86, 75),
+                RGBColor(227, 119, 194),
+                RGBColor(127, 127, 127),
+                RGBColor(188, 189, 34),
+                RGBColor(23, 190, 207),
+            ],
+            font: FontFamily::SansSerif,
+            text_color: black,
+            background: RGBColor(255, 255, 255),
+            axis_style: black.into(),
+            bold_grid_style: black.mix(0.2).into(),
+            light_grid_style: black.mix(0.1).into(),
+        }
+    }
+
+    /// The dark theme, light gray on dark gray with bright series colors
+    pub fn dark() -> Self {
+        let white = RGBColor(255, 255, 255);
+        Self {
+            palette: vec![
+                RGBColor(102, 194, 255),
+                RGBColor(255, 159, 64),
+                RGBColor(120, 220, 120),
+                RGBColor(255, 99, 132),
+                RGBColor(200, 160, 255),
+                RGBColor(255, 220, 90),
+                RGBColor(80, 230, 220),
+                RGBColor(255, 140, 220),
+            ],
+            font: FontFamily::SansSerif,
+            text_color: RGBColor(220, 220, 220),
+            background: RGBColor(30, 30, 34),
+            axis_style: RGBColor(200, 200, 200).into(),
+            bold_grid_style: white.mix(0.2).into(),
+            light_grid_style: white.mix(0.07).into(),
+        }
+    }
+
+    /// The light variant of the Solarized color scheme
+    pub fn solarized() -> Self {
+        let base1 = RGBColor(147, 161, 161);
+        Self {
+            palette: vec![
+                RGBColor(38, 139, 210),
+                RGBColor(203, 75, 22),
+                RGBColor(133, 153, 0),
+                RGBColor(211, 54, 130),
+                RGBColor(42, 161, 152),
+                RGBColor(181, 137, 0),
+                RGBColor(108, 113, 196),
+                RGBColor(220, 50, 47),
+            ],
+            font: FontFamily::SansSerif,
+            text_color: RGBColor(88, 110, 117),
+            background: RGBColor(253, 246, 227),
+            axis_style: RGBColor(101, 123, 131).into(),
+            bold_grid_style: base1.mix(0.5).into(),
+            light_grid_style: base1.mix(0.2).into(),
+        }
+    }
+
+    /// Pick a color of the palette, which cycles when the index is past its end
+    pub fn pick(&self, idx: usize) -> RGBColor {
+        match self.palette.len() {
+            0 => self.text_color,
+            len => self.palette[idx % len],
+        }
+    }
+
+    /// The text style with the font and the text color of the theme, at a size in pixels
+    pub fn text_style(&self, size: f64) -> TextStyle<'static> {
+        let mut style = TextStyle::from(FontDesc::new(self.font, size, FontStyle::Normal));
+        style.color = self.text_color.to_backend_color();
+        style
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_theme_palette() {
+        let theme = Theme::solarized();
+        assert_eq!(theme.pick(1), RGBColor(203, 75, 22));
+        assert_eq!(theme.pick(theme.palette.len() + 1), theme.pick(1));
+
+        let theme = Theme {
+            palette: vec![],
+            ..Theme::dark()
+        };
+        assert_eq!(theme.pick(3), theme.text_color);
+
+        let style = Theme::dark().text_style(15.0);
+        assert_eq!(style.font.get_size(), 15.0);
+        assert_eq!(style.color.rgb, (220, 220, 220));
+    }
+}